@@ -75,7 +75,6 @@ pub fn cargo_miri_test(name: &str, args: &str) -> Step {
     cargo(name, "miri", &format!("test {}", args))
 }
 
-#[allow(dead_code)]
 pub fn cargo_build(name: &str, args: &str) -> Step {
     cargo(name, "build", args)
 }
@@ -3,6 +3,7 @@ use std::fs::File;
 use std::io::Read;
 use std::io::Write;
 
+use crate::actions::cargo_build;
 use crate::actions::cargo_check;
 use crate::actions::cargo_doc;
 use crate::actions::cargo_miri_setup;
@@ -199,6 +200,29 @@ fn miri_test_job() -> Job {
     }
 }
 
+// Lite runtime generated code must work on targets without `std`.
+fn no_std_job() -> Job {
+    let mut steps = Vec::new();
+    steps.push(checkout_sources());
+    steps.push(rust_install_toolchain(RustToolchain::Stable));
+    steps.push(Step::run(
+        "Install target",
+        "rustup target add thumbv7em-none-eabihf",
+    ));
+    steps.push(cargo_test("cargo test", "-p protobuf-test-no-std"));
+    steps.push(cargo_build(
+        "cargo build",
+        "-p protobuf-test-no-std --target=thumbv7em-none-eabihf",
+    ));
+    Job {
+        id: "no-std".to_owned(),
+        name: "no_std".to_owned(),
+        runs_on: LINUX.ghwf,
+        steps,
+        ..Default::default()
+    }
+}
+
 // https://github.com/megalinter/megalinter
 fn super_linter_job() -> Job {
     let mut steps = Vec::new();
@@ -263,6 +287,8 @@ fn jobs() -> Yaml {
 
     r.push(miri_test_job());
 
+    r.push(no_std_job());
+
     r.push(super_linter_job());
 
     r.push(rustfmt_job());
@@ -224,6 +224,8 @@ impl Codegen {
 
         parser.inputs(&self.inputs);
         parser.includes(&self.includes);
+        // Comments are needed to generate documentation
+        parser.include_source_info(true);
         let parsed_and_typechecked = parser
             .parse_and_typecheck()
             .context("parse and typecheck")?;
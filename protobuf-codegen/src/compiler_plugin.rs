@@ -42,6 +42,7 @@ where
             r
         })
         .collect();
+    resp.set_supported_features(code_generator_response::Feature::FEATURE_PROTO3_OPTIONAL as u64);
     resp.write_to_writer(&mut stdout()).unwrap();
     Ok(())
 }
@@ -6,6 +6,7 @@ use protobuf::reflect::FileDescriptor;
 use protobuf::reflect::MessageDescriptor;
 use protobuf::reflect::OneofDescriptor;
 
+use crate::customize::rustproto_proto::customize_from_rustproto_for_file;
 use crate::customize::CustomizeCallback;
 use crate::Customize;
 
@@ -14,6 +15,8 @@ pub(crate) struct CustomizeElemCtx<'a> {
     pub(crate) for_elem: Customize,
     pub(crate) for_children: Customize,
     pub(crate) callback: &'a dyn CustomizeCallback,
+    /// Customize passed to codegen, before any file options are applied.
+    pub(crate) root: Customize,
 }
 
 impl<'a> fmt::Debug for CustomizeElemCtx<'a> {
@@ -42,8 +45,24 @@ impl<'a> CustomizeElemCtx<'a> {
             for_elem,
             for_children,
             callback: self.callback,
+            root: self.root.clone(),
         }
     }
+
+    /// Customize of given file, which may be not the file being generated.
+    pub(crate) fn for_file(&self, file: &FileDescriptor) -> Customize {
+        let root = CustomizeElemCtx {
+            for_elem: self.root.clone(),
+            for_children: self.root.clone(),
+            callback: self.callback,
+            root: self.root.clone(),
+        };
+        root.child(
+            &customize_from_rustproto_for_file(file.proto().options.get_or_default()),
+            file,
+        )
+        .for_elem
+    }
 }
 
 pub(crate) trait DescriptorForCustomize {
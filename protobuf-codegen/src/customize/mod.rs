@@ -112,6 +112,16 @@ pub struct Customize {
     ///
     /// This option will likely be on by default in rust-protobuf version 3.
     pub(crate) gen_mod_rs: Option<bool>,
+    /// Generate service traits, clients and servers.
+    pub(crate) gen_services: Option<bool>,
+    /// Generate borrowed read-only message views.
+    pub(crate) gen_views: Option<bool>,
+    /// Generate static field tables instead of per-message serialization code.
+    pub(crate) table_driven: Option<bool>,
+    /// Generate `cached_size` field in messages.
+    pub(crate) gen_cached_size: Option<bool>,
+    /// Use `BTreeMap` instead of `HashMap` for `map` fields.
+    pub(crate) btree_map: Option<bool>,
     /// Used internally to generate protos bundled in protobuf crate
     /// like `descriptor.proto`
     pub(crate) inside_protobuf: Option<bool>,
@@ -181,6 +191,64 @@ impl Customize {
         self
     }
 
+    /// Generate for each `service` a server trait, a client over
+    /// [`protobuf::service::Channel`] and a server dispatching requests to the trait.
+    ///
+    /// Generated code is transport agnostic. This option is off by default.
+    pub fn gen_services(mut self, gen_services: bool) -> Self {
+        self.gen_services = Some(gen_services);
+        self
+    }
+
+    /// Generate for each message `Foo` a read-only view `FooView<'a>`
+    /// borrowing strings, bytes and packed repeated fields from the input,
+    /// see [`protobuf::view`].
+    ///
+    /// This option is off by default.
+    pub fn gen_views(mut self, gen_views: bool) -> Self {
+        self.gen_views = Some(gen_views);
+        self
+    }
+
+    /// Generate for each message a static table of fields
+    /// interpreted by shared parser and serializer in `protobuf::rt::table`
+    /// instead of per-message `merge_from`, `compute_size`
+    /// and `write_to_with_cached_sizes` code.
+    ///
+    /// This reduces generated code size at the cost of some speed.
+    /// Messages with groups are generated as usual.
+    ///
+    /// This option is off by default.
+    pub fn table_driven(mut self, table_driven: bool) -> Self {
+        self.table_driven = Some(table_driven);
+        self
+    }
+
+    /// Generate `cached_size` field in messages.
+    ///
+    /// When the field is dropped, messages are smaller and are serialized
+    /// in one pass with [`Message::write_reverse`](protobuf::Message::write_reverse).
+    /// Without it, [`Message::cached_size`](protobuf::Message::cached_size) computes the size
+    /// (which takes time proportional to the message size),
+    /// and writing to `CodedOutputStream` serializes the message to a temporary buffer first.
+    ///
+    /// This option is on by default.
+    pub fn gen_cached_size(mut self, gen_cached_size: bool) -> Self {
+        self.gen_cached_size = Some(gen_cached_size);
+        self
+    }
+
+    /// Store `map` fields in `BTreeMap` instead of `HashMap`.
+    ///
+    /// `HashMap` is only available with `std` feature of `protobuf` crate,
+    /// so code used without `std` must be generated with this option.
+    ///
+    /// This option is off by default.
+    pub fn btree_map(mut self, btree_map: bool) -> Self {
+        self.btree_map = Some(btree_map);
+        self
+    }
+
     /// Generate code bundled in protobuf crate. Regular users don't need this option.
     pub fn inside_protobuf(mut self, inside_protobuf: bool) -> Self {
         self.inside_protobuf = Some(inside_protobuf);
@@ -216,6 +284,21 @@ impl Customize {
         if let Some(v) = that.gen_mod_rs {
             self.gen_mod_rs = Some(v);
         }
+        if let Some(v) = that.gen_services {
+            self.gen_services = Some(v);
+        }
+        if let Some(v) = that.gen_views {
+            self.gen_views = Some(v);
+        }
+        if let Some(v) = that.table_driven {
+            self.table_driven = Some(v);
+        }
+        if let Some(v) = that.gen_cached_size {
+            self.gen_cached_size = Some(v);
+        }
+        if let Some(v) = that.btree_map {
+            self.btree_map = Some(v);
+        }
         if let Some(v) = that.inside_protobuf {
             self.inside_protobuf = Some(v);
         }
@@ -262,6 +345,16 @@ impl Customize {
                 r.lite_runtime = Some(parse_bool(v)?);
             } else if n == "gen_mod_rs" {
                 r.gen_mod_rs = Some(parse_bool(v)?);
+            } else if n == "gen_services" {
+                r.gen_services = Some(parse_bool(v)?);
+            } else if n == "gen_views" {
+                r.gen_views = Some(parse_bool(v)?);
+            } else if n == "table_driven" {
+                r.table_driven = Some(parse_bool(v)?);
+            } else if n == "gen_cached_size" {
+                r.gen_cached_size = Some(parse_bool(v)?);
+            } else if n == "btree_map" {
+                r.btree_map = Some(parse_bool(v)?);
             } else if n == "inside_protobuf" {
                 r.inside_protobuf = Some(parse_bool(v)?);
             } else if n == "lite" {
@@ -16,6 +16,11 @@ pub(crate) fn customize_from_rustproto_for_message(source: &MessageOptions) -> C
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string.get(source);
     let lite_runtime = None;
     let gen_mod_rs = None;
+    let gen_services = None;
+    let gen_views = None;
+    let table_driven = None;
+    let gen_cached_size = None;
+    let btree_map = None;
     let inside_protobuf = None;
     Customize {
         before,
@@ -27,6 +32,11 @@ pub(crate) fn customize_from_rustproto_for_message(source: &MessageOptions) -> C
         tokio_bytes_for_string,
         lite_runtime,
         gen_mod_rs,
+        gen_services,
+        gen_views,
+        table_driven,
+        gen_cached_size,
+        btree_map,
         inside_protobuf,
     }
 }
@@ -45,6 +55,11 @@ pub(crate) fn customize_from_rustproto_for_field(source: &FieldOptions) -> Custo
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string_field.get(source);
     let lite_runtime = None;
     let gen_mod_rs = None;
+    let gen_services = None;
+    let gen_views = None;
+    let table_driven = None;
+    let gen_cached_size = None;
+    let btree_map = None;
     let inside_protobuf = None;
     Customize {
         before,
@@ -56,6 +71,11 @@ pub(crate) fn customize_from_rustproto_for_field(source: &FieldOptions) -> Custo
         tokio_bytes_for_string,
         lite_runtime,
         gen_mod_rs,
+        gen_services,
+        gen_views,
+        table_driven,
+        gen_cached_size,
+        btree_map,
         inside_protobuf,
     }
 }
@@ -70,6 +90,11 @@ pub(crate) fn customize_from_rustproto_for_file(source: &FileOptions) -> Customi
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string_all.get(source);
     let lite_runtime = rustproto::exts::lite_runtime_all.get(source);
     let gen_mod_rs = None;
+    let gen_services = rustproto::exts::gen_services_all.get(source);
+    let gen_views = rustproto::exts::gen_views_all.get(source);
+    let table_driven = rustproto::exts::table_driven_all.get(source);
+    let gen_cached_size = rustproto::exts::gen_cached_size_all.get(source);
+    let btree_map = rustproto::exts::btree_map_all.get(source);
     let inside_protobuf = None;
     Customize {
         before,
@@ -82,5 +107,10 @@ pub(crate) fn customize_from_rustproto_for_file(source: &FileOptions) -> Customi
         lite_runtime,
         inside_protobuf,
         gen_mod_rs,
+        gen_services,
+        gen_views,
+        table_driven,
+        gen_cached_size,
+        btree_map,
     }
 }
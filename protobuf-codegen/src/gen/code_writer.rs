@@ -2,6 +2,7 @@
 #![doc(hidden)]
 
 use crate::gen::rust_name::RustRelativePath;
+use crate::gen::rustdoc::rustdoc_lines;
 
 /// Field visibility.
 pub(crate) enum Visibility {
@@ -299,22 +300,21 @@ impl<'a> CodeWriter<'a> {
         info: Option<&protobuf::descriptor::SourceCodeInfo>,
         path: &[i32],
     ) {
-        let doc = info
-            .map(|v| &v.location)
-            .and_then(|ls| ls.iter().find(|l| l.path == path))
-            .map(|l| l.leading_comments());
-
-        let lines = doc
-            .iter()
-            .map(|doc| doc.lines())
-            .flatten()
-            .collect::<Vec<_>>();
-
-        // Skip comments with code blocks to avoid rustdoc trying to compile them.
-        if !lines.iter().any(|line| line.starts_with("    ")) {
-            for doc in &lines {
-                self.documentation(doc);
+        let location = match info.and_then(|v| v.location.iter().find(|l| l.path == path)) {
+            Some(location) => location,
+            None => return,
+        };
+
+        let mut doc = location.leading_comments().to_owned();
+        if !location.trailing_comments().is_empty() {
+            if !doc.is_empty() {
+                doc.push('\n');
             }
+            doc.push_str(location.trailing_comments());
+        }
+
+        for line in rustdoc_lines(&doc) {
+            self.documentation(&line);
         }
     }
 
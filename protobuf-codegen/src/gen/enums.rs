@@ -182,8 +182,22 @@ impl<'a> EnumGen<'a> {
             &self.customize.for_elem,
             &self.enum_with_scope.en,
         );
+        static VALUE_NUMBER: protobuf::rt::Lazy<i32> = protobuf::rt::Lazy::new();
+        let value_number = *VALUE_NUMBER.get(|| {
+            protobuf::reflect::MessageDescriptor::for_type::<EnumDescriptorProto>()
+                .field_by_name("value")
+                .expect("`value` must exist")
+                .proto()
+                .number()
+        });
+
+        let mut path = self.path.to_vec();
+        path.extend(&[value_number, 0]);
         w.expr_block(&format!("pub enum {}", type_name), |w| {
-            for value in self.values_all() {
+            for (id, value) in self.values_all().into_iter().enumerate() {
+                let len = path.len() - 1;
+                path[len] = id as i32;
+                w.all_documentation(self.info, &path);
                 write_protoc_insertion_point_for_enum_value(
                     w,
                     &self.customize.for_children,
@@ -235,7 +249,7 @@ impl<'a> EnumGen<'a> {
                 let ref type_name = self.type_name;
                 w.def_fn(
                     &format!(
-                        "from_i32(value: i32) -> ::std::option::Option<{}>",
+                        "from_i32(value: i32) -> ::core::option::Option<{}>",
                         type_name
                     ),
                     |w| {
@@ -243,7 +257,7 @@ impl<'a> EnumGen<'a> {
                             let values = self.values_unique();
                             for value in values {
                                 w.write_line(&format!(
-                                    "{} => ::std::option::Option::Some({}),",
+                                    "{} => ::core::option::Option::Some({}),",
                                     value.number(),
                                     value.rust_name_outer()
                                 ));
@@ -332,7 +346,7 @@ impl<'a> EnumGen<'a> {
     fn write_impl_eq(&self, w: &mut CodeWriter) {
         assert!(self.allow_alias());
         w.impl_for_block(
-            "::std::cmp::PartialEq",
+            "::core::cmp::PartialEq",
             &format!("{}", self.type_name),
             |w| {
                 w.def_fn("eq(&self, other: &Self) -> bool", |w| {
@@ -348,13 +362,16 @@ impl<'a> EnumGen<'a> {
 
     fn write_impl_hash(&self, w: &mut CodeWriter) {
         assert!(self.allow_alias());
-        w.impl_for_block("::std::hash::Hash", &format!("{}", self.type_name), |w| {
-            w.def_fn("hash<H : ::std::hash::Hasher>(&self, state: &mut H)", |w| {
-                w.write_line(&format!(
-                    "state.write_i32({}::Enum::value(self))",
-                    protobuf_crate_path(&self.customize.for_elem)
-                ));
-            });
+        w.impl_for_block("::core::hash::Hash", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                "hash<H : ::core::hash::Hasher>(&self, state: &mut H)",
+                |w| {
+                    w.write_line(&format!(
+                        "state.write_i32({}::Enum::value(self))",
+                        protobuf_crate_path(&self.customize.for_elem)
+                    ));
+                },
+            );
         });
     }
 
@@ -371,7 +388,7 @@ impl<'a> EnumGen<'a> {
             w.comment("Note, `Default` is implemented although default value is not 0");
         }
         w.impl_for_block(
-            "::std::default::Default",
+            "::core::default::Default",
             &format!("{}", self.type_name),
             |w| {
                 w.def_fn("default() -> Self", |w| {
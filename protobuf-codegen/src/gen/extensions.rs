@@ -85,7 +85,7 @@ impl<'a> ExtGen<'a> {
                 self.return_type_gen().rust_type(&self.customize),
             ),
             &format!(
-                "{} {{ field_number: {}, phantom: ::std::marker::PhantomData }}",
+                "{} {{ field_number: {}, phantom: ::core::marker::PhantomData }}",
                 field_type,
                 self.field.number()
             ),
@@ -77,6 +77,7 @@ impl FieldGen<'_> {
             format!("{}::has_{}", message, self.rust_name),
             format!("{}::{}", message, self.rust_name),
             format!("{}::set_{}", message, self.rust_name),
+            format!("{}::{}", message, self.clear_field_func()),
         ]
     }
 
@@ -87,13 +88,19 @@ impl FieldGen<'_> {
             format!("{}::{}", message, self.rust_name),
             format!("{}::mut_{}", message, self.rust_name),
             format!("{}::set_{}", message, self.rust_name),
+            format!("{}::{}", message, self.clear_field_func()),
         ]
     }
 
     fn accessor_fn_map(&self, map_field: &MapField) -> AccessorFn {
         let MapField { .. } = map_field;
+        let name = if self.customize.btree_map.unwrap_or(false) {
+            "make_btree_map_simpler_accessor"
+        } else {
+            "make_map_simpler_accessor"
+        };
         AccessorFn {
-            name: "make_map_simpler_accessor".to_owned(),
+            name: name.to_owned(),
             type_params: vec![format!("_"), format!("_")],
             callback_params: self.make_accessor_fns_lambda(),
         }
@@ -132,10 +139,10 @@ impl FieldGen<'_> {
                 ),
                 |w| {
                     w.case_expr(
-                        &format!("::std::option::Option::Some({}(e))", variant_path),
-                        "::std::option::Option::Some(*e)",
+                        &format!("::core::option::Option::Some({}(e))", variant_path),
+                        "::core::option::Option::Some(*e)",
                     );
-                    w.case_expr("_", "::std::option::Option::None");
+                    w.case_expr("_", "::core::option::Option::None");
                 },
             );
         });
@@ -151,7 +158,7 @@ impl FieldGen<'_> {
                 ),
                 |w| {
                     w.write_line(&format!(
-                        "message.{} = ::std::option::Option::Some({}(e));",
+                        "message.{} = ::core::option::Option::Some({}(e));",
                         oneof.oneof_field_name, variant_path
                     ));
                 },
@@ -163,7 +170,12 @@ impl FieldGen<'_> {
         AccessorFn {
             name: "make_oneof_enum_accessors".to_owned(),
             type_params: vec![format!("_")],
-            callback_params: vec![getter, setter, default],
+            callback_params: vec![
+                getter,
+                setter,
+                format!("{}::{}", message, self.clear_field_func()),
+                default,
+            ],
         }
     }
 
@@ -212,9 +224,6 @@ impl FieldGen<'_> {
                 type_params: vec![format!("{}", en.rust_name_relative(&self.file_and_mod()))],
                 callback_params: self.make_accessor_fns_lambda_default_value(),
             },
-            FieldElem::Group => {
-                unreachable!("no accessor for group field");
-            }
         }
     }
 
@@ -19,8 +19,8 @@ use crate::gen::message::RustTypeMessage;
 use crate::gen::oneof::OneofField;
 use crate::gen::protoc_insertion_point::write_protoc_insertion_point_for_field;
 use crate::gen::rust;
+use crate::gen::rust::expr_vec_new;
 use crate::gen::rust::EXPR_NONE;
-use crate::gen::rust::EXPR_VEC_NEW;
 use crate::gen::rust_name::RustIdent;
 use crate::gen::rust_name::RustIdentWithPath;
 use crate::gen::rust_types_values::*;
@@ -36,6 +36,7 @@ mod accessor;
 fn type_is_copy(field_type: field_descriptor_proto::Type) -> bool {
     match field_type {
         field_descriptor_proto::Type::TYPE_MESSAGE
+        | field_descriptor_proto::Type::TYPE_GROUP
         | field_descriptor_proto::Type::TYPE_STRING
         | field_descriptor_proto::Type::TYPE_BYTES => false,
         _ => true,
@@ -159,7 +160,7 @@ impl OptionKind {
 
     fn wrap_value(&self, value: &str, customize: &Customize) -> String {
         match self {
-            OptionKind::Option => format!("::std::option::Option::Some({})", value),
+            OptionKind::Option => format!("::core::option::Option::Some({})", value),
             OptionKind::MessageField => format!(
                 "{}::MessageField::some({})",
                 protobuf_crate_path(customize),
@@ -230,9 +231,9 @@ impl RepeatedFieldKind {
         }
     }
 
-    fn default(&self) -> String {
+    fn default(&self, customize: &Customize) -> String {
         match self {
-            RepeatedFieldKind::Vec => EXPR_VEC_NEW.to_owned(),
+            RepeatedFieldKind::Vec => expr_vec_new(customize),
         }
     }
 }
@@ -253,16 +254,16 @@ impl<'a> RepeatedField<'a> {
             .wrap_element(self.elem.rust_storage_elem_type(reference))
     }
 
-    fn default(&self) -> String {
-        self.kind().default()
+    fn default(&self, customize: &Customize) -> String {
+        self.kind().default(customize)
     }
 }
 
 #[derive(Clone)]
 pub struct MapField<'a> {
     _message: MessageWithScope<'a>,
-    key: FieldElem<'a>,
-    value: FieldElem<'a>,
+    pub key: FieldElem<'a>,
+    pub value: FieldElem<'a>,
 }
 
 #[derive(Clone)]
@@ -286,7 +287,7 @@ impl<'a> FieldKind<'a> {
     ) -> String {
         match self {
             FieldKind::Singular(s) => s.default_value(customize, reference, const_expr),
-            FieldKind::Repeated(r) => r.default(),
+            FieldKind::Repeated(r) => r.default(customize),
             FieldKind::Oneof(..) => EXPR_NONE.to_owned(),
             FieldKind::Map(..) => panic!("map fields cannot have field value"),
         }
@@ -335,7 +336,7 @@ impl<'a> FieldElemEnum<'a> {
         message_or_enum_to_rust_relative(&self.default_value.en, reference)
     }
 
-    fn enum_rust_type(&self, reference: &FileAndMod) -> RustType {
+    pub(crate) fn enum_rust_type(&self, reference: &FileAndMod) -> RustType {
         RustType::Enum(
             self.rust_name_relative(reference),
             self.default_value.rust_name(),
@@ -343,7 +344,7 @@ impl<'a> FieldElemEnum<'a> {
         )
     }
 
-    fn enum_or_unknown_rust_type(&self, reference: &FileAndMod) -> RustType {
+    pub(crate) fn enum_or_unknown_rust_type(&self, reference: &FileAndMod) -> RustType {
         RustType::EnumOrUnknown(
             self.rust_name_relative(reference),
             self.default_value.rust_name(),
@@ -351,7 +352,7 @@ impl<'a> FieldElemEnum<'a> {
         )
     }
 
-    fn default_value_rust_expr(&self, reference: &FileAndMod) -> RustIdentWithPath {
+    pub(crate) fn default_value_rust_expr(&self, reference: &FileAndMod) -> RustIdentWithPath {
         self.rust_name_relative(reference)
             .to_path()
             .with_ident(self.default_value.rust_name())
@@ -364,7 +365,7 @@ pub(crate) struct FieldElemMessage<'a> {
 }
 
 impl<'a> FieldElemMessage<'a> {
-    fn rust_name_relative(&self, reference: &FileAndMod) -> RustTypeMessage {
+    pub(crate) fn rust_name_relative(&self, reference: &FileAndMod) -> RustTypeMessage {
         RustTypeMessage(message_or_enum_to_rust_relative(&self.message, reference))
     }
 
@@ -378,14 +379,12 @@ pub(crate) enum FieldElem<'a> {
     Primitive(field_descriptor_proto::Type, PrimitiveTypeVariant),
     Message(FieldElemMessage<'a>),
     Enum(FieldElemEnum<'a>),
-    Group,
 }
 
 impl<'a> FieldElem<'a> {
     fn proto_type(&self) -> field_descriptor_proto::Type {
         match *self {
             FieldElem::Primitive(t, ..) => t,
-            FieldElem::Group => field_descriptor_proto::Type::TYPE_GROUP,
             FieldElem::Message(..) => field_descriptor_proto::Type::TYPE_MESSAGE,
             FieldElem::Enum(..) => field_descriptor_proto::Type::TYPE_ENUM,
         }
@@ -407,7 +406,6 @@ impl<'a> FieldElem<'a> {
                 PrimitiveTypeVariant::TokioBytes,
             ) => RustType::Bytes,
             FieldElem::Primitive(.., PrimitiveTypeVariant::TokioBytes) => unreachable!(),
-            FieldElem::Group => RustType::Group,
             FieldElem::Message(ref m) => m.rust_type(reference),
             FieldElem::Enum(ref en) => en.enum_or_unknown_rust_type(reference),
         }
@@ -422,19 +420,18 @@ impl<'a> FieldElem<'a> {
         }
     }
 
-    fn protobuf_type_gen(&self, reference: &FileAndMod) -> ProtobufTypeGen {
+    pub(crate) fn protobuf_type_gen(&self, reference: &FileAndMod) -> ProtobufTypeGen {
         match *self {
             FieldElem::Primitive(t, v) => ProtobufTypeGen::Primitive(t, v),
             FieldElem::Message(ref m) => ProtobufTypeGen::Message(m.rust_name_relative(reference)),
             FieldElem::Enum(ref en) => {
                 ProtobufTypeGen::EnumOrUnknown(en.rust_name_relative(reference))
             }
-            FieldElem::Group => unreachable!(),
         }
     }
 
     /// implementation of ProtobufType trait
-    fn lib_protobuf_type(&self, reference: &FileAndMod) -> String {
+    pub(crate) fn lib_protobuf_type(&self, reference: &FileAndMod) -> String {
         self.protobuf_type_gen(reference)
             .rust_type(&reference.customize)
     }
@@ -456,14 +453,13 @@ fn field_elem<'a>(
         unreachable!();
     }
 
-    if field.field.proto().field_type() == field_descriptor_proto::Type::TYPE_GROUP {
-        FieldElem::Group
-    } else if field.field.proto().has_type_name() {
+    if field.field.proto().has_type_name() {
         let message_or_enum = root_scope
             .find_message_or_enum(&ProtobufAbsPath::from(field.field.proto().type_name()));
         match (field.field.proto().field_type(), message_or_enum) {
             (
-                field_descriptor_proto::Type::TYPE_MESSAGE,
+                field_descriptor_proto::Type::TYPE_MESSAGE
+                | field_descriptor_proto::Type::TYPE_GROUP,
                 MessageOrEnumWithScope::Message(message),
             ) => FieldElem::Message(FieldElemMessage {
                 message: message.clone(),
@@ -520,8 +516,8 @@ pub(crate) struct FieldGen<'a> {
     pub generate_accessors: bool,
     pub generate_getter: bool,
     customize: Customize,
-    path: Vec<i32>,
-    info: Option<&'a SourceCodeInfo>,
+    pub path: Vec<i32>,
+    pub info: Option<&'a SourceCodeInfo>,
 }
 
 impl<'a> FieldGen<'a> {
@@ -543,12 +539,15 @@ impl<'a> FieldGen<'a> {
 
         let field_may_have_custom_default_value = syntax == Syntax::Proto2
             && field.field.proto().label() != field_descriptor_proto::Label::LABEL_REPEATED
-            && field.field.proto().field_type() != field_descriptor_proto::Type::TYPE_MESSAGE;
+            && field.field.proto().field_type() != field_descriptor_proto::Type::TYPE_MESSAGE
+            && field.field.proto().field_type() != field_descriptor_proto::Type::TYPE_GROUP;
 
         let default_expose_field = !field_may_have_custom_default_value;
         let expose_field = customize.expose_fields.unwrap_or(default_expose_field);
 
-        let default_generate_accessors = !expose_field;
+        // Proto3 optional fields have explicit presence, so generate
+        // `has_` and `clear_` accessors for them.
+        let default_generate_accessors = !expose_field || field.field.is_proto3_optional();
         let generate_accessors = customize
             .generate_accessors
             .unwrap_or(default_generate_accessors)
@@ -591,13 +590,15 @@ impl<'a> FieldGen<'a> {
                     let flag = if field.message.scope.file_scope.syntax() == Syntax::Proto3
                         && field.field.proto().field_type()
                             != field_descriptor_proto::Type::TYPE_MESSAGE
+                        && !field.field.is_proto3_optional()
                     {
                         SingularFieldFlag::WithoutFlag
                     } else {
                         let required = field.field.proto().label()
                             == field_descriptor_proto::Label::LABEL_REQUIRED;
                         let option_kind = match field.field.proto().field_type() {
-                            field_descriptor_proto::Type::TYPE_MESSAGE => OptionKind::MessageField,
+                            field_descriptor_proto::Type::TYPE_MESSAGE
+                            | field_descriptor_proto::Type::TYPE_GROUP => OptionKind::MessageField,
                             _ => OptionKind::Option,
                         };
 
@@ -629,7 +630,7 @@ impl<'a> FieldGen<'a> {
     }
 
     // for message level
-    fn file_and_mod(&self) -> FileAndMod {
+    pub(crate) fn file_and_mod(&self) -> FileAndMod {
         self.proto_field
             .message
             .scope
@@ -684,10 +685,15 @@ impl<'a> FieldGen<'a> {
             FieldKind::Repeated(ref repeated) => repeated.rust_type(reference),
             FieldKind::Map(MapField {
                 ref key, ref value, ..
-            }) => RustType::HashMap(
-                Box::new(key.rust_storage_elem_type(reference)),
-                Box::new(value.rust_storage_elem_type(reference)),
-            ),
+            }) => {
+                let key = Box::new(key.rust_storage_elem_type(reference));
+                let value = Box::new(value.rust_storage_elem_type(reference));
+                if self.customize.btree_map.unwrap_or(false) {
+                    RustType::BTreeMap(key, value)
+                } else {
+                    RustType::HashMap(key, value)
+                }
+            }
             FieldKind::Singular(ref singular) => singular.rust_storage_type(reference),
             FieldKind::Oneof(..) => unreachable!(),
         }
@@ -804,12 +810,12 @@ impl<'a> FieldGen<'a> {
 
     fn defaut_value_from_proto_float(f: f64, type_name: &str) -> String {
         if f.is_nan() {
-            format!("::std::{}::NAN", type_name)
+            format!("::core::{}::NAN", type_name)
         } else if f.is_infinite() {
             if f > 0.0 {
-                format!("::std::{}::INFINITY", type_name)
+                format!("::core::{}::INFINITY", type_name)
             } else {
-                format!("::std::{}::NEG_INFINITY", type_name)
+                format!("::core::{}::NEG_INFINITY", type_name)
             }
         } else {
             format!("{:?}{}", f, type_name)
@@ -843,7 +849,7 @@ impl<'a> FieldGen<'a> {
         })
     }
 
-    fn default_value_from_proto(&self) -> Option<String> {
+    pub(crate) fn default_value_from_proto(&self) -> Option<String> {
         match self.kind {
             FieldKind::Oneof(OneofField { ref elem, .. })
             | FieldKind::Singular(SingularField { ref elem, .. }) => {
@@ -912,7 +918,7 @@ impl<'a> FieldGen<'a> {
     pub fn reconstruct_def(&self) -> String {
         let prefix = match (self.proto_field.field.proto().label(), self.syntax) {
             (field_descriptor_proto::Label::LABEL_REPEATED, _) => "repeated ",
-            (_, Syntax::Proto3) => "",
+            (_, Syntax::Proto3) if !self.proto_field.field.is_proto3_optional() => "",
             (field_descriptor_proto::Label::LABEL_OPTIONAL, _) => "optional ",
             (field_descriptor_proto::Label::LABEL_REQUIRED, _) => "required ",
         };
@@ -929,7 +935,7 @@ impl<'a> FieldGen<'a> {
         match self.kind {
             FieldKind::Oneof(ref o) => {
                 w.write_line(&format!(
-                    "self.{} = ::std::option::Option::None;",
+                    "self.{} = ::core::option::Option::None;",
                     o.oneof_field_name
                 ));
             }
@@ -955,7 +961,8 @@ impl<'a> FieldGen<'a> {
         match field_type_size(self.proto_type) {
             Some(data_size) => format!("{}", data_size + self.tag_size()),
             None => match self.proto_type {
-                field_descriptor_proto::Type::TYPE_MESSAGE => panic!("not a single-liner"),
+                field_descriptor_proto::Type::TYPE_MESSAGE
+                | field_descriptor_proto::Type::TYPE_GROUP => panic!("not a single-liner"),
                 // We are not inlining `bytes_size` here,
                 // assuming the compiler is smart enough to do it for us.
                 // https://rust.godbolt.org/z/GrKa5zxq6
@@ -1017,7 +1024,14 @@ impl<'a> FieldGen<'a> {
         };
 
         match self.proto_type {
-            field_descriptor_proto::Type::TYPE_MESSAGE => {
+            field_descriptor_proto::Type::TYPE_MESSAGE
+            | field_descriptor_proto::Type::TYPE_GROUP => {
+                let write_fn = match self.proto_type {
+                    field_descriptor_proto::Type::TYPE_GROUP => {
+                        "write_group_field_with_cached_size"
+                    }
+                    _ => "write_message_field_with_cached_size",
+                };
                 let param_type = RustType::Ref(Box::new(
                     self.elem().rust_storage_elem_type(
                         &self
@@ -1029,8 +1043,9 @@ impl<'a> FieldGen<'a> {
                 ));
 
                 w.write_line(&format!(
-                    "{}::rt::write_message_field_with_cached_size({}, {}, {})?;",
+                    "{}::rt::{}({}, {}, {})?;",
                     protobuf_crate_path(&self.customize),
+                    write_fn,
                     self.proto_field.number(),
                     v.into_type(param_type, &self.customize).value,
                     os
@@ -1051,6 +1066,45 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // output code that writes single element to `ReverseOutputStream`
+    pub fn write_write_element_reverse(&self, w: &mut CodeWriter, os: &str, v: &RustValueTyped) {
+        if let FieldKind::Repeated(RepeatedField { packed: true, .. }) = self.kind {
+            unreachable!();
+        };
+
+        match self.proto_type {
+            field_descriptor_proto::Type::TYPE_MESSAGE
+            | field_descriptor_proto::Type::TYPE_GROUP => {
+                let write_fn = match self.proto_type {
+                    field_descriptor_proto::Type::TYPE_GROUP => "write_group_field_reverse",
+                    _ => "write_message_field_reverse",
+                };
+                let param_type = RustType::Ref(Box::new(
+                    self.elem().rust_storage_elem_type(&self.file_and_mod()),
+                ));
+
+                w.write_line(&format!(
+                    "{}::rt::{}({}, {}, {})?;",
+                    protobuf_crate_path(&self.customize),
+                    write_fn,
+                    self.proto_field.number(),
+                    v.into_type(param_type, &self.customize).value,
+                    os
+                ));
+            }
+            _ => {
+                let param_type = self.os_write_fn_param_type();
+                w.write_line(&format!(
+                    "{}.write_{}({}, {});",
+                    os,
+                    self.os_write_fn_suffix(),
+                    self.proto_field.number(),
+                    v.into_type(param_type, &self.customize).value
+                ));
+            }
+        }
+    }
+
     fn self_field(&self) -> String {
         format!("self.{}", self.rust_name)
     }
@@ -1123,27 +1177,23 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn write_struct_field(&self, w: &mut CodeWriter) {
-        if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP {
-            w.comment(&format!("{}: <group>", &self.rust_name));
-        } else {
-            w.all_documentation(self.info, &self.path);
+        w.all_documentation(self.info, &self.path);
 
-            write_protoc_insertion_point_for_field(w, &self.customize, &self.proto_field.field);
-            let vis = self.visibility();
-            w.field_decl_vis(
-                vis,
-                self.rust_name.get(),
-                &self
-                    .full_storage_type(
-                        &self
-                            .proto_field
-                            .message
-                            .scope
-                            .file_and_mod(self.customize.clone()),
-                    )
-                    .to_code(&self.customize),
-            );
-        }
+        write_protoc_insertion_point_for_field(w, &self.customize, &self.proto_field.field);
+        let vis = self.visibility();
+        w.field_decl_vis(
+            vis,
+            self.rust_name.get(),
+            &self
+                .full_storage_type(
+                    &self
+                        .proto_field
+                        .message
+                        .scope
+                        .file_and_mod(self.customize.clone()),
+                )
+                .to_code(&self.customize),
+        );
     }
 
     fn write_if_let_self_field_is_some<F>(&self, s: &SingularField, w: &mut CodeWriter, cb: F)
@@ -1252,6 +1302,149 @@ impl<'a> FieldGen<'a> {
         w.if_stmt(self_field_is_none, cb)
     }
 
+    /// Merge this field of `other` into `self`; oneof fields are merged by message.
+    pub(crate) fn write_merge_from_message(&self, w: &mut CodeWriter) {
+        let self_field = self.self_field();
+        let other_field = format!("other.{}", self.rust_name);
+        match &self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { option_kind, .. },
+                elem: FieldElem::Message(..),
+            }) => {
+                let mut_or_default = match option_kind {
+                    OptionKind::MessageField => format!("{}.mut_or_default()", self_field),
+                    OptionKind::Option => format!(
+                        "{}.get_or_insert_with(::core::default::Default::default)",
+                        self_field
+                    ),
+                };
+                w.if_let_stmt("Some(v)", &format!("{}.as_ref()", other_field), |w| {
+                    w.write_line(&format!("{}.merge_from_message(v);", mut_or_default));
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => {
+                w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                    w.write_line(&format!("{}.clone_from(&{});", self_field, other_field));
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                elem,
+            }) => {
+                let cond = match elem {
+                    FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
+                    | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
+                        format!("!{}.is_empty()", other_field)
+                    }
+                    _ => format!(
+                        "{} != {}",
+                        other_field,
+                        self.full_storage_type(&self.file_and_mod())
+                            .default_value(&self.customize, false)
+                    ),
+                };
+                w.if_stmt(cond, |w| {
+                    w.write_line(&format!("{}.clone_from(&{});", self_field, other_field));
+                });
+            }
+            FieldKind::Repeated(..) => {
+                w.write_line(&format!(
+                    "{}.extend({}.iter().cloned());",
+                    self_field, other_field
+                ));
+            }
+            FieldKind::Map(..) => {
+                w.write_line(&format!(
+                    "{}.extend({}.iter().map(|(k, v)| (k.clone(), v.clone())));",
+                    self_field, other_field
+                ));
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    /// Iterate over message values of a map field.
+    pub(crate) fn write_for_self_map_message_values<F>(&self, w: &mut CodeWriter, cb: F)
+    where
+        F: Fn(&mut CodeWriter),
+    {
+        match &self.kind {
+            FieldKind::Map(MapField {
+                value: FieldElem::Message(..),
+                ..
+            }) => {
+                w.for_stmt(&format!("{}.values()", self.self_field()), "v", cb);
+            }
+            FieldKind::Map(..) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    pub(crate) fn write_collect_missing_required_fields(&self, w: &mut CodeWriter) {
+        let rt = format!("{}::rt", protobuf_crate_path(&self.customize));
+        let name = self.proto_field.name();
+        let collect =
+            |path: String| format!("v.collect_missing_required_fields(&{}, missing);", path);
+        match &self.kind {
+            FieldKind::Singular(SingularField { flag, .. }) if flag.is_required() => {
+                self.write_if_self_field_is_none(w, |w| {
+                    w.write_line(&format!(
+                        "{}::push_missing_required_field(missing, prefix, \"{}\");",
+                        rt, name
+                    ));
+                });
+            }
+            _ => {}
+        }
+        match &self.kind {
+            FieldKind::Singular(SingularField {
+                elem: FieldElem::Message(..),
+                ..
+            })
+            | FieldKind::Oneof(OneofField {
+                elem: FieldElem::Message(..),
+                ..
+            }) => {
+                self.write_for_self_field(w, "v", |w, _| {
+                    w.write_line(&collect(format!(
+                        "{}::nested_field_path(prefix, \"{}\")",
+                        rt, name
+                    )));
+                });
+            }
+            FieldKind::Repeated(RepeatedField {
+                elem: FieldElem::Message(..),
+                ..
+            }) => {
+                w.for_stmt(
+                    &format!("{}.iter().enumerate()", self.self_field()),
+                    "(i, v)",
+                    |w| {
+                        w.write_line(&collect(format!(
+                            "{}::nested_repeated_field_path(prefix, \"{}\", i)",
+                            rt, name
+                        )));
+                    },
+                );
+            }
+            FieldKind::Map(MapField {
+                value: FieldElem::Message(..),
+                ..
+            }) => {
+                w.for_stmt(&format!("&{}", self.self_field()), "(k, v)", |w| {
+                    w.write_line(&collect(format!(
+                        "{}::nested_map_field_path(prefix, \"{}\", k)",
+                        rt, name
+                    )));
+                });
+            }
+            _ => {}
+        }
+    }
+
     // repeated or singular
     pub fn write_for_self_field<F>(&self, w: &mut CodeWriter, varn: &str, cb: F)
     where
@@ -1363,7 +1556,7 @@ impl<'a> FieldGen<'a> {
         match field_kind {
             SingularOrOneofField::Oneof(oneof) => {
                 w.write_line(format!(
-                    "self.{} = ::std::option::Option::Some({}({}))",
+                    "self.{} = ::core::option::Option::Some({}({}))",
                     oneof.oneof_field_name,
                     oneof.variant_path(
                         &self
@@ -1471,8 +1664,27 @@ impl<'a> FieldGen<'a> {
         r: &RepeatedField,
         w: &mut CodeWriter,
     ) {
+        if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP {
+            w.write_line(&format!(
+                "self.{}.push({}::rt::read_group_no_check({}, is)?);",
+                self.rust_name,
+                protobuf_crate_path(&self.customize),
+                self.proto_field.number(),
+            ));
+            self.write_check_repeated_len(w);
+            return;
+        }
+        if let FieldElem::Message(..) = &r.elem {
+            // Required fields are checked once for the whole message tree.
+            w.write_line(&format!(
+                "self.{}.push({}::rt::read_message_no_check(is)?);",
+                self.rust_name,
+                protobuf_crate_path(&self.customize),
+            ));
+            self.write_check_repeated_len(w);
+            return;
+        }
         let read_fn = match &r.elem {
-            FieldElem::Message(..) => "read_message",
             FieldElem::Primitive(Type::TYPE_STRING, PrimitiveTypeVariant::Default) => "read_string",
             FieldElem::Primitive(Type::TYPE_STRING, PrimitiveTypeVariant::TokioBytes) => {
                 "read_tokio_chars"
@@ -1484,9 +1696,18 @@ impl<'a> FieldGen<'a> {
             _ => unreachable!("for field {}", self.proto_field.field),
         };
         w.write_line(&format!("self.{}.push(is.{}()?);", self.rust_name, read_fn,));
+        self.write_check_repeated_len(w);
+    }
+
+    fn write_check_repeated_len(&self, w: &mut CodeWriter) {
+        w.write_line(&format!(
+            "{}::rt::check_repeated_len(is, self.{}.len())?;",
+            protobuf_crate_path(&self.customize),
+            self.rust_name,
+        ));
     }
 
-    fn tag_with_wire_type(&self, wire_type: WireType) -> u32 {
+    pub(crate) fn tag_with_wire_type(&self, wire_type: WireType) -> u32 {
         (self.proto_field.number() << 3) + (wire_type as u32)
     }
 
@@ -1497,11 +1718,20 @@ impl<'a> FieldGen<'a> {
     // Write `merge_from` part for this oneof field
     fn write_merge_from_oneof_case_block(&self, o: &OneofField, w: &mut CodeWriter) {
         w.case_block(&format!("{}", self.tag()), |w| {
-            let typed = RustValueTyped {
-                value: format!(
-                    "{}?",
-                    self.proto_type.read("is", o.elem.primitive_type_variant())
+            let read = match self.proto_type {
+                field_descriptor_proto::Type::TYPE_GROUP => format!(
+                    "{}::rt::read_group_no_check({}, is)",
+                    protobuf_crate_path(&self.customize),
+                    self.proto_field.number()
                 ),
+                field_descriptor_proto::Type::TYPE_MESSAGE => format!(
+                    "{}::rt::read_message_no_check(is)",
+                    protobuf_crate_path(&self.customize)
+                ),
+                t => t.read("is", o.elem.primitive_type_variant()),
+            };
+            let typed = RustValueTyped {
+                value: format!("{}?", read),
                 rust_type: self.full_storage_iter_elem_type(
                     &self
                         .proto_field
@@ -1518,7 +1748,7 @@ impl<'a> FieldGen<'a> {
             };
 
             w.write_line(&format!(
-                "self.{} = ::std::option::Option::Some({}({}));",
+                "self.{} = ::core::option::Option::Some({}({}));",
                 o.oneof_field_name,
                 o.variant_path(
                     &self
@@ -1553,6 +1783,16 @@ impl<'a> FieldGen<'a> {
     // Write `merge_from` part for this singular field
     fn write_merge_from_singular_case_block(&self, s: &SingularField, w: &mut CodeWriter) {
         w.case_block(&format!("{}", self.tag()), |w| match s.elem {
+            FieldElem::Message(..)
+                if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP =>
+            {
+                w.write_line(&format!(
+                    "{}::rt::read_singular_group_into_field({}, is, &mut self.{})?;",
+                    protobuf_crate_path(&self.customize),
+                    self.proto_field.number(),
+                    self.rust_name,
+                ));
+            }
             FieldElem::Message(..) => {
                 w.write_line(&format!(
                     "{}::rt::read_singular_message_into_field(is, &mut self.{})?;",
@@ -1593,6 +1833,7 @@ impl<'a> FieldGen<'a> {
                             "self.{}.push(is.read_enum_or_unknown()?);",
                             self.rust_name,
                         ));
+                        self.write_check_repeated_len(w);
                     },
                 );
                 w.case_block(
@@ -1624,6 +1865,7 @@ impl<'a> FieldGen<'a> {
                         self.rust_name,
                         protobuf_name(self.proto_type),
                     ));
+                    self.write_check_repeated_len(w);
                 });
             }
         }
@@ -1675,6 +1917,11 @@ impl<'a> FieldGen<'a> {
                     protobuf_crate_path(&self.customize),
                 ));
             }
+            field_descriptor_proto::Type::TYPE_GROUP => {
+                w.write_line(&format!("let len = {}.compute_size();", item_var));
+                let tag_size = self.tag_size();
+                w.write_line(&format!("{} += {} + len;", sum_var, tag_size * 2));
+            }
             _ => {
                 w.write_line(&format!(
                     "{} += {};",
@@ -1725,6 +1972,48 @@ impl<'a> FieldGen<'a> {
         };
     }
 
+    pub fn write_message_write_field_reverse(&self, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(ref s) => {
+                self.write_if_let_self_field_is_some(s, w, |v, w| {
+                    self.write_write_element_reverse(w, "os", &v);
+                });
+            }
+            FieldKind::Repeated(RepeatedField { packed: false, .. }) => {
+                let v_type = self.full_storage_iter_elem_type(&self.file_and_mod());
+                w.for_stmt(&format!("{}.iter().rev()", self.self_field()), "v", |w| {
+                    let v = RustValueTyped {
+                        value: "v".to_owned(),
+                        rust_type: v_type.clone(),
+                    };
+                    self.write_write_element_reverse(w, "os", &v);
+                });
+            }
+            FieldKind::Repeated(RepeatedField { packed: true, .. }) => {
+                w.write_line(&format!(
+                    "{}::rt::write_repeated_packed_reverse::<{}>({}, &{}, os)?;",
+                    protobuf_crate_path(&self.customize),
+                    self.elem().lib_protobuf_type(&self.file_and_mod()),
+                    self.proto_field.number(),
+                    self.self_field()
+                ));
+            }
+            FieldKind::Map(MapField {
+                ref key, ref value, ..
+            }) => {
+                w.write_line(&format!(
+                    "{}::rt::write_map_reverse::<{}, {}>({}, &{}, os)?;",
+                    protobuf_crate_path(&self.customize),
+                    key.lib_protobuf_type(&self.file_and_mod()),
+                    value.lib_protobuf_type(&self.file_and_mod()),
+                    self.proto_field.number(),
+                    self.self_field()
+                ));
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        };
+    }
+
     pub fn write_message_compute_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
         match self.kind {
             FieldKind::Singular(ref s) => {
@@ -1922,7 +2211,7 @@ impl<'a> FieldGen<'a> {
             };
             w.case_expr(
                 format!(
-                    "::std::option::Option::Some({}({}))",
+                    "::core::option::Option::Some({}({}))",
                     o.variant_path(
                         &self
                             .proto_field
@@ -1962,7 +2251,7 @@ impl<'a> FieldGen<'a> {
         });
     }
 
-    fn has_has(&self) -> bool {
+    pub(crate) fn has_has(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) | FieldKind::Map(..) => false,
             FieldKind::Singular(SingularField {
@@ -2005,7 +2294,7 @@ impl<'a> FieldGen<'a> {
                     w.match_expr(&format!("self.{}", oneof.oneof_field_name), |w| {
                         w.case_expr(
                             format!(
-                                "::std::option::Option::Some({}(..))",
+                                "::core::option::Option::Some({}(..))",
                                 oneof.variant_path(
                                     &self
                                         .proto_field
@@ -2064,7 +2353,7 @@ impl<'a> FieldGen<'a> {
                             &self.customize,
                         );
                         w.write_line(&format!(
-                            "self.{} = ::std::option::Option::Some({}({}))",
+                            "self.{} = ::core::option::Option::Some({}({}))",
                             oneof.oneof_field_name,
                             oneof.variant_path(
                                 &self
@@ -2146,7 +2435,7 @@ impl<'a> FieldGen<'a> {
                     // if oneof does not contain current field
                     w.if_let_else_stmt(
                         &format!(
-                            "::std::option::Option::Some({}(_))",
+                            "::core::option::Option::Some({}(_))",
                             o.variant_path(
                                 &self
                                     .proto_field
@@ -2161,7 +2450,7 @@ impl<'a> FieldGen<'a> {
                         |w| {
                             // initialize it with default value
                             w.write_line(&format!(
-                                "{} = ::std::option::Option::Some({}({}));",
+                                "{} = ::core::option::Option::Some({}({}));",
                                 self_field_oneof,
                                 o.variant_path(
                                     &self
@@ -2192,7 +2481,7 @@ impl<'a> FieldGen<'a> {
                     w.match_expr(self_field_oneof, |w| {
                         w.case_expr(
                             format!(
-                                "::std::option::Option::Some({}(ref mut v))",
+                                "::core::option::Option::Some({}(ref mut v))",
                                 o.variant_path(
                                     &self
                                         .proto_field
@@ -2247,7 +2536,7 @@ impl<'a> FieldGen<'a> {
                 );
                 w.case_expr(
                     format!(
-                        "::std::option::Option::Some({}(v))",
+                        "::core::option::Option::Some({}(v))",
                         o.variant_path(
                             &self
                                 .proto_field
@@ -2315,7 +2604,7 @@ impl<'a> FieldGen<'a> {
                 flag: SingularFieldFlag::WithoutFlag,
                 ..
             } => w.write_line(&format!(
-                "::std::mem::replace(&mut {}, {})",
+                "::core::mem::replace(&mut {}, {})",
                 self.self_field(),
                 self.full_storage_type(
                     &self
@@ -2349,7 +2638,7 @@ impl<'a> FieldGen<'a> {
                 FieldKind::Oneof(ref o) => self.write_message_field_take_oneof(o, w),
                 FieldKind::Repeated(..) | FieldKind::Map(..) => {
                     w.write_line(&format!(
-                        "::std::mem::replace(&mut self.{}, {})",
+                        "::core::mem::replace(&mut self.{}, {})",
                         self.rust_name,
                         take_xxx_return_type.default_value(&self.customize, false)
                     ));
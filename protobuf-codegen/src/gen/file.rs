@@ -18,6 +18,7 @@ use crate::gen::message::MessageGen;
 use crate::gen::paths::proto_path_to_rust_mod;
 use crate::gen::scope::FileScope;
 use crate::gen::scope::RootScope;
+use crate::gen::service::write_services;
 use crate::proto_name_to_rs;
 
 pub(crate) struct GenFileResult {
@@ -125,6 +126,8 @@ pub(crate) fn gen_file(
 
         write_extensions(file_descriptor, &root_scope, w, &customize);
 
+        write_services(file_descriptor, &root_scope, &customize, w);
+
         if !lite_runtime {
             w.write_line("");
             write_file_descriptor_data(file_descriptor, &customize.for_elem, w);
@@ -6,7 +6,7 @@ use protobuf::Message;
 use crate::gen::code_writer::CodeWriter;
 use crate::gen::inside::protobuf_crate_path;
 use crate::gen::paths::proto_path_to_fn_file_descriptor;
-use crate::gen::rust::EXPR_VEC_NEW;
+use crate::gen::rust::expr_vec_new;
 use crate::gen::scope::FileScope;
 use crate::gen::scope::WithScope;
 use crate::Customize;
@@ -54,7 +54,7 @@ fn write_file_descriptor(
                 "let file_descriptor = file_descriptor_lazy.get(|| {",
                 "});",
                 |w| {
-                    w.write_line(&format!("let mut deps = {};", EXPR_VEC_NEW));
+                    w.write_line(&format!("let mut deps = {};", expr_vec_new(customize)));
                     for f in &file_descriptor.proto().dependency {
                         w.write_line(&format!(
                             "deps.push({}());",
@@ -64,7 +64,7 @@ fn write_file_descriptor(
 
                     let scope = FileScope { file_descriptor };
 
-                    w.write_line(&format!("let mut messages = {};", EXPR_VEC_NEW));
+                    w.write_line(&format!("let mut messages = {};", expr_vec_new(customize)));
                     for m in scope.find_messages_except_map() {
                         if m.is_map() {
                             continue;
@@ -75,7 +75,7 @@ fn write_file_descriptor(
                         ));
                     }
 
-                    w.write_line(&format!("let mut enums = {};", EXPR_VEC_NEW));
+                    w.write_line(&format!("let mut enums = {};", expr_vec_new(customize)));
                     for e in scope.find_enums() {
                         w.write_line(&format!(
                             "enums.push({}::generated_enum_descriptor_data());",
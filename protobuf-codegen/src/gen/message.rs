@@ -10,6 +10,7 @@ use crate::customize::ctx::SpecialFieldPseudoDescriptor;
 use crate::customize::rustproto_proto::customize_from_rustproto_for_message;
 use crate::gen::code_writer::*;
 use crate::gen::enums::*;
+use crate::gen::field::FieldElem;
 use crate::gen::field::FieldGen;
 use crate::gen::field::FieldKind;
 use crate::gen::file_and_mod::FileAndMod;
@@ -28,6 +29,9 @@ use crate::gen::rust_types_values::*;
 use crate::gen::scope::MessageWithScope;
 use crate::gen::scope::RootScope;
 use crate::gen::scope::WithScope;
+use crate::gen::table::message_supports_table;
+use crate::gen::table::write_message_table;
+use crate::gen::view::write_message_view;
 use crate::Customize;
 
 /// Protobuf message Rust type name
@@ -143,15 +147,48 @@ impl<'a> MessageGen<'a> {
             .file_and_mod(self.customize.for_elem.clone())
     }
 
+    fn gen_views(&self) -> bool {
+        self.customize.for_elem.gen_views.unwrap_or(false)
+    }
+
+    fn gen_cached_size(&self) -> bool {
+        self.customize.for_elem.gen_cached_size.unwrap_or(true)
+    }
+
+    fn table_driven(&self) -> bool {
+        self.customize.for_elem.table_driven.unwrap_or(false) && message_supports_table(self)
+    }
+
     fn expose_oneof(&self) -> bool {
         self.customize.for_elem.expose_oneof.unwrap_or(true)
     }
 
     fn oneofs(&'a self) -> Vec<OneofGen<'a>> {
+        static ONEOF_DECL_NUMBER: protobuf::rt::Lazy<i32> = protobuf::rt::Lazy::new();
+        let oneof_decl_number = *ONEOF_DECL_NUMBER.get(|| {
+            protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto>()
+                .field_by_name("oneof_decl")
+                .expect("`oneof_decl` must exist")
+                .proto()
+                .number()
+        });
+
         self.message
             .oneofs()
             .into_iter()
-            .map(|oneof| OneofGen::parse(self, oneof, &self.customize))
+            .map(|oneof| {
+                // synthetic oneofs are skipped, so find the index by name
+                let id = self
+                    .message_descriptor
+                    .proto()
+                    .oneof_decl
+                    .iter()
+                    .position(|o| o.name() == oneof.oneof.name())
+                    .expect("oneof not found");
+                let mut path = self.path.to_vec();
+                path.extend_from_slice(&[oneof_decl_number, id as i32]);
+                OneofGen::parse(self, oneof, &self.customize, path, self.info)
+            })
             .collect()
     }
 
@@ -168,33 +205,30 @@ impl<'a> MessageGen<'a> {
     fn message_fields(&'a self) -> Vec<&'a FieldGen> {
         self.fields
             .iter()
-            .filter(|f| f.proto_type == field_descriptor_proto::Type::TYPE_MESSAGE)
+            .filter(|f| {
+                f.proto_type == field_descriptor_proto::Type::TYPE_MESSAGE
+                    || f.proto_type == field_descriptor_proto::Type::TYPE_GROUP
+            })
             .collect()
     }
 
-    fn fields_except_oneof(&'a self) -> Vec<&'a FieldGen> {
-        self.fields
-            .iter()
-            .filter(|f| match f.kind {
-                FieldKind::Oneof(..) => false,
+    /// Fields which contain messages, i. e. message fields and maps with message values.
+    fn fields_with_messages(&'a self) -> Vec<&'a FieldGen> {
+        self.message_fields()
+            .into_iter()
+            .filter(|f| match &f.kind {
+                FieldKind::Map(m) => matches!(m.value, FieldElem::Message(..)),
                 _ => true,
             })
             .collect()
     }
 
-    fn fields_except_group(&'a self) -> Vec<&'a FieldGen> {
-        self.fields
-            .iter()
-            .filter(|f| f.proto_type != field_descriptor_proto::Type::TYPE_GROUP)
-            .collect()
-    }
-
-    fn fields_except_oneof_and_group(&'a self) -> Vec<&'a FieldGen> {
+    fn fields_except_oneof(&'a self) -> Vec<&'a FieldGen> {
         self.fields
             .iter()
             .filter(|f| match f.kind {
                 FieldKind::Oneof(..) => false,
-                _ => f.proto_type != field_descriptor_proto::Type::TYPE_GROUP,
+                _ => true,
             })
             .collect()
     }
@@ -203,15 +237,22 @@ impl<'a> MessageGen<'a> {
     where
         F: Fn(&mut CodeWriter, &OneofVariantGen, &str, &RustType),
     {
-        for oneof in self.oneofs() {
-            let variants = oneof.variants_except_group();
+        self.write_match_oneof_variants(self.oneofs(), w, cb)
+    }
+
+    fn write_match_oneof_variants<F>(&self, oneofs: Vec<OneofGen>, w: &mut CodeWriter, cb: F)
+    where
+        F: Fn(&mut CodeWriter, &OneofVariantGen, &str, &RustType),
+    {
+        for oneof in oneofs {
+            let variants = oneof.variants();
             if variants.is_empty() {
                 // Special case because
                 // https://github.com/rust-lang/rust/issues/50642
                 continue;
             }
             w.if_let_stmt(
-                "::std::option::Option::Some(ref v)",
+                "::core::option::Option::Some(ref v)",
                 &format!("self.{}", oneof.oneof.field_name())[..],
                 |w| {
                     w.match_block("v", |w| {
@@ -243,8 +284,24 @@ impl<'a> MessageGen<'a> {
             protobuf_crate_path(&self.customize.for_elem),
         );
         w.def_fn(&sig, |w| {
+            if !self.gen_cached_size() {
+                // Without cached sizes writing forward is quadratic in nesting depth.
+                w.write_line(format!(
+                    "{}::rt::write_to_with_reverse(self, os)",
+                    protobuf_crate_path(&self.customize.for_elem),
+                ));
+                return;
+            }
+            if self.table_driven() {
+                w.write_line(&format!(
+                    "{}::rt::table::write_to_with_cached_sizes(self, os, {}::generated_message_table())",
+                    protobuf_crate_path(&self.customize.for_elem),
+                    self.type_name,
+                ));
+                return;
+            }
             // To have access to its methods but not polute the name space.
-            for f in self.fields_except_oneof_and_group() {
+            for f in self.fields_except_oneof() {
                 f.write_message_write_field(w);
             }
             self.write_match_each_oneof_variant(w, |w, variant, v, v_type| {
@@ -255,13 +312,53 @@ impl<'a> MessageGen<'a> {
                 variant.field.write_write_element(w, "os", &v);
             });
             w.write_line("os.write_unknown_fields(self.unknown_fields())?;");
-            w.write_line("::std::result::Result::Ok(())");
+            w.write_line("::core::result::Result::Ok(())");
+        });
+    }
+
+    fn write_write_reverse(&self, w: &mut CodeWriter) {
+        let sig = format!(
+            "write_reverse(&self, os: &mut {}::ReverseOutputStream) -> {}::Result<()>",
+            protobuf_crate_path(&self.customize.for_elem),
+            protobuf_crate_path(&self.customize.for_elem),
+        );
+        w.def_fn(&sig, |w| {
+            if self.table_driven() {
+                w.write_line(&format!(
+                    "{}::rt::table::write_reverse(self, os, {}::generated_message_table())",
+                    protobuf_crate_path(&self.customize.for_elem),
+                    self.type_name,
+                ));
+                return;
+            }
+            // Fields are written last to first.
+            w.write_line(&format!(
+                "{}::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;",
+                protobuf_crate_path(&self.customize.for_elem)
+            ));
+            let mut oneofs = self.oneofs();
+            oneofs.reverse();
+            self.write_match_oneof_variants(oneofs, w, |w, variant, v, v_type| {
+                let v = RustValueTyped {
+                    value: v.to_owned(),
+                    rust_type: v_type.clone(),
+                };
+                variant.field.write_write_element_reverse(w, "os", &v);
+            });
+            for f in self.fields_except_oneof().into_iter().rev() {
+                f.write_message_write_field_reverse(w);
+            }
+            w.write_line("::core::result::Result::Ok(())");
         });
     }
 
     fn write_cached_size_getter(&self, w: &mut CodeWriter) {
         w.def_fn("cached_size(&self) -> u32", |w| {
-            w.write_line("self.cached_size.get()");
+            if self.gen_cached_size() {
+                w.write_line("self.cached_size.get()");
+            } else {
+                w.write_line("self.compute_size() as u32");
+            }
         });
     }
 
@@ -278,7 +375,7 @@ impl<'a> MessageGen<'a> {
         w.stmt_block(
             &format!("static instance: {} = {}", self.type_name, self.type_name),
             |w| {
-                for f in &self.fields_except_oneof_and_group() {
+                for f in &self.fields_except_oneof() {
                     w.field_entry(
                         f.rust_name.get(),
                         &f.kind
@@ -295,13 +392,15 @@ impl<'a> MessageGen<'a> {
                         protobuf_crate_path(&self.customize.for_elem)
                     ),
                 );
-                w.field_entry(
-                    "cached_size",
-                    &format!(
-                        "{}::rt::CachedSize::new()",
-                        protobuf_crate_path(&self.customize.for_elem)
-                    ),
-                );
+                if self.gen_cached_size() {
+                    w.field_entry(
+                        "cached_size",
+                        &format!(
+                            "{}::rt::CachedSize::new()",
+                            protobuf_crate_path(&self.customize.for_elem)
+                        ),
+                    );
+                }
             },
         );
         w.write_line("&instance");
@@ -329,12 +428,26 @@ impl<'a> MessageGen<'a> {
         // First appended element is size of self, and then nested message sizes.
         // in serialization order are appended recursively.");
         w.comment("Compute sizes of nested messages");
+        if self.table_driven() {
+            w.def_fn("compute_size(&self) -> u64", |w| {
+                w.write_line(&format!(
+                    "let my_size = {}::rt::table::compute_size(self, {}::generated_message_table());",
+                    protobuf_crate_path(&self.customize.for_elem),
+                    self.type_name,
+                ));
+                if self.gen_cached_size() {
+                    w.write_line("self.cached_size.set(my_size as u32);");
+                }
+                w.write_line("my_size");
+            });
+            return;
+        }
         // there are unused variables in oneof
         w.allow(&["unused_variables"]);
         w.def_fn("compute_size(&self) -> u64", |w| {
             // To have access to its methods but not polute the name space.
             w.write_line("let mut my_size = 0;");
-            for field in self.fields_except_oneof_and_group() {
+            for field in self.fields_except_oneof() {
                 field.write_message_compute_field_size("my_size", w);
             }
             self.write_match_each_oneof_variant(w, |w, variant, v, vtype| {
@@ -344,13 +457,15 @@ impl<'a> MessageGen<'a> {
                 "my_size += {}::rt::unknown_fields_size(self.unknown_fields());",
                 protobuf_crate_path(&self.customize.for_elem)
             ));
-            w.write_line("self.cached_size.set(my_size as u32);");
+            if self.gen_cached_size() {
+                w.write_line("self.cached_size.set(my_size as u32);");
+            }
             w.write_line("my_size");
         });
     }
 
     fn write_field_accessors(&self, w: &mut CodeWriter) {
-        for f in self.fields_except_group() {
+        for f in &self.fields {
             f.write_message_single_field_accessors(w);
         }
     }
@@ -359,7 +474,7 @@ impl<'a> MessageGen<'a> {
         w.impl_self_block(&format!("{}", self.type_name), |w| {
             // TODO: new should probably be a part of Message trait
             w.pub_fn(&format!("new() -> {}", self.type_name), |w| {
-                w.write_line("::std::default::Default::default()");
+                w.write_line("::core::default::Default::default()");
             });
 
             self.write_field_accessors(w);
@@ -368,6 +483,11 @@ impl<'a> MessageGen<'a> {
                 w.write_line("");
                 self.write_generated_message_descriptor_data(w);
             }
+
+            if self.table_driven() {
+                w.write_line("");
+                write_message_table(self, &self.customize.for_elem, w);
+            }
         });
     }
 
@@ -396,11 +516,17 @@ impl<'a> MessageGen<'a> {
             protobuf_crate_path(&self.customize.for_elem),
         );
         w.def_fn(&sig, |w| {
-            w.while_block("!is.eof()?", |w| {
-                // TODO: combine check for EOF and read tag.
-                w.write_line(&format!("let tag = is.read_raw_varint32()?;"));
+            if self.table_driven() {
+                w.write_line(&format!(
+                    "{}::rt::table::merge_from(self, is, {}::generated_message_table())",
+                    protobuf_crate_path(&self.customize.for_elem),
+                    self.type_name,
+                ));
+                return;
+            }
+            w.while_block("let Some(tag) = is.read_raw_tag_or_eof()?", |w| {
                 w.match_block("tag", |w| {
-                    for f in &self.fields_except_group() {
+                    for f in &self.fields {
                         f.write_merge_from_field_case_block(w);
                     }
                     w.case_block("tag", |w| {
@@ -408,7 +534,7 @@ impl<'a> MessageGen<'a> {
                     });
                 });
             });
-            w.write_line("::std::result::Result::Ok(())");
+            w.write_line("::core::result::Result::Ok(())");
         });
     }
 
@@ -440,10 +566,10 @@ impl<'a> MessageGen<'a> {
             Visibility::Path(self.message.scope().rust_path_to_file().to_reverse()),
             &sig,
             |w| {
-                let fields = self.fields_except_group();
+                let fields = &self.fields;
                 w.write_line(&format!(
                     "let mut fields = {};",
-                    expr_vec_with_capacity(&format!("{}", fields.len()))
+                    expr_vec_with_capacity(&format!("{}", fields.len()), &self.customize.for_elem)
                 ));
                 for field in fields {
                     field.write_descriptor_field("fields", w);
@@ -473,25 +599,103 @@ impl<'a> MessageGen<'a> {
                 });
             }
 
-            for f in self.message_fields() {
-                if let FieldKind::Map(..) = f.kind {
-                    // TODO: check values
-                    continue;
-                }
-
+            for f in self.fields_with_messages() {
                 // TODO:
                 // if message is declared in this file and has no message fields,
                 // we could skip the check here
-                f.write_for_self_field(w, "v", |w, _t| {
+                let check = |w: &mut CodeWriter| {
                     w.if_stmt("!v.is_initialized()", |w| {
                         w.write_line("return false;");
                     });
-                });
+                };
+                match f.kind {
+                    FieldKind::Map(..) => f.write_for_self_map_message_values(w, check),
+                    _ => f.write_for_self_field(w, "v", |w, _t| check(w)),
+                }
             }
             w.write_line("true");
         });
     }
 
+    fn write_merge_from_message(&self, w: &mut CodeWriter) {
+        w.def_fn("merge_from_message(&mut self, other: &Self)", |w| {
+            for f in self.fields_except_oneof() {
+                f.write_merge_from_message(w);
+            }
+            for oneof in self.oneofs() {
+                let variants = oneof.variants();
+                if variants.is_empty() {
+                    continue;
+                }
+                let self_field = format!("self.{}", oneof.oneof.field_name());
+                w.if_let_stmt(
+                    "::core::option::Option::Some(ref v)",
+                    &format!("other.{}", oneof.oneof.field_name()),
+                    |w| {
+                        w.match_block("v", |w| {
+                            let mut all_messages = true;
+                            for variant in &variants {
+                                let is_message = variant.field.proto_type
+                                    == field_descriptor_proto::Type::TYPE_MESSAGE
+                                    || variant.field.proto_type
+                                        == field_descriptor_proto::Type::TYPE_GROUP;
+                                if !is_message {
+                                    all_messages = false;
+                                    continue;
+                                }
+                                let path = variant.path(&self.file_and_mod());
+                                w.case_block(format!("&{}(ref v)", path), |w| {
+                                    w.write_line(&format!(
+                                        "if let ::core::option::Option::Some({}(ref mut m)) = {} {{",
+                                        path, self_field
+                                    ));
+                                    w.indented(|w| w.write_line("m.merge_from_message(v);"));
+                                    w.write_line("} else {");
+                                    w.indented(|w| {
+                                        w.write_line(&format!(
+                                            "{} = ::core::option::Option::Some({}(v.clone()));",
+                                            self_field, path
+                                        ))
+                                    });
+                                    w.write_line("}");
+                                });
+                            }
+                            if !all_messages {
+                                w.case_block("_", |w| {
+                                    w.write_line(&format!(
+                                        "{} = ::core::option::Option::Some(v.clone());",
+                                        self_field
+                                    ));
+                                });
+                            }
+                        });
+                    },
+                );
+            }
+            w.write_line("self.unknown_fields.extend_from(&other.unknown_fields);");
+        });
+    }
+
+    fn write_collect_missing_required_fields(&self, w: &mut CodeWriter) {
+        if self.required_fields().is_empty() && self.fields_with_messages().is_empty() {
+            // Default implementation reports nothing.
+            return;
+        }
+        w.write_line("");
+        w.def_fn(
+            &format!(
+                "collect_missing_required_fields(&self, prefix: &str, missing: &mut {}::rt::Vec<{}::rt::String>)",
+                protobuf_crate_path(&self.customize.for_elem),
+                protobuf_crate_path(&self.customize.for_elem),
+            ),
+            |w| {
+                for f in &self.fields {
+                    f.write_collect_missing_required_fields(w);
+                }
+            },
+        );
+    }
+
     fn write_impl_message(&self, w: &mut CodeWriter) {
         w.impl_for_block(
             &format!("{}::Message", protobuf_crate_path(&self.customize.for_elem),),
@@ -501,15 +705,23 @@ impl<'a> MessageGen<'a> {
                     "const NAME: &'static str = \"{}\";",
                     self.message.message.name()
                 ));
+                if !self.gen_cached_size() {
+                    w.write_line("const WRITE_REVERSE: bool = true;");
+                }
                 w.write_line("");
                 self.write_is_initialized(w);
+                self.write_collect_missing_required_fields(w);
                 w.write_line("");
                 self.write_merge_from(w);
                 w.write_line("");
+                self.write_merge_from_message(w);
+                w.write_line("");
                 self.write_compute_size(w);
                 w.write_line("");
                 self.write_write_to_with_cached_sizes(w);
                 w.write_line("");
+                self.write_write_reverse(w);
+                w.write_line("");
                 self.write_cached_size_getter(w);
                 w.write_line("");
                 self.write_unknown_fields(w);
@@ -519,7 +731,7 @@ impl<'a> MessageGen<'a> {
                 });
                 w.write_line("");
                 w.def_fn("clear(&mut self)", |w| {
-                    for f in self.fields_except_group() {
+                    for f in &self.fields {
                         f.write_clear(w);
                     }
                     w.write_line("self.unknown_fields.clear();");
@@ -560,17 +772,21 @@ impl<'a> MessageGen<'a> {
     }
 
     fn write_impl_display(&self, w: &mut CodeWriter) {
-        w.impl_for_block("::std::fmt::Display", &format!("{}", self.type_name), |w| {
-            w.def_fn(
-                "fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result",
-                |w| {
-                    w.write_line(&format!(
-                        "{}::text_format::fmt(self, f)",
-                        protobuf_crate_path(&self.customize.for_elem)
-                    ));
-                },
-            );
-        });
+        w.impl_for_block(
+            "::core::fmt::Display",
+            &format!("{}", self.type_name),
+            |w| {
+                w.def_fn(
+                    "fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result",
+                    |w| {
+                        w.write_line(&format!(
+                            "{}::text_format::fmt(self, f)",
+                            protobuf_crate_path(&self.customize.for_elem)
+                        ));
+                    },
+                );
+            },
+        );
     }
 
     fn supports_derive_partial_eq(&self) -> bool {
@@ -601,6 +817,7 @@ impl<'a> MessageGen<'a> {
             if !self.oneofs().is_empty() {
                 w.comment("message oneof groups");
                 for oneof in self.oneofs() {
+                    w.all_documentation(oneof.info, &oneof.path);
                     let vis = match self.expose_oneof() {
                         true => Visibility::Public,
                         false => Visibility::Default,
@@ -648,26 +865,28 @@ impl<'a> MessageGen<'a> {
                     protobuf_crate_path(&self.customize.for_elem)
                 ),
             );
-            write_protoc_insertion_point_for_special_field(
-                w,
-                &customize_cached_size,
-                &self.message_descriptor,
-                "cached_size",
-            );
-            w.pub_field_decl(
-                "cached_size",
-                &format!(
-                    "{}::rt::CachedSize",
-                    protobuf_crate_path(&self.customize.for_elem)
-                ),
-            );
+            if self.gen_cached_size() {
+                write_protoc_insertion_point_for_special_field(
+                    w,
+                    &customize_cached_size,
+                    &self.message_descriptor,
+                    "cached_size",
+                );
+                w.pub_field_decl(
+                    "cached_size",
+                    &format!(
+                        "{}::rt::CachedSize",
+                        protobuf_crate_path(&self.customize.for_elem)
+                    ),
+                );
+            }
         });
     }
 
     fn write_impl_default_for_amp(&self, w: &mut CodeWriter) {
         w.impl_args_for_block(
             &["'a"],
-            "::std::default::Default",
+            "::core::default::Default",
             &format!("&'a {}", self.type_name),
             |w| {
                 w.def_fn(&format!("default() -> &'a {}", self.type_name), |w| {
@@ -683,7 +902,7 @@ impl<'a> MessageGen<'a> {
 
     fn write_dummy_impl_partial_eq(&self, w: &mut CodeWriter) {
         w.impl_for_block(
-            "::std::cmp::PartialEq",
+            "::core::cmp::PartialEq",
             &format!("{}", self.type_name),
             |w| {
                 w.def_fn("eq(&self, _: &Self) -> bool", |w| {
@@ -721,6 +940,10 @@ impl<'a> MessageGen<'a> {
             w.write_line("");
             self.write_impl_value(w);
         }
+        if self.gen_views() {
+            w.write_line("");
+            write_message_view(self, &self.customize, w);
+        }
 
         let mod_name = message_name_to_nested_mod_name(&self.message.message.name());
 
@@ -730,7 +953,9 @@ impl<'a> MessageGen<'a> {
             .to_scope()
             .messages()
             .into_iter()
-            .filter(|nested| {
+            // keep index in `nested_type` for source code info path
+            .enumerate()
+            .filter(|(_, nested)| {
                 // ignore map entries, because they are not used in map fields
                 !nested.is_map()
             })
@@ -762,9 +987,9 @@ impl<'a> MessageGen<'a> {
 
                 let mut path = self.path.to_vec();
                 path.extend(&[nested_type_number, 0]);
-                for (id, nested) in nested_messages.iter().enumerate() {
+                for (id, nested) in &nested_messages {
                     let len = path.len() - 1;
-                    path[len] = id as i32;
+                    path[len] = *id as i32;
 
                     if !first {
                         w.write_line("");
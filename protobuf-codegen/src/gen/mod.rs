@@ -17,6 +17,10 @@ pub(crate) mod protoc_insertion_point;
 pub(crate) mod rust;
 pub(crate) mod rust_name;
 pub(crate) mod rust_types_values;
+mod rustdoc;
 pub(crate) mod scope;
+pub(crate) mod service;
 pub(crate) mod strx;
+pub(crate) mod table;
+pub(crate) mod view;
 pub(crate) mod well_known_types;
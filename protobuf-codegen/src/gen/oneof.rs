@@ -3,6 +3,7 @@
 use std::collections::HashSet;
 
 use protobuf::descriptor::field_descriptor_proto;
+use protobuf::descriptor::SourceCodeInfo;
 use protobuf::reflect::FieldDescriptor;
 use protobuf_parse::ProtobufAbsPath;
 
@@ -49,7 +50,9 @@ impl<'a> OneofField<'a> {
         let mut visited_messages = HashSet::new();
         let mut fields = vec![field.clone()];
         while let Some(field) = fields.pop() {
-            if field.proto().field_type() == field_descriptor_proto::Type::TYPE_MESSAGE {
+            if field.proto().field_type() == field_descriptor_proto::Type::TYPE_MESSAGE
+                || field.proto().field_type() == field_descriptor_proto::Type::TYPE_GROUP
+            {
                 let message_name = ProtobufAbsPath::from(field.proto().type_name());
                 if !visited_messages.insert(message_name.clone()) {
                     continue;
@@ -169,6 +172,8 @@ pub(crate) struct OneofGen<'a> {
     message: &'a MessageGen<'a>,
     pub oneof: OneofWithContext<'a>,
     customize: CustomizeElemCtx<'a>,
+    pub path: Vec<i32>,
+    pub info: Option<&'a SourceCodeInfo>,
 }
 
 impl<'a> OneofGen<'a> {
@@ -176,12 +181,16 @@ impl<'a> OneofGen<'a> {
         message: &'a MessageGen,
         oneof: OneofWithContext<'a>,
         parent_customize: &CustomizeElemCtx<'a>,
+        path: Vec<i32>,
+        info: Option<&'a SourceCodeInfo>,
     ) -> OneofGen<'a> {
         let customize = parent_customize.child(&Customize::default(), &oneof.oneof);
         OneofGen {
             message,
             oneof,
             customize,
+            path,
+            info,
         }
     }
 
@@ -189,11 +198,11 @@ impl<'a> OneofGen<'a> {
         make_path(source, &self.oneof.rust_name())
     }
 
-    pub fn variants_except_group(&'a self) -> Vec<OneofVariantGen<'a>> {
+    pub fn variants(&'a self) -> Vec<OneofVariantGen<'a>> {
         self.oneof
             .variants()
             .into_iter()
-            .filter_map(|v| {
+            .map(|v| {
                 let field = self
                     .message
                     .fields
@@ -201,15 +210,7 @@ impl<'a> OneofGen<'a> {
                     .filter(|f| f.proto_field.name() == v.field.name())
                     .next()
                     .expect(&format!("field not found by name: {}", v.field.name()));
-                match field.proto_type {
-                    field_descriptor_proto::Type::TYPE_GROUP => None,
-                    _ => Some(OneofVariantGen::parse(
-                        self,
-                        v,
-                        field,
-                        self.message.root_scope,
-                    )),
-                }
+                OneofVariantGen::parse(self, v, field, self.message.root_scope)
             })
             .collect()
     }
@@ -242,17 +243,19 @@ impl<'a> OneofGen<'a> {
     }
 
     fn write_enum(&self, w: &mut CodeWriter) {
+        w.all_documentation(self.info, &self.path);
         let derive = vec!["Clone", "PartialEq", "Debug"];
         w.derive(&derive);
         w.write_line("#[non_exhaustive]");
         write_protoc_insertion_point_for_oneof(w, &self.customize.for_elem, &self.oneof.oneof);
         w.pub_enum(&self.oneof.rust_name().ident.to_string(), |w| {
-            for variant in self.variants_except_group() {
+            for variant in self.variants() {
                 write_protoc_insertion_point_for_oneof_field(
                     w,
                     &self.customize.for_children,
                     &variant.field.proto_field.field,
                 );
+                w.all_documentation(variant.field.info, &variant.field.path);
                 w.write_line(&format!(
                     "{}({}),",
                     variant.field.rust_name,
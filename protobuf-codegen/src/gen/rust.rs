@@ -1,3 +1,6 @@
+use crate::customize::Customize;
+use crate::gen::inside::protobuf_crate_path;
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 static RUST_KEYWORDS: &'static [&'static str] = &[
     "as",
@@ -102,10 +105,16 @@ pub fn quote_escape_bytes(bytes: &[u8]) -> String {
     buf
 }
 
-pub(crate) const EXPR_NONE: &str = "::std::option::Option::None";
-pub(crate) const EXPR_VEC_NEW: &str = "::std::vec::Vec::new()";
-pub(crate) fn expr_vec_with_capacity(capacity: &str) -> String {
-    format!("::std::vec::Vec::with_capacity({})", capacity)
+pub(crate) const EXPR_NONE: &str = "::core::option::Option::None";
+pub(crate) fn expr_vec_new(customize: &Customize) -> String {
+    format!("{}::rt::Vec::new()", protobuf_crate_path(customize))
+}
+pub(crate) fn expr_vec_with_capacity(capacity: &str, customize: &Customize) -> String {
+    format!(
+        "{}::rt::Vec::with_capacity({})",
+        protobuf_crate_path(customize),
+        capacity
+    )
 }
 
 #[cfg(test)]
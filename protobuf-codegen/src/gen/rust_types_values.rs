@@ -7,8 +7,8 @@ use crate::customize::Customize;
 use crate::gen::file_and_mod::FileAndMod;
 use crate::gen::inside::protobuf_crate_path;
 use crate::gen::message::RustTypeMessage;
+use crate::gen::rust::expr_vec_new;
 use crate::gen::rust::EXPR_NONE;
-use crate::gen::rust::EXPR_VEC_NEW;
 use crate::gen::rust_name::RustIdent;
 use crate::gen::rust_name::RustIdentWithPath;
 use crate::gen::rust_name::RustPath;
@@ -27,6 +27,7 @@ pub(crate) enum RustType {
     Bool,
     Vec(Box<RustType>),
     HashMap(Box<RustType>, Box<RustType>),
+    BTreeMap(Box<RustType>, Box<RustType>),
     String,
     // [T], not &[T]
     Slice(Box<RustType>),
@@ -50,8 +51,6 @@ pub(crate) enum RustType {
     Bytes,
     // chars::Chars
     Chars,
-    // group
-    Group,
 }
 
 impl RustType {
@@ -62,24 +61,38 @@ impl RustType {
             RustType::Int(false, bits) => format!("u{}", bits),
             RustType::Float(bits) => format!("f{}", bits),
             RustType::Bool => format!("bool"),
-            RustType::Vec(ref param) => format!("::std::vec::Vec<{}>", param.to_code(customize)),
+            RustType::Vec(ref param) => format!(
+                "{}::rt::Vec<{}>",
+                protobuf_crate_path(customize),
+                param.to_code(customize)
+            ),
             RustType::HashMap(ref key, ref value) => format!(
                 "::std::collections::HashMap<{}, {}>",
                 key.to_code(customize),
                 value.to_code(customize)
             ),
-            RustType::String => format!("::std::string::String"),
+            RustType::BTreeMap(ref key, ref value) => format!(
+                "{}::rt::BTreeMap<{}, {}>",
+                protobuf_crate_path(customize),
+                key.to_code(customize),
+                value.to_code(customize)
+            ),
+            RustType::String => format!("{}::rt::String", protobuf_crate_path(customize)),
             RustType::Slice(ref param) => format!("[{}]", param.to_code(customize)),
             RustType::Str => format!("str"),
             RustType::Option(ref param) => {
-                format!("::std::option::Option<{}>", param.to_code(customize))
+                format!("::core::option::Option<{}>", param.to_code(customize))
             }
             RustType::MessageField(ref param) => format!(
                 "{}::MessageField<{}>",
                 protobuf_crate_path(customize),
                 param.to_code(customize)
             ),
-            RustType::Uniq(ref param) => format!("::std::boxed::Box<{}>", param.to_code(customize)),
+            RustType::Uniq(ref param) => format!(
+                "{}::rt::Box<{}>",
+                protobuf_crate_path(customize),
+                param.to_code(customize)
+            ),
             RustType::Ref(ref param) => format!("&{}", param.to_code(customize)),
             RustType::Message(ref name) => format!("{}", name),
             RustType::Enum(ref name, ..) | RustType::Oneof(ref name) => format!("{}", name),
@@ -88,7 +101,6 @@ impl RustType {
                 protobuf_crate_path(customize),
                 name
             ),
-            RustType::Group => format!("<group>"),
             RustType::Bytes => format!("::bytes::Bytes"),
             RustType::Chars => format!("{}::Chars", protobuf_crate_path(customize)),
         }
@@ -198,9 +210,12 @@ impl RustType {
             RustType::Int(..) => "0".to_string(),
             RustType::Float(..) => "0.".to_string(),
             RustType::Bool => "false".to_string(),
-            RustType::Vec(..) => EXPR_VEC_NEW.to_string(),
+            RustType::Vec(..) => expr_vec_new(customize),
             RustType::HashMap(..) => "::std::collections::HashMap::new()".to_string(),
-            RustType::String => "::std::string::String::new()".to_string(),
+            RustType::BTreeMap(..) => {
+                format!("{}::rt::BTreeMap::new()", protobuf_crate_path(customize))
+            }
+            RustType::String => format!("{}::rt::String::new()", protobuf_crate_path(customize)),
             RustType::Bytes => "::bytes::Bytes::new()".to_string(),
             RustType::Chars => format!("{}::Chars::new()", protobuf_crate_path(customize)),
             RustType::Option(..) => EXPR_NONE.to_string(),
@@ -245,7 +260,8 @@ impl RustType {
             | RustType::Chars
             | RustType::String
             | RustType::MessageField(..)
-            | RustType::HashMap(..) => format!("{}.clear()", v),
+            | RustType::HashMap(..)
+            | RustType::BTreeMap(..) => format!("{}.clear()", v),
             RustType::Bool
             | RustType::Float(..)
             | RustType::Int(..)
@@ -280,7 +296,11 @@ impl RustType {
             (x, y) if x == y => return Ok(format!("{}", v)),
             (&RustType::Ref(ref x), y) if **x == *y => return Ok(format!("*{}", v)),
             (x, &RustType::Uniq(ref y)) if *x == **y => {
-                return Ok(format!("::std::boxed::Box::new({})", v))
+                return Ok(format!(
+                    "{}::rt::Box::new({})",
+                    protobuf_crate_path(customize),
+                    v
+                ))
             }
             (&RustType::Uniq(ref x), y) if **x == *y => return Ok(format!("*{}", v)),
             (&RustType::String, &RustType::Ref(ref t)) if **t == RustType::Str => {
@@ -303,7 +323,7 @@ impl RustType {
                        _ => false,
                     // TODO: from_static
                    } => {
-                return Ok(format!("<{}::Chars as ::std::convert::From<_>>::from({}.to_owned())",
+                return Ok(format!("<{}::Chars as ::core::convert::From<_>>::from({}.to_owned())",
                     protobuf_crate_path(customize), v))
             },
             (&RustType::Ref(ref t1), &RustType::Vec(ref t2))
@@ -313,7 +333,7 @@ impl RustType {
                    } => return Ok(format!("{}.to_vec()", v)),
             (&RustType::Ref(ref t1), &RustType::Bytes)
                 if t1.is_slice_u8() =>
-                    return Ok(format!("<::bytes::Bytes as ::std::convert::From<_>>::from({}.to_vec())", v)),
+                    return Ok(format!("<::bytes::Bytes as ::core::convert::From<_>>::from({}.to_vec())", v)),
             (&RustType::Vec(ref x), &RustType::Ref(ref t))
                 if match **t {
                        RustType::Slice(ref y) => x == y,
@@ -483,7 +503,7 @@ fn test_file_last_component() {
     assert_eq!("ab.proto", file_last_component("yy\\xx\\ab.proto"));
 }
 
-fn is_descriptor_proto(file: &FileDescriptorProto) -> bool {
+pub(crate) fn is_descriptor_proto(file: &FileDescriptorProto) -> bool {
     file.package() == "google.protobuf" && file_last_component(file.name()) == "descriptor.proto"
 }
 
@@ -0,0 +1,212 @@
+//! Convert `.proto` comments to rustdoc.
+//!
+//! Comments are written by humans for `.proto` files, not for rustdoc,
+//! so text which rustdoc interprets specially is escaped:
+//! brackets would be resolved as intra-doc links,
+//! angle brackets would be parsed as HTML tags,
+//! and code blocks would be compiled as Rust doctests.
+
+/// Indentation which starts an indented code block in markdown.
+const CODE_BLOCK_INDENT: usize = 4;
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Fence (three or more backticks or tildes) at the start of the line.
+fn fence(line: &str) -> Option<&str> {
+    let c = line.chars().next()?;
+    if c != '`' && c != '~' {
+        return None;
+    }
+    let len = line.len() - line.trim_start_matches(c).len();
+    if len < 3 {
+        return None;
+    }
+    Some(&line[..len])
+}
+
+fn is_url_start(s: &str) -> bool {
+    s.starts_with("http://") || s.starts_with("https://")
+}
+
+/// Escape text outside of code blocks.
+fn escape_line(line: &str) -> String {
+    let mut r = String::with_capacity(line.len());
+    let mut rem = line;
+    while let Some(c) = rem.chars().next() {
+        if c == '`' {
+            // Code span is copied as is if it is closed on the same line
+            let ticks = rem.len() - rem.trim_start_matches('`').len();
+            let (open, after) = rem.split_at(ticks);
+            let close = after
+                .match_indices(open)
+                .find(|&(pos, _)| !after[pos + ticks..].starts_with('`'));
+            match close {
+                Some((pos, _)) => {
+                    let end = ticks + pos + ticks;
+                    r.push_str(&rem[..end]);
+                    rem = &rem[end..];
+                }
+                None => {
+                    r.push_str(open);
+                    rem = after;
+                }
+            }
+        } else if c == '<' && is_url_start(&rem[1..]) && rem.contains('>') {
+            // Already an autolink
+            let end = rem.find('>').unwrap() + 1;
+            r.push_str(&rem[..end]);
+            rem = &rem[end..];
+        } else if is_url_start(rem) {
+            // Bare URLs are not rendered as links by rustdoc
+            let end = rem.find(char::is_whitespace).unwrap_or(rem.len());
+            let url = rem[..end].trim_end_matches(&['.', ',', ';', ':', '!', '?', '\'', '"'][..]);
+            r.push('<');
+            r.push_str(url);
+            r.push('>');
+            rem = &rem[url.len()..];
+        } else {
+            if matches!(c, '[' | ']' | '<' | '>' | '\\') {
+                r.push('\\');
+            }
+            r.push(c);
+            rem = &rem[c.len_utf8()..];
+        }
+    }
+    r
+}
+
+/// Convert a comment from `SourceCodeInfo` to the lines of rustdoc.
+///
+/// Code blocks are converted to fenced `text` blocks.
+pub(crate) fn rustdoc_lines(comment: &str) -> Vec<String> {
+    let lines: Vec<&str> = comment.lines().collect();
+
+    // rustdoc strips the common indentation, so do we when looking for code blocks
+    let min_indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| indent(l))
+        .min()
+        .unwrap_or(0);
+    let fence_indent = " ".repeat(min_indent);
+
+    let mut r = Vec::new();
+    let mut open_fence: Option<&str> = None;
+    let mut in_indented_code = false;
+    let mut prev_blank = true;
+    for line in lines {
+        let trimmed = line.trim_start();
+        let blank = trimmed.is_empty();
+
+        if let Some(f) = open_fence {
+            if let Some(c) = fence(trimmed) {
+                // Closing fence is at least as long as the opening one
+                if c.starts_with(f) && trimmed[c.len()..].trim().is_empty() {
+                    open_fence = None;
+                }
+            }
+            r.push(line.to_owned());
+            continue;
+        }
+
+        if in_indented_code {
+            if !blank && indent(line) - min_indent >= CODE_BLOCK_INDENT {
+                r.push(line.to_owned());
+                continue;
+            }
+            r.push(format!("{}```", fence_indent));
+            in_indented_code = false;
+        }
+
+        if let Some(f) = fence(trimmed) {
+            // Code without language would be compiled as Rust
+            let info = trimmed[f.len()..].trim();
+            let info = if info.is_empty() || info == "rust" {
+                "text"
+            } else {
+                info
+            };
+            r.push(format!("{}{}{}", &line[..indent(line)], f, info));
+            open_fence = Some(f);
+        } else if prev_blank && !blank && indent(line) - min_indent >= CODE_BLOCK_INDENT {
+            r.push(format!("{}```text", fence_indent));
+            r.push(line.to_owned());
+            in_indented_code = true;
+        } else {
+            r.push(escape_line(line));
+        }
+        prev_blank = blank;
+    }
+
+    if in_indented_code {
+        r.push(format!("{}```", fence_indent));
+    }
+
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use super::rustdoc_lines;
+
+    fn doc(comment: &str) -> String {
+        rustdoc_lines(comment).join("\n")
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(" Foo bar.", doc(" Foo bar.\n"));
+        assert_eq!(" a\n\n b", doc(" a\n\n b\n"));
+    }
+
+    #[test]
+    fn links_and_tags() {
+        assert_eq!(
+            r" \[default = 1\] for map\<string, Foo\>",
+            doc(" [default = 1] for map<string, Foo>")
+        );
+        assert_eq!(r" a\\b", doc(r" a\b"));
+    }
+
+    #[test]
+    fn code_span() {
+        assert_eq!(
+            r" Use `map<K, V>` and ``a ` [b]`` \[c\]",
+            doc(" Use `map<K, V>` and ``a ` [b]`` [c]")
+        );
+        assert_eq!(r" unclosed ` \[x\]", doc(" unclosed ` [x]"));
+    }
+
+    #[test]
+    fn urls() {
+        assert_eq!(
+            " See <https://example.com/a_b>.",
+            doc(" See https://example.com/a_b.")
+        );
+        assert_eq!(
+            " See <http://example.com>",
+            doc(" See <http://example.com>")
+        );
+    }
+
+    #[test]
+    fn indented_code() {
+        assert_eq!(
+            " Example:\n\n ```text\n     foo [bar];\n ```\n\n End.",
+            doc(" Example:\n\n     foo [bar];\n\n End.\n")
+        );
+        // Not a code block when continues a paragraph
+        assert_eq!(" a\n     \\[b\\]", doc(" a\n     [b]\n"));
+    }
+
+    #[test]
+    fn fenced_code() {
+        assert_eq!(
+            " ```text\n [a] <b>\n ```\n \\[c\\]",
+            doc(" ```\n [a] <b>\n ```\n [c]\n")
+        );
+        assert_eq!(" ~~~proto\n x\n ~~~", doc(" ~~~proto\n x\n ~~~\n"));
+    }
+}
@@ -386,6 +386,7 @@ impl<'a> MessageWithScope<'a> {
         self.message
             .oneofs()
             .into_iter()
+            .filter(|oneof| !oneof.is_synthetic())
             .map(|oneof| OneofWithContext {
                 message: self.clone(),
                 oneof,
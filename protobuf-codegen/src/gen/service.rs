@@ -0,0 +1,431 @@
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::MethodDescriptorProto;
+use protobuf::descriptor::ServiceDescriptorProto;
+use protobuf::descriptor::SourceCodeInfo;
+use protobuf::reflect::FileDescriptor;
+use protobuf_parse::snake_case;
+use protobuf_parse::ProtobufAbsPath;
+
+use crate::customize::ctx::CustomizeElemCtx;
+use crate::gen::code_writer::CodeWriter;
+use crate::gen::file_and_mod::FileAndMod;
+use crate::gen::inside::protobuf_crate_path;
+use crate::gen::rust::is_rust_keyword;
+use crate::gen::rust_name::RustIdent;
+use crate::gen::rust_types_values::type_name_to_rust_relative;
+use crate::gen::scope::FileScope;
+use crate::gen::scope::RootScope;
+
+/// Kind of method by streaming of request and response.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum MethodKind {
+    Unary,
+    ClientStreaming,
+    ServerStreaming,
+    BidiStreaming,
+}
+
+impl MethodKind {
+    fn new(method: &MethodDescriptorProto) -> MethodKind {
+        match (method.client_streaming(), method.server_streaming()) {
+            (false, false) => MethodKind::Unary,
+            (true, false) => MethodKind::ClientStreaming,
+            (false, true) => MethodKind::ServerStreaming,
+            (true, true) => MethodKind::BidiStreaming,
+        }
+    }
+
+    /// Name of `Channel` method and suffix of `rt::service` functions.
+    fn name(&self) -> &'static str {
+        match self {
+            MethodKind::Unary => "unary",
+            MethodKind::ClientStreaming => "client_streaming",
+            MethodKind::ServerStreaming => "server_streaming",
+            MethodKind::BidiStreaming => "bidi_streaming",
+        }
+    }
+
+    /// Generic lifetime and `self` parameter of a method.
+    fn self_param(&self) -> (&'static str, &'static str) {
+        match self {
+            MethodKind::Unary => ("", "&self"),
+            _ => ("<'a>", "&'a self"),
+        }
+    }
+
+    fn client_streaming(&self) -> bool {
+        matches!(
+            self,
+            MethodKind::ClientStreaming | MethodKind::BidiStreaming
+        )
+    }
+
+    fn server_streaming(&self) -> bool {
+        matches!(
+            self,
+            MethodKind::ServerStreaming | MethodKind::BidiStreaming
+        )
+    }
+}
+
+struct MethodGen<'a> {
+    proto: &'a MethodDescriptorProto,
+    kind: MethodKind,
+    rust_name: RustIdent,
+    input_type: String,
+    output_type: String,
+    path: Vec<i32>,
+}
+
+impl<'a> MethodGen<'a> {
+    fn new(
+        proto: &'a MethodDescriptorProto,
+        file_and_mod: &FileAndMod,
+        root_scope: &RootScope,
+        path: Vec<i32>,
+    ) -> MethodGen<'a> {
+        let mut rust_name = snake_case(proto.name());
+        if is_rust_keyword(&rust_name) {
+            rust_name.insert_str(0, "method_");
+        }
+        let message_type = |name: &str| {
+            type_name_to_rust_relative(&ProtobufAbsPath::from(name), file_and_mod, root_scope)
+                .to_string()
+        };
+        MethodGen {
+            proto,
+            kind: MethodKind::new(proto),
+            rust_name: RustIdent::new(&rust_name),
+            input_type: message_type(proto.input_type()),
+            output_type: message_type(proto.output_type()),
+            path,
+        }
+    }
+}
+
+struct ServiceGen<'a> {
+    proto: &'a ServiceDescriptorProto,
+    full_name: String,
+    methods: Vec<MethodGen<'a>>,
+    protobuf_crate: String,
+    path: Vec<i32>,
+    info: Option<&'a SourceCodeInfo>,
+}
+
+impl<'a> ServiceGen<'a> {
+    fn trait_name(&self) -> &str {
+        self.proto.name()
+    }
+
+    fn client_name(&self) -> String {
+        format!("{}Client", self.proto.name())
+    }
+
+    fn server_name(&self) -> String {
+        format!("{}Server", self.proto.name())
+    }
+
+    fn streaming(&self, message: &str, error: &str) -> String {
+        format!(
+            "{}::service::Streaming<'a, {}, {}>",
+            self.protobuf_crate, message, error
+        )
+    }
+
+    fn method_info(&self, method: &MethodGen) -> String {
+        format!(
+            "&{}::service::MethodInfo {{ service: \"{}\", method: \"{}\" }}",
+            self.protobuf_crate,
+            self.full_name,
+            method.proto.name()
+        )
+    }
+
+    fn write_trait(&self, w: &mut CodeWriter) {
+        w.all_documentation(self.info, &self.path);
+        w.expr_block(&format!("pub trait {}", self.trait_name()), |w| {
+            w.write_line("/// Error returned by service methods.");
+            w.write_line(&format!(
+                "type Error: ::core::convert::From<{}::Error>;",
+                self.protobuf_crate
+            ));
+            for method in &self.methods {
+                w.write_line("");
+                w.all_documentation(self.info, &method.path);
+                let request = match method.kind.client_streaming() {
+                    true => format!(
+                        "requests: {}",
+                        self.streaming(&method.input_type, "Self::Error")
+                    ),
+                    false => format!("request: {}", method.input_type),
+                };
+                let response = match method.kind.server_streaming() {
+                    true => self.streaming(&method.output_type, "Self::Error"),
+                    false => method.output_type.clone(),
+                };
+                let (generics, self_param) = method.kind.self_param();
+                w.write_line(&format!(
+                    "fn {}{}({}, {}) -> ::core::result::Result<{}, Self::Error>;",
+                    method.rust_name, generics, self_param, request, response
+                ));
+            }
+        });
+    }
+
+    fn write_client(&self, w: &mut CodeWriter) {
+        w.write_line(&format!(
+            "/// Client of service `{}` sending requests over a channel.",
+            self.full_name
+        ));
+        w.derive(&["Debug", "Clone"]);
+        w.pub_struct(&format!("{}<C>", self.client_name()), |w| {
+            w.field_decl("channel", "C");
+        });
+        w.write_line("");
+        w.expr_block(
+            &format!(
+                "impl<C: {}::service::Channel> {}<C>",
+                self.protobuf_crate,
+                self.client_name()
+            ),
+            |w| {
+                w.write_line("/// Create a client sending requests over given channel.");
+                w.pub_fn("new(channel: C) -> Self", |w| {
+                    w.write_line(&format!("{} {{ channel }}", self.client_name()));
+                });
+                w.write_line("");
+                w.write_line("/// Channel used by this client.");
+                w.pub_fn("channel(&self) -> &C", |w| {
+                    w.write_line("&self.channel");
+                });
+                for method in &self.methods {
+                    w.write_line("");
+                    w.all_documentation(self.info, &method.path);
+                    let response = match method.kind.server_streaming() {
+                        true => self.streaming(&method.output_type, "C::Error"),
+                        false => method.output_type.clone(),
+                    };
+                    let (generics, self_param) = method.kind.self_param();
+                    let (generics, request, arg, where_clause) =
+                        match method.kind.client_streaming() {
+                            true => (
+                                "<'a, I>",
+                                "requests: I".to_owned(),
+                                "requests.into_iter()",
+                                format!(
+                                " where I: ::core::iter::IntoIterator<Item = {}>, I::IntoIter: 'a",
+                                method.input_type
+                            ),
+                            ),
+                            false => (
+                                generics,
+                                format!("request: &{}", method.input_type),
+                                "request",
+                                String::new(),
+                            ),
+                        };
+                    w.pub_fn(
+                        &format!(
+                            "{}{}({}, {}) -> ::core::result::Result<{}, C::Error>{}",
+                            method.rust_name, generics, self_param, request, response, where_clause
+                        ),
+                        |w| {
+                            w.write_line(&format!(
+                                "{}::rt::service::client_{}(&self.channel, {}, {})",
+                                self.protobuf_crate,
+                                method.kind.name(),
+                                self.method_info(method),
+                                arg
+                            ));
+                        },
+                    );
+                }
+            },
+        );
+    }
+
+    fn write_server(&self, w: &mut CodeWriter) {
+        w.write_line(&format!(
+            "/// Dispatch serialized requests to an implementation of `{}`.",
+            self.trait_name()
+        ));
+        w.write_line("///");
+        w.write_line("/// Can be used by a transport on the server side,");
+        w.write_line(&format!(
+            "/// or as an in-process channel for `{}`.",
+            self.client_name()
+        ));
+        w.derive(&["Debug", "Clone"]);
+        w.pub_struct(&format!("{}<S>", self.server_name()), |w| {
+            w.field_decl("service", "S");
+        });
+        w.write_line("");
+        w.expr_block(
+            &format!("impl<S: {}> {}<S>", self.trait_name(), self.server_name()),
+            |w| {
+                w.write_line("/// Create a server dispatching requests to given service.");
+                w.pub_fn("new(service: S) -> Self", |w| {
+                    w.write_line(&format!("{} {{ service }}", self.server_name()));
+                });
+                w.write_line("");
+                w.write_line("/// Service implementation.");
+                w.pub_fn("service(&self) -> &S", |w| {
+                    w.write_line("&self.service");
+                });
+            },
+        );
+        w.write_line("");
+        w.expr_block(
+            &format!(
+                "impl<S: {}> {}::service::Channel for {}<S>",
+                self.trait_name(),
+                self.protobuf_crate,
+                self.server_name()
+            ),
+            |w| {
+                w.write_line("type Error = S::Error;");
+                for kind in [
+                    MethodKind::Unary,
+                    MethodKind::ClientStreaming,
+                    MethodKind::ServerStreaming,
+                    MethodKind::BidiStreaming,
+                ] {
+                    w.write_line("");
+                    self.write_server_dispatch(kind, w);
+                }
+            },
+        );
+    }
+
+    fn write_server_dispatch(&self, kind: MethodKind, w: &mut CodeWriter) {
+        let (request, request_type) = match kind.client_streaming() {
+            true => (
+                "requests",
+                format!("{}::service::Requests<'a>", self.protobuf_crate),
+            ),
+            false => ("request", "::std::vec::Vec<u8>".to_owned()),
+        };
+        let response = match kind.server_streaming() {
+            true => self.streaming("::std::vec::Vec<u8>", "S::Error"),
+            false => "::std::vec::Vec<u8>".to_owned(),
+        };
+        let (generics, self_param) = kind.self_param();
+        let methods: Vec<&MethodGen> = self.methods.iter().filter(|m| m.kind == kind).collect();
+        let unknown_method = format!(
+            "::core::result::Result::Err({}::rt::service::unknown_method(method).into())",
+            self.protobuf_crate
+        );
+        w.def_fn(
+            &format!(
+                "{}{}({}, method: &{}::service::MethodInfo, {}: {}) -> ::core::result::Result<{}, S::Error>",
+                kind.name(),
+                generics,
+                self_param,
+                self.protobuf_crate,
+                request,
+                request_type,
+                response
+            ),
+            |w| {
+                if methods.is_empty() {
+                    w.write_line(&format!("let _ = {};", request));
+                    w.write_line(&unknown_method);
+                    return;
+                }
+                // Methods of other services may have the same name.
+                w.if_stmt(
+                    format!("method.service != \"{}\"", self.full_name),
+                    |w| {
+                        w.write_line(format!("return {};", unknown_method));
+                    },
+                );
+                w.match_expr("method.method", |w| {
+                    for method in &methods {
+                        w.case_expr(
+                            &format!("\"{}\"", method.proto.name()),
+                            &format!(
+                                "{}::rt::service::server_{}({}, |r| self.service.{}(r))",
+                                self.protobuf_crate,
+                                kind.name(),
+                                request,
+                                method.rust_name
+                            ),
+                        );
+                    }
+                    w.case_expr("_", &unknown_method);
+                });
+            },
+        );
+    }
+
+    fn write(&self, w: &mut CodeWriter) {
+        self.write_trait(w);
+        w.write_line("");
+        self.write_client(w);
+        w.write_line("");
+        self.write_server(w);
+    }
+}
+
+pub(crate) fn write_services(
+    file: &FileDescriptor,
+    root_scope: &RootScope,
+    customize: &CustomizeElemCtx,
+    w: &mut CodeWriter,
+) {
+    if !customize.for_elem.gen_services.unwrap_or(false) {
+        return;
+    }
+
+    static SERVICE_NUMBER: protobuf::rt::Lazy<i32> = protobuf::rt::Lazy::new();
+    let service_number = *SERVICE_NUMBER.get(|| {
+        protobuf::reflect::MessageDescriptor::for_type::<FileDescriptorProto>()
+            .field_by_name("service")
+            .expect("`service` must exist")
+            .proto()
+            .number()
+    });
+    static METHOD_NUMBER: protobuf::rt::Lazy<i32> = protobuf::rt::Lazy::new();
+    let method_number = *METHOD_NUMBER.get(|| {
+        protobuf::reflect::MessageDescriptor::for_type::<ServiceDescriptorProto>()
+            .field_by_name("method")
+            .expect("`method` must exist")
+            .proto()
+            .number()
+    });
+
+    let file_and_mod = FileScope {
+        file_descriptor: file,
+    }
+    .to_scope()
+    .file_and_mod(customize.for_elem.clone());
+
+    for (id, proto) in file.proto().service.iter().enumerate() {
+        let path = vec![service_number, id as i32];
+        let methods = proto
+            .method
+            .iter()
+            .enumerate()
+            .map(|(method_id, method)| {
+                let mut path = path.clone();
+                path.extend_from_slice(&[method_number, method_id as i32]);
+                MethodGen::new(method, &file_and_mod, root_scope, path)
+            })
+            .collect();
+        let full_name = match file.proto().package() {
+            "" => proto.name().to_owned(),
+            package => format!("{}.{}", package, proto.name()),
+        };
+
+        w.write_line("");
+        ServiceGen {
+            proto,
+            full_name,
+            methods,
+            protobuf_crate: protobuf_crate_path(&customize.for_elem).to_string(),
+            path,
+            info: file.proto().source_code_info.as_ref(),
+        }
+        .write(w);
+    }
+}
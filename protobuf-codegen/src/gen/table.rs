@@ -0,0 +1,190 @@
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::rt::WireType;
+
+use crate::customize::Customize;
+use crate::gen::code_writer::CodeWriter;
+use crate::gen::field::FieldElem;
+use crate::gen::field::FieldGen;
+use crate::gen::field::FieldKind;
+use crate::gen::field::OptionKind;
+use crate::gen::field::SingularFieldFlag;
+use crate::gen::inside::protobuf_crate_path;
+use crate::gen::message::MessageGen;
+use crate::gen::scope::WithScope;
+
+/// Table-driven code cannot be generated for messages with groups.
+pub(crate) fn message_supports_table(message: &MessageGen) -> bool {
+    message
+        .fields
+        .iter()
+        .all(|f| f.proto_type != Type::TYPE_GROUP)
+}
+
+struct TableFieldGen<'a> {
+    field: &'a FieldGen<'a>,
+    protobuf_crate: String,
+}
+
+impl<'a> TableFieldGen<'a> {
+    fn elem_protobuf_type(&self, elem: &FieldElem) -> String {
+        elem.lib_protobuf_type(&self.field.file_and_mod())
+    }
+
+    /// Wrapper type from `rt::table` for the field storage.
+    fn wrapper_type(&self) -> String {
+        let wrapper = match &self.field.kind {
+            FieldKind::Singular(s) => match (&s.flag, &s.elem) {
+                (SingularFieldFlag::WithoutFlag, elem) => {
+                    format!("Implicit::<{}>", self.elem_protobuf_type(elem))
+                }
+                (
+                    SingularFieldFlag::WithFlag {
+                        option_kind: OptionKind::MessageField,
+                        ..
+                    },
+                    FieldElem::Message(m),
+                ) => format!(
+                    "SingularMessage::<{}>",
+                    m.rust_name_relative(&self.field.file_and_mod())
+                ),
+                (SingularFieldFlag::WithFlag { .. }, elem) => {
+                    format!("Optional::<{}>", self.elem_protobuf_type(elem))
+                }
+            },
+            FieldKind::Repeated(r) => {
+                let wrapper = match &r.elem {
+                    FieldElem::Message(..)
+                    | FieldElem::Primitive(Type::TYPE_STRING, ..)
+                    | FieldElem::Primitive(Type::TYPE_BYTES, ..) => "Repeated",
+                    _ if r.packed => "RepeatedPacked",
+                    _ => "RepeatedUnpacked",
+                };
+                format!("{}::<{}>", wrapper, self.elem_protobuf_type(&r.elem))
+            }
+            // Map storage type is inferred from the field.
+            FieldKind::Map(m) => format!(
+                "MapField::<{}, {}, _>",
+                self.elem_protobuf_type(&m.key),
+                self.elem_protobuf_type(&m.value)
+            ),
+            FieldKind::Oneof(o) => format!("OneofValue::<{}>", self.elem_protobuf_type(&o.elem)),
+        };
+        format!("{}::rt::table::{}", self.protobuf_crate, wrapper)
+    }
+
+    fn write_entry(&self, w: &mut CodeWriter) {
+        match &self.field.kind {
+            FieldKind::Oneof(o) => {
+                let variant_path = o.variant_path(
+                    &self
+                        .field
+                        .proto_field
+                        .message
+                        .scope
+                        .rust_path_to_file()
+                        .clone()
+                        .into_path(),
+                );
+                let wire_type = WireType::for_type(self.field.proto_type);
+                w.write_line(&format!(
+                    "{}::rt::table::TableField::oneof(",
+                    self.protobuf_crate
+                ));
+                w.indented(|w| {
+                    w.write_line(&format!("{},", self.field.proto_field.number()));
+                    w.write_line(&format!(
+                        "{}::rt::WireType::{:?},",
+                        self.protobuf_crate, wire_type
+                    ));
+                    w.write_line(&format!("|m| match &m.{} {{", o.oneof_field_name));
+                    w.indented(|w| {
+                        w.write_line(&format!(
+                            "::core::option::Option::Some({}(v)) => ::core::option::Option::Some({}::wrap(v)),",
+                            variant_path,
+                            self.wrapper_type(),
+                        ));
+                        w.write_line("_ => ::core::option::Option::None,");
+                    });
+                    w.write_line("},");
+                    let read = format!(
+                        "<{} as {}::reflect::types::ProtobufType>::read(is)?",
+                        self.elem_protobuf_type(&o.elem),
+                        self.protobuf_crate
+                    );
+                    let value = if o.boxed {
+                        format!("{}::rt::Box::new({})", self.protobuf_crate, read)
+                    } else {
+                        read
+                    };
+                    w.write_line("|m, is| {");
+                    w.indented(|w| {
+                        w.write_line(&format!(
+                            "m.{} = ::core::option::Option::Some({}({}));",
+                            o.oneof_field_name, variant_path, value
+                        ));
+                        w.write_line("::core::result::Result::Ok(())");
+                    });
+                    w.write_line("},");
+                });
+                w.write_line("),");
+            }
+            _ => {
+                let wrapper_type = self.wrapper_type();
+                w.write_line(&format!(
+                    "{}::rt::table::TableField::field(",
+                    self.protobuf_crate
+                ));
+                w.indented(|w| {
+                    w.write_line(&format!("{},", self.field.proto_field.number()));
+                    w.write_line(&format!(
+                        "|m| {}::wrap(&m.{}),",
+                        wrapper_type, self.field.rust_name
+                    ));
+                    w.write_line(&format!(
+                        "|m| {}::wrap_mut(&mut m.{}),",
+                        wrapper_type, self.field.rust_name
+                    ));
+                });
+                w.write_line("),");
+            }
+        }
+    }
+}
+
+/// Write `generated_message_table` function for message `Foo`
+/// inside `impl Foo` block.
+pub(crate) fn write_message_table(message: &MessageGen, customize: &Customize, w: &mut CodeWriter) {
+    let protobuf_crate = protobuf_crate_path(customize);
+    let message_name = message.message.rust_name();
+    let mut fields: Vec<TableFieldGen> = message
+        .fields
+        .iter()
+        .map(|field| TableFieldGen {
+            field,
+            protobuf_crate: protobuf_crate.to_string(),
+        })
+        .collect();
+    fields.sort_by_key(|f| f.field.proto_field.number());
+
+    let table_type = format!(
+        "{}::rt::table::MessageTable<{}>",
+        protobuf_crate, message_name
+    );
+    w.def_fn(
+        &format!("generated_message_table() -> &'static {}", table_type),
+        |w| {
+            w.write_line(&format!(
+                "static TABLE: {} = {}::rt::table::MessageTable::new(&[",
+                table_type, protobuf_crate
+            ));
+            w.indented(|w| {
+                for f in &fields {
+                    w.write_line(&format!("// {}", f.field.reconstruct_def()));
+                    f.write_entry(w);
+                }
+            });
+            w.write_line("]);");
+            w.write_line("&TABLE");
+        },
+    );
+}
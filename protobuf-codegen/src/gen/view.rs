@@ -0,0 +1,363 @@
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::rt::WireType;
+
+use crate::customize::ctx::CustomizeElemCtx;
+use crate::customize::Customize;
+use crate::gen::code_writer::CodeWriter;
+use crate::gen::field::FieldElem;
+use crate::gen::field::FieldGen;
+use crate::gen::field::FieldKind;
+use crate::gen::inside::protobuf_crate_path;
+use crate::gen::message::MessageGen;
+use crate::gen::oneof::OneofField;
+use crate::gen::rust::EXPR_NONE;
+use crate::gen::rust_name::RustIdent;
+use crate::gen::rust_types_values::is_descriptor_proto;
+use crate::gen::rust_types_values::rust_name;
+use crate::gen::rust_types_values::PrimitiveTypeVariant;
+use crate::gen::rust_types_values::ProtobufTypeGen;
+use crate::gen::scope::WithScope;
+use crate::gen::well_known_types::is_well_known_type_full;
+
+/// How field value is stored and returned in a view.
+enum ViewElem {
+    /// Scalar, `string`, `bytes` or enum, decoded with `ProtobufType` marker.
+    Value {
+        protobuf_type: String,
+        rust_type: String,
+    },
+    /// Message with generated view.
+    Message { view_type: String },
+    /// Message without generated view (e. g. well-known types or messages
+    /// of files generated without views), raw bytes.
+    MessageBytes,
+}
+
+struct ViewFieldGen<'a> {
+    field: &'a FieldGen<'a>,
+    elem: ViewElem,
+    protobuf_crate: String,
+}
+
+impl<'a> ViewFieldGen<'a> {
+    /// Map, group and extension fields are not accessible through views.
+    fn new(field: &'a FieldGen<'a>, ctx: &CustomizeElemCtx) -> Option<ViewFieldGen<'a>> {
+        let customize = &ctx.for_elem;
+        if field.proto_type == Type::TYPE_GROUP {
+            return None;
+        }
+        let elem = match &field.kind {
+            FieldKind::Singular(s) => &s.elem,
+            FieldKind::Oneof(o) => &o.elem,
+            FieldKind::Repeated(r) => &r.elem,
+            FieldKind::Map(..) => return None,
+        };
+        let reference = field.file_and_mod();
+        let elem = match elem {
+            FieldElem::Primitive(t, ..) => ViewElem::Value {
+                protobuf_type: ProtobufTypeGen::Primitive(*t, PrimitiveTypeVariant::Default)
+                    .rust_type(customize),
+                rust_type: match t {
+                    Type::TYPE_STRING => "&'a str".to_owned(),
+                    Type::TYPE_BYTES => "&'a [u8]".to_owned(),
+                    t => rust_name(*t).to_code(customize),
+                },
+            },
+            FieldElem::Enum(en) => ViewElem::Value {
+                protobuf_type: elem.protobuf_type_gen(&reference).rust_type(customize),
+                rust_type: en.enum_or_unknown_rust_type(&reference).to_code(customize),
+            },
+            FieldElem::Message(m) => {
+                let bundled = is_well_known_type_full(&m.message.name_absolute()).is_some()
+                    || is_descriptor_proto(m.message.file_descriptor());
+                // Message may be defined in a file generated without views.
+                let has_view = ctx
+                    .for_file(m.message.scope.file_scope.file_descriptor)
+                    .gen_views
+                    .unwrap_or(false);
+                if bundled || !has_view {
+                    ViewElem::MessageBytes
+                } else {
+                    let mut view_type = m.rust_name_relative(&reference).0;
+                    view_type.ident = RustIdent::new(&format!("{}View", view_type.ident));
+                    ViewElem::Message {
+                        view_type: format!("{}<'a>", view_type),
+                    }
+                }
+            }
+        };
+        Some(ViewFieldGen {
+            field,
+            elem,
+            protobuf_crate: protobuf_crate_path(customize).to_string(),
+        })
+    }
+
+    fn name(&self) -> &RustIdent {
+        &self.field.rust_name
+    }
+
+    fn is_repeated(&self) -> bool {
+        matches!(self.field.kind, FieldKind::Repeated(..))
+    }
+
+    /// `ProtobufType` used to read the field in `parse`.
+    fn read_protobuf_type(&self) -> String {
+        match &self.elem {
+            ViewElem::Value { protobuf_type, .. } => protobuf_type.clone(),
+            ViewElem::Message { .. } | ViewElem::MessageBytes => {
+                format!("{}::reflect::types::ProtobufTypeBytes", self.protobuf_crate)
+            }
+        }
+    }
+
+    fn storage_type(&self) -> String {
+        match &self.elem {
+            ViewElem::Value { rust_type, .. } => {
+                format!("::core::option::Option<{}>", rust_type)
+            }
+            ViewElem::Message { .. } | ViewElem::MessageBytes => {
+                "::core::option::Option<&'a [u8]>".to_owned()
+            }
+        }
+    }
+
+    fn write_struct_field(&self, w: &mut CodeWriter) {
+        if !self.is_repeated() {
+            w.field_decl(self.name().get(), &self.storage_type());
+        }
+    }
+
+    fn oneof(&self) -> Option<&OneofField> {
+        match &self.field.kind {
+            FieldKind::Oneof(o) => Some(o),
+            _ => None,
+        }
+    }
+
+    fn write_parse_case(&self, w: &mut CodeWriter, fields: &[ViewFieldGen]) {
+        let wire_type = WireType::for_type(self.field.proto_type);
+        let mut tags = vec![self.field.tag_with_wire_type(wire_type)];
+        if self.is_repeated() && wire_type != WireType::LengthDelimited {
+            tags.push(self.field.tag_with_wire_type(WireType::LengthDelimited));
+        }
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        w.case_block(tags.join(" | "), |w| {
+            if self.is_repeated() {
+                w.write_line(&format!(
+                    "{}::rt::view_check_repeated::<{}>(&mut input, tag)?;",
+                    self.protobuf_crate,
+                    self.read_protobuf_type(),
+                ));
+                return;
+            }
+            w.write_line(&format!(
+                "view.{} = ::core::option::Option::Some(<{} as {}::view::ViewType>::read(&mut input)?);",
+                self.name(),
+                self.read_protobuf_type(),
+                self.protobuf_crate,
+            ));
+            if let Some(oneof) = self.oneof() {
+                for f in fields {
+                    match f.oneof() {
+                        Some(o)
+                            if o.oneof_field_name == oneof.oneof_field_name
+                                && f.name() != self.name() =>
+                        {
+                            w.write_line(&format!("view.{} = {};", f.name(), EXPR_NONE));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        });
+    }
+
+    fn default_value(&self, customize: &Customize) -> String {
+        if let Some(default_value) = self.field.default_value_from_proto() {
+            return default_value;
+        }
+        match self.field.proto_type {
+            Type::TYPE_STRING => "\"\"".to_owned(),
+            Type::TYPE_BYTES => "b\"\"".to_owned(),
+            t => rust_name(t).default_value(customize, false),
+        }
+    }
+
+    fn write_accessors(&self, w: &mut CodeWriter, customize: &Customize) {
+        let name = self.name();
+        if self.is_repeated() {
+            let elem_type = match &self.elem {
+                ViewElem::Message { view_type } => {
+                    format!(
+                        "{}::view::ViewTypeMessage<{}>",
+                        self.protobuf_crate, view_type
+                    )
+                }
+                _ => self.read_protobuf_type(),
+            };
+            w.pub_fn(
+                &format!(
+                    "{}(&self) -> {}::view::RepeatedView<'a, {}>",
+                    name, self.protobuf_crate, elem_type
+                ),
+                |w| {
+                    w.write_line(&format!(
+                        "{}::view::RepeatedView::new(self.view_bytes, {})",
+                        self.protobuf_crate,
+                        self.field.proto_field.number()
+                    ));
+                },
+            );
+            return;
+        }
+
+        match (&self.elem, self.field.elem()) {
+            (ViewElem::Message { view_type }, _) => {
+                w.pub_fn(
+                    &format!(
+                        "{}(&self) -> {}::Result<::core::option::Option<{}>>",
+                        name, self.protobuf_crate, view_type
+                    ),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}.map(<{} as {}::view::MessageView<'a>>::parse).transpose()",
+                            name, view_type, self.protobuf_crate
+                        ));
+                    },
+                );
+            }
+            (ViewElem::MessageBytes, _) => {
+                w.pub_fn(
+                    &format!("{}(&self) -> ::core::option::Option<&'a [u8]>", name),
+                    |w| {
+                        w.write_line(&format!("self.{}", name));
+                    },
+                );
+            }
+            (ViewElem::Value { .. }, FieldElem::Enum(en)) => {
+                let reference = self.field.file_and_mod();
+                let default_value = en.default_value_rust_expr(&reference);
+                w.pub_fn(
+                    &format!(
+                        "{}(&self) -> {}",
+                        name,
+                        en.enum_rust_type(&reference).to_code(customize)
+                    ),
+                    |w| {
+                        w.match_expr(&format!("self.{}", name), |w| {
+                            w.case_expr(
+                                "::core::option::Option::Some(e)",
+                                &format!("e.enum_value_or({})", default_value),
+                            );
+                            w.case_expr("::core::option::Option::None", &default_value.to_string());
+                        });
+                    },
+                );
+            }
+            (ViewElem::Value { rust_type, .. }, _) => {
+                w.pub_fn(&format!("{}(&self) -> {}", name, rust_type), |w| {
+                    w.write_line(&format!(
+                        "self.{}.unwrap_or({})",
+                        name,
+                        self.default_value(customize)
+                    ));
+                });
+            }
+        }
+
+        if self.field.has_has() {
+            w.write_line("");
+            w.pub_fn(&format!("has_{}(&self) -> bool", name), |w| {
+                w.write_line(&format!("self.{}.is_some()", name));
+            });
+        }
+    }
+}
+
+/// Write `FooView<'a>` for message `Foo`.
+pub(crate) fn write_message_view(message: &MessageGen, ctx: &CustomizeElemCtx, w: &mut CodeWriter) {
+    let customize = &ctx.for_elem;
+    let protobuf_crate = protobuf_crate_path(customize);
+    let message_name = message.message.rust_name();
+    let view_name = format!("{}View", message_name);
+    let fields: Vec<ViewFieldGen> = message
+        .fields
+        .iter()
+        .filter_map(|f| ViewFieldGen::new(f, ctx))
+        .collect();
+
+    w.write_line(&format!(
+        "/// Borrowed read-only view of message `{}`.",
+        message_name
+    ));
+    w.derive(&["Clone", "Copy", "Default", "Debug"]);
+    w.pub_struct(&format!("{}<'a>", view_name), |w| {
+        w.field_decl("view_bytes", "&'a [u8]");
+        for f in &fields {
+            f.write_struct_field(w);
+        }
+    });
+
+    w.write_line("");
+    w.impl_self_block(&format!("<'a> {}<'a>", view_name), |w| {
+        let mut first = true;
+        for f in &fields {
+            if !first {
+                w.write_line("");
+            }
+            first = false;
+            w.write_line(&format!("// {}", f.field.reconstruct_def()));
+            w.write_line("");
+            f.write_accessors(w, customize);
+        }
+    });
+
+    w.write_line("");
+    w.impl_args_for_block(
+        &["'a"],
+        &format!("{}::view::MessageView<'a>", protobuf_crate),
+        &format!("{}<'a>", view_name),
+        |w| {
+            w.write_line(&format!("type Message = {};", message_name));
+            w.write_line("");
+            w.def_fn(
+                &format!(
+                    "parse(bytes: &'a [u8]) -> {}::Result<{}<'a>>",
+                    protobuf_crate, view_name
+                ),
+                |w| {
+                    w.write_line(&format!(
+                        "let mut view = {} {{ view_bytes: bytes, ..::core::default::Default::default() }};",
+                        view_name
+                    ));
+                    w.write_line("let mut input = bytes;");
+                    w.while_block(
+                        &format!(
+                            "let ::core::option::Option::Some(tag) = {}::rt::view_read_tag(&mut input)?",
+                            protobuf_crate
+                        ),
+                        |w| {
+                            w.match_block("tag", |w| {
+                                for f in &fields {
+                                    f.write_parse_case(w, &fields);
+                                }
+                                w.case_block("tag", |w| {
+                                    w.write_line(&format!(
+                                        "{}::rt::view_skip_field(&mut input, tag)?;",
+                                        protobuf_crate
+                                    ));
+                                });
+                            });
+                        },
+                    );
+                    w.write_line("::core::result::Result::Ok(view)");
+                },
+            );
+            w.write_line("");
+            w.def_fn("bytes(&self) -> &'a [u8]", |w| {
+                w.write_line("self.view_bytes");
+            });
+        },
+    );
+}
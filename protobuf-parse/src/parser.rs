@@ -19,6 +19,7 @@ pub struct Parser {
     pub(crate) inputs: Vec<PathBuf>,
     pub(crate) protoc: Option<PathBuf>,
     pub(crate) protoc_extra_args: Vec<OsString>,
+    pub(crate) include_source_info: bool,
 }
 
 impl Parser {
@@ -86,6 +87,13 @@ impl Parser {
         self
     }
 
+    /// Include `source_code_info` (locations and comments)
+    /// in the generated descriptors. Default is `false`.
+    pub fn include_source_info(&mut self, include_source_info: bool) -> &mut Self {
+        self.include_source_info = include_source_info;
+        self
+    }
+
     /// Parse `.proto` files and typecheck them using pure Rust parser of `protoc` command.
     pub fn parse_and_typecheck(&self) -> anyhow::Result<ParsedAndTypechecked> {
         match &self.which_parser {
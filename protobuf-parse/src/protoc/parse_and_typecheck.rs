@@ -33,6 +33,7 @@ pub(crate) fn parse_and_typecheck(parser: &Parser) -> anyhow::Result<ParsedAndTy
         .includes(&parser.includes)
         .out(&temp_file)
         .include_imports(true)
+        .include_source_info(parser.include_source_info)
         .extra_args(&parser.protoc_extra_args)
         .write_descriptor_set()?;
 
@@ -0,0 +1,277 @@
+//! Attribution of comments to declarations.
+//!
+//! Comments are split into trailing comments of the previous declaration,
+//! detached comments and leading comments of the next declaration
+//! exactly like `protoc` tokenizer does it.
+
+/// Comments found between two tokens.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Comments {
+    /// Comment on the same line (or the lines right after) the previous token.
+    pub prev_trailing: String,
+    /// Comments separated by blank lines from both tokens.
+    pub detached: Vec<String>,
+    /// Comment right before the next token.
+    pub next_leading: String,
+}
+
+struct Collector {
+    comments: Comments,
+    buffer: String,
+    has_comment: bool,
+    is_line_comment: bool,
+    can_attach_to_prev: bool,
+}
+
+impl Collector {
+    fn buffer_for_line_comment(&mut self) -> &mut String {
+        // Combine with previous line comments, but not block comments
+        if self.has_comment && !self.is_line_comment {
+            self.flush();
+        }
+        self.has_comment = true;
+        self.is_line_comment = true;
+        &mut self.buffer
+    }
+
+    fn buffer_for_block_comment(&mut self) -> &mut String {
+        if self.has_comment {
+            self.flush();
+        }
+        self.has_comment = true;
+        self.is_line_comment = false;
+        &mut self.buffer
+    }
+
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.has_comment = false;
+    }
+
+    fn flush(&mut self) {
+        if self.has_comment {
+            if self.can_attach_to_prev {
+                self.comments.prev_trailing.push_str(&self.buffer);
+                self.can_attach_to_prev = false;
+            } else {
+                self.comments.detached.push(self.buffer.clone());
+            }
+            self.clear_buffer();
+        }
+    }
+
+    fn finish(mut self) -> Comments {
+        if self.has_comment {
+            self.comments.next_leading = self.buffer;
+        }
+        self.comments
+    }
+}
+
+struct Gap<'a> {
+    rem: &'a str,
+}
+
+enum CommentStart {
+    Line,
+    Block,
+    None,
+}
+
+impl<'a> Gap<'a> {
+    fn skip_whitespace_no_newline(&mut self) {
+        self.rem = self
+            .rem
+            .trim_start_matches([' ', '\t', '\r', '\x0b', '\x0c']);
+    }
+
+    fn skip_if_eq(&mut self, s: &str) -> bool {
+        match self.rem.strip_prefix(s) {
+            Some(rem) => {
+                self.rem = rem;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn comment_start(&mut self) -> CommentStart {
+        if self.skip_if_eq("//") {
+            CommentStart::Line
+        } else if self.skip_if_eq("/*") {
+            CommentStart::Block
+        } else {
+            CommentStart::None
+        }
+    }
+
+    fn line_comment(&mut self, content: &mut String) {
+        let len = self.rem.find('\n').map_or(self.rem.len(), |p| p + 1);
+        content.push_str(&self.rem[..len]);
+        self.rem = &self.rem[len..];
+    }
+
+    fn block_comment(&mut self, content: &mut String) {
+        loop {
+            let len = self.rem.find(&['*', '\n'][..]).unwrap_or(self.rem.len());
+            content.push_str(&self.rem[..len]);
+            self.rem = &self.rem[len..];
+            if self.skip_if_eq("\n") {
+                content.push('\n');
+                // Strip leading whitespace and asterisk
+                self.skip_whitespace_no_newline();
+                if self.skip_if_eq("*") && self.skip_if_eq("/") {
+                    return;
+                }
+            } else if self.skip_if_eq("*/") || self.rem.is_empty() {
+                return;
+            } else {
+                content.push('*');
+                self.rem = &self.rem[1..];
+            }
+        }
+    }
+}
+
+/// Split comments between two tokens.
+///
+/// `gap` is the text between the tokens, it must contain
+/// only whitespace and comments. When `after_start` is set,
+/// there's no previous token (`gap` is the start of the file).
+/// `end_of_scope` means the next token is `}`, `]`, `)` or the end of file,
+/// so comments before it cannot be attached to it.
+pub(crate) fn split_comments(gap: &str, after_start: bool, end_of_scope: bool) -> Comments {
+    let mut collector = Collector {
+        comments: Comments::default(),
+        buffer: String::new(),
+        has_comment: false,
+        is_line_comment: false,
+        can_attach_to_prev: true,
+    };
+    let mut gap = Gap { rem: gap };
+
+    if after_start {
+        collector.can_attach_to_prev = false;
+    } else {
+        // A comment on the same line is attached to the previous declaration
+        gap.skip_whitespace_no_newline();
+        match gap.comment_start() {
+            CommentStart::Line => {
+                gap.line_comment(collector.buffer_for_line_comment());
+                collector.flush();
+            }
+            CommentStart::Block => {
+                gap.block_comment(collector.buffer_for_block_comment());
+                gap.skip_whitespace_no_newline();
+                if !gap.skip_if_eq("\n") {
+                    // The next token is on the same line,
+                    // so it is not clear what the comment belongs to
+                    collector.clear_buffer();
+                    return collector.finish();
+                }
+                collector.flush();
+            }
+            CommentStart::None => {
+                if !gap.skip_if_eq("\n") {
+                    return collector.finish();
+                }
+            }
+        }
+    }
+
+    loop {
+        gap.skip_whitespace_no_newline();
+        match gap.comment_start() {
+            CommentStart::Line => {
+                gap.line_comment(collector.buffer_for_line_comment());
+            }
+            CommentStart::Block => {
+                gap.block_comment(collector.buffer_for_block_comment());
+                gap.skip_whitespace_no_newline();
+                gap.skip_if_eq("\n");
+            }
+            CommentStart::None => {
+                if gap.skip_if_eq("\n") {
+                    // Blank line
+                    collector.flush();
+                    collector.can_attach_to_prev = false;
+                } else {
+                    if end_of_scope {
+                        collector.flush();
+                    }
+                    return collector.finish();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn comments(prev_trailing: &str, detached: &[&str], next_leading: &str) -> Comments {
+        Comments {
+            prev_trailing: prev_trailing.to_owned(),
+            detached: detached.iter().map(|&s| s.to_owned()).collect(),
+            next_leading: next_leading.to_owned(),
+        }
+    }
+
+    #[test]
+    fn same_line() {
+        assert_eq!(comments("", &[], ""), split_comments(" ", false, false));
+        assert_eq!(
+            comments(" t\n", &[], ""),
+            split_comments(" // t\n  ", false, false)
+        );
+        assert_eq!(
+            comments("", &[], ""),
+            split_comments(" /* x */ ", false, false)
+        );
+    }
+
+    #[test]
+    fn trailing_and_leading() {
+        assert_eq!(
+            comments(" t\n", &[" d\n"], " l1\n l2\n"),
+            split_comments(" // t\n\n// d\n\n  // l1\n  // l2\n  ", false, false)
+        );
+    }
+
+    #[test]
+    fn next_line_trailing() {
+        assert_eq!(
+            comments(" t\n", &[], " l\n"),
+            split_comments("\n  // t\n\n  // l\n  ", false, false)
+        );
+    }
+
+    #[test]
+    fn block() {
+        assert_eq!(
+            comments("", &[], " Block\n comment "),
+            split_comments("\n\n/* Block\n * comment */\n", false, false)
+        );
+        assert_eq!(
+            comments("", &[" a "], " b\n"),
+            split_comments("\n\n/* a */\n// b\n", false, false)
+        );
+    }
+
+    #[test]
+    fn end_of_scope() {
+        assert_eq!(
+            comments(" t\n", &[" d\n"], ""),
+            split_comments("\n  // t\n\n  // d\n", false, true)
+        );
+    }
+
+    #[test]
+    fn start_of_file() {
+        assert_eq!(
+            comments("", &[" a\n"], " b\n"),
+            split_comments("// a\n\n// b\n", true, false)
+        );
+    }
+}
@@ -3,6 +3,8 @@
 mod option_resolver;
 mod type_resolver;
 
+use std::collections::HashSet;
+
 use protobuf;
 use protobuf::descriptor::descriptor_proto::ReservedRange;
 use protobuf::descriptor::field_descriptor_proto;
@@ -284,6 +286,8 @@ impl<'a> Resolver<'a> {
             output.field = fields;
         }
 
+        Self::generate_synthetic_oneofs(&mut output);
+
         for ext in &input.extension_ranges {
             let mut extension_range = protobuf::descriptor::descriptor_proto::ExtensionRange::new();
             extension_range.set_start(ext.from);
@@ -312,6 +316,32 @@ impl<'a> Resolver<'a> {
         Ok(output)
     }
 
+    /// Add single-field oneof for each proto3 optional field
+    /// the same way `protoc` does it.
+    fn generate_synthetic_oneofs(message: &mut protobuf::descriptor::DescriptorProto) {
+        let mut names: HashSet<String> = HashSet::new();
+        names.extend(message.field.iter().map(|f| f.name().to_owned()));
+        names.extend(message.oneof_decl.iter().map(|o| o.name().to_owned()));
+
+        for field in &mut message.field {
+            if !field.proto3_optional() {
+                continue;
+            }
+            let mut oneof_name = field.name().to_owned();
+            if !oneof_name.starts_with('_') {
+                oneof_name.insert(0, '_');
+            }
+            while names.contains(&oneof_name) {
+                oneof_name.insert(0, 'X');
+            }
+            names.insert(oneof_name.clone());
+            field.set_oneof_index(message.oneof_decl.len() as i32);
+            let mut oneof = protobuf::descriptor::OneofDescriptorProto::new();
+            oneof.set_name(oneof_name);
+            message.oneof_decl.push(oneof);
+        }
+    }
+
     fn service_method(
         &self,
         input: &model::Method,
@@ -331,6 +361,12 @@ impl<'a> Resolver<'a> {
                 .full_name
                 .to_string(),
         );
+        if input.client_streaming {
+            output.set_client_streaming(true);
+        }
+        if input.server_streaming {
+            output.set_server_streaming(true);
+        }
         Ok(output)
     }
 
@@ -363,6 +399,12 @@ impl<'a> Resolver<'a> {
             output.set_label(protobuf::descriptor::field_descriptor_proto::Label::LABEL_REPEATED);
         } else {
             output.set_label(label(input.t.rule));
+
+            if input.t.rule == Some(model::Rule::Optional)
+                && self.current_file.syntax == model::Syntax::Proto3
+            {
+                output.set_proto3_optional(true);
+            }
         }
 
         let t = self.field_type(scope, &input.t.name, &input.t.typ)?;
@@ -551,15 +593,15 @@ fn syntax(input: model::Syntax) -> String {
     }
 }
 
-fn label(input: model::Rule) -> protobuf::descriptor::field_descriptor_proto::Label {
+fn label(input: Option<model::Rule>) -> protobuf::descriptor::field_descriptor_proto::Label {
     match input {
-        model::Rule::Optional => {
+        Some(model::Rule::Optional) | None => {
             protobuf::descriptor::field_descriptor_proto::Label::LABEL_OPTIONAL
         }
-        model::Rule::Required => {
+        Some(model::Rule::Required) => {
             protobuf::descriptor::field_descriptor_proto::Label::LABEL_REQUIRED
         }
-        model::Rule::Repeated => {
+        Some(model::Rule::Repeated) => {
             protobuf::descriptor::field_descriptor_proto::Label::LABEL_REPEATED
         }
     }
@@ -585,6 +627,7 @@ pub(crate) fn file_descriptor(
     name: &ProtoPath,
     input: &model::FileDescriptor,
     deps: &[FileDescriptorPair],
+    include_source_info: bool,
 ) -> anyhow::Result<protobuf::descriptor::FileDescriptorProto> {
     let resolver = Resolver {
         current_file: &input,
@@ -652,7 +695,7 @@ pub(crate) fn file_descriptor(
     let descriptor_without_options = FileDescriptor::new_dynamic(
         output.clone(),
         deps.iter().map(|d| d.descriptor.clone()).collect(),
-    );
+    )?;
 
     let option_resolver = OptionResoler {
         resolver: &resolver,
@@ -661,5 +704,9 @@ pub(crate) fn file_descriptor(
 
     option_resolver.file(&mut output)?;
 
+    if include_source_info {
+        output.source_code_info = Some(option_resolver.source_code_info()?).into();
+    }
+
     Ok(output)
 }
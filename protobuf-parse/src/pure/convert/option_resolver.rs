@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
 use protobuf::descriptor::DescriptorProto;
 use protobuf::descriptor::EnumDescriptorProto;
@@ -7,6 +9,7 @@ use protobuf::descriptor::FileDescriptorProto;
 use protobuf::descriptor::MethodDescriptorProto;
 use protobuf::descriptor::OneofDescriptorProto;
 use protobuf::descriptor::ServiceDescriptorProto;
+use protobuf::descriptor::SourceCodeInfo;
 use protobuf::reflect::FieldDescriptor;
 use protobuf::reflect::FileDescriptor;
 use protobuf::reflect::MessageDescriptor;
@@ -16,6 +19,7 @@ use protobuf::UnknownFields;
 use protobuf::UnknownValue;
 
 use crate::model;
+use crate::model::OptionsKind;
 use crate::model::ProtobufConstant;
 use crate::model::ProtobufConstantMessage;
 use crate::model::ProtobufConstantMessageFieldName;
@@ -944,6 +948,111 @@ impl<'a> OptionResoler<'a> {
         Ok(())
     }
 
+    /// Path of field numbers to the option field in options message `M`,
+    /// and whether that field is repeated.
+    fn option_path<M>(
+        &self,
+        scope: &ProtobufAbsPathRef,
+        option_name: &ProtobufOptionName,
+    ) -> anyhow::Result<(Vec<i32>, bool)>
+    where
+        M: MessageFull,
+    {
+        let options_type = M::descriptor_static();
+        match option_name {
+            ProtobufOptionName::Builtin(simple) => match options_type.field_by_name(simple.get()) {
+                Some(field) => Ok((vec![field.proto().number()], field.is_repeated())),
+                None => Err(OptionResolverError::BuiltinOptionNotFound(
+                    options_type.full_name().to_owned(),
+                    simple.get().to_owned(),
+                )
+                .into()),
+            },
+            ProtobufOptionName::Ext(e) => {
+                let mut path = Vec::new();
+                let mut field = self.ext_resolve_field(scope, &options_type, &e.0[0])?;
+                for part in &e.0[1..] {
+                    path.push(field.proto().number());
+                    let message = match TypeResolved::from_field(field.proto()) {
+                        TypeResolved::Message(name) | TypeResolved::Group(name) => {
+                            self.find_message_by_abs_name(&name)?
+                        }
+                        _ => {
+                            return Err(OptionResolverError::ExtensionIsNotMessage(format!(
+                                "scope: {}, option name: {}",
+                                scope, option_name
+                            ))
+                            .into())
+                        }
+                    };
+                    field = self.ext_resolve_field(scope, &message, part)?;
+                }
+                path.push(field.proto().number());
+                Ok((path, field.is_repeated()))
+            }
+        }
+    }
+
+    /// Source code info with option locations pointing to the resolved option fields.
+    pub(crate) fn source_code_info(&self) -> anyhow::Result<SourceCodeInfo> {
+        let mut source_code_info = SourceCodeInfo::new();
+        // Like `protoc`, locations of repeated options are indexed in declaration order
+        let mut repeated_option_counts: HashMap<Vec<i32>, i32> = HashMap::new();
+        for location in &self.resolver.current_file.source_locations {
+            let mut path = location.path.clone();
+            if let Some(option) = &location.option {
+                let mut scope = self.resolver.current_file.package.clone();
+                for name in &option.scope {
+                    scope.push_simple(ProtobufIdentRef::new(name));
+                }
+                let (option_path, repeated) = match option.kind {
+                    OptionsKind::File => {
+                        self.option_path::<protobuf::descriptor::FileOptions>(&scope, &option.name)
+                    }
+                    OptionsKind::Message => self
+                        .option_path::<protobuf::descriptor::MessageOptions>(&scope, &option.name),
+                    OptionsKind::Field => {
+                        self.option_path::<protobuf::descriptor::FieldOptions>(&scope, &option.name)
+                    }
+                    OptionsKind::Oneof => {
+                        self.option_path::<protobuf::descriptor::OneofOptions>(&scope, &option.name)
+                    }
+                    OptionsKind::Enum => {
+                        self.option_path::<protobuf::descriptor::EnumOptions>(&scope, &option.name)
+                    }
+                    OptionsKind::EnumValue => self
+                        .option_path::<protobuf::descriptor::EnumValueOptions>(
+                            &scope,
+                            &option.name,
+                        ),
+                    OptionsKind::Service => self
+                        .option_path::<protobuf::descriptor::ServiceOptions>(&scope, &option.name),
+                    OptionsKind::Method => self
+                        .option_path::<protobuf::descriptor::MethodOptions>(&scope, &option.name),
+                }?;
+                path.extend(option_path);
+                if repeated {
+                    let count = repeated_option_counts.entry(path.clone()).or_insert(0);
+                    path.push(*count);
+                    *count += 1;
+                }
+            }
+
+            let mut output = protobuf::descriptor::source_code_info::Location::new();
+            output.path = path;
+            output.span = location.span.clone();
+            if !location.leading_comments.is_empty() {
+                output.set_leading_comments(location.leading_comments.clone());
+            }
+            if !location.trailing_comments.is_empty() {
+                output.set_trailing_comments(location.trailing_comments.clone());
+            }
+            output.leading_detached_comments = location.leading_detached_comments.clone();
+            source_code_info.location.push(output);
+        }
+        Ok(source_code_info)
+    }
+
     pub(crate) fn file(&self, output: &mut FileDescriptorProto) -> anyhow::Result<()> {
         // TODO: use it to resolve messages.
         let _ = &self.descriptor_without_options;
@@ -1,10 +1,12 @@
 //! Pure rust `.proto` file parser.
 
+mod comments;
 pub(crate) mod convert;
 pub(crate) mod model;
 pub(crate) mod parse_and_typecheck;
 pub(crate) mod parse_dependencies;
 mod parser;
+mod source_info;
 
 pub use parse_and_typecheck::parse_and_typecheck_custom;
 pub use parse_dependencies::*;
@@ -185,8 +185,8 @@ pub(crate) enum FieldType {
 pub(crate) struct Field {
     /// Field name
     pub name: String,
-    /// Field `Rule`
-    pub rule: Rule,
+    /// Field `Rule`, `None` if label is not specified
+    pub rule: Option<Rule>,
     /// Field type
     pub typ: FieldType,
     /// Tag number
@@ -564,6 +564,45 @@ pub(crate) struct FileDescriptor {
     pub services: Vec<WithLoc<Service>>,
     /// Non-builtin options
     pub options: Vec<ProtobufOption>,
+    /// Locations of declarations, in the order of `SourceCodeInfo`
+    pub source_locations: Vec<SourceLocation>,
+}
+
+/// Kind of options message an option belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum OptionsKind {
+    File,
+    Message,
+    Field,
+    Oneof,
+    Enum,
+    EnumValue,
+    Service,
+    Method,
+}
+
+/// Option which location path is not known until the option is resolved
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SourceLocationOption {
+    pub kind: OptionsKind,
+    /// Names of messages (relative to the package) option name is resolved in
+    pub scope: Vec<String>,
+    pub name: ProtobufOptionName,
+}
+
+/// Location of a declaration, converted to `SourceCodeInfo.Location`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SourceLocation {
+    /// Path in `FileDescriptorProto`, for options it is path to options message
+    pub path: Vec<i32>,
+    /// Zero-based `[start_line, start_col, end_line, end_col]`,
+    /// `end_line` is omitted when it is the same as `start_line`
+    pub span: Vec<i32>,
+    pub leading_comments: String,
+    pub trailing_comments: String,
+    pub leading_detached_comments: Vec<String>,
+    /// Set for option locations
+    pub option: Option<SourceLocationOption>,
 }
 
 impl FileDescriptor {
@@ -53,6 +53,7 @@ where
 {
     parsed_files: IndexMap<ProtoPathBuf, FileDescriptorPair>,
     resolver: R,
+    include_source_info: bool,
 }
 
 impl<R> Run<R>
@@ -109,18 +110,23 @@ where
 
         let this_file_deps: Vec<_> = this_file_deps.into_iter().map(|(_, v)| v).collect();
 
-        let descriptor_proto = convert::file_descriptor(protobuf_path, &parsed, &this_file_deps)
-            .map_err(|e| WithFileError {
-                file: resolved.path.clone(),
-                error: e.into(),
-            })?;
+        let descriptor_proto = convert::file_descriptor(
+            protobuf_path,
+            &parsed,
+            &this_file_deps,
+            self.include_source_info,
+        )
+        .map_err(|e| WithFileError {
+            file: resolved.path.clone(),
+            error: e.into(),
+        })?;
 
         let deps = self
             .parsed_files
             .values()
             .map(|v| v.descriptor.clone())
             .collect();
-        let descriptor = FileDescriptor::new_dynamic(descriptor_proto.clone(), deps);
+        let descriptor = FileDescriptor::new_dynamic(descriptor_proto.clone(), deps)?;
 
         self.parsed_files.insert(
             protobuf_path.to_proto_path_buf(),
@@ -253,6 +259,7 @@ pub fn parse_and_typecheck(parser: &Parser) -> anyhow::Result<ParsedAndTypecheck
     let mut run = Run {
         parsed_files: IndexMap::new(),
         resolver: fs_resolver(&parser.includes),
+        include_source_info: parser.include_source_info,
     };
 
     let relative_paths = parser
@@ -294,6 +301,7 @@ pub fn parse_and_typecheck_custom(
     let mut run = Run {
         parsed_files: IndexMap::new(),
         resolver,
+        include_source_info: false,
     };
 
     for proto_path in input {
@@ -1,3 +1,4 @@
+use std::mem;
 use std::str;
 
 use protobuf::text_format::lexer::int;
@@ -30,6 +31,7 @@ use crate::pure::model::ImportVis;
 use crate::pure::model::Message;
 use crate::pure::model::Method;
 use crate::pure::model::OneOf;
+use crate::pure::model::OptionsKind;
 use crate::pure::model::ProtobufConstant;
 use crate::pure::model::ProtobufConstantMessage;
 use crate::pure::model::ProtobufOption;
@@ -38,8 +40,10 @@ use crate::pure::model::ProtobufOptionNameExt;
 use crate::pure::model::ProtobufOptionNamePart;
 use crate::pure::model::Rule;
 use crate::pure::model::Service;
+use crate::pure::model::SourceLocationOption;
 use crate::pure::model::Syntax;
 use crate::pure::model::WithLoc;
+use crate::pure::source_info::*;
 
 /// Basic information about parsing error.
 #[derive(Debug, thiserror::Error)]
@@ -180,8 +184,78 @@ impl ToU8 for u32 {
 
 #[derive(Clone)]
 pub(crate) struct Parser<'a> {
+    input: &'a str,
     pub tokenizer: Tokenizer<'a>,
     syntax: Syntax,
+    source_info: SourceInfoRecorder,
+    /// File and messages being parsed, innermost last
+    scopes: Vec<DescriptorScope>,
+}
+
+/// File or message being parsed.
+///
+/// Counts elements parsed so far to compute location paths.
+#[derive(Clone, Default)]
+struct DescriptorScope {
+    /// Path to the message, empty for file
+    path: Vec<i32>,
+    /// Message name, `None` for file
+    name: Option<String>,
+    fields: i32,
+    /// Top-level messages for file
+    nested_types: i32,
+    enums: i32,
+    extension_ranges: i32,
+    extensions: i32,
+    oneofs: i32,
+    reserved_ranges: i32,
+    reserved_names: i32,
+    dependencies: i32,
+    public_dependencies: i32,
+    weak_dependencies: i32,
+    services: i32,
+}
+
+fn next_index(counter: &mut i32) -> i32 {
+    *counter += 1;
+    *counter - 1
+}
+
+impl DescriptorScope {
+    fn is_file(&self) -> bool {
+        self.name.is_none()
+    }
+
+    fn path(&self, suffix: &[i32]) -> Vec<i32> {
+        let mut path = self.path.clone();
+        path.extend_from_slice(suffix);
+        path
+    }
+
+    fn next_nested_type_path(&mut self) -> Vec<i32> {
+        let field = match self.is_file() {
+            true => FILE_MESSAGE_TYPE,
+            false => MESSAGE_NESTED_TYPE,
+        };
+        let index = next_index(&mut self.nested_types);
+        self.path(&[field, index])
+    }
+
+    fn next_enum_path(&mut self) -> Vec<i32> {
+        let field = match self.is_file() {
+            true => FILE_ENUM_TYPE,
+            false => MESSAGE_ENUM_TYPE,
+        };
+        let index = next_index(&mut self.enums);
+        self.path(&[field, index])
+    }
+
+    fn extensions_path(&self) -> Vec<i32> {
+        match self.is_file() {
+            true => self.path(&[FILE_EXTENSION]),
+            false => self.path(&[MESSAGE_EXTENSION]),
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -286,11 +360,135 @@ impl NumLitEx for NumLit {
 impl<'a> Parser<'a> {
     pub(crate) fn new(input: &'a str) -> Parser<'a> {
         Parser {
+            input,
             tokenizer: Tokenizer::new(input, ParserLanguage::Proto),
             syntax: Syntax::Proto2,
+            source_info: SourceInfoRecorder::default(),
+            scopes: vec![DescriptorScope::default()],
+        }
+    }
+
+    // Source code info
+
+    fn scope(&mut self) -> &mut DescriptorScope {
+        self.scopes.last_mut().unwrap()
+    }
+
+    fn child_path(&self, location: LocationId, suffix: &[i32]) -> Vec<i32> {
+        let mut path = self.source_info.path(location).to_vec();
+        path.extend_from_slice(suffix);
+        path
+    }
+
+    /// Start location at the next token.
+    fn location_start(&mut self, path: Vec<i32>) -> LocationId {
+        let start = self.tokenizer.lookahead_pos();
+        self.source_info.start(path, start)
+    }
+
+    /// End location at the last consumed token.
+    fn location_end(&mut self, location: LocationId) {
+        let end = self.tokenizer.last_token_end_pos();
+        self.source_info.end(location, end);
+    }
+
+    /// Record location of tokens consumed by `parse`.
+    fn with_location<R>(
+        &mut self,
+        path: Vec<i32>,
+        parse: impl FnOnce(&mut Self) -> anyhow::Result<R>,
+    ) -> anyhow::Result<R> {
+        let location = self.location_start(path);
+        let r = parse(self)?;
+        self.location_end(location);
+        Ok(r)
+    }
+
+    /// Start and end of tokens consumed by `parse`.
+    fn with_span<R>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> anyhow::Result<R>,
+    ) -> anyhow::Result<(R, (usize, usize))> {
+        let start = self.tokenizer.lookahead_pos();
+        let r = parse(self)?;
+        Ok((r, (start, self.tokenizer.last_token_end_pos())))
+    }
+
+    fn location_with_span(&mut self, path: Vec<i32>, (start, end): (usize, usize)) {
+        let location = self.source_info.start(path, start);
+        self.source_info.end(location, end);
+    }
+
+    /// Mark the location as a location of the option `name`.
+    fn set_option_location(
+        &mut self,
+        location: LocationId,
+        kind: OptionsKind,
+        name: &ProtobufOptionName,
+    ) {
+        // Options are resolved in the scope of the containing message,
+        // message options are resolved in the message parent scope
+        let scopes = match kind {
+            OptionsKind::Message => &self.scopes[..self.scopes.len() - 1],
+            _ => &self.scopes[..],
+        };
+        let scope = scopes.iter().flat_map(|s| s.name.clone()).collect();
+        self.source_info.set_option(
+            location,
+            SourceLocationOption {
+                kind,
+                scope,
+                name: name.clone(),
+            },
+        );
+    }
+
+    /// Consume the token which ends a declaration (`;`, `{` or `}`)
+    /// and attach comments around it to the declaration.
+    fn next_end_of_declaration(
+        &mut self,
+        symbol: char,
+        desc: &'static str,
+        location: Option<LocationId>,
+    ) -> anyhow::Result<()> {
+        self.tokenizer.next_symbol_expect_eq(symbol, desc)?;
+        self.end_of_declaration(symbol, location)
+    }
+
+    fn next_end_of_declaration_if(
+        &mut self,
+        symbol: char,
+        location: Option<LocationId>,
+    ) -> anyhow::Result<bool> {
+        if self.tokenizer.next_symbol_if_eq(symbol)? {
+            self.end_of_declaration(symbol, location)?;
+            Ok(true)
+        } else {
+            Ok(false)
         }
     }
 
+    fn end_of_declaration(
+        &mut self,
+        symbol: char,
+        location: Option<LocationId>,
+    ) -> anyhow::Result<()> {
+        let start = self.tokenizer.last_token_end_pos();
+        let end = self.tokenizer.lookahead_pos();
+        let end_of_scope = self.tokenizer.syntax_eof()?
+            || matches!(
+                self.tokenizer.lookahead_if_symbol()?,
+                Some('}') | Some(']') | Some(')')
+            );
+        self.source_info.end_of_declaration(
+            symbol,
+            location,
+            &self.input[start..end],
+            end_of_scope,
+        );
+        Ok(())
+    }
+
     // Protobuf grammar
 
     // fullIdent = ident { "." ident }
@@ -321,7 +519,7 @@ impl<'a> Parser<'a> {
 
     // emptyStatement = ";"
     fn next_empty_statement_opt(&mut self) -> anyhow::Result<Option<()>> {
-        if self.tokenizer.next_symbol_if_eq(';')? {
+        if self.next_end_of_declaration_if(';', None)? {
             Ok(Some(()))
         } else {
             Ok(None)
@@ -339,12 +537,12 @@ impl<'a> Parser<'a> {
     // groupName = capitalLetter { letter | decimalDigit | "_" }
     fn next_group_name(&mut self) -> anyhow::Result<String> {
         // lexer cannot distinguish between group name and other ident
-        let mut clone = self.clone();
-        let ident = clone.tokenizer.next_ident()?;
+        let mut clone = self.tokenizer.clone();
+        let ident = clone.next_ident()?;
         if !ident.chars().next().unwrap().is_ascii_uppercase() {
             return Err(ParserError::GroupNameShouldStartWithUpperCase.into());
         }
-        *self = clone;
+        self.tokenizer = clone;
         Ok(ident)
     }
 
@@ -468,7 +666,9 @@ impl<'a> Parser<'a> {
     // syntax = "syntax" "=" quote "proto2" quote ";"
     // syntax = "syntax" "=" quote "proto3" quote ";"
     fn next_syntax(&mut self) -> anyhow::Result<Option<Syntax>> {
-        if self.tokenizer.next_ident_if_eq("syntax")? {
+        if self.tokenizer.lookahead_is_ident("syntax")? {
+            let location = self.location_start(vec![FILE_SYNTAX]);
+            self.tokenizer.next_ident_expect_eq("syntax")?;
             self.tokenizer.next_symbol_expect_eq('=', "syntax")?;
             let syntax_str = self.tokenizer.next_str_lit()?.decode_utf8()?;
             let syntax = if syntax_str == "proto2" {
@@ -478,7 +678,8 @@ impl<'a> Parser<'a> {
             } else {
                 return Err(ParserError::UnknownSyntax.into());
             };
-            self.tokenizer.next_symbol_expect_eq(';', "syntax")?;
+            self.next_end_of_declaration(';', "syntax", Some(location))?;
+            self.location_end(location);
             Ok(Some(syntax))
         } else {
             Ok(None)
@@ -489,16 +690,28 @@ impl<'a> Parser<'a> {
 
     // import = "import" [ "weak" | "public" ] strLit ";"
     fn next_import_opt(&mut self) -> anyhow::Result<Option<model::Import>> {
-        if self.tokenizer.next_ident_if_eq("import")? {
-            let vis = if self.tokenizer.next_ident_if_eq("weak")? {
+        if self.tokenizer.lookahead_is_ident("import")? {
+            let index = next_index(&mut self.scope().dependencies);
+            let location = self.location_start(vec![FILE_DEPENDENCY, index]);
+            self.tokenizer.next_ident_expect_eq("import")?;
+            let vis = if self.tokenizer.lookahead_is_ident("weak")? {
+                let index = next_index(&mut self.scope().weak_dependencies);
+                self.with_location(vec![FILE_WEAK_DEPENDENCY, index], |p| {
+                    Ok(p.tokenizer.next_ident_expect_eq("weak")?)
+                })?;
                 ImportVis::Weak
-            } else if self.tokenizer.next_ident_if_eq("public")? {
+            } else if self.tokenizer.lookahead_is_ident("public")? {
+                let index = next_index(&mut self.scope().public_dependencies);
+                self.with_location(vec![FILE_PUBLIC_DEPENDENCY, index], |p| {
+                    Ok(p.tokenizer.next_ident_expect_eq("public")?)
+                })?;
                 ImportVis::Public
             } else {
                 ImportVis::Default
             };
             let path = self.tokenizer.next_str_lit()?.decode_utf8()?;
-            self.tokenizer.next_symbol_expect_eq(';', "import")?;
+            self.next_end_of_declaration(';', "import", Some(location))?;
+            self.location_end(location);
             let path = ProtoPathBuf::new(path)?;
             Ok(Some(model::Import { path, vis }))
         } else {
@@ -510,9 +723,12 @@ impl<'a> Parser<'a> {
 
     // package = "package" fullIdent ";"
     fn next_package_opt(&mut self) -> anyhow::Result<Option<ProtobufAbsPath>> {
-        if self.tokenizer.next_ident_if_eq("package")? {
+        if self.tokenizer.lookahead_is_ident("package")? {
+            let location = self.location_start(vec![FILE_PACKAGE]);
+            self.tokenizer.next_ident_expect_eq("package")?;
             let package = self.next_full_ident_rel()?;
-            self.tokenizer.next_symbol_expect_eq(';', "package")?;
+            self.next_end_of_declaration(';', "package", Some(location))?;
+            self.location_end(location);
             Ok(Some(package.into_absolute()))
         } else {
             Ok(None)
@@ -553,43 +769,74 @@ impl<'a> Parser<'a> {
     }
 
     // option = "option" optionName  "=" constant ";"
-    fn next_option_opt(&mut self) -> anyhow::Result<Option<ProtobufOption>> {
-        if self.tokenizer.next_ident_if_eq("option")? {
+    fn next_option_opt(
+        &mut self,
+        options_path: Vec<i32>,
+        kind: OptionsKind,
+    ) -> anyhow::Result<Option<ProtobufOption>> {
+        if self.tokenizer.lookahead_is_ident("option")? {
+            let statement_location = self.location_start(options_path.clone());
+            let location = self.location_start(options_path);
+            self.tokenizer.next_ident_expect_eq("option")?;
             let name = self.next_option_name()?;
             self.tokenizer.next_symbol_expect_eq('=', "option")?;
             let value = self.next_constant()?;
-            self.tokenizer.next_symbol_expect_eq(';', "option")?;
+            self.next_end_of_declaration(';', "option", Some(location))?;
+            self.location_end(location);
+            self.location_end(statement_location);
+            self.set_option_location(location, kind, &name);
             Ok(Some(ProtobufOption { name, value }))
         } else {
             Ok(None)
         }
     }
 
+    // optionAssignment = optionName "=" constant
+    fn next_option_assignment(
+        &mut self,
+        options_location: LocationId,
+        kind: OptionsKind,
+        desc: &'static str,
+    ) -> anyhow::Result<ProtobufOption> {
+        let location = self.location_start(self.child_path(options_location, &[]));
+        let name = self.next_option_name()?;
+        self.tokenizer.next_symbol_expect_eq('=', desc)?;
+        let value = self.next_constant()?;
+        self.location_end(location);
+        self.set_option_location(location, kind, &name);
+        Ok(ProtobufOption { name, value })
+    }
+
     // Fields
 
     // label = "required" | "optional" | "repeated"
-    fn next_label(&mut self, mode: MessageBodyParseMode) -> anyhow::Result<Rule> {
+    fn next_label(
+        &mut self,
+        mode: MessageBodyParseMode,
+        field_location: LocationId,
+    ) -> anyhow::Result<Option<Rule>> {
         let map = &[
             ("optional", Rule::Optional),
             ("required", Rule::Required),
             ("repeated", Rule::Repeated),
         ];
         for &(name, value) in map {
-            let mut clone = self.clone();
-            if clone.tokenizer.next_ident_if_eq(name)? {
+            if self.tokenizer.lookahead_is_ident(name)? {
                 if !mode.label_allowed(value) {
                     return Err(ParserError::LabelNotAllowed.into());
                 }
 
-                *self = clone;
-                return Ok(value);
+                self.with_location(self.child_path(field_location, &[FIELD_LABEL]), |p| {
+                    Ok(p.tokenizer.next_ident_expect_eq(name)?)
+                })?;
+                return Ok(Some(value));
             }
         }
 
         if mode.some_label_required() {
             Err(ParserError::LabelRequired.into())
         } else {
-            Ok(Rule::Optional)
+            Ok(None)
         }
     }
 
@@ -634,49 +881,107 @@ impl<'a> Parser<'a> {
     }
 
     // fieldOption = optionName "=" constant
-    fn next_field_option(&mut self) -> anyhow::Result<ProtobufOption> {
-        let name = self.next_option_name()?;
-        self.tokenizer.next_symbol_expect_eq('=', "field option")?;
-        let value = self.next_constant()?;
-        Ok(ProtobufOption { name, value })
+    fn next_field_option(
+        &mut self,
+        field_location: LocationId,
+        options_location: LocationId,
+    ) -> anyhow::Result<ProtobufOption> {
+        if self.tokenizer.lookahead_is_ident("default")? {
+            // Default value is not an option, it is located in the field
+            let name = self.next_option_name()?;
+            self.tokenizer.next_symbol_expect_eq('=', "field option")?;
+            let value = self.with_location(
+                self.child_path(field_location, &[FIELD_DEFAULT_VALUE]),
+                |p| p.next_constant(),
+            )?;
+            Ok(ProtobufOption { name, value })
+        } else if self.tokenizer.lookahead_is_ident("json_name")? {
+            // Same for JSON name
+            let path = self.child_path(field_location, &[FIELD_JSON_NAME]);
+            let location = self.location_start(path.clone());
+            let name = self.next_option_name()?;
+            self.tokenizer.next_symbol_expect_eq('=', "field option")?;
+            let value = self.with_location(path, |p| p.next_constant())?;
+            self.location_end(location);
+            Ok(ProtobufOption { name, value })
+        } else {
+            self.next_option_assignment(options_location, OptionsKind::Field, "field option")
+        }
     }
 
     // fieldOptions = fieldOption { ","  fieldOption }
-    fn next_field_options(&mut self) -> anyhow::Result<Vec<ProtobufOption>> {
+    fn next_field_options(
+        &mut self,
+        field_location: LocationId,
+    ) -> anyhow::Result<Vec<ProtobufOption>> {
         let mut options = Vec::new();
 
-        options.push(self.next_field_option()?);
+        let options_location =
+            self.location_start(self.child_path(field_location, &[FIELD_OPTIONS]));
+        self.tokenizer.next_symbol_expect_eq('[', "field")?;
+
+        options.push(self.next_field_option(field_location, options_location)?);
 
         while self.tokenizer.next_symbol_if_eq(',')? {
-            options.push(self.next_field_option()?);
+            options.push(self.next_field_option(field_location, options_location)?);
         }
 
+        self.tokenizer.next_symbol_expect_eq(']', "field")?;
+        self.location_end(options_location);
+
         Ok(options)
     }
 
     // field = label type fieldName "=" fieldNumber [ "[" fieldOptions "]" ] ";"
     // group = label "group" groupName "=" fieldNumber messageBody
-    fn next_field(&mut self, mode: MessageBodyParseMode) -> anyhow::Result<WithLoc<Field>> {
+    fn next_field(
+        &mut self,
+        mode: MessageBodyParseMode,
+        location: LocationId,
+    ) -> anyhow::Result<WithLoc<Field>> {
         let loc = self.tokenizer.lookahead_loc();
-        let rule = if self.clone().tokenizer.next_ident_if_eq("map")? {
+        let rule = if self.tokenizer.lookahead_is_ident("map")? {
             if !mode.map_allowed() {
                 return Err(ParserError::MapFieldNotAllowed.into());
             }
-            Rule::Optional
+            None
         } else {
-            self.next_label(mode)?
+            self.next_label(mode, location)?
         };
-        if self.tokenizer.next_ident_if_eq("group")? {
-            let name = self.next_group_name()?.to_owned();
+        if self.tokenizer.lookahead_is_ident("group")? {
+            self.with_location(self.child_path(location, &[FIELD_TYPE]), |p| {
+                Ok(p.tokenizer.next_ident_expect_eq("group")?)
+            })?;
+            let (name, name_span) = self.with_span(|p| p.next_group_name())?;
+            self.location_with_span(self.child_path(location, &[FIELD_NAME]), name_span);
             self.tokenizer.next_symbol_expect_eq('=', "group")?;
-            let number = self.next_field_number()?;
+            let number = self.with_location(self.child_path(location, &[FIELD_NUMBER]), |p| {
+                p.next_field_number()
+            })?;
+
+            // Group is both a field and a nested message, like `protoc`
+            // record both locations starting at the field start
+            let group_path = self.scope().next_nested_type_path();
+            let group_location = self
+                .source_info
+                .start(group_path.clone(), self.source_info.start_pos(location));
+            self.location_with_span(self.child_path(group_location, &[MESSAGE_NAME]), name_span);
+            self.location_with_span(self.child_path(location, &[FIELD_TYPE_NAME]), name_span);
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::MessageProto2,
                 Syntax::Proto3 => MessageBodyParseMode::MessageProto3,
             };
 
-            let MessageBody { fields, .. } = self.next_message_body(mode)?;
+            self.scopes.push(DescriptorScope {
+                path: group_path,
+                name: Some(name.clone()),
+                ..DescriptorScope::default()
+            });
+            let MessageBody { fields, .. } = self.next_message_body(mode, group_location, None)?;
+            self.scopes.pop();
+            self.location_end(group_location);
+            self.location_end(location);
 
             let fields = fields
                 .into_iter()
@@ -698,20 +1003,37 @@ impl<'a> Parser<'a> {
             };
             Ok(WithLoc { t: field, loc })
         } else {
+            // Path depends on type, set it after the type is parsed
+            let type_location = self.location_start(Vec::new());
             let typ = self.next_field_type()?;
-            let name = self.tokenizer.next_ident()?.to_owned();
-            self.tokenizer.next_symbol_expect_eq('=', "field")?;
-            let number = self.next_field_number()?;
+            let type_path = match typ {
+                FieldType::MessageOrEnum(..) | FieldType::Map(..) => {
+                    self.child_path(location, &[FIELD_TYPE_NAME])
+                }
+                _ => self.child_path(location, &[FIELD_TYPE]),
+            };
+            self.source_info.set_path(type_location, type_path);
+            self.location_end(type_location);
 
-            let mut options = Vec::new();
+            let name = self.with_location(self.child_path(location, &[FIELD_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
+            self.tokenizer.next_symbol_expect_eq('=', "field")?;
+            let number = self.with_location(self.child_path(location, &[FIELD_NUMBER]), |p| {
+                p.next_field_number()
+            })?;
 
-            if self.tokenizer.next_symbol_if_eq('[')? {
-                for o in self.next_field_options()? {
-                    options.push(o);
-                }
-                self.tokenizer.next_symbol_expect_eq(']', "field")?;
+            let options = if self.tokenizer.lookahead_is_symbol('[')? {
+                self.next_field_options(location)?
+            } else {
+                Vec::new()
+            };
+            self.next_end_of_declaration(';', "field", Some(location))?;
+            self.location_end(location);
+            if let FieldType::Map(..) = typ {
+                // Map entry is a nested message
+                self.scope().nested_types += 1;
             }
-            self.tokenizer.next_symbol_expect_eq(';', "field")?;
             let field = Field {
                 name,
                 rule,
@@ -726,11 +1048,18 @@ impl<'a> Parser<'a> {
     // oneof = "oneof" oneofName "{" { oneofField | emptyStatement } "}"
     // oneofField = type fieldName "=" fieldNumber [ "[" fieldOptions "]" ] ";"
     fn next_oneof_opt(&mut self) -> anyhow::Result<Option<OneOf>> {
-        if self.tokenizer.next_ident_if_eq("oneof")? {
-            let name = self.tokenizer.next_ident()?.to_owned();
+        if self.tokenizer.lookahead_is_ident("oneof")? {
+            let index = next_index(&mut self.scope().oneofs);
+            let path = self.scope().path(&[MESSAGE_ONEOF_DECL, index]);
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("oneof")?;
+            let name = self.with_location(self.child_path(location, &[ONEOF_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
             let MessageBody {
                 fields, options, ..
-            } = self.next_message_body(MessageBodyParseMode::Oneof)?;
+            } = self.next_message_body(MessageBodyParseMode::Oneof, location, None)?;
+            self.location_end(location);
             let fields = fields
                 .into_iter()
                 .map(|fo| match fo.t {
@@ -773,34 +1102,52 @@ impl<'a> Parser<'a> {
     // Extensions
 
     // range =  intLit [ "to" ( intLit | "max" ) ]
-    fn next_range(&mut self) -> anyhow::Result<FieldNumberRange> {
-        let from = self.next_field_number()?;
+    fn next_range(&mut self, path: Vec<i32>) -> anyhow::Result<FieldNumberRange> {
+        let location = self.location_start(path);
+        let (from, from_span) = self.with_span(|p| p.next_field_number())?;
+        self.location_with_span(self.child_path(location, &[RANGE_START]), from_span);
         let to = if self.tokenizer.next_ident_if_eq("to")? {
-            if self.tokenizer.next_ident_if_eq("max")? {
-                0x20000000 - 1
-            } else {
-                self.next_field_number()?
-            }
+            self.with_location(self.child_path(location, &[RANGE_END]), |p| {
+                if p.tokenizer.next_ident_if_eq("max")? {
+                    Ok(0x20000000 - 1)
+                } else {
+                    p.next_field_number()
+                }
+            })?
         } else {
+            self.location_with_span(self.child_path(location, &[RANGE_END]), from_span);
             from
         };
+        self.location_end(location);
         Ok(FieldNumberRange { from, to })
     }
 
     // ranges = range { "," range }
-    fn next_ranges(&mut self) -> anyhow::Result<Vec<FieldNumberRange>> {
+    fn next_ranges(
+        &mut self,
+        location: LocationId,
+        counter: fn(&mut DescriptorScope) -> &mut i32,
+    ) -> anyhow::Result<Vec<FieldNumberRange>> {
         let mut ranges = Vec::new();
-        ranges.push(self.next_range()?);
-        while self.tokenizer.next_symbol_if_eq(',')? {
-            ranges.push(self.next_range()?);
+        loop {
+            let index = next_index(counter(self.scope()));
+            ranges.push(self.next_range(self.child_path(location, &[index]))?);
+            if !self.tokenizer.next_symbol_if_eq(',')? {
+                return Ok(ranges);
+            }
         }
-        Ok(ranges)
     }
 
     // extensions = "extensions" ranges ";"
     fn next_extensions_opt(&mut self) -> anyhow::Result<Option<Vec<FieldNumberRange>>> {
-        if self.tokenizer.next_ident_if_eq("extensions")? {
-            Ok(Some(self.next_ranges()?))
+        if self.tokenizer.lookahead_is_ident("extensions")? {
+            let path = self.scope().path(&[MESSAGE_EXTENSION_RANGE]);
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("extensions")?;
+            let ranges = self.next_ranges(location, |s| &mut s.extension_ranges)?;
+            self.next_end_of_declaration(';', "extensions", Some(location))?;
+            self.location_end(location);
+            Ok(Some(ranges))
         } else {
             Ok(None)
         }
@@ -814,19 +1161,35 @@ impl<'a> Parser<'a> {
     fn next_reserved_opt(
         &mut self,
     ) -> anyhow::Result<Option<(Vec<FieldNumberRange>, Vec<String>)>> {
-        if self.tokenizer.next_ident_if_eq("reserved")? {
-            let (ranges, names) = if let &Token::StrLit(..) = self.tokenizer.lookahead_some()? {
-                let mut names = Vec::new();
-                names.push(self.tokenizer.next_str_lit()?.decode_utf8()?);
-                while self.tokenizer.next_symbol_if_eq(',')? {
-                    names.push(self.tokenizer.next_str_lit()?.decode_utf8()?);
-                }
-                (Vec::new(), names)
-            } else {
-                (self.next_ranges()?, Vec::new())
-            };
+        if self.tokenizer.lookahead_is_ident("reserved")? {
+            let start = self.tokenizer.lookahead_pos();
+            self.tokenizer.next_ident_expect_eq("reserved")?;
+            let (ranges, names, location) =
+                if let &Token::StrLit(..) = self.tokenizer.lookahead_some()? {
+                    let path = self.scope().path(&[MESSAGE_RESERVED_NAME]);
+                    let location = self.source_info.start(path, start);
+                    let mut names = Vec::new();
+                    loop {
+                        let index = next_index(&mut self.scope().reserved_names);
+                        names.push(
+                            self.with_location(self.child_path(location, &[index]), |p| {
+                                Ok(p.tokenizer.next_str_lit()?.decode_utf8()?)
+                            })?,
+                        );
+                        if !self.tokenizer.next_symbol_if_eq(',')? {
+                            break;
+                        }
+                    }
+                    (Vec::new(), names, location)
+                } else {
+                    let path = self.scope().path(&[MESSAGE_RESERVED_RANGE]);
+                    let location = self.source_info.start(path, start);
+                    let ranges = self.next_ranges(location, |s| &mut s.reserved_ranges)?;
+                    (ranges, Vec::new(), location)
+                };
 
-            self.tokenizer.next_symbol_expect_eq(';', "reserved")?;
+            self.next_end_of_declaration(';', "reserved", Some(location))?;
+            self.location_end(location);
 
             Ok(Some((ranges, names)))
         } else {
@@ -839,12 +1202,15 @@ impl<'a> Parser<'a> {
     // Enum definition
 
     // enumValueOption = optionName "=" constant
-    fn next_enum_value_option(&mut self) -> anyhow::Result<ProtobufOption> {
-        let name = self.next_option_name()?;
-        self.tokenizer
-            .next_symbol_expect_eq('=', "enum value option")?;
-        let value = self.next_constant()?;
-        Ok(ProtobufOption { name, value })
+    fn next_enum_value_option(
+        &mut self,
+        options_location: LocationId,
+    ) -> anyhow::Result<ProtobufOption> {
+        self.next_option_assignment(
+            options_location,
+            OptionsKind::EnumValue,
+            "enum value option",
+        )
     }
 
     // https://github.com/google/protobuf/issues/4561
@@ -863,18 +1229,28 @@ impl<'a> Parser<'a> {
     }
 
     // enumField = ident "=" intLit [ "[" enumValueOption { ","  enumValueOption } "]" ]";"
-    fn next_enum_field(&mut self) -> anyhow::Result<EnumValue> {
-        let name = self.tokenizer.next_ident()?.to_owned();
+    fn next_enum_field(&mut self, location: LocationId) -> anyhow::Result<EnumValue> {
+        let name = self.with_location(self.child_path(location, &[ENUM_VALUE_NAME]), |p| {
+            Ok(p.tokenizer.next_ident()?)
+        })?;
         self.tokenizer.next_symbol_expect_eq('=', "enum field")?;
-        let number = self.next_enum_value()?;
+        let number = self.with_location(self.child_path(location, &[ENUM_VALUE_NUMBER]), |p| {
+            p.next_enum_value()
+        })?;
         let mut options = Vec::new();
-        if self.tokenizer.next_symbol_if_eq('[')? {
-            options.push(self.next_enum_value_option()?);
+        if self.tokenizer.lookahead_is_symbol('[')? {
+            let options_location =
+                self.location_start(self.child_path(location, &[ENUM_VALUE_OPTIONS]));
+            self.tokenizer.next_symbol_expect_eq('[', "enum field")?;
+            options.push(self.next_enum_value_option(options_location)?);
             while self.tokenizer.next_symbol_if_eq(',')? {
-                options.push(self.next_enum_value_option()?);
+                options.push(self.next_enum_value_option(options_location)?);
             }
             self.tokenizer.next_symbol_expect_eq(']', "enum field")?;
+            self.location_end(options_location);
         }
+        self.next_end_of_declaration(';', "enum field", Some(location))?;
+        self.location_end(location);
 
         Ok(EnumValue {
             name,
@@ -888,27 +1264,36 @@ impl<'a> Parser<'a> {
     fn next_enum_opt(&mut self) -> anyhow::Result<Option<WithLoc<Enumeration>>> {
         let loc = self.tokenizer.lookahead_loc();
 
-        if self.tokenizer.next_ident_if_eq("enum")? {
-            let name = self.tokenizer.next_ident()?.to_owned();
+        if self.tokenizer.lookahead_is_ident("enum")? {
+            let path = self.scope().next_enum_path();
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("enum")?;
+            let name = self.with_location(self.child_path(location, &[ENUM_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
 
             let mut values = Vec::new();
             let mut options = Vec::new();
 
-            self.tokenizer.next_symbol_expect_eq('{', "enum")?;
+            self.next_end_of_declaration('{', "enum", Some(location))?;
             while self.tokenizer.lookahead_if_symbol()? != Some('}') {
                 // emptyStatement
-                if self.tokenizer.next_symbol_if_eq(';')? {
+                if self.next_end_of_declaration_if(';', None)? {
                     continue;
                 }
 
-                if let Some(o) = self.next_option_opt()? {
+                let options_path = self.child_path(location, &[ENUM_OPTIONS]);
+                if let Some(o) = self.next_option_opt(options_path, OptionsKind::Enum)? {
                     options.push(o);
                     continue;
                 }
 
-                values.push(self.next_enum_field()?);
+                let value_path = self.child_path(location, &[ENUM_VALUE, values.len() as i32]);
+                let value_location = self.location_start(value_path);
+                values.push(self.next_enum_field(value_location)?);
             }
-            self.tokenizer.next_symbol_expect_eq('}', "enum")?;
+            self.next_end_of_declaration('}', "enum", None)?;
+            self.location_end(location);
             let enumeration = Enumeration {
                 name,
                 values,
@@ -927,8 +1312,16 @@ impl<'a> Parser<'a> {
 
     // messageBody = "{" { field | enum | message | extend | extensions | group |
     //               option | oneof | mapField | reserved | emptyStatement } "}"
-    fn next_message_body(&mut self, mode: MessageBodyParseMode) -> anyhow::Result<MessageBody> {
-        self.tokenizer.next_symbol_expect_eq('{', "message body")?;
+    //
+    // `location` is the location of message, oneof or extend,
+    // `extendee_span` is the extendee type name span for extend.
+    fn next_message_body(
+        &mut self,
+        mode: MessageBodyParseMode,
+        location: LocationId,
+        extendee_span: Option<(usize, usize)>,
+    ) -> anyhow::Result<MessageBody> {
+        self.next_end_of_declaration('{', "message body", Some(location))?;
 
         let mut r = MessageBody::default();
 
@@ -936,7 +1329,7 @@ impl<'a> Parser<'a> {
             let loc = self.tokenizer.lookahead_loc();
 
             // emptyStatement
-            if self.tokenizer.next_symbol_if_eq(';')? {
+            if self.next_end_of_declaration_if(';', None)? {
                 continue;
             }
 
@@ -982,7 +1375,17 @@ impl<'a> Parser<'a> {
             }
 
             if mode.is_option_allowed() {
-                if let Some(option) = self.next_option_opt()? {
+                let (options_path, kind) = match mode {
+                    MessageBodyParseMode::Oneof => (
+                        self.child_path(location, &[ONEOF_OPTIONS]),
+                        OptionsKind::Oneof,
+                    ),
+                    _ => (
+                        self.child_path(location, &[MESSAGE_OPTIONS]),
+                        OptionsKind::Message,
+                    ),
+                };
+                if let Some(option) = self.next_option_opt(options_path, kind)? {
                     r.options.push(option);
                     continue;
                 }
@@ -990,11 +1393,27 @@ impl<'a> Parser<'a> {
                 self.tokenizer.next_ident_if_eq_error("option")?;
             }
 
-            let field = FieldOrOneOf::Field(self.next_field(mode)?);
+            let field_location = match extendee_span {
+                Some(extendee_span) => {
+                    let index = next_index(&mut self.scope().extensions);
+                    let field_location = self.location_start(self.child_path(location, &[index]));
+                    self.location_with_span(
+                        self.child_path(field_location, &[FIELD_EXTENDEE]),
+                        extendee_span,
+                    );
+                    field_location
+                }
+                None => {
+                    let index = next_index(&mut self.scope().fields);
+                    let path = self.scope().path(&[MESSAGE_FIELD, index]);
+                    self.location_start(path)
+                }
+            };
+            let field = FieldOrOneOf::Field(self.next_field(mode, field_location)?);
             r.fields.push(WithLoc { t: field, loc });
         }
 
-        self.tokenizer.next_symbol_expect_eq('}', "message body")?;
+        self.next_end_of_declaration('}', "message body", None)?;
 
         Ok(r)
     }
@@ -1003,14 +1422,25 @@ impl<'a> Parser<'a> {
     fn next_message_opt(&mut self) -> anyhow::Result<Option<WithLoc<Message>>> {
         let loc = self.tokenizer.lookahead_loc();
 
-        if self.tokenizer.next_ident_if_eq("message")? {
-            let name = self.tokenizer.next_ident()?.to_owned();
+        if self.tokenizer.lookahead_is_ident("message")? {
+            let path = self.scope().next_nested_type_path();
+            let location = self.location_start(path.clone());
+            self.tokenizer.next_ident_expect_eq("message")?;
+            let name = self.with_location(self.child_path(location, &[MESSAGE_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::MessageProto2,
                 Syntax::Proto3 => MessageBodyParseMode::MessageProto3,
             };
 
+            self.scopes.push(DescriptorScope {
+                path,
+                name: Some(name.clone()),
+                ..DescriptorScope::default()
+            });
+
             let MessageBody {
                 fields,
                 reserved_nums,
@@ -1020,7 +1450,9 @@ impl<'a> Parser<'a> {
                 options,
                 extensions,
                 extension_ranges,
-            } = self.next_message_body(mode)?;
+            } = self.next_message_body(mode, location, None)?;
+            self.scopes.pop();
+            self.location_end(location);
 
             let message = Message {
                 name,
@@ -1043,21 +1475,24 @@ impl<'a> Parser<'a> {
 
     // extend = "extend" messageType "{" {field | group | emptyStatement} "}"
     fn next_extend_opt(&mut self) -> anyhow::Result<Option<Vec<WithLoc<Extension>>>> {
-        let mut clone = self.clone();
-        if clone.tokenizer.next_ident_if_eq("extend")? {
+        if self.tokenizer.lookahead_is_ident("extend")? {
             // According to spec `extend` is only for `proto2`, but it is used in `proto3`
             // https://github.com/google/protobuf/issues/4610
 
-            *self = clone;
+            let path = self.scope().extensions_path();
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("extend")?;
 
-            let extendee = self.next_message_or_enum_type()?;
+            let (extendee, extendee_span) = self.with_span(|p| p.next_message_or_enum_type())?;
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::ExtendProto2,
                 Syntax::Proto3 => MessageBodyParseMode::ExtendProto3,
             };
 
-            let MessageBody { fields, .. } = self.next_message_body(mode)?;
+            let MessageBody { fields, .. } =
+                self.next_message_body(mode, location, Some(extendee_span))?;
+            self.location_end(location);
 
             // TODO: is oneof allowed in extend?
             let fields: Vec<WithLoc<Field>> = fields
@@ -1086,11 +1521,15 @@ impl<'a> Parser<'a> {
 
     // Service definition
 
-    fn next_options_or_colon(&mut self) -> anyhow::Result<Vec<ProtobufOption>> {
+    fn next_options_or_colon(
+        &mut self,
+        location: LocationId,
+    ) -> anyhow::Result<Vec<ProtobufOption>> {
         let mut options = Vec::new();
-        if self.tokenizer.next_symbol_if_eq('{')? {
+        if self.next_end_of_declaration_if('{', Some(location))? {
             while self.tokenizer.lookahead_if_symbol()? != Some('}') {
-                if let Some(option) = self.next_option_opt()? {
+                let options_path = self.child_path(location, &[METHOD_OPTIONS]);
+                if let Some(option) = self.next_option_opt(options_path, OptionsKind::Method)? {
                     options.push(option);
                     continue;
                 }
@@ -1101,9 +1540,9 @@ impl<'a> Parser<'a> {
 
                 return Err(ParserError::IncorrectInput.into());
             }
-            self.tokenizer.next_symbol_expect_eq('}', "option")?;
+            self.next_end_of_declaration('}', "option", None)?;
         } else {
-            self.tokenizer.next_symbol_expect_eq(';', "option")?;
+            self.next_end_of_declaration(';', "option", Some(location))?;
         }
 
         Ok(options)
@@ -1111,16 +1550,27 @@ impl<'a> Parser<'a> {
 
     // stream = "stream" streamName "(" messageType "," messageType ")"
     //        (( "{" { option | emptyStatement } "}") | ";" )
-    fn next_stream_opt(&mut self) -> anyhow::Result<Option<Method>> {
+    fn next_stream_opt(&mut self, path: Vec<i32>) -> anyhow::Result<Option<Method>> {
         assert_eq!(Syntax::Proto2, self.syntax);
-        if self.tokenizer.next_ident_if_eq("stream")? {
-            let name = self.tokenizer.next_ident()?;
+        if self.tokenizer.lookahead_is_ident("stream")? {
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("stream")?;
+            let name = self.with_location(self.child_path(location, &[METHOD_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
             self.tokenizer.next_symbol_expect_eq('(', "stream")?;
-            let input_type = self.next_message_or_enum_type()?;
+            let input_type = self
+                .with_location(self.child_path(location, &[METHOD_INPUT_TYPE]), |p| {
+                    p.next_message_or_enum_type()
+                })?;
             self.tokenizer.next_symbol_expect_eq(',', "stream")?;
-            let output_type = self.next_message_or_enum_type()?;
+            let output_type = self
+                .with_location(self.child_path(location, &[METHOD_OUTPUT_TYPE]), |p| {
+                    p.next_message_or_enum_type()
+                })?;
             self.tokenizer.next_symbol_expect_eq(')', "stream")?;
-            let options = self.next_options_or_colon()?;
+            let options = self.next_options_or_colon(location)?;
+            self.location_end(location);
             Ok(Some(Method {
                 name,
                 input_type,
@@ -1134,22 +1584,50 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn next_stream_keyword_opt(
+        &mut self,
+        location: LocationId,
+        field: i32,
+    ) -> anyhow::Result<bool> {
+        if self.tokenizer.lookahead_is_ident("stream")? {
+            self.with_location(self.child_path(location, &[field]), |p| {
+                Ok(p.tokenizer.next_ident_expect_eq("stream")?)
+            })?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
     // rpc = "rpc" rpcName "(" [ "stream" ] messageType ")"
     //     "returns" "(" [ "stream" ] messageType ")"
     //     (( "{" { option | emptyStatement } "}" ) | ";" )
-    fn next_rpc_opt(&mut self) -> anyhow::Result<Option<Method>> {
-        if self.tokenizer.next_ident_if_eq("rpc")? {
-            let name = self.tokenizer.next_ident()?;
+    fn next_rpc_opt(&mut self, path: Vec<i32>) -> anyhow::Result<Option<Method>> {
+        if self.tokenizer.lookahead_is_ident("rpc")? {
+            let location = self.location_start(path);
+            self.tokenizer.next_ident_expect_eq("rpc")?;
+            let name = self.with_location(self.child_path(location, &[METHOD_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
             self.tokenizer.next_symbol_expect_eq('(', "rpc")?;
-            let client_streaming = self.tokenizer.next_ident_if_eq("stream")?;
-            let input_type = self.next_message_or_enum_type()?;
+            let client_streaming =
+                self.next_stream_keyword_opt(location, METHOD_CLIENT_STREAMING)?;
+            let input_type = self
+                .with_location(self.child_path(location, &[METHOD_INPUT_TYPE]), |p| {
+                    p.next_message_or_enum_type()
+                })?;
             self.tokenizer.next_symbol_expect_eq(')', "rpc")?;
             self.tokenizer.next_ident_expect_eq("returns")?;
             self.tokenizer.next_symbol_expect_eq('(', "rpc")?;
-            let server_streaming = self.tokenizer.next_ident_if_eq("stream")?;
-            let output_type = self.next_message_or_enum_type()?;
+            let server_streaming =
+                self.next_stream_keyword_opt(location, METHOD_SERVER_STREAMING)?;
+            let output_type = self
+                .with_location(self.child_path(location, &[METHOD_OUTPUT_TYPE]), |p| {
+                    p.next_message_or_enum_type()
+                })?;
             self.tokenizer.next_symbol_expect_eq(')', "rpc")?;
-            let options = self.next_options_or_colon()?;
+            let options = self.next_options_or_colon(location)?;
+            self.location_end(location);
             Ok(Some(Method {
                 name,
                 input_type,
@@ -1171,25 +1649,33 @@ impl<'a> Parser<'a> {
     fn next_service_opt(&mut self) -> anyhow::Result<Option<WithLoc<Service>>> {
         let loc = self.tokenizer.lookahead_loc();
 
-        if self.tokenizer.next_ident_if_eq("service")? {
-            let name = self.tokenizer.next_ident()?;
+        if self.tokenizer.lookahead_is_ident("service")? {
+            let index = next_index(&mut self.scope().services);
+            let location = self.location_start(vec![FILE_SERVICE, index]);
+            self.tokenizer.next_ident_expect_eq("service")?;
+            let name = self.with_location(self.child_path(location, &[SERVICE_NAME]), |p| {
+                Ok(p.tokenizer.next_ident()?)
+            })?;
             let mut methods = Vec::new();
             let mut options = Vec::new();
-            self.tokenizer.next_symbol_expect_eq('{', "service")?;
+            self.next_end_of_declaration('{', "service", Some(location))?;
             while self.tokenizer.lookahead_if_symbol()? != Some('}') {
-                if let Some(method) = self.next_rpc_opt()? {
+                let method_path =
+                    self.child_path(location, &[SERVICE_METHOD, methods.len() as i32]);
+                if let Some(method) = self.next_rpc_opt(method_path.clone())? {
                     methods.push(method);
                     continue;
                 }
 
                 if self.syntax == Syntax::Proto2 {
-                    if let Some(method) = self.next_stream_opt()? {
+                    if let Some(method) = self.next_stream_opt(method_path)? {
                         methods.push(method);
                         continue;
                     }
                 }
 
-                if let Some(o) = self.next_option_opt()? {
+                let options_path = self.child_path(location, &[SERVICE_OPTIONS]);
+                if let Some(o) = self.next_option_opt(options_path, OptionsKind::Service)? {
                     options.push(o);
                     continue;
                 }
@@ -1200,7 +1686,8 @@ impl<'a> Parser<'a> {
 
                 return Err(ParserError::IncorrectInput.into());
             }
-            self.tokenizer.next_symbol_expect_eq('}', "service")?;
+            self.next_end_of_declaration('}', "service", None)?;
+            self.location_end(location);
             Ok(Some(WithLoc {
                 loc,
                 t: Service {
@@ -1219,6 +1706,10 @@ impl<'a> Parser<'a> {
     // proto = syntax { import | package | option | topLevelDef | emptyStatement }
     // topLevelDef = message | enum | extend | service
     pub fn next_proto(&mut self) -> anyhow::Result<FileDescriptor> {
+        let start = self.tokenizer.lookahead_pos();
+        self.source_info.start_of_file(&self.input[..start]);
+        let location = self.location_start(Vec::new());
+
         let syntax = self.next_syntax()?.unwrap_or(Syntax::Proto2);
         self.syntax = syntax;
 
@@ -1241,7 +1732,7 @@ impl<'a> Parser<'a> {
                 continue;
             }
 
-            if let Some(option) = self.next_option_opt()? {
+            if let Some(option) = self.next_option_opt(vec![FILE_OPTIONS], OptionsKind::File)? {
                 options.push(option);
                 continue;
             }
@@ -1266,12 +1757,15 @@ impl<'a> Parser<'a> {
                 continue;
             }
 
-            if self.tokenizer.next_symbol_if_eq(';')? {
+            if self.next_end_of_declaration_if(';', None)? {
                 continue;
             }
 
             return Err(ParserError::IncorrectInput.into());
         }
+        self.location_end(location);
+
+        let source_locations = mem::take(&mut self.source_info).finish(self.input);
 
         Ok(FileDescriptor {
             imports,
@@ -1282,6 +1776,7 @@ impl<'a> Parser<'a> {
             extensions,
             services,
             options,
+            source_locations,
         })
     }
 }
@@ -1330,7 +1825,10 @@ mod test {
     #[test]
     fn test_field_default_value_int() {
         let msg = r#"  optional int64 f = 4 [default = 12];  "#;
-        let mess = parse(msg, |p| p.next_field(MessageBodyParseMode::MessageProto2));
+        let mess = parse(msg, |p| {
+            let location = p.location_start(vec![FILE_EXTENSION, 0]);
+            p.next_field(MessageBodyParseMode::MessageProto2, location)
+        });
         assert_eq!("f", mess.t.name);
         assert_eq!(
             ProtobufOptionName::simple("default"),
@@ -1342,7 +1840,10 @@ mod test {
     #[test]
     fn test_field_default_value_float() {
         let msg = r#"  optional float f = 2 [default = 10.0];  "#;
-        let mess = parse(msg, |p| p.next_field(MessageBodyParseMode::MessageProto2));
+        let mess = parse(msg, |p| {
+            let location = p.location_start(vec![FILE_EXTENSION, 0]);
+            p.next_field(MessageBodyParseMode::MessageProto2, location)
+        });
         assert_eq!("f", mess.t.name);
         assert_eq!(
             ProtobufOptionName::simple("default"),
@@ -1388,7 +1889,9 @@ mod test {
     fn test_ignore() {
         let msg = r#"option optimize_for = SPEED;"#;
 
-        parse_opt(msg, |p| p.next_option_opt());
+        parse_opt(msg, |p| {
+            p.next_option_opt(vec![FILE_OPTIONS], OptionsKind::File)
+        });
     }
 
     #[test]
@@ -1563,6 +2066,37 @@ mod test {
         assert_eq!("bbb", mess.t.regular_fields_for_test()[2].name);
     }
 
+    #[test]
+    fn test_source_locations() {
+        let msg = r#"syntax = "proto3";
+
+// Detached
+
+// Message comment
+message Foo {
+  int32 bar = 1; // Field comment
+}
+"#;
+        let desc = parse(msg, |p| p.next_proto());
+
+        let foo = desc
+            .source_locations
+            .iter()
+            .find(|l| l.path == [4, 0])
+            .expect("message location");
+        assert_eq!(vec![5, 0, 7, 1], foo.span);
+        assert_eq!(" Message comment\n", foo.leading_comments);
+        assert_eq!(vec![" Detached\n"], foo.leading_detached_comments);
+
+        let bar = desc
+            .source_locations
+            .iter()
+            .find(|l| l.path == [4, 0, 2, 0])
+            .expect("field location");
+        assert_eq!(vec![6, 2, 16], bar.span);
+        assert_eq!(" Field comment\n", bar.trailing_comments);
+    }
+
     #[test]
     fn test_incorrect_file_descriptor() {
         let msg = r#"
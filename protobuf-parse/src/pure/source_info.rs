@@ -0,0 +1,210 @@
+//! `SourceCodeInfo` locations recorded while parsing.
+//!
+//! Locations are recorded in the same order with the same paths,
+//! spans and comments as `protoc` does it.
+
+use crate::pure::comments::split_comments;
+use crate::pure::model;
+
+// Field numbers of `descriptor.proto` messages used in location paths.
+
+pub(crate) const FILE_PACKAGE: i32 = 2;
+pub(crate) const FILE_DEPENDENCY: i32 = 3;
+pub(crate) const FILE_MESSAGE_TYPE: i32 = 4;
+pub(crate) const FILE_ENUM_TYPE: i32 = 5;
+pub(crate) const FILE_SERVICE: i32 = 6;
+pub(crate) const FILE_EXTENSION: i32 = 7;
+pub(crate) const FILE_OPTIONS: i32 = 8;
+pub(crate) const FILE_PUBLIC_DEPENDENCY: i32 = 10;
+pub(crate) const FILE_WEAK_DEPENDENCY: i32 = 11;
+pub(crate) const FILE_SYNTAX: i32 = 12;
+
+pub(crate) const MESSAGE_NAME: i32 = 1;
+pub(crate) const MESSAGE_FIELD: i32 = 2;
+pub(crate) const MESSAGE_NESTED_TYPE: i32 = 3;
+pub(crate) const MESSAGE_ENUM_TYPE: i32 = 4;
+pub(crate) const MESSAGE_EXTENSION_RANGE: i32 = 5;
+pub(crate) const MESSAGE_EXTENSION: i32 = 6;
+pub(crate) const MESSAGE_OPTIONS: i32 = 7;
+pub(crate) const MESSAGE_ONEOF_DECL: i32 = 8;
+pub(crate) const MESSAGE_RESERVED_RANGE: i32 = 9;
+pub(crate) const MESSAGE_RESERVED_NAME: i32 = 10;
+
+pub(crate) const RANGE_START: i32 = 1;
+pub(crate) const RANGE_END: i32 = 2;
+
+pub(crate) const FIELD_NAME: i32 = 1;
+pub(crate) const FIELD_EXTENDEE: i32 = 2;
+pub(crate) const FIELD_NUMBER: i32 = 3;
+pub(crate) const FIELD_LABEL: i32 = 4;
+pub(crate) const FIELD_TYPE: i32 = 5;
+pub(crate) const FIELD_TYPE_NAME: i32 = 6;
+pub(crate) const FIELD_DEFAULT_VALUE: i32 = 7;
+pub(crate) const FIELD_OPTIONS: i32 = 8;
+pub(crate) const FIELD_JSON_NAME: i32 = 10;
+
+pub(crate) const ONEOF_NAME: i32 = 1;
+pub(crate) const ONEOF_OPTIONS: i32 = 2;
+
+pub(crate) const ENUM_NAME: i32 = 1;
+pub(crate) const ENUM_VALUE: i32 = 2;
+pub(crate) const ENUM_OPTIONS: i32 = 3;
+
+pub(crate) const ENUM_VALUE_NAME: i32 = 1;
+pub(crate) const ENUM_VALUE_NUMBER: i32 = 2;
+pub(crate) const ENUM_VALUE_OPTIONS: i32 = 3;
+
+pub(crate) const SERVICE_NAME: i32 = 1;
+pub(crate) const SERVICE_METHOD: i32 = 2;
+pub(crate) const SERVICE_OPTIONS: i32 = 3;
+
+pub(crate) const METHOD_NAME: i32 = 1;
+pub(crate) const METHOD_INPUT_TYPE: i32 = 2;
+pub(crate) const METHOD_OUTPUT_TYPE: i32 = 3;
+pub(crate) const METHOD_OPTIONS: i32 = 4;
+pub(crate) const METHOD_CLIENT_STREAMING: i32 = 5;
+pub(crate) const METHOD_SERVER_STREAMING: i32 = 6;
+
+/// Index of a location in [`SourceInfoRecorder`].
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct LocationId(usize);
+
+#[derive(Clone, Debug)]
+struct RecordedLocation {
+    path: Vec<i32>,
+    /// Byte offsets
+    start: usize,
+    end: usize,
+    leading_comments: String,
+    trailing_comments: String,
+    leading_detached_comments: Vec<String>,
+    option: Option<model::SourceLocationOption>,
+}
+
+/// Locations and comments collected by the parser.
+#[derive(Clone, Default)]
+pub(crate) struct SourceInfoRecorder {
+    locations: Vec<RecordedLocation>,
+    upcoming_leading_comments: String,
+    upcoming_detached_comments: Vec<String>,
+}
+
+impl SourceInfoRecorder {
+    /// Add a location, its end must be set later with [`end`](Self::end).
+    pub(crate) fn start(&mut self, path: Vec<i32>, start: usize) -> LocationId {
+        self.locations.push(RecordedLocation {
+            path,
+            start,
+            end: start,
+            leading_comments: String::new(),
+            trailing_comments: String::new(),
+            leading_detached_comments: Vec::new(),
+            option: None,
+        });
+        LocationId(self.locations.len() - 1)
+    }
+
+    pub(crate) fn end(&mut self, id: LocationId, end: usize) {
+        self.locations[id.0].end = end;
+    }
+
+    pub(crate) fn start_pos(&self, id: LocationId) -> usize {
+        self.locations[id.0].start
+    }
+
+    pub(crate) fn path(&self, id: LocationId) -> &[i32] {
+        &self.locations[id.0].path
+    }
+
+    /// Set path of location which is not known when location starts.
+    pub(crate) fn set_path(&mut self, id: LocationId, path: Vec<i32>) {
+        self.locations[id.0].path = path;
+    }
+
+    /// Mark location as an option location, path of which
+    /// is resolved after the options are interpreted.
+    pub(crate) fn set_option(&mut self, id: LocationId, option: model::SourceLocationOption) {
+        self.locations[id.0].option = Some(option);
+    }
+
+    /// Collect comments at the start of the file.
+    pub(crate) fn start_of_file(&mut self, gap: &str) {
+        let comments = split_comments(gap, true, false);
+        self.upcoming_leading_comments = comments.next_leading;
+        self.upcoming_detached_comments = comments.detached;
+    }
+
+    /// Collect comments after the token which ends a declaration
+    /// (`;`, `{` or `}`), and attach them to the declaration `location`.
+    ///
+    /// Leading comments are remembered until the next declaration ends.
+    pub(crate) fn end_of_declaration(
+        &mut self,
+        symbol: char,
+        location: Option<LocationId>,
+        gap: &str,
+        end_of_scope: bool,
+    ) {
+        let comments = split_comments(gap, false, end_of_scope);
+        let leading = std::mem::replace(&mut self.upcoming_leading_comments, comments.next_leading);
+        match location {
+            Some(id) => {
+                let detached =
+                    std::mem::replace(&mut self.upcoming_detached_comments, comments.detached);
+                let location = &mut self.locations[id.0];
+                location.leading_comments = leading;
+                location.trailing_comments = comments.prev_trailing;
+                location.leading_detached_comments = detached;
+            }
+            None if symbol == '}' => {
+                // End of scope, drop pending detached comments
+                self.upcoming_detached_comments = comments.detached;
+            }
+            None => {
+                self.upcoming_detached_comments.extend(comments.detached);
+            }
+        }
+    }
+
+    /// Convert byte offsets to spans.
+    pub(crate) fn finish(self, input: &str) -> Vec<model::SourceLocation> {
+        let line_starts: Vec<usize> = Some(0)
+            .into_iter()
+            .chain(input.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line_col = |pos: usize| {
+            let line = line_starts.partition_point(|&s| s <= pos) - 1;
+            let mut col = 0;
+            for &b in &input.as_bytes()[line_starts[line]..pos] {
+                // Same as `protoc` which expands tabs to 8 columns
+                col = if b == b'\t' {
+                    col + 8 - col % 8
+                } else {
+                    col + 1
+                };
+            }
+            (line as i32, col)
+        };
+
+        self.locations
+            .into_iter()
+            .map(|l| {
+                let (start_line, start_col) = line_col(l.start);
+                let (end_line, end_col) = line_col(l.end);
+                let span = if start_line == end_line {
+                    vec![start_line, start_col, end_col]
+                } else {
+                    vec![start_line, start_col, end_line, end_col]
+                };
+                model::SourceLocation {
+                    path: l.path,
+                    span,
+                    leading_comments: l.leading_comments,
+                    trailing_comments: l.trailing_comments,
+                    leading_detached_comments: l.leading_detached_comments,
+                    option: l.option,
+                }
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,13 @@
-use std::cmp;
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem;
+use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
 use std::io::BufRead;
+#[cfg(feature = "std")]
 use std::io::BufReader;
+#[cfg(feature = "std")]
 use std::io::Read;
-use std::mem;
-use std::mem::MaybeUninit;
 
 #[cfg(feature = "bytes")]
 use bytes::buf::UninitSlice;
@@ -14,6 +18,7 @@ use bytes::Bytes;
 #[cfg(feature = "bytes")]
 use bytes::BytesMut;
 
+#[cfg(feature = "std")]
 use crate::buf_read_or_reader::BufReadOrReader;
 use crate::coded_input_stream::READ_RAW_BYTES_MAX_ALLOC;
 use crate::error::ProtobufError;
@@ -24,6 +29,7 @@ use crate::misc::vec_spare_capacity_mut;
 
 // If an input stream is constructed with a `Read`, we create a
 // `BufReader` with an internal buffer of this size.
+#[cfg(feature = "std")]
 const INPUT_STREAM_BUFFER_SIZE: usize = 4096;
 
 const USE_UNSAFE_FOR_SPEED: bool = true;
@@ -32,7 +38,10 @@ const NO_LIMIT: u64 = u64::MAX;
 
 /// Hold all possible combinations of input source
 enum InputSource<'a> {
+    #[cfg(feature = "std")]
     Read(BufReadOrReader<'a>),
+    // Slice is read through `buf`, the field only keeps the borrow.
+    #[allow(dead_code)]
     Slice(&'a [u8]),
     #[cfg(feature = "bytes")]
     Bytes(&'a Bytes),
@@ -59,8 +68,14 @@ pub(crate) struct BufReadIter<'a> {
     limit_within_buf: usize,
     pos_of_buf_start: u64,
     limit: u64,
+    /// Absolute position of the total bytes limit.
+    /// `limit` is never greater than this.
+    total_limit: u64,
+    /// Configured limit, reported in the error.
+    max_total_bytes: u64,
 }
 
+#[cfg(feature = "std")]
 impl<'a> Drop for BufReadIter<'a> {
     fn drop(&mut self) {
         match self.input_source {
@@ -71,6 +86,7 @@ impl<'a> Drop for BufReadIter<'a> {
 }
 
 impl<'ignore> BufReadIter<'ignore> {
+    #[cfg(feature = "std")]
     pub(crate) fn from_read<'a>(read: &'a mut dyn Read) -> BufReadIter<'a> {
         BufReadIter {
             input_source: InputSource::Read(BufReadOrReader::BufReader(BufReader::with_capacity(
@@ -82,9 +98,12 @@ impl<'ignore> BufReadIter<'ignore> {
             limit_within_buf: 0,
             pos_of_buf_start: 0,
             limit: NO_LIMIT,
+            total_limit: NO_LIMIT,
+            max_total_bytes: NO_LIMIT,
         }
     }
 
+    #[cfg(feature = "std")]
     pub(crate) fn from_buf_read<'a>(buf_read: &'a mut dyn BufRead) -> BufReadIter<'a> {
         BufReadIter {
             input_source: InputSource::Read(BufReadOrReader::BufRead(buf_read)),
@@ -93,6 +112,8 @@ impl<'ignore> BufReadIter<'ignore> {
             limit_within_buf: 0,
             pos_of_buf_start: 0,
             limit: NO_LIMIT,
+            total_limit: NO_LIMIT,
+            max_total_bytes: NO_LIMIT,
         }
     }
 
@@ -104,6 +125,8 @@ impl<'ignore> BufReadIter<'ignore> {
             limit_within_buf: bytes.len(),
             pos_of_buf_start: 0,
             limit: NO_LIMIT,
+            total_limit: NO_LIMIT,
+            max_total_bytes: NO_LIMIT,
         }
     }
 
@@ -116,6 +139,8 @@ impl<'ignore> BufReadIter<'ignore> {
             limit_within_buf: bytes.len(),
             pos_of_buf_start: 0,
             limit: NO_LIMIT,
+            total_limit: NO_LIMIT,
+            max_total_bytes: NO_LIMIT,
         }
     }
 
@@ -150,6 +175,9 @@ impl<'ignore> BufReadIter<'ignore> {
         };
 
         if new_limit > self.limit {
+            if new_limit > self.total_limit {
+                return Err(self.total_limit_exceeded());
+            }
             return Err(ProtobufError::WireError(WireError::LimitIncrease).into());
         }
 
@@ -164,11 +192,81 @@ impl<'ignore> BufReadIter<'ignore> {
     pub(crate) fn pop_limit(&mut self, limit: u64) {
         assert!(limit >= self.limit);
 
-        self.limit = limit;
+        self.limit = cmp::min(limit, self.total_limit);
 
         self.update_limit_within_buf();
     }
 
+    /// Limit number of bytes read from current position.
+    ///
+    /// Unlike `push_limit`, reaching this limit is an error unless input ends there.
+    pub(crate) fn set_total_limit(&mut self, limit: u64) {
+        self.total_limit = self.pos().saturating_add(limit);
+        self.max_total_bytes = limit;
+        if self.total_limit < self.limit {
+            self.limit = self.total_limit;
+            self.update_limit_within_buf();
+        }
+    }
+
+    #[cold]
+    fn total_limit_exceeded(&self) -> crate::Error {
+        WireError::DecodeLimitExceeded("max_total_bytes", self.max_total_bytes).into()
+    }
+
+    /// Error when current limit is reached, but more bytes are needed.
+    #[cold]
+    fn limit_reached_error(&mut self, e: WireError) -> crate::Error {
+        if self.pos() == self.total_limit {
+            match self.has_bytes_after_limit() {
+                Ok(true) => return self.total_limit_exceeded(),
+                Ok(false) => {}
+                Err(e) => return e,
+            }
+        }
+        e.into()
+    }
+
+    /// Error when there are less than `len` bytes before current limit.
+    #[cold]
+    fn truncated_error(&self, len: u64, e: WireError) -> crate::Error {
+        if self.pos().saturating_add(len) > self.total_limit {
+            self.total_limit_exceeded()
+        } else {
+            e.into()
+        }
+    }
+
+    /// Check if underlying input has data after current position,
+    /// which must be at limit.
+    #[cold]
+    fn has_bytes_after_limit(&mut self) -> Result<bool> {
+        debug_assert!(self.pos() == self.limit);
+
+        if self.pos_within_buf < self.buf.len() {
+            return Ok(true);
+        }
+
+        match self.input_source {
+            #[cfg(feature = "std")]
+            InputSource::Read(ref mut buf_read) => {
+                let consume = self.buf.len();
+                self.pos_of_buf_start += self.buf.len() as u64;
+                self.buf = &[];
+                self.pos_within_buf = 0;
+                self.limit_within_buf = 0;
+
+                buf_read.consume(consume);
+                self.buf = unsafe { mem::transmute::<&[u8], &[u8]>(buf_read.fill_buf()?) };
+
+                self.update_limit_within_buf();
+
+                Ok(!self.buf.is_empty())
+            }
+            _ => Ok(false),
+        }
+    }
+
     #[inline]
     pub(crate) fn remaining_in_buf(&self) -> &[u8] {
         if USE_UNSAFE_FOR_SPEED {
@@ -199,7 +297,11 @@ impl<'ignore> BufReadIter<'ignore> {
     #[inline(always)]
     pub(crate) fn eof(&mut self) -> Result<bool> {
         if self.pos_within_buf == self.limit_within_buf {
-            Ok(self.fill_buf()?.is_empty())
+            let eof = self.fill_buf()?.is_empty();
+            if eof && self.pos() == self.total_limit && self.has_bytes_after_limit()? {
+                return Err(self.total_limit_exceeded());
+            }
+            Ok(eof)
         } else {
             Ok(false)
         }
@@ -210,7 +312,7 @@ impl<'ignore> BufReadIter<'ignore> {
         if self.pos_within_buf == self.limit_within_buf {
             self.do_fill_buf()?;
             if self.remaining_in_buf_len() == 0 {
-                return Err(ProtobufError::WireError(WireError::UnexpectedEof).into());
+                return Err(self.limit_reached_error(WireError::UnexpectedEof));
             }
         }
 
@@ -232,7 +334,7 @@ impl<'ignore> BufReadIter<'ignore> {
             };
 
             if end > self.limit_within_buf {
-                return Err(ProtobufError::WireError(WireError::UnexpectedEof).into());
+                return Err(self.truncated_error(len as u64, WireError::UnexpectedEof));
             }
 
             let r = bytes.slice(self.pos_within_buf..end);
@@ -264,6 +366,7 @@ impl<'ignore> BufReadIter<'ignore> {
     }
 
     /// Returns 0 when EOF or limit reached.
+    #[cfg(feature = "std")]
     pub(crate) fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
         let rem = self.fill_buf()?;
 
@@ -285,9 +388,11 @@ impl<'ignore> BufReadIter<'ignore> {
         Ok(len)
     }
 
+    // Only slices can be read without `std`.
+    #[cfg_attr(not(feature = "std"), allow(unreachable_code, unused_variables))]
     fn read_exact_slow(&mut self, buf: &mut [MaybeUninit<u8>]) -> Result<()> {
         if self.bytes_until_limit() < buf.len() as u64 {
-            return Err(ProtobufError::WireError(WireError::UnexpectedEof).into());
+            return Err(self.truncated_error(buf.len() as u64, WireError::UnexpectedEof));
         }
 
         let consume = self.pos_within_buf;
@@ -297,6 +402,7 @@ impl<'ignore> BufReadIter<'ignore> {
         self.limit_within_buf = 0;
 
         match self.input_source {
+            #[cfg(feature = "std")]
             InputSource::Read(ref mut buf_read) => {
                 buf_read.consume(consume);
                 buf_read.read_exact_uninit(buf)?;
@@ -333,7 +439,7 @@ impl<'ignore> BufReadIter<'ignore> {
     pub(crate) fn read_exact_to_vec(&mut self, count: usize, target: &mut Vec<u8>) -> Result<()> {
         // TODO: also do some limits when reading from unlimited source
         if count as u64 > self.bytes_until_limit() {
-            return Err(ProtobufError::WireError(WireError::TruncatedMessage).into());
+            return Err(self.truncated_error(count as u64, WireError::TruncatedMessage));
         }
 
         target.clear();
@@ -370,6 +476,7 @@ impl<'ignore> BufReadIter<'ignore> {
         Ok(())
     }
 
+    #[cfg_attr(not(feature = "std"), allow(unreachable_code, unused_variables))]
     fn do_fill_buf(&mut self) -> Result<()> {
         debug_assert!(self.pos_within_buf == self.limit_within_buf);
 
@@ -386,6 +493,7 @@ impl<'ignore> BufReadIter<'ignore> {
         self.limit_within_buf = 0;
 
         match self.input_source {
+            #[cfg(feature = "std")]
             InputSource::Read(ref mut buf_read) => {
                 buf_read.consume(consume);
                 self.buf = unsafe { mem::transmute(buf_read.fill_buf()?) };
@@ -1,7 +1,7 @@
-use std::hash::Hash;
-use std::hash::Hasher;
-use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
 
 /// Cached size field used in generated code.
 ///
@@ -1,8 +1,16 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem;
+use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
 use std::io::BufRead;
+#[cfg(feature = "std")]
 use std::io::Read;
-use std::mem;
-use std::mem::MaybeUninit;
 
 #[cfg(feature = "bytes")]
 use ::bytes::Bytes;
@@ -29,6 +37,7 @@ use crate::reflect::types::ProtobufTypeSint32;
 use crate::reflect::types::ProtobufTypeSint64;
 use crate::reflect::types::ProtobufTypeUint32;
 use crate::reflect::types::ProtobufTypeUint64;
+#[cfg(feature = "std")]
 use crate::reflect::MessageDescriptor;
 use crate::unknown::UnknownValue;
 use crate::wire_format;
@@ -37,10 +46,12 @@ use crate::zigzag::decode_zig_zag_32;
 use crate::zigzag::decode_zig_zag_64;
 use crate::EnumOrUnknown;
 use crate::Message;
+#[cfg(feature = "std")]
 use crate::MessageDyn;
+use crate::ParseOptions;
 
 // Default recursion level limit. 100 is the default value of C++'s implementation.
-const DEFAULT_RECURSION_LIMIT: u32 = 100;
+pub(crate) const DEFAULT_RECURSION_LIMIT: u32 = 100;
 
 // Max allocated vec when reading length-delimited from unknown input stream
 pub(crate) const READ_RAW_BYTES_MAX_ALLOC: usize = 10_000_000;
@@ -50,12 +61,25 @@ pub struct CodedInputStream<'a> {
     source: BufReadIter<'a>,
     recursion_level: u32,
     recursion_limit: u32,
+    max_string_len: u32,
+    max_bytes_len: u32,
+    max_repeated_len: usize,
+    max_map_entries: usize,
+    /// Field number of the group being read, if any.
+    current_group: Option<u32>,
+    /// Set when `END_GROUP` tag of `current_group` is consumed,
+    /// so the message parsing loop can stop.
+    group_ended: bool,
+    /// Number of the field being read in the current message, zero if none.
+    /// Used to report field path in errors.
+    current_field: u32,
 }
 
 impl<'a> CodedInputStream<'a> {
     /// Wrap a `Read`.
     ///
     /// Note resulting `CodedInputStream` is buffered even if `Read` is not.
+    #[cfg(feature = "std")]
     pub fn new(read: &'a mut dyn Read) -> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_read(read))
     }
@@ -63,6 +87,7 @@ impl<'a> CodedInputStream<'a> {
     /// Create from `BufRead`.
     ///
     /// `CodedInputStream` will utilize `BufRead` buffer.
+    #[cfg(feature = "std")]
     pub fn from_buffered_reader(buf_read: &'a mut dyn BufRead) -> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_buf_read(buf_read))
     }
@@ -87,6 +112,13 @@ impl<'a> CodedInputStream<'a> {
             source: source,
             recursion_level: 0,
             recursion_limit: DEFAULT_RECURSION_LIMIT,
+            max_string_len: u32::MAX,
+            max_bytes_len: u32::MAX,
+            max_repeated_len: usize::MAX,
+            max_map_entries: usize::MAX,
+            current_group: None,
+            group_ended: false,
+            current_field: 0,
         }
     }
 
@@ -95,6 +127,70 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_limit = limit;
     }
 
+    /// Apply decode limits from given options.
+    ///
+    /// Total bytes limit is counted from current position.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.recursion_limit = options.recursion_limit;
+        if let Some(max_total_bytes) = options.max_total_bytes {
+            self.source.set_total_limit(max_total_bytes);
+        }
+        self.max_string_len = options.max_string_len.unwrap_or(u32::MAX);
+        self.max_bytes_len = options.max_bytes_len.unwrap_or(u32::MAX);
+        self.max_repeated_len = options.max_repeated_len.unwrap_or(usize::MAX);
+        self.max_map_entries = options.max_map_entries.unwrap_or(usize::MAX);
+    }
+
+    /// Check the number of elements of repeated field being read.
+    #[inline]
+    pub(crate) fn check_repeated_len(&self, len: usize) -> crate::Result<()> {
+        if len > self.max_repeated_len {
+            return Err(WireError::DecodeLimitExceeded(
+                "max_repeated_len",
+                self.max_repeated_len as u64,
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Check the number of entries of map field being read.
+    #[inline]
+    pub(crate) fn check_map_len(&self, len: usize) -> crate::Result<()> {
+        if len > self.max_map_entries {
+            return Err(WireError::DecodeLimitExceeded(
+                "max_map_entries",
+                self.max_map_entries as u64,
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn read_string_len(&mut self) -> crate::Result<u32> {
+        let len = self.read_raw_varint32()?;
+        if len > self.max_string_len {
+            return Err(WireError::DecodeLimitExceeded(
+                "max_string_len",
+                self.max_string_len as u64,
+            )
+            .into());
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn read_bytes_len(&mut self) -> crate::Result<u32> {
+        let len = self.read_raw_varint32()?;
+        if len > self.max_bytes_len {
+            return Err(
+                WireError::DecodeLimitExceeded("max_bytes_len", self.max_bytes_len as u64).into(),
+            );
+        }
+        Ok(len)
+    }
+
     #[inline]
     pub(crate) fn incr_recursion(&mut self) -> crate::Result<()> {
         if self.recursion_level >= self.recursion_limit {
@@ -151,8 +247,14 @@ impl<'a> CodedInputStream<'a> {
     }
 
     /// Are we at EOF?
+    ///
+    /// When reading a group, this also returns `true` after the group `END_GROUP` tag
+    /// has been read.
     #[inline(always)]
     pub fn eof(&mut self) -> crate::Result<bool> {
+        if self.group_ended {
+            return Ok(true);
+        }
         self.source.eof()
     }
 
@@ -276,7 +378,38 @@ impl<'a> CodedInputStream<'a> {
     /// Read tag, return it is pair (field number, wire type)
     #[inline]
     pub fn read_tag_unpack(&mut self) -> crate::Result<(u32, WireType)> {
-        self.read_tag().map(|t| t.unpack())
+        let tag = self.read_tag()?;
+        self.current_field = tag.field_number();
+        Ok(tag.unpack())
+    }
+
+    /// Read tag of the next field, or return `None` at the end of the message.
+    ///
+    /// Field number is remembered, so decode errors report the field path.
+    #[inline]
+    pub fn read_raw_tag_or_eof(&mut self) -> crate::Result<Option<u32>> {
+        self.current_field = 0;
+        if self.eof()? {
+            return Ok(None);
+        }
+        let tag = self.read_raw_varint32()?;
+        self.current_field = tag >> 3;
+        Ok(Some(tag))
+    }
+
+    /// Decode a nested message (or the top-level message) with `merge`.
+    ///
+    /// If `merge` fails, the error is annotated with the stream position
+    /// and the number of the field being decoded in the nested message.
+    #[inline]
+    pub(crate) fn merge_nested<R>(
+        &mut self,
+        merge: impl FnOnce(&mut CodedInputStream<'a>) -> crate::Result<R>,
+    ) -> crate::Result<R> {
+        let outer_field = mem::replace(&mut self.current_field, 0);
+        let r = merge(self);
+        let field = mem::replace(&mut self.current_field, outer_field);
+        r.map_err(|e| e.with_field_context(self.pos(), field))
     }
 
     /// Read `double`
@@ -378,11 +511,12 @@ impl<'a> CodedInputStream<'a> {
             READ_RAW_BYTES_MAX_ALLOC / (T::ENCODED_SIZE as usize)
         };
 
-        target.reserve(reserve);
+        target.reserve(cmp::min(reserve, self.max_repeated_len));
 
         let old_limit = self.push_limit(len_bytes)?;
         while !self.eof()? {
             target.push(T::read(self)?);
+            self.check_repeated_len(target.len())?;
         }
         self.pop_limit(old_limit);
         Ok(())
@@ -402,11 +536,12 @@ impl<'a> CodedInputStream<'a> {
             READ_RAW_BYTES_MAX_ALLOC
         };
 
-        target.reserve(reserve);
+        target.reserve(cmp::min(reserve, self.max_repeated_len));
 
         let old_limit = self.push_limit(len_bytes)?;
         while !self.eof()? {
             target.push(T::read(self)?);
+            self.check_repeated_len(target.len())?;
         }
         self.pop_limit(old_limit);
         Ok(())
@@ -490,6 +625,7 @@ impl<'a> CodedInputStream<'a> {
     }
 
     /// Read repeated packed enum values into the vector.
+    #[cfg(feature = "std")]
     pub(crate) fn read_repeated_packed_enum_values_into(
         &mut self,
         target: &mut Vec<i32>,
@@ -546,20 +682,21 @@ impl<'a> CodedInputStream<'a> {
     /// Read `bytes` field, length delimited
     #[cfg(feature = "bytes")]
     pub fn read_tokio_bytes(&mut self) -> crate::Result<Bytes> {
-        let len = self.read_raw_varint32()?;
+        let len = self.read_bytes_len()?;
         self.read_raw_tokio_bytes(len as usize)
     }
 
     /// Read `string` field, length delimited
     #[cfg(feature = "bytes")]
     pub fn read_tokio_chars(&mut self) -> crate::Result<Chars> {
-        let bytes = self.read_tokio_bytes()?;
+        let len = self.read_string_len()?;
+        let bytes = self.read_raw_tokio_bytes(len as usize)?;
         Ok(Chars::from_bytes(bytes).map_err(ProtobufError::Utf8)?)
     }
 
     /// Read `bytes` field, length delimited
     pub fn read_bytes_into(&mut self, target: &mut Vec<u8>) -> crate::Result<()> {
-        let len = self.read_raw_varint32()?;
+        let len = self.read_bytes_len()?;
         self.read_raw_bytes_into(len, target)?;
         Ok(())
     }
@@ -576,7 +713,8 @@ impl<'a> CodedInputStream<'a> {
         target.clear();
         // take target's buffer
         let mut vec = mem::replace(target, String::new()).into_bytes();
-        self.read_bytes_into(&mut vec)?;
+        let len = self.read_string_len()?;
+        self.read_raw_bytes_into(len, &mut vec)?;
 
         let s = match String::from_utf8(vec) {
             Ok(t) => t,
@@ -586,6 +724,80 @@ impl<'a> CodedInputStream<'a> {
         Ok(())
     }
 
+    /// Consume `END_GROUP` tag with given field number.
+    ///
+    /// Fails if tag does not match the group currently being read.
+    pub(crate) fn end_group(&mut self, field_number: u32) -> crate::Result<()> {
+        if self.current_group != Some(field_number) || self.group_ended {
+            return Err(WireError::UnexpectedWireType(WireType::EndGroup).into());
+        }
+        self.group_ended = true;
+        Ok(())
+    }
+
+    fn merge_group_impl(
+        &mut self,
+        field_number: u32,
+        merge: impl FnOnce(&mut CodedInputStream<'a>) -> crate::Result<()>,
+    ) -> crate::Result<()> {
+        self.incr_recursion()?;
+        let prev_group = self.current_group.replace(field_number);
+        let r = self.merge_nested(merge);
+        let ended = mem::replace(&mut self.group_ended, false);
+        self.current_group = prev_group;
+        self.decr_recursion();
+        r?;
+        if !ended {
+            return Err(WireError::UnexpectedEof.into());
+        }
+        Ok(())
+    }
+
+    /// Read group fields into a message, do not check if message is initialized.
+    ///
+    /// `START_GROUP` tag must be already consumed.
+    /// Reads up to and including the matching `END_GROUP` tag.
+    pub fn merge_group<M: Message>(
+        &mut self,
+        field_number: u32,
+        message: &mut M,
+    ) -> crate::Result<()> {
+        self.merge_group_impl(field_number, |is| message.merge_from(is))
+    }
+
+    /// Like `merge_group`, but for dynamic messages.
+    #[cfg(feature = "std")]
+    pub fn merge_group_dyn(
+        &mut self,
+        field_number: u32,
+        message: &mut dyn MessageDyn,
+    ) -> crate::Result<()> {
+        self.merge_group_impl(field_number, |is| message.merge_from_dyn(is))
+    }
+
+    /// Read group.
+    ///
+    /// `START_GROUP` tag must be already consumed.
+    pub fn read_group<M: Message>(&mut self, field_number: u32) -> crate::Result<M> {
+        let mut r: M = Message::new();
+        self.merge_group(field_number, &mut r)?;
+        r.check_initialized()?;
+        Ok(r)
+    }
+
+    /// Read group as dynamic message.
+    #[cfg(feature = "std")]
+    pub fn read_group_dyn(
+        &mut self,
+        field_number: u32,
+        descriptor: &MessageDescriptor,
+    ) -> crate::Result<Box<dyn MessageDyn>> {
+        let mut r = descriptor.new_instance();
+        self.merge_group_dyn(field_number, &mut *r)?;
+        r.check_initialized_dyn()?;
+        Ok(r)
+    }
+
     /// Read message, do not check if message is initialized
     pub fn merge_message<M: Message>(&mut self, message: &mut M) -> crate::Result<()> {
         self.incr_recursion()?;
@@ -596,20 +808,26 @@ impl<'a> CodedInputStream<'a> {
             }
         }
 
-        let mut decr = DecrRecursion(self);
+        let decr = DecrRecursion(self);
 
         let len = decr.0.read_raw_varint64()?;
         let old_limit = decr.0.push_limit(len)?;
-        message.merge_from(&mut decr.0)?;
+        // `END_GROUP` of enclosing group must not appear inside nested message
+        let group = decr.0.current_group.take();
+        decr.0.merge_nested(|is| message.merge_from(is))?;
+        decr.0.current_group = group;
         decr.0.pop_limit(old_limit);
         Ok(())
     }
 
     /// Like `merge_message`, but for dynamic messages.
+    #[cfg(feature = "std")]
     pub fn merge_message_dyn(&mut self, message: &mut dyn MessageDyn) -> crate::Result<()> {
         let len = self.read_raw_varint64()?;
         let old_limit = self.push_limit(len)?;
-        message.merge_from_dyn(self)?;
+        let group = self.current_group.take();
+        self.merge_nested(|is| message.merge_from_dyn(is))?;
+        self.current_group = group;
         self.pop_limit(old_limit);
         Ok(())
     }
@@ -623,6 +841,7 @@ impl<'a> CodedInputStream<'a> {
     }
 
     /// Read message.
+    #[cfg(feature = "std")]
     pub fn read_message_dyn(
         &mut self,
         descriptor: &MessageDescriptor,
@@ -634,12 +853,14 @@ impl<'a> CodedInputStream<'a> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a> Read for CodedInputStream<'a> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.source.read(buf).map_err(Into::into)
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a> BufRead for CodedInputStream<'a> {
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         self.source.fill_buf().map_err(Into::into)
@@ -660,8 +881,8 @@ mod test {
 
     use super::CodedInputStream;
     use super::READ_RAW_BYTES_MAX_ALLOC;
-    use crate::error::ProtobufError;
     use crate::hex::decode_hex;
+    use crate::ErrorKind;
 
     fn test_read_partial<F>(hex: &str, mut callback: F)
     where
@@ -733,29 +954,20 @@ mod test {
     fn test_input_stream_read_raw_vaint_malformed() {
         // varint cannot have length > 10
         test_read_partial("ff ff ff ff ff ff ff ff ff ff 01", |reader| {
-            let error = reader.read_raw_varint64().unwrap_err().0;
-            match *error {
-                ProtobufError::WireError(..) => (),
-                _ => panic!(),
-            }
+            let error = reader.read_raw_varint64().unwrap_err();
+            assert_eq!(ErrorKind::IncorrectVarint, error.kind());
         });
         test_read_partial("ff ff ff ff ff ff ff ff ff ff 01", |reader| {
-            let error = reader.read_raw_varint32().unwrap_err().0;
-            match *error {
-                ProtobufError::WireError(..) => (),
-                _ => panic!(),
-            }
+            let error = reader.read_raw_varint32().unwrap_err();
+            assert_eq!(ErrorKind::IncorrectVarint, error.kind());
         });
     }
 
     #[test]
     fn test_input_stream_read_raw_varint_unexpected_eof() {
         test_read_partial("96 97", |reader| {
-            let error = reader.read_raw_varint32().unwrap_err().0;
-            match *error {
-                ProtobufError::WireError(..) => (),
-                _ => panic!(),
-            }
+            let error = reader.read_raw_varint32().unwrap_err();
+            assert_eq!(ErrorKind::UnexpectedEof, error.kind());
         });
     }
 
@@ -1,9 +1,12 @@
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
 use std::io::Write;
-use std::mem::MaybeUninit;
-use std::ptr;
-use std::slice;
 
 use crate::error::ProtobufError;
 use crate::misc::maybe_uninit_write_slice;
@@ -12,8 +15,10 @@ use crate::rt::vec_packed_enum_or_unknown_data_size;
 use crate::rt::vec_packed_fixed_data_size;
 use crate::rt::vec_packed_varint_data_size;
 use crate::rt::vec_packed_varint_zigzag_data_size;
+use crate::rt::write_group_field_with_cached_size;
 use crate::varint;
 use crate::wire_format;
+#[cfg(feature = "std")]
 use crate::wire_format::check_message_size;
 use crate::wire_format::WireType;
 use crate::zigzag::encode_zig_zag_32;
@@ -21,21 +26,24 @@ use crate::zigzag::encode_zig_zag_64;
 use crate::Enum;
 use crate::EnumOrUnknown;
 use crate::Message;
+#[cfg(feature = "std")]
 use crate::MessageDyn;
-use crate::MessageFull;
 use crate::UnknownFields;
 use crate::UnknownValueRef;
 
 // Equal to the default buffer size of `BufWriter`, so when
 // `CodedOutputStream` wraps `BufWriter`, it often skips double buffering.
+#[cfg(feature = "std")]
 const OUTPUT_STREAM_BUFFER_SIZE: usize = 8 * 1024;
 
+#[cfg(feature = "std")]
 pub trait WithCodedOutputStream {
     fn with_coded_output_stream<T, F>(self, cb: F) -> crate::Result<T>
     where
         F: FnOnce(&mut CodedOutputStream) -> crate::Result<T>;
 }
 
+#[cfg(feature = "std")]
 impl<'a> WithCodedOutputStream for &'a mut (dyn Write + 'a) {
     fn with_coded_output_stream<T, F>(self, cb: F) -> crate::Result<T>
     where
@@ -48,6 +56,7 @@ impl<'a> WithCodedOutputStream for &'a mut (dyn Write + 'a) {
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a> WithCodedOutputStream for &'a mut Vec<u8> {
     fn with_coded_output_stream<T, F>(mut self, cb: F) -> crate::Result<T>
     where
@@ -62,6 +71,7 @@ impl<'a> WithCodedOutputStream for &'a mut Vec<u8> {
 
 /// Output buffer/writer for `CodedOutputStream`.
 enum OutputTarget<'a> {
+    #[cfg(feature = "std")]
     Write(&'a mut dyn Write, Vec<u8>),
     Vec(&'a mut Vec<u8>),
     /// The buffer is passed as `&[u8]` to `CodedOutputStream` constructor
@@ -75,6 +85,7 @@ enum OutputTarget<'a> {
 impl<'a> fmt::Debug for OutputTarget<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            #[cfg(feature = "std")]
             OutputTarget::Write(_w, vec) => f
                 .debug_struct("Write")
                 .field("buf_len", &vec.len())
@@ -105,12 +116,15 @@ pub struct CodedOutputStream<'a> {
     pos_within_buf: usize,
     /// Absolute position of the buffer start.
     pos_of_buffer_start: u64,
+    /// Write map entries and unknown fields sorted.
+    deterministic: bool,
 }
 
 impl<'a> CodedOutputStream<'a> {
     /// Construct from given `Write`.
     ///
     /// `CodedOutputStream` is buffered even if `Write` is not
+    #[cfg(feature = "std")]
     pub fn new(writer: &'a mut dyn Write) -> CodedOutputStream<'a> {
         let buffer_len = OUTPUT_STREAM_BUFFER_SIZE;
 
@@ -127,6 +141,7 @@ impl<'a> CodedOutputStream<'a> {
             buffer,
             pos_within_buf: 0,
             pos_of_buffer_start: 0,
+            deterministic: false,
         }
     }
 
@@ -142,6 +157,7 @@ impl<'a> CodedOutputStream<'a> {
             buffer,
             pos_within_buf: 0,
             pos_of_buffer_start: 0,
+            deterministic: false,
         }
     }
 
@@ -153,9 +169,43 @@ impl<'a> CodedOutputStream<'a> {
             buffer,
             pos_within_buf: 0,
             pos_of_buffer_start: 0,
+            deterministic: false,
         }
     }
 
+    /// Enable or disable deterministic serialization.
+    ///
+    /// In deterministic mode map entries are written ordered by key,
+    /// and unknown fields (including extensions of generated messages)
+    /// are written ordered by field number.
+    /// So the same message is always serialized to the same bytes
+    /// by the same version of this library.
+    ///
+    /// Note deterministic serialization is not canonical:
+    /// serialized bytes may differ between protobuf implementations or versions.
+    ///
+    /// ```
+    /// # use protobuf::CodedOutputStream;
+    /// # use protobuf::Message;
+    /// # fn write<M: Message>(message: &M) -> protobuf::Result<Vec<u8>> {
+    /// let mut bytes = Vec::new();
+    /// let mut os = CodedOutputStream::vec(&mut bytes);
+    /// os.set_deterministic(true);
+    /// message.write_to(&mut os)?;
+    /// os.flush()?;
+    /// drop(os);
+    /// # Ok(bytes)
+    /// # }
+    /// ```
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Is deterministic serialization enabled?
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
     /// Total number of bytes written to this stream.
     ///
     /// This number may be larger than the actual number of bytes written to the underlying stream,
@@ -176,7 +226,11 @@ impl<'a> CodedOutputStream<'a> {
             OutputTarget::Bytes => {
                 assert_eq!(self.buffer().len() as u64, self.pos_within_buf as u64);
             }
-            OutputTarget::Write(..) | OutputTarget::Vec(..) => {
+            #[cfg(feature = "std")]
+            OutputTarget::Write(..) => {
+                panic!("must not be called with Writer or Vec");
+            }
+            OutputTarget::Vec(..) => {
                 panic!("must not be called with Writer or Vec");
             }
         }
@@ -188,6 +242,7 @@ impl<'a> CodedOutputStream<'a> {
         unsafe { &*(self.buffer as *mut [MaybeUninit<u8>]) }
     }
 
+    #[cfg(feature = "std")]
     #[inline(always)]
     fn filled_buffer_impl<'s>(buffer: *mut [MaybeUninit<u8>], position: usize) -> &'s [u8] {
         // SAFETY: this function is safe assuming `buffer` and `position`
@@ -199,6 +254,7 @@ impl<'a> CodedOutputStream<'a> {
 
     fn refresh_buffer(&mut self) -> crate::Result<()> {
         match self.target {
+            #[cfg(feature = "std")]
             OutputTarget::Write(ref mut write, _) => {
                 write.write_all(Self::filled_buffer_impl(self.buffer, self.pos_within_buf))?;
                 self.pos_of_buffer_start += self.pos_within_buf as u64;
@@ -214,11 +270,7 @@ impl<'a> CodedOutputStream<'a> {
                 self.pos_within_buf = 0;
             },
             OutputTarget::Bytes => {
-                return Err(ProtobufError::IoError(io::Error::new(
-                    io::ErrorKind::Other,
-                    "given slice is too small to serialize the message",
-                ))
-                .into());
+                return Err(ProtobufError::BufferTooSmall.into());
             }
         }
         Ok(())
@@ -243,6 +295,7 @@ impl<'a> CodedOutputStream<'a> {
                 self.pos_within_buf = 0;
                 Ok(())
             }
+            #[cfg(feature = "std")]
             OutputTarget::Write(..) => self.refresh_buffer(),
         }
     }
@@ -287,6 +340,7 @@ impl<'a> CodedOutputStream<'a> {
             OutputTarget::Bytes => {
                 unreachable!();
             }
+            #[cfg(feature = "std")]
             OutputTarget::Write(ref mut write, _) => {
                 write.write_all(bytes)?;
             }
@@ -579,7 +633,12 @@ impl<'a> CodedOutputStream<'a> {
     }
 
     /// Write unknown fields
+    ///
+    /// Fields are sorted by number in deterministic mode.
     pub fn write_unknown_fields(&mut self, fields: &UnknownFields) -> crate::Result<()> {
+        if self.deterministic {
+            return self.write_unknown_fields_sorted(fields);
+        }
         for (number, values) in fields {
             for value in values {
                 self.write_unknown(number, value)?;
@@ -621,6 +680,7 @@ impl<'a> CodedOutputStream<'a> {
     }
 
     /// Write dynamic message
+    #[cfg(feature = "std")]
     pub fn write_message_no_tag_dyn(&mut self, msg: &dyn MessageDyn) -> crate::Result<()> {
         let size = msg.compute_size_dyn();
         let size = check_message_size(size)?;
@@ -992,17 +1052,14 @@ impl<'a> CodedOutputStream<'a> {
     }
 
     /// Write `message` field
-    pub fn write_message<M: MessageFull>(
-        &mut self,
-        field_number: u32,
-        msg: &M,
-    ) -> crate::Result<()> {
+    pub fn write_message<M: Message>(&mut self, field_number: u32, msg: &M) -> crate::Result<()> {
         self.write_tag(field_number, WireType::LengthDelimited)?;
         self.write_message_no_tag(msg)?;
         Ok(())
     }
 
     /// Write dynamic `message` field
+    #[cfg(feature = "std")]
     pub fn write_message_dyn(
         &mut self,
         field_number: u32,
@@ -1012,8 +1069,29 @@ impl<'a> CodedOutputStream<'a> {
         self.write_message_no_tag_dyn(msg)?;
         Ok(())
     }
+
+    /// Write `group` field
+    pub fn write_group<M: Message>(&mut self, field_number: u32, msg: &M) -> crate::Result<()> {
+        msg.compute_size();
+        write_group_field_with_cached_size(field_number, msg, self)
+    }
+
+    /// Write dynamic `group` field
+    #[cfg(feature = "std")]
+    pub fn write_group_dyn(
+        &mut self,
+        field_number: u32,
+        msg: &dyn MessageDyn,
+    ) -> crate::Result<()> {
+        self.write_tag(field_number, WireType::StartGroup)?;
+        msg.compute_size_dyn();
+        msg.write_to_with_cached_sizes_dyn(self)?;
+        self.write_tag(field_number, WireType::EndGroup)?;
+        Ok(())
+    }
 }
 
+#[cfg(feature = "std")]
 impl<'a> Write for CodedOutputStream<'a> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.write_raw_bytes(buf)?;
@@ -28,7 +28,7 @@
 pub struct FileDescriptorSet {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorSet.file)
-    pub file: ::std::vec::Vec<FileDescriptorProto>,
+    pub file: crate::rt::Vec<FileDescriptorProto>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FileDescriptorSet.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -36,7 +36,7 @@ pub struct FileDescriptorSet {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FileDescriptorSet {
+impl<'a> ::core::default::Default for &'a FileDescriptorSet {
     fn default() -> &'a FileDescriptorSet {
         <FileDescriptorSet as crate::Message>::default_instance()
     }
@@ -44,11 +44,11 @@ impl<'a> ::std::default::Default for &'a FileDescriptorSet {
 
 impl FileDescriptorSet {
     pub fn new() -> FileDescriptorSet {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "file",
             |m: &FileDescriptorSet| { &m.file },
@@ -74,19 +74,30 @@ impl crate::Message for FileDescriptorSet {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.file.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "file", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.file.push(is.read_message()?);
+                    self.file.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.file.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.file.extend(other.file.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -107,7 +118,15 @@ impl crate::Message for FileDescriptorSet {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.file.iter().rev() {
+            crate::rt::write_message_field_reverse(1, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -133,7 +152,7 @@ impl crate::Message for FileDescriptorSet {
 
     fn default_instance() -> &'static FileDescriptorSet {
         static instance: FileDescriptorSet = FileDescriptorSet {
-            file: ::std::vec::Vec::new(),
+            file: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -147,8 +166,8 @@ impl crate::MessageFull for FileDescriptorSet {
     }
 }
 
-impl ::std::fmt::Display for FileDescriptorSet {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FileDescriptorSet {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -162,29 +181,31 @@ impl crate::reflect::ProtobufValue for FileDescriptorSet {
 // @@protoc_insertion_point(message:google.protobuf.FileDescriptorProto)
 pub struct FileDescriptorProto {
     // message fields
+    ///  file name, relative to root of source tree
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
+    ///  e.g. "foo", "foo.bar", etc.
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.package)
-    package: ::std::option::Option<::std::string::String>,
+    package: ::core::option::Option<crate::rt::String>,
     ///  Names of files imported by this file.
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.dependency)
-    pub dependency: ::std::vec::Vec<::std::string::String>,
+    pub dependency: crate::rt::Vec<crate::rt::String>,
     ///  Indexes of the public imported files in the dependency list above.
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.public_dependency)
-    pub public_dependency: ::std::vec::Vec<i32>,
+    pub public_dependency: crate::rt::Vec<i32>,
     ///  Indexes of the weak imported files in the dependency list.
     ///  For Google-internal migration only. Do not use.
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.weak_dependency)
-    pub weak_dependency: ::std::vec::Vec<i32>,
+    pub weak_dependency: crate::rt::Vec<i32>,
     ///  All top-level definitions in this file.
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.message_type)
-    pub message_type: ::std::vec::Vec<DescriptorProto>,
+    pub message_type: crate::rt::Vec<DescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.enum_type)
-    pub enum_type: ::std::vec::Vec<EnumDescriptorProto>,
+    pub enum_type: crate::rt::Vec<EnumDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.service)
-    pub service: ::std::vec::Vec<ServiceDescriptorProto>,
+    pub service: crate::rt::Vec<ServiceDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.extension)
-    pub extension: ::std::vec::Vec<FieldDescriptorProto>,
+    pub extension: crate::rt::Vec<FieldDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.options)
     pub options: crate::MessageField<FileOptions>,
     ///  This field contains optional information about the original source code.
@@ -196,7 +217,7 @@ pub struct FileDescriptorProto {
     ///  The syntax of the proto file.
     ///  The supported values are "proto2" and "proto3".
     // @@protoc_insertion_point(field:google.protobuf.FileDescriptorProto.syntax)
-    syntax: ::std::option::Option<::std::string::String>,
+    syntax: ::core::option::Option<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FileDescriptorProto.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -204,7 +225,7 @@ pub struct FileDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FileDescriptorProto {
+impl<'a> ::core::default::Default for &'a FileDescriptorProto {
     fn default() -> &'a FileDescriptorProto {
         <FileDescriptorProto as crate::Message>::default_instance()
     }
@@ -212,7 +233,7 @@ impl<'a> ::std::default::Default for &'a FileDescriptorProto {
 
 impl FileDescriptorProto {
     pub fn new() -> FileDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -225,7 +246,7 @@ impl FileDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -233,22 +254,22 @@ impl FileDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string package = 2;
@@ -261,7 +282,7 @@ impl FileDescriptorProto {
     }
 
     pub fn clear_package(&mut self) {
-        self.package = ::std::option::Option::None;
+        self.package = ::core::option::Option::None;
     }
 
     pub fn has_package(&self) -> bool {
@@ -269,22 +290,22 @@ impl FileDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_package(&mut self, v: ::std::string::String) {
-        self.package = ::std::option::Option::Some(v);
+    pub fn set_package(&mut self, v: crate::rt::String) {
+        self.package = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_package(&mut self) -> &mut ::std::string::String {
+    pub fn mut_package(&mut self) -> &mut crate::rt::String {
         if self.package.is_none() {
-            self.package = ::std::option::Option::Some(::std::string::String::new());
+            self.package = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.package.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_package(&mut self) -> ::std::string::String {
-        self.package.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_package(&mut self) -> crate::rt::String {
+        self.package.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string syntax = 12;
@@ -297,7 +318,7 @@ impl FileDescriptorProto {
     }
 
     pub fn clear_syntax(&mut self) {
-        self.syntax = ::std::option::Option::None;
+        self.syntax = ::core::option::Option::None;
     }
 
     pub fn has_syntax(&self) -> bool {
@@ -305,26 +326,26 @@ impl FileDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_syntax(&mut self, v: ::std::string::String) {
-        self.syntax = ::std::option::Option::Some(v);
+    pub fn set_syntax(&mut self, v: crate::rt::String) {
+        self.syntax = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_syntax(&mut self) -> &mut ::std::string::String {
+    pub fn mut_syntax(&mut self) -> &mut crate::rt::String {
         if self.syntax.is_none() {
-            self.syntax = ::std::option::Option::Some(::std::string::String::new());
+            self.syntax = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.syntax.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_syntax(&mut self) -> ::std::string::String {
-        self.syntax.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_syntax(&mut self) -> crate::rt::String {
+        self.syntax.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(12);
+        let mut fields = crate::rt::Vec::with_capacity(12);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &FileDescriptorProto| { &m.name },
@@ -433,42 +454,69 @@ impl crate::Message for FileDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.message_type.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "message_type", i), missing);
+        };
+        for (i, v) in self.enum_type.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "enum_type", i), missing);
+        };
+        for (i, v) in self.service.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "service", i), missing);
+        };
+        for (i, v) in self.extension.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "extension", i), missing);
+        };
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+        for v in &self.source_code_info {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "source_code_info"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.package = ::std::option::Option::Some(is.read_string()?);
+                    self.package = ::core::option::Option::Some(is.read_string()?);
                 },
                 26 => {
                     self.dependency.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.dependency.len())?;
                 },
                 82 => {
                     is.read_repeated_packed_int32_into(&mut self.public_dependency)?;
                 },
                 80 => {
                     self.public_dependency.push(is.read_int32()?);
+                    crate::rt::check_repeated_len(is, self.public_dependency.len())?;
                 },
                 90 => {
                     is.read_repeated_packed_int32_into(&mut self.weak_dependency)?;
                 },
                 88 => {
                     self.weak_dependency.push(is.read_int32()?);
+                    crate::rt::check_repeated_len(is, self.weak_dependency.len())?;
                 },
                 34 => {
-                    self.message_type.push(is.read_message()?);
+                    self.message_type.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.message_type.len())?;
                 },
                 42 => {
-                    self.enum_type.push(is.read_message()?);
+                    self.enum_type.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.enum_type.len())?;
                 },
                 50 => {
-                    self.service.push(is.read_message()?);
+                    self.service.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.service.len())?;
                 },
                 58 => {
-                    self.extension.push(is.read_message()?);
+                    self.extension.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.extension.len())?;
                 },
                 66 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
@@ -477,14 +525,40 @@ impl crate::Message for FileDescriptorProto {
                     crate::rt::read_singular_message_into_field(is, &mut self.source_code_info)?;
                 },
                 98 => {
-                    self.syntax = ::std::option::Option::Some(is.read_string()?);
+                    self.syntax = ::core::option::Option::Some(is.read_string()?);
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if other.package.is_some() {
+            self.package.clone_from(&other.package);
+        }
+        self.dependency.extend(other.dependency.iter().cloned());
+        self.public_dependency.extend(other.public_dependency.iter().cloned());
+        self.weak_dependency.extend(other.weak_dependency.iter().cloned());
+        self.message_type.extend(other.message_type.iter().cloned());
+        self.enum_type.extend(other.enum_type.iter().cloned());
+        self.service.extend(other.service.iter().cloned());
+        self.extension.extend(other.extension.iter().cloned());
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if let Some(v) = other.source_code_info.as_ref() {
+            self.source_code_info.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax.is_some() {
+            self.syntax.clone_from(&other.syntax);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -576,7 +650,48 @@ impl crate::Message for FileDescriptorProto {
             os.write_string(12, v)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.syntax.as_ref() {
+            os.write_string(12, v);
+        }
+        if let Some(v) = self.source_code_info.as_ref() {
+            crate::rt::write_message_field_reverse(9, v, os)?;
+        }
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(8, v, os)?;
+        }
+        for v in self.extension.iter().rev() {
+            crate::rt::write_message_field_reverse(7, v, os)?;
+        };
+        for v in self.service.iter().rev() {
+            crate::rt::write_message_field_reverse(6, v, os)?;
+        };
+        for v in self.enum_type.iter().rev() {
+            crate::rt::write_message_field_reverse(5, v, os)?;
+        };
+        for v in self.message_type.iter().rev() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        };
+        for v in self.weak_dependency.iter().rev() {
+            os.write_int32(11, *v);
+        };
+        for v in self.public_dependency.iter().rev() {
+            os.write_int32(10, *v);
+        };
+        for v in self.dependency.iter().rev() {
+            os.write_string(3, &v);
+        };
+        if let Some(v) = self.package.as_ref() {
+            os.write_string(2, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -596,8 +711,8 @@ impl crate::Message for FileDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
-        self.package = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
+        self.package = ::core::option::Option::None;
         self.dependency.clear();
         self.public_dependency.clear();
         self.weak_dependency.clear();
@@ -607,24 +722,24 @@ impl crate::Message for FileDescriptorProto {
         self.extension.clear();
         self.options.clear();
         self.source_code_info.clear();
-        self.syntax = ::std::option::Option::None;
+        self.syntax = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static FileDescriptorProto {
         static instance: FileDescriptorProto = FileDescriptorProto {
-            name: ::std::option::Option::None,
-            package: ::std::option::Option::None,
-            dependency: ::std::vec::Vec::new(),
-            public_dependency: ::std::vec::Vec::new(),
-            weak_dependency: ::std::vec::Vec::new(),
-            message_type: ::std::vec::Vec::new(),
-            enum_type: ::std::vec::Vec::new(),
-            service: ::std::vec::Vec::new(),
-            extension: ::std::vec::Vec::new(),
+            name: ::core::option::Option::None,
+            package: ::core::option::Option::None,
+            dependency: crate::rt::Vec::new(),
+            public_dependency: crate::rt::Vec::new(),
+            weak_dependency: crate::rt::Vec::new(),
+            message_type: crate::rt::Vec::new(),
+            enum_type: crate::rt::Vec::new(),
+            service: crate::rt::Vec::new(),
+            extension: crate::rt::Vec::new(),
             options: crate::MessageField::none(),
             source_code_info: crate::MessageField::none(),
-            syntax: ::std::option::Option::None,
+            syntax: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -638,8 +753,8 @@ impl crate::MessageFull for FileDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for FileDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FileDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -654,27 +769,27 @@ impl crate::reflect::ProtobufValue for FileDescriptorProto {
 pub struct DescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.field)
-    pub field: ::std::vec::Vec<FieldDescriptorProto>,
+    pub field: crate::rt::Vec<FieldDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.extension)
-    pub extension: ::std::vec::Vec<FieldDescriptorProto>,
+    pub extension: crate::rt::Vec<FieldDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.nested_type)
-    pub nested_type: ::std::vec::Vec<DescriptorProto>,
+    pub nested_type: crate::rt::Vec<DescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.enum_type)
-    pub enum_type: ::std::vec::Vec<EnumDescriptorProto>,
+    pub enum_type: crate::rt::Vec<EnumDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.extension_range)
-    pub extension_range: ::std::vec::Vec<descriptor_proto::ExtensionRange>,
+    pub extension_range: crate::rt::Vec<descriptor_proto::ExtensionRange>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.oneof_decl)
-    pub oneof_decl: ::std::vec::Vec<OneofDescriptorProto>,
+    pub oneof_decl: crate::rt::Vec<OneofDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.options)
     pub options: crate::MessageField<MessageOptions>,
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.reserved_range)
-    pub reserved_range: ::std::vec::Vec<descriptor_proto::ReservedRange>,
+    pub reserved_range: crate::rt::Vec<descriptor_proto::ReservedRange>,
     ///  Reserved field names, which may not be used by fields in the same message.
     ///  A given name may only be reserved once.
     // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.reserved_name)
-    pub reserved_name: ::std::vec::Vec<::std::string::String>,
+    pub reserved_name: crate::rt::Vec<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.DescriptorProto.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -682,7 +797,7 @@ pub struct DescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DescriptorProto {
+impl<'a> ::core::default::Default for &'a DescriptorProto {
     fn default() -> &'a DescriptorProto {
         <DescriptorProto as crate::Message>::default_instance()
     }
@@ -690,7 +805,7 @@ impl<'a> ::std::default::Default for &'a DescriptorProto {
 
 impl DescriptorProto {
     pub fn new() -> DescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -703,7 +818,7 @@ impl DescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -711,26 +826,26 @@ impl DescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(10);
+        let mut fields = crate::rt::Vec::with_capacity(10);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &DescriptorProto| { &m.name },
@@ -837,46 +952,98 @@ impl crate::Message for DescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.field.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "field", i), missing);
+        };
+        for (i, v) in self.extension.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "extension", i), missing);
+        };
+        for (i, v) in self.nested_type.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "nested_type", i), missing);
+        };
+        for (i, v) in self.enum_type.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "enum_type", i), missing);
+        };
+        for (i, v) in self.extension_range.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "extension_range", i), missing);
+        };
+        for (i, v) in self.oneof_decl.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "oneof_decl", i), missing);
+        };
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+        for (i, v) in self.reserved_range.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "reserved_range", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.field.push(is.read_message()?);
+                    self.field.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.field.len())?;
                 },
                 50 => {
-                    self.extension.push(is.read_message()?);
+                    self.extension.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.extension.len())?;
                 },
                 26 => {
-                    self.nested_type.push(is.read_message()?);
+                    self.nested_type.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.nested_type.len())?;
                 },
                 34 => {
-                    self.enum_type.push(is.read_message()?);
+                    self.enum_type.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.enum_type.len())?;
                 },
                 42 => {
-                    self.extension_range.push(is.read_message()?);
+                    self.extension_range.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.extension_range.len())?;
                 },
                 66 => {
-                    self.oneof_decl.push(is.read_message()?);
+                    self.oneof_decl.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.oneof_decl.len())?;
                 },
                 58 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
                 },
                 74 => {
-                    self.reserved_range.push(is.read_message()?);
+                    self.reserved_range.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.reserved_range.len())?;
                 },
                 82 => {
                     self.reserved_name.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.reserved_name.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        self.field.extend(other.field.iter().cloned());
+        self.extension.extend(other.extension.iter().cloned());
+        self.nested_type.extend(other.nested_type.iter().cloned());
+        self.enum_type.extend(other.enum_type.iter().cloned());
+        self.extension_range.extend(other.extension_range.iter().cloned());
+        self.oneof_decl.extend(other.oneof_decl.iter().cloned());
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.reserved_range.extend(other.reserved_range.iter().cloned());
+        self.reserved_name.extend(other.reserved_name.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -958,7 +1125,42 @@ impl crate::Message for DescriptorProto {
             os.write_string(10, &v)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.reserved_name.iter().rev() {
+            os.write_string(10, &v);
+        };
+        for v in self.reserved_range.iter().rev() {
+            crate::rt::write_message_field_reverse(9, v, os)?;
+        };
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(7, v, os)?;
+        }
+        for v in self.oneof_decl.iter().rev() {
+            crate::rt::write_message_field_reverse(8, v, os)?;
+        };
+        for v in self.extension_range.iter().rev() {
+            crate::rt::write_message_field_reverse(5, v, os)?;
+        };
+        for v in self.enum_type.iter().rev() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        };
+        for v in self.nested_type.iter().rev() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        };
+        for v in self.extension.iter().rev() {
+            crate::rt::write_message_field_reverse(6, v, os)?;
+        };
+        for v in self.field.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -978,7 +1180,7 @@ impl crate::Message for DescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
         self.field.clear();
         self.extension.clear();
         self.nested_type.clear();
@@ -993,16 +1195,16 @@ impl crate::Message for DescriptorProto {
 
     fn default_instance() -> &'static DescriptorProto {
         static instance: DescriptorProto = DescriptorProto {
-            name: ::std::option::Option::None,
-            field: ::std::vec::Vec::new(),
-            extension: ::std::vec::Vec::new(),
-            nested_type: ::std::vec::Vec::new(),
-            enum_type: ::std::vec::Vec::new(),
-            extension_range: ::std::vec::Vec::new(),
-            oneof_decl: ::std::vec::Vec::new(),
+            name: ::core::option::Option::None,
+            field: crate::rt::Vec::new(),
+            extension: crate::rt::Vec::new(),
+            nested_type: crate::rt::Vec::new(),
+            enum_type: crate::rt::Vec::new(),
+            extension_range: crate::rt::Vec::new(),
+            oneof_decl: crate::rt::Vec::new(),
             options: crate::MessageField::none(),
-            reserved_range: ::std::vec::Vec::new(),
-            reserved_name: ::std::vec::Vec::new(),
+            reserved_range: crate::rt::Vec::new(),
+            reserved_name: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -1016,8 +1218,8 @@ impl crate::MessageFull for DescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for DescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for DescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1032,10 +1234,12 @@ pub mod descriptor_proto {
     // @@protoc_insertion_point(message:google.protobuf.DescriptorProto.ExtensionRange)
     pub struct ExtensionRange {
         // message fields
+        ///  Inclusive.
         // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.ExtensionRange.start)
-        start: ::std::option::Option<i32>,
+        start: ::core::option::Option<i32>,
+        ///  Exclusive.
         // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.ExtensionRange.end)
-        end: ::std::option::Option<i32>,
+        end: ::core::option::Option<i32>,
         // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.ExtensionRange.options)
         pub options: crate::MessageField<super::ExtensionRangeOptions>,
         // special fields
@@ -1045,7 +1249,7 @@ pub mod descriptor_proto {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a ExtensionRange {
+    impl<'a> ::core::default::Default for &'a ExtensionRange {
         fn default() -> &'a ExtensionRange {
             <ExtensionRange as crate::Message>::default_instance()
         }
@@ -1053,7 +1257,7 @@ pub mod descriptor_proto {
 
     impl ExtensionRange {
         pub fn new() -> ExtensionRange {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional int32 start = 1;
@@ -1063,7 +1267,7 @@ pub mod descriptor_proto {
         }
 
         pub fn clear_start(&mut self) {
-            self.start = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
         }
 
         pub fn has_start(&self) -> bool {
@@ -1072,7 +1276,7 @@ pub mod descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_start(&mut self, v: i32) {
-            self.start = ::std::option::Option::Some(v);
+            self.start = ::core::option::Option::Some(v);
         }
 
         // optional int32 end = 2;
@@ -1082,7 +1286,7 @@ pub mod descriptor_proto {
         }
 
         pub fn clear_end(&mut self) {
-            self.end = ::std::option::Option::None;
+            self.end = ::core::option::Option::None;
         }
 
         pub fn has_end(&self) -> bool {
@@ -1091,11 +1295,11 @@ pub mod descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_end(&mut self, v: i32) {
-            self.end = ::std::option::Option::Some(v);
+            self.end = ::core::option::Option::Some(v);
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut fields = crate::rt::Vec::with_capacity(3);
             fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
                 "start",
                 |m: &ExtensionRange| { &m.start },
@@ -1133,15 +1337,20 @@ pub mod descriptor_proto {
             true
         }
 
+        fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+            for v in &self.options {
+                v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+            };
+        }
+
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     8 => {
-                        self.start = ::std::option::Option::Some(is.read_int32()?);
+                        self.start = ::core::option::Option::Some(is.read_int32()?);
                     },
                     16 => {
-                        self.end = ::std::option::Option::Some(is.read_int32()?);
+                        self.end = ::core::option::Option::Some(is.read_int32()?);
                     },
                     26 => {
                         crate::rt::read_singular_message_into_field(is, &mut self.options)?;
@@ -1151,7 +1360,20 @@ pub mod descriptor_proto {
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start.clone_from(&other.start);
+            }
+            if other.end.is_some() {
+                self.end.clone_from(&other.end);
+            }
+            if let Some(v) = other.options.as_ref() {
+                self.options.mut_or_default().merge_from_message(v);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -1184,7 +1406,21 @@ pub mod descriptor_proto {
                 crate::rt::write_message_field_with_cached_size(3, v, os)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.options.as_ref() {
+                crate::rt::write_message_field_reverse(3, v, os)?;
+            }
+            if let Some(v) = self.end {
+                os.write_int32(2, v);
+            }
+            if let Some(v) = self.start {
+                os.write_int32(1, v);
+            }
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -1204,16 +1440,16 @@ pub mod descriptor_proto {
         }
 
         fn clear(&mut self) {
-            self.start = ::std::option::Option::None;
-            self.end = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
+            self.end = ::core::option::Option::None;
             self.options.clear();
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static ExtensionRange {
             static instance: ExtensionRange = ExtensionRange {
-                start: ::std::option::Option::None,
-                end: ::std::option::Option::None,
+                start: ::core::option::Option::None,
+                end: ::core::option::Option::None,
                 options: crate::MessageField::none(),
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
@@ -1228,8 +1464,8 @@ pub mod descriptor_proto {
         }
     }
 
-    impl ::std::fmt::Display for ExtensionRange {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for ExtensionRange {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -1245,10 +1481,12 @@ pub mod descriptor_proto {
     // @@protoc_insertion_point(message:google.protobuf.DescriptorProto.ReservedRange)
     pub struct ReservedRange {
         // message fields
+        ///  Inclusive.
         // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.ReservedRange.start)
-        start: ::std::option::Option<i32>,
+        start: ::core::option::Option<i32>,
+        ///  Exclusive.
         // @@protoc_insertion_point(field:google.protobuf.DescriptorProto.ReservedRange.end)
-        end: ::std::option::Option<i32>,
+        end: ::core::option::Option<i32>,
         // special fields
         // @@protoc_insertion_point(special_field:google.protobuf.DescriptorProto.ReservedRange.unknown_fields)
         pub unknown_fields: crate::UnknownFields,
@@ -1256,7 +1494,7 @@ pub mod descriptor_proto {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a ReservedRange {
+    impl<'a> ::core::default::Default for &'a ReservedRange {
         fn default() -> &'a ReservedRange {
             <ReservedRange as crate::Message>::default_instance()
         }
@@ -1264,7 +1502,7 @@ pub mod descriptor_proto {
 
     impl ReservedRange {
         pub fn new() -> ReservedRange {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional int32 start = 1;
@@ -1274,7 +1512,7 @@ pub mod descriptor_proto {
         }
 
         pub fn clear_start(&mut self) {
-            self.start = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
         }
 
         pub fn has_start(&self) -> bool {
@@ -1283,7 +1521,7 @@ pub mod descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_start(&mut self, v: i32) {
-            self.start = ::std::option::Option::Some(v);
+            self.start = ::core::option::Option::Some(v);
         }
 
         // optional int32 end = 2;
@@ -1293,7 +1531,7 @@ pub mod descriptor_proto {
         }
 
         pub fn clear_end(&mut self) {
-            self.end = ::std::option::Option::None;
+            self.end = ::core::option::Option::None;
         }
 
         pub fn has_end(&self) -> bool {
@@ -1302,11 +1540,11 @@ pub mod descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_end(&mut self, v: i32) {
-            self.end = ::std::option::Option::Some(v);
+            self.end = ::core::option::Option::Some(v);
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut fields = crate::rt::Vec::with_capacity(2);
             fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
                 "start",
                 |m: &ReservedRange| { &m.start },
@@ -1335,21 +1573,30 @@ pub mod descriptor_proto {
         }
 
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     8 => {
-                        self.start = ::std::option::Option::Some(is.read_int32()?);
+                        self.start = ::core::option::Option::Some(is.read_int32()?);
                     },
                     16 => {
-                        self.end = ::std::option::Option::Some(is.read_int32()?);
+                        self.end = ::core::option::Option::Some(is.read_int32()?);
                     },
                     tag => {
                         crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start.clone_from(&other.start);
+            }
+            if other.end.is_some() {
+                self.end.clone_from(&other.end);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -1375,7 +1622,18 @@ pub mod descriptor_proto {
                 os.write_int32(2, v)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.end {
+                os.write_int32(2, v);
+            }
+            if let Some(v) = self.start {
+                os.write_int32(1, v);
+            }
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -1395,15 +1653,15 @@ pub mod descriptor_proto {
         }
 
         fn clear(&mut self) {
-            self.start = ::std::option::Option::None;
-            self.end = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
+            self.end = ::core::option::Option::None;
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static ReservedRange {
             static instance: ReservedRange = ReservedRange {
-                start: ::std::option::Option::None,
-                end: ::std::option::Option::None,
+                start: ::core::option::Option::None,
+                end: ::core::option::Option::None,
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
             };
@@ -1417,8 +1675,8 @@ pub mod descriptor_proto {
         }
     }
 
-    impl ::std::fmt::Display for ReservedRange {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for ReservedRange {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -1434,7 +1692,7 @@ pub struct ExtensionRangeOptions {
     // message fields
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.ExtensionRangeOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.ExtensionRangeOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -1442,7 +1700,7 @@ pub struct ExtensionRangeOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ExtensionRangeOptions {
+impl<'a> ::core::default::Default for &'a ExtensionRangeOptions {
     fn default() -> &'a ExtensionRangeOptions {
         <ExtensionRangeOptions as crate::Message>::default_instance()
     }
@@ -1450,11 +1708,11 @@ impl<'a> ::std::default::Default for &'a ExtensionRangeOptions {
 
 impl ExtensionRangeOptions {
     pub fn new() -> ExtensionRangeOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &ExtensionRangeOptions| { &m.uninterpreted_option },
@@ -1480,19 +1738,30 @@ impl crate::Message for ExtensionRangeOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -1513,7 +1782,15 @@ impl crate::Message for ExtensionRangeOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -1539,7 +1816,7 @@ impl crate::Message for ExtensionRangeOptions {
 
     fn default_instance() -> &'static ExtensionRangeOptions {
         static instance: ExtensionRangeOptions = ExtensionRangeOptions {
-            uninterpreted_option: ::std::vec::Vec::new(),
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -1553,8 +1830,8 @@ impl crate::MessageFull for ExtensionRangeOptions {
     }
 }
 
-impl ::std::fmt::Display for ExtensionRangeOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for ExtensionRangeOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1569,43 +1846,43 @@ impl crate::reflect::ProtobufValue for ExtensionRangeOptions {
 pub struct FieldDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.number)
-    number: ::std::option::Option<i32>,
+    number: ::core::option::Option<i32>,
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.label)
-    label: ::std::option::Option<crate::EnumOrUnknown<field_descriptor_proto::Label>>,
+    label: ::core::option::Option<crate::EnumOrUnknown<field_descriptor_proto::Label>>,
     ///  If type_name is set, this need not be set.  If both this and type_name
     ///  are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.type)
-    field_type: ::std::option::Option<crate::EnumOrUnknown<field_descriptor_proto::Type>>,
+    field_type: ::core::option::Option<crate::EnumOrUnknown<field_descriptor_proto::Type>>,
     ///  For message and enum types, this is the name of the type.  If the name
     ///  starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
     ///  rules are used to find the type (i.e. first the nested types within this
     ///  message are searched, then within the parent, on up to the root
     ///  namespace).
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.type_name)
-    type_name: ::std::option::Option<::std::string::String>,
+    type_name: ::core::option::Option<crate::rt::String>,
     ///  For extensions, this is the name of the type being extended.  It is
     ///  resolved in the same manner as type_name.
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.extendee)
-    extendee: ::std::option::Option<::std::string::String>,
+    extendee: ::core::option::Option<crate::rt::String>,
     ///  For numeric types, contains the original text representation of the value.
     ///  For booleans, "true" or "false".
     ///  For strings, contains the default text contents (not escaped in any way).
-    ///  For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+    ///  For bytes, contains the C escaped value.  All bytes \>= 128 are escaped.
     ///  TODO(kenton):  Base-64 encode?
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.default_value)
-    default_value: ::std::option::Option<::std::string::String>,
+    default_value: ::core::option::Option<crate::rt::String>,
     ///  If set, gives the index of a oneof in the containing type's oneof_decl
     ///  list.  This field is a member of that oneof.
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.oneof_index)
-    oneof_index: ::std::option::Option<i32>,
+    oneof_index: ::core::option::Option<i32>,
     ///  JSON name of this field. The value is set by protocol compiler. If the
     ///  user has set a "json_name" option on this field, that option's value
     ///  will be used. Otherwise, it's deduced from the field's name by converting
     ///  it to camelCase.
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.json_name)
-    json_name: ::std::option::Option<::std::string::String>,
+    json_name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.options)
     pub options: crate::MessageField<FieldOptions>,
     ///  If true, this is a proto3 "optional". When a proto3 field is optional, it
@@ -1630,7 +1907,7 @@ pub struct FieldDescriptorProto {
     ///  Proto2 optional fields do not set this flag, because they already indicate
     ///  optional with `LABEL_OPTIONAL`.
     // @@protoc_insertion_point(field:google.protobuf.FieldDescriptorProto.proto3_optional)
-    proto3_optional: ::std::option::Option<bool>,
+    proto3_optional: ::core::option::Option<bool>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FieldDescriptorProto.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -1638,7 +1915,7 @@ pub struct FieldDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FieldDescriptorProto {
+impl<'a> ::core::default::Default for &'a FieldDescriptorProto {
     fn default() -> &'a FieldDescriptorProto {
         <FieldDescriptorProto as crate::Message>::default_instance()
     }
@@ -1646,7 +1923,7 @@ impl<'a> ::std::default::Default for &'a FieldDescriptorProto {
 
 impl FieldDescriptorProto {
     pub fn new() -> FieldDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -1659,7 +1936,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -1667,22 +1944,22 @@ impl FieldDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional int32 number = 3;
@@ -1692,7 +1969,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_number(&mut self) {
-        self.number = ::std::option::Option::None;
+        self.number = ::core::option::Option::None;
     }
 
     pub fn has_number(&self) -> bool {
@@ -1701,7 +1978,7 @@ impl FieldDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_number(&mut self, v: i32) {
-        self.number = ::std::option::Option::Some(v);
+        self.number = ::core::option::Option::Some(v);
     }
 
     // optional .google.protobuf.FieldDescriptorProto.Label label = 4;
@@ -1714,7 +1991,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_label(&mut self) {
-        self.label = ::std::option::Option::None;
+        self.label = ::core::option::Option::None;
     }
 
     pub fn has_label(&self) -> bool {
@@ -1723,7 +2000,7 @@ impl FieldDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_label(&mut self, v: field_descriptor_proto::Label) {
-        self.label = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.label = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     // optional .google.protobuf.FieldDescriptorProto.Type type = 5;
@@ -1736,7 +2013,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_field_type(&mut self) {
-        self.field_type = ::std::option::Option::None;
+        self.field_type = ::core::option::Option::None;
     }
 
     pub fn has_field_type(&self) -> bool {
@@ -1745,7 +2022,7 @@ impl FieldDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_field_type(&mut self, v: field_descriptor_proto::Type) {
-        self.field_type = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.field_type = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     // optional string type_name = 6;
@@ -1758,7 +2035,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_type_name(&mut self) {
-        self.type_name = ::std::option::Option::None;
+        self.type_name = ::core::option::Option::None;
     }
 
     pub fn has_type_name(&self) -> bool {
@@ -1766,22 +2043,22 @@ impl FieldDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_type_name(&mut self, v: ::std::string::String) {
-        self.type_name = ::std::option::Option::Some(v);
+    pub fn set_type_name(&mut self, v: crate::rt::String) {
+        self.type_name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_type_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_type_name(&mut self) -> &mut crate::rt::String {
         if self.type_name.is_none() {
-            self.type_name = ::std::option::Option::Some(::std::string::String::new());
+            self.type_name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.type_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_type_name(&mut self) -> ::std::string::String {
-        self.type_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_type_name(&mut self) -> crate::rt::String {
+        self.type_name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string extendee = 2;
@@ -1794,7 +2071,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_extendee(&mut self) {
-        self.extendee = ::std::option::Option::None;
+        self.extendee = ::core::option::Option::None;
     }
 
     pub fn has_extendee(&self) -> bool {
@@ -1802,22 +2079,22 @@ impl FieldDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_extendee(&mut self, v: ::std::string::String) {
-        self.extendee = ::std::option::Option::Some(v);
+    pub fn set_extendee(&mut self, v: crate::rt::String) {
+        self.extendee = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_extendee(&mut self) -> &mut ::std::string::String {
+    pub fn mut_extendee(&mut self) -> &mut crate::rt::String {
         if self.extendee.is_none() {
-            self.extendee = ::std::option::Option::Some(::std::string::String::new());
+            self.extendee = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.extendee.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_extendee(&mut self) -> ::std::string::String {
-        self.extendee.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_extendee(&mut self) -> crate::rt::String {
+        self.extendee.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string default_value = 7;
@@ -1830,7 +2107,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_default_value(&mut self) {
-        self.default_value = ::std::option::Option::None;
+        self.default_value = ::core::option::Option::None;
     }
 
     pub fn has_default_value(&self) -> bool {
@@ -1838,22 +2115,22 @@ impl FieldDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_default_value(&mut self, v: ::std::string::String) {
-        self.default_value = ::std::option::Option::Some(v);
+    pub fn set_default_value(&mut self, v: crate::rt::String) {
+        self.default_value = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_default_value(&mut self) -> &mut ::std::string::String {
+    pub fn mut_default_value(&mut self) -> &mut crate::rt::String {
         if self.default_value.is_none() {
-            self.default_value = ::std::option::Option::Some(::std::string::String::new());
+            self.default_value = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.default_value.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_default_value(&mut self) -> ::std::string::String {
-        self.default_value.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_default_value(&mut self) -> crate::rt::String {
+        self.default_value.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional int32 oneof_index = 9;
@@ -1863,7 +2140,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_oneof_index(&mut self) {
-        self.oneof_index = ::std::option::Option::None;
+        self.oneof_index = ::core::option::Option::None;
     }
 
     pub fn has_oneof_index(&self) -> bool {
@@ -1872,7 +2149,7 @@ impl FieldDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_oneof_index(&mut self, v: i32) {
-        self.oneof_index = ::std::option::Option::Some(v);
+        self.oneof_index = ::core::option::Option::Some(v);
     }
 
     // optional string json_name = 10;
@@ -1885,7 +2162,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_json_name(&mut self) {
-        self.json_name = ::std::option::Option::None;
+        self.json_name = ::core::option::Option::None;
     }
 
     pub fn has_json_name(&self) -> bool {
@@ -1893,22 +2170,22 @@ impl FieldDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_json_name(&mut self, v: ::std::string::String) {
-        self.json_name = ::std::option::Option::Some(v);
+    pub fn set_json_name(&mut self, v: crate::rt::String) {
+        self.json_name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_json_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_json_name(&mut self) -> &mut crate::rt::String {
         if self.json_name.is_none() {
-            self.json_name = ::std::option::Option::Some(::std::string::String::new());
+            self.json_name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.json_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_json_name(&mut self) -> ::std::string::String {
-        self.json_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_json_name(&mut self) -> crate::rt::String {
+        self.json_name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional bool proto3_optional = 17;
@@ -1918,7 +2195,7 @@ impl FieldDescriptorProto {
     }
 
     pub fn clear_proto3_optional(&mut self) {
-        self.proto3_optional = ::std::option::Option::None;
+        self.proto3_optional = ::core::option::Option::None;
     }
 
     pub fn has_proto3_optional(&self) -> bool {
@@ -1927,11 +2204,11 @@ impl FieldDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_proto3_optional(&mut self, v: bool) {
-        self.proto3_optional = ::std::option::Option::Some(v);
+        self.proto3_optional = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(11);
+        let mut fields = crate::rt::Vec::with_capacity(11);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &FieldDescriptorProto| { &m.name },
@@ -2017,49 +2294,91 @@ impl crate::Message for FieldDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 24 => {
-                    self.number = ::std::option::Option::Some(is.read_int32()?);
+                    self.number = ::core::option::Option::Some(is.read_int32()?);
                 },
                 32 => {
-                    self.label = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.label = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 40 => {
-                    self.field_type = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.field_type = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 50 => {
-                    self.type_name = ::std::option::Option::Some(is.read_string()?);
+                    self.type_name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.extendee = ::std::option::Option::Some(is.read_string()?);
+                    self.extendee = ::core::option::Option::Some(is.read_string()?);
                 },
                 58 => {
-                    self.default_value = ::std::option::Option::Some(is.read_string()?);
+                    self.default_value = ::core::option::Option::Some(is.read_string()?);
                 },
                 72 => {
-                    self.oneof_index = ::std::option::Option::Some(is.read_int32()?);
+                    self.oneof_index = ::core::option::Option::Some(is.read_int32()?);
                 },
                 82 => {
-                    self.json_name = ::std::option::Option::Some(is.read_string()?);
+                    self.json_name = ::core::option::Option::Some(is.read_string()?);
                 },
                 66 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
                 },
                 136 => {
-                    self.proto3_optional = ::std::option::Option::Some(is.read_bool()?);
+                    self.proto3_optional = ::core::option::Option::Some(is.read_bool()?);
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if other.number.is_some() {
+            self.number.clone_from(&other.number);
+        }
+        if other.label.is_some() {
+            self.label.clone_from(&other.label);
+        }
+        if other.field_type.is_some() {
+            self.field_type.clone_from(&other.field_type);
+        }
+        if other.type_name.is_some() {
+            self.type_name.clone_from(&other.type_name);
+        }
+        if other.extendee.is_some() {
+            self.extendee.clone_from(&other.extendee);
+        }
+        if other.default_value.is_some() {
+            self.default_value.clone_from(&other.default_value);
+        }
+        if other.oneof_index.is_some() {
+            self.oneof_index.clone_from(&other.oneof_index);
+        }
+        if other.json_name.is_some() {
+            self.json_name.clone_from(&other.json_name);
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if other.proto3_optional.is_some() {
+            self.proto3_optional.clone_from(&other.proto3_optional);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -2140,7 +2459,45 @@ impl crate::Message for FieldDescriptorProto {
             os.write_bool(17, v)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.proto3_optional {
+            os.write_bool(17, v);
+        }
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(8, v, os)?;
+        }
+        if let Some(v) = self.json_name.as_ref() {
+            os.write_string(10, v);
+        }
+        if let Some(v) = self.oneof_index {
+            os.write_int32(9, v);
+        }
+        if let Some(v) = self.default_value.as_ref() {
+            os.write_string(7, v);
+        }
+        if let Some(v) = self.extendee.as_ref() {
+            os.write_string(2, v);
+        }
+        if let Some(v) = self.type_name.as_ref() {
+            os.write_string(6, v);
+        }
+        if let Some(v) = self.field_type {
+            os.write_enum(5, crate::EnumOrUnknown::value(&v));
+        }
+        if let Some(v) = self.label {
+            os.write_enum(4, crate::EnumOrUnknown::value(&v));
+        }
+        if let Some(v) = self.number {
+            os.write_int32(3, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -2160,33 +2517,33 @@ impl crate::Message for FieldDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
-        self.number = ::std::option::Option::None;
-        self.label = ::std::option::Option::None;
-        self.field_type = ::std::option::Option::None;
-        self.type_name = ::std::option::Option::None;
-        self.extendee = ::std::option::Option::None;
-        self.default_value = ::std::option::Option::None;
-        self.oneof_index = ::std::option::Option::None;
-        self.json_name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
+        self.number = ::core::option::Option::None;
+        self.label = ::core::option::Option::None;
+        self.field_type = ::core::option::Option::None;
+        self.type_name = ::core::option::Option::None;
+        self.extendee = ::core::option::Option::None;
+        self.default_value = ::core::option::Option::None;
+        self.oneof_index = ::core::option::Option::None;
+        self.json_name = ::core::option::Option::None;
         self.options.clear();
-        self.proto3_optional = ::std::option::Option::None;
+        self.proto3_optional = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static FieldDescriptorProto {
         static instance: FieldDescriptorProto = FieldDescriptorProto {
-            name: ::std::option::Option::None,
-            number: ::std::option::Option::None,
-            label: ::std::option::Option::None,
-            field_type: ::std::option::Option::None,
-            type_name: ::std::option::Option::None,
-            extendee: ::std::option::Option::None,
-            default_value: ::std::option::Option::None,
-            oneof_index: ::std::option::Option::None,
-            json_name: ::std::option::Option::None,
+            name: ::core::option::Option::None,
+            number: ::core::option::Option::None,
+            label: ::core::option::Option::None,
+            field_type: ::core::option::Option::None,
+            type_name: ::core::option::Option::None,
+            extendee: ::core::option::Option::None,
+            default_value: ::core::option::Option::None,
+            oneof_index: ::core::option::Option::None,
+            json_name: ::core::option::Option::None,
             options: crate::MessageField::none(),
-            proto3_optional: ::std::option::Option::None,
+            proto3_optional: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -2200,8 +2557,8 @@ impl crate::MessageFull for FieldDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for FieldDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FieldDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -2215,14 +2572,20 @@ pub mod field_descriptor_proto {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.FieldDescriptorProto.Type)
     pub enum Type {
+        ///  0 is reserved for errors.
+        ///  Order is weird for historical reasons.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_DOUBLE)
         TYPE_DOUBLE = 1,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_FLOAT)
         TYPE_FLOAT = 2,
+        ///  Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT64 if
+        ///  negative values are likely.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_INT64)
         TYPE_INT64 = 3,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_UINT64)
         TYPE_UINT64 = 4,
+        ///  Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT32 if
+        ///  negative values are likely.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_INT32)
         TYPE_INT32 = 5,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_FIXED64)
@@ -2233,10 +2596,16 @@ pub mod field_descriptor_proto {
         TYPE_BOOL = 8,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_STRING)
         TYPE_STRING = 9,
+        ///  Tag-delimited aggregate.
+        ///  Group type is deprecated and not supported in proto3. However, Proto3
+        ///  implementations should still be able to parse the group wire format and
+        ///  treat group fields as unknown fields.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_GROUP)
         TYPE_GROUP = 10,
+        ///  Length-delimited aggregate.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_MESSAGE)
         TYPE_MESSAGE = 11,
+        ///  New in version 2.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_BYTES)
         TYPE_BYTES = 12,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_UINT32)
@@ -2247,8 +2616,10 @@ pub mod field_descriptor_proto {
         TYPE_SFIXED32 = 15,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_SFIXED64)
         TYPE_SFIXED64 = 16,
+        ///  Uses ZigZag encoding.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_SINT32)
         TYPE_SINT32 = 17,
+        ///  Uses ZigZag encoding.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Type.TYPE_SINT64)
         TYPE_SINT64 = 18,
     }
@@ -2258,27 +2629,27 @@ pub mod field_descriptor_proto {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Type> {
+        fn from_i32(value: i32) -> ::core::option::Option<Type> {
             match value {
-                1 => ::std::option::Option::Some(Type::TYPE_DOUBLE),
-                2 => ::std::option::Option::Some(Type::TYPE_FLOAT),
-                3 => ::std::option::Option::Some(Type::TYPE_INT64),
-                4 => ::std::option::Option::Some(Type::TYPE_UINT64),
-                5 => ::std::option::Option::Some(Type::TYPE_INT32),
-                6 => ::std::option::Option::Some(Type::TYPE_FIXED64),
-                7 => ::std::option::Option::Some(Type::TYPE_FIXED32),
-                8 => ::std::option::Option::Some(Type::TYPE_BOOL),
-                9 => ::std::option::Option::Some(Type::TYPE_STRING),
-                10 => ::std::option::Option::Some(Type::TYPE_GROUP),
-                11 => ::std::option::Option::Some(Type::TYPE_MESSAGE),
-                12 => ::std::option::Option::Some(Type::TYPE_BYTES),
-                13 => ::std::option::Option::Some(Type::TYPE_UINT32),
-                14 => ::std::option::Option::Some(Type::TYPE_ENUM),
-                15 => ::std::option::Option::Some(Type::TYPE_SFIXED32),
-                16 => ::std::option::Option::Some(Type::TYPE_SFIXED64),
-                17 => ::std::option::Option::Some(Type::TYPE_SINT32),
-                18 => ::std::option::Option::Some(Type::TYPE_SINT64),
-                _ => ::std::option::Option::None
+                1 => ::core::option::Option::Some(Type::TYPE_DOUBLE),
+                2 => ::core::option::Option::Some(Type::TYPE_FLOAT),
+                3 => ::core::option::Option::Some(Type::TYPE_INT64),
+                4 => ::core::option::Option::Some(Type::TYPE_UINT64),
+                5 => ::core::option::Option::Some(Type::TYPE_INT32),
+                6 => ::core::option::Option::Some(Type::TYPE_FIXED64),
+                7 => ::core::option::Option::Some(Type::TYPE_FIXED32),
+                8 => ::core::option::Option::Some(Type::TYPE_BOOL),
+                9 => ::core::option::Option::Some(Type::TYPE_STRING),
+                10 => ::core::option::Option::Some(Type::TYPE_GROUP),
+                11 => ::core::option::Option::Some(Type::TYPE_MESSAGE),
+                12 => ::core::option::Option::Some(Type::TYPE_BYTES),
+                13 => ::core::option::Option::Some(Type::TYPE_UINT32),
+                14 => ::core::option::Option::Some(Type::TYPE_ENUM),
+                15 => ::core::option::Option::Some(Type::TYPE_SFIXED32),
+                16 => ::core::option::Option::Some(Type::TYPE_SFIXED64),
+                17 => ::core::option::Option::Some(Type::TYPE_SINT32),
+                18 => ::core::option::Option::Some(Type::TYPE_SINT64),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -2314,7 +2685,7 @@ pub mod field_descriptor_proto {
     }
 
     // Note, `Default` is implemented although default value is not 0
-    impl ::std::default::Default for Type {
+    impl ::core::default::Default for Type {
         fn default() -> Self {
             Type::TYPE_DOUBLE
         }
@@ -2329,6 +2700,7 @@ pub mod field_descriptor_proto {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.FieldDescriptorProto.Label)
     pub enum Label {
+        ///  0 is reserved for errors
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Label.LABEL_OPTIONAL)
         LABEL_OPTIONAL = 1,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldDescriptorProto.Label.LABEL_REQUIRED)
@@ -2342,12 +2714,12 @@ pub mod field_descriptor_proto {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Label> {
+        fn from_i32(value: i32) -> ::core::option::Option<Label> {
             match value {
-                1 => ::std::option::Option::Some(Label::LABEL_OPTIONAL),
-                2 => ::std::option::Option::Some(Label::LABEL_REQUIRED),
-                3 => ::std::option::Option::Some(Label::LABEL_REPEATED),
-                _ => ::std::option::Option::None
+                1 => ::core::option::Option::Some(Label::LABEL_OPTIONAL),
+                2 => ::core::option::Option::Some(Label::LABEL_REQUIRED),
+                3 => ::core::option::Option::Some(Label::LABEL_REPEATED),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -2368,7 +2740,7 @@ pub mod field_descriptor_proto {
     }
 
     // Note, `Default` is implemented although default value is not 0
-    impl ::std::default::Default for Label {
+    impl ::core::default::Default for Label {
         fn default() -> Self {
             Label::LABEL_OPTIONAL
         }
@@ -2387,7 +2759,7 @@ pub mod field_descriptor_proto {
 pub struct OneofDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.OneofDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.OneofDescriptorProto.options)
     pub options: crate::MessageField<OneofOptions>,
     // special fields
@@ -2397,7 +2769,7 @@ pub struct OneofDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a OneofDescriptorProto {
+impl<'a> ::core::default::Default for &'a OneofDescriptorProto {
     fn default() -> &'a OneofDescriptorProto {
         <OneofDescriptorProto as crate::Message>::default_instance()
     }
@@ -2405,7 +2777,7 @@ impl<'a> ::std::default::Default for &'a OneofDescriptorProto {
 
 impl OneofDescriptorProto {
     pub fn new() -> OneofDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -2418,7 +2790,7 @@ impl OneofDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -2426,26 +2798,26 @@ impl OneofDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &OneofDescriptorProto| { &m.name },
@@ -2477,12 +2849,17 @@ impl crate::Message for OneofDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
@@ -2492,7 +2869,17 @@ impl crate::Message for OneofDescriptorProto {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -2519,7 +2906,18 @@ impl crate::Message for OneofDescriptorProto {
             crate::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -2539,14 +2937,14 @@ impl crate::Message for OneofDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
         self.options.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static OneofDescriptorProto {
         static instance: OneofDescriptorProto = OneofDescriptorProto {
-            name: ::std::option::Option::None,
+            name: ::core::option::Option::None,
             options: crate::MessageField::none(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -2561,8 +2959,8 @@ impl crate::MessageFull for OneofDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for OneofDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for OneofDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -2577,20 +2975,20 @@ impl crate::reflect::ProtobufValue for OneofDescriptorProto {
 pub struct EnumDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.value)
-    pub value: ::std::vec::Vec<EnumValueDescriptorProto>,
+    pub value: crate::rt::Vec<EnumValueDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.options)
     pub options: crate::MessageField<EnumOptions>,
     ///  Range of reserved numeric values. Reserved numeric values may not be used
     ///  by enum values in the same enum declaration. Reserved ranges may not
     ///  overlap.
     // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.reserved_range)
-    pub reserved_range: ::std::vec::Vec<enum_descriptor_proto::EnumReservedRange>,
+    pub reserved_range: crate::rt::Vec<enum_descriptor_proto::EnumReservedRange>,
     ///  Reserved enum value names, which may not be reused. A given name may only
     ///  be reserved once.
     // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.reserved_name)
-    pub reserved_name: ::std::vec::Vec<::std::string::String>,
+    pub reserved_name: crate::rt::Vec<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.EnumDescriptorProto.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -2598,7 +2996,7 @@ pub struct EnumDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a EnumDescriptorProto {
+impl<'a> ::core::default::Default for &'a EnumDescriptorProto {
     fn default() -> &'a EnumDescriptorProto {
         <EnumDescriptorProto as crate::Message>::default_instance()
     }
@@ -2606,7 +3004,7 @@ impl<'a> ::std::default::Default for &'a EnumDescriptorProto {
 
 impl EnumDescriptorProto {
     pub fn new() -> EnumDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -2619,7 +3017,7 @@ impl EnumDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -2627,26 +3025,26 @@ impl EnumDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = crate::rt::Vec::with_capacity(5);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &EnumDescriptorProto| { &m.name },
@@ -2703,31 +3101,58 @@ impl crate::Message for EnumDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.value.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "value", i), missing);
+        };
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+        for (i, v) in self.reserved_range.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "reserved_range", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.value.push(is.read_message()?);
+                    self.value.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.value.len())?;
                 },
                 26 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
                 },
                 34 => {
-                    self.reserved_range.push(is.read_message()?);
+                    self.reserved_range.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.reserved_range.len())?;
                 },
                 42 => {
                     self.reserved_name.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.reserved_name.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        self.value.extend(other.value.iter().cloned());
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.reserved_range.extend(other.reserved_range.iter().cloned());
+        self.reserved_name.extend(other.reserved_name.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -2774,7 +3199,27 @@ impl crate::Message for EnumDescriptorProto {
             os.write_string(5, &v)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.reserved_name.iter().rev() {
+            os.write_string(5, &v);
+        };
+        for v in self.reserved_range.iter().rev() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        };
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        }
+        for v in self.value.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -2794,7 +3239,7 @@ impl crate::Message for EnumDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
         self.value.clear();
         self.options.clear();
         self.reserved_range.clear();
@@ -2804,11 +3249,11 @@ impl crate::Message for EnumDescriptorProto {
 
     fn default_instance() -> &'static EnumDescriptorProto {
         static instance: EnumDescriptorProto = EnumDescriptorProto {
-            name: ::std::option::Option::None,
-            value: ::std::vec::Vec::new(),
+            name: ::core::option::Option::None,
+            value: crate::rt::Vec::new(),
             options: crate::MessageField::none(),
-            reserved_range: ::std::vec::Vec::new(),
-            reserved_name: ::std::vec::Vec::new(),
+            reserved_range: crate::rt::Vec::new(),
+            reserved_name: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -2822,8 +3267,8 @@ impl crate::MessageFull for EnumDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for EnumDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EnumDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -2844,10 +3289,12 @@ pub mod enum_descriptor_proto {
     // @@protoc_insertion_point(message:google.protobuf.EnumDescriptorProto.EnumReservedRange)
     pub struct EnumReservedRange {
         // message fields
+        ///  Inclusive.
         // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.EnumReservedRange.start)
-        start: ::std::option::Option<i32>,
+        start: ::core::option::Option<i32>,
+        ///  Inclusive.
         // @@protoc_insertion_point(field:google.protobuf.EnumDescriptorProto.EnumReservedRange.end)
-        end: ::std::option::Option<i32>,
+        end: ::core::option::Option<i32>,
         // special fields
         // @@protoc_insertion_point(special_field:google.protobuf.EnumDescriptorProto.EnumReservedRange.unknown_fields)
         pub unknown_fields: crate::UnknownFields,
@@ -2855,7 +3302,7 @@ pub mod enum_descriptor_proto {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a EnumReservedRange {
+    impl<'a> ::core::default::Default for &'a EnumReservedRange {
         fn default() -> &'a EnumReservedRange {
             <EnumReservedRange as crate::Message>::default_instance()
         }
@@ -2863,7 +3310,7 @@ pub mod enum_descriptor_proto {
 
     impl EnumReservedRange {
         pub fn new() -> EnumReservedRange {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional int32 start = 1;
@@ -2873,7 +3320,7 @@ pub mod enum_descriptor_proto {
         }
 
         pub fn clear_start(&mut self) {
-            self.start = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
         }
 
         pub fn has_start(&self) -> bool {
@@ -2882,7 +3329,7 @@ pub mod enum_descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_start(&mut self, v: i32) {
-            self.start = ::std::option::Option::Some(v);
+            self.start = ::core::option::Option::Some(v);
         }
 
         // optional int32 end = 2;
@@ -2892,7 +3339,7 @@ pub mod enum_descriptor_proto {
         }
 
         pub fn clear_end(&mut self) {
-            self.end = ::std::option::Option::None;
+            self.end = ::core::option::Option::None;
         }
 
         pub fn has_end(&self) -> bool {
@@ -2901,11 +3348,11 @@ pub mod enum_descriptor_proto {
 
         // Param is passed by value, moved
         pub fn set_end(&mut self, v: i32) {
-            self.end = ::std::option::Option::Some(v);
+            self.end = ::core::option::Option::Some(v);
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut fields = crate::rt::Vec::with_capacity(2);
             fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
                 "start",
                 |m: &EnumReservedRange| { &m.start },
@@ -2934,21 +3381,30 @@ pub mod enum_descriptor_proto {
         }
 
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     8 => {
-                        self.start = ::std::option::Option::Some(is.read_int32()?);
+                        self.start = ::core::option::Option::Some(is.read_int32()?);
                     },
                     16 => {
-                        self.end = ::std::option::Option::Some(is.read_int32()?);
+                        self.end = ::core::option::Option::Some(is.read_int32()?);
                     },
                     tag => {
                         crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start.clone_from(&other.start);
+            }
+            if other.end.is_some() {
+                self.end.clone_from(&other.end);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -2974,7 +3430,18 @@ pub mod enum_descriptor_proto {
                 os.write_int32(2, v)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.end {
+                os.write_int32(2, v);
+            }
+            if let Some(v) = self.start {
+                os.write_int32(1, v);
+            }
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -2994,15 +3461,15 @@ pub mod enum_descriptor_proto {
         }
 
         fn clear(&mut self) {
-            self.start = ::std::option::Option::None;
-            self.end = ::std::option::Option::None;
+            self.start = ::core::option::Option::None;
+            self.end = ::core::option::Option::None;
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static EnumReservedRange {
             static instance: EnumReservedRange = EnumReservedRange {
-                start: ::std::option::Option::None,
-                end: ::std::option::Option::None,
+                start: ::core::option::Option::None,
+                end: ::core::option::Option::None,
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
             };
@@ -3016,8 +3483,8 @@ pub mod enum_descriptor_proto {
         }
     }
 
-    impl ::std::fmt::Display for EnumReservedRange {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for EnumReservedRange {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -3033,9 +3500,9 @@ pub mod enum_descriptor_proto {
 pub struct EnumValueDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.EnumValueDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.EnumValueDescriptorProto.number)
-    number: ::std::option::Option<i32>,
+    number: ::core::option::Option<i32>,
     // @@protoc_insertion_point(field:google.protobuf.EnumValueDescriptorProto.options)
     pub options: crate::MessageField<EnumValueOptions>,
     // special fields
@@ -3045,7 +3512,7 @@ pub struct EnumValueDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a EnumValueDescriptorProto {
+impl<'a> ::core::default::Default for &'a EnumValueDescriptorProto {
     fn default() -> &'a EnumValueDescriptorProto {
         <EnumValueDescriptorProto as crate::Message>::default_instance()
     }
@@ -3053,7 +3520,7 @@ impl<'a> ::std::default::Default for &'a EnumValueDescriptorProto {
 
 impl EnumValueDescriptorProto {
     pub fn new() -> EnumValueDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -3066,7 +3533,7 @@ impl EnumValueDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -3074,22 +3541,22 @@ impl EnumValueDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional int32 number = 2;
@@ -3099,7 +3566,7 @@ impl EnumValueDescriptorProto {
     }
 
     pub fn clear_number(&mut self) {
-        self.number = ::std::option::Option::None;
+        self.number = ::core::option::Option::None;
     }
 
     pub fn has_number(&self) -> bool {
@@ -3108,11 +3575,11 @@ impl EnumValueDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_number(&mut self, v: i32) {
-        self.number = ::std::option::Option::Some(v);
+        self.number = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &EnumValueDescriptorProto| { &m.name },
@@ -3150,15 +3617,20 @@ impl crate::Message for EnumValueDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 16 => {
-                    self.number = ::std::option::Option::Some(is.read_int32()?);
+                    self.number = ::core::option::Option::Some(is.read_int32()?);
                 },
                 26 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
@@ -3168,7 +3640,20 @@ impl crate::Message for EnumValueDescriptorProto {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if other.number.is_some() {
+            self.number.clone_from(&other.number);
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -3201,7 +3686,21 @@ impl crate::Message for EnumValueDescriptorProto {
             crate::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        }
+        if let Some(v) = self.number {
+            os.write_int32(2, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -3221,16 +3720,16 @@ impl crate::Message for EnumValueDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
-        self.number = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
+        self.number = ::core::option::Option::None;
         self.options.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static EnumValueDescriptorProto {
         static instance: EnumValueDescriptorProto = EnumValueDescriptorProto {
-            name: ::std::option::Option::None,
-            number: ::std::option::Option::None,
+            name: ::core::option::Option::None,
+            number: ::core::option::Option::None,
             options: crate::MessageField::none(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -3245,8 +3744,8 @@ impl crate::MessageFull for EnumValueDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for EnumValueDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EnumValueDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -3261,9 +3760,9 @@ impl crate::reflect::ProtobufValue for EnumValueDescriptorProto {
 pub struct ServiceDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.ServiceDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.ServiceDescriptorProto.method)
-    pub method: ::std::vec::Vec<MethodDescriptorProto>,
+    pub method: crate::rt::Vec<MethodDescriptorProto>,
     // @@protoc_insertion_point(field:google.protobuf.ServiceDescriptorProto.options)
     pub options: crate::MessageField<ServiceOptions>,
     // special fields
@@ -3273,7 +3772,7 @@ pub struct ServiceDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ServiceDescriptorProto {
+impl<'a> ::core::default::Default for &'a ServiceDescriptorProto {
     fn default() -> &'a ServiceDescriptorProto {
         <ServiceDescriptorProto as crate::Message>::default_instance()
     }
@@ -3281,7 +3780,7 @@ impl<'a> ::std::default::Default for &'a ServiceDescriptorProto {
 
 impl ServiceDescriptorProto {
     pub fn new() -> ServiceDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -3294,7 +3793,7 @@ impl ServiceDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -3302,26 +3801,26 @@ impl ServiceDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &ServiceDescriptorProto| { &m.name },
@@ -3363,15 +3862,24 @@ impl crate::Message for ServiceDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.method.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "method", i), missing);
+        };
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.method.push(is.read_message()?);
+                    self.method.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.method.len())?;
                 },
                 26 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
@@ -3381,7 +3889,18 @@ impl crate::Message for ServiceDescriptorProto {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        self.method.extend(other.method.iter().cloned());
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -3415,7 +3934,21 @@ impl crate::Message for ServiceDescriptorProto {
             crate::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        }
+        for v in self.method.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -3435,7 +3968,7 @@ impl crate::Message for ServiceDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
         self.method.clear();
         self.options.clear();
         self.unknown_fields.clear();
@@ -3443,8 +3976,8 @@ impl crate::Message for ServiceDescriptorProto {
 
     fn default_instance() -> &'static ServiceDescriptorProto {
         static instance: ServiceDescriptorProto = ServiceDescriptorProto {
-            name: ::std::option::Option::None,
-            method: ::std::vec::Vec::new(),
+            name: ::core::option::Option::None,
+            method: crate::rt::Vec::new(),
             options: crate::MessageField::none(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -3459,8 +3992,8 @@ impl crate::MessageFull for ServiceDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for ServiceDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for ServiceDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -3475,21 +4008,21 @@ impl crate::reflect::ProtobufValue for ServiceDescriptorProto {
 pub struct MethodDescriptorProto {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.name)
-    name: ::std::option::Option<::std::string::String>,
+    name: ::core::option::Option<crate::rt::String>,
     ///  Input and output type names.  These are resolved in the same way as
     ///  FieldDescriptorProto.type_name, but must refer to a message type.
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.input_type)
-    input_type: ::std::option::Option<::std::string::String>,
+    input_type: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.output_type)
-    output_type: ::std::option::Option<::std::string::String>,
+    output_type: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.options)
     pub options: crate::MessageField<MethodOptions>,
     ///  Identifies if client streams multiple client messages
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.client_streaming)
-    client_streaming: ::std::option::Option<bool>,
+    client_streaming: ::core::option::Option<bool>,
     ///  Identifies if server streams multiple server messages
     // @@protoc_insertion_point(field:google.protobuf.MethodDescriptorProto.server_streaming)
-    server_streaming: ::std::option::Option<bool>,
+    server_streaming: ::core::option::Option<bool>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.MethodDescriptorProto.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -3497,7 +4030,7 @@ pub struct MethodDescriptorProto {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MethodDescriptorProto {
+impl<'a> ::core::default::Default for &'a MethodDescriptorProto {
     fn default() -> &'a MethodDescriptorProto {
         <MethodDescriptorProto as crate::Message>::default_instance()
     }
@@ -3505,7 +4038,7 @@ impl<'a> ::std::default::Default for &'a MethodDescriptorProto {
 
 impl MethodDescriptorProto {
     pub fn new() -> MethodDescriptorProto {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string name = 1;
@@ -3518,7 +4051,7 @@ impl MethodDescriptorProto {
     }
 
     pub fn clear_name(&mut self) {
-        self.name = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
     }
 
     pub fn has_name(&self) -> bool {
@@ -3526,22 +4059,22 @@ impl MethodDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: crate::rt::String) {
+        self.name = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+    pub fn mut_name(&mut self) -> &mut crate::rt::String {
         if self.name.is_none() {
-            self.name = ::std::option::Option::Some(::std::string::String::new());
+            self.name = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> crate::rt::String {
+        self.name.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string input_type = 2;
@@ -3554,7 +4087,7 @@ impl MethodDescriptorProto {
     }
 
     pub fn clear_input_type(&mut self) {
-        self.input_type = ::std::option::Option::None;
+        self.input_type = ::core::option::Option::None;
     }
 
     pub fn has_input_type(&self) -> bool {
@@ -3562,22 +4095,22 @@ impl MethodDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_input_type(&mut self, v: ::std::string::String) {
-        self.input_type = ::std::option::Option::Some(v);
+    pub fn set_input_type(&mut self, v: crate::rt::String) {
+        self.input_type = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_input_type(&mut self) -> &mut ::std::string::String {
+    pub fn mut_input_type(&mut self) -> &mut crate::rt::String {
         if self.input_type.is_none() {
-            self.input_type = ::std::option::Option::Some(::std::string::String::new());
+            self.input_type = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.input_type.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_input_type(&mut self) -> ::std::string::String {
-        self.input_type.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_input_type(&mut self) -> crate::rt::String {
+        self.input_type.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string output_type = 3;
@@ -3590,7 +4123,7 @@ impl MethodDescriptorProto {
     }
 
     pub fn clear_output_type(&mut self) {
-        self.output_type = ::std::option::Option::None;
+        self.output_type = ::core::option::Option::None;
     }
 
     pub fn has_output_type(&self) -> bool {
@@ -3598,22 +4131,22 @@ impl MethodDescriptorProto {
     }
 
     // Param is passed by value, moved
-    pub fn set_output_type(&mut self, v: ::std::string::String) {
-        self.output_type = ::std::option::Option::Some(v);
+    pub fn set_output_type(&mut self, v: crate::rt::String) {
+        self.output_type = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_output_type(&mut self) -> &mut ::std::string::String {
+    pub fn mut_output_type(&mut self) -> &mut crate::rt::String {
         if self.output_type.is_none() {
-            self.output_type = ::std::option::Option::Some(::std::string::String::new());
+            self.output_type = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.output_type.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_output_type(&mut self) -> ::std::string::String {
-        self.output_type.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_output_type(&mut self) -> crate::rt::String {
+        self.output_type.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional bool client_streaming = 5;
@@ -3623,7 +4156,7 @@ impl MethodDescriptorProto {
     }
 
     pub fn clear_client_streaming(&mut self) {
-        self.client_streaming = ::std::option::Option::None;
+        self.client_streaming = ::core::option::Option::None;
     }
 
     pub fn has_client_streaming(&self) -> bool {
@@ -3632,7 +4165,7 @@ impl MethodDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_client_streaming(&mut self, v: bool) {
-        self.client_streaming = ::std::option::Option::Some(v);
+        self.client_streaming = ::core::option::Option::Some(v);
     }
 
     // optional bool server_streaming = 6;
@@ -3642,7 +4175,7 @@ impl MethodDescriptorProto {
     }
 
     pub fn clear_server_streaming(&mut self) {
-        self.server_streaming = ::std::option::Option::None;
+        self.server_streaming = ::core::option::Option::None;
     }
 
     pub fn has_server_streaming(&self) -> bool {
@@ -3651,11 +4184,11 @@ impl MethodDescriptorProto {
 
     // Param is passed by value, moved
     pub fn set_server_streaming(&mut self, v: bool) {
-        self.server_streaming = ::std::option::Option::Some(v);
+        self.server_streaming = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = crate::rt::Vec::with_capacity(6);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "name",
             |m: &MethodDescriptorProto| { &m.name },
@@ -3711,34 +4244,61 @@ impl crate::Message for MethodDescriptorProto {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for v in &self.options {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "options"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
+                    self.name = ::core::option::Option::Some(is.read_string()?);
                 },
                 18 => {
-                    self.input_type = ::std::option::Option::Some(is.read_string()?);
+                    self.input_type = ::core::option::Option::Some(is.read_string()?);
                 },
                 26 => {
-                    self.output_type = ::std::option::Option::Some(is.read_string()?);
+                    self.output_type = ::core::option::Option::Some(is.read_string()?);
                 },
                 34 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.options)?;
                 },
                 40 => {
-                    self.client_streaming = ::std::option::Option::Some(is.read_bool()?);
+                    self.client_streaming = ::core::option::Option::Some(is.read_bool()?);
                 },
                 48 => {
-                    self.server_streaming = ::std::option::Option::Some(is.read_bool()?);
+                    self.server_streaming = ::core::option::Option::Some(is.read_bool()?);
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if other.input_type.is_some() {
+            self.input_type.clone_from(&other.input_type);
+        }
+        if other.output_type.is_some() {
+            self.output_type.clone_from(&other.output_type);
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if other.client_streaming.is_some() {
+            self.client_streaming.clone_from(&other.client_streaming);
+        }
+        if other.server_streaming.is_some() {
+            self.server_streaming.clone_from(&other.server_streaming);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -3789,7 +4349,30 @@ impl crate::Message for MethodDescriptorProto {
             os.write_bool(6, v)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.server_streaming {
+            os.write_bool(6, v);
+        }
+        if let Some(v) = self.client_streaming {
+            os.write_bool(5, v);
+        }
+        if let Some(v) = self.options.as_ref() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        }
+        if let Some(v) = self.output_type.as_ref() {
+            os.write_string(3, v);
+        }
+        if let Some(v) = self.input_type.as_ref() {
+            os.write_string(2, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -3809,23 +4392,23 @@ impl crate::Message for MethodDescriptorProto {
     }
 
     fn clear(&mut self) {
-        self.name = ::std::option::Option::None;
-        self.input_type = ::std::option::Option::None;
-        self.output_type = ::std::option::Option::None;
+        self.name = ::core::option::Option::None;
+        self.input_type = ::core::option::Option::None;
+        self.output_type = ::core::option::Option::None;
         self.options.clear();
-        self.client_streaming = ::std::option::Option::None;
-        self.server_streaming = ::std::option::Option::None;
+        self.client_streaming = ::core::option::Option::None;
+        self.server_streaming = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static MethodDescriptorProto {
         static instance: MethodDescriptorProto = MethodDescriptorProto {
-            name: ::std::option::Option::None,
-            input_type: ::std::option::Option::None,
-            output_type: ::std::option::Option::None,
+            name: ::core::option::Option::None,
+            input_type: ::core::option::Option::None,
+            output_type: ::core::option::Option::None,
             options: crate::MessageField::none(),
-            client_streaming: ::std::option::Option::None,
-            server_streaming: ::std::option::Option::None,
+            client_streaming: ::core::option::Option::None,
+            server_streaming: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -3839,8 +4422,8 @@ impl crate::MessageFull for MethodDescriptorProto {
     }
 }
 
-impl ::std::fmt::Display for MethodDescriptorProto {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MethodDescriptorProto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -3858,14 +4441,14 @@ pub struct FileOptions {
     ///  inappropriate because proto packages do not normally start with backwards
     ///  domain names.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_package)
-    java_package: ::std::option::Option<::std::string::String>,
+    java_package: ::core::option::Option<crate::rt::String>,
     ///  Controls the name of the wrapper Java class generated for the .proto file.
     ///  That class will always contain the .proto file's getDescriptor() method as
     ///  well as any top-level extensions defined in the .proto file.
     ///  If java_multiple_files is disabled, then all the other classes from the
     ///  .proto file will be nested inside the single wrapper outer class.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_outer_classname)
-    java_outer_classname: ::std::option::Option<::std::string::String>,
+    java_outer_classname: ::core::option::Option<crate::rt::String>,
     ///  If enabled, then the Java code generator will generate a separate .java
     ///  file for each top-level message, enum, and service defined in the .proto
     ///  file.  Thus, these types will *not* be nested inside the wrapper class
@@ -3873,10 +4456,10 @@ pub struct FileOptions {
     ///  generated to contain the file's getDescriptor() method as well as any
     ///  top-level extensions defined in the file.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_multiple_files)
-    java_multiple_files: ::std::option::Option<bool>,
+    java_multiple_files: ::core::option::Option<bool>,
     ///  This option does nothing.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_generate_equals_and_hash)
-    java_generate_equals_and_hash: ::std::option::Option<bool>,
+    java_generate_equals_and_hash: ::core::option::Option<bool>,
     ///  If set true, then the Java2 code generator will generate code that
     ///  throws an exception whenever an attempt is made to assign a non-UTF-8
     ///  byte sequence to a string field.
@@ -3884,16 +4467,16 @@ pub struct FileOptions {
     ///  However, an extension field still accepts non-UTF-8 byte sequences.
     ///  This option has no effect on when used with the lite runtime.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_string_check_utf8)
-    java_string_check_utf8: ::std::option::Option<bool>,
+    java_string_check_utf8: ::core::option::Option<bool>,
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.optimize_for)
-    optimize_for: ::std::option::Option<crate::EnumOrUnknown<file_options::OptimizeMode>>,
+    optimize_for: ::core::option::Option<crate::EnumOrUnknown<file_options::OptimizeMode>>,
     ///  Sets the Go package where structs generated from this .proto will be
     ///  placed. If omitted, the Go package will be derived from the following:
     ///    - The basename of the package import path, if provided.
     ///    - Otherwise, the package statement in the .proto file, if present.
     ///    - Otherwise, the basename of the .proto file, without extension.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.go_package)
-    go_package: ::std::option::Option<::std::string::String>,
+    go_package: ::core::option::Option<crate::rt::String>,
     ///  Should generic services be generated in each language?  "Generic" services
     ///  are not specific to any particular RPC system.  They are generated by the
     ///  main code generators in each language (without additional plugins).
@@ -3905,59 +4488,59 @@ pub struct FileOptions {
     ///  these default to false.  Old code which depends on generic services should
     ///  explicitly set them to true.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.cc_generic_services)
-    cc_generic_services: ::std::option::Option<bool>,
+    cc_generic_services: ::core::option::Option<bool>,
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.java_generic_services)
-    java_generic_services: ::std::option::Option<bool>,
+    java_generic_services: ::core::option::Option<bool>,
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.py_generic_services)
-    py_generic_services: ::std::option::Option<bool>,
+    py_generic_services: ::core::option::Option<bool>,
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.php_generic_services)
-    php_generic_services: ::std::option::Option<bool>,
+    php_generic_services: ::core::option::Option<bool>,
     ///  Is this file deprecated?
     ///  Depending on the target platform, this can emit Deprecated annotations
     ///  for everything in the file, or it will be completely ignored; in the very
     ///  least, this is a formalization for deprecating files.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     ///  Enables the use of arenas for the proto messages in this file. This applies
     ///  only to generated classes for C++.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.cc_enable_arenas)
-    cc_enable_arenas: ::std::option::Option<bool>,
+    cc_enable_arenas: ::core::option::Option<bool>,
     ///  Sets the objective c class prefix which is prepended to all objective c
     ///  generated classes from this .proto. There is no default.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.objc_class_prefix)
-    objc_class_prefix: ::std::option::Option<::std::string::String>,
+    objc_class_prefix: ::core::option::Option<crate::rt::String>,
     ///  Namespace for generated classes; defaults to the package.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.csharp_namespace)
-    csharp_namespace: ::std::option::Option<::std::string::String>,
+    csharp_namespace: ::core::option::Option<crate::rt::String>,
     ///  By default Swift generators will take the proto package and CamelCase it
     ///  replacing '.' with underscore and use that to prefix the types/symbols
     ///  defined. When this options is provided, they will use this value instead
     ///  to prefix the types/symbols defined.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.swift_prefix)
-    swift_prefix: ::std::option::Option<::std::string::String>,
+    swift_prefix: ::core::option::Option<crate::rt::String>,
     ///  Sets the php class prefix which is prepended to all php generated classes
     ///  from this .proto. Default is empty.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.php_class_prefix)
-    php_class_prefix: ::std::option::Option<::std::string::String>,
+    php_class_prefix: ::core::option::Option<crate::rt::String>,
     ///  Use this option to change the namespace of php generated classes. Default
     ///  is empty. When this option is empty, the package name will be used for
     ///  determining the namespace.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.php_namespace)
-    php_namespace: ::std::option::Option<::std::string::String>,
+    php_namespace: ::core::option::Option<crate::rt::String>,
     ///  Use this option to change the namespace of php generated metadata classes.
     ///  Default is empty. When this option is empty, the proto file name will be
     ///  used for determining the namespace.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.php_metadata_namespace)
-    php_metadata_namespace: ::std::option::Option<::std::string::String>,
+    php_metadata_namespace: ::core::option::Option<crate::rt::String>,
     ///  Use this option to change the package of ruby generated classes. Default
     ///  is empty. When this option is not set, the package name will be used for
     ///  determining the ruby package.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.ruby_package)
-    ruby_package: ::std::option::Option<::std::string::String>,
+    ruby_package: ::core::option::Option<crate::rt::String>,
     ///  The parser stores options it doesn't recognize here.
     ///  See the documentation for the "Options" section above.
     // @@protoc_insertion_point(field:google.protobuf.FileOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FileOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -3965,7 +4548,7 @@ pub struct FileOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FileOptions {
+impl<'a> ::core::default::Default for &'a FileOptions {
     fn default() -> &'a FileOptions {
         <FileOptions as crate::Message>::default_instance()
     }
@@ -3973,7 +4556,7 @@ impl<'a> ::std::default::Default for &'a FileOptions {
 
 impl FileOptions {
     pub fn new() -> FileOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string java_package = 1;
@@ -3986,7 +4569,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_package(&mut self) {
-        self.java_package = ::std::option::Option::None;
+        self.java_package = ::core::option::Option::None;
     }
 
     pub fn has_java_package(&self) -> bool {
@@ -3994,22 +4577,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_java_package(&mut self, v: ::std::string::String) {
-        self.java_package = ::std::option::Option::Some(v);
+    pub fn set_java_package(&mut self, v: crate::rt::String) {
+        self.java_package = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_java_package(&mut self) -> &mut ::std::string::String {
+    pub fn mut_java_package(&mut self) -> &mut crate::rt::String {
         if self.java_package.is_none() {
-            self.java_package = ::std::option::Option::Some(::std::string::String::new());
+            self.java_package = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.java_package.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_java_package(&mut self) -> ::std::string::String {
-        self.java_package.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_java_package(&mut self) -> crate::rt::String {
+        self.java_package.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string java_outer_classname = 8;
@@ -4022,7 +4605,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_outer_classname(&mut self) {
-        self.java_outer_classname = ::std::option::Option::None;
+        self.java_outer_classname = ::core::option::Option::None;
     }
 
     pub fn has_java_outer_classname(&self) -> bool {
@@ -4030,22 +4613,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_java_outer_classname(&mut self, v: ::std::string::String) {
-        self.java_outer_classname = ::std::option::Option::Some(v);
+    pub fn set_java_outer_classname(&mut self, v: crate::rt::String) {
+        self.java_outer_classname = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_java_outer_classname(&mut self) -> &mut ::std::string::String {
+    pub fn mut_java_outer_classname(&mut self) -> &mut crate::rt::String {
         if self.java_outer_classname.is_none() {
-            self.java_outer_classname = ::std::option::Option::Some(::std::string::String::new());
+            self.java_outer_classname = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.java_outer_classname.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_java_outer_classname(&mut self) -> ::std::string::String {
-        self.java_outer_classname.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_java_outer_classname(&mut self) -> crate::rt::String {
+        self.java_outer_classname.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional bool java_multiple_files = 10;
@@ -4055,7 +4638,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_multiple_files(&mut self) {
-        self.java_multiple_files = ::std::option::Option::None;
+        self.java_multiple_files = ::core::option::Option::None;
     }
 
     pub fn has_java_multiple_files(&self) -> bool {
@@ -4064,7 +4647,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_java_multiple_files(&mut self, v: bool) {
-        self.java_multiple_files = ::std::option::Option::Some(v);
+        self.java_multiple_files = ::core::option::Option::Some(v);
     }
 
     // optional bool java_generate_equals_and_hash = 20;
@@ -4074,7 +4657,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_generate_equals_and_hash(&mut self) {
-        self.java_generate_equals_and_hash = ::std::option::Option::None;
+        self.java_generate_equals_and_hash = ::core::option::Option::None;
     }
 
     pub fn has_java_generate_equals_and_hash(&self) -> bool {
@@ -4083,7 +4666,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_java_generate_equals_and_hash(&mut self, v: bool) {
-        self.java_generate_equals_and_hash = ::std::option::Option::Some(v);
+        self.java_generate_equals_and_hash = ::core::option::Option::Some(v);
     }
 
     // optional bool java_string_check_utf8 = 27;
@@ -4093,7 +4676,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_string_check_utf8(&mut self) {
-        self.java_string_check_utf8 = ::std::option::Option::None;
+        self.java_string_check_utf8 = ::core::option::Option::None;
     }
 
     pub fn has_java_string_check_utf8(&self) -> bool {
@@ -4102,7 +4685,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_java_string_check_utf8(&mut self, v: bool) {
-        self.java_string_check_utf8 = ::std::option::Option::Some(v);
+        self.java_string_check_utf8 = ::core::option::Option::Some(v);
     }
 
     // optional .google.protobuf.FileOptions.OptimizeMode optimize_for = 9;
@@ -4115,7 +4698,7 @@ impl FileOptions {
     }
 
     pub fn clear_optimize_for(&mut self) {
-        self.optimize_for = ::std::option::Option::None;
+        self.optimize_for = ::core::option::Option::None;
     }
 
     pub fn has_optimize_for(&self) -> bool {
@@ -4124,7 +4707,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_optimize_for(&mut self, v: file_options::OptimizeMode) {
-        self.optimize_for = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.optimize_for = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     // optional string go_package = 11;
@@ -4137,7 +4720,7 @@ impl FileOptions {
     }
 
     pub fn clear_go_package(&mut self) {
-        self.go_package = ::std::option::Option::None;
+        self.go_package = ::core::option::Option::None;
     }
 
     pub fn has_go_package(&self) -> bool {
@@ -4145,22 +4728,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_go_package(&mut self, v: ::std::string::String) {
-        self.go_package = ::std::option::Option::Some(v);
+    pub fn set_go_package(&mut self, v: crate::rt::String) {
+        self.go_package = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_go_package(&mut self) -> &mut ::std::string::String {
+    pub fn mut_go_package(&mut self) -> &mut crate::rt::String {
         if self.go_package.is_none() {
-            self.go_package = ::std::option::Option::Some(::std::string::String::new());
+            self.go_package = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.go_package.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_go_package(&mut self) -> ::std::string::String {
-        self.go_package.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_go_package(&mut self) -> crate::rt::String {
+        self.go_package.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional bool cc_generic_services = 16;
@@ -4170,7 +4753,7 @@ impl FileOptions {
     }
 
     pub fn clear_cc_generic_services(&mut self) {
-        self.cc_generic_services = ::std::option::Option::None;
+        self.cc_generic_services = ::core::option::Option::None;
     }
 
     pub fn has_cc_generic_services(&self) -> bool {
@@ -4179,7 +4762,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_cc_generic_services(&mut self, v: bool) {
-        self.cc_generic_services = ::std::option::Option::Some(v);
+        self.cc_generic_services = ::core::option::Option::Some(v);
     }
 
     // optional bool java_generic_services = 17;
@@ -4189,7 +4772,7 @@ impl FileOptions {
     }
 
     pub fn clear_java_generic_services(&mut self) {
-        self.java_generic_services = ::std::option::Option::None;
+        self.java_generic_services = ::core::option::Option::None;
     }
 
     pub fn has_java_generic_services(&self) -> bool {
@@ -4198,7 +4781,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_java_generic_services(&mut self, v: bool) {
-        self.java_generic_services = ::std::option::Option::Some(v);
+        self.java_generic_services = ::core::option::Option::Some(v);
     }
 
     // optional bool py_generic_services = 18;
@@ -4208,7 +4791,7 @@ impl FileOptions {
     }
 
     pub fn clear_py_generic_services(&mut self) {
-        self.py_generic_services = ::std::option::Option::None;
+        self.py_generic_services = ::core::option::Option::None;
     }
 
     pub fn has_py_generic_services(&self) -> bool {
@@ -4217,7 +4800,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_py_generic_services(&mut self, v: bool) {
-        self.py_generic_services = ::std::option::Option::Some(v);
+        self.py_generic_services = ::core::option::Option::Some(v);
     }
 
     // optional bool php_generic_services = 42;
@@ -4227,7 +4810,7 @@ impl FileOptions {
     }
 
     pub fn clear_php_generic_services(&mut self) {
-        self.php_generic_services = ::std::option::Option::None;
+        self.php_generic_services = ::core::option::Option::None;
     }
 
     pub fn has_php_generic_services(&self) -> bool {
@@ -4236,7 +4819,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_php_generic_services(&mut self, v: bool) {
-        self.php_generic_services = ::std::option::Option::Some(v);
+        self.php_generic_services = ::core::option::Option::Some(v);
     }
 
     // optional bool deprecated = 23;
@@ -4246,7 +4829,7 @@ impl FileOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -4255,7 +4838,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     // optional bool cc_enable_arenas = 31;
@@ -4265,7 +4848,7 @@ impl FileOptions {
     }
 
     pub fn clear_cc_enable_arenas(&mut self) {
-        self.cc_enable_arenas = ::std::option::Option::None;
+        self.cc_enable_arenas = ::core::option::Option::None;
     }
 
     pub fn has_cc_enable_arenas(&self) -> bool {
@@ -4274,7 +4857,7 @@ impl FileOptions {
 
     // Param is passed by value, moved
     pub fn set_cc_enable_arenas(&mut self, v: bool) {
-        self.cc_enable_arenas = ::std::option::Option::Some(v);
+        self.cc_enable_arenas = ::core::option::Option::Some(v);
     }
 
     // optional string objc_class_prefix = 36;
@@ -4287,7 +4870,7 @@ impl FileOptions {
     }
 
     pub fn clear_objc_class_prefix(&mut self) {
-        self.objc_class_prefix = ::std::option::Option::None;
+        self.objc_class_prefix = ::core::option::Option::None;
     }
 
     pub fn has_objc_class_prefix(&self) -> bool {
@@ -4295,22 +4878,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_objc_class_prefix(&mut self, v: ::std::string::String) {
-        self.objc_class_prefix = ::std::option::Option::Some(v);
+    pub fn set_objc_class_prefix(&mut self, v: crate::rt::String) {
+        self.objc_class_prefix = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_objc_class_prefix(&mut self) -> &mut ::std::string::String {
+    pub fn mut_objc_class_prefix(&mut self) -> &mut crate::rt::String {
         if self.objc_class_prefix.is_none() {
-            self.objc_class_prefix = ::std::option::Option::Some(::std::string::String::new());
+            self.objc_class_prefix = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.objc_class_prefix.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_objc_class_prefix(&mut self) -> ::std::string::String {
-        self.objc_class_prefix.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_objc_class_prefix(&mut self) -> crate::rt::String {
+        self.objc_class_prefix.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string csharp_namespace = 37;
@@ -4323,7 +4906,7 @@ impl FileOptions {
     }
 
     pub fn clear_csharp_namespace(&mut self) {
-        self.csharp_namespace = ::std::option::Option::None;
+        self.csharp_namespace = ::core::option::Option::None;
     }
 
     pub fn has_csharp_namespace(&self) -> bool {
@@ -4331,22 +4914,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_csharp_namespace(&mut self, v: ::std::string::String) {
-        self.csharp_namespace = ::std::option::Option::Some(v);
+    pub fn set_csharp_namespace(&mut self, v: crate::rt::String) {
+        self.csharp_namespace = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_csharp_namespace(&mut self) -> &mut ::std::string::String {
+    pub fn mut_csharp_namespace(&mut self) -> &mut crate::rt::String {
         if self.csharp_namespace.is_none() {
-            self.csharp_namespace = ::std::option::Option::Some(::std::string::String::new());
+            self.csharp_namespace = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.csharp_namespace.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_csharp_namespace(&mut self) -> ::std::string::String {
-        self.csharp_namespace.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_csharp_namespace(&mut self) -> crate::rt::String {
+        self.csharp_namespace.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string swift_prefix = 39;
@@ -4359,7 +4942,7 @@ impl FileOptions {
     }
 
     pub fn clear_swift_prefix(&mut self) {
-        self.swift_prefix = ::std::option::Option::None;
+        self.swift_prefix = ::core::option::Option::None;
     }
 
     pub fn has_swift_prefix(&self) -> bool {
@@ -4367,22 +4950,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_swift_prefix(&mut self, v: ::std::string::String) {
-        self.swift_prefix = ::std::option::Option::Some(v);
+    pub fn set_swift_prefix(&mut self, v: crate::rt::String) {
+        self.swift_prefix = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_swift_prefix(&mut self) -> &mut ::std::string::String {
+    pub fn mut_swift_prefix(&mut self) -> &mut crate::rt::String {
         if self.swift_prefix.is_none() {
-            self.swift_prefix = ::std::option::Option::Some(::std::string::String::new());
+            self.swift_prefix = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.swift_prefix.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_swift_prefix(&mut self) -> ::std::string::String {
-        self.swift_prefix.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_swift_prefix(&mut self) -> crate::rt::String {
+        self.swift_prefix.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string php_class_prefix = 40;
@@ -4395,7 +4978,7 @@ impl FileOptions {
     }
 
     pub fn clear_php_class_prefix(&mut self) {
-        self.php_class_prefix = ::std::option::Option::None;
+        self.php_class_prefix = ::core::option::Option::None;
     }
 
     pub fn has_php_class_prefix(&self) -> bool {
@@ -4403,22 +4986,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_php_class_prefix(&mut self, v: ::std::string::String) {
-        self.php_class_prefix = ::std::option::Option::Some(v);
+    pub fn set_php_class_prefix(&mut self, v: crate::rt::String) {
+        self.php_class_prefix = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_php_class_prefix(&mut self) -> &mut ::std::string::String {
+    pub fn mut_php_class_prefix(&mut self) -> &mut crate::rt::String {
         if self.php_class_prefix.is_none() {
-            self.php_class_prefix = ::std::option::Option::Some(::std::string::String::new());
+            self.php_class_prefix = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.php_class_prefix.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_php_class_prefix(&mut self) -> ::std::string::String {
-        self.php_class_prefix.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_php_class_prefix(&mut self) -> crate::rt::String {
+        self.php_class_prefix.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string php_namespace = 41;
@@ -4431,7 +5014,7 @@ impl FileOptions {
     }
 
     pub fn clear_php_namespace(&mut self) {
-        self.php_namespace = ::std::option::Option::None;
+        self.php_namespace = ::core::option::Option::None;
     }
 
     pub fn has_php_namespace(&self) -> bool {
@@ -4439,22 +5022,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_php_namespace(&mut self, v: ::std::string::String) {
-        self.php_namespace = ::std::option::Option::Some(v);
+    pub fn set_php_namespace(&mut self, v: crate::rt::String) {
+        self.php_namespace = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_php_namespace(&mut self) -> &mut ::std::string::String {
+    pub fn mut_php_namespace(&mut self) -> &mut crate::rt::String {
         if self.php_namespace.is_none() {
-            self.php_namespace = ::std::option::Option::Some(::std::string::String::new());
+            self.php_namespace = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.php_namespace.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_php_namespace(&mut self) -> ::std::string::String {
-        self.php_namespace.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_php_namespace(&mut self) -> crate::rt::String {
+        self.php_namespace.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string php_metadata_namespace = 44;
@@ -4467,7 +5050,7 @@ impl FileOptions {
     }
 
     pub fn clear_php_metadata_namespace(&mut self) {
-        self.php_metadata_namespace = ::std::option::Option::None;
+        self.php_metadata_namespace = ::core::option::Option::None;
     }
 
     pub fn has_php_metadata_namespace(&self) -> bool {
@@ -4475,22 +5058,22 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_php_metadata_namespace(&mut self, v: ::std::string::String) {
-        self.php_metadata_namespace = ::std::option::Option::Some(v);
+    pub fn set_php_metadata_namespace(&mut self, v: crate::rt::String) {
+        self.php_metadata_namespace = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_php_metadata_namespace(&mut self) -> &mut ::std::string::String {
+    pub fn mut_php_metadata_namespace(&mut self) -> &mut crate::rt::String {
         if self.php_metadata_namespace.is_none() {
-            self.php_metadata_namespace = ::std::option::Option::Some(::std::string::String::new());
+            self.php_metadata_namespace = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.php_metadata_namespace.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_php_metadata_namespace(&mut self) -> ::std::string::String {
-        self.php_metadata_namespace.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_php_metadata_namespace(&mut self) -> crate::rt::String {
+        self.php_metadata_namespace.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional string ruby_package = 45;
@@ -4503,7 +5086,7 @@ impl FileOptions {
     }
 
     pub fn clear_ruby_package(&mut self) {
-        self.ruby_package = ::std::option::Option::None;
+        self.ruby_package = ::core::option::Option::None;
     }
 
     pub fn has_ruby_package(&self) -> bool {
@@ -4511,26 +5094,26 @@ impl FileOptions {
     }
 
     // Param is passed by value, moved
-    pub fn set_ruby_package(&mut self, v: ::std::string::String) {
-        self.ruby_package = ::std::option::Option::Some(v);
+    pub fn set_ruby_package(&mut self, v: crate::rt::String) {
+        self.ruby_package = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ruby_package(&mut self) -> &mut ::std::string::String {
+    pub fn mut_ruby_package(&mut self) -> &mut crate::rt::String {
         if self.ruby_package.is_none() {
-            self.ruby_package = ::std::option::Option::Some(::std::string::String::new());
+            self.ruby_package = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.ruby_package.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_ruby_package(&mut self) -> ::std::string::String {
-        self.ruby_package.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_ruby_package(&mut self) -> crate::rt::String {
+        self.ruby_package.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(21);
+        let mut fields = crate::rt::Vec::with_capacity(21);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "java_package",
             |m: &FileOptions| { &m.java_package },
@@ -4676,83 +5259,154 @@ impl crate::Message for FileOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.java_package = ::std::option::Option::Some(is.read_string()?);
+                    self.java_package = ::core::option::Option::Some(is.read_string()?);
                 },
                 66 => {
-                    self.java_outer_classname = ::std::option::Option::Some(is.read_string()?);
+                    self.java_outer_classname = ::core::option::Option::Some(is.read_string()?);
                 },
                 80 => {
-                    self.java_multiple_files = ::std::option::Option::Some(is.read_bool()?);
+                    self.java_multiple_files = ::core::option::Option::Some(is.read_bool()?);
                 },
                 160 => {
-                    self.java_generate_equals_and_hash = ::std::option::Option::Some(is.read_bool()?);
+                    self.java_generate_equals_and_hash = ::core::option::Option::Some(is.read_bool()?);
                 },
                 216 => {
-                    self.java_string_check_utf8 = ::std::option::Option::Some(is.read_bool()?);
+                    self.java_string_check_utf8 = ::core::option::Option::Some(is.read_bool()?);
                 },
                 72 => {
-                    self.optimize_for = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.optimize_for = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 90 => {
-                    self.go_package = ::std::option::Option::Some(is.read_string()?);
+                    self.go_package = ::core::option::Option::Some(is.read_string()?);
                 },
                 128 => {
-                    self.cc_generic_services = ::std::option::Option::Some(is.read_bool()?);
+                    self.cc_generic_services = ::core::option::Option::Some(is.read_bool()?);
                 },
                 136 => {
-                    self.java_generic_services = ::std::option::Option::Some(is.read_bool()?);
+                    self.java_generic_services = ::core::option::Option::Some(is.read_bool()?);
                 },
                 144 => {
-                    self.py_generic_services = ::std::option::Option::Some(is.read_bool()?);
+                    self.py_generic_services = ::core::option::Option::Some(is.read_bool()?);
                 },
                 336 => {
-                    self.php_generic_services = ::std::option::Option::Some(is.read_bool()?);
+                    self.php_generic_services = ::core::option::Option::Some(is.read_bool()?);
                 },
                 184 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 248 => {
-                    self.cc_enable_arenas = ::std::option::Option::Some(is.read_bool()?);
+                    self.cc_enable_arenas = ::core::option::Option::Some(is.read_bool()?);
                 },
                 290 => {
-                    self.objc_class_prefix = ::std::option::Option::Some(is.read_string()?);
+                    self.objc_class_prefix = ::core::option::Option::Some(is.read_string()?);
                 },
                 298 => {
-                    self.csharp_namespace = ::std::option::Option::Some(is.read_string()?);
+                    self.csharp_namespace = ::core::option::Option::Some(is.read_string()?);
                 },
                 314 => {
-                    self.swift_prefix = ::std::option::Option::Some(is.read_string()?);
+                    self.swift_prefix = ::core::option::Option::Some(is.read_string()?);
                 },
                 322 => {
-                    self.php_class_prefix = ::std::option::Option::Some(is.read_string()?);
+                    self.php_class_prefix = ::core::option::Option::Some(is.read_string()?);
                 },
                 330 => {
-                    self.php_namespace = ::std::option::Option::Some(is.read_string()?);
+                    self.php_namespace = ::core::option::Option::Some(is.read_string()?);
                 },
                 354 => {
-                    self.php_metadata_namespace = ::std::option::Option::Some(is.read_string()?);
+                    self.php_metadata_namespace = ::core::option::Option::Some(is.read_string()?);
                 },
                 362 => {
-                    self.ruby_package = ::std::option::Option::Some(is.read_string()?);
+                    self.ruby_package = ::core::option::Option::Some(is.read_string()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.java_package.is_some() {
+            self.java_package.clone_from(&other.java_package);
+        }
+        if other.java_outer_classname.is_some() {
+            self.java_outer_classname.clone_from(&other.java_outer_classname);
+        }
+        if other.java_multiple_files.is_some() {
+            self.java_multiple_files.clone_from(&other.java_multiple_files);
+        }
+        if other.java_generate_equals_and_hash.is_some() {
+            self.java_generate_equals_and_hash.clone_from(&other.java_generate_equals_and_hash);
+        }
+        if other.java_string_check_utf8.is_some() {
+            self.java_string_check_utf8.clone_from(&other.java_string_check_utf8);
+        }
+        if other.optimize_for.is_some() {
+            self.optimize_for.clone_from(&other.optimize_for);
+        }
+        if other.go_package.is_some() {
+            self.go_package.clone_from(&other.go_package);
+        }
+        if other.cc_generic_services.is_some() {
+            self.cc_generic_services.clone_from(&other.cc_generic_services);
+        }
+        if other.java_generic_services.is_some() {
+            self.java_generic_services.clone_from(&other.java_generic_services);
+        }
+        if other.py_generic_services.is_some() {
+            self.py_generic_services.clone_from(&other.py_generic_services);
+        }
+        if other.php_generic_services.is_some() {
+            self.php_generic_services.clone_from(&other.php_generic_services);
+        }
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        if other.cc_enable_arenas.is_some() {
+            self.cc_enable_arenas.clone_from(&other.cc_enable_arenas);
+        }
+        if other.objc_class_prefix.is_some() {
+            self.objc_class_prefix.clone_from(&other.objc_class_prefix);
+        }
+        if other.csharp_namespace.is_some() {
+            self.csharp_namespace.clone_from(&other.csharp_namespace);
+        }
+        if other.swift_prefix.is_some() {
+            self.swift_prefix.clone_from(&other.swift_prefix);
+        }
+        if other.php_class_prefix.is_some() {
+            self.php_class_prefix.clone_from(&other.php_class_prefix);
+        }
+        if other.php_namespace.is_some() {
+            self.php_namespace.clone_from(&other.php_namespace);
+        }
+        if other.php_metadata_namespace.is_some() {
+            self.php_metadata_namespace.clone_from(&other.php_metadata_namespace);
+        }
+        if other.ruby_package.is_some() {
+            self.ruby_package.clone_from(&other.ruby_package);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
         if let Some(v) = self.java_package.as_ref() {
@@ -4889,7 +5543,75 @@ impl crate::Message for FileOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.ruby_package.as_ref() {
+            os.write_string(45, v);
+        }
+        if let Some(v) = self.php_metadata_namespace.as_ref() {
+            os.write_string(44, v);
+        }
+        if let Some(v) = self.php_namespace.as_ref() {
+            os.write_string(41, v);
+        }
+        if let Some(v) = self.php_class_prefix.as_ref() {
+            os.write_string(40, v);
+        }
+        if let Some(v) = self.swift_prefix.as_ref() {
+            os.write_string(39, v);
+        }
+        if let Some(v) = self.csharp_namespace.as_ref() {
+            os.write_string(37, v);
+        }
+        if let Some(v) = self.objc_class_prefix.as_ref() {
+            os.write_string(36, v);
+        }
+        if let Some(v) = self.cc_enable_arenas {
+            os.write_bool(31, v);
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(23, v);
+        }
+        if let Some(v) = self.php_generic_services {
+            os.write_bool(42, v);
+        }
+        if let Some(v) = self.py_generic_services {
+            os.write_bool(18, v);
+        }
+        if let Some(v) = self.java_generic_services {
+            os.write_bool(17, v);
+        }
+        if let Some(v) = self.cc_generic_services {
+            os.write_bool(16, v);
+        }
+        if let Some(v) = self.go_package.as_ref() {
+            os.write_string(11, v);
+        }
+        if let Some(v) = self.optimize_for {
+            os.write_enum(9, crate::EnumOrUnknown::value(&v));
+        }
+        if let Some(v) = self.java_string_check_utf8 {
+            os.write_bool(27, v);
+        }
+        if let Some(v) = self.java_generate_equals_and_hash {
+            os.write_bool(20, v);
+        }
+        if let Some(v) = self.java_multiple_files {
+            os.write_bool(10, v);
+        }
+        if let Some(v) = self.java_outer_classname.as_ref() {
+            os.write_string(8, v);
+        }
+        if let Some(v) = self.java_package.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -4909,53 +5631,53 @@ impl crate::Message for FileOptions {
     }
 
     fn clear(&mut self) {
-        self.java_package = ::std::option::Option::None;
-        self.java_outer_classname = ::std::option::Option::None;
-        self.java_multiple_files = ::std::option::Option::None;
-        self.java_generate_equals_and_hash = ::std::option::Option::None;
-        self.java_string_check_utf8 = ::std::option::Option::None;
-        self.optimize_for = ::std::option::Option::None;
-        self.go_package = ::std::option::Option::None;
-        self.cc_generic_services = ::std::option::Option::None;
-        self.java_generic_services = ::std::option::Option::None;
-        self.py_generic_services = ::std::option::Option::None;
-        self.php_generic_services = ::std::option::Option::None;
-        self.deprecated = ::std::option::Option::None;
-        self.cc_enable_arenas = ::std::option::Option::None;
-        self.objc_class_prefix = ::std::option::Option::None;
-        self.csharp_namespace = ::std::option::Option::None;
-        self.swift_prefix = ::std::option::Option::None;
-        self.php_class_prefix = ::std::option::Option::None;
-        self.php_namespace = ::std::option::Option::None;
-        self.php_metadata_namespace = ::std::option::Option::None;
-        self.ruby_package = ::std::option::Option::None;
+        self.java_package = ::core::option::Option::None;
+        self.java_outer_classname = ::core::option::Option::None;
+        self.java_multiple_files = ::core::option::Option::None;
+        self.java_generate_equals_and_hash = ::core::option::Option::None;
+        self.java_string_check_utf8 = ::core::option::Option::None;
+        self.optimize_for = ::core::option::Option::None;
+        self.go_package = ::core::option::Option::None;
+        self.cc_generic_services = ::core::option::Option::None;
+        self.java_generic_services = ::core::option::Option::None;
+        self.py_generic_services = ::core::option::Option::None;
+        self.php_generic_services = ::core::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
+        self.cc_enable_arenas = ::core::option::Option::None;
+        self.objc_class_prefix = ::core::option::Option::None;
+        self.csharp_namespace = ::core::option::Option::None;
+        self.swift_prefix = ::core::option::Option::None;
+        self.php_class_prefix = ::core::option::Option::None;
+        self.php_namespace = ::core::option::Option::None;
+        self.php_metadata_namespace = ::core::option::Option::None;
+        self.ruby_package = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static FileOptions {
         static instance: FileOptions = FileOptions {
-            java_package: ::std::option::Option::None,
-            java_outer_classname: ::std::option::Option::None,
-            java_multiple_files: ::std::option::Option::None,
-            java_generate_equals_and_hash: ::std::option::Option::None,
-            java_string_check_utf8: ::std::option::Option::None,
-            optimize_for: ::std::option::Option::None,
-            go_package: ::std::option::Option::None,
-            cc_generic_services: ::std::option::Option::None,
-            java_generic_services: ::std::option::Option::None,
-            py_generic_services: ::std::option::Option::None,
-            php_generic_services: ::std::option::Option::None,
-            deprecated: ::std::option::Option::None,
-            cc_enable_arenas: ::std::option::Option::None,
-            objc_class_prefix: ::std::option::Option::None,
-            csharp_namespace: ::std::option::Option::None,
-            swift_prefix: ::std::option::Option::None,
-            php_class_prefix: ::std::option::Option::None,
-            php_namespace: ::std::option::Option::None,
-            php_metadata_namespace: ::std::option::Option::None,
-            ruby_package: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            java_package: ::core::option::Option::None,
+            java_outer_classname: ::core::option::Option::None,
+            java_multiple_files: ::core::option::Option::None,
+            java_generate_equals_and_hash: ::core::option::Option::None,
+            java_string_check_utf8: ::core::option::Option::None,
+            optimize_for: ::core::option::Option::None,
+            go_package: ::core::option::Option::None,
+            cc_generic_services: ::core::option::Option::None,
+            java_generic_services: ::core::option::Option::None,
+            py_generic_services: ::core::option::Option::None,
+            php_generic_services: ::core::option::Option::None,
+            deprecated: ::core::option::Option::None,
+            cc_enable_arenas: ::core::option::Option::None,
+            objc_class_prefix: ::core::option::Option::None,
+            csharp_namespace: ::core::option::Option::None,
+            swift_prefix: ::core::option::Option::None,
+            php_class_prefix: ::core::option::Option::None,
+            php_namespace: ::core::option::Option::None,
+            php_metadata_namespace: ::core::option::Option::None,
+            ruby_package: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -4969,8 +5691,8 @@ impl crate::MessageFull for FileOptions {
     }
 }
 
-impl ::std::fmt::Display for FileOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FileOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -4985,10 +5707,15 @@ pub mod file_options {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.FileOptions.OptimizeMode)
     pub enum OptimizeMode {
+        ///  Generate complete code for parsing, serialization,
         // @@protoc_insertion_point(enum_value:google.protobuf.FileOptions.OptimizeMode.SPEED)
         SPEED = 1,
+        ///  etc.
+        ///
+        ///  Use ReflectionOps to implement these methods.
         // @@protoc_insertion_point(enum_value:google.protobuf.FileOptions.OptimizeMode.CODE_SIZE)
         CODE_SIZE = 2,
+        ///  Generate code using MessageLite and the lite runtime.
         // @@protoc_insertion_point(enum_value:google.protobuf.FileOptions.OptimizeMode.LITE_RUNTIME)
         LITE_RUNTIME = 3,
     }
@@ -4998,12 +5725,12 @@ pub mod file_options {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<OptimizeMode> {
+        fn from_i32(value: i32) -> ::core::option::Option<OptimizeMode> {
             match value {
-                1 => ::std::option::Option::Some(OptimizeMode::SPEED),
-                2 => ::std::option::Option::Some(OptimizeMode::CODE_SIZE),
-                3 => ::std::option::Option::Some(OptimizeMode::LITE_RUNTIME),
-                _ => ::std::option::Option::None
+                1 => ::core::option::Option::Some(OptimizeMode::SPEED),
+                2 => ::core::option::Option::Some(OptimizeMode::CODE_SIZE),
+                3 => ::core::option::Option::Some(OptimizeMode::LITE_RUNTIME),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -5024,7 +5751,7 @@ pub mod file_options {
     }
 
     // Note, `Default` is implemented although default value is not 0
-    impl ::std::default::Default for OptimizeMode {
+    impl ::core::default::Default for OptimizeMode {
         fn default() -> Self {
             OptimizeMode::SPEED
         }
@@ -5041,24 +5768,63 @@ pub mod file_options {
 // @@protoc_insertion_point(message:google.protobuf.MessageOptions)
 pub struct MessageOptions {
     // message fields
+    ///  Set true to use the old proto1 MessageSet wire format for extensions.
+    ///  This is provided for backwards-compatibility with the MessageSet wire
+    ///  format.  You should not use this for any other reason:  It's less
+    ///  efficient, has fewer features, and is more complicated.
+    ///
+    ///  The message must be defined exactly as follows:
+    ///    message Foo {
+    ///      option message_set_wire_format = true;
+    ///      extensions 4 to max;
+    ///    }
+    ///  Note that the message cannot have any defined fields; MessageSets only
+    ///  have extensions.
+    ///
+    ///  All extensions of your type must be singular messages; e.g. they cannot
+    ///  be int32s, enums, or repeated messages.
+    ///
+    ///  Because this is an option, the above two restrictions are not enforced by
+    ///  the protocol compiler.
     // @@protoc_insertion_point(field:google.protobuf.MessageOptions.message_set_wire_format)
-    message_set_wire_format: ::std::option::Option<bool>,
+    message_set_wire_format: ::core::option::Option<bool>,
     ///  Disables the generation of the standard "descriptor()" accessor, which can
     ///  conflict with a field of the same name.  This is meant to make migration
     ///  from proto1 easier; new code should avoid fields named "descriptor".
     // @@protoc_insertion_point(field:google.protobuf.MessageOptions.no_standard_descriptor_accessor)
-    no_standard_descriptor_accessor: ::std::option::Option<bool>,
+    no_standard_descriptor_accessor: ::core::option::Option<bool>,
     ///  Is this message deprecated?
     ///  Depending on the target platform, this can emit Deprecated annotations
     ///  for the message, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating messages.
     // @@protoc_insertion_point(field:google.protobuf.MessageOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
+    ///  Whether the message is an automatically generated map entry type for the
+    ///  maps field.
+    ///
+    ///  For maps fields:
+    ///      map\<KeyType, ValueType\> map_field = 1;
+    ///  The parsed descriptor looks like:
+    ///      message MapFieldEntry {
+    ///          option map_entry = true;
+    ///          optional KeyType key = 1;
+    ///          optional ValueType value = 2;
+    ///      }
+    ///      repeated MapFieldEntry map_field = 1;
+    ///
+    ///  Implementations may choose not to generate the map_entry=true message, but
+    ///  use a native map in the target language to hold the keys and values.
+    ///  The reflection APIs in such implementations still need to work as
+    ///  if the field is a repeated message field.
+    ///
+    ///  NOTE: Do not set the option in .proto files. Always use the maps syntax
+    ///  instead. The option should only be implicitly set by the proto compiler
+    ///  parser.
     // @@protoc_insertion_point(field:google.protobuf.MessageOptions.map_entry)
-    map_entry: ::std::option::Option<bool>,
+    map_entry: ::core::option::Option<bool>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.MessageOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.MessageOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -5066,7 +5832,7 @@ pub struct MessageOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MessageOptions {
+impl<'a> ::core::default::Default for &'a MessageOptions {
     fn default() -> &'a MessageOptions {
         <MessageOptions as crate::Message>::default_instance()
     }
@@ -5074,7 +5840,7 @@ impl<'a> ::std::default::Default for &'a MessageOptions {
 
 impl MessageOptions {
     pub fn new() -> MessageOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional bool message_set_wire_format = 1;
@@ -5084,7 +5850,7 @@ impl MessageOptions {
     }
 
     pub fn clear_message_set_wire_format(&mut self) {
-        self.message_set_wire_format = ::std::option::Option::None;
+        self.message_set_wire_format = ::core::option::Option::None;
     }
 
     pub fn has_message_set_wire_format(&self) -> bool {
@@ -5093,7 +5859,7 @@ impl MessageOptions {
 
     // Param is passed by value, moved
     pub fn set_message_set_wire_format(&mut self, v: bool) {
-        self.message_set_wire_format = ::std::option::Option::Some(v);
+        self.message_set_wire_format = ::core::option::Option::Some(v);
     }
 
     // optional bool no_standard_descriptor_accessor = 2;
@@ -5103,7 +5869,7 @@ impl MessageOptions {
     }
 
     pub fn clear_no_standard_descriptor_accessor(&mut self) {
-        self.no_standard_descriptor_accessor = ::std::option::Option::None;
+        self.no_standard_descriptor_accessor = ::core::option::Option::None;
     }
 
     pub fn has_no_standard_descriptor_accessor(&self) -> bool {
@@ -5112,7 +5878,7 @@ impl MessageOptions {
 
     // Param is passed by value, moved
     pub fn set_no_standard_descriptor_accessor(&mut self, v: bool) {
-        self.no_standard_descriptor_accessor = ::std::option::Option::Some(v);
+        self.no_standard_descriptor_accessor = ::core::option::Option::Some(v);
     }
 
     // optional bool deprecated = 3;
@@ -5122,7 +5888,7 @@ impl MessageOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -5131,7 +5897,7 @@ impl MessageOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     // optional bool map_entry = 7;
@@ -5141,7 +5907,7 @@ impl MessageOptions {
     }
 
     pub fn clear_map_entry(&mut self) {
-        self.map_entry = ::std::option::Option::None;
+        self.map_entry = ::core::option::Option::None;
     }
 
     pub fn has_map_entry(&self) -> bool {
@@ -5150,11 +5916,11 @@ impl MessageOptions {
 
     // Param is passed by value, moved
     pub fn set_map_entry(&mut self, v: bool) {
-        self.map_entry = ::std::option::Option::Some(v);
+        self.map_entry = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = crate::rt::Vec::with_capacity(5);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "message_set_wire_format",
             |m: &MessageOptions| { &m.message_set_wire_format },
@@ -5204,31 +5970,54 @@ impl crate::Message for MessageOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.message_set_wire_format = ::std::option::Option::Some(is.read_bool()?);
+                    self.message_set_wire_format = ::core::option::Option::Some(is.read_bool()?);
                 },
                 16 => {
-                    self.no_standard_descriptor_accessor = ::std::option::Option::Some(is.read_bool()?);
+                    self.no_standard_descriptor_accessor = ::core::option::Option::Some(is.read_bool()?);
                 },
                 24 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 56 => {
-                    self.map_entry = ::std::option::Option::Some(is.read_bool()?);
+                    self.map_entry = ::core::option::Option::Some(is.read_bool()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.message_set_wire_format.is_some() {
+            self.message_set_wire_format.clone_from(&other.message_set_wire_format);
+        }
+        if other.no_standard_descriptor_accessor.is_some() {
+            self.no_standard_descriptor_accessor.clone_from(&other.no_standard_descriptor_accessor);
+        }
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        if other.map_entry.is_some() {
+            self.map_entry.clone_from(&other.map_entry);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -5273,7 +6062,27 @@ impl crate::Message for MessageOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.map_entry {
+            os.write_bool(7, v);
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v);
+        }
+        if let Some(v) = self.no_standard_descriptor_accessor {
+            os.write_bool(2, v);
+        }
+        if let Some(v) = self.message_set_wire_format {
+            os.write_bool(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -5293,21 +6102,21 @@ impl crate::Message for MessageOptions {
     }
 
     fn clear(&mut self) {
-        self.message_set_wire_format = ::std::option::Option::None;
-        self.no_standard_descriptor_accessor = ::std::option::Option::None;
-        self.deprecated = ::std::option::Option::None;
-        self.map_entry = ::std::option::Option::None;
+        self.message_set_wire_format = ::core::option::Option::None;
+        self.no_standard_descriptor_accessor = ::core::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
+        self.map_entry = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static MessageOptions {
         static instance: MessageOptions = MessageOptions {
-            message_set_wire_format: ::std::option::Option::None,
-            no_standard_descriptor_accessor: ::std::option::Option::None,
-            deprecated: ::std::option::Option::None,
-            map_entry: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            message_set_wire_format: ::core::option::Option::None,
+            no_standard_descriptor_accessor: ::core::option::Option::None,
+            deprecated: ::core::option::Option::None,
+            map_entry: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -5321,8 +6130,8 @@ impl crate::MessageFull for MessageOptions {
     }
 }
 
-impl ::std::fmt::Display for MessageOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MessageOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -5340,14 +6149,14 @@ pub struct FieldOptions {
     ///  options below.  This option is not yet implemented in the open source
     ///  release -- sorry, we'll try to include it in a future version!
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.ctype)
-    ctype: ::std::option::Option<crate::EnumOrUnknown<field_options::CType>>,
+    ctype: ::core::option::Option<crate::EnumOrUnknown<field_options::CType>>,
     ///  The packed option can be enabled for repeated primitive fields to enable
     ///  a more efficient representation on the wire. Rather than repeatedly
     ///  writing the tag and type for each element, the entire array is encoded as
     ///  a single length-delimited blob. In proto3, only explicit setting it to
     ///  false will avoid using packed encoding.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.packed)
-    packed: ::std::option::Option<bool>,
+    packed: ::core::option::Option<bool>,
     ///  The jstype option determines the JavaScript type used for values of the
     ///  field.  The option is permitted only for 64 bit integral and fixed types
     ///  (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
@@ -5360,7 +6169,7 @@ pub struct FieldOptions {
     ///  This option is an enum to permit additional types to be added, e.g.
     ///  goog.math.Integer.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.jstype)
-    jstype: ::std::option::Option<crate::EnumOrUnknown<field_options::JSType>>,
+    jstype: ::core::option::Option<crate::EnumOrUnknown<field_options::JSType>>,
     ///  Should this field be parsed lazily?  Lazy applies only to message-type
     ///  fields.  It means that when the outer message is initially parsed, the
     ///  inner message's contents will not be parsed but instead stored in encoded
@@ -5390,19 +6199,19 @@ pub struct FieldOptions {
     ///  check its required fields, regardless of whether or not the message has
     ///  been parsed.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.lazy)
-    lazy: ::std::option::Option<bool>,
+    lazy: ::core::option::Option<bool>,
     ///  Is this field deprecated?
     ///  Depending on the target platform, this can emit Deprecated annotations
     ///  for accessors, or it will be completely ignored; in the very least, this
     ///  is a formalization for deprecating fields.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     ///  For Google-internal migration only. Do not use.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.weak)
-    weak: ::std::option::Option<bool>,
+    weak: ::core::option::Option<bool>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.FieldOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FieldOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -5410,7 +6219,7 @@ pub struct FieldOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FieldOptions {
+impl<'a> ::core::default::Default for &'a FieldOptions {
     fn default() -> &'a FieldOptions {
         <FieldOptions as crate::Message>::default_instance()
     }
@@ -5418,7 +6227,7 @@ impl<'a> ::std::default::Default for &'a FieldOptions {
 
 impl FieldOptions {
     pub fn new() -> FieldOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional .google.protobuf.FieldOptions.CType ctype = 1;
@@ -5431,7 +6240,7 @@ impl FieldOptions {
     }
 
     pub fn clear_ctype(&mut self) {
-        self.ctype = ::std::option::Option::None;
+        self.ctype = ::core::option::Option::None;
     }
 
     pub fn has_ctype(&self) -> bool {
@@ -5440,7 +6249,7 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_ctype(&mut self, v: field_options::CType) {
-        self.ctype = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.ctype = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     // optional bool packed = 2;
@@ -5450,7 +6259,7 @@ impl FieldOptions {
     }
 
     pub fn clear_packed(&mut self) {
-        self.packed = ::std::option::Option::None;
+        self.packed = ::core::option::Option::None;
     }
 
     pub fn has_packed(&self) -> bool {
@@ -5459,7 +6268,7 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_packed(&mut self, v: bool) {
-        self.packed = ::std::option::Option::Some(v);
+        self.packed = ::core::option::Option::Some(v);
     }
 
     // optional .google.protobuf.FieldOptions.JSType jstype = 6;
@@ -5472,7 +6281,7 @@ impl FieldOptions {
     }
 
     pub fn clear_jstype(&mut self) {
-        self.jstype = ::std::option::Option::None;
+        self.jstype = ::core::option::Option::None;
     }
 
     pub fn has_jstype(&self) -> bool {
@@ -5481,7 +6290,7 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_jstype(&mut self, v: field_options::JSType) {
-        self.jstype = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.jstype = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     // optional bool lazy = 5;
@@ -5491,7 +6300,7 @@ impl FieldOptions {
     }
 
     pub fn clear_lazy(&mut self) {
-        self.lazy = ::std::option::Option::None;
+        self.lazy = ::core::option::Option::None;
     }
 
     pub fn has_lazy(&self) -> bool {
@@ -5500,7 +6309,7 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_lazy(&mut self, v: bool) {
-        self.lazy = ::std::option::Option::Some(v);
+        self.lazy = ::core::option::Option::Some(v);
     }
 
     // optional bool deprecated = 3;
@@ -5510,7 +6319,7 @@ impl FieldOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -5519,7 +6328,7 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     // optional bool weak = 10;
@@ -5529,7 +6338,7 @@ impl FieldOptions {
     }
 
     pub fn clear_weak(&mut self) {
-        self.weak = ::std::option::Option::None;
+        self.weak = ::core::option::Option::None;
     }
 
     pub fn has_weak(&self) -> bool {
@@ -5538,11 +6347,11 @@ impl FieldOptions {
 
     // Param is passed by value, moved
     pub fn set_weak(&mut self, v: bool) {
-        self.weak = ::std::option::Option::Some(v);
+        self.weak = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = crate::rt::Vec::with_capacity(7);
         fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, field_options::CType>(
             "ctype",
             |m: &FieldOptions| { &m.ctype },
@@ -5604,37 +6413,66 @@ impl crate::Message for FieldOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.ctype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.ctype = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 16 => {
-                    self.packed = ::std::option::Option::Some(is.read_bool()?);
+                    self.packed = ::core::option::Option::Some(is.read_bool()?);
                 },
                 48 => {
-                    self.jstype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.jstype = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 40 => {
-                    self.lazy = ::std::option::Option::Some(is.read_bool()?);
+                    self.lazy = ::core::option::Option::Some(is.read_bool()?);
                 },
                 24 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 80 => {
-                    self.weak = ::std::option::Option::Some(is.read_bool()?);
+                    self.weak = ::core::option::Option::Some(is.read_bool()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.ctype.is_some() {
+            self.ctype.clone_from(&other.ctype);
+        }
+        if other.packed.is_some() {
+            self.packed.clone_from(&other.packed);
+        }
+        if other.jstype.is_some() {
+            self.jstype.clone_from(&other.jstype);
+        }
+        if other.lazy.is_some() {
+            self.lazy.clone_from(&other.lazy);
+        }
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        if other.weak.is_some() {
+            self.weak.clone_from(&other.weak);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -5691,7 +6529,33 @@ impl crate::Message for FieldOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.weak {
+            os.write_bool(10, v);
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v);
+        }
+        if let Some(v) = self.lazy {
+            os.write_bool(5, v);
+        }
+        if let Some(v) = self.jstype {
+            os.write_enum(6, crate::EnumOrUnknown::value(&v));
+        }
+        if let Some(v) = self.packed {
+            os.write_bool(2, v);
+        }
+        if let Some(v) = self.ctype {
+            os.write_enum(1, crate::EnumOrUnknown::value(&v));
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -5711,25 +6575,25 @@ impl crate::Message for FieldOptions {
     }
 
     fn clear(&mut self) {
-        self.ctype = ::std::option::Option::None;
-        self.packed = ::std::option::Option::None;
-        self.jstype = ::std::option::Option::None;
-        self.lazy = ::std::option::Option::None;
-        self.deprecated = ::std::option::Option::None;
-        self.weak = ::std::option::Option::None;
+        self.ctype = ::core::option::Option::None;
+        self.packed = ::core::option::Option::None;
+        self.jstype = ::core::option::Option::None;
+        self.lazy = ::core::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
+        self.weak = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static FieldOptions {
         static instance: FieldOptions = FieldOptions {
-            ctype: ::std::option::Option::None,
-            packed: ::std::option::Option::None,
-            jstype: ::std::option::Option::None,
-            lazy: ::std::option::Option::None,
-            deprecated: ::std::option::Option::None,
-            weak: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            ctype: ::core::option::Option::None,
+            packed: ::core::option::Option::None,
+            jstype: ::core::option::Option::None,
+            lazy: ::core::option::Option::None,
+            deprecated: ::core::option::Option::None,
+            weak: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -5743,8 +6607,8 @@ impl crate::MessageFull for FieldOptions {
     }
 }
 
-impl ::std::fmt::Display for FieldOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FieldOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -5758,6 +6622,7 @@ pub mod field_options {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.FieldOptions.CType)
     pub enum CType {
+        ///  Default mode.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldOptions.CType.STRING)
         STRING = 0,
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldOptions.CType.CORD)
@@ -5771,12 +6636,12 @@ pub mod field_options {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<CType> {
+        fn from_i32(value: i32) -> ::core::option::Option<CType> {
             match value {
-                0 => ::std::option::Option::Some(CType::STRING),
-                1 => ::std::option::Option::Some(CType::CORD),
-                2 => ::std::option::Option::Some(CType::STRING_PIECE),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(CType::STRING),
+                1 => ::core::option::Option::Some(CType::CORD),
+                2 => ::core::option::Option::Some(CType::STRING_PIECE),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -5796,7 +6661,7 @@ pub mod field_options {
         }
     }
 
-    impl ::std::default::Default for CType {
+    impl ::core::default::Default for CType {
         fn default() -> Self {
             CType::STRING
         }
@@ -5811,10 +6676,13 @@ pub mod field_options {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.FieldOptions.JSType)
     pub enum JSType {
+        ///  Use the default type.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldOptions.JSType.JS_NORMAL)
         JS_NORMAL = 0,
+        ///  Use JavaScript strings.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldOptions.JSType.JS_STRING)
         JS_STRING = 1,
+        ///  Use JavaScript numbers.
         // @@protoc_insertion_point(enum_value:google.protobuf.FieldOptions.JSType.JS_NUMBER)
         JS_NUMBER = 2,
     }
@@ -5824,12 +6692,12 @@ pub mod field_options {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<JSType> {
+        fn from_i32(value: i32) -> ::core::option::Option<JSType> {
             match value {
-                0 => ::std::option::Option::Some(JSType::JS_NORMAL),
-                1 => ::std::option::Option::Some(JSType::JS_STRING),
-                2 => ::std::option::Option::Some(JSType::JS_NUMBER),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(JSType::JS_NORMAL),
+                1 => ::core::option::Option::Some(JSType::JS_STRING),
+                2 => ::core::option::Option::Some(JSType::JS_NUMBER),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -5849,7 +6717,7 @@ pub mod field_options {
         }
     }
 
-    impl ::std::default::Default for JSType {
+    impl ::core::default::Default for JSType {
         fn default() -> Self {
             JSType::JS_NORMAL
         }
@@ -5868,7 +6736,7 @@ pub struct OneofOptions {
     // message fields
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.OneofOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.OneofOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -5876,7 +6744,7 @@ pub struct OneofOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a OneofOptions {
+impl<'a> ::core::default::Default for &'a OneofOptions {
     fn default() -> &'a OneofOptions {
         <OneofOptions as crate::Message>::default_instance()
     }
@@ -5884,11 +6752,11 @@ impl<'a> ::std::default::Default for &'a OneofOptions {
 
 impl OneofOptions {
     pub fn new() -> OneofOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &OneofOptions| { &m.uninterpreted_option },
@@ -5914,19 +6782,30 @@ impl crate::Message for OneofOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -5947,7 +6826,15 @@ impl crate::Message for OneofOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -5973,7 +6860,7 @@ impl crate::Message for OneofOptions {
 
     fn default_instance() -> &'static OneofOptions {
         static instance: OneofOptions = OneofOptions {
-            uninterpreted_option: ::std::vec::Vec::new(),
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -5987,8 +6874,8 @@ impl crate::MessageFull for OneofOptions {
     }
 }
 
-impl ::std::fmt::Display for OneofOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for OneofOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -6004,16 +6891,16 @@ pub struct EnumOptions {
     ///  Set this option to true to allow mapping different tag names to the same
     ///  value.
     // @@protoc_insertion_point(field:google.protobuf.EnumOptions.allow_alias)
-    allow_alias: ::std::option::Option<bool>,
+    allow_alias: ::core::option::Option<bool>,
     ///  Is this enum deprecated?
     ///  Depending on the target platform, this can emit Deprecated annotations
     ///  for the enum, or it will be completely ignored; in the very least, this
     ///  is a formalization for deprecating enums.
     // @@protoc_insertion_point(field:google.protobuf.EnumOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.EnumOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.EnumOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -6021,7 +6908,7 @@ pub struct EnumOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a EnumOptions {
+impl<'a> ::core::default::Default for &'a EnumOptions {
     fn default() -> &'a EnumOptions {
         <EnumOptions as crate::Message>::default_instance()
     }
@@ -6029,7 +6916,7 @@ impl<'a> ::std::default::Default for &'a EnumOptions {
 
 impl EnumOptions {
     pub fn new() -> EnumOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional bool allow_alias = 2;
@@ -6039,7 +6926,7 @@ impl EnumOptions {
     }
 
     pub fn clear_allow_alias(&mut self) {
-        self.allow_alias = ::std::option::Option::None;
+        self.allow_alias = ::core::option::Option::None;
     }
 
     pub fn has_allow_alias(&self) -> bool {
@@ -6048,7 +6935,7 @@ impl EnumOptions {
 
     // Param is passed by value, moved
     pub fn set_allow_alias(&mut self, v: bool) {
-        self.allow_alias = ::std::option::Option::Some(v);
+        self.allow_alias = ::core::option::Option::Some(v);
     }
 
     // optional bool deprecated = 3;
@@ -6058,7 +6945,7 @@ impl EnumOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -6067,11 +6954,11 @@ impl EnumOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "allow_alias",
             |m: &EnumOptions| { &m.allow_alias },
@@ -6109,25 +6996,42 @@ impl crate::Message for EnumOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 16 => {
-                    self.allow_alias = ::std::option::Option::Some(is.read_bool()?);
+                    self.allow_alias = ::core::option::Option::Some(is.read_bool()?);
                 },
                 24 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.allow_alias.is_some() {
+            self.allow_alias.clone_from(&other.allow_alias);
+        }
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -6160,7 +7064,21 @@ impl crate::Message for EnumOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v);
+        }
+        if let Some(v) = self.allow_alias {
+            os.write_bool(2, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -6180,17 +7098,17 @@ impl crate::Message for EnumOptions {
     }
 
     fn clear(&mut self) {
-        self.allow_alias = ::std::option::Option::None;
-        self.deprecated = ::std::option::Option::None;
+        self.allow_alias = ::core::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static EnumOptions {
         static instance: EnumOptions = EnumOptions {
-            allow_alias: ::std::option::Option::None,
-            deprecated: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            allow_alias: ::core::option::Option::None,
+            deprecated: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -6204,8 +7122,8 @@ impl crate::MessageFull for EnumOptions {
     }
 }
 
-impl ::std::fmt::Display for EnumOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EnumOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -6223,10 +7141,10 @@ pub struct EnumValueOptions {
     ///  for the enum value, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating enum values.
     // @@protoc_insertion_point(field:google.protobuf.EnumValueOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.EnumValueOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.EnumValueOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -6234,7 +7152,7 @@ pub struct EnumValueOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a EnumValueOptions {
+impl<'a> ::core::default::Default for &'a EnumValueOptions {
     fn default() -> &'a EnumValueOptions {
         <EnumValueOptions as crate::Message>::default_instance()
     }
@@ -6242,7 +7160,7 @@ impl<'a> ::std::default::Default for &'a EnumValueOptions {
 
 impl EnumValueOptions {
     pub fn new() -> EnumValueOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional bool deprecated = 1;
@@ -6252,7 +7170,7 @@ impl EnumValueOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -6261,11 +7179,11 @@ impl EnumValueOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "deprecated",
             |m: &EnumValueOptions| { &m.deprecated },
@@ -6297,22 +7215,36 @@ impl crate::Message for EnumValueOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -6339,7 +7271,18 @@ impl crate::Message for EnumValueOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.deprecated {
+            os.write_bool(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -6359,15 +7302,15 @@ impl crate::Message for EnumValueOptions {
     }
 
     fn clear(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static EnumValueOptions {
         static instance: EnumValueOptions = EnumValueOptions {
-            deprecated: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            deprecated: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -6381,8 +7324,8 @@ impl crate::MessageFull for EnumValueOptions {
     }
 }
 
-impl ::std::fmt::Display for EnumValueOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EnumValueOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -6400,10 +7343,10 @@ pub struct ServiceOptions {
     ///  for the service, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating services.
     // @@protoc_insertion_point(field:google.protobuf.ServiceOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.ServiceOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.ServiceOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -6411,7 +7354,7 @@ pub struct ServiceOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ServiceOptions {
+impl<'a> ::core::default::Default for &'a ServiceOptions {
     fn default() -> &'a ServiceOptions {
         <ServiceOptions as crate::Message>::default_instance()
     }
@@ -6419,7 +7362,7 @@ impl<'a> ::std::default::Default for &'a ServiceOptions {
 
 impl ServiceOptions {
     pub fn new() -> ServiceOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional bool deprecated = 33;
@@ -6429,7 +7372,7 @@ impl ServiceOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -6438,11 +7381,11 @@ impl ServiceOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "deprecated",
             |m: &ServiceOptions| { &m.deprecated },
@@ -6474,22 +7417,36 @@ impl crate::Message for ServiceOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 264 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -6516,7 +7473,18 @@ impl crate::Message for ServiceOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.deprecated {
+            os.write_bool(33, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -6536,15 +7504,15 @@ impl crate::Message for ServiceOptions {
     }
 
     fn clear(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static ServiceOptions {
         static instance: ServiceOptions = ServiceOptions {
-            deprecated: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            deprecated: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -6558,8 +7526,8 @@ impl crate::MessageFull for ServiceOptions {
     }
 }
 
-impl ::std::fmt::Display for ServiceOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for ServiceOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -6577,12 +7545,12 @@ pub struct MethodOptions {
     ///  for the method, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating methods.
     // @@protoc_insertion_point(field:google.protobuf.MethodOptions.deprecated)
-    deprecated: ::std::option::Option<bool>,
+    deprecated: ::core::option::Option<bool>,
     // @@protoc_insertion_point(field:google.protobuf.MethodOptions.idempotency_level)
-    idempotency_level: ::std::option::Option<crate::EnumOrUnknown<method_options::IdempotencyLevel>>,
+    idempotency_level: ::core::option::Option<crate::EnumOrUnknown<method_options::IdempotencyLevel>>,
     ///  The parser stores options it doesn't recognize here. See above.
     // @@protoc_insertion_point(field:google.protobuf.MethodOptions.uninterpreted_option)
-    pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
+    pub uninterpreted_option: crate::rt::Vec<UninterpretedOption>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.MethodOptions.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -6590,7 +7558,7 @@ pub struct MethodOptions {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MethodOptions {
+impl<'a> ::core::default::Default for &'a MethodOptions {
     fn default() -> &'a MethodOptions {
         <MethodOptions as crate::Message>::default_instance()
     }
@@ -6598,7 +7566,7 @@ impl<'a> ::std::default::Default for &'a MethodOptions {
 
 impl MethodOptions {
     pub fn new() -> MethodOptions {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional bool deprecated = 33;
@@ -6608,7 +7576,7 @@ impl MethodOptions {
     }
 
     pub fn clear_deprecated(&mut self) {
-        self.deprecated = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
     }
 
     pub fn has_deprecated(&self) -> bool {
@@ -6617,7 +7585,7 @@ impl MethodOptions {
 
     // Param is passed by value, moved
     pub fn set_deprecated(&mut self, v: bool) {
-        self.deprecated = ::std::option::Option::Some(v);
+        self.deprecated = ::core::option::Option::Some(v);
     }
 
     // optional .google.protobuf.MethodOptions.IdempotencyLevel idempotency_level = 34;
@@ -6630,7 +7598,7 @@ impl MethodOptions {
     }
 
     pub fn clear_idempotency_level(&mut self) {
-        self.idempotency_level = ::std::option::Option::None;
+        self.idempotency_level = ::core::option::Option::None;
     }
 
     pub fn has_idempotency_level(&self) -> bool {
@@ -6639,11 +7607,11 @@ impl MethodOptions {
 
     // Param is passed by value, moved
     pub fn set_idempotency_level(&mut self, v: method_options::IdempotencyLevel) {
-        self.idempotency_level = ::std::option::Option::Some(crate::EnumOrUnknown::new(v));
+        self.idempotency_level = ::core::option::Option::Some(crate::EnumOrUnknown::new(v));
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "deprecated",
             |m: &MethodOptions| { &m.deprecated },
@@ -6681,25 +7649,42 @@ impl crate::Message for MethodOptions {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.uninterpreted_option.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "uninterpreted_option", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 264 => {
-                    self.deprecated = ::std::option::Option::Some(is.read_bool()?);
+                    self.deprecated = ::core::option::Option::Some(is.read_bool()?);
                 },
                 272 => {
-                    self.idempotency_level = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.idempotency_level = ::core::option::Option::Some(is.read_enum_or_unknown()?);
                 },
                 7994 => {
-                    self.uninterpreted_option.push(is.read_message()?);
+                    self.uninterpreted_option.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.uninterpreted_option.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated.clone_from(&other.deprecated);
+        }
+        if other.idempotency_level.is_some() {
+            self.idempotency_level.clone_from(&other.idempotency_level);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -6732,7 +7717,21 @@ impl crate::Message for MethodOptions {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.uninterpreted_option.iter().rev() {
+            crate::rt::write_message_field_reverse(999, v, os)?;
+        };
+        if let Some(v) = self.idempotency_level {
+            os.write_enum(34, crate::EnumOrUnknown::value(&v));
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(33, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -6752,17 +7751,17 @@ impl crate::Message for MethodOptions {
     }
 
     fn clear(&mut self) {
-        self.deprecated = ::std::option::Option::None;
-        self.idempotency_level = ::std::option::Option::None;
+        self.deprecated = ::core::option::Option::None;
+        self.idempotency_level = ::core::option::Option::None;
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static MethodOptions {
         static instance: MethodOptions = MethodOptions {
-            deprecated: ::std::option::Option::None,
-            idempotency_level: ::std::option::Option::None,
-            uninterpreted_option: ::std::vec::Vec::new(),
+            deprecated: ::core::option::Option::None,
+            idempotency_level: ::core::option::Option::None,
+            uninterpreted_option: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -6776,8 +7775,8 @@ impl crate::MessageFull for MethodOptions {
     }
 }
 
-impl ::std::fmt::Display for MethodOptions {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MethodOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -6796,8 +7795,10 @@ pub mod method_options {
     pub enum IdempotencyLevel {
         // @@protoc_insertion_point(enum_value:google.protobuf.MethodOptions.IdempotencyLevel.IDEMPOTENCY_UNKNOWN)
         IDEMPOTENCY_UNKNOWN = 0,
+        ///  implies idempotent
         // @@protoc_insertion_point(enum_value:google.protobuf.MethodOptions.IdempotencyLevel.NO_SIDE_EFFECTS)
         NO_SIDE_EFFECTS = 1,
+        ///  idempotent, but may have side effects
         // @@protoc_insertion_point(enum_value:google.protobuf.MethodOptions.IdempotencyLevel.IDEMPOTENT)
         IDEMPOTENT = 2,
     }
@@ -6807,12 +7808,12 @@ pub mod method_options {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<IdempotencyLevel> {
+        fn from_i32(value: i32) -> ::core::option::Option<IdempotencyLevel> {
             match value {
-                0 => ::std::option::Option::Some(IdempotencyLevel::IDEMPOTENCY_UNKNOWN),
-                1 => ::std::option::Option::Some(IdempotencyLevel::NO_SIDE_EFFECTS),
-                2 => ::std::option::Option::Some(IdempotencyLevel::IDEMPOTENT),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(IdempotencyLevel::IDEMPOTENCY_UNKNOWN),
+                1 => ::core::option::Option::Some(IdempotencyLevel::NO_SIDE_EFFECTS),
+                2 => ::core::option::Option::Some(IdempotencyLevel::IDEMPOTENT),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -6832,7 +7833,7 @@ pub mod method_options {
         }
     }
 
-    impl ::std::default::Default for IdempotencyLevel {
+    impl ::core::default::Default for IdempotencyLevel {
         fn default() -> Self {
             IdempotencyLevel::IDEMPOTENCY_UNKNOWN
         }
@@ -6856,21 +7857,21 @@ pub mod method_options {
 pub struct UninterpretedOption {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.name)
-    pub name: ::std::vec::Vec<uninterpreted_option::NamePart>,
+    pub name: crate::rt::Vec<uninterpreted_option::NamePart>,
     ///  The value of the uninterpreted option, in whatever type the tokenizer
     ///  identified it as during parsing. Exactly one of these should be set.
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.identifier_value)
-    identifier_value: ::std::option::Option<::std::string::String>,
+    identifier_value: ::core::option::Option<crate::rt::String>,
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.positive_int_value)
-    positive_int_value: ::std::option::Option<u64>,
+    positive_int_value: ::core::option::Option<u64>,
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.negative_int_value)
-    negative_int_value: ::std::option::Option<i64>,
+    negative_int_value: ::core::option::Option<i64>,
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.double_value)
-    double_value: ::std::option::Option<f64>,
+    double_value: ::core::option::Option<f64>,
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.string_value)
-    string_value: ::std::option::Option<::std::vec::Vec<u8>>,
+    string_value: ::core::option::Option<crate::rt::Vec<u8>>,
     // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.aggregate_value)
-    aggregate_value: ::std::option::Option<::std::string::String>,
+    aggregate_value: ::core::option::Option<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.UninterpretedOption.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -6878,7 +7879,7 @@ pub struct UninterpretedOption {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UninterpretedOption {
+impl<'a> ::core::default::Default for &'a UninterpretedOption {
     fn default() -> &'a UninterpretedOption {
         <UninterpretedOption as crate::Message>::default_instance()
     }
@@ -6886,7 +7887,7 @@ impl<'a> ::std::default::Default for &'a UninterpretedOption {
 
 impl UninterpretedOption {
     pub fn new() -> UninterpretedOption {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string identifier_value = 3;
@@ -6899,7 +7900,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_identifier_value(&mut self) {
-        self.identifier_value = ::std::option::Option::None;
+        self.identifier_value = ::core::option::Option::None;
     }
 
     pub fn has_identifier_value(&self) -> bool {
@@ -6907,22 +7908,22 @@ impl UninterpretedOption {
     }
 
     // Param is passed by value, moved
-    pub fn set_identifier_value(&mut self, v: ::std::string::String) {
-        self.identifier_value = ::std::option::Option::Some(v);
+    pub fn set_identifier_value(&mut self, v: crate::rt::String) {
+        self.identifier_value = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_identifier_value(&mut self) -> &mut ::std::string::String {
+    pub fn mut_identifier_value(&mut self) -> &mut crate::rt::String {
         if self.identifier_value.is_none() {
-            self.identifier_value = ::std::option::Option::Some(::std::string::String::new());
+            self.identifier_value = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.identifier_value.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_identifier_value(&mut self) -> ::std::string::String {
-        self.identifier_value.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_identifier_value(&mut self) -> crate::rt::String {
+        self.identifier_value.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional uint64 positive_int_value = 4;
@@ -6932,7 +7933,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_positive_int_value(&mut self) {
-        self.positive_int_value = ::std::option::Option::None;
+        self.positive_int_value = ::core::option::Option::None;
     }
 
     pub fn has_positive_int_value(&self) -> bool {
@@ -6941,7 +7942,7 @@ impl UninterpretedOption {
 
     // Param is passed by value, moved
     pub fn set_positive_int_value(&mut self, v: u64) {
-        self.positive_int_value = ::std::option::Option::Some(v);
+        self.positive_int_value = ::core::option::Option::Some(v);
     }
 
     // optional int64 negative_int_value = 5;
@@ -6951,7 +7952,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_negative_int_value(&mut self) {
-        self.negative_int_value = ::std::option::Option::None;
+        self.negative_int_value = ::core::option::Option::None;
     }
 
     pub fn has_negative_int_value(&self) -> bool {
@@ -6960,7 +7961,7 @@ impl UninterpretedOption {
 
     // Param is passed by value, moved
     pub fn set_negative_int_value(&mut self, v: i64) {
-        self.negative_int_value = ::std::option::Option::Some(v);
+        self.negative_int_value = ::core::option::Option::Some(v);
     }
 
     // optional double double_value = 6;
@@ -6970,7 +7971,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_double_value(&mut self) {
-        self.double_value = ::std::option::Option::None;
+        self.double_value = ::core::option::Option::None;
     }
 
     pub fn has_double_value(&self) -> bool {
@@ -6979,7 +7980,7 @@ impl UninterpretedOption {
 
     // Param is passed by value, moved
     pub fn set_double_value(&mut self, v: f64) {
-        self.double_value = ::std::option::Option::Some(v);
+        self.double_value = ::core::option::Option::Some(v);
     }
 
     // optional bytes string_value = 7;
@@ -6992,7 +7993,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_string_value(&mut self) {
-        self.string_value = ::std::option::Option::None;
+        self.string_value = ::core::option::Option::None;
     }
 
     pub fn has_string_value(&self) -> bool {
@@ -7000,22 +8001,22 @@ impl UninterpretedOption {
     }
 
     // Param is passed by value, moved
-    pub fn set_string_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.string_value = ::std::option::Option::Some(v);
+    pub fn set_string_value(&mut self, v: crate::rt::Vec<u8>) {
+        self.string_value = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_string_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+    pub fn mut_string_value(&mut self) -> &mut crate::rt::Vec<u8> {
         if self.string_value.is_none() {
-            self.string_value = ::std::option::Option::Some(::std::vec::Vec::new());
+            self.string_value = ::core::option::Option::Some(crate::rt::Vec::new());
         }
         self.string_value.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_string_value(&mut self) -> ::std::vec::Vec<u8> {
-        self.string_value.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_string_value(&mut self) -> crate::rt::Vec<u8> {
+        self.string_value.take().unwrap_or_else(|| crate::rt::Vec::new())
     }
 
     // optional string aggregate_value = 8;
@@ -7028,7 +8029,7 @@ impl UninterpretedOption {
     }
 
     pub fn clear_aggregate_value(&mut self) {
-        self.aggregate_value = ::std::option::Option::None;
+        self.aggregate_value = ::core::option::Option::None;
     }
 
     pub fn has_aggregate_value(&self) -> bool {
@@ -7036,26 +8037,26 @@ impl UninterpretedOption {
     }
 
     // Param is passed by value, moved
-    pub fn set_aggregate_value(&mut self, v: ::std::string::String) {
-        self.aggregate_value = ::std::option::Option::Some(v);
+    pub fn set_aggregate_value(&mut self, v: crate::rt::String) {
+        self.aggregate_value = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_aggregate_value(&mut self) -> &mut ::std::string::String {
+    pub fn mut_aggregate_value(&mut self) -> &mut crate::rt::String {
         if self.aggregate_value.is_none() {
-            self.aggregate_value = ::std::option::Option::Some(::std::string::String::new());
+            self.aggregate_value = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.aggregate_value.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_aggregate_value(&mut self) -> ::std::string::String {
-        self.aggregate_value.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_aggregate_value(&mut self) -> crate::rt::String {
+        self.aggregate_value.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = crate::rt::Vec::with_capacity(7);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "name",
             |m: &UninterpretedOption| { &m.name },
@@ -7117,37 +8118,66 @@ impl crate::Message for UninterpretedOption {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.name.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "name", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 18 => {
-                    self.name.push(is.read_message()?);
+                    self.name.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.name.len())?;
                 },
                 26 => {
-                    self.identifier_value = ::std::option::Option::Some(is.read_string()?);
+                    self.identifier_value = ::core::option::Option::Some(is.read_string()?);
                 },
                 32 => {
-                    self.positive_int_value = ::std::option::Option::Some(is.read_uint64()?);
+                    self.positive_int_value = ::core::option::Option::Some(is.read_uint64()?);
                 },
                 40 => {
-                    self.negative_int_value = ::std::option::Option::Some(is.read_int64()?);
+                    self.negative_int_value = ::core::option::Option::Some(is.read_int64()?);
                 },
                 49 => {
-                    self.double_value = ::std::option::Option::Some(is.read_double()?);
+                    self.double_value = ::core::option::Option::Some(is.read_double()?);
                 },
                 58 => {
-                    self.string_value = ::std::option::Option::Some(is.read_bytes()?);
+                    self.string_value = ::core::option::Option::Some(is.read_bytes()?);
                 },
                 66 => {
-                    self.aggregate_value = ::std::option::Option::Some(is.read_string()?);
+                    self.aggregate_value = ::core::option::Option::Some(is.read_string()?);
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.name.extend(other.name.iter().cloned());
+        if other.identifier_value.is_some() {
+            self.identifier_value.clone_from(&other.identifier_value);
+        }
+        if other.positive_int_value.is_some() {
+            self.positive_int_value.clone_from(&other.positive_int_value);
+        }
+        if other.negative_int_value.is_some() {
+            self.negative_int_value.clone_from(&other.negative_int_value);
+        }
+        if other.double_value.is_some() {
+            self.double_value.clone_from(&other.double_value);
+        }
+        if other.string_value.is_some() {
+            self.string_value.clone_from(&other.string_value);
+        }
+        if other.aggregate_value.is_some() {
+            self.aggregate_value.clone_from(&other.aggregate_value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -7204,7 +8234,33 @@ impl crate::Message for UninterpretedOption {
             os.write_string(8, v)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.aggregate_value.as_ref() {
+            os.write_string(8, v);
+        }
+        if let Some(v) = self.string_value.as_ref() {
+            os.write_bytes(7, v);
+        }
+        if let Some(v) = self.double_value {
+            os.write_double(6, v);
+        }
+        if let Some(v) = self.negative_int_value {
+            os.write_int64(5, v);
+        }
+        if let Some(v) = self.positive_int_value {
+            os.write_uint64(4, v);
+        }
+        if let Some(v) = self.identifier_value.as_ref() {
+            os.write_string(3, v);
+        }
+        for v in self.name.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -7225,24 +8281,24 @@ impl crate::Message for UninterpretedOption {
 
     fn clear(&mut self) {
         self.name.clear();
-        self.identifier_value = ::std::option::Option::None;
-        self.positive_int_value = ::std::option::Option::None;
-        self.negative_int_value = ::std::option::Option::None;
-        self.double_value = ::std::option::Option::None;
-        self.string_value = ::std::option::Option::None;
-        self.aggregate_value = ::std::option::Option::None;
+        self.identifier_value = ::core::option::Option::None;
+        self.positive_int_value = ::core::option::Option::None;
+        self.negative_int_value = ::core::option::Option::None;
+        self.double_value = ::core::option::Option::None;
+        self.string_value = ::core::option::Option::None;
+        self.aggregate_value = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static UninterpretedOption {
         static instance: UninterpretedOption = UninterpretedOption {
-            name: ::std::vec::Vec::new(),
-            identifier_value: ::std::option::Option::None,
-            positive_int_value: ::std::option::Option::None,
-            negative_int_value: ::std::option::Option::None,
-            double_value: ::std::option::Option::None,
-            string_value: ::std::option::Option::None,
-            aggregate_value: ::std::option::Option::None,
+            name: crate::rt::Vec::new(),
+            identifier_value: ::core::option::Option::None,
+            positive_int_value: ::core::option::Option::None,
+            negative_int_value: ::core::option::Option::None,
+            double_value: ::core::option::Option::None,
+            string_value: ::core::option::Option::None,
+            aggregate_value: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -7256,8 +8312,8 @@ impl crate::MessageFull for UninterpretedOption {
     }
 }
 
-impl ::std::fmt::Display for UninterpretedOption {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for UninterpretedOption {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -7271,16 +8327,16 @@ pub mod uninterpreted_option {
     ///  The name of the uninterpreted option.  Each string represents a segment in
     ///  a dot-separated name.  is_extension is true iff a segment represents an
     ///  extension (denoted with parentheses in options specs in .proto files).
-    ///  E.g.,{ ["foo", false], ["bar.baz", true], ["qux", false] } represents
+    ///  E.g.,{ \["foo", false\], \["bar.baz", true\], \["qux", false\] } represents
     ///  "foo.(bar.baz).qux".
     #[derive(PartialEq,Clone,Default,Debug)]
     // @@protoc_insertion_point(message:google.protobuf.UninterpretedOption.NamePart)
     pub struct NamePart {
         // message fields
         // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.NamePart.name_part)
-        name_part: ::std::option::Option<::std::string::String>,
+        name_part: ::core::option::Option<crate::rt::String>,
         // @@protoc_insertion_point(field:google.protobuf.UninterpretedOption.NamePart.is_extension)
-        is_extension: ::std::option::Option<bool>,
+        is_extension: ::core::option::Option<bool>,
         // special fields
         // @@protoc_insertion_point(special_field:google.protobuf.UninterpretedOption.NamePart.unknown_fields)
         pub unknown_fields: crate::UnknownFields,
@@ -7288,7 +8344,7 @@ pub mod uninterpreted_option {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a NamePart {
+    impl<'a> ::core::default::Default for &'a NamePart {
         fn default() -> &'a NamePart {
             <NamePart as crate::Message>::default_instance()
         }
@@ -7296,7 +8352,7 @@ pub mod uninterpreted_option {
 
     impl NamePart {
         pub fn new() -> NamePart {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // required string name_part = 1;
@@ -7309,7 +8365,7 @@ pub mod uninterpreted_option {
         }
 
         pub fn clear_name_part(&mut self) {
-            self.name_part = ::std::option::Option::None;
+            self.name_part = ::core::option::Option::None;
         }
 
         pub fn has_name_part(&self) -> bool {
@@ -7317,22 +8373,22 @@ pub mod uninterpreted_option {
         }
 
         // Param is passed by value, moved
-        pub fn set_name_part(&mut self, v: ::std::string::String) {
-            self.name_part = ::std::option::Option::Some(v);
+        pub fn set_name_part(&mut self, v: crate::rt::String) {
+            self.name_part = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_name_part(&mut self) -> &mut ::std::string::String {
+        pub fn mut_name_part(&mut self) -> &mut crate::rt::String {
             if self.name_part.is_none() {
-                self.name_part = ::std::option::Option::Some(::std::string::String::new());
+                self.name_part = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.name_part.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_name_part(&mut self) -> ::std::string::String {
-            self.name_part.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_name_part(&mut self) -> crate::rt::String {
+            self.name_part.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         // required bool is_extension = 2;
@@ -7342,7 +8398,7 @@ pub mod uninterpreted_option {
         }
 
         pub fn clear_is_extension(&mut self) {
-            self.is_extension = ::std::option::Option::None;
+            self.is_extension = ::core::option::Option::None;
         }
 
         pub fn has_is_extension(&self) -> bool {
@@ -7351,11 +8407,11 @@ pub mod uninterpreted_option {
 
         // Param is passed by value, moved
         pub fn set_is_extension(&mut self, v: bool) {
-            self.is_extension = ::std::option::Option::Some(v);
+            self.is_extension = ::core::option::Option::Some(v);
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut fields = crate::rt::Vec::with_capacity(2);
             fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
                 "name_part",
                 |m: &NamePart| { &m.name_part },
@@ -7389,22 +8445,40 @@ pub mod uninterpreted_option {
             true
         }
 
+        fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+            if self.name_part.is_none() {
+                crate::rt::push_missing_required_field(missing, prefix, "name_part");
+            }
+            if self.is_extension.is_none() {
+                crate::rt::push_missing_required_field(missing, prefix, "is_extension");
+            }
+        }
+
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     10 => {
-                        self.name_part = ::std::option::Option::Some(is.read_string()?);
+                        self.name_part = ::core::option::Option::Some(is.read_string()?);
                     },
                     16 => {
-                        self.is_extension = ::std::option::Option::Some(is.read_bool()?);
+                        self.is_extension = ::core::option::Option::Some(is.read_bool()?);
                     },
                     tag => {
                         crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.name_part.is_some() {
+                self.name_part.clone_from(&other.name_part);
+            }
+            if other.is_extension.is_some() {
+                self.is_extension.clone_from(&other.is_extension);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -7430,7 +8504,18 @@ pub mod uninterpreted_option {
                 os.write_bool(2, v)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.is_extension {
+                os.write_bool(2, v);
+            }
+            if let Some(v) = self.name_part.as_ref() {
+                os.write_string(1, v);
+            }
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -7450,15 +8535,15 @@ pub mod uninterpreted_option {
         }
 
         fn clear(&mut self) {
-            self.name_part = ::std::option::Option::None;
-            self.is_extension = ::std::option::Option::None;
+            self.name_part = ::core::option::Option::None;
+            self.is_extension = ::core::option::Option::None;
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static NamePart {
             static instance: NamePart = NamePart {
-                name_part: ::std::option::Option::None,
-                is_extension: ::std::option::Option::None,
+                name_part: ::core::option::Option::None,
+                is_extension: ::core::option::Option::None,
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
             };
@@ -7472,8 +8557,8 @@ pub mod uninterpreted_option {
         }
     }
 
-    impl ::std::fmt::Display for NamePart {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for NamePart {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -7489,8 +8574,51 @@ pub mod uninterpreted_option {
 // @@protoc_insertion_point(message:google.protobuf.SourceCodeInfo)
 pub struct SourceCodeInfo {
     // message fields
+    ///  A Location identifies a piece of source code in a .proto file which
+    ///  corresponds to a particular definition.  This information is intended
+    ///  to be useful to IDEs, code indexers, documentation generators, and similar
+    ///  tools.
+    ///
+    ///  For example, say we have a file like:
+    ///    message Foo {
+    ///      optional string foo = 1;
+    ///    }
+    ///  Let's look at just the field definition:
+    ///    optional string foo = 1;
+    ///    ^       ^^     ^^  ^  ^^^
+    ///    a       bc     de  f  ghi
+    ///  We have the following locations:
+    ///    span   path               represents
+    ///    \[a,i)  \[ 4, 0, 2, 0 \]     The whole field definition.
+    ///    \[a,b)  \[ 4, 0, 2, 0, 4 \]  The label (optional).
+    ///    \[c,d)  \[ 4, 0, 2, 0, 5 \]  The type (string).
+    ///    \[e,f)  \[ 4, 0, 2, 0, 1 \]  The name (foo).
+    ///    \[g,h)  \[ 4, 0, 2, 0, 3 \]  The number (1).
+    ///
+    ///  Notes:
+    ///  - A location may refer to a repeated field itself (i.e. not to any
+    ///    particular index within it).  This is used whenever a set of elements are
+    ///    logically enclosed in a single code segment.  For example, an entire
+    ///    extend block (possibly containing multiple extension definitions) will
+    ///    have an outer location whose path refers to the "extensions" repeated
+    ///    field without an index.
+    ///  - Multiple locations may have the same path.  This happens when a single
+    ///    logical declaration is spread out across multiple places.  The most
+    ///    obvious example is the "extend" block again -- there may be multiple
+    ///    extend blocks in the same scope, each of which will have the same path.
+    ///  - A location's span is not always a subset of its parent's span.  For
+    ///    example, the "extendee" of an extension declaration appears at the
+    ///    beginning of the "extend" block and is shared by all extensions within
+    ///    the block.
+    ///  - Just because a location's span is a subset of some other location's span
+    ///    does not mean that it is a descendant.  For example, a "group" defines
+    ///    both a type and a field in a single declaration.  Thus, the locations
+    ///    corresponding to the type and field and their components will overlap.
+    ///  - Code which tries to interpret locations should probably be designed to
+    ///    ignore those that it doesn't understand, as more types of locations could
+    ///    be recorded in the future.
     // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.location)
-    pub location: ::std::vec::Vec<source_code_info::Location>,
+    pub location: crate::rt::Vec<source_code_info::Location>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.SourceCodeInfo.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -7498,7 +8626,7 @@ pub struct SourceCodeInfo {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SourceCodeInfo {
+impl<'a> ::core::default::Default for &'a SourceCodeInfo {
     fn default() -> &'a SourceCodeInfo {
         <SourceCodeInfo as crate::Message>::default_instance()
     }
@@ -7506,11 +8634,11 @@ impl<'a> ::std::default::Default for &'a SourceCodeInfo {
 
 impl SourceCodeInfo {
     pub fn new() -> SourceCodeInfo {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "location",
             |m: &SourceCodeInfo| { &m.location },
@@ -7536,19 +8664,30 @@ impl crate::Message for SourceCodeInfo {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.location.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "location", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.location.push(is.read_message()?);
+                    self.location.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.location.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.location.extend(other.location.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -7569,7 +8708,15 @@ impl crate::Message for SourceCodeInfo {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.location.iter().rev() {
+            crate::rt::write_message_field_reverse(1, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -7595,7 +8742,7 @@ impl crate::Message for SourceCodeInfo {
 
     fn default_instance() -> &'static SourceCodeInfo {
         static instance: SourceCodeInfo = SourceCodeInfo {
-            location: ::std::vec::Vec::new(),
+            location: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -7609,8 +8756,8 @@ impl crate::MessageFull for SourceCodeInfo {
     }
 }
 
-impl ::std::fmt::Display for SourceCodeInfo {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for SourceCodeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -7625,21 +8772,91 @@ pub mod source_code_info {
     // @@protoc_insertion_point(message:google.protobuf.SourceCodeInfo.Location)
     pub struct Location {
         // message fields
+        ///  Identifies which part of the FileDescriptorProto was defined at this
+        ///  location.
+        ///
+        ///  Each element is a field number or an index.  They form a path from
+        ///  the root FileDescriptorProto to the place where the definition.  For
+        ///  example, this path:
+        ///    \[ 4, 3, 2, 7, 1 \]
+        ///  refers to:
+        ///    file.message_type(3)  // 4, 3
+        ///        .field(7)         // 2, 7
+        ///        .name()           // 1
+        ///  This is because FileDescriptorProto.message_type has field number 4:
+        ///    repeated DescriptorProto message_type = 4;
+        ///  and DescriptorProto.field has field number 2:
+        ///    repeated FieldDescriptorProto field = 2;
+        ///  and FieldDescriptorProto.name has field number 1:
+        ///    optional string name = 1;
+        ///
+        ///  Thus, the above path gives the location of a field name.  If we removed
+        ///  the last element:
+        ///    \[ 4, 3, 2, 7 \]
+        ///  this path refers to the whole field declaration (from the beginning
+        ///  of the label to the terminating semicolon).
         // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.Location.path)
-        pub path: ::std::vec::Vec<i32>,
+        pub path: crate::rt::Vec<i32>,
         ///  Always has exactly three or four elements: start line, start column,
         ///  end line (optional, otherwise assumed same as start line), end column.
         ///  These are packed into a single field for efficiency.  Note that line
         ///  and column numbers are zero-based -- typically you will want to add
         ///  1 to each before displaying to a user.
         // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.Location.span)
-        pub span: ::std::vec::Vec<i32>,
+        pub span: crate::rt::Vec<i32>,
+        ///  If this SourceCodeInfo represents a complete declaration, these are any
+        ///  comments appearing before and after the declaration which appear to be
+        ///  attached to the declaration.
+        ///
+        ///  A series of line comments appearing on consecutive lines, with no other
+        ///  tokens appearing on those lines, will be treated as a single comment.
+        ///
+        ///  leading_detached_comments will keep paragraphs of comments that appear
+        ///  before (but not connected to) the current element. Each paragraph,
+        ///  separated by empty lines, will be one comment element in the repeated
+        ///  field.
+        ///
+        ///  Only the comment content is provided; comment markers (e.g. //) are
+        ///  stripped out.  For block comments, leading whitespace and an asterisk
+        ///  will be stripped from the beginning of each line other than the first.
+        ///  Newlines are included in the output.
+        ///
+        ///  Examples:
+        ///
+        ///    optional int32 foo = 1;  // Comment attached to foo.
+        ///    // Comment attached to bar.
+        ///    optional int32 bar = 2;
+        ///
+        ///    optional string baz = 3;
+        ///    // Comment attached to baz.
+        ///    // Another line attached to baz.
+        ///
+        ///    // Comment attached to qux.
+        ///    //
+        ///    // Another line attached to qux.
+        ///    optional double qux = 4;
+        ///
+        ///    // Detached comment for corge. This is not leading or trailing comments
+        ///    // to qux or corge because there are blank lines separating it from
+        ///    // both.
+        ///
+        ///    // Detached comment for corge paragraph 2.
+        ///
+        ///    optional string corge = 5;
+        ///    /* Block comment attached
+        ///     * to corge.  Leading asterisks
+        ///     * will be removed. */
+        ///    /* Block comment attached to
+        ///     * grault. */
+        ///    optional int32 grault = 6;
+        ///
+        ///    // ignored detached comments.
         // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.Location.leading_comments)
-        leading_comments: ::std::option::Option<::std::string::String>,
+        leading_comments: ::core::option::Option<crate::rt::String>,
         // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.Location.trailing_comments)
-        trailing_comments: ::std::option::Option<::std::string::String>,
+        trailing_comments: ::core::option::Option<crate::rt::String>,
         // @@protoc_insertion_point(field:google.protobuf.SourceCodeInfo.Location.leading_detached_comments)
-        pub leading_detached_comments: ::std::vec::Vec<::std::string::String>,
+        pub leading_detached_comments: crate::rt::Vec<crate::rt::String>,
         // special fields
         // @@protoc_insertion_point(special_field:google.protobuf.SourceCodeInfo.Location.unknown_fields)
         pub unknown_fields: crate::UnknownFields,
@@ -7647,7 +8864,7 @@ pub mod source_code_info {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a Location {
+    impl<'a> ::core::default::Default for &'a Location {
         fn default() -> &'a Location {
             <Location as crate::Message>::default_instance()
         }
@@ -7655,7 +8872,7 @@ pub mod source_code_info {
 
     impl Location {
         pub fn new() -> Location {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional string leading_comments = 3;
@@ -7668,7 +8885,7 @@ pub mod source_code_info {
         }
 
         pub fn clear_leading_comments(&mut self) {
-            self.leading_comments = ::std::option::Option::None;
+            self.leading_comments = ::core::option::Option::None;
         }
 
         pub fn has_leading_comments(&self) -> bool {
@@ -7676,22 +8893,22 @@ pub mod source_code_info {
         }
 
         // Param is passed by value, moved
-        pub fn set_leading_comments(&mut self, v: ::std::string::String) {
-            self.leading_comments = ::std::option::Option::Some(v);
+        pub fn set_leading_comments(&mut self, v: crate::rt::String) {
+            self.leading_comments = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_leading_comments(&mut self) -> &mut ::std::string::String {
+        pub fn mut_leading_comments(&mut self) -> &mut crate::rt::String {
             if self.leading_comments.is_none() {
-                self.leading_comments = ::std::option::Option::Some(::std::string::String::new());
+                self.leading_comments = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.leading_comments.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_leading_comments(&mut self) -> ::std::string::String {
-            self.leading_comments.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_leading_comments(&mut self) -> crate::rt::String {
+            self.leading_comments.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         // optional string trailing_comments = 4;
@@ -7704,7 +8921,7 @@ pub mod source_code_info {
         }
 
         pub fn clear_trailing_comments(&mut self) {
-            self.trailing_comments = ::std::option::Option::None;
+            self.trailing_comments = ::core::option::Option::None;
         }
 
         pub fn has_trailing_comments(&self) -> bool {
@@ -7712,26 +8929,26 @@ pub mod source_code_info {
         }
 
         // Param is passed by value, moved
-        pub fn set_trailing_comments(&mut self, v: ::std::string::String) {
-            self.trailing_comments = ::std::option::Option::Some(v);
+        pub fn set_trailing_comments(&mut self, v: crate::rt::String) {
+            self.trailing_comments = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_trailing_comments(&mut self) -> &mut ::std::string::String {
+        pub fn mut_trailing_comments(&mut self) -> &mut crate::rt::String {
             if self.trailing_comments.is_none() {
-                self.trailing_comments = ::std::option::Option::Some(::std::string::String::new());
+                self.trailing_comments = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.trailing_comments.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_trailing_comments(&mut self) -> ::std::string::String {
-            self.trailing_comments.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_trailing_comments(&mut self) -> crate::rt::String {
+            self.trailing_comments.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(5);
+            let mut fields = crate::rt::Vec::with_capacity(5);
             fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
                 "path",
                 |m: &Location| { &m.path },
@@ -7775,36 +8992,51 @@ pub mod source_code_info {
         }
 
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     10 => {
                         is.read_repeated_packed_int32_into(&mut self.path)?;
                     },
                     8 => {
                         self.path.push(is.read_int32()?);
+                        crate::rt::check_repeated_len(is, self.path.len())?;
                     },
                     18 => {
                         is.read_repeated_packed_int32_into(&mut self.span)?;
                     },
                     16 => {
                         self.span.push(is.read_int32()?);
+                        crate::rt::check_repeated_len(is, self.span.len())?;
                     },
                     26 => {
-                        self.leading_comments = ::std::option::Option::Some(is.read_string()?);
+                        self.leading_comments = ::core::option::Option::Some(is.read_string()?);
                     },
                     34 => {
-                        self.trailing_comments = ::std::option::Option::Some(is.read_string()?);
+                        self.trailing_comments = ::core::option::Option::Some(is.read_string()?);
                     },
                     50 => {
                         self.leading_detached_comments.push(is.read_string()?);
+                        crate::rt::check_repeated_len(is, self.leading_detached_comments.len())?;
                     },
                     tag => {
                         crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            self.path.extend(other.path.iter().cloned());
+            self.span.extend(other.span.iter().cloned());
+            if other.leading_comments.is_some() {
+                self.leading_comments.clone_from(&other.leading_comments);
+            }
+            if other.trailing_comments.is_some() {
+                self.trailing_comments.clone_from(&other.trailing_comments);
+            }
+            self.leading_detached_comments.extend(other.leading_detached_comments.iter().cloned());
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -7844,7 +9076,23 @@ pub mod source_code_info {
                 os.write_string(6, &v)?;
             };
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            for v in self.leading_detached_comments.iter().rev() {
+                os.write_string(6, &v);
+            };
+            if let Some(v) = self.trailing_comments.as_ref() {
+                os.write_string(4, v);
+            }
+            if let Some(v) = self.leading_comments.as_ref() {
+                os.write_string(3, v);
+            }
+            crate::rt::write_repeated_packed_reverse::<crate::reflect::types::ProtobufTypeInt32>(2, &self.span, os)?;
+            crate::rt::write_repeated_packed_reverse::<crate::reflect::types::ProtobufTypeInt32>(1, &self.path, os)?;
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -7866,19 +9114,19 @@ pub mod source_code_info {
         fn clear(&mut self) {
             self.path.clear();
             self.span.clear();
-            self.leading_comments = ::std::option::Option::None;
-            self.trailing_comments = ::std::option::Option::None;
+            self.leading_comments = ::core::option::Option::None;
+            self.trailing_comments = ::core::option::Option::None;
             self.leading_detached_comments.clear();
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static Location {
             static instance: Location = Location {
-                path: ::std::vec::Vec::new(),
-                span: ::std::vec::Vec::new(),
-                leading_comments: ::std::option::Option::None,
-                trailing_comments: ::std::option::Option::None,
-                leading_detached_comments: ::std::vec::Vec::new(),
+                path: crate::rt::Vec::new(),
+                span: crate::rt::Vec::new(),
+                leading_comments: ::core::option::Option::None,
+                trailing_comments: ::core::option::Option::None,
+                leading_detached_comments: crate::rt::Vec::new(),
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
             };
@@ -7892,8 +9140,8 @@ pub mod source_code_info {
         }
     }
 
-    impl ::std::fmt::Display for Location {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for Location {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -7913,7 +9161,7 @@ pub struct GeneratedCodeInfo {
     ///  An Annotation connects some span of text in generated code to an element
     ///  of its generating .proto file.
     // @@protoc_insertion_point(field:google.protobuf.GeneratedCodeInfo.annotation)
-    pub annotation: ::std::vec::Vec<generated_code_info::Annotation>,
+    pub annotation: crate::rt::Vec<generated_code_info::Annotation>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.GeneratedCodeInfo.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -7921,7 +9169,7 @@ pub struct GeneratedCodeInfo {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a GeneratedCodeInfo {
+impl<'a> ::core::default::Default for &'a GeneratedCodeInfo {
     fn default() -> &'a GeneratedCodeInfo {
         <GeneratedCodeInfo as crate::Message>::default_instance()
     }
@@ -7929,11 +9177,11 @@ impl<'a> ::std::default::Default for &'a GeneratedCodeInfo {
 
 impl GeneratedCodeInfo {
     pub fn new() -> GeneratedCodeInfo {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "annotation",
             |m: &GeneratedCodeInfo| { &m.annotation },
@@ -7959,19 +9207,30 @@ impl crate::Message for GeneratedCodeInfo {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.annotation.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "annotation", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.annotation.push(is.read_message()?);
+                    self.annotation.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.annotation.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.annotation.extend(other.annotation.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -7992,7 +9251,15 @@ impl crate::Message for GeneratedCodeInfo {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.annotation.iter().rev() {
+            crate::rt::write_message_field_reverse(1, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -8018,7 +9285,7 @@ impl crate::Message for GeneratedCodeInfo {
 
     fn default_instance() -> &'static GeneratedCodeInfo {
         static instance: GeneratedCodeInfo = GeneratedCodeInfo {
-            annotation: ::std::vec::Vec::new(),
+            annotation: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -8032,8 +9299,8 @@ impl crate::MessageFull for GeneratedCodeInfo {
     }
 }
 
-impl ::std::fmt::Display for GeneratedCodeInfo {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for GeneratedCodeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -8051,19 +9318,19 @@ pub mod generated_code_info {
         ///  Identifies the element in the original source .proto file. This field
         ///  is formatted the same as SourceCodeInfo.Location.path.
         // @@protoc_insertion_point(field:google.protobuf.GeneratedCodeInfo.Annotation.path)
-        pub path: ::std::vec::Vec<i32>,
+        pub path: crate::rt::Vec<i32>,
         ///  Identifies the filesystem path to the original source .proto.
         // @@protoc_insertion_point(field:google.protobuf.GeneratedCodeInfo.Annotation.source_file)
-        source_file: ::std::option::Option<::std::string::String>,
+        source_file: ::core::option::Option<crate::rt::String>,
         ///  Identifies the starting offset in bytes in the generated code
         ///  that relates to the identified object.
         // @@protoc_insertion_point(field:google.protobuf.GeneratedCodeInfo.Annotation.begin)
-        begin: ::std::option::Option<i32>,
+        begin: ::core::option::Option<i32>,
         ///  Identifies the ending offset in bytes in the generated code that
         ///  relates to the identified offset. The end offset should be one past
         ///  the last relevant byte (so the length of the text = end - begin).
         // @@protoc_insertion_point(field:google.protobuf.GeneratedCodeInfo.Annotation.end)
-        end: ::std::option::Option<i32>,
+        end: ::core::option::Option<i32>,
         // special fields
         // @@protoc_insertion_point(special_field:google.protobuf.GeneratedCodeInfo.Annotation.unknown_fields)
         pub unknown_fields: crate::UnknownFields,
@@ -8071,7 +9338,7 @@ pub mod generated_code_info {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a Annotation {
+    impl<'a> ::core::default::Default for &'a Annotation {
         fn default() -> &'a Annotation {
             <Annotation as crate::Message>::default_instance()
         }
@@ -8079,7 +9346,7 @@ pub mod generated_code_info {
 
     impl Annotation {
         pub fn new() -> Annotation {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional string source_file = 2;
@@ -8092,7 +9359,7 @@ pub mod generated_code_info {
         }
 
         pub fn clear_source_file(&mut self) {
-            self.source_file = ::std::option::Option::None;
+            self.source_file = ::core::option::Option::None;
         }
 
         pub fn has_source_file(&self) -> bool {
@@ -8100,22 +9367,22 @@ pub mod generated_code_info {
         }
 
         // Param is passed by value, moved
-        pub fn set_source_file(&mut self, v: ::std::string::String) {
-            self.source_file = ::std::option::Option::Some(v);
+        pub fn set_source_file(&mut self, v: crate::rt::String) {
+            self.source_file = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_source_file(&mut self) -> &mut ::std::string::String {
+        pub fn mut_source_file(&mut self) -> &mut crate::rt::String {
             if self.source_file.is_none() {
-                self.source_file = ::std::option::Option::Some(::std::string::String::new());
+                self.source_file = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.source_file.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_source_file(&mut self) -> ::std::string::String {
-            self.source_file.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_source_file(&mut self) -> crate::rt::String {
+            self.source_file.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         // optional int32 begin = 3;
@@ -8125,7 +9392,7 @@ pub mod generated_code_info {
         }
 
         pub fn clear_begin(&mut self) {
-            self.begin = ::std::option::Option::None;
+            self.begin = ::core::option::Option::None;
         }
 
         pub fn has_begin(&self) -> bool {
@@ -8134,7 +9401,7 @@ pub mod generated_code_info {
 
         // Param is passed by value, moved
         pub fn set_begin(&mut self, v: i32) {
-            self.begin = ::std::option::Option::Some(v);
+            self.begin = ::core::option::Option::Some(v);
         }
 
         // optional int32 end = 4;
@@ -8144,7 +9411,7 @@ pub mod generated_code_info {
         }
 
         pub fn clear_end(&mut self) {
-            self.end = ::std::option::Option::None;
+            self.end = ::core::option::Option::None;
         }
 
         pub fn has_end(&self) -> bool {
@@ -8153,11 +9420,11 @@ pub mod generated_code_info {
 
         // Param is passed by value, moved
         pub fn set_end(&mut self, v: i32) {
-            self.end = ::std::option::Option::Some(v);
+            self.end = ::core::option::Option::Some(v);
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut fields = crate::rt::Vec::with_capacity(4);
             fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
                 "path",
                 |m: &Annotation| { &m.path },
@@ -8197,30 +9464,44 @@ pub mod generated_code_info {
         }
 
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     10 => {
                         is.read_repeated_packed_int32_into(&mut self.path)?;
                     },
                     8 => {
                         self.path.push(is.read_int32()?);
+                        crate::rt::check_repeated_len(is, self.path.len())?;
                     },
                     18 => {
-                        self.source_file = ::std::option::Option::Some(is.read_string()?);
+                        self.source_file = ::core::option::Option::Some(is.read_string()?);
                     },
                     24 => {
-                        self.begin = ::std::option::Option::Some(is.read_int32()?);
+                        self.begin = ::core::option::Option::Some(is.read_int32()?);
                     },
                     32 => {
-                        self.end = ::std::option::Option::Some(is.read_int32()?);
+                        self.end = ::core::option::Option::Some(is.read_int32()?);
                     },
                     tag => {
                         crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            self.path.extend(other.path.iter().cloned());
+            if other.source_file.is_some() {
+                self.source_file.clone_from(&other.source_file);
+            }
+            if other.begin.is_some() {
+                self.begin.clone_from(&other.begin);
+            }
+            if other.end.is_some() {
+                self.end.clone_from(&other.end);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -8256,7 +9537,22 @@ pub mod generated_code_info {
                 os.write_int32(4, v)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.end {
+                os.write_int32(4, v);
+            }
+            if let Some(v) = self.begin {
+                os.write_int32(3, v);
+            }
+            if let Some(v) = self.source_file.as_ref() {
+                os.write_string(2, v);
+            }
+            crate::rt::write_repeated_packed_reverse::<crate::reflect::types::ProtobufTypeInt32>(1, &self.path, os)?;
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -8277,18 +9573,18 @@ pub mod generated_code_info {
 
         fn clear(&mut self) {
             self.path.clear();
-            self.source_file = ::std::option::Option::None;
-            self.begin = ::std::option::Option::None;
-            self.end = ::std::option::Option::None;
+            self.source_file = ::core::option::Option::None;
+            self.begin = ::core::option::Option::None;
+            self.end = ::core::option::Option::None;
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static Annotation {
             static instance: Annotation = Annotation {
-                path: ::std::vec::Vec::new(),
-                source_file: ::std::option::Option::None,
-                begin: ::std::option::Option::None,
-                end: ::std::option::Option::None,
+                path: crate::rt::Vec::new(),
+                source_file: ::core::option::Option::None,
+                begin: ::core::option::Option::None,
+                end: ::core::option::Option::None,
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
             };
@@ -8302,8 +9598,8 @@ pub mod generated_code_info {
         }
     }
 
-    impl ::std::fmt::Display for Annotation {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for Annotation {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -9779,8 +11075,8 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(FileDescriptorSet::generated_message_descriptor_data());
         messages.push(FileDescriptorProto::generated_message_descriptor_data());
         messages.push(DescriptorProto::generated_message_descriptor_data());
@@ -9808,7 +11104,7 @@ pub fn file_descriptor() -> crate::reflect::FileDescriptor {
         messages.push(uninterpreted_option::NamePart::generated_message_descriptor_data());
         messages.push(source_code_info::Location::generated_message_descriptor_data());
         messages.push(generated_code_info::Annotation::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         enums.push(field_descriptor_proto::Type::generated_enum_descriptor_data());
         enums.push(field_descriptor_proto::Label::generated_enum_descriptor_data());
         enums.push(file_options::OptimizeMode::generated_enum_descriptor_data());
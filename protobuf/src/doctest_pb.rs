@@ -31,7 +31,7 @@ pub struct MyMessage {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MyMessage {
+impl<'a> ::core::default::Default for &'a MyMessage {
     fn default() -> &'a MyMessage {
         <MyMessage as crate::Message>::default_instance()
     }
@@ -39,11 +39,11 @@ impl<'a> ::std::default::Default for &'a MyMessage {
 
 impl MyMessage {
     pub fn new() -> MyMessage {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = crate::rt::Vec::with_capacity(0);
         crate::reflect::GeneratedMessageDescriptorData::new_2::<MyMessage>(
             "MyMessage",
             0,
@@ -60,15 +60,18 @@ impl crate::Message for MyMessage {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -82,7 +85,12 @@ impl crate::Message for MyMessage {
 
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -120,8 +128,8 @@ impl crate::MessageFull for MyMessage {
     }
 }
 
-impl ::std::fmt::Display for MyMessage {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MyMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -149,10 +157,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(MyMessage::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -1,10 +1,14 @@
-use std::fmt;
-use std::marker::PhantomData;
+use core::fmt;
+use core::marker::PhantomData;
 
+#[cfg(feature = "std")]
 use crate::reflect::runtime_types::RuntimeTypeEnumOrUnknown;
+#[cfg(feature = "std")]
 use crate::reflect::EnumDescriptor;
+#[cfg(feature = "std")]
 use crate::reflect::ProtobufValue;
 use crate::Enum;
+#[cfg(feature = "std")]
 use crate::EnumFull;
 
 /// Protobuf enums with possibly unknown values are preserved in this struct.
@@ -64,6 +68,7 @@ impl<E: Enum> EnumOrUnknown<E> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<E: EnumFull> EnumOrUnknown<E> {
     /// Get enum descriptor by type.
     pub fn enum_descriptor_static() -> EnumDescriptor {
@@ -92,6 +97,7 @@ impl<E: Enum + fmt::Debug> fmt::Debug for EnumOrUnknown<E> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<E: EnumFull> ProtobufValue for EnumOrUnknown<E> {
     type RuntimeType = RuntimeTypeEnumOrUnknown<E>;
 }
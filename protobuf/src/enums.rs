@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 /// Trait implemented by all protobuf enum types.
 ///
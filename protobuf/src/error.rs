@@ -1,10 +1,21 @@
+#[cfg(feature = "std")]
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str;
+#[cfg(feature = "std")]
 use std::io;
-use std::str;
 
+#[cfg(feature = "std")]
+use crate::reflect::error::ReflectError;
 use crate::wire_format::WireType;
 
 /// `Result` alias for `ProtobufError`
-pub type Result<T> = std::result::Result<T, crate::Error>;
+pub type Result<T> = core::result::Result<T, crate::Error>;
 
 /// Enum values added here for diagnostic purposes.
 /// Users should not depend on specific values.
@@ -33,12 +44,27 @@ pub(crate) enum WireError {
     LimitIncrease,
     #[error("Encoded message size {0} is too large")]
     MessageTooLarge(u64),
+    #[error("Decode limit `{0}` of {1} exceeded")]
+    DecodeLimitExceeded(&'static str, u64),
+}
+
+/// Formats the list of missing required fields for `MessageNotInitialized`.
+struct MissingRequiredFields<'a>(&'a [String]);
+
+impl<'a> fmt::Display for MissingRequiredFields<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, path) in self.0.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { ": " } else { ", " }, path)?;
+        }
+        Ok(())
+    }
 }
 
 /// Generic protobuf error
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum ProtobufError {
     /// I/O error when reading or writing
+    #[cfg(feature = "std")]
     #[error(transparent)]
     IoError(#[from] io::Error),
     /// Malformed input
@@ -51,56 +77,330 @@ pub(crate) enum ProtobufError {
         #[from]
         str::Utf8Error,
     ),
+    /// Output slice is too small to write the message.
+    #[error("Given slice is too small to serialize the message")]
+    BufferTooSmall,
     /// Not all required fields of message set.
-    #[error("Message `{}` is missing required fields", .0)]
-    MessageNotInitialized(String),
+    #[error(
+        "Message `{}` is missing required fields{}",
+        .message,
+        MissingRequiredFields(.missing)
+    )]
+    MessageNotInitialized {
+        /// Name of the message.
+        message: String,
+        /// Paths of unset required fields, like `items[3].sku`.
+        missing: Vec<String>,
+    },
     /// Protobuf type and runtime types mismatch.
+    #[cfg(feature = "std")]
     #[error("Protobuf type and runtime types are not compatible")]
     IncompatibleProtobufTypeAndRuntimeType,
-    /// Group field type not implemented.
-    #[error("Group field is not supported")]
-    GroupIsNotImplemented,
+    /// Service has no such method.
+    #[cfg(feature = "std")]
+    #[error("Unknown method `{}`", .0)]
+    UnknownMethod(String),
+    /// Incorrect or unresolvable descriptors.
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Reflect(#[from] ReflectError),
+}
+
+/// Kind of [`Error`].
+///
+/// New kinds may be added in future versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// I/O error when reading or writing.
+    Io,
+    /// Input ended in the middle of a value or a message.
+    UnexpectedEof,
+    /// Wire type is not valid or does not match the field.
+    UnexpectedWireType,
+    /// Tag is not valid (e. g. field number zero).
+    IncorrectTag,
+    /// Varint is longer than ten bytes.
+    IncorrectVarint,
+    /// `string` field is not valid UTF-8.
+    Utf8,
+    /// Enum value is not known (closed enums).
+    InvalidEnumValue,
+    /// Messages are nested deeper than recursion limit.
+    OverRecursionLimit,
+    /// Length-delimited value is longer than the enclosing message.
+    TruncatedMessage,
+    /// Limit arithmetic overflow.
+    LimitOverflow,
+    /// Attempt to push a limit greater than the current limit.
+    LimitIncrease,
+    /// Encoded message does not fit in 2 GiB.
+    MessageTooLarge,
+    /// One of [`ParseOptions`](crate::ParseOptions) limits exceeded.
+    DecodeLimitExceeded,
+    /// Output slice is too small to write the message.
+    BufferTooSmall,
+    /// Not all required fields of a message are set.
+    MessageNotInitialized,
+    /// Protobuf type and runtime types mismatch.
+    IncompatibleProtobufTypeAndRuntimeType,
+    /// Service has no such method.
+    UnknownMethod,
+    /// Incorrect or unresolvable descriptors.
+    Reflect,
+}
+
+impl ProtobufError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "std")]
+            ProtobufError::IoError(..) => ErrorKind::Io,
+            ProtobufError::WireError(e) => match e {
+                WireError::UnexpectedEof => ErrorKind::UnexpectedEof,
+                WireError::UnexpectedWireType(..) => ErrorKind::UnexpectedWireType,
+                WireError::IncorrectTag(..) => ErrorKind::IncorrectTag,
+                WireError::IncorrectVarint => ErrorKind::IncorrectVarint,
+                WireError::Utf8Error => ErrorKind::Utf8,
+                WireError::InvalidEnumValue(..) => ErrorKind::InvalidEnumValue,
+                WireError::OverRecursionLimit => ErrorKind::OverRecursionLimit,
+                WireError::TruncatedMessage => ErrorKind::TruncatedMessage,
+                WireError::LimitOverflow => ErrorKind::LimitOverflow,
+                WireError::LimitIncrease => ErrorKind::LimitIncrease,
+                WireError::MessageTooLarge(..) => ErrorKind::MessageTooLarge,
+                WireError::DecodeLimitExceeded(..) => ErrorKind::DecodeLimitExceeded,
+            },
+            ProtobufError::Utf8(..) => ErrorKind::Utf8,
+            ProtobufError::BufferTooSmall => ErrorKind::BufferTooSmall,
+            ProtobufError::MessageNotInitialized { .. } => ErrorKind::MessageNotInitialized,
+            #[cfg(feature = "std")]
+            ProtobufError::IncompatibleProtobufTypeAndRuntimeType => {
+                ErrorKind::IncompatibleProtobufTypeAndRuntimeType
+            }
+            #[cfg(feature = "std")]
+            ProtobufError::UnknownMethod(..) => ErrorKind::UnknownMethod,
+            #[cfg(feature = "std")]
+            ProtobufError::Reflect(..) => ErrorKind::Reflect,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ErrorImpl {
+    error: ProtobufError,
+    /// Input stream position where decoding failed.
+    position: Option<u64>,
+    /// Field numbers, outermost first.
+    field_path: Vec<u32>,
 }
 
 /// Error type for protobuf operations.
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct Error(pub(crate) Box<ProtobufError>);
+///
+/// Decode errors also carry the input position where the error happened
+/// and the path of fields being decoded.
+///
+/// ```
+/// # use protobuf::Message;
+/// # use protobuf::ErrorKind;
+/// # use protobuf::well_known_types::Timestamp;
+/// // Field 1 (`seconds`) is a truncated varint.
+/// let err = Timestamp::parse_from_bytes(&[8, 0x80]).unwrap_err();
+/// assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+/// assert_eq!(Some(2), err.position());
+/// assert_eq!(&[1], err.field_path());
+/// ```
+pub struct Error(Box<ErrorImpl>);
+
+impl Error {
+    /// Kind of this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.0.error.kind()
+    }
+
+    /// Position in the input stream ([`CodedInputStream::pos`](crate::CodedInputStream::pos))
+    /// where decoding failed.
+    ///
+    /// `None` if this is not a decode error.
+    pub fn position(&self) -> Option<u64> {
+        self.0.position
+    }
+
+    /// Numbers of fields being decoded when the error happened,
+    /// starting from the field of the outermost message.
+    ///
+    /// Map entry key and value are fields `1` and `2`.
+    /// Empty if the error is not in a field or this is not a decode error.
+    pub fn field_path(&self) -> &[u32] {
+        &self.0.field_path
+    }
+
+    /// Paths of required fields which are not set, like `order.items[3].sku`.
+    ///
+    /// Empty unless the error kind is
+    /// [`MessageNotInitialized`](ErrorKind::MessageNotInitialized).
+    pub fn missing_required_fields(&self) -> &[String] {
+        match &self.0.error {
+            ProtobufError::MessageNotInitialized { missing, .. } => missing,
+            _ => &[],
+        }
+    }
+
+    /// Names of fields in [`field_path`](Error::field_path),
+    /// resolved starting from the outermost message.
+    ///
+    /// Unresolvable fields (e. g. unknown fields) are returned as numbers.
+    #[cfg(feature = "std")]
+    pub fn field_path_names(&self, message: &crate::reflect::MessageDescriptor) -> Vec<String> {
+        use crate::reflect::RuntimeFieldType;
+        use crate::reflect::RuntimeTypeBox;
+
+        enum Scope {
+            Message(crate::reflect::MessageDescriptor),
+            /// Map entry with message value type, if any.
+            MapEntry(Option<crate::reflect::MessageDescriptor>),
+            None,
+        }
+
+        let mut scope = Scope::Message(message.clone());
+        let mut names = Vec::new();
+        for &number in self.field_path() {
+            let (name, next) = match &scope {
+                Scope::Message(m) => match m.field_by_number(number) {
+                    Some(f) => {
+                        let next = match f.runtime_field_type() {
+                            RuntimeFieldType::Singular(RuntimeTypeBox::Message(m))
+                            | RuntimeFieldType::Repeated(RuntimeTypeBox::Message(m)) => {
+                                Scope::Message(m)
+                            }
+                            RuntimeFieldType::Map(_, RuntimeTypeBox::Message(m)) => {
+                                Scope::MapEntry(Some(m))
+                            }
+                            RuntimeFieldType::Map(..) => Scope::MapEntry(None),
+                            _ => Scope::None,
+                        };
+                        (f.name().to_owned(), next)
+                    }
+                    None => (number.to_string(), Scope::None),
+                },
+                Scope::MapEntry(_) if number == 1 => ("key".to_owned(), Scope::None),
+                Scope::MapEntry(value) if number == 2 => (
+                    "value".to_owned(),
+                    match value {
+                        Some(m) => Scope::Message(m.clone()),
+                        None => Scope::None,
+                    },
+                ),
+                _ => (number.to_string(), Scope::None),
+            };
+            names.push(name);
+            scope = next;
+        }
+        names
+    }
+
+    /// Record decoding context when the error passes through a message boundary.
+    ///
+    /// `field_number` is the field being decoded in the message (zero if none).
+    #[cold]
+    pub(crate) fn with_field_context(mut self, position: u64, field_number: u32) -> Error {
+        if self.0.position.is_none() {
+            self.0.position = Some(position);
+        }
+        if field_number != 0 {
+            self.0.field_path.insert(0, field_number);
+        }
+        self
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_tuple("Error");
+        d.field(&self.0.error);
+        if let Some(position) = self.0.position {
+            d.field(&position);
+        }
+        if !self.0.field_path.is_empty() {
+            d.field(&self.0.field_path);
+        }
+        d.finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0.error, f)?;
+        if !self.0.field_path.is_empty() {
+            write!(f, " in field ")?;
+            for (i, number) in self.0.field_path.iter().enumerate() {
+                if i != 0 {
+                    write!(f, ".")?;
+                }
+                write!(f, "{}", number)?;
+            }
+        }
+        if let Some(position) = self.0.position {
+            write!(f, " at offset {}", position)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        core::error::Error::source(&self.0.error)
+    }
+}
 
 impl From<ProtobufError> for Error {
     #[cold]
     fn from(e: ProtobufError) -> Self {
-        Self(Box::new(e))
+        Self(Box::new(ErrorImpl {
+            error: e,
+            position: None,
+            field_path: Vec::new(),
+        }))
     }
 }
 
 impl From<WireError> for Error {
     #[cold]
     fn from(e: WireError) -> Self {
-        Self(Box::new(ProtobufError::WireError(e)))
+        ProtobufError::WireError(e).into()
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Error> for io::Error {
     #[cold]
     fn from(err: Error) -> Self {
-        match *err.0 {
-            ProtobufError::IoError(e) => e,
-            ProtobufError::WireError(e) => {
-                io::Error::new(io::ErrorKind::InvalidData, ProtobufError::WireError(e))
-            }
-            ProtobufError::MessageNotInitialized(message) => io::Error::new(
-                io::ErrorKind::InvalidInput,
-                ProtobufError::MessageNotInitialized(message),
+        let kind = match &err.0.error {
+            ProtobufError::IoError(..) => None,
+            ProtobufError::WireError(..) => Some(io::ErrorKind::InvalidData),
+            ProtobufError::MessageNotInitialized { .. } => Some(io::ErrorKind::InvalidInput),
+            _ => Some(io::ErrorKind::Other),
+        };
+        match (kind, err.0.error) {
+            (None, ProtobufError::IoError(e)) => e,
+            (kind, error) => io::Error::new(
+                kind.unwrap_or(io::ErrorKind::Other),
+                Error(Box::new(ErrorImpl { error, ..*err.0 })),
             ),
-            e => io::Error::new(io::ErrorKind::Other, Box::new(e)),
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl From<ReflectError> for Error {
+    #[cold]
+    fn from(e: ReflectError) -> Self {
+        ProtobufError::Reflect(e).into()
+    }
+}
+
+#[cfg(feature = "std")]
 impl From<io::Error> for Error {
     #[cold]
     fn from(err: io::Error) -> Self {
-        Error(Box::new(ProtobufError::IoError(err)))
+        ProtobufError::IoError(err).into()
     }
 }
@@ -2,12 +2,25 @@
 //!
 //! Extensions are [described in the official protobuf documentation][exts].
 //!
+//! Extension values are stored in message [`UnknownFields`](crate::UnknownFields),
+//! and decoded on access. Extensions can be found at runtime
+//! with [`ExtensionRegistry`](crate::reflect::ExtensionRegistry).
+//!
 //! [exts]: https://developers.google.com/protocol-buffers/docs/proto#extensions
 
+use std::fmt;
 use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
 
+use crate::error::WireError;
 use crate::reflect::types::ProtobufType;
+use crate::unknown::UnknownValueRef;
+use crate::wire_format::WireType;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
 use crate::Message;
+use crate::UnknownFields;
 
 /// Optional ext field
 ///
@@ -42,12 +55,199 @@ impl<M: Message, T: ProtobufType> ExtFieldOptional<M, T> {
             .get(self.field_number)
             .and_then(T::get_from_unknown)
     }
+
+    /// Check if extension is set in a message.
+    pub fn has(&self, m: &M) -> bool {
+        self.get(m).is_some()
+    }
+
+    /// Set extension value replacing the previous value.
+    pub fn set(&self, m: &mut M, value: T::ProtobufValue) {
+        write_values::<T>(
+            self.field_number,
+            std::slice::from_ref(&value),
+            m.mut_unknown_fields(),
+        );
+    }
+
+    /// Remove extension value from a message.
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+
+    /// Get a mutable reference to the value, setting it to default if unset.
+    ///
+    /// Modified value is stored back in the message when returned object is dropped.
+    pub fn mut_or_default<'a>(&self, m: &'a mut M) -> ExtValueMut<'a, M, T::ProtobufValue> {
+        let value = self.get(m).unwrap_or_default();
+        ExtValueMut {
+            message: m,
+            field_number: self.field_number,
+            value,
+            write: |field_number, value, unknown_fields| {
+                write_values::<T>(field_number, std::slice::from_ref(value), unknown_fields)
+            },
+        }
+    }
 }
 
 impl<M: Message, T: ProtobufType> ExtFieldRepeated<M, T> {
-    /// Get a copy of value from a message (**not implemented**).
-    pub fn get(&self, _m: &M) -> Vec<T::ProtobufValue> {
-        // TODO
-        unimplemented!()
+    /// Get a copy of values from a message.
+    ///
+    /// Both packed and non-packed values are decoded.
+    /// Values which cannot be decoded are skipped.
+    pub fn get(&self, m: &M) -> Vec<T::ProtobufValue> {
+        match m.unknown_fields().get(self.field_number) {
+            Some(values) => values
+                .iter()
+                .flat_map(|value| read_values::<T>(value).unwrap_or_default())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Set extension values replacing the previous values.
+    pub fn set(&self, m: &mut M, values: Vec<T::ProtobufValue>) {
+        write_values::<T>(self.field_number, &values, m.mut_unknown_fields());
+    }
+
+    /// Append a value to the extension.
+    pub fn push(&self, m: &mut M, value: T::ProtobufValue) {
+        let mut values = self.mut_vec(m);
+        values.push(value);
+    }
+
+    /// Remove all extension values from a message.
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+
+    /// Get a mutable reference to the values.
+    ///
+    /// Modified values are stored back in the message when returned object is dropped.
+    pub fn mut_vec<'a>(&self, m: &'a mut M) -> ExtValueMut<'a, M, Vec<T::ProtobufValue>> {
+        let value = self.get(m);
+        ExtValueMut {
+            message: m,
+            field_number: self.field_number,
+            value,
+            write: |field_number, values, unknown_fields| {
+                write_values::<T>(field_number, values, unknown_fields)
+            },
+        }
+    }
+}
+
+/// Mutable reference to extension value.
+///
+/// Returned by [`ExtFieldOptional::mut_or_default`] and [`ExtFieldRepeated::mut_vec`].
+/// Value is a decoded copy, which is written back to the message on drop.
+pub struct ExtValueMut<'a, M: Message, V> {
+    message: &'a mut M,
+    field_number: u32,
+    value: V,
+    write: fn(u32, &V, &mut UnknownFields),
+}
+
+impl<'a, M: Message, V> Deref for ExtValueMut<'a, M, V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<'a, M: Message, V> DerefMut for ExtValueMut<'a, M, V> {
+    fn deref_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+impl<'a, M: Message, V: fmt::Debug> fmt::Debug for ExtValueMut<'a, M, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<'a, M: Message, V> Drop for ExtValueMut<'a, M, V> {
+    fn drop(&mut self) {
+        (self.write)(
+            self.field_number,
+            &self.value,
+            self.message.mut_unknown_fields(),
+        );
+    }
+}
+
+/// Replace extension values in unknown fields.
+fn write_values<T: ProtobufType>(
+    field_number: u32,
+    values: &[T::ProtobufValue],
+    unknown_fields: &mut UnknownFields,
+) {
+    unknown_fields.remove(field_number);
+    // `ProtobufType` is never a group, and writing to a `Vec` does not fail.
+    write_to_unknown_fields(unknown_fields, |os| {
+        for value in values {
+            T::compute_size(value);
+            T::write_with_cached_size(field_number, value, os)?;
+        }
+        Ok(())
+    })
+    .expect("failed to serialize extension value");
+}
+
+/// Serialize fields with `write` and add them to unknown fields.
+///
+/// Groups cannot be stored in unknown fields, so writing a group is an error,
+/// in which case unknown fields are not modified.
+pub(crate) fn write_to_unknown_fields(
+    unknown_fields: &mut UnknownFields,
+    write: impl FnOnce(&mut CodedOutputStream) -> crate::Result<()>,
+) -> crate::Result<()> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        write(&mut os)?;
+        os.flush()?;
+    }
+
+    let mut values = Vec::new();
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        let (field_number, wire_type) = is.read_tag_unpack()?;
+        if let WireType::StartGroup | WireType::EndGroup = wire_type {
+            return Err(WireError::UnexpectedWireType(wire_type).into());
+        }
+        values.push((field_number, is.read_unknown(wire_type)?));
+    }
+    for (field_number, value) in values {
+        unknown_fields.add_value(field_number, value);
+    }
+    Ok(())
+}
+
+/// Decode single unknown value which may be packed.
+fn read_values<T: ProtobufType>(value: UnknownValueRef) -> crate::Result<Vec<T::ProtobufValue>> {
+    let wire_type = value.wire_type();
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.write_unknown_no_tag(value)?;
+        os.flush()?;
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    let mut r = Vec::new();
+    if wire_type == T::WIRE_TYPE {
+        r.push(T::read(&mut is)?);
+    } else if wire_type == WireType::LengthDelimited {
+        let len = is.read_raw_varint64()?;
+        let old_limit = is.push_limit(len)?;
+        while !is.eof()? {
+            r.push(T::read(&mut is)?);
+        }
+        is.pop_limit(old_limit);
     }
+    Ok(r)
 }
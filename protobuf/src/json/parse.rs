@@ -5,6 +5,7 @@ use super::base64;
 use super::float;
 use super::rfc_3339;
 use crate::json::base64::FromBase64Error;
+use crate::json::print::is_well_known_type_with_special_json;
 use crate::json::well_known_wrapper::WellKnownWrapper;
 use crate::message_dyn::MessageDyn;
 use crate::message_full::MessageFull;
@@ -15,6 +16,7 @@ use crate::reflect::MessageDescriptor;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::RuntimeFieldType;
 use crate::reflect::RuntimeTypeBox;
+use crate::reflect::TypeRegistry;
 use crate::text_format::lexer::JsonNumberLit;
 use crate::text_format::lexer::Lexer;
 use crate::text_format::lexer::LexerError;
@@ -73,8 +75,10 @@ enum ParseErrorWithoutLocInner {
     ExpectingNumber,
     #[error("Unexpected token")]
     UnexpectedToken,
-    #[error("Any parsing is not implemented")]
-    AnyParsingIsNotImplemented,
+    #[error("Any object must contain `@type` field")]
+    AnyTypeUrlMissing,
+    #[error("Type not found in type registry: `{}`", .0)]
+    AnyTypeNotFound(String),
     #[error("Message not initialized")]
     MessageNotInitialized,
 }
@@ -128,7 +132,7 @@ type ParseResult<A> = Result<A, ParseError>;
 #[derive(Clone)]
 struct Parser<'a> {
     tokenizer: Tokenizer<'a>,
-    parse_options: ParseOptions,
+    parse_options: &'a ParseOptions,
 }
 
 trait FromJsonNumber: PartialEq + Sized {
@@ -677,27 +681,30 @@ impl<'a> Parser<'a> {
             first = false;
 
             let field_name = self.read_string()?;
-            // Proto3 JSON parsers are required to accept both
-            // the converted `lowerCamelCase` name and the proto field name.
-            match descriptor.field_by_name_or_json_name(&field_name) {
-                Some(field) => {
-                    self.tokenizer.next_symbol_expect_eq(':', "object")?;
-                    self.merge_field(message, &field)?;
-                }
-                None if self.parse_options.ignore_unknown_fields => {
-                    self.tokenizer.next_symbol_expect_eq(':', "object")?;
-                    self.skip_json_value()?;
-                }
-                None => {
-                    return Err(ParseErrorWithoutLoc(
-                        ParseErrorWithoutLocInner::UnknownFieldName(field_name),
-                    ))
-                }
-            };
+            self.tokenizer.next_symbol_expect_eq(':', "object")?;
+            self.merge_field_by_name(message, &descriptor, field_name)?;
         }
         Ok(())
     }
 
+    /// Merge field value, field name and colon are already consumed.
+    fn merge_field_by_name(
+        &mut self,
+        message: &mut dyn MessageDyn,
+        descriptor: &MessageDescriptor,
+        field_name: String,
+    ) -> ParseResultWithoutLoc<()> {
+        // Proto3 JSON parsers are required to accept both
+        // the converted `lowerCamelCase` name and the proto field name.
+        match descriptor.field_by_name_or_json_name(&field_name) {
+            Some(field) => self.merge_field(message, &field),
+            None if self.parse_options.ignore_unknown_fields => self.skip_json_value(),
+            None => Err(ParseErrorWithoutLoc(
+                ParseErrorWithoutLocInner::UnknownFieldName(field_name),
+            )),
+        }
+    }
+
     fn merge_wk_duration(&mut self, duration: &mut Duration) -> ParseResultWithoutLoc<()> {
         let s = self.read_string()?;
         let mut lexer = Lexer::new(&s, ParserLanguage::Json);
@@ -783,7 +790,7 @@ impl<'a> Parser<'a> {
     fn merge_wk_field_mask(&mut self, field_mask: &mut FieldMask) -> ParseResultWithoutLoc<()> {
         let s = self.read_string()?;
         if !s.is_empty() {
-            field_mask.paths = s.split(',').map(|s| s.to_owned()).collect();
+            field_mask.paths = FieldMask::from_json_string(&s).paths;
         }
         Ok(())
     }
@@ -823,10 +830,88 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn merge_wk_any(&mut self, _value: &mut Any) -> ParseResultWithoutLoc<()> {
-        Err(ParseErrorWithoutLoc(
-            ParseErrorWithoutLocInner::AnyParsingIsNotImplemented,
-        ))
+    /// Find `@type` field of JSON object, skipping other fields.
+    fn read_any_type_url(&mut self) -> ParseResultWithoutLoc<Option<String>> {
+        self.tokenizer.next_symbol_expect_eq('{', "object")?;
+        let mut first = true;
+        while !self.tokenizer.next_symbol_if_eq('}')? {
+            if !first {
+                self.tokenizer.next_symbol_expect_eq(',', "object")?;
+            }
+            first = false;
+
+            let field_name = self.read_string()?;
+            self.tokenizer.next_symbol_expect_eq(':', "object")?;
+            if field_name == "@type" {
+                return Ok(Some(self.read_string()?));
+            }
+            self.skip_json_value()?;
+        }
+        Ok(None)
+    }
+
+    fn merge_wk_any(&mut self, any: &mut Any) -> ParseResultWithoutLoc<()> {
+        // `@type` is not required to be the first field,
+        // so look it up using a copy of the parser.
+        let type_url = match self.clone().read_any_type_url()? {
+            Some(type_url) => type_url,
+            None => {
+                // Empty object is a default `Any`.
+                self.tokenizer.next_symbol_expect_eq('{', "object")?;
+                self.tokenizer
+                    .next_symbol_expect_eq('}', "object")
+                    .map_err(|_| {
+                        ParseErrorWithoutLoc(ParseErrorWithoutLocInner::AnyTypeUrlMissing)
+                    })?;
+                return Ok(());
+            }
+        };
+
+        let descriptor = match self
+            .parse_options
+            .type_registry
+            .find_message_by_type_url(&type_url)
+        {
+            Some(descriptor) => descriptor,
+            None => {
+                return Err(ParseErrorWithoutLoc(
+                    ParseErrorWithoutLocInner::AnyTypeNotFound(type_url),
+                ))
+            }
+        };
+        let mut message = descriptor.new_instance();
+        let special_json = is_well_known_type_with_special_json(&descriptor);
+
+        self.tokenizer.next_symbol_expect_eq('{', "object")?;
+        let mut first = true;
+        while !self.tokenizer.next_symbol_if_eq('}')? {
+            if !first {
+                self.tokenizer.next_symbol_expect_eq(',', "object")?;
+            }
+            first = false;
+
+            let field_name = self.read_string()?;
+            self.tokenizer.next_symbol_expect_eq(':', "object")?;
+            if field_name == "@type" {
+                self.read_string()?;
+            } else if !special_json {
+                self.merge_field_by_name(&mut *message, &descriptor, field_name)?;
+            } else if field_name == "value" {
+                self.merge_inner(&mut *message)?;
+            } else if self.parse_options.ignore_unknown_fields {
+                self.skip_json_value()?;
+            } else {
+                return Err(ParseErrorWithoutLoc(
+                    ParseErrorWithoutLocInner::UnknownFieldName(field_name),
+                ));
+            }
+        }
+
+        any.value = message
+            .write_to_bytes_dyn()
+            .map_err(|_| ParseErrorWithoutLoc(ParseErrorWithoutLocInner::MessageNotInitialized))?;
+        any.type_url = type_url;
+        Ok(())
     }
 
     fn read_wk_value(&mut self) -> ParseResultWithoutLoc<Value> {
@@ -864,6 +949,10 @@ pub struct ParseOptions {
     /// When `true` fields with unknown names are ignored.
     /// When `false` parser returns an error on unknown field.
     pub ignore_unknown_fields: bool,
+    /// Types used to parse contents of `google.protobuf.Any`.
+    ///
+    /// Parsing `Any` with a type not found in the registry is an error.
+    pub type_registry: TypeRegistry,
     /// Prevent initializing `ParseOptions` enumerating all field.
     pub _future_options: (),
 }
@@ -876,7 +965,7 @@ pub fn merge_from_str_with_options(
 ) -> ParseResult<()> {
     let mut parser = Parser {
         tokenizer: Tokenizer::new(json, ParserLanguage::Json),
-        parse_options: parse_options.clone(),
+        parse_options,
     };
     parser.merge(message)
 }
@@ -8,6 +8,7 @@ use crate::json::well_known_wrapper::WellKnownWrapper;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::EnumValueDescriptor;
+use crate::reflect::MessageDescriptor;
 use crate::reflect::MessageRef;
 use crate::reflect::ReflectFieldRef;
 use crate::reflect::ReflectMapRef;
@@ -15,6 +16,7 @@ use crate::reflect::ReflectRepeatedRef;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeFieldType;
 use crate::reflect::RuntimeTypeBox;
+use crate::reflect::TypeRegistry;
 use crate::well_known_types::value;
 use crate::well_known_types::Any;
 use crate::well_known_types::BoolValue;
@@ -37,7 +39,8 @@ use crate::well_known_types::Value;
 #[derive(Debug)]
 enum PrintErrorInner {
     Fmt(fmt::Error),
-    AnyPrintingIsNotImplemented,
+    AnyTypeNotFound(String),
+    AnyValue(crate::Error),
     TimestampNegativeNanos,
 }
 
@@ -53,9 +56,9 @@ impl From<fmt::Error> for PrintError {
 
 pub type PrintResult<T> = Result<T, PrintError>;
 
-struct Printer {
+struct Printer<'a> {
     buf: String,
-    print_options: PrintOptions,
+    print_options: &'a PrintOptions,
 }
 
 trait PrintableToJson {
@@ -233,13 +236,13 @@ impl PrintableToJson for Timestamp {
 
 impl PrintableToJson for FieldMask {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
-        w.print_printable(&self.paths.join(","))
+        w.print_printable(&self.to_json_string())
     }
 }
 
 impl PrintableToJson for Any {
-    fn print_to_json(&self, _w: &mut Printer) -> PrintResult<()> {
-        Err(PrintError(PrintErrorInner::AnyPrintingIsNotImplemented))
+    fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
+        w.print_wk_any(self)
     }
 }
 
@@ -337,7 +340,7 @@ impl<'a, O: ObjectKey> ObjectKey for &'a O {
     }
 }
 
-impl Printer {
+impl<'a> Printer<'a> {
     fn print_comma_but_first(&mut self, first: &mut bool) -> fmt::Result {
         if *first {
             *first = false;
@@ -460,10 +463,20 @@ impl Printer {
     }
 
     fn print_regular_message(&mut self, message: &MessageRef) -> Result<(), PrintError> {
-        let descriptor = message.descriptor_dyn();
-
         write!(self.buf, "{{")?;
         let mut first = true;
+        self.print_regular_message_fields(message, &mut first)?;
+        write!(self.buf, "}}")?;
+        Ok(())
+    }
+
+    fn print_regular_message_fields(
+        &mut self,
+        message: &MessageRef,
+        first: &mut bool,
+    ) -> PrintResult<()> {
+        let descriptor = message.descriptor_dyn();
+
         for field in descriptor.fields() {
             let json_field_name = if self.print_options.proto_field_name {
                 field.name()
@@ -488,33 +501,60 @@ impl Printer {
 
                         if !is_message && !is_oneof {
                             let v = field.get_singular_field_or_default(&**message);
-                            self.print_comma_but_first(&mut first)?;
+                            self.print_comma_but_first(first)?;
                             write!(self.buf, "\"{}\": ", json_field_name)?;
                             self.print_printable(&v)?;
                         }
                     }
                 }
                 ReflectFieldRef::Optional(Some(v)) => {
-                    self.print_comma_but_first(&mut first)?;
+                    self.print_comma_but_first(first)?;
                     write!(self.buf, "\"{}\": ", json_field_name)?;
                     self.print_printable(&v)?;
                 }
                 ReflectFieldRef::Repeated(v) => {
                     if !v.is_empty() || self.print_options.always_output_default_values {
-                        self.print_comma_but_first(&mut first)?;
+                        self.print_comma_but_first(first)?;
                         write!(self.buf, "\"{}\": ", json_field_name)?;
                         self.print_repeated(&v)?;
                     }
                 }
                 ReflectFieldRef::Map(v) => {
                     if !v.is_empty() || self.print_options.always_output_default_values {
-                        self.print_comma_but_first(&mut first)?;
+                        self.print_comma_but_first(first)?;
                         write!(self.buf, "\"{}\": ", json_field_name)?;
                         self.print_map(&v)?;
                     }
                 }
             }
         }
+        Ok(())
+    }
+
+    fn print_wk_any(&mut self, any: &Any) -> PrintResult<()> {
+        if any.type_url.is_empty() && any.value.is_empty() {
+            return Ok(write!(self.buf, "{{}}")?);
+        }
+
+        let descriptor = self
+            .print_options
+            .type_registry
+            .find_message_by_type_url(&any.type_url)
+            .ok_or_else(|| PrintError(PrintErrorInner::AnyTypeNotFound(any.type_url.clone())))?;
+        let message = descriptor
+            .parse_from_bytes(&any.value)
+            .map_err(|e| PrintError(PrintErrorInner::AnyValue(e)))?;
+        let message = MessageRef::from(&*message);
+
+        write!(self.buf, "{{\"@type\": ")?;
+        self.print_printable(&any.type_url)?;
+        if is_well_known_type_with_special_json(&descriptor) {
+            write!(self.buf, ", \"value\": ")?;
+            self.print_message(&message)?;
+        } else {
+            let mut first = false;
+            self.print_regular_message_fields(&message, &mut first)?;
+        }
         write!(self.buf, "}}")?;
         Ok(())
     }
@@ -554,6 +594,10 @@ pub struct PrintOptions {
     pub proto_field_name: bool,
     /// Output field default values.
     pub always_output_default_values: bool,
+    /// Types used to print contents of `google.protobuf.Any`.
+    ///
+    /// Printing `Any` with a type not found in the registry is an error.
+    pub type_registry: TypeRegistry,
     /// Prevent initializing `PrintOptions` enumerating all field.
     pub _future_options: (),
 }
@@ -565,7 +609,7 @@ pub fn print_to_string_with_options(
 ) -> PrintResult<String> {
     let mut printer = Printer {
         buf: String::new(),
-        print_options: print_options.clone(),
+        print_options,
     };
     printer.print_message(&MessageRef::from(message))?;
     Ok(printer.buf)
@@ -575,3 +619,27 @@ pub fn print_to_string_with_options(
 pub fn print_to_string(message: &dyn MessageDyn) -> PrintResult<String> {
     print_to_string_with_options(message, &PrintOptions::default())
 }
+
+/// Well-known types which have special JSON representation,
+/// and are wrapped in `"value"` field when stored in `Any`.
+pub(crate) fn is_well_known_type_with_special_json(descriptor: &MessageDescriptor) -> bool {
+    match descriptor.full_name() {
+        "google.protobuf.Any"
+        | "google.protobuf.Duration"
+        | "google.protobuf.Timestamp"
+        | "google.protobuf.FieldMask"
+        | "google.protobuf.Value"
+        | "google.protobuf.ListValue"
+        | "google.protobuf.Struct"
+        | "google.protobuf.DoubleValue"
+        | "google.protobuf.FloatValue"
+        | "google.protobuf.Int64Value"
+        | "google.protobuf.UInt64Value"
+        | "google.protobuf.Int32Value"
+        | "google.protobuf.UInt32Value"
+        | "google.protobuf.BoolValue"
+        | "google.protobuf.StringValue"
+        | "google.protobuf.BytesValue" => true,
+        _ => false,
+    }
+}
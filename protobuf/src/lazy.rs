@@ -1,24 +1,46 @@
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+#[cfg(not(feature = "std"))]
+use once_cell::race::OnceBox;
+#[cfg(feature = "std")]
 use once_cell::sync::OnceCell;
 
 /// Lazily initialized static variable.
 ///
 /// Used in generated code.
 ///
-/// Currently a wrapper around `once_cell`s `OnceCell`.
+/// Currently a wrapper around `once_cell`s `OnceCell`,
+/// or `OnceBox` without `std` feature. Without `std`
+/// `f` may be called several times when accessed concurrently,
+/// but only one value is stored.
 pub struct Lazy<T> {
+    #[cfg(feature = "std")]
     once_cell: OnceCell<T>,
+    #[cfg(not(feature = "std"))]
+    once_cell: OnceBox<T>,
 }
 
 impl<T> Lazy<T> {
     /// Uninitialized state.
     pub const fn new() -> Lazy<T> {
         Lazy {
+            #[cfg(feature = "std")]
             once_cell: OnceCell::new(),
+            #[cfg(not(feature = "std"))]
+            once_cell: OnceBox::new(),
         }
     }
 
     /// Lazily initialize the value.
+    #[cfg(feature = "std")]
     pub fn get(&self, f: impl FnOnce() -> T) -> &T {
         self.once_cell.get_or_init(f)
     }
+
+    /// Lazily initialize the value.
+    #[cfg(not(feature = "std"))]
+    pub fn get(&self, f: impl FnOnce() -> T) -> &T {
+        self.once_cell.get_or_init(|| Box::new(f()))
+    }
 }
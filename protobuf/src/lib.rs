@@ -15,7 +15,14 @@
 //!
 //! ## Features
 //!
-//! This crate has one feature, which is `with-bytes`.
+//! `std` feature is enabled by default. When disabled, the crate is `no_std`
+//! and only requires `alloc`. Without `std`:
+//! * messages can be parsed only from slices (`CodedInputStream::from_bytes`)
+//!   and written only to slices or vectors (`CodedOutputStream::bytes`, `CodedOutputStream::vec`)
+//! * reflection, dynamic messages, text format, JSON and well-known types are not available,
+//!   so only code generated with `lite_runtime` option can be used
+//! * generated code must store map fields in `BTreeMap` instead of `HashMap`
+//!   (`btree_map` codegen option)
 //!
 //! `with-bytes` enables `protobuf` crate support for
 //! [`bytes` crate](https://github.com/tokio-rs/bytes):
@@ -39,53 +46,84 @@
 //!   but can be used for mechanical processing of `.proto` files.
 
 #![deny(missing_docs)]
-#![deny(rustdoc::broken_intra_doc_links)]
+// Docs link to `std`-only items.
+#![cfg_attr(feature = "std", deny(rustdoc::broken_intra_doc_links))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub use crate::coded_input_stream::CodedInputStream;
 pub use crate::coded_output_stream::CodedOutputStream;
+#[cfg(feature = "std")]
 pub use crate::enum_full::EnumFull;
 pub use crate::enum_or_unknown::EnumOrUnknown;
 pub use crate::enums::Enum;
 pub use crate::message::Message;
+#[cfg(feature = "std")]
 pub use crate::message_dyn::MessageDyn;
 pub use crate::message_field::MessageField;
+#[cfg(feature = "std")]
 pub use crate::message_full::MessageFull;
 pub use crate::oneof::Oneof;
+pub use crate::parse_options::ParseOptions;
+pub use crate::reverse_output_stream::ReverseOutputStream;
 pub use crate::unknown::UnknownFields;
 pub use crate::unknown::UnknownFieldsIter;
 pub use crate::unknown::UnknownValue;
 pub use crate::unknown::UnknownValueRef;
 pub use crate::unknown::UnknownValues;
 pub use crate::unknown::UnknownValuesIter;
+#[cfg(feature = "std")]
+pub use crate::well_known_types_util::FieldMaskMergeOptions;
 pub(crate) mod wire_format;
 #[cfg(feature = "bytes")]
 pub use crate::chars::Chars;
 pub use crate::error::Error;
+pub use crate::error::ErrorKind;
 pub use crate::error::Result;
 
 // generated
+#[cfg(feature = "std")]
 pub mod descriptor;
+#[cfg(feature = "std")]
 pub mod plugin;
+#[cfg(feature = "std")]
 pub mod rustproto;
 
 mod coded_input_stream;
 mod coded_output_stream;
+#[cfg(feature = "std")]
 mod enum_full;
 mod enum_or_unknown;
 mod enums;
 mod error;
+#[cfg(feature = "std")]
 pub mod ext;
+#[cfg(feature = "std")]
 pub mod json;
 mod lazy;
 mod message;
+#[cfg(feature = "std")]
 mod message_dyn;
 mod message_field;
+#[cfg(feature = "std")]
 mod message_full;
 mod oneof;
+mod parse_options;
 pub mod reflect;
+mod reverse_output_stream;
 pub mod rt;
+#[cfg(feature = "std")]
+pub mod service;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
 pub mod text_format;
+#[cfg(feature = "std")]
+pub mod view;
+#[cfg(feature = "std")]
 pub mod well_known_types;
+#[cfg(feature = "std")]
 mod well_known_types_util;
 
 // used by test
@@ -102,6 +140,7 @@ mod zigzag;
 mod misc;
 
 mod buf_read_iter;
+#[cfg(feature = "std")]
 mod buf_read_or_reader;
 
 #[cfg(feature = "bytes")]
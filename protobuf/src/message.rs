@@ -1,11 +1,19 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::io::Read;
+#[cfg(feature = "std")]
 use std::io::Write;
 
+#[cfg(feature = "std")]
 use crate::coded_output_stream::WithCodedOutputStream;
 use crate::error::ProtobufError;
+use crate::reverse_output_stream::ReverseOutputStream;
 use crate::wire_format::check_message_size;
 use crate::CodedInputStream;
 use crate::CodedOutputStream;
+use crate::ParseOptions;
 use crate::UnknownFields;
 
 /// Trait which is implemented by all generated message.
@@ -21,17 +29,68 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// but when lite runtime is requested, this field can be used.
     const NAME: &'static str;
 
+    /// Serialize to bytes with [`write_reverse`](Message::write_reverse) in one pass.
+    ///
+    /// Set in messages generated without `cached_size` field,
+    /// other messages compute sizes first and write forward.
+    #[doc(hidden)]
+    const WRITE_REVERSE: bool = false;
+
     /// True iff all required fields are initialized.
     /// Always returns `true` for protobuf 3.
     fn is_initialized(&self) -> bool;
 
+    /// Append paths of required fields which are not set in this message
+    /// and nested messages to `missing`, each path prefixed with `prefix`.
+    ///
+    /// Implemented by generated code for messages which have required
+    /// or message fields. Use [`missing_required_fields`](Message::missing_required_fields)
+    /// instead of calling this function directly.
+    #[doc(hidden)]
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut Vec<String>) {
+        let _ = (prefix, missing);
+    }
+
+    /// Paths of all required fields which are not set, like `order.items[3].sku`.
+    ///
+    /// Empty iff [`is_initialized`](Message::is_initialized) is `true`.
+    fn missing_required_fields(&self) -> Vec<String> {
+        let mut missing = Vec::new();
+        self.collect_missing_required_fields("", &mut missing);
+        missing
+    }
+
     /// Update this message object with fields read from given stream.
     fn merge_from(&mut self, is: &mut CodedInputStream) -> crate::Result<()>;
 
+    /// Merge fields of `other` into this message.
+    ///
+    /// Set singular fields overwrite fields of this message, repeated fields
+    /// are appended, map entries are inserted, message fields are merged
+    /// recursively, and oneof fields replace the other set oneof field.
+    /// Unknown fields are appended.
+    ///
+    /// The default implementation serializes `other` and merges the result with
+    /// [`merge_from_bytes`](Message::merge_from_bytes), so it inherits parser
+    /// behavior for singular message fields, which are replaced rather than merged.
+    /// Generated code overrides it with a direct implementation.
+    fn merge_from_message(&mut self, other: &Self) {
+        let mut bytes = Vec::new();
+        other.compute_size();
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        other
+            .write_to_with_cached_sizes(&mut os)
+            .expect("serialize message");
+        os.flush().expect("serialize message");
+        drop(os);
+        self.merge_from_bytes(&bytes)
+            .expect("merge serialized message");
+    }
+
     /// Parse message from stream.
     fn parse_from(is: &mut CodedInputStream) -> crate::Result<Self> {
         let mut r: Self = Message::new();
-        r.merge_from(is)?;
+        is.merge_nested(|is| r.merge_from(is))?;
         r.check_initialized()?;
         Ok(r)
     }
@@ -52,6 +111,20 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// Note if message size exceeds u32, the cached size is stored truncated.
     fn cached_size(&self) -> u32;
 
+    /// Write message to the stream which is filled from the end,
+    /// so fields must be written in reverse order.
+    ///
+    /// Unlike [`write_to_with_cached_sizes`](Message::write_to_with_cached_sizes),
+    /// this function does not need sizes computed and cached,
+    /// and serializes the message in one pass.
+    ///
+    /// Generated code overrides this function, the default implementation
+    /// computes message size and writes the message forward.
+    fn write_reverse(&self, os: &mut ReverseOutputStream) -> crate::Result<()> {
+        let size = check_message_size(self.compute_size())?;
+        os.write_forward(size as usize, |os| self.write_to_with_cached_sizes(os))
+    }
+
     /// Write the message to the stream.
     ///
     /// Results in error if message is not fully initialized.
@@ -59,7 +132,9 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
         self.check_initialized()?;
 
         // cache sizes
-        self.compute_size();
+        if !Self::WRITE_REVERSE {
+            self.compute_size();
+        }
         // TODO: reserve additional
         self.write_to_with_cached_sizes(os)?;
 
@@ -92,6 +167,15 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// Write the message to the vec, prepend the message with message length
     /// encoded as varint.
     fn write_length_delimited_to_vec(&self, vec: &mut Vec<u8>) -> crate::Result<()> {
+        if Self::WRITE_REVERSE {
+            return ReverseOutputStream::append_to_vec(vec, |os| {
+                self.write_reverse(os)?;
+                let size = check_message_size(os.len() as u64)?;
+                os.write_raw_varint32(size);
+                Ok(())
+            });
+        }
+
         let mut os = CodedOutputStream::vec(vec);
         self.write_length_delimited_to(&mut os)?;
         os.flush()?;
@@ -101,11 +185,12 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// Update this message object with fields read from given stream.
     fn merge_from_bytes(&mut self, bytes: &[u8]) -> crate::Result<()> {
         let mut is = CodedInputStream::from_bytes(bytes);
-        self.merge_from(&mut is)
+        is.merge_nested(|is| self.merge_from(is))
     }
 
     /// Parse message from reader.
     /// Parse stops on EOF or when error encountered.
+    #[cfg(feature = "std")]
     fn parse_from_reader(reader: &mut dyn Read) -> crate::Result<Self>
     where
         Self: Sized,
@@ -127,6 +212,34 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
         Ok(r)
     }
 
+    /// Parse message from reader with decode limits.
+    #[cfg(feature = "std")]
+    fn parse_from_reader_with_options(
+        reader: &mut dyn Read,
+        options: &ParseOptions,
+    ) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut is = CodedInputStream::new(reader);
+        is.set_parse_options(options);
+        let r = Message::parse_from(&mut is)?;
+        is.check_eof()?;
+        Ok(r)
+    }
+
+    /// Parse message from byte array with decode limits.
+    fn parse_from_bytes_with_options(bytes: &[u8], options: &ParseOptions) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        is.set_parse_options(options);
+        let r = Message::parse_from(&mut is)?;
+        is.check_eof()?;
+        Ok(r)
+    }
+
     /// Parse message from `Bytes` object.
     /// Resulting message may share references to the passed bytes object.
     #[cfg(feature = "bytes")]
@@ -144,7 +257,9 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// Resulting message may share references to the passed bytes object.
     /// Note that `Iterator<Item=Bytes>` is not compatible with `Iterator<Item=&Bytes>`.
     #[cfg(feature = "bytes")]
-    fn parse_from_tokio_bytes_iter(bytes_iter: &mut dyn Iterator<Item=&bytes::Bytes>) -> crate::Result<Self>
+    fn parse_from_tokio_bytes_iter(
+        bytes_iter: &mut dyn Iterator<Item = &bytes::Bytes>,
+    ) -> crate::Result<Self>
     where
         Self: Sized,
     {
@@ -159,20 +274,33 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// Check if all required fields of this object are initialized.
     fn check_initialized(&self) -> crate::Result<()> {
         if !self.is_initialized() {
-            Err(ProtobufError::MessageNotInitialized(Self::NAME.to_owned()).into())
+            Err(ProtobufError::MessageNotInitialized {
+                message: Self::NAME.to_owned(),
+                missing: self.missing_required_fields(),
+            }
+            .into())
         } else {
             Ok(())
         }
     }
 
     /// Write the message to the writer.
+    #[cfg(feature = "std")]
     fn write_to_writer(&self, w: &mut dyn Write) -> crate::Result<()> {
         w.with_coded_output_stream(|os| self.write_to(os))
     }
 
     /// Write the message to bytes vec.
     fn write_to_vec(&self, v: &mut Vec<u8>) -> crate::Result<()> {
-        v.with_coded_output_stream(|os| self.write_to(os))
+        if Self::WRITE_REVERSE {
+            self.check_initialized()?;
+            return ReverseOutputStream::append_to_vec(v, |os| self.write_reverse(os));
+        }
+
+        let mut os = CodedOutputStream::vec(v);
+        self.write_to(&mut os)?;
+        os.flush()?;
+        Ok(())
     }
 
     /// Write the message to bytes vec.
@@ -182,10 +310,32 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     fn write_to_bytes(&self) -> crate::Result<Vec<u8>> {
         self.check_initialized()?;
 
+        if Self::WRITE_REVERSE {
+            let mut os = ReverseOutputStream::new();
+            self.write_reverse(&mut os)?;
+            return Ok(os.into_vec());
+        }
+
+        let size = self.compute_size() as usize;
+        let mut v = Vec::with_capacity(size);
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            self.write_to_with_cached_sizes(&mut os)?;
+            os.flush()?;
+        }
+        Ok(v)
+    }
+
+    /// Write the message to bytes vec with deterministic serialization,
+    /// see [`CodedOutputStream::set_deterministic`].
+    fn write_to_bytes_deterministic(&self) -> crate::Result<Vec<u8>> {
+        self.check_initialized()?;
+
         let size = self.compute_size() as usize;
         let mut v = Vec::with_capacity(size);
         {
             let mut os = CodedOutputStream::vec(&mut v);
+            os.set_deterministic(true);
             self.write_to_with_cached_sizes(&mut os)?;
             os.flush()?;
         }
@@ -194,6 +344,7 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
 
     /// Write the message to the writer, prepend the message with message length
     /// encoded as varint.
+    #[cfg(feature = "std")]
     fn write_length_delimited_to_writer(&self, w: &mut dyn Write) -> crate::Result<()> {
         w.with_coded_output_stream(|os| self.write_length_delimited_to(os))
     }
@@ -202,7 +353,7 @@ pub trait Message: Clone + Send + Sync + Sized + 'static {
     /// encoded as varint.
     fn write_length_delimited_to_bytes(&self) -> crate::Result<Vec<u8>> {
         let mut v = Vec::new();
-        v.with_coded_output_stream(|os| self.write_length_delimited_to(os))?;
+        self.write_length_delimited_to_vec(&mut v)?;
         Ok(v)
     }
 
@@ -35,6 +35,13 @@ pub trait MessageDyn: Any + fmt::Debug + fmt::Display + Send + Sync + 'static {
     /// Always returns `true` for protobuf 3.
     fn is_initialized_dyn(&self) -> bool;
 
+    /// Append paths of required fields which are not set in this message
+    /// and nested messages to `missing`, each path prefixed with `prefix`.
+    ///
+    /// See [`Message::collect_missing_required_fields`](crate::Message::collect_missing_required_fields).
+    #[doc(hidden)]
+    fn collect_missing_required_fields_dyn(&self, prefix: &str, missing: &mut Vec<String>);
+
     /// Get a reference to unknown fields.
     fn unknown_fields_dyn(&self) -> &UnknownFields;
     /// Get a mutable reference to unknown fields.
@@ -62,6 +69,10 @@ impl<M: MessageFull> MessageDyn for M {
         self.is_initialized()
     }
 
+    fn collect_missing_required_fields_dyn(&self, prefix: &str, missing: &mut Vec<String>) {
+        self.collect_missing_required_fields(prefix, missing)
+    }
+
     fn unknown_fields_dyn(&self) -> &UnknownFields {
         self.unknown_fields()
     }
@@ -72,18 +83,39 @@ impl<M: MessageFull> MessageDyn for M {
 }
 
 impl dyn MessageDyn {
+    /// Paths of all required fields which are not set, like `order.items[3].sku`.
+    ///
+    /// Empty iff [`is_initialized_dyn`](MessageDyn::is_initialized_dyn) is `true`.
+    pub fn missing_required_fields_dyn(&self) -> Vec<String> {
+        let mut missing = Vec::new();
+        self.collect_missing_required_fields_dyn("", &mut missing);
+        missing
+    }
+
     /// Check if all required fields of this object are initialized.
     pub fn check_initialized_dyn(&self) -> crate::Result<()> {
         if !self.is_initialized_dyn() {
-            Err(
-                ProtobufError::MessageNotInitialized(self.descriptor_dyn().name().to_owned())
-                    .into(),
-            )
+            Err(ProtobufError::MessageNotInitialized {
+                message: self.descriptor_dyn().name().to_owned(),
+                missing: self.missing_required_fields_dyn(),
+            }
+            .into())
         } else {
             Ok(())
         }
     }
 
+    /// Merge fields of `other` message of the same type into this message.
+    ///
+    /// See [`MessageDescriptor::merge_from_message`] for semantics.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn merge_from_message_dyn(&mut self, other: &dyn MessageDyn) {
+        self.descriptor_dyn().merge_from_message(self, other)
+    }
+
     /// Write the message to the writer.
     pub fn write_to_writer_dyn(&self, w: &mut dyn Write) -> crate::Result<()> {
         w.with_coded_output_stream(|os| self.write_to_dyn(os))
@@ -120,7 +152,7 @@ impl dyn MessageDyn {
     /// Update this message object with fields read from given stream.
     pub fn merge_from_bytes_dyn(&mut self, bytes: &[u8]) -> crate::Result<()> {
         let mut is = CodedInputStream::from_bytes(bytes);
-        self.merge_from_dyn(&mut is)
+        is.merge_nested(|is| self.merge_from_dyn(is))
     }
 
     /// Write the message to bytes vec.
@@ -144,6 +176,22 @@ impl dyn MessageDyn {
         Ok(v)
     }
 
+    /// Write the message to bytes vec with deterministic serialization,
+    /// see [`CodedOutputStream::set_deterministic`].
+    pub fn write_to_bytes_deterministic_dyn(&self) -> crate::Result<Vec<u8>> {
+        self.check_initialized_dyn()?;
+
+        let size = self.compute_size_dyn() as usize;
+        let mut v = Vec::with_capacity(size);
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            os.set_deterministic(true);
+            self.write_to_with_cached_sizes_dyn(&mut os)?;
+            os.flush()?;
+        }
+        Ok(v)
+    }
+
     /// Write the message to the stream prepending the message with message length
     /// encoded as varint.
     pub fn write_length_delimited_to_dyn(&self, os: &mut CodedOutputStream) -> crate::Result<()> {
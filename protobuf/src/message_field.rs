@@ -1,7 +1,8 @@
-use std::default::Default;
-use std::hash::Hash;
-use std::ops::Deref;
-use std::option;
+use alloc::boxed::Box;
+use core::default::Default;
+use core::hash::Hash;
+use core::ops::Deref;
+use core::option;
 
 use crate::Message;
 
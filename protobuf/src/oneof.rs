@@ -1,8 +1,10 @@
+#[cfg(feature = "std")]
 use crate::reflect::OneofDescriptor;
 
 /// Trait implemented by all oneof types in generated code.
 pub trait Oneof {
     /// Descriptor object for this oneof.
+    #[cfg(feature = "std")]
     fn descriptor() -> OneofDescriptor {
         unimplemented!("TODO")
     }
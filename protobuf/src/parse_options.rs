@@ -0,0 +1,50 @@
+/// Limits applied when decoding binary protobuf.
+///
+/// Default options only limit the recursion depth, which is the behavior
+/// of plain [`Message::parse_from_bytes`](crate::Message::parse_from_bytes).
+/// When parsing untrusted input it is recommended to set the other limits too.
+///
+/// Exceeding a limit is reported as a parse error.
+///
+/// ```
+/// # use protobuf::ParseOptions;
+/// let options = ParseOptions {
+///     max_total_bytes: Some(1 << 20),
+///     max_string_len: Some(4096),
+///     max_repeated_len: Some(1000),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// Maximum nesting depth of messages and groups.
+    ///
+    /// Default is 100, same as in C++ implementation.
+    pub recursion_limit: u32,
+    /// Maximum number of bytes read when parsing a message.
+    pub max_total_bytes: Option<u64>,
+    /// Maximum length of a `string` field value in bytes.
+    pub max_string_len: Option<u32>,
+    /// Maximum length of a `bytes` field value.
+    pub max_bytes_len: Option<u32>,
+    /// Maximum number of elements of a single repeated field.
+    pub max_repeated_len: Option<usize>,
+    /// Maximum number of entries of a single map field.
+    pub max_map_entries: Option<usize>,
+    /// Prevent initializing `ParseOptions` enumerating all field.
+    pub _future_options: (),
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            recursion_limit: crate::coded_input_stream::DEFAULT_RECURSION_LIMIT,
+            max_total_bytes: None,
+            max_string_len: None,
+            max_bytes_len: None,
+            max_repeated_len: None,
+            max_map_entries: None,
+            _future_options: (),
+        }
+    }
+}
@@ -27,15 +27,15 @@
 pub struct Version {
     // message fields
     // @@protoc_insertion_point(field:google.protobuf.compiler.Version.major)
-    major: ::std::option::Option<i32>,
+    major: ::core::option::Option<i32>,
     // @@protoc_insertion_point(field:google.protobuf.compiler.Version.minor)
-    minor: ::std::option::Option<i32>,
+    minor: ::core::option::Option<i32>,
     // @@protoc_insertion_point(field:google.protobuf.compiler.Version.patch)
-    patch: ::std::option::Option<i32>,
+    patch: ::core::option::Option<i32>,
     ///  A suffix for alpha, beta or rc release, e.g., "alpha-1", "rc2". It should
     ///  be empty for mainline stable releases.
     // @@protoc_insertion_point(field:google.protobuf.compiler.Version.suffix)
-    suffix: ::std::option::Option<::std::string::String>,
+    suffix: ::core::option::Option<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.compiler.Version.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -43,7 +43,7 @@ pub struct Version {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Version {
+impl<'a> ::core::default::Default for &'a Version {
     fn default() -> &'a Version {
         <Version as crate::Message>::default_instance()
     }
@@ -51,7 +51,7 @@ impl<'a> ::std::default::Default for &'a Version {
 
 impl Version {
     pub fn new() -> Version {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional int32 major = 1;
@@ -61,7 +61,7 @@ impl Version {
     }
 
     pub fn clear_major(&mut self) {
-        self.major = ::std::option::Option::None;
+        self.major = ::core::option::Option::None;
     }
 
     pub fn has_major(&self) -> bool {
@@ -70,7 +70,7 @@ impl Version {
 
     // Param is passed by value, moved
     pub fn set_major(&mut self, v: i32) {
-        self.major = ::std::option::Option::Some(v);
+        self.major = ::core::option::Option::Some(v);
     }
 
     // optional int32 minor = 2;
@@ -80,7 +80,7 @@ impl Version {
     }
 
     pub fn clear_minor(&mut self) {
-        self.minor = ::std::option::Option::None;
+        self.minor = ::core::option::Option::None;
     }
 
     pub fn has_minor(&self) -> bool {
@@ -89,7 +89,7 @@ impl Version {
 
     // Param is passed by value, moved
     pub fn set_minor(&mut self, v: i32) {
-        self.minor = ::std::option::Option::Some(v);
+        self.minor = ::core::option::Option::Some(v);
     }
 
     // optional int32 patch = 3;
@@ -99,7 +99,7 @@ impl Version {
     }
 
     pub fn clear_patch(&mut self) {
-        self.patch = ::std::option::Option::None;
+        self.patch = ::core::option::Option::None;
     }
 
     pub fn has_patch(&self) -> bool {
@@ -108,7 +108,7 @@ impl Version {
 
     // Param is passed by value, moved
     pub fn set_patch(&mut self, v: i32) {
-        self.patch = ::std::option::Option::Some(v);
+        self.patch = ::core::option::Option::Some(v);
     }
 
     // optional string suffix = 4;
@@ -121,7 +121,7 @@ impl Version {
     }
 
     pub fn clear_suffix(&mut self) {
-        self.suffix = ::std::option::Option::None;
+        self.suffix = ::core::option::Option::None;
     }
 
     pub fn has_suffix(&self) -> bool {
@@ -129,26 +129,26 @@ impl Version {
     }
 
     // Param is passed by value, moved
-    pub fn set_suffix(&mut self, v: ::std::string::String) {
-        self.suffix = ::std::option::Option::Some(v);
+    pub fn set_suffix(&mut self, v: crate::rt::String) {
+        self.suffix = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_suffix(&mut self) -> &mut ::std::string::String {
+    pub fn mut_suffix(&mut self) -> &mut crate::rt::String {
         if self.suffix.is_none() {
-            self.suffix = ::std::option::Option::Some(::std::string::String::new());
+            self.suffix = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.suffix.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_suffix(&mut self) -> ::std::string::String {
-        self.suffix.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_suffix(&mut self) -> crate::rt::String {
+        self.suffix.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = crate::rt::Vec::with_capacity(4);
         fields.push(crate::reflect::rt::v2::make_option_get_copy_simpler_accessor::<_, _>(
             "major",
             |m: &Version| { &m.major },
@@ -189,27 +189,42 @@ impl crate::Message for Version {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.major = ::std::option::Option::Some(is.read_int32()?);
+                    self.major = ::core::option::Option::Some(is.read_int32()?);
                 },
                 16 => {
-                    self.minor = ::std::option::Option::Some(is.read_int32()?);
+                    self.minor = ::core::option::Option::Some(is.read_int32()?);
                 },
                 24 => {
-                    self.patch = ::std::option::Option::Some(is.read_int32()?);
+                    self.patch = ::core::option::Option::Some(is.read_int32()?);
                 },
                 34 => {
-                    self.suffix = ::std::option::Option::Some(is.read_string()?);
+                    self.suffix = ::core::option::Option::Some(is.read_string()?);
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.major.is_some() {
+            self.major.clone_from(&other.major);
+        }
+        if other.minor.is_some() {
+            self.minor.clone_from(&other.minor);
+        }
+        if other.patch.is_some() {
+            self.patch.clone_from(&other.patch);
+        }
+        if other.suffix.is_some() {
+            self.suffix.clone_from(&other.suffix);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -247,7 +262,24 @@ impl crate::Message for Version {
             os.write_string(4, v)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.suffix.as_ref() {
+            os.write_string(4, v);
+        }
+        if let Some(v) = self.patch {
+            os.write_int32(3, v);
+        }
+        if let Some(v) = self.minor {
+            os.write_int32(2, v);
+        }
+        if let Some(v) = self.major {
+            os.write_int32(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -267,19 +299,19 @@ impl crate::Message for Version {
     }
 
     fn clear(&mut self) {
-        self.major = ::std::option::Option::None;
-        self.minor = ::std::option::Option::None;
-        self.patch = ::std::option::Option::None;
-        self.suffix = ::std::option::Option::None;
+        self.major = ::core::option::Option::None;
+        self.minor = ::core::option::Option::None;
+        self.patch = ::core::option::Option::None;
+        self.suffix = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static Version {
         static instance: Version = Version {
-            major: ::std::option::Option::None,
-            minor: ::std::option::Option::None,
-            patch: ::std::option::Option::None,
-            suffix: ::std::option::Option::None,
+            major: ::core::option::Option::None,
+            minor: ::core::option::Option::None,
+            patch: ::core::option::Option::None,
+            suffix: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -293,8 +325,8 @@ impl crate::MessageFull for Version {
     }
 }
 
-impl ::std::fmt::Display for Version {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Version {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -312,10 +344,10 @@ pub struct CodeGeneratorRequest {
     ///  code generator should generate code only for these files.  Each file's
     ///  descriptor will be included in proto_file, below.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorRequest.file_to_generate)
-    pub file_to_generate: ::std::vec::Vec<::std::string::String>,
+    pub file_to_generate: crate::rt::Vec<crate::rt::String>,
     ///  The generator parameter passed on the command-line.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorRequest.parameter)
-    parameter: ::std::option::Option<::std::string::String>,
+    parameter: ::core::option::Option<crate::rt::String>,
     ///  FileDescriptorProtos for all files in files_to_generate and everything
     ///  they import.  The files will appear in topological order, so each file
     ///  appears before any file that imports it.
@@ -331,7 +363,7 @@ pub struct CodeGeneratorRequest {
     ///  Type names of fields and extensions in the FileDescriptorProto are always
     ///  fully qualified.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorRequest.proto_file)
-    pub proto_file: ::std::vec::Vec<crate::descriptor::FileDescriptorProto>,
+    pub proto_file: crate::rt::Vec<crate::descriptor::FileDescriptorProto>,
     ///  The version number of protocol compiler.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorRequest.compiler_version)
     pub compiler_version: crate::MessageField<Version>,
@@ -342,7 +374,7 @@ pub struct CodeGeneratorRequest {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CodeGeneratorRequest {
+impl<'a> ::core::default::Default for &'a CodeGeneratorRequest {
     fn default() -> &'a CodeGeneratorRequest {
         <CodeGeneratorRequest as crate::Message>::default_instance()
     }
@@ -350,7 +382,7 @@ impl<'a> ::std::default::Default for &'a CodeGeneratorRequest {
 
 impl CodeGeneratorRequest {
     pub fn new() -> CodeGeneratorRequest {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string parameter = 2;
@@ -363,7 +395,7 @@ impl CodeGeneratorRequest {
     }
 
     pub fn clear_parameter(&mut self) {
-        self.parameter = ::std::option::Option::None;
+        self.parameter = ::core::option::Option::None;
     }
 
     pub fn has_parameter(&self) -> bool {
@@ -371,26 +403,26 @@ impl CodeGeneratorRequest {
     }
 
     // Param is passed by value, moved
-    pub fn set_parameter(&mut self, v: ::std::string::String) {
-        self.parameter = ::std::option::Option::Some(v);
+    pub fn set_parameter(&mut self, v: crate::rt::String) {
+        self.parameter = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_parameter(&mut self) -> &mut ::std::string::String {
+    pub fn mut_parameter(&mut self) -> &mut crate::rt::String {
         if self.parameter.is_none() {
-            self.parameter = ::std::option::Option::Some(::std::string::String::new());
+            self.parameter = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.parameter.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_parameter(&mut self) -> ::std::string::String {
-        self.parameter.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_parameter(&mut self) -> crate::rt::String {
+        self.parameter.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = crate::rt::Vec::with_capacity(4);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "file_to_generate",
             |m: &CodeGeneratorRequest| { &m.file_to_generate },
@@ -437,18 +469,28 @@ impl crate::Message for CodeGeneratorRequest {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.proto_file.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "proto_file", i), missing);
+        };
+        for v in &self.compiler_version {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "compiler_version"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.file_to_generate.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.file_to_generate.len())?;
                 },
                 18 => {
-                    self.parameter = ::std::option::Option::Some(is.read_string()?);
+                    self.parameter = ::core::option::Option::Some(is.read_string()?);
                 },
                 122 => {
-                    self.proto_file.push(is.read_message()?);
+                    self.proto_file.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.proto_file.len())?;
                 },
                 26 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.compiler_version)?;
@@ -458,7 +500,19 @@ impl crate::Message for CodeGeneratorRequest {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.file_to_generate.extend(other.file_to_generate.iter().cloned());
+        if other.parameter.is_some() {
+            self.parameter.clone_from(&other.parameter);
+        }
+        self.proto_file.extend(other.proto_file.iter().cloned());
+        if let Some(v) = other.compiler_version.as_ref() {
+            self.compiler_version.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -498,7 +552,24 @@ impl crate::Message for CodeGeneratorRequest {
             crate::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.compiler_version.as_ref() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        }
+        for v in self.proto_file.iter().rev() {
+            crate::rt::write_message_field_reverse(15, v, os)?;
+        };
+        if let Some(v) = self.parameter.as_ref() {
+            os.write_string(2, v);
+        }
+        for v in self.file_to_generate.iter().rev() {
+            os.write_string(1, &v);
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -519,7 +590,7 @@ impl crate::Message for CodeGeneratorRequest {
 
     fn clear(&mut self) {
         self.file_to_generate.clear();
-        self.parameter = ::std::option::Option::None;
+        self.parameter = ::core::option::Option::None;
         self.proto_file.clear();
         self.compiler_version.clear();
         self.unknown_fields.clear();
@@ -527,9 +598,9 @@ impl crate::Message for CodeGeneratorRequest {
 
     fn default_instance() -> &'static CodeGeneratorRequest {
         static instance: CodeGeneratorRequest = CodeGeneratorRequest {
-            file_to_generate: ::std::vec::Vec::new(),
-            parameter: ::std::option::Option::None,
-            proto_file: ::std::vec::Vec::new(),
+            file_to_generate: crate::rt::Vec::new(),
+            parameter: ::core::option::Option::None,
+            proto_file: crate::rt::Vec::new(),
             compiler_version: crate::MessageField::none(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -544,8 +615,8 @@ impl crate::MessageFull for CodeGeneratorRequest {
     }
 }
 
-impl ::std::fmt::Display for CodeGeneratorRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for CodeGeneratorRequest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -568,13 +639,13 @@ pub struct CodeGeneratorResponse {
     ///  unparseable -- should be reported by writing a message to stderr and
     ///  exiting with a non-zero status code.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.error)
-    error: ::std::option::Option<::std::string::String>,
+    error: ::core::option::Option<crate::rt::String>,
     ///  A bitmask of supported features that the code generator supports.
     ///  This is a bitwise "or" of values from the Feature enum.
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.supported_features)
-    supported_features: ::std::option::Option<u64>,
+    supported_features: ::core::option::Option<u64>,
     // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.file)
-    pub file: ::std::vec::Vec<code_generator_response::File>,
+    pub file: crate::rt::Vec<code_generator_response::File>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.compiler.CodeGeneratorResponse.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -582,7 +653,7 @@ pub struct CodeGeneratorResponse {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CodeGeneratorResponse {
+impl<'a> ::core::default::Default for &'a CodeGeneratorResponse {
     fn default() -> &'a CodeGeneratorResponse {
         <CodeGeneratorResponse as crate::Message>::default_instance()
     }
@@ -590,7 +661,7 @@ impl<'a> ::std::default::Default for &'a CodeGeneratorResponse {
 
 impl CodeGeneratorResponse {
     pub fn new() -> CodeGeneratorResponse {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // optional string error = 1;
@@ -603,7 +674,7 @@ impl CodeGeneratorResponse {
     }
 
     pub fn clear_error(&mut self) {
-        self.error = ::std::option::Option::None;
+        self.error = ::core::option::Option::None;
     }
 
     pub fn has_error(&self) -> bool {
@@ -611,22 +682,22 @@ impl CodeGeneratorResponse {
     }
 
     // Param is passed by value, moved
-    pub fn set_error(&mut self, v: ::std::string::String) {
-        self.error = ::std::option::Option::Some(v);
+    pub fn set_error(&mut self, v: crate::rt::String) {
+        self.error = ::core::option::Option::Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+    pub fn mut_error(&mut self) -> &mut crate::rt::String {
         if self.error.is_none() {
-            self.error = ::std::option::Option::Some(::std::string::String::new());
+            self.error = ::core::option::Option::Some(crate::rt::String::new());
         }
         self.error.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_error(&mut self) -> ::std::string::String {
-        self.error.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_error(&mut self) -> crate::rt::String {
+        self.error.take().unwrap_or_else(|| crate::rt::String::new())
     }
 
     // optional uint64 supported_features = 2;
@@ -636,7 +707,7 @@ impl CodeGeneratorResponse {
     }
 
     pub fn clear_supported_features(&mut self) {
-        self.supported_features = ::std::option::Option::None;
+        self.supported_features = ::core::option::Option::None;
     }
 
     pub fn has_supported_features(&self) -> bool {
@@ -645,11 +716,11 @@ impl CodeGeneratorResponse {
 
     // Param is passed by value, moved
     pub fn set_supported_features(&mut self, v: u64) {
-        self.supported_features = ::std::option::Option::Some(v);
+        self.supported_features = ::core::option::Option::Some(v);
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
             "error",
             |m: &CodeGeneratorResponse| { &m.error },
@@ -687,25 +758,42 @@ impl crate::Message for CodeGeneratorResponse {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.file.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "file", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.error = ::std::option::Option::Some(is.read_string()?);
+                    self.error = ::core::option::Option::Some(is.read_string()?);
                 },
                 16 => {
-                    self.supported_features = ::std::option::Option::Some(is.read_uint64()?);
+                    self.supported_features = ::core::option::Option::Some(is.read_uint64()?);
                 },
                 122 => {
-                    self.file.push(is.read_message()?);
+                    self.file.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.file.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.error.is_some() {
+            self.error.clone_from(&other.error);
+        }
+        if other.supported_features.is_some() {
+            self.supported_features.clone_from(&other.supported_features);
+        }
+        self.file.extend(other.file.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -738,7 +826,21 @@ impl crate::Message for CodeGeneratorResponse {
             crate::rt::write_message_field_with_cached_size(15, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.file.iter().rev() {
+            crate::rt::write_message_field_reverse(15, v, os)?;
+        };
+        if let Some(v) = self.supported_features {
+            os.write_uint64(2, v);
+        }
+        if let Some(v) = self.error.as_ref() {
+            os.write_string(1, v);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -758,17 +860,17 @@ impl crate::Message for CodeGeneratorResponse {
     }
 
     fn clear(&mut self) {
-        self.error = ::std::option::Option::None;
-        self.supported_features = ::std::option::Option::None;
+        self.error = ::core::option::Option::None;
+        self.supported_features = ::core::option::Option::None;
         self.file.clear();
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static CodeGeneratorResponse {
         static instance: CodeGeneratorResponse = CodeGeneratorResponse {
-            error: ::std::option::Option::None,
-            supported_features: ::std::option::Option::None,
-            file: ::std::vec::Vec::new(),
+            error: ::core::option::Option::None,
+            supported_features: ::core::option::Option::None,
+            file: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -782,8 +884,8 @@ impl crate::MessageFull for CodeGeneratorResponse {
     }
 }
 
-impl ::std::fmt::Display for CodeGeneratorResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for CodeGeneratorResponse {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -802,7 +904,7 @@ pub mod code_generator_response {
         ///  The file name, relative to the output directory.  The name must not
         ///  contain "." or ".." components and must be relative, not be absolute (so,
         ///  the file cannot lie outside the output directory).  "/" must be used as
-        ///  the path separator, not "\".
+        ///  the path separator, not "\\".
         ///
         ///  If the name is omitted, the content will be appended to the previous
         ///  file.  This allows the generator to break large files into small chunks,
@@ -811,7 +913,7 @@ pub mod code_generator_response {
         ///  this writing protoc does not optimize for this -- it will read the entire
         ///  CodeGeneratorResponse before writing files to disk.
         // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.File.name)
-        name: ::std::option::Option<::std::string::String>,
+        name: ::core::option::Option<crate::rt::String>,
         ///  If non-empty, indicates that the named file should already exist, and the
         ///  content here is to be inserted into that file at a defined insertion
         ///  point.  This feature allows a code generator to extend the output
@@ -850,10 +952,10 @@ pub mod code_generator_response {
         ///
         ///  If |insertion_point| is present, |name| must also be present.
         // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.File.insertion_point)
-        insertion_point: ::std::option::Option<::std::string::String>,
+        insertion_point: ::core::option::Option<crate::rt::String>,
         ///  The file contents.
         // @@protoc_insertion_point(field:google.protobuf.compiler.CodeGeneratorResponse.File.content)
-        content: ::std::option::Option<::std::string::String>,
+        content: ::core::option::Option<crate::rt::String>,
         ///  Information describing the file content being inserted. If an insertion
         ///  point is used, this information will be appropriately offset and inserted
         ///  into the code generation metadata for the generated files.
@@ -866,7 +968,7 @@ pub mod code_generator_response {
         pub cached_size: crate::rt::CachedSize,
     }
 
-    impl<'a> ::std::default::Default for &'a File {
+    impl<'a> ::core::default::Default for &'a File {
         fn default() -> &'a File {
             <File as crate::Message>::default_instance()
         }
@@ -874,7 +976,7 @@ pub mod code_generator_response {
 
     impl File {
         pub fn new() -> File {
-            ::std::default::Default::default()
+            ::core::default::Default::default()
         }
 
         // optional string name = 1;
@@ -887,7 +989,7 @@ pub mod code_generator_response {
         }
 
         pub fn clear_name(&mut self) {
-            self.name = ::std::option::Option::None;
+            self.name = ::core::option::Option::None;
         }
 
         pub fn has_name(&self) -> bool {
@@ -895,22 +997,22 @@ pub mod code_generator_response {
         }
 
         // Param is passed by value, moved
-        pub fn set_name(&mut self, v: ::std::string::String) {
-            self.name = ::std::option::Option::Some(v);
+        pub fn set_name(&mut self, v: crate::rt::String) {
+            self.name = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        pub fn mut_name(&mut self) -> &mut crate::rt::String {
             if self.name.is_none() {
-                self.name = ::std::option::Option::Some(::std::string::String::new());
+                self.name = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.name.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_name(&mut self) -> ::std::string::String {
-            self.name.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_name(&mut self) -> crate::rt::String {
+            self.name.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         // optional string insertion_point = 2;
@@ -923,7 +1025,7 @@ pub mod code_generator_response {
         }
 
         pub fn clear_insertion_point(&mut self) {
-            self.insertion_point = ::std::option::Option::None;
+            self.insertion_point = ::core::option::Option::None;
         }
 
         pub fn has_insertion_point(&self) -> bool {
@@ -931,22 +1033,22 @@ pub mod code_generator_response {
         }
 
         // Param is passed by value, moved
-        pub fn set_insertion_point(&mut self, v: ::std::string::String) {
-            self.insertion_point = ::std::option::Option::Some(v);
+        pub fn set_insertion_point(&mut self, v: crate::rt::String) {
+            self.insertion_point = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_insertion_point(&mut self) -> &mut ::std::string::String {
+        pub fn mut_insertion_point(&mut self) -> &mut crate::rt::String {
             if self.insertion_point.is_none() {
-                self.insertion_point = ::std::option::Option::Some(::std::string::String::new());
+                self.insertion_point = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.insertion_point.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_insertion_point(&mut self) -> ::std::string::String {
-            self.insertion_point.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_insertion_point(&mut self) -> crate::rt::String {
+            self.insertion_point.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         // optional string content = 15;
@@ -959,7 +1061,7 @@ pub mod code_generator_response {
         }
 
         pub fn clear_content(&mut self) {
-            self.content = ::std::option::Option::None;
+            self.content = ::core::option::Option::None;
         }
 
         pub fn has_content(&self) -> bool {
@@ -967,26 +1069,26 @@ pub mod code_generator_response {
         }
 
         // Param is passed by value, moved
-        pub fn set_content(&mut self, v: ::std::string::String) {
-            self.content = ::std::option::Option::Some(v);
+        pub fn set_content(&mut self, v: crate::rt::String) {
+            self.content = ::core::option::Option::Some(v);
         }
 
         // Mutable pointer to the field.
         // If field is not initialized, it is initialized with default value first.
-        pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        pub fn mut_content(&mut self) -> &mut crate::rt::String {
             if self.content.is_none() {
-                self.content = ::std::option::Option::Some(::std::string::String::new());
+                self.content = ::core::option::Option::Some(crate::rt::String::new());
             }
             self.content.as_mut().unwrap()
         }
 
         // Take field
-        pub fn take_content(&mut self) -> ::std::string::String {
-            self.content.take().unwrap_or_else(|| ::std::string::String::new())
+        pub fn take_content(&mut self) -> crate::rt::String {
+            self.content.take().unwrap_or_else(|| crate::rt::String::new())
         }
 
         pub(in super) fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut fields = crate::rt::Vec::with_capacity(4);
             fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
                 "name",
                 |m: &File| { &m.name },
@@ -1030,18 +1132,23 @@ pub mod code_generator_response {
             true
         }
 
+        fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+            for v in &self.generated_code_info {
+                v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "generated_code_info"), missing);
+            };
+        }
+
         fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-            while !is.eof()? {
-                let tag = is.read_raw_varint32()?;
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
                 match tag {
                     10 => {
-                        self.name = ::std::option::Option::Some(is.read_string()?);
+                        self.name = ::core::option::Option::Some(is.read_string()?);
                     },
                     18 => {
-                        self.insertion_point = ::std::option::Option::Some(is.read_string()?);
+                        self.insertion_point = ::core::option::Option::Some(is.read_string()?);
                     },
                     122 => {
-                        self.content = ::std::option::Option::Some(is.read_string()?);
+                        self.content = ::core::option::Option::Some(is.read_string()?);
                     },
                     130 => {
                         crate::rt::read_singular_message_into_field(is, &mut self.generated_code_info)?;
@@ -1051,7 +1158,23 @@ pub mod code_generator_response {
                     },
                 };
             }
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.name.is_some() {
+                self.name.clone_from(&other.name);
+            }
+            if other.insertion_point.is_some() {
+                self.insertion_point.clone_from(&other.insertion_point);
+            }
+            if other.content.is_some() {
+                self.content.clone_from(&other.content);
+            }
+            if let Some(v) = other.generated_code_info.as_ref() {
+                self.generated_code_info.mut_or_default().merge_from_message(v);
+            }
+            self.unknown_fields.extend_from(&other.unknown_fields);
         }
 
         // Compute sizes of nested messages
@@ -1090,7 +1213,24 @@ pub mod code_generator_response {
                 crate::rt::write_message_field_with_cached_size(16, v, os)?;
             }
             os.write_unknown_fields(self.unknown_fields())?;
-            ::std::result::Result::Ok(())
+            ::core::result::Result::Ok(())
+        }
+
+        fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+            crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+            if let Some(v) = self.generated_code_info.as_ref() {
+                crate::rt::write_message_field_reverse(16, v, os)?;
+            }
+            if let Some(v) = self.content.as_ref() {
+                os.write_string(15, v);
+            }
+            if let Some(v) = self.insertion_point.as_ref() {
+                os.write_string(2, v);
+            }
+            if let Some(v) = self.name.as_ref() {
+                os.write_string(1, v);
+            }
+            ::core::result::Result::Ok(())
         }
 
         fn cached_size(&self) -> u32 {
@@ -1110,18 +1250,18 @@ pub mod code_generator_response {
         }
 
         fn clear(&mut self) {
-            self.name = ::std::option::Option::None;
-            self.insertion_point = ::std::option::Option::None;
-            self.content = ::std::option::Option::None;
+            self.name = ::core::option::Option::None;
+            self.insertion_point = ::core::option::Option::None;
+            self.content = ::core::option::Option::None;
             self.generated_code_info.clear();
             self.unknown_fields.clear();
         }
 
         fn default_instance() -> &'static File {
             static instance: File = File {
-                name: ::std::option::Option::None,
-                insertion_point: ::std::option::Option::None,
-                content: ::std::option::Option::None,
+                name: ::core::option::Option::None,
+                insertion_point: ::core::option::Option::None,
+                content: ::core::option::Option::None,
                 generated_code_info: crate::MessageField::none(),
                 unknown_fields: crate::UnknownFields::new(),
                 cached_size: crate::rt::CachedSize::new(),
@@ -1136,8 +1276,8 @@ pub mod code_generator_response {
         }
     }
 
-    impl ::std::fmt::Display for File {
-        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+    impl ::core::fmt::Display for File {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
             crate::text_format::fmt(self, f)
         }
     }
@@ -1161,11 +1301,11 @@ pub mod code_generator_response {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Feature> {
+        fn from_i32(value: i32) -> ::core::option::Option<Feature> {
             match value {
-                0 => ::std::option::Option::Some(Feature::FEATURE_NONE),
-                1 => ::std::option::Option::Some(Feature::FEATURE_PROTO3_OPTIONAL),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(Feature::FEATURE_NONE),
+                1 => ::core::option::Option::Some(Feature::FEATURE_PROTO3_OPTIONAL),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -1184,7 +1324,7 @@ pub mod code_generator_response {
         }
     }
 
-    impl ::std::default::Default for Feature {
+    impl ::core::default::Default for Feature {
         fn default() -> Self {
             Feature::FEATURE_NONE
         }
@@ -1453,14 +1593,14 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
         deps.push(crate::descriptor::file_descriptor());
-        let mut messages = ::std::vec::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Version::generated_message_descriptor_data());
         messages.push(CodeGeneratorRequest::generated_message_descriptor_data());
         messages.push(CodeGeneratorResponse::generated_message_descriptor_data());
         messages.push(code_generator_response::File::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         enums.push(code_generator_response::Feature::generated_enum_descriptor_data());
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
@@ -1,11 +1,14 @@
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::Hash;
+use std::marker::PhantomData;
 
 use crate::message_dyn::MessageDyn;
 use crate::message_full::MessageFull;
 use crate::reflect::acc::v2::AccessorV2;
 use crate::reflect::acc::FieldAccessor;
+use crate::reflect::map::ReflectMap;
 use crate::reflect::map::ReflectMapMut;
 use crate::reflect::map::ReflectMapRef;
 use crate::reflect::runtime_types::RuntimeTypeHashable;
@@ -28,22 +31,23 @@ impl<'a> fmt::Debug for MapFieldAccessorHolder {
     }
 }
 
-struct MapFieldAccessorImpl<M, K, V>
+struct MapFieldAccessorImpl<M, K, V, C>
 where
     M: MessageFull,
     K: ProtobufValue,
     V: ProtobufValue,
 {
-    get_field: fn(&M) -> &HashMap<K, V>,
-    mut_field: fn(&mut M) -> &mut HashMap<K, V>,
+    get_field: fn(&M) -> &C,
+    mut_field: fn(&mut M) -> &mut C,
+    _marker: PhantomData<(K, V)>,
 }
 
-impl<M, K, V> MapFieldAccessor for MapFieldAccessorImpl<M, K, V>
+impl<M, K, V, C> MapFieldAccessor for MapFieldAccessorImpl<M, K, V, C>
 where
     M: MessageFull,
-    K: ProtobufValue + Eq + Hash,
-    K::RuntimeType: RuntimeTypeHashable,
+    K: ProtobufValue,
     V: ProtobufValue,
+    C: ReflectMap,
 {
     fn get_reflect<'a>(&self, m: &'a dyn MessageDyn) -> ReflectMapRef<'a> {
         let m = m.downcast_ref().unwrap();
@@ -77,9 +81,33 @@ where
     FieldAccessor::new_v2(
         name,
         AccessorV2::Map(MapFieldAccessorHolder {
-            accessor: Box::new(MapFieldAccessorImpl::<M, K, V> {
+            accessor: Box::new(MapFieldAccessorImpl::<M, K, V, HashMap<K, V>> {
+                get_field,
+                mut_field,
+                _marker: PhantomData,
+            }),
+        }),
+    )
+}
+
+/// Make accessor for map field stored in `BTreeMap`
+pub fn make_btree_map_simpler_accessor<M, K, V>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a BTreeMap<K, V>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut BTreeMap<K, V>,
+) -> FieldAccessor
+where
+    M: MessageFull + 'static,
+    K: ProtobufValue + Ord,
+    V: ProtobufValue,
+{
+    FieldAccessor::new_v2(
+        name,
+        AccessorV2::Map(MapFieldAccessorHolder {
+            accessor: Box::new(MapFieldAccessorImpl::<M, K, V, BTreeMap<K, V>> {
                 get_field,
                 mut_field,
+                _marker: PhantomData,
             }),
         }),
     )
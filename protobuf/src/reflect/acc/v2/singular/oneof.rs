@@ -31,6 +31,7 @@ pub fn make_oneof_message_has_get_mut_set_accessor<M, F>(
     get_field: for<'a> fn(&'a M) -> &'a F,
     mut_field: for<'a> fn(&'a mut M) -> &'a mut F,
     set_field: fn(&mut M, F),
+    clear_field: fn(&mut M),
 ) -> FieldAccessor
 where
     M: MessageFull + 'static,
@@ -46,7 +47,10 @@ where
                 },
                 get_or_default_impl: GetOrDefaultGetRef::<M, F> { get_field },
                 mut_or_default_impl: MutOrDefaultGetMut::<M, F> { mut_field },
-                set_impl: SetImplSetField::<M, F> { set_field },
+                set_impl: SetImplSetField::<M, F> {
+                    set_field,
+                    clear_field,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
@@ -59,6 +63,7 @@ pub fn make_oneof_copy_has_get_set_simpler_accessors<M, V>(
     has: fn(&M) -> bool,
     get: fn(&M) -> V,
     set: fn(&mut M, V),
+    clear: fn(&mut M),
 ) -> FieldAccessor
 where
     M: MessageFull + 'static,
@@ -71,7 +76,10 @@ where
                 get_option_impl: GetOptionImplHasGetCopy::<M, V> { has, get },
                 get_or_default_impl: GetOrDefaultGetCopy::<M, V> { get_field: get },
                 mut_or_default_impl: MutOrDefaultUnmplemented::new(),
-                set_impl: SetImplSetField::<M, V> { set_field: set },
+                set_impl: SetImplSetField::<M, V> {
+                    set_field: set,
+                    clear_field: clear,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
@@ -81,6 +89,7 @@ where
 struct OneofEnumAccessor<M: MessageFull, E: EnumFull> {
     get: fn(&M) -> Option<EnumOrUnknown<E>>,
     set: fn(&mut M, EnumOrUnknown<E>),
+    clear: fn(&mut M),
     default_value: E,
 }
 
@@ -112,6 +121,13 @@ impl<M: MessageFull, E: EnumFull> SingularFieldAccessor for OneofEnumAccessor<M,
             _ => panic!("expecting enum value"),
         }
     }
+
+    fn clear_field(&self, m: &mut dyn MessageDyn) {
+        let m = m.downcast_mut().unwrap();
+        if (self.get)(m).is_some() {
+            (self.clear)(m);
+        }
+    }
 }
 
 /// Make accessor for `Copy` field
@@ -119,6 +135,7 @@ pub fn make_oneof_enum_accessors<M, E>(
     name: &'static str,
     get: fn(&M) -> Option<EnumOrUnknown<E>>,
     set: fn(&mut M, EnumOrUnknown<E>),
+    clear: fn(&mut M),
     default_value: E,
 ) -> FieldAccessor
 where
@@ -131,6 +148,7 @@ where
             accessor: Box::new(OneofEnumAccessor {
                 get,
                 set,
+                clear,
                 default_value,
             }),
         }),
@@ -143,6 +161,7 @@ pub fn make_oneof_deref_has_get_set_simpler_accessor<M, F>(
     has: fn(&M) -> bool,
     get: for<'a> fn(&'a M) -> &'a <F::RuntimeType as RuntimeTypeWithDeref>::DerefTarget,
     set: fn(&mut M, F),
+    clear: fn(&mut M),
 ) -> FieldAccessor
 where
     M: MessageFull + 'static,
@@ -156,7 +175,10 @@ where
                 get_option_impl: GetOptionImplHasGetRefDeref::<M, F> { has, get },
                 get_or_default_impl: GetOrDefaultGetRefDeref::<M, F> { get_field: get },
                 mut_or_default_impl: MutOrDefaultUnmplemented::new(),
-                set_impl: SetImplSetField::<M, F> { set_field: set },
+                set_impl: SetImplSetField::<M, F> {
+                    set_field: set,
+                    clear_field: clear,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
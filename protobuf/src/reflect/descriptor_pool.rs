@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::descriptor::FileDescriptorProto;
+use crate::descriptor::FileDescriptorSet;
+use crate::reflect::error::ReflectError;
+use crate::reflect::file::fds::FdsBuilder;
+use crate::reflect::name::concat_paths;
+use crate::reflect::EnumDescriptor;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::FileDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::MethodDescriptor;
+use crate::reflect::ServiceDescriptor;
+
+#[derive(Clone)]
+enum Symbol {
+    Message(MessageDescriptor),
+    Enum(EnumDescriptor),
+    Service(ServiceDescriptor),
+    Method(MethodDescriptor),
+    Extension(FieldDescriptor),
+}
+
+#[derive(Clone)]
+struct SymbolEntry {
+    /// Name of the file where the symbol is defined.
+    file: String,
+    symbol: Symbol,
+}
+
+/// Set of files which can be loaded incrementally,
+/// and symbols which can be resolved by fully qualified name across all the files.
+///
+/// Unlike [`FileDescriptor::new_dynamic`], dependencies of added files
+/// are looked up in the pool.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::descriptor::FileDescriptorSet;
+/// use protobuf::reflect::DescriptorPool;
+/// use protobuf::well_known_types::Timestamp;
+/// use protobuf::MessageFull;
+///
+/// let mut fds = FileDescriptorSet::new();
+/// fds.file
+///     .push(Timestamp::descriptor_static().file_descriptor_proto().clone());
+///
+/// let mut pool = DescriptorPool::new();
+/// pool.add_file_descriptor_set(fds).unwrap();
+/// let timestamp = pool
+///     .message_by_full_name("google.protobuf.Timestamp")
+///     .unwrap();
+/// assert_eq!("Timestamp", timestamp.name());
+/// ```
+#[derive(Default, Clone)]
+pub struct DescriptorPool {
+    files: Vec<FileDescriptor>,
+    files_by_name: HashMap<String, FileDescriptor>,
+    symbols: HashMap<String, SymbolEntry>,
+}
+
+impl fmt::Debug for DescriptorPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DescriptorPool")
+            .field(
+                "files",
+                &self
+                    .files
+                    .iter()
+                    .map(|f| f.proto().name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl DescriptorPool {
+    /// Create an empty pool.
+    pub fn new() -> DescriptorPool {
+        DescriptorPool::default()
+    }
+
+    /// Add file and all its dependencies to the pool.
+    ///
+    /// Adding a file with the same name again is no-op if the content is the same.
+    /// On error the pool is not modified.
+    pub fn add_file(&mut self, file: &FileDescriptor) -> crate::Result<()> {
+        self.add_files(std::slice::from_ref(file))
+    }
+
+    /// Build a file from a descriptor proto and add it to the pool.
+    ///
+    /// All dependencies of the file must be already added to the pool.
+    /// On error the pool is not modified.
+    pub fn add_file_descriptor_proto(
+        &mut self,
+        proto: FileDescriptorProto,
+    ) -> crate::Result<FileDescriptor> {
+        let mut files = self.add_file_descriptor_protos(vec![proto])?;
+        Ok(files.pop().unwrap())
+    }
+
+    /// Build files from a descriptor set and add them to the pool.
+    ///
+    /// Files may be specified in any order. Dependencies are looked up
+    /// in the set and in files previously added to the pool.
+    /// Returned files are in the same order as in the set.
+    /// On error the pool is not modified.
+    pub fn add_file_descriptor_set(
+        &mut self,
+        file_descriptor_set: FileDescriptorSet,
+    ) -> crate::Result<Vec<FileDescriptor>> {
+        self.add_file_descriptor_protos(file_descriptor_set.file)
+    }
+
+    fn add_file_descriptor_protos(
+        &mut self,
+        protos: Vec<FileDescriptorProto>,
+    ) -> crate::Result<Vec<FileDescriptor>> {
+        let mut names = HashMap::new();
+        for proto in &protos {
+            if names.insert(proto.name(), proto).is_some() {
+                return Err(ReflectError::NonUniqueFileDescriptor(proto.name().to_owned()).into());
+            }
+        }
+
+        // Files already in the pool are reused.
+        let mut new_protos = Vec::new();
+        for proto in &protos {
+            match self.files_by_name.get(proto.name()) {
+                Some(file) if file.proto() == proto => {}
+                Some(_) => {
+                    return Err(
+                        ReflectError::NonUniqueFileDescriptor(proto.name().to_owned()).into(),
+                    )
+                }
+                None => new_protos.push(proto.clone()),
+            }
+        }
+
+        let built = FdsBuilder::build(new_protos, &self.files_by_name)?;
+
+        self.add_files(&built)?;
+        let files = protos
+            .iter()
+            .map(|p| self.files_by_name[p.name()].clone())
+            .collect();
+        Ok(files)
+    }
+
+    /// Add files with dependencies, remove everything added on error.
+    fn add_files(&mut self, files: &[FileDescriptor]) -> crate::Result<()> {
+        let files_len = self.files.len();
+        let mut added_symbols = Vec::new();
+        let r = files
+            .iter()
+            .try_for_each(|file| self.add_file_impl(file, &mut added_symbols));
+        if r.is_err() {
+            for file in self.files.drain(files_len..) {
+                self.files_by_name.remove(file.proto().name());
+            }
+            for full_name in &added_symbols {
+                self.symbols.remove(full_name);
+            }
+        }
+        r
+    }
+
+    fn add_file_impl(
+        &mut self,
+        file: &FileDescriptor,
+        added_symbols: &mut Vec<String>,
+    ) -> crate::Result<()> {
+        let name = file.proto().name();
+        if let Some(existing) = self.files_by_name.get(name) {
+            return if existing == file || existing.proto() == file.proto() {
+                Ok(())
+            } else {
+                Err(ReflectError::NonUniqueFileDescriptor(name.to_owned()).into())
+            };
+        }
+
+        for dep in file.deps() {
+            self.add_file_impl(dep, added_symbols)?;
+        }
+
+        for (full_name, symbol) in file_symbols(file) {
+            if let Some(existing) = self.symbols.get(&full_name) {
+                return Err(ReflectError::DuplicateSymbol(
+                    full_name,
+                    existing.file.clone(),
+                    name.to_owned(),
+                )
+                .into());
+            }
+            added_symbols.push(full_name.clone());
+            self.symbols.insert(
+                full_name,
+                SymbolEntry {
+                    file: name.to_owned(),
+                    symbol,
+                },
+            );
+        }
+
+        self.files_by_name.insert(name.to_owned(), file.clone());
+        self.files.push(file.clone());
+        Ok(())
+    }
+
+    /// All files in the pool, dependencies before dependents.
+    pub fn files(&self) -> &[FileDescriptor] {
+        &self.files
+    }
+
+    /// Find file by name (e. g. `google/protobuf/timestamp.proto`).
+    pub fn file_by_name(&self, name: &str) -> Option<FileDescriptor> {
+        self.files_by_name.get(name).cloned()
+    }
+
+    fn symbol(&self, full_name: &str) -> Option<&Symbol> {
+        let full_name = full_name.strip_prefix('.').unwrap_or(full_name);
+        self.symbols.get(full_name).map(|e| &e.symbol)
+    }
+
+    /// Find message by fully qualified name (e. g. `google.protobuf.Timestamp`).
+    ///
+    /// Leading dot is allowed.
+    pub fn message_by_full_name(&self, full_name: &str) -> Option<MessageDescriptor> {
+        match self.symbol(full_name) {
+            Some(Symbol::Message(m)) => Some(m.clone()),
+            _ => None,
+        }
+    }
+
+    /// Find enum by fully qualified name.
+    pub fn enum_by_full_name(&self, full_name: &str) -> Option<EnumDescriptor> {
+        match self.symbol(full_name) {
+            Some(Symbol::Enum(e)) => Some(e.clone()),
+            _ => None,
+        }
+    }
+
+    /// Find service by fully qualified name.
+    pub fn service_by_full_name(&self, full_name: &str) -> Option<ServiceDescriptor> {
+        match self.symbol(full_name) {
+            Some(Symbol::Service(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Find method by fully qualified name (e. g. `pkg.MyService.MyMethod`).
+    pub fn method_by_full_name(&self, full_name: &str) -> Option<MethodDescriptor> {
+        match self.symbol(full_name) {
+            Some(Symbol::Method(m)) => Some(m.clone()),
+            _ => None,
+        }
+    }
+
+    /// Find extension by fully qualified name (e. g. `pkg.my_ext` or `pkg.Message.my_ext`).
+    pub fn extension_by_full_name(&self, full_name: &str) -> Option<FieldDescriptor> {
+        match self.symbol(full_name) {
+            Some(Symbol::Extension(e)) => Some(e.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// All symbols defined in the file with their full names.
+fn file_symbols(file: &FileDescriptor) -> Vec<(String, Symbol)> {
+    fn add_message(m: MessageDescriptor, r: &mut Vec<(String, Symbol)>) {
+        for e in m.enums() {
+            r.push((e.full_name().to_owned(), Symbol::Enum(e)));
+        }
+        for e in m.extensions() {
+            r.push((e.full_name(), Symbol::Extension(e)));
+        }
+        for n in m.nested_messages() {
+            add_message(n, r);
+        }
+        r.push((m.full_name().to_owned(), Symbol::Message(m)));
+    }
+
+    let mut r = Vec::new();
+    for m in file.messages() {
+        add_message(m, &mut r);
+    }
+    for e in file.enums() {
+        r.push((e.full_name().to_owned(), Symbol::Enum(e)));
+    }
+    for e in file.extensions() {
+        r.push((e.full_name(), Symbol::Extension(e)));
+    }
+    for s in file.services() {
+        let service_name = concat_paths(file.proto().package(), s.proto().name());
+        for m in s.methods() {
+            r.push((
+                concat_paths(&service_name, m.proto().name()),
+                Symbol::Method(m),
+            ));
+        }
+        r.push((service_name, Symbol::Service(s)));
+    }
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::descriptor::FileDescriptorSet;
+    use crate::reflect::DescriptorPool;
+    use crate::MessageFull;
+
+    fn file(name: &str, package: &str, messages: &[&str], deps: &[&str]) -> FileDescriptorProto {
+        let mut file = FileDescriptorProto::new();
+        file.set_name(name.to_owned());
+        file.set_package(package.to_owned());
+        for &m in messages {
+            let mut message = DescriptorProto::new();
+            message.set_name(m.to_owned());
+            file.message_type.push(message);
+        }
+        file.dependency = deps.iter().map(|d| (*d).to_owned()).collect();
+        file
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn add_file() {
+        let mut pool = DescriptorPool::new();
+        pool.add_file(FileDescriptorProto::descriptor_static().file_descriptor())
+            .unwrap();
+        assert!(pool
+            .message_by_full_name(".google.protobuf.DescriptorProto.ExtensionRange")
+            .is_some());
+        assert!(pool
+            .enum_by_full_name("google.protobuf.FieldDescriptorProto.Type")
+            .is_some());
+        assert!(pool
+            .message_by_full_name("google.protobuf.FieldDescriptorProto.Type")
+            .is_none());
+        // Same file again is no-op.
+        pool.add_file(FileDescriptorProto::descriptor_static().file_descriptor())
+            .unwrap();
+        assert_eq!(1, pool.files().len());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn add_file_descriptor_set_in_any_order() {
+        let mut fds = FileDescriptorSet::new();
+        fds.file.push(file("b.proto", "b", &["B"], &["a.proto"]));
+        fds.file.push(file("a.proto", "a", &["A"], &[]));
+
+        let mut pool = DescriptorPool::new();
+        let files = pool.add_file_descriptor_set(fds).unwrap();
+        assert_eq!("b.proto", files[0].proto().name());
+        assert!(pool.message_by_full_name("a.A").is_some());
+        assert!(pool.message_by_full_name("b.B").is_some());
+
+        // Incrementally added file depends on the file in the pool.
+        pool.add_file_descriptor_proto(file("c.proto", "c", &["C"], &["b.proto"]))
+            .unwrap();
+        assert_eq!(3, pool.files().len());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn errors() {
+        let mut pool = DescriptorPool::new();
+        pool.add_file_descriptor_proto(file("a.proto", "a", &["A"], &[]))
+            .unwrap();
+
+        let e = pool
+            .add_file_descriptor_proto(file("b.proto", "b", &["B"], &["missing.proto"]))
+            .unwrap_err();
+        assert!(e.to_string().contains("missing.proto"), "{}", e);
+
+        let e = pool
+            .add_file_descriptor_proto(file("a2.proto", "a", &["A"], &[]))
+            .unwrap_err();
+        assert_eq!(
+            "Symbol `a.A` is defined in both `a.proto` and `a2.proto`",
+            e.to_string()
+        );
+
+        let e = pool
+            .add_file_descriptor_proto(file("a.proto", "a", &["A", "A2"], &[]))
+            .unwrap_err();
+        assert!(e.to_string().contains("a.proto"), "{}", e);
+
+        let mut fds = FileDescriptorSet::new();
+        fds.file.push(file("x.proto", "x", &[], &["y.proto"]));
+        fds.file.push(file("y.proto", "y", &[], &["x.proto"]));
+        assert!(pool.add_file_descriptor_set(fds).is_err());
+
+        // Files and symbols added before the error are removed.
+        let mut fds = FileDescriptorSet::new();
+        fds.file.push(file("g.proto", "g", &["G"], &[]));
+        fds.file.push(file("a2.proto", "a", &["A"], &[]));
+        assert!(pool.add_file_descriptor_set(fds).is_err());
+        assert!(pool.message_by_full_name("g.G").is_none());
+        assert!(pool.file_by_name("g.proto").is_none());
+        pool.add_file_descriptor_proto(file("g.proto", "g", &["G"], &[]))
+            .unwrap();
+
+        assert_eq!(2, pool.files().len());
+    }
+}
@@ -0,0 +1,414 @@
+use std::fmt;
+
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectMapRef;
+use crate::reflect::ReflectRepeatedRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::MessageDyn;
+
+/// Configurable reflective comparison of two messages of the same type.
+///
+/// Similar to `MessageDifferencer` of Google protobuf C++ library.
+/// [`diff`](MessageDifferencer::diff) lists differences by field path,
+/// [`equals`](MessageDifferencer::equals) is the same comparison as a predicate.
+///
+/// Unknown fields and extensions are not compared.
+///
+/// ```
+/// # use protobuf::MessageFull;
+/// # use protobuf::reflect::DifferenceKind;
+/// # use protobuf::reflect::MessageDifferencer;
+/// # use protobuf::reflect::ReflectValueBox;
+/// # use protobuf::well_known_types::ListValue;
+/// # use protobuf::well_known_types::Value;
+/// fn list(numbers: &[f64]) -> ListValue {
+///     let mut list = ListValue::new();
+///     for &n in numbers {
+///         let mut value = Value::new();
+///         value.set_number_value(n);
+///         list.values.push(value);
+///     }
+///     list
+/// }
+///
+/// let a = list(&[1.0, 2.0]);
+/// let b = list(&[2.0, 1.0]);
+///
+/// let mut differencer = MessageDifferencer::default();
+/// assert!(!differencer.equals(&a, &b));
+/// let diff = differencer.diff(&a, &b);
+/// assert_eq!("values[0].number_value", diff[0].path);
+/// assert_eq!(
+///     DifferenceKind::Modified(ReflectValueBox::F64(1.0), ReflectValueBox::F64(2.0)),
+///     diff[0].kind
+/// );
+///
+/// let values = ListValue::descriptor_static().field_by_name("values").unwrap();
+/// differencer.repeated_as_set.push(values);
+/// assert!(differencer.equals(&a, &b));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageDifferencer {
+    /// Repeated fields compared ignoring order of elements.
+    ///
+    /// Each element must have an equal element in the other message.
+    pub repeated_as_set: Vec<FieldDescriptor>,
+    /// Repeated message fields compared as maps: elements are matched
+    /// by the value of the key field (second in the pair) of the element message.
+    pub repeated_as_map: Vec<(FieldDescriptor, FieldDescriptor)>,
+    /// Fields which are not compared.
+    pub ignored_fields: Vec<FieldDescriptor>,
+    /// Floats are considered equal if they differ by at most
+    /// `float_margin + float_fraction * max(|a|, |b|)`.
+    ///
+    /// Default is zero, meaning exact comparison.
+    pub float_margin: f64,
+    /// See [`float_margin`](MessageDifferencer::float_margin).
+    pub float_fraction: f64,
+    /// When `true`, `NaN` values are considered equal to each other.
+    pub nan_equal: bool,
+    /// Prevent initializing `MessageDifferencer` enumerating all field.
+    pub _future_options: (),
+}
+
+/// Kind of [`Difference`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DifferenceKind {
+    /// Value is present only in the second message.
+    Added(ReflectValueBox),
+    /// Value is present only in the first message.
+    Removed(ReflectValueBox),
+    /// Values differ.
+    Modified(ReflectValueBox, ReflectValueBox),
+}
+
+/// Single difference found by [`MessageDifferencer::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// Path of the value, like `items[2].price` or `attrs["color"]`.
+    ///
+    /// Indices of repeated fields compared as lists or sets refer to
+    /// the message which contains the value (the second one for `Added`).
+    /// Repeated fields compared as maps are indexed by the key.
+    pub path: String,
+    /// What is different.
+    pub kind: DifferenceKind,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DifferenceKind::Added(v) => write!(f, "{}: added {:?}", self.path, v),
+            DifferenceKind::Removed(v) => write!(f, "{}: removed {:?}", self.path, v),
+            DifferenceKind::Modified(a, b) => {
+                write!(f, "{}: modified {:?} -> {:?}", self.path, a, b)
+            }
+        }
+    }
+}
+
+/// Path of the value being compared, formatted only when a difference is found.
+#[derive(Clone, Copy)]
+enum Path<'p> {
+    Root,
+    Field(&'p Path<'p>, &'p str),
+    Index(&'p Path<'p>, &'p dyn fmt::Debug),
+}
+
+impl<'p> fmt::Display for Path<'p> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Path::Root => Ok(()),
+            Path::Field(Path::Root, name) => write!(f, "{}", name),
+            Path::Field(parent, name) => write!(f, "{}.{}", parent, name),
+            Path::Index(parent, index) => write!(f, "{}[{:?}]", parent, index),
+        }
+    }
+}
+
+/// Where comparison results go.
+struct Report<'r> {
+    /// `None` when only equality is needed.
+    differences: Option<&'r mut Vec<Difference>>,
+    equal: bool,
+}
+
+impl<'r> Report<'r> {
+    /// Record a difference, return `true` if comparison should continue.
+    fn add(&mut self, path: &Path, kind: impl FnOnce() -> DifferenceKind) -> bool {
+        self.equal = false;
+        match &mut self.differences {
+            Some(differences) => {
+                differences.push(Difference {
+                    path: path.to_string(),
+                    kind: kind(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl MessageDifferencer {
+    /// List differences between two messages.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn diff(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        let mut report = Report {
+            differences: Some(&mut differences),
+            equal: true,
+        };
+        self.compare_messages(&Path::Root, a, b, &mut report);
+        differences
+    }
+
+    /// Check if messages are equal according to this configuration.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn equals(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> bool {
+        let mut report = Report {
+            differences: None,
+            equal: true,
+        };
+        self.compare_messages(&Path::Root, a, b, &mut report);
+        report.equal
+    }
+
+    fn values_equal(&self, a: &ReflectValueRef, b: &ReflectValueRef) -> bool {
+        let mut report = Report {
+            differences: None,
+            equal: true,
+        };
+        self.compare_values(&Path::Root, a, b, &mut report);
+        report.equal
+    }
+
+    fn floats_equal(&self, a: f64, b: f64) -> bool {
+        if a.is_nan() || b.is_nan() {
+            return a.is_nan() && b.is_nan() && self.nan_equal;
+        }
+        a == b || (a - b).abs() <= self.float_margin + self.float_fraction * a.abs().max(b.abs())
+    }
+
+    // All `compare_` functions return `true` if comparison should continue.
+
+    fn compare_messages(
+        &self,
+        path: &Path,
+        a: &dyn MessageDyn,
+        b: &dyn MessageDyn,
+        report: &mut Report,
+    ) -> bool {
+        let descriptor = a.descriptor_dyn();
+        assert_eq!(descriptor, b.descriptor_dyn());
+
+        for field in descriptor.fields() {
+            if self.ignored_fields.contains(&field) {
+                continue;
+            }
+            let path = Path::Field(path, field.name());
+            let cont = match (field.get_reflect(a), field.get_reflect(b)) {
+                (ReflectFieldRef::Optional(a), ReflectFieldRef::Optional(b)) => match (a, b) {
+                    (None, None) => true,
+                    (None, Some(b)) => report.add(&path, || DifferenceKind::Added(b.to_box())),
+                    (Some(a), None) => report.add(&path, || DifferenceKind::Removed(a.to_box())),
+                    (Some(a), Some(b)) => self.compare_values(&path, &a, &b, report),
+                },
+                (ReflectFieldRef::Repeated(a), ReflectFieldRef::Repeated(b)) => {
+                    self.compare_repeated(&field, &path, &a, &b, report)
+                }
+                (ReflectFieldRef::Map(a), ReflectFieldRef::Map(b)) => {
+                    self.compare_maps(&path, &a, &b, report)
+                }
+                _ => unreachable!(),
+            };
+            if !cont {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn compare_values(
+        &self,
+        path: &Path,
+        a: &ReflectValueRef,
+        b: &ReflectValueRef,
+        report: &mut Report,
+    ) -> bool {
+        let equal = match (a, b) {
+            (ReflectValueRef::Message(a), ReflectValueRef::Message(b)) => {
+                return self.compare_messages(path, &**a, &**b, report);
+            }
+            (ReflectValueRef::F32(a), ReflectValueRef::F32(b)) => {
+                self.floats_equal(*a as f64, *b as f64)
+            }
+            (ReflectValueRef::F64(a), ReflectValueRef::F64(b)) => self.floats_equal(*a, *b),
+            (a, b) => a == b,
+        };
+        equal || report.add(path, || DifferenceKind::Modified(a.to_box(), b.to_box()))
+    }
+
+    fn compare_repeated(
+        &self,
+        field: &FieldDescriptor,
+        path: &Path,
+        a: &ReflectRepeatedRef,
+        b: &ReflectRepeatedRef,
+        report: &mut Report,
+    ) -> bool {
+        if let Some((_, key)) = self.repeated_as_map.iter().find(|(f, _)| f == field) {
+            self.compare_repeated_as_map(key, path, a, b, report)
+        } else if self.repeated_as_set.contains(field) {
+            self.compare_repeated_as_set(path, a, b, report)
+        } else {
+            self.compare_repeated_as_list(path, a, b, report)
+        }
+    }
+
+    fn compare_repeated_as_list(
+        &self,
+        path: &Path,
+        a: &ReflectRepeatedRef,
+        b: &ReflectRepeatedRef,
+        report: &mut Report,
+    ) -> bool {
+        for i in 0..a.len().max(b.len()) {
+            let path = Path::Index(path, &i);
+            let cont = if i >= b.len() {
+                report.add(&path, || DifferenceKind::Removed(a.get(i).to_box()))
+            } else if i >= a.len() {
+                report.add(&path, || DifferenceKind::Added(b.get(i).to_box()))
+            } else {
+                self.compare_values(&path, &a.get(i), &b.get(i), report)
+            };
+            if !cont {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn compare_repeated_as_set(
+        &self,
+        path: &Path,
+        a: &ReflectRepeatedRef,
+        b: &ReflectRepeatedRef,
+        report: &mut Report,
+    ) -> bool {
+        let mut b_matched = vec![false; b.len()];
+        for i in 0..a.len() {
+            let av = a.get(i);
+            match (0..b.len()).find(|&j| !b_matched[j] && self.values_equal(&av, &b.get(j))) {
+                Some(j) => b_matched[j] = true,
+                None => {
+                    if !report.add(&Path::Index(path, &i), || {
+                        DifferenceKind::Removed(av.to_box())
+                    }) {
+                        return false;
+                    }
+                }
+            }
+        }
+        for (j, matched) in b_matched.into_iter().enumerate() {
+            if !matched
+                && !report.add(&Path::Index(path, &j), || {
+                    DifferenceKind::Added(b.get(j).to_box())
+                })
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn compare_repeated_as_map(
+        &self,
+        key: &FieldDescriptor,
+        path: &Path,
+        a: &ReflectRepeatedRef,
+        b: &ReflectRepeatedRef,
+        report: &mut Report,
+    ) -> bool {
+        let key_of = |v: &ReflectValueRef| match v {
+            ReflectValueRef::Message(m) => key.get_singular_field_or_default(&**m).to_box(),
+            _ => panic!("repeated field compared as map must be a message field"),
+        };
+        let b_keys: Vec<ReflectValueBox> = (0..b.len()).map(|j| key_of(&b.get(j))).collect();
+        let mut b_matched = vec![false; b.len()];
+        for i in 0..a.len() {
+            let av = a.get(i);
+            let a_key = key_of(&av);
+            let a_key_ref = a_key.as_value_ref();
+            let path = Path::Index(path, a_key_ref.map_key_debug());
+            let cont = match (0..b.len()).find(|&j| !b_matched[j] && b_keys[j] == a_key) {
+                Some(j) => {
+                    b_matched[j] = true;
+                    self.compare_values(&path, &av, &b.get(j), report)
+                }
+                None => report.add(&path, || DifferenceKind::Removed(av.to_box())),
+            };
+            if !cont {
+                return false;
+            }
+        }
+        for (j, matched) in b_matched.into_iter().enumerate() {
+            let b_key = b_keys[j].as_value_ref();
+            if !matched
+                && !report.add(&Path::Index(path, b_key.map_key_debug()), || {
+                    DifferenceKind::Added(b.get(j).to_box())
+                })
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn compare_maps(
+        &self,
+        path: &Path,
+        a: &ReflectMapRef,
+        b: &ReflectMapRef,
+        report: &mut Report,
+    ) -> bool {
+        // Sort to report differences in deterministic order.
+        let sort = report.differences.is_some();
+        let mut a_entries: Vec<_> = a.into_iter().collect();
+        if sort {
+            a_entries.sort_by(|(ka, _), (kb, _)| ka.cmp_map_key(kb));
+        }
+        for (k, av) in a_entries {
+            let path = Path::Index(path, k.map_key_debug());
+            let cont = match b.get(k.clone()) {
+                Some(bv) => self.compare_values(&path, &av, &bv, report),
+                None => report.add(&path, || DifferenceKind::Removed(av.to_box())),
+            };
+            if !cont {
+                return false;
+            }
+        }
+        let mut b_entries: Vec<_> = b.into_iter().collect();
+        if sort {
+            b_entries.sort_by(|(ka, _), (kb, _)| ka.cmp_map_key(kb));
+        }
+        for (k, bv) in b_entries {
+            if a.get(k.clone()).is_none()
+                && !report.add(&Path::Index(path, k.map_key_debug()), || {
+                    DifferenceKind::Added(bv.to_box())
+                })
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -6,6 +6,7 @@ use crate::reflect::map::ReflectMap;
 use crate::reflect::map::ReflectMapIter;
 use crate::reflect::map::ReflectMapIterTrait;
 use crate::reflect::runtime_types::RuntimeType;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
@@ -167,6 +168,22 @@ impl ReflectMap for DynamicMap {
         .map(ReflectValueBox::as_value_ref)
     }
 
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueMut<'a>> {
+        match (&mut self.maps, key) {
+            (Maps::U32(m), ReflectValueRef::U32(v)) => m.get_mut(&v),
+            (Maps::U64(m), ReflectValueRef::U64(v)) => m.get_mut(&v),
+            (Maps::I32(m), ReflectValueRef::I32(v)) => m.get_mut(&v),
+            (Maps::I64(m), ReflectValueRef::I64(v)) => m.get_mut(&v),
+            (Maps::Bool(m), ReflectValueRef::Bool(v)) => m.get_mut(&v),
+            (Maps::String(m), ReflectValueRef::String(v)) => m.get_mut(&*v),
+            _ => None,
+        }
+        .map(|v| match v {
+            ReflectValueBox::Message(m) => ReflectValueMut::Message(&mut **m),
+            _ => panic!("Expected message value"),
+        })
+    }
+
     fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
         assert!(value.get_type() == self.value);
         match (&mut self.maps, &key) {
@@ -183,6 +200,18 @@ impl ReflectMap for DynamicMap {
         };
     }
 
+    fn remove(&mut self, key: ReflectValueRef) {
+        match (&mut self.maps, key) {
+            (Maps::U32(m), ReflectValueRef::U32(v)) => m.remove(&v),
+            (Maps::U64(m), ReflectValueRef::U64(v)) => m.remove(&v),
+            (Maps::I32(m), ReflectValueRef::I32(v)) => m.remove(&v),
+            (Maps::I64(m), ReflectValueRef::I64(v)) => m.remove(&v),
+            (Maps::Bool(m), ReflectValueRef::Bool(v)) => m.remove(&v),
+            (Maps::String(m), ReflectValueRef::String(v)) => m.remove(&*v),
+            _ => None,
+        };
+    }
+
     fn clear(&mut self) {
         self.maps.clear()
     }
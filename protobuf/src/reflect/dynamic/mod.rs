@@ -8,6 +8,8 @@ use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::map::DynamicMap;
 use crate::reflect::dynamic::optional::DynamicOptional;
 use crate::reflect::dynamic::repeated::DynamicRepeated;
+use crate::reflect::field::extension;
+use crate::reflect::field::FieldDescriptorImpl;
 use crate::reflect::map::ReflectMap;
 use crate::reflect::protobuf_type_box::ProtobufTypeBox;
 use crate::reflect::repeated::ReflectRepeated;
@@ -23,9 +25,11 @@ use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeFieldType;
 use crate::reflect::Syntax;
+use crate::rt;
 use crate::rt::bytes_size;
 use crate::rt::compute_raw_varint32_size;
 use crate::rt::compute_raw_varint64_size;
+use crate::rt::group_size;
 use crate::rt::map::read_map_template;
 use crate::rt::string_size;
 use crate::rt::tag_size;
@@ -87,6 +91,9 @@ pub(crate) struct DynamicMessage {
     /// Fields by index in the description.
     /// This field is lazy-init: it is empty when created.
     fields: Box<[DynamicFieldValue]>,
+    /// Extension fields which were accessed mutably.
+    /// Other extension values are stored in unknown fields.
+    extensions: Vec<(FieldDescriptor, DynamicFieldValue)>,
     unknown_fields: UnknownFields,
     _cached_size: CachedSize,
 }
@@ -96,6 +103,7 @@ impl DynamicMessage {
         DynamicMessage {
             descriptor,
             fields: Vec::new().into_boxed_slice(),
+            extensions: Vec::new(),
             unknown_fields: UnknownFields::new(),
             _cached_size: CachedSize::new(),
         }
@@ -112,7 +120,10 @@ impl DynamicMessage {
     }
 
     pub(crate) fn get_reflect<'a>(&'a self, field: &FieldDescriptor) -> ReflectFieldRef<'a> {
-        let (descriptor, index) = field.regular();
+        let (descriptor, index) = match &field.imp {
+            FieldDescriptorImpl::Field(descriptor, index) => (descriptor, *index),
+            _ => return self.get_extension(field),
+        };
         assert_eq!(&self.descriptor, descriptor);
         if self.fields.is_empty() {
             ReflectFieldRef::default_for_field(field)
@@ -121,8 +132,24 @@ impl DynamicMessage {
         }
     }
 
+    fn get_extension<'a>(&'a self, field: &FieldDescriptor) -> ReflectFieldRef<'a> {
+        assert_eq!(self.descriptor, field.containing_message());
+        match self.extensions.iter().find(|(f, _)| f == field) {
+            Some((_, v)) => v.as_ref(),
+            None => extension::get_reflect(field, &self.unknown_fields),
+        }
+    }
+
     pub fn clear_field(&mut self, field: &FieldDescriptor) {
-        let (descriptor, index) = field.regular();
+        let (descriptor, index) = match &field.imp {
+            FieldDescriptorImpl::Field(descriptor, index) => (descriptor, *index),
+            _ => {
+                assert_eq!(self.descriptor, field.containing_message());
+                self.extensions.retain(|(f, _)| f != field);
+                self.unknown_fields.remove(field.proto().number() as u32);
+                return;
+            }
+        };
         assert_eq!(&self.descriptor, descriptor);
         if self.fields.is_empty() {
             return;
@@ -131,6 +158,53 @@ impl DynamicMessage {
         self.fields[index].clear();
     }
 
+    fn mut_field_value(&mut self, field: &FieldDescriptor) -> &mut DynamicFieldValue {
+        match &field.imp {
+            FieldDescriptorImpl::Field(descriptor, index) => {
+                assert_eq!(&self.descriptor, descriptor);
+                self.init_fields();
+                &mut self.fields[*index]
+            }
+            _ => self.mut_extension(field),
+        }
+    }
+
+    /// Move extension value from unknown fields to extension fields.
+    fn mut_extension(&mut self, field: &FieldDescriptor) -> &mut DynamicFieldValue {
+        assert_eq!(self.descriptor, field.containing_message());
+        let index = match self.extensions.iter().position(|(f, _)| f == field) {
+            Some(index) => index,
+            None => {
+                let mut value = DynamicFieldValue::default_for_field(field);
+                match (
+                    extension::get_reflect(field, &self.unknown_fields),
+                    &mut value,
+                ) {
+                    (ReflectFieldRef::Optional(Some(v)), DynamicFieldValue::Singular(s)) => {
+                        s.set(v.to_box())
+                    }
+                    (ReflectFieldRef::Repeated(r), DynamicFieldValue::Repeated(d)) => {
+                        for v in &r {
+                            d.push(v.to_box());
+                        }
+                    }
+                    _ => {}
+                }
+                self.unknown_fields.remove(field.proto().number() as u32);
+                self.extensions.push((field.clone(), value));
+                self.extensions.len() - 1
+            }
+        };
+        &mut self.extensions[index].1
+    }
+
+    fn extension_by_number(&self, number: u32) -> Option<FieldDescriptor> {
+        self.extensions
+            .iter()
+            .find(|(f, _)| f.proto().number() as u32 == number)
+            .map(|(f, _)| f.clone())
+    }
+
     fn clear_oneof_group_fields_except(&mut self, field: &FieldDescriptor) {
         if let Some(oneof) = field.containing_oneof() {
             for next in oneof.fields() {
@@ -146,12 +220,9 @@ impl DynamicMessage {
         &'a mut self,
         field: &FieldDescriptor,
     ) -> ReflectValueMut<'a> {
-        let (descriptor, index) = field.regular();
-        assert_eq!(&self.descriptor, descriptor);
-        self.init_fields();
         self.clear_oneof_group_fields_except(field);
         // TODO: reset oneof group fields
-        match &mut self.fields[index] {
+        match self.mut_field_value(field) {
             DynamicFieldValue::Singular(f) => f.mut_or_default(),
             _ => panic!("Not a singular field"),
         }
@@ -161,38 +232,34 @@ impl DynamicMessage {
         &'a mut self,
         field: &FieldDescriptor,
     ) -> ReflectRepeatedMut<'a> {
-        let (descriptor, index) = field.regular();
-        assert_eq!(&self.descriptor, descriptor);
-        self.init_fields();
         // TODO: reset oneof group fields
-        match &mut self.fields[index] {
+        match self.mut_field_value(field) {
             DynamicFieldValue::Repeated(r) => ReflectRepeatedMut::new(r),
             _ => panic!("Not a repeated field: {}", field),
         }
     }
 
     pub(crate) fn mut_map<'a>(&'a mut self, field: &FieldDescriptor) -> ReflectMapMut<'a> {
-        let (descriptor, index) = field.regular();
-        assert_eq!(&self.descriptor, descriptor);
-        self.init_fields();
         // TODO: reset oneof group fields
-        match &mut self.fields[index] {
+        match self.mut_field_value(field) {
             DynamicFieldValue::Map(m) => ReflectMapMut::new(m),
             _ => panic!("Not a map field: {}", field),
         }
     }
 
     pub(crate) fn set_field(&mut self, field: &FieldDescriptor, value: ReflectValueBox) {
-        let (descriptor, index) = field.regular();
-        assert_eq!(&self.descriptor, descriptor);
-        self.init_fields();
-        // TODO: reset oneof group fields
-        match &mut self.fields[index] {
+        self.clear_oneof_group_fields_except(field);
+        match self.mut_field_value(field) {
             DynamicFieldValue::Singular(s) => s.set(value),
             _ => panic!("Not a singular field: {}", field),
         }
     }
 
+    /// Extension fields stored in this message (not in unknown fields).
+    pub(crate) fn extension_fields(&self) -> impl Iterator<Item = FieldDescriptor> + '_ {
+        self.extensions.iter().map(|(f, _)| f.clone())
+    }
+
     pub(crate) fn downcast_ref(message: &dyn MessageDyn) -> &DynamicMessage {
         assert!(Any::type_id(&*message) == TypeId::of::<DynamicMessage>());
         unsafe { &*(message as *const dyn MessageDyn as *const DynamicMessage) }
@@ -208,13 +275,16 @@ impl DynamicMessage {
         handler: &mut impl ForEachSingularFieldToWrite,
     ) -> crate::Result<()> {
         let is_proto3 = self.descriptor.file_descriptor().syntax() == Syntax::Proto3;
-        for field_desc in self.descriptor.fields() {
+        for field_desc in self.descriptor.fields().chain(self.extension_fields()) {
             let field_number = field_desc.proto().number() as u32;
             match field_desc.runtime_field_type() {
                 RuntimeFieldType::Singular(..) => {
                     if let Some(v) = field_desc.get_singular(self) {
-                        // Ignore default value for proto3.
-                        if !is_proto3 || v.is_non_zero() {
+                        // Ignore default value for proto3 fields without presence.
+                        let has_presence = !is_proto3
+                            || field_desc.is_extension()
+                            || field_desc.containing_oneof_including_synthetic().is_some();
+                        if has_presence || v.is_non_zero() {
                             handler.field(field_desc.proto().field_type(), field_number, &v)?;
                         }
                     }
@@ -237,7 +307,11 @@ impl DynamicMessage {
                 RuntimeFieldType::Map(_, _) => {
                     let map = field_desc.get_map(self);
                     let (key_type, value_type) = field_desc.map_proto_type();
-                    for (k, v) in &map {
+                    let mut entries: Vec<_> = (&map).into_iter().collect();
+                    if handler.sort_map_entries() {
+                        entries.sort_by(|(a, _), (b, _)| a.cmp_map_key(b));
+                    }
+                    for (k, v) in entries {
                         handler.map_field_entry(
                             field_number,
                             &k,
@@ -256,6 +330,10 @@ impl DynamicMessage {
 }
 
 trait ForEachSingularFieldToWrite {
+    /// Visit map entries ordered by key.
+    fn sort_map_entries(&self) -> bool {
+        false
+    }
     fn field(&mut self, t: Type, number: u32, value: &ReflectValueRef) -> crate::Result<()>;
     fn repeated_packed(
         &mut self,
@@ -288,7 +366,7 @@ impl MessageDyn for DynamicMessage {
 
     fn is_initialized_dyn(&self) -> bool {
         // TODO: this check can be much faster for proto3 without contained proto2 messages.
-        for f in self.descriptor.fields() {
+        for f in self.descriptor.fields().chain(self.extension_fields()) {
             let fv = self.get_reflect(&f);
             match fv {
                 ReflectFieldRef::Optional(s) => match s {
@@ -323,10 +401,64 @@ impl MessageDyn for DynamicMessage {
         true
     }
 
+    fn collect_missing_required_fields_dyn(&self, prefix: &str, missing: &mut Vec<String>) {
+        for f in self.descriptor.fields().chain(self.extension_fields()) {
+            let name = match f.is_extension() {
+                true => format!("[{}]", f.full_name()),
+                false => f.name().to_owned(),
+            };
+            match self.get_reflect(&f) {
+                ReflectFieldRef::Optional(s) => match s {
+                    None => {
+                        if f.is_required() {
+                            rt::push_missing_required_field(missing, prefix, &name);
+                        }
+                    }
+                    Some(ReflectValueRef::Message(m)) => {
+                        m.collect_missing_required_fields_dyn(
+                            &rt::nested_field_path(prefix, &name),
+                            missing,
+                        );
+                    }
+                    Some(_) => {}
+                },
+                ReflectFieldRef::Repeated(r) => {
+                    for (i, v) in (&r).into_iter().enumerate() {
+                        if let ReflectValueRef::Message(m) = v {
+                            m.collect_missing_required_fields_dyn(
+                                &rt::nested_repeated_field_path(prefix, &name, i),
+                                missing,
+                            );
+                        }
+                    }
+                }
+                ReflectFieldRef::Map(m) => {
+                    for (k, v) in &m {
+                        if let ReflectValueRef::Message(m) = v {
+                            m.collect_missing_required_fields_dyn(
+                                &rt::nested_map_field_path(prefix, &name, k.map_key_debug()),
+                                missing,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn merge_from_dyn(&mut self, is: &mut CodedInputStream) -> crate::Result<()> {
         while !is.eof()? {
             let (field, wire_type) = is.read_tag_unpack()?;
-            let field_desc = match self.descriptor.field_by_number(field) {
+            // Group field may have the same number as the group.
+            if wire_type == WireType::EndGroup {
+                is.end_group(field)?;
+                continue;
+            }
+            let field_desc = match self
+                .descriptor
+                .field_by_number(field)
+                .or_else(|| self.extension_by_number(field))
+            {
                 Some(f) => f,
                 None => {
                     read_unknown_or_skip_group_with_tag_unpacked(
@@ -341,13 +473,14 @@ impl MessageDyn for DynamicMessage {
             match field_desc.runtime_field_type() {
                 RuntimeFieldType::Singular(rtb) => {
                     let pt = ProtobufTypeBox::new(rtb, field_desc.proto().field_type())?;
-                    let value = pt.read(is, wire_type)?;
+                    let value = pt.read(is, field, wire_type)?;
                     self.set_field(&field_desc, value);
                 }
                 RuntimeFieldType::Repeated(rtb) => {
                     let pt = ProtobufTypeBox::new(rtb, field_desc.proto().field_type())?;
                     let mut repeated = self.mut_repeated(&field_desc);
-                    pt.read_repeated_into(is, wire_type, &mut repeated)?;
+                    pt.read_repeated_into(is, field, wire_type, &mut repeated)?;
+                    is.check_repeated_len(repeated.len())?;
                 }
                 RuntimeFieldType::Map(..) => {
                     let (key_type, value_type) = field_desc.map_proto_type();
@@ -358,15 +491,16 @@ impl MessageDyn for DynamicMessage {
                         wire_type,
                         is,
                         |wire_type, is| {
-                            key = key_type.read(is, wire_type)?;
+                            key = key_type.read(is, 1, wire_type)?;
                             Ok(())
                         },
                         |wire_type, is| {
-                            value = value_type.read(is, wire_type)?;
+                            value = value_type.read(is, 2, wire_type)?;
                             Ok(())
                         },
                     )?;
                     map.insert(key, value);
+                    is.check_map_len(map.len())?;
                 }
             }
         }
@@ -379,6 +513,10 @@ impl MessageDyn for DynamicMessage {
         }
 
         impl<'a, 'o> ForEachSingularFieldToWrite for Handler<'a, 'o> {
+            fn sort_map_entries(&self) -> bool {
+                self.os.is_deterministic()
+            }
+
             fn field(
                 &mut self,
                 t: Type,
@@ -488,7 +626,7 @@ impl MessageDyn for DynamicMessage {
 }
 
 /// Write singular field to output stream
-fn singular_write_to(
+pub(crate) fn singular_write_to(
     proto_type: Type,
     field_number: u32,
     v: &ReflectValueRef,
@@ -504,7 +642,8 @@ fn singular_write_to(
             os.write_message_dyn(field_number, &*msg_v)
         }
         Type::TYPE_GROUP => {
-            unimplemented!()
+            let msg_v = v.to_message().unwrap();
+            os.write_group_dyn(field_number, &*msg_v)
         }
         Type::TYPE_UINT32 => os.write_uint32(field_number, v.to_u32().unwrap()),
         Type::TYPE_UINT64 => os.write_uint64(field_number, v.to_u64().unwrap()),
@@ -525,7 +664,11 @@ fn singular_write_to(
 }
 
 /// Compute singular field size
-fn compute_singular_size(proto_type: Type, field_number: u32, v: &ReflectValueRef) -> u64 {
+pub(crate) fn compute_singular_size(
+    proto_type: Type,
+    field_number: u32,
+    v: &ReflectValueRef,
+) -> u64 {
     match proto_type {
         Type::TYPE_ENUM => {
             let enum_v = v.to_enum_value().unwrap();
@@ -537,7 +680,8 @@ fn compute_singular_size(proto_type: Type, field_number: u32, v: &ReflectValueRe
             tag_size(field_number) + compute_raw_varint64_size(len) + len
         }
         Type::TYPE_GROUP => {
-            unimplemented!()
+            let msg_v = v.to_message().unwrap();
+            group_size(field_number, msg_v.compute_size_dyn())
         }
         Type::TYPE_UINT32 => {
             let typed_v = v.to_u32().unwrap();
@@ -1,5 +1,6 @@
 use crate::reflect::repeated::ReflectRepeated;
 use crate::reflect::repeated::ReflectRepeatedIter;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::MessageDescriptor;
 use crate::reflect::MessageRef;
@@ -125,6 +126,51 @@ impl ReflectRepeated for DynamicRepeated {
         }
     }
 
+    fn remove(&mut self, index: usize) {
+        match self {
+            DynamicRepeated::U32(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::U64(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::I32(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::I64(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::F32(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::F64(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::Bool(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::String(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::Bytes(vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::Enum(_descriptor, vs) => {
+                vs.remove(index);
+            }
+            DynamicRepeated::Message(_descriptor, vs) => {
+                vs.remove(index);
+            }
+        }
+    }
+
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut {
+        match self {
+            DynamicRepeated::Message(_descriptor, vs) => ReflectValueMut::Message(&mut *vs[index]),
+            _ => panic!("Expected message value"),
+        }
+    }
+
     fn clear(&mut self) {
         match self {
             DynamicRepeated::U32(vs) => vs.clear(),
@@ -0,0 +1,34 @@
+use crate::descriptor::field_descriptor_proto::Type;
+
+/// Errors when building or resolving descriptors.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ReflectError {
+    #[error("Message `{}` not found in files: {}", .0, .1)]
+    MessageNotFoundInFiles(String, String),
+    #[error("Enum `{}` not found in files: {}", .0, .1)]
+    EnumNotFoundInFiles(String, String),
+    #[error("Enum value `{}` for default value of field `{}` not found", .0, .1)]
+    DefaultValueEnumValueNotFound(String, String),
+    #[error("Field `{}` of type {:?} cannot have a default value", .0, .1)]
+    FieldCannotHaveDefaultValue(String, Type),
+    #[error("Non-unique field number {} in message `{}`", .1, .0)]
+    NonUniqueFieldNumber(String, i32),
+    #[error("Non-unique field name `{}` in message `{}`", .1, .0)]
+    NonUniqueFieldName(String, String),
+    #[error("Incorrect map entry message `{}`", .0)]
+    IncorrectMapEntry(String),
+    #[error("Dependency `{}` of `{}` not found; given dependencies: {}", .0, .1, .2)]
+    DependencyNotFound(String, String, String),
+    #[error("Cycle in file dependencies: {}", .0)]
+    CycleInFileDependencies(String),
+    #[error("File `{}` is already added with different content", .0)]
+    NonUniqueFileDescriptor(String),
+    #[error("Symbol `{}` is defined in both `{}` and `{}`", .0, .1, .2)]
+    DuplicateSymbol(String, String, String),
+    #[error("Field mask path `{}` is not valid for message `{}`", .0, .1)]
+    InvalidFieldMaskPath(String, String),
+    #[error("Group extension `{}` cannot be stored in unknown fields of a generated message", .0)]
+    GroupExtensionInUnknownFields(String),
+    #[error("Invalid field path `{}` for message `{}`: {}", .0, .1, .2)]
+    InvalidFieldPath(String, String, &'static str),
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::reflect::FieldDescriptor;
+use crate::reflect::FileDescriptor;
+use crate::reflect::MessageDescriptor;
+
+/// Set of extension fields which can be looked up by extended message.
+///
+/// Extension values are stored in message unknown fields, and can be accessed
+/// with reflection once extension [`FieldDescriptor`] is found.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::reflect::ExtensionRegistry;
+///
+/// let mut registry = ExtensionRegistry::new();
+/// registry.add_file(&protobuf::rustproto::file_descriptor());
+/// let ext = registry
+///     .find_by_name("google.protobuf.FileOptions", "rustproto.lite_runtime_all")
+///     .unwrap();
+/// assert_eq!(17035, ext.proto().number());
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct ExtensionRegistry {
+    /// Extensions by extendee full name and field number.
+    extensions: HashMap<String, BTreeMap<u32, FieldDescriptor>>,
+}
+
+impl ExtensionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> ExtensionRegistry {
+        ExtensionRegistry::default()
+    }
+
+    /// Register an extension field.
+    ///
+    /// Extension with the same number previously registered
+    /// for the same message is replaced.
+    ///
+    /// # Panics
+    ///
+    /// If the field is not an extension.
+    pub fn add_extension(&mut self, field: FieldDescriptor) {
+        assert!(field.is_extension(), "not an extension: {}", field);
+        self.extensions
+            .entry(field.containing_message().full_name().to_owned())
+            .or_default()
+            .insert(field.proto().number() as u32, field);
+    }
+
+    /// Register all extensions (including extensions declared in messages)
+    /// defined in the file and all its dependencies.
+    pub fn add_file(&mut self, file: &FileDescriptor) {
+        for file in file.all_files() {
+            for field in file.extensions() {
+                self.add_extension(field);
+            }
+            for i in 0..file.index().index.messages.len() {
+                for field in MessageDescriptor::new(file.clone(), i).extensions() {
+                    self.add_extension(field);
+                }
+            }
+        }
+    }
+
+    /// Find extension of a message by field number.
+    ///
+    /// `extendee` is fully qualified message name (e. g. `google.protobuf.FileOptions`).
+    pub fn find_by_number(&self, extendee: &str, number: u32) -> Option<FieldDescriptor> {
+        self.extensions.get(extendee)?.get(&number).cloned()
+    }
+
+    /// Find extension of a message by fully qualified extension name
+    /// (e. g. `rustproto.lite_runtime_all`).
+    ///
+    /// `extendee` is fully qualified message name (e. g. `google.protobuf.FileOptions`).
+    pub fn find_by_name(&self, extendee: &str, full_name: &str) -> Option<FieldDescriptor> {
+        self.extensions
+            .get(extendee)?
+            .values()
+            .find(|f| f.full_name() == full_name)
+            .cloned()
+    }
+
+    /// All registered extensions of a message ordered by field number.
+    pub fn extensions_of(&self, extendee: &str) -> Vec<FieldDescriptor> {
+        match self.extensions.get(extendee) {
+            Some(extensions) => extensions.values().cloned().collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::FieldOptions;
+    use crate::reflect::ExtensionRegistry;
+    use crate::rustproto;
+    use crate::MessageFull;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn add_file() {
+        let mut registry = ExtensionRegistry::new();
+        registry.add_file(&rustproto::file_descriptor());
+
+        let ext = registry
+            .find_by_number(FieldOptions::descriptor_static().full_name(), 17003)
+            .unwrap();
+        assert_eq!("rustproto.expose_fields_field", ext.full_name());
+        assert_eq!(FieldOptions::descriptor_static(), ext.containing_message());
+        assert_eq!(
+            Some(ext.clone()),
+            registry.find_by_name(
+                "google.protobuf.FieldOptions",
+                "rustproto.expose_fields_field"
+            )
+        );
+        assert!(registry
+            .extensions_of("google.protobuf.FieldOptions")
+            .contains(&ext));
+        assert!(registry
+            .find_by_number("google.protobuf.FieldOptions", 1)
+            .is_none());
+        assert!(registry.extensions_of("google.protobuf.Empty").is_empty());
+    }
+}
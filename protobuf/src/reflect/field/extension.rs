@@ -0,0 +1,190 @@
+//! Reflective access to extension values stored in unknown fields.
+
+use std::str;
+
+use crate::descriptor::field_descriptor_proto::Type;
+use crate::ext::write_to_unknown_fields;
+use crate::reflect::dynamic::compute_singular_size;
+use crate::reflect::dynamic::singular_write_to;
+use crate::reflect::error::ReflectError;
+use crate::reflect::field::protobuf_field_type::ProtobufFieldType;
+use crate::reflect::protobuf_type_box::ProtobufTypeBox;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageRef;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectRepeatedRef;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeTypeBox;
+use crate::unknown::UnknownValueRef;
+use crate::unknown::UnknownValues;
+use crate::wire_format::WireType;
+use crate::zigzag::decode_zig_zag_32;
+use crate::zigzag::decode_zig_zag_64;
+use crate::CodedInputStream;
+use crate::UnknownFields;
+
+/// Decode extension value from unknown fields.
+///
+/// Values which cannot be decoded are skipped.
+pub(crate) fn get_reflect<'a>(
+    field: &FieldDescriptor,
+    unknown_fields: &'a UnknownFields,
+) -> ReflectFieldRef<'a> {
+    let values = unknown_fields.get(field.proto().number() as u32);
+    match field.protobuf_field_type() {
+        ProtobufFieldType::Singular(t) => {
+            ReflectFieldRef::Optional(values.and_then(|values| decode_singular(&t, values)))
+        }
+        ProtobufFieldType::Repeated(t) => {
+            ReflectFieldRef::Repeated(ReflectRepeatedRef::new_decoded(
+                t.runtime().clone(),
+                values
+                    .map(|values| decode_repeated(&t, values))
+                    .unwrap_or_default(),
+            ))
+        }
+        ProtobufFieldType::Map(..) => panic!("extension cannot be a map: {}", field),
+    }
+}
+
+/// Replace extension values in unknown fields.
+pub(crate) fn set_values(
+    field: &FieldDescriptor,
+    unknown_fields: &mut UnknownFields,
+    values: &[ReflectValueRef],
+) -> crate::Result<()> {
+    check_not_group(field)?;
+    unknown_fields.remove(field.proto().number() as u32);
+    append_values(field, unknown_fields, values)
+}
+
+/// Append extension values to unknown fields.
+pub(crate) fn append_values(
+    field: &FieldDescriptor,
+    unknown_fields: &mut UnknownFields,
+    values: &[ReflectValueRef],
+) -> crate::Result<()> {
+    check_not_group(field)?;
+    let field_number = field.proto().number() as u32;
+    let t = field.proto().field_type();
+    write_to_unknown_fields(unknown_fields, |os| {
+        for value in values {
+            // Computes and caches message sizes.
+            compute_singular_size(t, field_number, value);
+            singular_write_to(t, field_number, value, os)?;
+        }
+        Ok(())
+    })
+}
+
+/// Groups are skipped when parsing, so they cannot be stored in unknown fields.
+fn check_not_group(field: &FieldDescriptor) -> crate::Result<()> {
+    if field.proto().field_type() == Type::TYPE_GROUP {
+        return Err(ReflectError::GroupExtensionInUnknownFields(field.to_string()).into());
+    }
+    Ok(())
+}
+
+fn decode_singular<'a>(
+    t: &ProtobufTypeBox,
+    values: &'a UnknownValues,
+) -> Option<ReflectValueRef<'a>> {
+    match t.runtime() {
+        // Multiple occurrences of a message are merged.
+        RuntimeTypeBox::Message(m) if t.t() == Type::TYPE_MESSAGE => {
+            if values.length_delimited.is_empty() {
+                return None;
+            }
+            let mut message = m.new_instance();
+            for bytes in &values.length_delimited {
+                message.merge_from_bytes_dyn(bytes).ok()?;
+            }
+            Some(ReflectValueRef::Message(MessageRef::owned(message)))
+        }
+        _ => decode_repeated(t, values).pop(),
+    }
+}
+
+fn decode_repeated<'a>(t: &ProtobufTypeBox, values: &'a UnknownValues) -> Vec<ReflectValueRef<'a>> {
+    let mut r = Vec::new();
+    for value in values {
+        decode_value(t, value, &mut r);
+    }
+    r
+}
+
+fn decode_value<'a>(
+    t: &ProtobufTypeBox,
+    value: UnknownValueRef<'a>,
+    r: &mut Vec<ReflectValueRef<'a>>,
+) {
+    match (t.t(), value) {
+        (Type::TYPE_STRING, UnknownValueRef::LengthDelimited(bytes)) => {
+            if let Ok(s) = str::from_utf8(bytes) {
+                r.push(ReflectValueRef::String(s));
+            }
+        }
+        (Type::TYPE_BYTES, UnknownValueRef::LengthDelimited(bytes)) => {
+            r.push(ReflectValueRef::Bytes(bytes));
+        }
+        (Type::TYPE_MESSAGE, UnknownValueRef::LengthDelimited(bytes)) => {
+            if let RuntimeTypeBox::Message(m) = t.runtime() {
+                if let Ok(message) = m.parse_from_bytes(bytes) {
+                    r.push(ReflectValueRef::Message(MessageRef::owned(message)));
+                }
+            }
+        }
+        (Type::TYPE_STRING, _) | (Type::TYPE_BYTES, _) | (Type::TYPE_MESSAGE, _) => {}
+        // Groups are not preserved in unknown fields.
+        (Type::TYPE_GROUP, _) => {}
+        (_, UnknownValueRef::LengthDelimited(bytes)) => {
+            // Packed repeated field.
+            let mut is = CodedInputStream::from_bytes(bytes);
+            let wire_type = WireType::for_type(t.t());
+            while let Ok(false) = is.eof() {
+                let value = match wire_type {
+                    WireType::Varint => is.read_raw_varint64().map(UnknownValueRef::Varint),
+                    WireType::Fixed32 => {
+                        is.read_raw_little_endian32().map(UnknownValueRef::Fixed32)
+                    }
+                    WireType::Fixed64 => {
+                        is.read_raw_little_endian64().map(UnknownValueRef::Fixed64)
+                    }
+                    _ => return,
+                };
+                match value {
+                    Ok(value) => r.extend(decode_scalar(t, value)),
+                    Err(_) => return,
+                }
+            }
+        }
+        (_, value) => r.extend(decode_scalar(t, value)),
+    }
+}
+
+fn decode_scalar(t: &ProtobufTypeBox, value: UnknownValueRef) -> Option<ReflectValueRef<'static>> {
+    Some(match (t.t(), value) {
+        (Type::TYPE_INT32, UnknownValueRef::Varint(v)) => ReflectValueRef::I32(v as i32),
+        (Type::TYPE_INT64, UnknownValueRef::Varint(v)) => ReflectValueRef::I64(v as i64),
+        (Type::TYPE_UINT32, UnknownValueRef::Varint(v)) => ReflectValueRef::U32(v as u32),
+        (Type::TYPE_UINT64, UnknownValueRef::Varint(v)) => ReflectValueRef::U64(v),
+        (Type::TYPE_SINT32, UnknownValueRef::Varint(v)) => {
+            ReflectValueRef::I32(decode_zig_zag_32(v as u32))
+        }
+        (Type::TYPE_SINT64, UnknownValueRef::Varint(v)) => {
+            ReflectValueRef::I64(decode_zig_zag_64(v))
+        }
+        (Type::TYPE_BOOL, UnknownValueRef::Varint(v)) => ReflectValueRef::Bool(v != 0),
+        (Type::TYPE_ENUM, UnknownValueRef::Varint(v)) => match t.runtime() {
+            RuntimeTypeBox::Enum(e) => ReflectValueRef::Enum(e.clone(), v as i32),
+            _ => unreachable!(),
+        },
+        (Type::TYPE_FIXED32, UnknownValueRef::Fixed32(v)) => ReflectValueRef::U32(v),
+        (Type::TYPE_SFIXED32, UnknownValueRef::Fixed32(v)) => ReflectValueRef::I32(v as i32),
+        (Type::TYPE_FLOAT, UnknownValueRef::Fixed32(v)) => ReflectValueRef::F32(f32::from_bits(v)),
+        (Type::TYPE_FIXED64, UnknownValueRef::Fixed64(v)) => ReflectValueRef::U64(v),
+        (Type::TYPE_SFIXED64, UnknownValueRef::Fixed64(v)) => ReflectValueRef::I64(v as i64),
+        (Type::TYPE_DOUBLE, UnknownValueRef::Fixed64(v)) => ReflectValueRef::F64(f64::from_bits(v)),
+        _ => return None,
+    })
+}
@@ -2,6 +2,7 @@ use crate::descriptor::field_descriptor_proto;
 use crate::descriptor::field_descriptor_proto::Type;
 use crate::descriptor::FieldDescriptorProto;
 use crate::json::json_name;
+use crate::reflect::error::ReflectError;
 use crate::reflect::field::protobuf_field_type::ProtobufFieldType;
 use crate::reflect::file::building::FileDescriptorBuilding;
 use crate::reflect::protobuf_type_box::ProtobufTypeBox;
@@ -92,8 +93,8 @@ impl FieldIndex {
     fn enum_default_value(
         field: &FieldDescriptorProto,
         building: &FileDescriptorBuilding,
-    ) -> FieldDefaultValue {
-        let en = building.find_enum(field.type_name());
+    ) -> crate::Result<FieldDefaultValue> {
+        let en = building.find_enum(field.type_name())?;
         let (n, _) = match en
             .value
             .iter()
@@ -101,35 +102,46 @@ impl FieldIndex {
             .find(|(_n, v)| v.name() == field.default_value())
         {
             Some(v) => v,
-            None => panic!(
-                "enum value not found a default value: {}",
-                field.default_value()
-            ),
+            None => {
+                return Err(ReflectError::DefaultValueEnumValueNotFound(
+                    field.default_value().to_owned(),
+                    field.name().to_owned(),
+                )
+                .into())
+            }
         };
-        FieldDefaultValue::Enum(n)
+        Ok(FieldDefaultValue::Enum(n))
     }
 
     fn parse_default_value(
         field: &FieldDescriptorProto,
         building: &FileDescriptorBuilding,
-    ) -> FieldDefaultValue {
-        FieldDefaultValue::ReflectValueBox(match field.field_type() {
-            t @ field_descriptor_proto::Type::TYPE_GROUP
-            | t @ field_descriptor_proto::Type::TYPE_MESSAGE => {
-                panic!("{:?} cannot have a default value", t)
-            }
-            field_descriptor_proto::Type::TYPE_ENUM => {
-                return Self::enum_default_value(field, building)
-            }
-            t => {
-                RuntimeTypeBox::from_proto_type(t).parse_proto_default_value(field.default_value())
-            }
-        })
+    ) -> crate::Result<FieldDefaultValue> {
+        Ok(FieldDefaultValue::ReflectValueBox(
+            match field.field_type() {
+                t @ field_descriptor_proto::Type::TYPE_GROUP
+                | t @ field_descriptor_proto::Type::TYPE_MESSAGE => {
+                    return Err(ReflectError::FieldCannotHaveDefaultValue(
+                        field.name().to_owned(),
+                        t,
+                    )
+                    .into())
+                }
+                field_descriptor_proto::Type::TYPE_ENUM => {
+                    return Self::enum_default_value(field, building)
+                }
+                t => RuntimeTypeBox::from_proto_type(t)
+                    .parse_proto_default_value(field.default_value()),
+            },
+        ))
     }
 
-    pub fn index(field: &FieldDescriptorProto, building: &FileDescriptorBuilding) -> FieldIndex {
+    pub fn index(
+        field: &FieldDescriptorProto,
+        building: &FileDescriptorBuilding,
+    ) -> crate::Result<FieldIndex> {
         let default_value = if field.has_default_value() {
-            Some(Self::parse_default_value(field, building))
+            Some(Self::parse_default_value(field, building)?)
         } else {
             None
         };
@@ -141,17 +153,17 @@ impl FieldIndex {
         };
 
         let extendee = if field.has_extendee() {
-            Some(building.resolve_message(field.extendee()))
+            Some(building.resolve_message(field.extendee())?)
         } else {
             None
         };
 
-        FieldIndex {
+        Ok(FieldIndex {
             default_value,
             json_name,
             extendee,
-            field_type: building.resolve_field_type(field),
-        }
+            field_type: building.resolve_field_type(field)?,
+        })
     }
 
     pub(crate) fn default_value<'a>(&'a self, field: &FieldDescriptor) -> ReflectValueRef<'a> {
@@ -1,3 +1,5 @@
+use std::any::Any;
+use std::any::TypeId;
 use std::fmt;
 
 use crate::descriptor::field_descriptor_proto;
@@ -10,9 +12,11 @@ use crate::reflect::acc::v2::AccessorV2;
 use crate::reflect::acc::GeneratedFieldAccessor;
 use crate::reflect::dynamic::DynamicMessage;
 use crate::reflect::field::dynamic::DynamicFieldDescriptorRef;
+use crate::reflect::field::index::FieldDefaultValue;
 use crate::reflect::field::index::FieldIndex;
 use crate::reflect::field::protobuf_field_type::ProtobufFieldType;
 use crate::reflect::field::runtime_field_type::RuntimeFieldType;
+use crate::reflect::file::FileDescriptorImpl;
 use crate::reflect::map::ReflectMapMut;
 use crate::reflect::map::ReflectMapRef;
 use crate::reflect::message::message_ref::MessageRef;
@@ -31,6 +35,7 @@ use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
 
 pub(crate) mod dynamic;
+pub(crate) mod extension;
 pub(crate) mod index;
 pub(crate) mod protobuf_field_type;
 pub(crate) mod runtime_field_type;
@@ -113,12 +118,19 @@ impl fmt::Display for FieldDescriptor {
     }
 }
 
+impl fmt::Debug for FieldDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldDescriptor")
+            .field(&self.full_name())
+            .finish()
+    }
+}
+
 impl FieldDescriptor {
     pub(crate) fn regular(&self) -> (&MessageDescriptor, usize) {
         match &self.imp {
             FieldDescriptorImpl::Field(m, i) => (m, *i),
-            // TODO: implement and remove this function
-            _ => unimplemented!("extension fields are not fully supported yet"),
+            _ => panic!("not a regular field: {}", self),
         }
     }
 
@@ -154,7 +166,17 @@ impl FieldDescriptor {
     }
 
     /// Oneof descriptor containing this field.
+    ///
+    /// Synthetic oneofs (generated by `protoc` for proto3 `optional` fields)
+    /// are not returned, use [`containing_oneof_including_synthetic`](Self::containing_oneof_including_synthetic)
+    /// to get them.
     pub fn containing_oneof(&self) -> Option<OneofDescriptor> {
+        self.containing_oneof_including_synthetic()
+            .filter(|o| !o.is_synthetic())
+    }
+
+    /// Oneof descriptor containing this field, including synthetic oneofs.
+    pub fn containing_oneof_including_synthetic(&self) -> Option<OneofDescriptor> {
         if let FieldDescriptorImpl::Field(m, _) = &self.imp {
             let proto = self.proto();
             if proto.has_oneof_index() {
@@ -170,9 +192,18 @@ impl FieldDescriptor {
         }
     }
 
+    /// Is this field an extension.
+    pub fn is_extension(&self) -> bool {
+        match &self.imp {
+            FieldDescriptorImpl::Field(..) => false,
+            FieldDescriptorImpl::ExtensionInMessage(..)
+            | FieldDescriptorImpl::ExtensionInFile(..) => true,
+        }
+    }
+
     /// Message which contains this field.
     ///
-    /// For extension fields, this is the message being extended (**not implemented**).
+    /// For extension fields, this is the message being extended.
     pub fn containing_message(&self) -> MessageDescriptor {
         match &self.imp {
             FieldDescriptorImpl::Field(m, _) => m.clone(),
@@ -217,6 +248,13 @@ impl FieldDescriptor {
         }
     }
 
+    /// Is this field declared `optional` in proto3 file.
+    ///
+    /// Such fields track presence like proto2 `optional` fields.
+    pub fn is_proto3_optional(&self) -> bool {
+        self.proto().proto3_optional()
+    }
+
     /// Is this field required.
     pub fn is_required(&self) -> bool {
         self.proto().label() == field_descriptor_proto::Label::LABEL_REQUIRED
@@ -336,7 +374,10 @@ impl FieldDescriptor {
     /// # Panics
     ///
     /// If this field belongs to a different message type or
-    /// field type is not singular message.
+    /// field type is not singular message, or if this field is an extension
+    /// of a generated message: such extensions are stored in unknown fields and
+    /// cannot be modified in place, use [`set_singular_field`](Self::set_singular_field)
+    /// or [`protobuf::ext`](crate::ext) instead.
     pub fn mut_message<'a>(&self, m: &'a mut dyn MessageDyn) -> &'a mut dyn MessageDyn {
         match self.mut_singular_field_or_default(m) {
             ReflectValueMut::Message(m) => m,
@@ -360,6 +401,12 @@ impl FieldDescriptor {
     ///
     /// If this field belongs to a different message type or fields is not singular.
     pub fn get_singular_field_or_default<'a>(&self, m: &'a dyn MessageDyn) -> ReflectValueRef<'a> {
+        if self.is_extension() {
+            return match self.get_singular(m) {
+                Some(v) => v,
+                None => self.extension_default_value(),
+            };
+        }
         let (descriptor, index) = self.regular();
         match self.get_singular(m) {
             Some(m) => m,
@@ -375,8 +422,80 @@ impl FieldDescriptor {
         }
     }
 
+    /// Default value of extension field.
+    ///
+    /// Explicit `string` and `bytes` defaults of extensions declared in dynamic files
+    /// cannot outlive the descriptor, so an empty value is returned for them.
+    fn extension_default_value<'a>(&self) -> ReflectValueRef<'a> {
+        let static_index: Option<&'static FieldIndex> = match &self.imp {
+            FieldDescriptorImpl::ExtensionInFile(file, i) => match &file.imp {
+                FileDescriptorImpl::Generated(g) => Some(&g.common.extensions[*i]),
+                FileDescriptorImpl::Dynamic(..) => None,
+            },
+            FieldDescriptorImpl::ExtensionInMessage(m, i) => match m.get_impl() {
+                MessageDescriptorImplRef::Generated(..) => {
+                    Some(&m.generated_index().extensions[*i])
+                }
+                MessageDescriptorImplRef::Dynamic(..) => None,
+            },
+            FieldDescriptorImpl::Field(..) => unreachable!(),
+        };
+        if let Some(index) = static_index {
+            return index.default_value(self);
+        }
+        match (&self.index().default_value, self.singular_default_value()) {
+            (Some(FieldDefaultValue::ReflectValueBox(..)), ReflectValueRef::String(..))
+            | (Some(FieldDefaultValue::ReflectValueBox(..)), ReflectValueRef::Bytes(..))
+            | (None, _) => self.singular_runtime_type().default_value_ref(),
+            (_, ReflectValueRef::U32(v)) => ReflectValueRef::U32(v),
+            (_, ReflectValueRef::U64(v)) => ReflectValueRef::U64(v),
+            (_, ReflectValueRef::I32(v)) => ReflectValueRef::I32(v),
+            (_, ReflectValueRef::I64(v)) => ReflectValueRef::I64(v),
+            (_, ReflectValueRef::F32(v)) => ReflectValueRef::F32(v),
+            (_, ReflectValueRef::F64(v)) => ReflectValueRef::F64(v),
+            (_, ReflectValueRef::Bool(v)) => ReflectValueRef::Bool(v),
+            (_, ReflectValueRef::Enum(e, v)) => ReflectValueRef::Enum(e, v),
+            (_, v) => panic!("unexpected default value {:?} for {}", v, self),
+        }
+    }
+
+    /// Dynamic message, or `None` for generated message.
+    ///
+    /// Used for extensions, which are stored in unknown fields of generated messages.
+    fn as_dynamic(m: &dyn MessageDyn) -> Option<&DynamicMessage> {
+        if Any::type_id(m) == TypeId::of::<DynamicMessage>() {
+            Some(DynamicMessage::downcast_ref(m))
+        } else {
+            None
+        }
+    }
+
+    fn as_dynamic_mut(m: &mut dyn MessageDyn) -> Option<&mut DynamicMessage> {
+        if Any::type_id(&*m) == TypeId::of::<DynamicMessage>() {
+            Some(DynamicMessage::downcast_mut(m))
+        } else {
+            None
+        }
+    }
+
+    fn extension_of_dynamic_mut<'a>(&self, m: &'a mut dyn MessageDyn) -> &'a mut DynamicMessage {
+        match FieldDescriptor::as_dynamic_mut(m) {
+            Some(m) => m,
+            None => panic!(
+                "extension of generated message cannot be modified in place, \
+                use `set_singular_field` or `protobuf::ext`: {}",
+                self
+            ),
+        }
+    }
+
     // Not public because it is not implemented for all types
     fn mut_singular_field_or_default<'a>(&self, m: &'a mut dyn MessageDyn) -> ReflectValueMut<'a> {
+        if self.is_extension() {
+            return self
+                .extension_of_dynamic_mut(m)
+                .mut_singular_field_or_default(self);
+        }
         match self.singular() {
             SingularFieldAccessorRef::Generated(g) => g.accessor.mut_field_or_default(m),
             SingularFieldAccessorRef::Dynamic(..) => {
@@ -402,12 +521,76 @@ impl FieldDescriptor {
     /// # Panics
     ///
     /// If this field belongs to a different message type or
-    /// field is not singular or value is of different type.
+    /// field is not singular or value is of different type,
+    /// or if this field is a group extension of a generated message
+    /// (use [`try_set_singular_field`](Self::try_set_singular_field) to get an error instead).
     pub fn set_singular_field(&self, m: &mut dyn MessageDyn, value: ReflectValueBox) {
+        if let Err(e) = self.try_set_singular_field(m, value) {
+            panic!("failed to set field {}: {}", self, e);
+        }
+    }
+
+    /// Set singular field.
+    ///
+    /// Return an error if this field is a group extension of a generated message:
+    /// extensions of generated messages are stored in unknown fields,
+    /// which cannot hold groups.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not singular or value is of different type.
+    pub fn try_set_singular_field(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> crate::Result<()> {
+        if self.is_extension() {
+            assert_eq!(self.singular_runtime_type(), value.get_type());
+            return match FieldDescriptor::as_dynamic_mut(m) {
+                Some(m) => {
+                    m.set_field(self, value);
+                    Ok(())
+                }
+                None => {
+                    assert_eq!(self.containing_message(), m.descriptor_dyn());
+                    extension::set_values(self, m.mut_unknown_fields_dyn(), &[value.as_value_ref()])
+                }
+            };
+        }
         match self.singular() {
             SingularFieldAccessorRef::Generated(g) => g.accessor.set_field(m, value),
             SingularFieldAccessorRef::Dynamic(d) => d.set_field(m, value),
         }
+        Ok(())
+    }
+
+    /// Clear field: unset singular field or remove all elements of repeated or map field.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type.
+    pub fn clear_field(&self, m: &mut dyn MessageDyn) {
+        if self.is_extension() {
+            return match FieldDescriptor::as_dynamic_mut(m) {
+                Some(m) => m.clear_field(self),
+                None => {
+                    assert_eq!(self.containing_message(), m.descriptor_dyn());
+                    m.mut_unknown_fields_dyn()
+                        .remove(self.proto().number() as u32)
+                }
+            };
+        }
+        match self.runtime_field_type() {
+            RuntimeFieldType::Singular(..) => match self.singular() {
+                SingularFieldAccessorRef::Generated(g) => g.accessor.clear_field(m),
+                SingularFieldAccessorRef::Dynamic(..) => {
+                    DynamicMessage::downcast_mut(m).clear_field(self)
+                }
+            },
+            RuntimeFieldType::Repeated(..) => self.mut_repeated(m).clear(),
+            RuntimeFieldType::Map(..) => self.mut_map(m).clear(),
+        }
     }
 
     /// Dynamic representation of field type with wire type.
@@ -426,6 +609,15 @@ impl FieldDescriptor {
     ///
     /// If this field belongs to a different message type.
     pub fn get_reflect<'a>(&self, m: &'a dyn MessageDyn) -> ReflectFieldRef<'a> {
+        if self.is_extension() {
+            return match FieldDescriptor::as_dynamic(m) {
+                Some(m) => m.get_reflect(self),
+                None => {
+                    assert_eq!(self.containing_message(), m.descriptor_dyn());
+                    extension::get_reflect(self, m.unknown_fields_dyn())
+                }
+            };
+        }
         match self.get_impl() {
             FieldDescriptorImplRef::Generated(g) => g.get_reflect(m),
             FieldDescriptorImplRef::Dynamic(d) => d.get_reflect(m),
@@ -464,14 +656,51 @@ impl FieldDescriptor {
     ///
     /// # Panics
     ///
-    /// If this field belongs to a different message type or field is not `repeated`.
+    /// If this field belongs to a different message type or field is not `repeated`,
+    /// or if this field is an extension of a generated message: such extensions
+    /// are stored in unknown fields and cannot be modified in place.
     pub fn mut_repeated<'a>(&self, m: &'a mut dyn MessageDyn) -> ReflectRepeatedMut<'a> {
+        if self.is_extension() {
+            return self.extension_of_dynamic_mut(m).mut_repeated(self);
+        }
         match self.repeated() {
             RepeatedFieldAccessorRef::Generated(g) => g.accessor.mut_repeated(m),
             RepeatedFieldAccessorRef::Dynamic(d) => d.mut_repeated(m),
         }
     }
 
+    /// Append a value to `repeated` field.
+    ///
+    /// Unlike [`mut_repeated`](Self::mut_repeated), this function also works
+    /// for extensions of generated messages, except group extensions,
+    /// for which an error is returned.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not `repeated` or value is of different type.
+    pub(crate) fn push_repeated(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> crate::Result<()> {
+        if self.is_extension() && FieldDescriptor::as_dynamic(m).is_none() {
+            assert_eq!(self.containing_message(), m.descriptor_dyn());
+            let t = match self.runtime_field_type() {
+                RuntimeFieldType::Repeated(t) => t,
+                _ => panic!("not a repeated field: {}", self),
+            };
+            assert_eq!(t, value.get_type());
+            return extension::append_values(
+                self,
+                m.mut_unknown_fields_dyn(),
+                &[value.as_value_ref()],
+            );
+        }
+        self.mut_repeated(m).push(value);
+        Ok(())
+    }
+
     // map
 
     /// Get `map` field.
@@ -0,0 +1,408 @@
+use std::fmt;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::error::ReflectError;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+
+#[derive(Debug, Clone)]
+enum Subscript {
+    Index(usize),
+    Key(ReflectValueBox),
+}
+
+#[derive(Debug, Clone)]
+struct FieldPathElement {
+    field: FieldDescriptor,
+    subscript: Option<Subscript>,
+    /// Type of the value selected by this element.
+    value_type: RuntimeTypeBox,
+}
+
+impl FieldPathElement {
+    fn get<'a>(&self, message: &'a dyn MessageDyn) -> Option<ReflectValueRef<'a>> {
+        match &self.subscript {
+            None => self.field.get_singular(message),
+            Some(Subscript::Index(index)) => {
+                let repeated = self.field.get_repeated(message);
+                if *index < repeated.len() {
+                    Some(repeated.get(*index))
+                } else {
+                    None
+                }
+            }
+            Some(Subscript::Key(key)) => {
+                self.field.get_map(message).get_borrowed(key.as_value_ref())
+            }
+        }
+    }
+
+    /// Message selected by this element, or `None` if it does not exist and `create` is false.
+    ///
+    /// # Panics
+    ///
+    /// If `create` is true and repeated field index is out of range.
+    fn mut_message<'a>(
+        &self,
+        message: &'a mut dyn MessageDyn,
+        create: bool,
+    ) -> Option<&'a mut dyn MessageDyn> {
+        match &self.subscript {
+            None => {
+                if !create && !self.field.has_field(message) {
+                    return None;
+                }
+                Some(self.field.mut_message(message))
+            }
+            Some(Subscript::Index(index)) => {
+                let repeated = self.field.mut_repeated(message);
+                if *index >= repeated.len() {
+                    assert!(
+                        !create,
+                        "index {} is out of range of field {} of length {}",
+                        index,
+                        self.field,
+                        repeated.len()
+                    );
+                    return None;
+                }
+                Some(repeated.into_message_mut(*index))
+            }
+            Some(Subscript::Key(key)) => {
+                let mut map = self.field.mut_map(message);
+                if create && map.get(key.as_value_ref()).is_none() {
+                    let value = match &self.value_type {
+                        RuntimeTypeBox::Message(m) => m.new_instance(),
+                        t => unreachable!("not a message: {}", t),
+                    };
+                    map.insert(key.clone(), ReflectValueBox::Message(value));
+                }
+                map.into_message_mut(key.as_value_ref())
+            }
+        }
+    }
+}
+
+impl fmt::Display for FieldPathElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.field.name())?;
+        match &self.subscript {
+            None => Ok(()),
+            Some(Subscript::Index(index)) => write!(f, "[{}]", index),
+            Some(Subscript::Key(key)) => match key {
+                ReflectValueBox::String(s) => {
+                    write!(f, "[\"")?;
+                    for c in s.chars() {
+                        if c == '"' || c == '\\' {
+                            write!(f, "\\")?;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    write!(f, "\"]")
+                }
+                ReflectValueBox::U32(v) => write!(f, "[{}]", v),
+                ReflectValueBox::U64(v) => write!(f, "[{}]", v),
+                ReflectValueBox::I32(v) => write!(f, "[{}]", v),
+                ReflectValueBox::I64(v) => write!(f, "[{}]", v),
+                ReflectValueBox::Bool(v) => write!(f, "[{}]", v),
+                v => unreachable!("not a map key: {:?}", v),
+            },
+        }
+    }
+}
+
+/// Split `[...]` from the start of `s`, return bracket content and the remaining string.
+fn split_subscript(s: &str) -> Option<(&str, &str)> {
+    let content = s.strip_prefix('[')?;
+    let end = if let Some(quoted) = content.strip_prefix('"') {
+        let mut escaped = false;
+        let quote = quoted.char_indices().find(|&(_, c)| {
+            let end = !escaped && c == '"';
+            escaped = !escaped && c == '\\';
+            end
+        })?;
+        quote.0 + 2
+    } else {
+        content.find(']')?
+    };
+    Some((&content[..end], content[end..].strip_prefix(']')?))
+}
+
+fn parse_string_key(s: &str) -> Option<String> {
+    let s = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut r = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                c @ ('"' | '\\') => r.push(c),
+                _ => return None,
+            },
+            c => r.push(c),
+        }
+    }
+    Some(r)
+}
+
+fn parse_key(s: &str, key_type: &RuntimeTypeBox) -> Option<ReflectValueBox> {
+    match key_type {
+        RuntimeTypeBox::U32 => s.parse().ok().map(ReflectValueBox::U32),
+        RuntimeTypeBox::U64 => s.parse().ok().map(ReflectValueBox::U64),
+        RuntimeTypeBox::I32 => s.parse().ok().map(ReflectValueBox::I32),
+        RuntimeTypeBox::I64 => s.parse().ok().map(ReflectValueBox::I64),
+        RuntimeTypeBox::Bool => s.parse().ok().map(ReflectValueBox::Bool),
+        RuntimeTypeBox::String => parse_string_key(s).map(ReflectValueBox::String),
+        _ => None,
+    }
+}
+
+/// Path to a value nested in a message, like `a.b[3].c` or `labels["env"]`.
+///
+/// A path is a list of field names separated by dots. An element of a repeated
+/// field is selected by index in brackets, a value of a map field is selected
+/// by key in brackets. String keys are quoted, with `"` and `\` escaped
+/// with a backslash.
+///
+/// A path is resolved against a message type when parsed,
+/// and can be used with generated and dynamic messages of that type alike.
+///
+/// # Examples
+///
+/// ```
+/// # use protobuf::MessageFull;
+/// # use protobuf::reflect::FieldPath;
+/// # use protobuf::reflect::ReflectValueBox;
+/// # use protobuf::reflect::ReflectValueRef;
+/// # use protobuf::well_known_types::Struct;
+/// let path = FieldPath::parse(&Struct::descriptor_static(), "fields[\"env\"].string_value")?;
+/// let mut s = Struct::new();
+/// path.set(&mut s, ReflectValueBox::String("prod".to_owned()));
+/// assert_eq!(Some(ReflectValueRef::String("prod")), path.get(&s));
+/// # Ok::<(), protobuf::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FieldPath {
+    message: MessageDescriptor,
+    elements: Vec<FieldPathElement>,
+}
+
+impl FieldPath {
+    /// Parse a path relative to given message type.
+    ///
+    /// All fields in the path except the last one must be message fields,
+    /// and repeated or map fields must be followed by index or key.
+    pub fn parse(message: &MessageDescriptor, path: &str) -> crate::Result<FieldPath> {
+        let error = |reason| -> crate::Error {
+            ReflectError::InvalidFieldPath(path.to_owned(), message.full_name().to_owned(), reason)
+                .into()
+        };
+
+        let mut elements = Vec::new();
+        let mut current = message.clone();
+        let mut rest = path;
+        loop {
+            let end = rest.find(&['.', '['][..]).unwrap_or(rest.len());
+            let field = current
+                .field_by_name(&rest[..end])
+                .ok_or_else(|| error("unknown field"))?;
+            rest = &rest[end..];
+
+            let (subscript, value_type) = match field.runtime_field_type() {
+                RuntimeFieldType::Singular(t) => (None, t),
+                RuntimeFieldType::Repeated(t) => {
+                    let (index, r) = split_subscript(rest)
+                        .ok_or_else(|| error("repeated field without index"))?;
+                    let index = index.parse().map_err(|_| error("incorrect index"))?;
+                    rest = r;
+                    (Some(Subscript::Index(index)), t)
+                }
+                RuntimeFieldType::Map(k, v) => {
+                    let (key, r) =
+                        split_subscript(rest).ok_or_else(|| error("map field without key"))?;
+                    let key = parse_key(key, &k).ok_or_else(|| error("incorrect map key"))?;
+                    rest = r;
+                    (Some(Subscript::Key(key)), v)
+                }
+            };
+            let next = match &value_type {
+                RuntimeTypeBox::Message(m) => Some(m.clone()),
+                _ => None,
+            };
+            elements.push(FieldPathElement {
+                field,
+                subscript,
+                value_type,
+            });
+
+            if rest.is_empty() {
+                break;
+            }
+            rest = rest
+                .strip_prefix('.')
+                .ok_or_else(|| error("expecting `.` after field"))?;
+            current = next.ok_or_else(|| error("field of non-message value"))?;
+        }
+
+        Ok(FieldPath {
+            message: message.clone(),
+            elements,
+        })
+    }
+
+    /// Message type this path is relative to.
+    pub fn message_descriptor(&self) -> &MessageDescriptor {
+        &self.message
+    }
+
+    /// Type of the value at this path.
+    pub fn value_type(&self) -> RuntimeTypeBox {
+        self.last().value_type.clone()
+    }
+
+    fn last(&self) -> &FieldPathElement {
+        self.elements.last().unwrap()
+    }
+
+    fn check_message(&self, message: &dyn MessageDyn) {
+        assert_eq!(
+            self.message,
+            message.descriptor_dyn(),
+            "field path {} is for a different message type",
+            self
+        );
+    }
+
+    /// Get value at this path.
+    ///
+    /// Return `None` if the value or any of enclosing messages is not set,
+    /// repeated field index is out of range or map key is not found.
+    ///
+    /// # Panics
+    ///
+    /// If message is of a different type.
+    pub fn get<'a>(&self, message: &'a dyn MessageDyn) -> Option<ReflectValueRef<'a>> {
+        self.check_message(message);
+        let (last, init) = self.elements.split_last().unwrap();
+        let mut message = message;
+        for element in init {
+            message = match element.get(message)? {
+                ReflectValueRef::Message(m) => m.as_borrowed()?,
+                _ => unreachable!(),
+            };
+        }
+        last.get(message)
+    }
+
+    /// Get mutable reference to message at this path.
+    ///
+    /// Return `None` if the message or any of enclosing messages is not set,
+    /// repeated field index is out of range or map key is not found.
+    ///
+    /// # Panics
+    ///
+    /// If message is of a different type or this path does not point to a message.
+    pub fn get_mut<'a>(&self, message: &'a mut dyn MessageDyn) -> Option<&'a mut dyn MessageDyn> {
+        self.check_message(message);
+        assert!(
+            matches!(self.last().value_type, RuntimeTypeBox::Message(..)),
+            "field path {} does not point to a message",
+            self
+        );
+        let mut message = message;
+        for element in &self.elements {
+            message = element.mut_message(message, false)?;
+        }
+        Some(message)
+    }
+
+    /// Set value at this path, creating enclosing messages if needed.
+    ///
+    /// # Panics
+    ///
+    /// If message is of a different type, value is of a different type
+    /// or repeated field index is out of range.
+    pub fn set(&self, message: &mut dyn MessageDyn, value: ReflectValueBox) {
+        self.check_message(message);
+        let (last, init) = self.elements.split_last().unwrap();
+        let mut message = message;
+        for element in init {
+            message = element.mut_message(message, true).unwrap();
+        }
+        match &last.subscript {
+            None => last.field.set_singular_field(message, value),
+            Some(Subscript::Index(index)) => last.field.mut_repeated(message).set(*index, value),
+            Some(Subscript::Key(key)) => last.field.mut_map(message).insert(key.clone(), value),
+        }
+    }
+
+    /// Clear value at this path: unset singular field,
+    /// remove repeated field element or map entry.
+    ///
+    /// Do nothing if the value does not exist.
+    ///
+    /// # Panics
+    ///
+    /// If message is of a different type.
+    pub fn clear(&self, message: &mut dyn MessageDyn) {
+        self.check_message(message);
+        let (last, init) = self.elements.split_last().unwrap();
+        let mut message = message;
+        for element in init {
+            message = match element.mut_message(message, false) {
+                Some(m) => m,
+                None => return,
+            };
+        }
+        match &last.subscript {
+            None => last.field.clear_field(message),
+            Some(Subscript::Index(index)) => {
+                let mut repeated = last.field.mut_repeated(message);
+                if *index < repeated.len() {
+                    repeated.remove(*index);
+                }
+            }
+            Some(Subscript::Key(key)) => last.field.mut_map(message).remove(key.as_value_ref()),
+        }
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, element) in self.elements.iter().enumerate() {
+            if i != 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", element)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_string_key;
+    use super::split_subscript;
+
+    #[test]
+    fn subscript() {
+        assert_eq!(Some(("3", ".a")), split_subscript("[3].a"));
+        assert_eq!(Some(("\"a]\\\"b\"", "")), split_subscript("[\"a]\\\"b\"]"));
+        assert_eq!(None, split_subscript("[3"));
+        assert_eq!(None, split_subscript("3]"));
+        assert_eq!(None, split_subscript("[\"a]"));
+    }
+
+    #[test]
+    fn string_key() {
+        assert_eq!(
+            Some("a\"b\\c".to_owned()),
+            parse_string_key("\"a\\\"b\\\\c\"")
+        );
+        assert_eq!(None, parse_string_key("\"a\\nb\""));
+        assert_eq!(None, parse_string_key("a"));
+    }
+}
@@ -5,6 +5,7 @@ use crate::descriptor::DescriptorProto;
 use crate::descriptor::EnumDescriptorProto;
 use crate::descriptor::FieldDescriptorProto;
 use crate::descriptor::FileDescriptorProto;
+use crate::reflect::error::ReflectError;
 use crate::reflect::field::index::ForwardProtobufFieldType;
 use crate::reflect::field::index::ForwardProtobufTypeBox;
 use crate::reflect::file::index::FileIndex;
@@ -26,27 +27,22 @@ impl<'a> FileDescriptorBuilding<'a> {
             .chain(self.deps_with_public.iter().map(|d| d.proto()))
     }
 
-    pub fn find_enum(&self, full_name: &str) -> &'a EnumDescriptorProto {
+    pub fn find_enum(&self, full_name: &str) -> crate::Result<&'a EnumDescriptorProto> {
         assert!(full_name.starts_with("."));
 
         for file in self.all_descriptors() {
             if let Some(name_to_package) =
                 protobuf_name_starts_with_package(full_name, file.package())
             {
-                if let Some((_, me)) = find_message_or_enum(file, name_to_package) {
-                    match me {
-                        MessageOrEnum::Enum(e) => return e,
-                        MessageOrEnum::Message(_) => panic!("not an enum: {}", full_name),
-                    }
+                if let Some((_, MessageOrEnum::Enum(e))) =
+                    find_message_or_enum(file, name_to_package)
+                {
+                    return Ok(e);
                 }
             }
         }
 
-        panic!(
-            "enum not found: {}, in files: {}",
-            full_name,
-            self.all_files_str()
-        );
+        Err(ReflectError::EnumNotFoundInFiles(full_name.to_owned(), self.all_files_str()).into())
     }
 
     fn all_files_str(&self) -> String {
@@ -59,14 +55,14 @@ impl<'a> FileDescriptorBuilding<'a> {
     pub(crate) fn resolve_field_type(
         &self,
         field: &FieldDescriptorProto,
-    ) -> ForwardProtobufFieldType {
-        match field.label() {
+    ) -> crate::Result<ForwardProtobufFieldType> {
+        Ok(match field.label() {
             field_descriptor_proto::Label::LABEL_OPTIONAL
             | field_descriptor_proto::Label::LABEL_REQUIRED => {
-                ForwardProtobufFieldType::Singular(self.resolve_field_element_type(field))
+                ForwardProtobufFieldType::Singular(self.resolve_field_element_type(field)?)
             }
             field_descriptor_proto::Label::LABEL_REPEATED => {
-                let element = self.resolve_field_element_type(field);
+                let element = self.resolve_field_element_type(field)?;
                 let type_proto = match &element {
                     ForwardProtobufTypeBox::CurrentFileMessage(m) => Some(
                         self.current_file_index.messages[*m]
@@ -81,17 +77,22 @@ impl<'a> FileDescriptorBuilding<'a> {
                     _ => None,
                 };
                 match type_proto {
-                    Some(m) if m.options.get_or_default().map_entry() => self.map_field(m),
+                    Some(m) if m.options.get_or_default().map_entry() => self.map_field(m)?,
                     _ => ForwardProtobufFieldType::Repeated(element),
                 }
             }
-        }
+        })
     }
 
-    fn resolve_field_element_type(&self, field: &FieldDescriptorProto) -> ForwardProtobufTypeBox {
-        match field.field_type() {
+    fn resolve_field_element_type(
+        &self,
+        field: &FieldDescriptorProto,
+    ) -> crate::Result<ForwardProtobufTypeBox> {
+        Ok(match field.field_type() {
             field_descriptor_proto::Type::TYPE_MESSAGE
-            | field_descriptor_proto::Type::TYPE_GROUP => self.resolve_message(field.type_name()),
+            | field_descriptor_proto::Type::TYPE_GROUP => {
+                self.resolve_message(field.type_name())?
+            }
             field_descriptor_proto::Type::TYPE_ENUM => {
                 if let Some(name_to_package) = protobuf_name_starts_with_package(
                     field.type_name(),
@@ -102,25 +103,25 @@ impl<'a> FileDescriptorBuilding<'a> {
                         .enums_by_name_to_package
                         .get(name_to_package)
                     {
-                        return ForwardProtobufTypeBox::CurrentFileEnum(*index);
+                        return Ok(ForwardProtobufTypeBox::CurrentFileEnum(*index));
                     }
                 }
                 for dep in self.deps_with_public {
                     if let Some(m) = dep.enum_by_full_name(field.type_name()) {
-                        return ForwardProtobufTypeBox::enumeration(m);
+                        return Ok(ForwardProtobufTypeBox::enumeration(m));
                     }
                 }
-                panic!(
-                    "enum not found: {}; files: {}",
-                    field.type_name(),
-                    self.all_files_str()
-                );
+                return Err(ReflectError::EnumNotFoundInFiles(
+                    field.type_name().to_owned(),
+                    self.all_files_str(),
+                )
+                .into());
             }
             t => ForwardProtobufTypeBox::from_proto_type(t),
-        }
+        })
     }
 
-    pub(crate) fn resolve_message(&self, type_name: &str) -> ForwardProtobufTypeBox {
+    pub(crate) fn resolve_message(&self, type_name: &str) -> crate::Result<ForwardProtobufTypeBox> {
         if let Some(name_to_package) =
             protobuf_name_starts_with_package(type_name, self.current_file_descriptor.package())
         {
@@ -129,47 +130,41 @@ impl<'a> FileDescriptorBuilding<'a> {
                 .message_by_name_to_package
                 .get(name_to_package)
             {
-                return ForwardProtobufTypeBox::CurrentFileMessage(*index);
+                return Ok(ForwardProtobufTypeBox::CurrentFileMessage(*index));
             }
         }
         for dep in self.deps_with_public {
             if let Some(m) = dep.message_by_full_name(type_name) {
-                return ForwardProtobufTypeBox::message(m);
+                return Ok(ForwardProtobufTypeBox::message(m));
             }
         }
-        // TODO: error, not panic
-        panic!(
-            "message not found: {}; files: {}",
-            type_name,
-            self.all_files_str()
-        );
+        Err(ReflectError::MessageNotFoundInFiles(type_name.to_owned(), self.all_files_str()).into())
     }
 
-    fn map_field(&self, type_proto: &DescriptorProto) -> ForwardProtobufFieldType {
-        assert!(type_proto.name().ends_with("Entry"));
-
-        assert_eq!(0, type_proto.extension.len());
-        assert_eq!(0, type_proto.extension_range.len());
-        assert_eq!(0, type_proto.nested_type.len());
-        assert_eq!(0, type_proto.enum_type.len());
-
-        assert_eq!(2, type_proto.field.len());
-        let key = &type_proto.field[0];
-        let value = &type_proto.field[1];
-
-        assert_eq!("key", key.name());
-        assert_eq!("value", value.name());
-
-        assert_eq!(1, key.number());
-        assert_eq!(2, value.number());
-
-        assert_eq!(field_descriptor_proto::Label::LABEL_OPTIONAL, key.label());
-        assert_eq!(field_descriptor_proto::Label::LABEL_OPTIONAL, value.label());
+    fn map_field(&self, type_proto: &DescriptorProto) -> crate::Result<ForwardProtobufFieldType> {
+        let is_map_entry = |key: &FieldDescriptorProto, value: &FieldDescriptorProto| {
+            type_proto.name().ends_with("Entry")
+                && type_proto.extension.is_empty()
+                && type_proto.extension_range.is_empty()
+                && type_proto.nested_type.is_empty()
+                && type_proto.enum_type.is_empty()
+                && key.name() == "key"
+                && value.name() == "value"
+                && key.number() == 1
+                && value.number() == 2
+                && key.label() == field_descriptor_proto::Label::LABEL_OPTIONAL
+                && value.label() == field_descriptor_proto::Label::LABEL_OPTIONAL
+        };
+
+        let (key, value) = match type_proto.field.as_slice() {
+            [key, value] if is_map_entry(key, value) => (key, value),
+            _ => return Err(ReflectError::IncorrectMapEntry(type_proto.name().to_owned()).into()),
+        };
 
         // It is OK to resolve using current descriptor because map field
         // should always point to the same file.
-        let key = self.resolve_field_element_type(key);
-        let value = self.resolve_field_element_type(value);
-        ForwardProtobufFieldType::Map(key, value)
+        let key = self.resolve_field_element_type(key)?;
+        let value = self.resolve_field_element_type(value)?;
+        Ok(ForwardProtobufFieldType::Map(key, value))
     }
 }
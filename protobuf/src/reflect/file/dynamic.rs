@@ -23,10 +23,10 @@ impl DynamicFileDescriptor {
     pub fn new(
         proto: FileDescriptorProto,
         dependencies: Vec<FileDescriptor>,
-    ) -> DynamicFileDescriptor {
+    ) -> crate::Result<DynamicFileDescriptor> {
         let proto = Arc::new(proto);
 
-        let index = FileIndex::index(&*proto, &dependencies);
+        let index = FileIndex::index(&*proto, &dependencies)?;
 
         let file_descriptor_building = FileDescriptorBuilding {
             current_file_index: &index,
@@ -44,16 +44,16 @@ impl DynamicFileDescriptor {
                     &file_descriptor_building,
                 )
             })
-            .collect();
+            .collect::<crate::Result<_>>()?;
 
-        let common = FileDescriptorCommon::new(index, dependencies, &proto);
+        let common = FileDescriptorCommon::new(index, dependencies, &proto)?;
 
-        DynamicFileDescriptor {
+        Ok(DynamicFileDescriptor {
             messages,
             enums: Self::enums(&proto),
             proto,
             common,
-        }
+        })
     }
 
     fn enums(proto: &Arc<FileDescriptorProto>) -> Vec<DynamicEnumDescriptor> {
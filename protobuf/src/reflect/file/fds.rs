@@ -2,45 +2,84 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 
 use crate::descriptor::FileDescriptorProto;
+use crate::reflect::error::ReflectError;
 use crate::reflect::FileDescriptor;
 
-pub(crate) struct FdsBuilder {
+pub(crate) struct FdsBuilder<'a> {
     names: Vec<String>,
     unprocessed: HashMap<String, FileDescriptorProto>,
     processed: HashMap<String, FileDescriptor>,
+    /// Previously built files which can be used as dependencies.
+    available: &'a HashMap<String, FileDescriptor>,
 }
 
-impl FdsBuilder {
-    fn process_one(&mut self) {
-        let (n, deps) = self
-            .unprocessed
+impl<'a> FdsBuilder<'a> {
+    fn all_names(&self) -> String {
+        let mut names: Vec<&str> = self
+            .names
             .iter()
-            .find_map(|(n, p)| {
-                let deps: Result<Vec<FileDescriptor>, ()> = p
-                    .dependency
-                    .iter()
-                    .map(|n| match self.processed.get(n) {
-                        Some(d) => Ok(d.clone()),
-                        None => {
-                            assert!(
-                                self.unprocessed.get(n).is_some(),
-                                "unsatisfied dependency: {}",
-                                n
-                            );
-                            Err(())
-                        }
-                    })
-                    .collect();
-                deps.ok().map(|deps| (n, deps))
-            })
-            .unwrap();
-        let n = n.clone();
+            .map(|n| n.as_str())
+            .chain(self.available.keys().map(|n| n.as_str()))
+            .collect();
+        names.sort();
+        names.join(", ")
+    }
+
+    fn process_one(&mut self) -> crate::Result<()> {
+        let mut ready = None;
+        'files: for n in &self.names {
+            let p = match self.unprocessed.get(n) {
+                Some(p) => p,
+                None => continue,
+            };
+            let mut deps = Vec::with_capacity(p.dependency.len());
+            for d in &p.dependency {
+                match self.processed.get(d).or_else(|| self.available.get(d)) {
+                    Some(d) => deps.push(d.clone()),
+                    None if self.unprocessed.contains_key(d) => continue 'files,
+                    None => {
+                        return Err(ReflectError::DependencyNotFound(
+                            d.clone(),
+                            n.clone(),
+                            self.all_names(),
+                        )
+                        .into())
+                    }
+                }
+            }
+            ready = Some((n.clone(), deps));
+            break;
+        }
+
+        let (n, deps) = match ready {
+            Some(ready) => ready,
+            None => {
+                let mut unprocessed: Vec<&str> =
+                    self.unprocessed.keys().map(|n| n.as_str()).collect();
+                unprocessed.sort();
+                return Err(ReflectError::CycleInFileDependencies(unprocessed.join(", ")).into());
+            }
+        };
         let proto = self.unprocessed.remove(&n).unwrap();
         self.processed
-            .insert(n.clone(), FileDescriptor::new_dynamic(proto, deps));
+            .insert(n, FileDescriptor::new_dynamic(proto, deps)?);
+        Ok(())
     }
 
-    pub fn build(protos: Vec<FileDescriptorProto>) -> Vec<FileDescriptor> {
+    /// Build files in dependency order.
+    ///
+    /// Dependencies are looked up in `protos` and in `available`.
+    pub fn build(
+        protos: Vec<FileDescriptorProto>,
+        available: &HashMap<String, FileDescriptor>,
+    ) -> crate::Result<Vec<FileDescriptor>> {
+        let mut names = HashSet::new();
+        for p in &protos {
+            if !names.insert(p.name()) {
+                return Err(ReflectError::NonUniqueFileDescriptor(p.name().to_owned()).into());
+            }
+        }
+
         let mut builder = FdsBuilder {
             names: protos.iter().map(|p| p.name().to_owned()).collect(),
             unprocessed: protos
@@ -48,18 +87,19 @@ impl FdsBuilder {
                 .map(|p| (p.name().to_owned(), p))
                 .collect(),
             processed: HashMap::new(),
+            available,
         };
 
         while !builder.unprocessed.is_empty() {
-            builder.process_one();
+            builder.process_one()?;
         }
 
         let mut processed = builder.processed;
-        builder
+        Ok(builder
             .names
             .iter()
             .map(|n| processed.remove(n).unwrap())
-            .collect()
+            .collect())
     }
 }
 
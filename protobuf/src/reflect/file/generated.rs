@@ -29,7 +29,7 @@ impl GeneratedFileDescriptor {
         messages: Vec<GeneratedMessageDescriptorData>,
         enums: Vec<GeneratedEnumDescriptorData>,
     ) -> GeneratedFileDescriptor {
-        let index = FileIndex::index(file_descriptor_proto, &dependencies);
+        let index = FileIndex::index(file_descriptor_proto, &dependencies).unwrap();
 
         let mut messages: HashMap<&str, GeneratedMessageDescriptorData> = messages
             .into_iter()
@@ -68,7 +68,7 @@ impl GeneratedFileDescriptor {
             .map(|(i, e)| GeneratedEnumDescriptor::new(e, i, file_descriptor_proto))
             .collect();
 
-        let common = FileDescriptorCommon::new(index, dependencies, file_descriptor_proto);
+        let common = FileDescriptorCommon::new(index, dependencies, file_descriptor_proto).unwrap();
 
         GeneratedFileDescriptor {
             proto: file_descriptor_proto,
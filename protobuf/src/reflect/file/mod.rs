@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use crate::descriptor::DescriptorProto;
 use crate::descriptor::FileDescriptorProto;
+use crate::reflect::error::ReflectError;
 use crate::reflect::field::FieldDescriptorImpl;
 use crate::reflect::file::common::FileDescriptorCommon;
 use crate::reflect::file::dynamic::DynamicFileDescriptor;
@@ -193,38 +194,47 @@ impl FileDescriptor {
     }
 
     /// Dynamic message created from [`FileDescriptorProto`] without generated files.
-    // TODO: this should return Result
+    ///
+    /// Fails if a dependency is not provided or a type reference cannot be resolved.
+    /// Use [`DescriptorPool`](crate::reflect::DescriptorPool) to load multiple files
+    /// without specifying dependencies explicitly.
     pub fn new_dynamic(
         proto: FileDescriptorProto,
         dependencies: Vec<FileDescriptor>,
-    ) -> FileDescriptor {
-        // TODO: make it return `Result` on unsatisfied dependencies.
+    ) -> crate::Result<FileDescriptor> {
         // remove undeclared dependencies
-        let dependencies: HashMap<_, &FileDescriptor> =
+        let given: HashMap<_, &FileDescriptor> =
             dependencies.iter().map(|d| (d.proto().name(), d)).collect();
         let dependencies: Vec<FileDescriptor> = proto
             .dependency
             .iter()
-            .map(|d| {
-                // TODO: do not panic
-                (*dependencies
-                    .get(d.as_str())
-                    .unwrap_or_else(|| panic!("dependency not found: {}", d)))
-                .clone()
+            .map(|d| match given.get(d.as_str()) {
+                Some(d) => Ok((*d).clone()),
+                None => Err(ReflectError::DependencyNotFound(
+                    d.clone(),
+                    proto.name().to_owned(),
+                    dependencies
+                        .iter()
+                        .map(|d| d.proto().name())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        FileDescriptor {
+        Ok(FileDescriptor {
             imp: FileDescriptorImpl::Dynamic(Arc::new(DynamicFileDescriptor::new(
                 proto,
                 dependencies,
-            ))),
-        }
+            )?)),
+        })
     }
 
     /// Create a set of file descriptors from individual file descriptors.
-    pub fn new_dynamic_fds(protos: Vec<FileDescriptorProto>) -> Vec<FileDescriptor> {
-        FdsBuilder::build(protos)
+    ///
+    /// All dependencies must be present in `protos`.
+    pub fn new_dynamic_fds(protos: Vec<FileDescriptorProto>) -> crate::Result<Vec<FileDescriptor>> {
+        FdsBuilder::build(protos, &HashMap::new())
     }
 
     /// `.proto` data for this file.
@@ -252,14 +262,15 @@ impl FileDescriptor {
             .collect()
     }
 
-    fn _all_files(&self) -> Vec<&FileDescriptor> {
+    /// This file and all its dependencies, recursively, each file once.
+    pub(crate) fn all_files(&self) -> Vec<&FileDescriptor> {
         let mut r = Vec::new();
         let mut visited = HashSet::new();
 
         let mut stack = Vec::new();
         stack.push(self);
         while let Some(file) = stack.pop() {
-            if !visited.insert(file) {
+            if !visited.insert(file.proto().name()) {
                 continue;
             }
 
@@ -1,11 +1,15 @@
+use std::collections::btree_map;
 use std::collections::hash_map;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::marker::PhantomData;
 
 use crate::reflect::map::ReflectMap;
 use crate::reflect::map::ReflectMapIter;
 use crate::reflect::map::ReflectMapIterTrait;
 use crate::reflect::runtime_types::RuntimeTypeHashable;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
@@ -18,7 +22,10 @@ where
     K::RuntimeType: RuntimeTypeHashable,
 {
     fn reflect_iter<'a>(&'a self) -> ReflectMapIter<'a> {
-        ReflectMapIter::new(GeneratedMapIterImpl::<'a, K, V> { iter: self.iter() })
+        ReflectMapIter::new(GeneratedMapIterImpl::<'a, K, V, hash_map::Iter<'a, K, V>> {
+            iter: self.iter(),
+            _marker: PhantomData,
+        })
     }
 
     fn len(&self) -> usize {
@@ -33,12 +40,80 @@ where
         <K::RuntimeType as RuntimeTypeHashable>::hash_map_get(self, key).map(V::as_ref)
     }
 
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueMut<'a>> {
+        let key = K::from_value_box(key.to_box()).ok()?;
+        HashMap::get_mut(self, &key).map(V::as_mut)
+    }
+
     fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
         let key: K = key.downcast().expect("wrong key type");
         let value: V = value.downcast().expect("wrong value type");
         self.insert(key, value);
     }
 
+    fn remove(&mut self, key: ReflectValueRef) {
+        if let Ok(key) = K::from_value_box(key.to_box()) {
+            HashMap::remove(self, &key);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn key_type(&self) -> RuntimeTypeBox {
+        K::runtime_type_box()
+    }
+
+    fn value_type(&self) -> RuntimeTypeBox {
+        V::runtime_type_box()
+    }
+}
+
+impl<K, V> ReflectMap for BTreeMap<K, V>
+where
+    K: ProtobufValue + Ord,
+    V: ProtobufValue,
+{
+    fn reflect_iter<'a>(&'a self) -> ReflectMapIter<'a> {
+        ReflectMapIter::new(
+            GeneratedMapIterImpl::<'a, K, V, btree_map::Iter<'a, K, V>> {
+                iter: self.iter(),
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn get<'a>(&'a self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>> {
+        let key = K::from_value_box(key.to_box()).ok()?;
+        BTreeMap::get(self, &key).map(V::as_ref)
+    }
+
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueMut<'a>> {
+        let key = K::from_value_box(key.to_box()).ok()?;
+        BTreeMap::get_mut(self, &key).map(V::as_mut)
+    }
+
+    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
+        let key: K = key.downcast().expect("wrong key type");
+        let value: V = value.downcast().expect("wrong value type");
+        self.insert(key, value);
+    }
+
+    fn remove(&mut self, key: ReflectValueRef) {
+        if let Ok(key) = K::from_value_box(key.to_box()) {
+            BTreeMap::remove(self, &key);
+        }
+    }
+
     fn clear(&mut self) {
         self.clear();
     }
@@ -52,12 +127,13 @@ where
     }
 }
 
-struct GeneratedMapIterImpl<'a, K: Eq + Hash + 'static, V: 'static> {
-    iter: hash_map::Iter<'a, K, V>,
+struct GeneratedMapIterImpl<'a, K: 'static, V: 'static, I: Iterator<Item = (&'a K, &'a V)>> {
+    iter: I,
+    _marker: PhantomData<(&'a K, &'a V)>,
 }
 
-impl<'a, K: ProtobufValue + Eq + Hash, V: ProtobufValue> ReflectMapIterTrait<'a>
-    for GeneratedMapIterImpl<'a, K, V>
+impl<'a, K: ProtobufValue, V: ProtobufValue, I: Iterator<Item = (&'a K, &'a V)>>
+    ReflectMapIterTrait<'a> for GeneratedMapIterImpl<'a, K, V, I>
 {
     fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueRef<'a>)> {
         match self.iter.next() {
@@ -1,13 +1,15 @@
+use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::map::DynamicMap;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
 
 mod generated;
 
-/// Implemented for `HashMap` with appropriate keys and values
+/// Implemented for `HashMap` and `BTreeMap` with appropriate keys and values
 pub(crate) trait ReflectMap: Send + Sync + 'static {
     fn reflect_iter(&self) -> ReflectMapIter;
 
@@ -17,8 +19,12 @@ pub(crate) trait ReflectMap: Send + Sync + 'static {
 
     fn get<'a>(&'a self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>>;
 
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueMut<'a>>;
+
     fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox);
 
+    fn remove(&mut self, key: ReflectValueRef);
+
     fn clear(&mut self);
 
     fn key_type(&self) -> RuntimeTypeBox;
@@ -114,6 +120,14 @@ impl<'a> ReflectMapRef<'a> {
         }
     }
 
+    /// Find a value by given key, borrowed for the lifetime of the map.
+    pub(crate) fn get_borrowed(&self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>> {
+        match &self.imp {
+            ReflectMapRefImpl::Generated(map) => map.get(key),
+            ReflectMapRefImpl::DynamicEmpty(..) => None,
+        }
+    }
+
     /// Map key type
     pub fn key_type(&self) -> RuntimeTypeBox {
         match &self.imp {
@@ -197,10 +211,26 @@ impl<'a> ReflectMapMut<'a> {
         self.map.insert(key, value)
     }
 
+    /// Remove a value for given key.
+    pub fn remove(&mut self, key: ReflectValueRef) {
+        self.map.remove(key)
+    }
+
     /// Clear
     pub fn clear(&mut self) {
         self.map.clear();
     }
+
+    /// Mutable reference to message value for given key.
+    ///
+    /// # Panics
+    ///
+    /// If value type is not a message.
+    pub(crate) fn into_message_mut(self, key: ReflectValueRef) -> Option<&'a mut dyn MessageDyn> {
+        match self.map.get_mut(key)? {
+            ReflectValueMut::Message(m) => Some(m),
+        }
+    }
 }
 
 /// Iterator over map
@@ -20,6 +20,7 @@ pub(crate) trait MessageFactory: Send + Sync + 'static {
     fn default_instance(&self) -> &dyn MessageDyn;
     fn clone(&self, message: &dyn MessageDyn) -> Box<dyn MessageDyn>;
     fn eq(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> bool;
+    fn merge_from_message(&self, target: &mut dyn MessageDyn, source: &dyn MessageDyn);
 }
 
 impl<'a> fmt::Debug for &'a dyn MessageFactory {
@@ -54,6 +55,12 @@ where
         let b: &M = b.downcast_ref().expect("wrong message type");
         a == b
     }
+
+    fn merge_from_message(&self, target: &mut dyn MessageDyn, source: &dyn MessageDyn) {
+        let target: &mut M = target.downcast_mut().expect("wrong message type");
+        let source: &M = source.downcast_ref().expect("wrong message type");
+        target.merge_from_message(source)
+    }
 }
 
 #[doc(hidden)]
@@ -128,7 +135,7 @@ impl GeneratedMessageDescriptor {
             field_proto_by_name.insert(field_proto.name(), field_proto);
         }
 
-        let index = MessageIndex::index(proto, building);
+        let index = MessageIndex::index(proto, building).unwrap();
 
         GeneratedMessageDescriptor {
             non_map: Some(NonMapMessageDescriptor {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use crate::descriptor::DescriptorProto;
+use crate::reflect::error::ReflectError;
 use crate::reflect::field::index::FieldIndex;
 use crate::reflect::file::building::FileDescriptorBuilding;
 
@@ -17,7 +18,7 @@ impl MessageIndex {
     pub(crate) fn index(
         proto: &DescriptorProto,
         building: &FileDescriptorBuilding,
-    ) -> MessageIndex {
+    ) -> crate::Result<MessageIndex> {
         let mut index_by_name = HashMap::new();
         let mut index_by_name_or_json_name = HashMap::new();
         let mut index_by_number = HashMap::new();
@@ -26,20 +27,45 @@ impl MessageIndex {
             .field
             .iter()
             .map(|f| FieldIndex::index(f, building))
-            .collect();
+            .collect::<crate::Result<_>>()?;
         for (i, f) in proto.field.iter().enumerate() {
             let field_index = &fields[i];
 
-            assert!(index_by_number.insert(f.number() as u32, i).is_none());
-            assert!(index_by_name.insert(f.name().to_owned(), i).is_none());
-            assert!(index_by_name_or_json_name
+            if index_by_number.insert(f.number() as u32, i).is_some() {
+                return Err(ReflectError::NonUniqueFieldNumber(
+                    proto.name().to_owned(),
+                    f.number(),
+                )
+                .into());
+            }
+            if index_by_name.insert(f.name().to_owned(), i).is_some() {
+                return Err(ReflectError::NonUniqueFieldName(
+                    proto.name().to_owned(),
+                    f.name().to_owned(),
+                )
+                .into());
+            }
+            if index_by_name_or_json_name
                 .insert(f.name().to_owned(), i)
-                .is_none());
+                .is_some()
+            {
+                return Err(ReflectError::NonUniqueFieldName(
+                    proto.name().to_owned(),
+                    f.name().to_owned(),
+                )
+                .into());
+            }
 
-            if field_index.json_name != f.name() {
-                assert!(index_by_name_or_json_name
+            if field_index.json_name != f.name()
+                && index_by_name_or_json_name
                     .insert(field_index.json_name.clone(), i)
-                    .is_none());
+                    .is_some()
+            {
+                return Err(ReflectError::NonUniqueFieldName(
+                    proto.name().to_owned(),
+                    field_index.json_name.clone(),
+                )
+                .into());
             }
         }
 
@@ -47,14 +73,14 @@ impl MessageIndex {
             .extension
             .iter()
             .map(|f| FieldIndex::index(f, building))
-            .collect();
+            .collect::<crate::Result<_>>()?;
 
-        MessageIndex {
+        Ok(MessageIndex {
             fields,
             field_index_by_name: index_by_name,
             field_index_by_name_or_json_name: index_by_name_or_json_name,
             field_index_by_number: index_by_number,
             extensions,
-        }
+        })
     }
 }
@@ -7,10 +7,22 @@ use crate::reflect::reflect_eq::ReflectEqMode;
 use crate::reflect::MessageDescriptor;
 use crate::MessageFull;
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 enum MessageRefImpl<'a> {
     Message(&'a dyn MessageDyn),
     EmptyDynamic(DynamicMessage),
+    /// Message decoded on access, e. g. extension value stored in unknown fields.
+    Owned(Box<dyn MessageDyn>),
+}
+
+impl<'a> Clone for MessageRefImpl<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            MessageRefImpl::Message(m) => MessageRefImpl::Message(*m),
+            MessageRefImpl::EmptyDynamic(m) => MessageRefImpl::EmptyDynamic(m.clone()),
+            MessageRefImpl::Owned(m) => MessageRefImpl::Owned(m.clone_box()),
+        }
+    }
 }
 
 /// Wrapper around either [`MessageFull`] reference or a container for an empty dynamic message.
@@ -51,6 +63,12 @@ impl<'a> MessageRef<'a> {
         }
     }
 
+    pub(crate) fn owned(message: Box<dyn MessageDyn>) -> MessageRef<'a> {
+        MessageRef {
+            imp: MessageRefImpl::Owned(message),
+        }
+    }
+
     /// Default (empty) instance of given message type.
     pub fn default_instance(message: &MessageDescriptor) -> MessageRef<'static> {
         // Note we create a native generated instance for generated types
@@ -62,6 +80,14 @@ impl<'a> MessageRef<'a> {
             },
         }
     }
+
+    /// Referenced message if it is borrowed for `'a` rather than owned by this object.
+    pub(crate) fn as_borrowed(&self) -> Option<&'a dyn MessageDyn> {
+        match &self.imp {
+            MessageRefImpl::Message(m) => Some(*m),
+            MessageRefImpl::EmptyDynamic(..) | MessageRefImpl::Owned(..) => None,
+        }
+    }
 }
 
 impl<'a> Deref for MessageRef<'a> {
@@ -71,6 +97,7 @@ impl<'a> Deref for MessageRef<'a> {
         match &self.imp {
             MessageRefImpl::Message(m) => *m,
             MessageRefImpl::EmptyDynamic(e) => e,
+            MessageRefImpl::Owned(m) => &**m,
         }
     }
 }
@@ -18,7 +18,10 @@ use crate::reflect::EnumDescriptor;
 use crate::reflect::FieldDescriptor;
 use crate::reflect::FileDescriptor;
 use crate::reflect::OneofDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
 use crate::CodedInputStream;
+use crate::ParseOptions;
 
 pub(crate) mod dynamic;
 pub(crate) mod generated;
@@ -202,6 +205,63 @@ impl MessageDescriptor {
         }
     }
 
+    /// Merge `source` into `target` with protobuf semantics:
+    /// set singular fields overwrite, repeated fields are appended,
+    /// map entries are inserted, message fields are merged recursively,
+    /// and oneof fields replace the other set oneof field.
+    /// Extensions are merged like fields. Unknown fields are appended.
+    ///
+    /// Generated messages use [`Message::merge_from_message`](crate::Message::merge_from_message).
+    ///
+    /// # Panics
+    ///
+    /// Is any message has different type than this descriptor.
+    pub fn merge_from_message(&self, target: &mut dyn MessageDyn, source: &dyn MessageDyn) {
+        assert_eq!(self, &target.descriptor_dyn());
+        assert_eq!(self, &source.descriptor_dyn());
+        match self.get_impl() {
+            MessageDescriptorImplRef::Generated(g) => {
+                g.non_map().factory.merge_from_message(target, source)
+            }
+            MessageDescriptorImplRef::Dynamic(..) => {
+                self.merge_from_message_reflect(target, source)
+            }
+        }
+    }
+
+    /// Reflective implementation of `merge_from_message`.
+    pub(crate) fn merge_from_message_reflect(
+        &self,
+        target: &mut dyn MessageDyn,
+        source: &dyn MessageDyn,
+    ) {
+        let extensions = DynamicMessage::downcast_ref(source).extension_fields();
+        for field in self.fields().chain(extensions) {
+            match field.get_reflect(source) {
+                ReflectFieldRef::Optional(None) => {}
+                ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => {
+                    field.mut_message(target).merge_from_message_dyn(&*m);
+                }
+                ReflectFieldRef::Optional(Some(v)) => field.set_singular_field(target, v.to_box()),
+                ReflectFieldRef::Repeated(r) => {
+                    let mut target = field.mut_repeated(target);
+                    for v in r {
+                        target.push(v.to_box());
+                    }
+                }
+                ReflectFieldRef::Map(m) => {
+                    let mut target = field.mut_map(target);
+                    for (k, v) in &m {
+                        target.insert(k.to_box(), v.to_box());
+                    }
+                }
+            }
+        }
+        target
+            .mut_unknown_fields_dyn()
+            .extend_from(source.unknown_fields_dyn());
+    }
+
     /// Similar to `eq`, but considers `NaN` values equal.
     ///
     /// # Panics
@@ -247,7 +307,7 @@ impl MessageDescriptor {
         &self.index_entry().name_to_package
     }
 
-    /// Nested oneofs
+    /// Nested oneofs, including synthetic oneofs (see [`OneofDescriptor::is_synthetic`]).
     pub fn oneofs<'a>(&'a self) -> impl ExactSizeIterator<Item = OneofDescriptor> + 'a {
         self.proto()
             .oneof_decl
@@ -319,7 +379,7 @@ impl MessageDescriptor {
     /// Parse message from stream.
     pub fn parse_from(&self, is: &mut CodedInputStream) -> crate::Result<Box<dyn MessageDyn>> {
         let mut r = self.new_instance();
-        r.merge_from_dyn(is)?;
+        is.merge_nested(|is| r.merge_from_dyn(is))?;
         r.check_initialized_dyn()?;
         Ok(r)
     }
@@ -340,6 +400,32 @@ impl MessageDescriptor {
         is.check_eof()?;
         Ok(r)
     }
+
+    /// Parse message from reader with decode limits.
+    pub fn parse_from_reader_with_options(
+        &self,
+        reader: &mut dyn Read,
+        options: &ParseOptions,
+    ) -> crate::Result<Box<dyn MessageDyn>> {
+        let mut is = CodedInputStream::new(reader);
+        is.set_parse_options(options);
+        let r = self.parse_from(&mut is)?;
+        is.check_eof()?;
+        Ok(r)
+    }
+
+    /// Parse message from byte array with decode limits.
+    pub fn parse_from_bytes_with_options(
+        &self,
+        bytes: &[u8],
+        options: &ParseOptions,
+    ) -> crate::Result<Box<dyn MessageDyn>> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        is.set_parse_options(options);
+        let r = self.parse_from(&mut is)?;
+        is.check_eof()?;
+        Ok(r)
+    }
 }
 
 pub(crate) enum MessageDescriptorImplRef<'a> {
@@ -12,59 +12,145 @@
 //!
 //! The API roughly follows Google protobuf C++ and Java API.
 //! Some minor adjustements are made to make code more idiomatic to rust.
+//!
+//! Without `std` feature only [`types`] module is available.
 
+#[cfg(feature = "std")]
 mod acc;
+#[cfg(feature = "std")]
+mod descriptor_pool;
+#[cfg(feature = "std")]
+mod differencer;
+#[cfg(feature = "std")]
 mod dynamic;
+#[cfg(feature = "std")]
 mod enums;
+#[cfg(feature = "std")]
+pub(crate) mod error;
+#[cfg(feature = "std")]
+mod extension_registry;
+#[cfg(feature = "std")]
 mod field;
+#[cfg(feature = "std")]
+mod field_path;
+#[cfg(feature = "std")]
 mod file;
+#[cfg(feature = "std")]
 mod find_message_or_enum;
+#[cfg(feature = "std")]
 mod map;
+#[cfg(feature = "std")]
 pub(crate) mod message;
+#[cfg(feature = "std")]
 mod oneof;
+#[cfg(feature = "std")]
 mod protobuf_type_box;
+#[cfg(feature = "std")]
 mod repeated;
+#[cfg(feature = "std")]
 mod runtime_type_box;
+#[cfg(feature = "std")]
 mod service;
+#[cfg(feature = "std")]
 mod type_dynamic;
+#[cfg(feature = "std")]
+mod type_registry;
+#[cfg(feature = "std")]
 pub(crate) mod value;
+#[cfg(feature = "std")]
+mod visitor;
 
 // TODO: this is referenced from generated code.
 //   Do something about it.
+#[cfg(feature = "std")]
 pub mod runtime_types;
 pub mod types;
 
+#[cfg(feature = "std")]
 pub(crate) mod reflect_eq;
 
+#[cfg(feature = "std")]
 pub mod rt;
 
+#[cfg(feature = "std")]
 pub(crate) mod name;
 
+#[cfg(feature = "std")]
+pub use self::descriptor_pool::DescriptorPool;
+#[cfg(feature = "std")]
+pub use self::differencer::Difference;
+#[cfg(feature = "std")]
+pub use self::differencer::DifferenceKind;
+#[cfg(feature = "std")]
+pub use self::differencer::MessageDifferencer;
 #[doc(hidden)]
+#[cfg(feature = "std")]
 pub use self::enums::generated::GeneratedEnumDescriptorData;
+#[cfg(feature = "std")]
 pub use self::enums::EnumDescriptor;
+#[cfg(feature = "std")]
 pub use self::enums::EnumValueDescriptor;
+#[cfg(feature = "std")]
+pub use self::extension_registry::ExtensionRegistry;
+#[cfg(feature = "std")]
 pub use self::field::runtime_field_type::RuntimeFieldType;
+#[cfg(feature = "std")]
 pub use self::field::FieldDescriptor;
+#[cfg(feature = "std")]
 pub use self::field::ReflectFieldRef;
+#[cfg(feature = "std")]
+pub use self::field_path::FieldPath;
 #[doc(hidden)]
+#[cfg(feature = "std")]
 pub use self::file::generated::GeneratedFileDescriptor;
+#[cfg(feature = "std")]
 pub use self::file::syntax::Syntax;
+#[cfg(feature = "std")]
 pub use self::file::FileDescriptor;
+#[cfg(feature = "std")]
 pub use self::map::ReflectMapMut;
+#[cfg(feature = "std")]
 pub use self::map::ReflectMapRef;
 #[doc(hidden)]
+#[cfg(feature = "std")]
 pub use self::message::generated::GeneratedMessageDescriptorData;
+#[cfg(feature = "std")]
 pub use self::message::message_ref::MessageRef;
+#[cfg(feature = "std")]
 pub use self::message::MessageDescriptor;
+#[cfg(feature = "std")]
 pub use self::oneof::OneofDescriptor;
+#[cfg(feature = "std")]
 pub use self::reflect_eq::ReflectEq;
+#[cfg(feature = "std")]
 pub use self::reflect_eq::ReflectEqMode;
+#[cfg(feature = "std")]
 pub use self::repeated::ReflectRepeatedMut;
+#[cfg(feature = "std")]
 pub use self::repeated::ReflectRepeatedRef;
+#[cfg(feature = "std")]
 pub use self::runtime_type_box::RuntimeTypeBox;
+#[cfg(feature = "std")]
 pub use self::service::MethodDescriptor;
+#[cfg(feature = "std")]
 pub use self::service::ServiceDescriptor;
+#[cfg(feature = "std")]
+pub use self::type_registry::TypeRegistry;
+#[cfg(feature = "std")]
 pub use self::value::value_box::ReflectValueBox;
+#[cfg(feature = "std")]
 pub use self::value::value_ref::ReflectValueRef;
+#[cfg(feature = "std")]
 pub use self::value::ProtobufValue;
+#[cfg(feature = "std")]
+pub use self::visitor::walk_message;
+#[cfg(feature = "std")]
+pub use self::visitor::walk_message_mut;
+#[cfg(feature = "std")]
+pub use self::visitor::MessageVisitor;
+#[cfg(feature = "std")]
+pub use self::visitor::MessageVisitorMut;
+#[cfg(feature = "std")]
+pub use self::visitor::ValuePosition;
+#[cfg(feature = "std")]
+pub use self::visitor::VisitAction;
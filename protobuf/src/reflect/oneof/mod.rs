@@ -26,10 +26,27 @@ impl OneofDescriptor {
         format!("{}.{}", self.message_descriptor.full_name(), self.name())
     }
 
+    /// Synthetic oneof is generated by `protoc` for proto3 `optional` field.
+    ///
+    /// Such oneof contains exactly one field, and should not be treated as a oneof
+    /// by code generators or users.
+    pub fn is_synthetic(&self) -> bool {
+        let mut fields = self
+            .message_descriptor
+            .proto()
+            .field
+            .iter()
+            .filter(|f| f.has_oneof_index() && f.oneof_index() as usize == self.index);
+        match (fields.next(), fields.next()) {
+            (Some(f), None) => f.proto3_optional(),
+            _ => false,
+        }
+    }
+
     /// Fields in this oneof.
     pub fn fields<'a>(&'a self) -> impl Iterator<Item = FieldDescriptor> + 'a {
         self.message_descriptor
             .fields()
-            .filter(move |f| f.containing_oneof().as_ref() == Some(self))
+            .filter(move |f| f.containing_oneof_including_synthetic().as_ref() == Some(self))
     }
 }
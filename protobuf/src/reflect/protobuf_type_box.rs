@@ -62,7 +62,7 @@ impl ProtobufTypeBox {
             (Type::TYPE_BYTES, RuntimeTypeBox::VecU8) => {}
             (Type::TYPE_MESSAGE, RuntimeTypeBox::Message(..)) => {}
             (Type::TYPE_ENUM, RuntimeTypeBox::Enum(..)) => {}
-            (Type::TYPE_GROUP, ..) => return Err(ProtobufError::GroupIsNotImplemented.into()),
+            (Type::TYPE_GROUP, RuntimeTypeBox::Message(..)) => {}
             _ => return Err(ProtobufError::IncompatibleProtobufTypeAndRuntimeType.into()),
         }
         Ok(ProtobufTypeBox { runtime, t })
@@ -71,6 +71,7 @@ impl ProtobufTypeBox {
     pub(crate) fn read(
         &self,
         is: &mut CodedInputStream,
+        field_number: u32,
         wire_type: WireType,
     ) -> crate::Result<ReflectValueBox> {
         if wire_type != WireType::for_type(self.t) {
@@ -99,9 +100,21 @@ impl ProtobufTypeBox {
                 }
                 _ => unreachable!(),
             },
-            Type::TYPE_GROUP => return Err(ProtobufError::GroupIsNotImplemented.into()),
+            Type::TYPE_GROUP => match &self.runtime {
+                RuntimeTypeBox::Message(m) => {
+                    // Required fields are checked once for the whole message tree.
+                    let mut message = m.new_instance();
+                    is.merge_group_dyn(field_number, &mut *message)?;
+                    ReflectValueBox::Message(message)
+                }
+                _ => unreachable!(),
+            },
             Type::TYPE_MESSAGE => match &self.runtime {
-                RuntimeTypeBox::Message(m) => ReflectValueBox::Message(is.read_message_dyn(m)?),
+                RuntimeTypeBox::Message(m) => {
+                    let mut message = m.new_instance();
+                    is.merge_message_dyn(&mut *message)?;
+                    ReflectValueBox::Message(message)
+                }
                 _ => unreachable!(),
             },
         })
@@ -110,11 +123,12 @@ impl ProtobufTypeBox {
     pub(crate) fn read_repeated_into(
         &self,
         is: &mut CodedInputStream,
+        field_number: u32,
         wire_type: WireType,
         repeated: &mut ReflectRepeatedMut,
     ) -> crate::Result<()> {
         if wire_type == WireType::for_type(self.t) {
-            let value = self.read(is, wire_type)?;
+            let value = self.read(is, field_number, wire_type)?;
             repeated.push(value);
             Ok(())
         } else if wire_type == WireType::LengthDelimited {
@@ -215,8 +229,7 @@ impl ProtobufTypeBox {
                     }
                     _ => unreachable!(),
                 },
-                Type::TYPE_GROUP => Err(ProtobufError::GroupIsNotImplemented.into()),
-                Type::TYPE_MESSAGE | Type::TYPE_STRING | Type::TYPE_BYTES => {
+                Type::TYPE_GROUP | Type::TYPE_MESSAGE | Type::TYPE_STRING | Type::TYPE_BYTES => {
                     Err(WireError::UnexpectedWireType(wire_type).into())
                 }
             }
@@ -3,9 +3,11 @@ use std::fmt;
 use std::mem;
 use std::slice;
 
+use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::repeated::DynamicRepeated;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::value::value_ref::ReflectValueRef;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
@@ -28,6 +30,19 @@ pub(crate) trait ReflectRepeated: Sync + 'static + fmt::Debug {
     ///
     /// * if the element type does not match the collection element type
     fn push(&mut self, value: ReflectValueBox);
+    /// Remove element at index.
+    ///
+    /// # Panics
+    ///
+    /// * if index is out of bounds
+    fn remove(&mut self, index: usize);
+    /// Get mutable reference to element at index.
+    ///
+    /// # Panics
+    ///
+    /// * if index is out of bounds
+    /// * if the element type is not a message
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut;
     fn clear(&mut self);
     /// Get the collection element type.
     fn element_type(&self) -> RuntimeTypeBox;
@@ -80,6 +95,14 @@ impl<V: ProtobufValue> ReflectRepeated for Vec<V> {
         self.push(value)
     }
 
+    fn remove(&mut self, index: usize) {
+        Vec::remove(self, index);
+    }
+
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut {
+        V::as_mut(&mut self[index])
+    }
+
     fn clear(&mut self) {
         self.clear()
     }
@@ -146,6 +169,14 @@ impl<V: ProtobufValue> ReflectRepeated for [V] {
         panic!("push is not possible for [V]");
     }
 
+    fn remove(&mut self, _index: usize) {
+        panic!("remove is not possible for [V]");
+    }
+
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut {
+        V::as_mut(&mut self[index])
+    }
+
     fn clear(&mut self) {
         panic!("clear is not possible for [V]");
     }
@@ -263,6 +294,9 @@ impl<'a> IntoIterator for &'a dyn ReflectRepeated {
 enum ReflectRepeatedRefImpl<'a> {
     Generated(&'a dyn ReflectRepeated),
     DynamicEmpty(DynamicRepeated),
+    /// Values decoded on access, e. g. repeated extension stored in unknown fields.
+    /// Values are also stored in `DynamicRepeated` to provide typed slices.
+    Decoded(DynamicRepeated, Vec<ReflectValueRef<'a>>),
 }
 
 impl<'a> fmt::Debug for ReflectRepeatedRefImpl<'a> {
@@ -270,6 +304,7 @@ impl<'a> fmt::Debug for ReflectRepeatedRefImpl<'a> {
         match self {
             ReflectRepeatedRefImpl::Generated(r) => fmt::Debug::fmt(r, f),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => fmt::Debug::fmt(r, f),
+            ReflectRepeatedRefImpl::Decoded(r, _) => fmt::Debug::fmt(r, f),
         }
     }
 }
@@ -298,11 +333,25 @@ impl<'a> ReflectRepeatedRef<'a> {
         }
     }
 
+    pub(crate) fn new_decoded(
+        elem: RuntimeTypeBox,
+        values: Vec<ReflectValueRef<'a>>,
+    ) -> ReflectRepeatedRef<'a> {
+        let mut repeated = DynamicRepeated::new(elem);
+        for v in &values {
+            repeated.push(v.to_box());
+        }
+        ReflectRepeatedRef {
+            imp: ReflectRepeatedRefImpl::Decoded(repeated, values),
+        }
+    }
+
     /// Number of elements in repeated field
     pub fn len(&self) -> usize {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(g) => g.len(),
             ReflectRepeatedRefImpl::DynamicEmpty(d) => d.len(),
+            ReflectRepeatedRefImpl::Decoded(_, v) => v.len(),
         }
     }
 
@@ -317,6 +366,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.get(index),
             ReflectRepeatedRefImpl::DynamicEmpty(..) => panic!("empty"),
+            ReflectRepeatedRefImpl::Decoded(_, v) => v[index].clone(),
         }
     }
 
@@ -325,6 +375,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.element_type(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.element_type(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.element_type(),
         }
     }
 
@@ -332,6 +383,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_enum_values(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_enum_values(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_enum_values(),
         }
     }
 
@@ -339,6 +391,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_bool(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_bool(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_bool(),
         }
     }
 
@@ -346,6 +399,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_u32(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_u32(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_u32(),
         }
     }
 
@@ -353,6 +407,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_i32(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_i32(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_i32(),
         }
     }
 
@@ -360,6 +415,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_u64(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_u64(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_u64(),
         }
     }
 
@@ -367,6 +423,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_i64(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_i64(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_i64(),
         }
     }
 
@@ -374,6 +431,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_f32(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_f32(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_f32(),
         }
     }
 
@@ -381,6 +439,7 @@ impl<'a> ReflectRepeatedRef<'a> {
         match &self.imp {
             ReflectRepeatedRefImpl::Generated(r) => r.data_f64(),
             ReflectRepeatedRefImpl::DynamicEmpty(r) => r.data_f64(),
+            ReflectRepeatedRefImpl::Decoded(r, _) => r.data_f64(),
         }
     }
 }
@@ -504,10 +563,30 @@ impl<'a> ReflectRepeatedMut<'a> {
         self.repeated.push(value);
     }
 
+    /// Remove an item at given index.
+    ///
+    /// # Panics
+    ///
+    /// If index if out of range
+    pub fn remove(&mut self, index: usize) {
+        self.repeated.remove(index);
+    }
+
     /// Self-explanatory
     pub fn clear(&mut self) {
         self.repeated.clear();
     }
+
+    /// Mutable reference to message element.
+    ///
+    /// # Panics
+    ///
+    /// If index if out of range or element type is not a message
+    pub(crate) fn into_message_mut(self, index: usize) -> &'a mut dyn MessageDyn {
+        match self.repeated.get_mut(index) {
+            ReflectValueMut::Message(m) => m,
+        }
+    }
 }
 
 /// Iterator over repeated field.
@@ -12,14 +12,14 @@ impl ServiceIndex {
     pub(crate) fn index(
         proto: &ServiceDescriptorProto,
         building: &FileDescriptorBuilding,
-    ) -> ServiceIndex {
-        ServiceIndex {
+    ) -> crate::Result<ServiceIndex> {
+        Ok(ServiceIndex {
             methods: proto
                 .method
                 .iter()
                 .map(|method| MethodIndex::index(method, building))
-                .collect(),
-        }
+                .collect::<crate::Result<_>>()?,
+        })
     }
 }
 
@@ -33,12 +33,12 @@ impl MethodIndex {
     pub(crate) fn index(
         proto: &MethodDescriptorProto,
         building: &FileDescriptorBuilding,
-    ) -> MethodIndex {
-        let input_type = building.resolve_message(proto.input_type());
-        let output_type = building.resolve_message(proto.output_type());
-        MethodIndex {
+    ) -> crate::Result<MethodIndex> {
+        let input_type = building.resolve_message(proto.input_type())?;
+        let output_type = building.resolve_message(proto.output_type())?;
+        Ok(MethodIndex {
             input_type,
             output_type,
-        }
+        })
     }
 }
@@ -49,6 +49,7 @@ impl ServiceDescriptor {
 }
 
 /// Service method descriptor.
+#[derive(Clone, Eq, PartialEq)]
 pub struct MethodDescriptor {
     service_descriptor: ServiceDescriptor,
     index: usize,
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::reflect::FileDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::well_known_types::Any;
+use crate::MessageFull;
+
+/// Set of message types which can be looked up by name.
+///
+/// Used to resolve the message type stored in
+/// [`Any`](crate::well_known_types::Any) when printing or parsing JSON.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::reflect::TypeRegistry;
+/// use protobuf::well_known_types::Duration;
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.add_message_type::<Duration>();
+/// assert!(registry
+///     .find_message_by_type_url("type.googleapis.com/google.protobuf.Duration")
+///     .is_some());
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct TypeRegistry {
+    messages: HashMap<String, MessageDescriptor>,
+}
+
+impl TypeRegistry {
+    /// Create an empty registry.
+    pub fn new() -> TypeRegistry {
+        TypeRegistry::default()
+    }
+
+    /// Register a single message type.
+    pub fn add_message(&mut self, descriptor: MessageDescriptor) {
+        self.messages
+            .insert(descriptor.full_name().to_owned(), descriptor);
+    }
+
+    /// Register a generated message type.
+    pub fn add_message_type<M: MessageFull>(&mut self) {
+        self.add_message(M::descriptor_static());
+    }
+
+    /// Register all messages (including nested messages)
+    /// defined in the file and all its dependencies.
+    pub fn add_file(&mut self, file: &FileDescriptor) {
+        for file in file.all_files() {
+            for i in 0..file.index().index.messages.len() {
+                self.add_message(MessageDescriptor::new(file.clone(), i));
+            }
+        }
+    }
+
+    /// Find message by fully qualified name (e. g. `google.protobuf.Duration`).
+    pub fn find_message_by_full_name(&self, full_name: &str) -> Option<MessageDescriptor> {
+        self.messages.get(full_name).cloned()
+    }
+
+    /// Find message by [`Any`](crate::well_known_types::Any) type URL
+    /// (e. g. `type.googleapis.com/google.protobuf.Duration`).
+    pub fn find_message_by_type_url(&self, type_url: &str) -> Option<MessageDescriptor> {
+        self.find_message_by_full_name(Any::type_name_from_type_url(type_url)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::FileDescriptorProto;
+    use crate::reflect::TypeRegistry;
+    use crate::well_known_types::Duration;
+    use crate::MessageFull;
+
+    #[test]
+    fn add_file() {
+        let mut registry = TypeRegistry::new();
+        registry.add_file(FileDescriptorProto::descriptor_static().file_descriptor());
+        let d = registry
+            .find_message_by_type_url(
+                "type.googleapis.com/google.protobuf.DescriptorProto.ExtensionRange",
+            )
+            .unwrap();
+        assert_eq!("ExtensionRange", d.name());
+        assert!(registry
+            .find_message_by_full_name("google.protobuf.Duration")
+            .is_none());
+    }
+
+    #[test]
+    fn add_message_type() {
+        let mut registry = TypeRegistry::new();
+        registry.add_message_type::<Duration>();
+        assert_eq!(
+            Duration::descriptor_static(),
+            registry
+                .find_message_by_full_name("google.protobuf.Duration")
+                .unwrap()
+        );
+        assert!(registry
+            .find_message_by_type_url("google.protobuf.Duration")
+            .is_none());
+    }
+}
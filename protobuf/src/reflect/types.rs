@@ -2,7 +2,9 @@
 
 #![doc(hidden)]
 
-use std::marker;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker;
 
 #[cfg(feature = "bytes")]
 use ::bytes::Bytes;
@@ -13,9 +15,13 @@ use crate::coded_input_stream::CodedInputStream;
 use crate::coded_output_stream::CodedOutputStream;
 use crate::enums::Enum;
 use crate::error::Result;
+#[cfg(feature = "std")]
 pub use crate::reflect::type_dynamic::ProtobufTypeDynamic;
+#[cfg(feature = "std")]
 use crate::reflect::type_dynamic::ProtobufTypeDynamicImpl;
+#[cfg(feature = "std")]
 use crate::reflect::ProtobufValue;
+use crate::reverse_output_stream::ReverseOutputStream;
 use crate::rt;
 use crate::unknown::UnknownValues;
 use crate::wire_format::WireType;
@@ -30,6 +36,7 @@ pub trait ProtobufType: Send + Sync + Clone + Sized + 'static {
     type ProtobufValue: Default;
 
     /// Dynamic version of this
+    #[cfg(feature = "std")]
     fn dynamic() -> &'static dyn ProtobufTypeDynamic
     where
         Self::ProtobufValue: ProtobufValue,
@@ -83,6 +90,20 @@ pub trait ProtobufType: Send + Sync + Clone + Sized + 'static {
         value: &Self::ProtobufValue,
         os: &mut CodedOutputStream,
     ) -> Result<()>;
+
+    /// Write a value with a tag to the stream filled from the end.
+    ///
+    /// Nested messages are written without cached sizes.
+    fn write_reverse(
+        field_number: u32,
+        value: &Self::ProtobufValue,
+        os: &mut ReverseOutputStream,
+    ) -> Result<()> {
+        let size = rt::tag_size(field_number) + Self::compute_size_with_length_delimiter(value);
+        os.write_forward(size as usize, |os| {
+            Self::write_with_cached_size(field_number, value, os)
+        })
+    }
 }
 
 /// All fixed size types
@@ -184,6 +205,11 @@ impl ProtobufType for ProtobufTypeFloat {
     ) -> Result<()> {
         os.write_float(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &f32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_float(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeFloat {
@@ -219,6 +245,11 @@ impl ProtobufType for ProtobufTypeDouble {
     ) -> Result<()> {
         os.write_double(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &f64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_double(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeDouble {
@@ -250,6 +281,11 @@ impl ProtobufType for ProtobufTypeInt32 {
         os.write_int32(field_number, *value)
     }
 
+    fn write_reverse(field_number: u32, value: &i32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_int32(field_number, *value);
+        Ok(())
+    }
+
     fn get_from_unknown(unknown_values: &UnknownValues) -> Option<i32> {
         unknown_values.varint.iter().rev().next().map(|&v| v as i32)
     }
@@ -279,6 +315,11 @@ impl ProtobufType for ProtobufTypeInt64 {
     ) -> Result<()> {
         os.write_int64(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &i64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_int64(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeUint32 {
@@ -305,6 +346,11 @@ impl ProtobufType for ProtobufTypeUint32 {
     ) -> Result<()> {
         os.write_uint32(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &u32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_uint32(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeUint64 {
@@ -331,6 +377,11 @@ impl ProtobufType for ProtobufTypeUint64 {
     ) -> Result<()> {
         os.write_uint64(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &u64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_uint64(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeSint32 {
@@ -357,6 +408,11 @@ impl ProtobufType for ProtobufTypeSint32 {
     ) -> Result<()> {
         os.write_sint32(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &i32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_sint32(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeSint64 {
@@ -383,6 +439,11 @@ impl ProtobufType for ProtobufTypeSint64 {
     ) -> Result<()> {
         os.write_sint64(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &i64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_sint64(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeFixed32 {
@@ -409,6 +470,11 @@ impl ProtobufType for ProtobufTypeFixed32 {
     ) -> Result<()> {
         os.write_fixed32(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &u32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_fixed32(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeFixed32 {
@@ -439,6 +505,11 @@ impl ProtobufType for ProtobufTypeFixed64 {
     ) -> Result<()> {
         os.write_fixed64(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &u64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_fixed64(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeFixed64 {
@@ -469,6 +540,11 @@ impl ProtobufType for ProtobufTypeSfixed32 {
     ) -> Result<()> {
         os.write_sfixed32(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &i32, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_sfixed32(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeSfixed32 {
@@ -499,6 +575,11 @@ impl ProtobufType for ProtobufTypeSfixed64 {
     ) -> Result<()> {
         os.write_sfixed64(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &i64, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_sfixed64(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufTypeFixed for ProtobufTypeSfixed64 {
@@ -529,6 +610,11 @@ impl ProtobufType for ProtobufTypeBool {
     ) -> Result<()> {
         os.write_bool(field_number, *value)
     }
+
+    fn write_reverse(field_number: u32, value: &bool, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_bool(field_number, *value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeString {
@@ -557,6 +643,15 @@ impl ProtobufType for ProtobufTypeString {
     ) -> Result<()> {
         os.write_string(field_number, &value)
     }
+
+    fn write_reverse(
+        field_number: u32,
+        value: &String,
+        os: &mut ReverseOutputStream,
+    ) -> Result<()> {
+        os.write_string(field_number, value);
+        Ok(())
+    }
 }
 
 impl ProtobufType for ProtobufTypeBytes {
@@ -583,6 +678,15 @@ impl ProtobufType for ProtobufTypeBytes {
     ) -> Result<()> {
         os.write_bytes(field_number, &value)
     }
+
+    fn write_reverse(
+        field_number: u32,
+        value: &Vec<u8>,
+        os: &mut ReverseOutputStream,
+    ) -> Result<()> {
+        os.write_bytes(field_number, value);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -610,6 +714,11 @@ impl ProtobufType for ProtobufTypeTokioBytes {
     ) -> Result<()> {
         os.write_bytes(field_number, &value)
     }
+
+    fn write_reverse(field_number: u32, value: &Bytes, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_bytes(field_number, value);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -637,6 +746,11 @@ impl ProtobufType for ProtobufTypeTokioChars {
     ) -> Result<()> {
         os.write_string(field_number, &value)
     }
+
+    fn write_reverse(field_number: u32, value: &Chars, os: &mut ReverseOutputStream) -> Result<()> {
+        os.write_string(field_number, value);
+        Ok(())
+    }
 }
 
 impl<E: Enum> ProtobufType for ProtobufTypeEnumOrUnknown<E> {
@@ -653,7 +767,7 @@ impl<E: Enum> ProtobufType for ProtobufTypeEnumOrUnknown<E> {
     }
 
     fn compute_size(value: &EnumOrUnknown<E>) -> u64 {
-        rt::compute_raw_varint32_size(value.value() as u32) // TODO: wrap
+        ProtobufTypeInt32::compute_size(&value.value())
     }
 
     fn write_with_cached_size(
@@ -663,6 +777,15 @@ impl<E: Enum> ProtobufType for ProtobufTypeEnumOrUnknown<E> {
     ) -> Result<()> {
         os.write_enum_or_unknown(field_number, *value)
     }
+
+    fn write_reverse(
+        field_number: u32,
+        value: &EnumOrUnknown<E>,
+        os: &mut ReverseOutputStream,
+    ) -> Result<()> {
+        os.write_enum(field_number, value.value());
+        Ok(())
+    }
 }
 
 impl<M: Message + Clone + Default> ProtobufType for ProtobufTypeMessage<M> {
@@ -671,17 +794,16 @@ impl<M: Message + Clone + Default> ProtobufType for ProtobufTypeMessage<M> {
     const WIRE_TYPE: WireType = WireType::LengthDelimited;
 
     fn read(is: &mut CodedInputStream) -> Result<M> {
-        is.read_message()
+        rt::read_message_no_check(is)
     }
 
     fn get_from_unknown(unknown_values: &UnknownValues) -> Option<M> {
-        // TODO: do not panic
         unknown_values
             .length_delimited
             .iter()
             .rev()
             .next()
-            .map(|bytes| M::parse_from_bytes(bytes).expect("cannot parse message"))
+            .and_then(|bytes| M::parse_from_bytes(bytes).ok())
     }
 
     fn compute_size(value: &M) -> u64 {
@@ -702,4 +824,12 @@ impl<M: Message + Clone + Default> ProtobufType for ProtobufTypeMessage<M> {
         value.write_to_with_cached_sizes(os)?;
         Ok(())
     }
+
+    fn write_reverse(
+        field_number: u32,
+        value: &Self::ProtobufValue,
+        os: &mut ReverseOutputStream,
+    ) -> Result<()> {
+        rt::write_message_field_reverse(field_number, value, os)
+    }
 }
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::mem;
@@ -75,6 +77,36 @@ impl<'a> ReflectValueRef<'a> {
         }
     }
 
+    /// Compare map keys of the same type.
+    ///
+    /// # Panics
+    ///
+    /// If values are not valid map keys of the same type.
+    pub(crate) fn cmp_map_key(&self, other: &ReflectValueRef) -> Ordering {
+        match (self, other) {
+            (ReflectValueRef::U32(a), ReflectValueRef::U32(b)) => a.cmp(b),
+            (ReflectValueRef::U64(a), ReflectValueRef::U64(b)) => a.cmp(b),
+            (ReflectValueRef::I32(a), ReflectValueRef::I32(b)) => a.cmp(b),
+            (ReflectValueRef::I64(a), ReflectValueRef::I64(b)) => a.cmp(b),
+            (ReflectValueRef::Bool(a), ReflectValueRef::Bool(b)) => a.cmp(b),
+            (ReflectValueRef::String(a), ReflectValueRef::String(b)) => a.cmp(b),
+            (a, b) => panic!("not comparable map keys: {:?} and {:?}", a, b),
+        }
+    }
+
+    /// Format map key the same way generated code formats Rust map keys.
+    pub(crate) fn map_key_debug(&self) -> &dyn fmt::Debug {
+        match self {
+            ReflectValueRef::U32(v) => v,
+            ReflectValueRef::U64(v) => v,
+            ReflectValueRef::I32(v) => v,
+            ReflectValueRef::I64(v) => v,
+            ReflectValueRef::Bool(v) => v,
+            ReflectValueRef::String(v) => v,
+            k => k,
+        }
+    }
+
     pub(crate) fn is_initialized(&self) -> bool {
         if let ReflectValueRef::Message(m) = self {
             m.is_initialized_dyn()
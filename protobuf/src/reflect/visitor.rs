@@ -0,0 +1,301 @@
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::MessageDyn;
+use crate::UnknownFields;
+
+/// Position of a value in a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValuePosition<'a> {
+    /// Value of a singular field.
+    Singular,
+    /// Element of a repeated field at given index.
+    Index(usize),
+    /// Value of a map field at given key.
+    Key(ReflectValueRef<'a>),
+}
+
+/// Callbacks for [`walk_message`].
+///
+/// Messages are walked depth first. For each message, set fields are visited
+/// in declaration order, so only the set field of a oneof is visited.
+/// Map entries are visited in unspecified order.
+/// Extensions are not visited.
+///
+/// All methods have empty default implementations.
+///
+/// ```
+/// # use protobuf::reflect::walk_message;
+/// # use protobuf::reflect::MessageVisitor;
+/// # use protobuf::reflect::FieldDescriptor;
+/// # use protobuf::reflect::ReflectValueRef;
+/// # use protobuf::reflect::ValuePosition;
+/// # use protobuf::well_known_types::Value;
+/// # use protobuf::well_known_types::ListValue;
+/// #[derive(Default)]
+/// struct CountNumbers(u32);
+///
+/// impl MessageVisitor for CountNumbers {
+///     fn visit_value(&mut self, _: &FieldDescriptor, _: ValuePosition, value: ReflectValueRef) {
+///         if let ReflectValueRef::F64(..) = value {
+///             self.0 += 1;
+///         }
+///     }
+/// }
+///
+/// let mut list = ListValue::new();
+/// for n in [1.0, 2.0] {
+///     let mut value = Value::new();
+///     value.set_number_value(n);
+///     list.values.push(value);
+/// }
+///
+/// let mut count = CountNumbers::default();
+/// walk_message(&list, &mut count);
+/// assert_eq!(2, count.0);
+/// ```
+pub trait MessageVisitor {
+    /// Called before fields of a message are visited.
+    ///
+    /// Return `false` to skip the message; [`leave_message`](Self::leave_message)
+    /// is not called for skipped messages.
+    fn enter_message(&mut self, message: &dyn MessageDyn) -> bool {
+        let _ = message;
+        true
+    }
+
+    /// Called after fields and unknown fields of a message are visited.
+    fn leave_message(&mut self, message: &dyn MessageDyn) {
+        let _ = message;
+    }
+
+    /// Called before values of a set field are visited.
+    ///
+    /// Return `false` to skip the field; [`leave_field`](Self::leave_field)
+    /// is not called for skipped fields.
+    fn enter_field(&mut self, field: &FieldDescriptor) -> bool {
+        let _ = field;
+        true
+    }
+
+    /// Called after values of a field are visited.
+    fn leave_field(&mut self, field: &FieldDescriptor) {
+        let _ = field;
+    }
+
+    /// Called for each value of a field.
+    ///
+    /// Message values are walked after this call.
+    fn visit_value(
+        &mut self,
+        field: &FieldDescriptor,
+        position: ValuePosition,
+        value: ReflectValueRef,
+    ) {
+        let _ = (field, position, value);
+    }
+
+    /// Called for unknown fields of a message if there are any.
+    fn visit_unknown_fields(&mut self, unknown_fields: &UnknownFields) {
+        let _ = unknown_fields;
+    }
+}
+
+/// What to do with a value visited by [`MessageVisitorMut`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum VisitAction {
+    /// Keep the value. Message values are walked.
+    Keep,
+    /// Replace the value. Replacement is not walked.
+    Replace(ReflectValueBox),
+    /// Clear singular field, remove repeated field element or map entry.
+    Clear,
+}
+
+/// Callbacks for [`walk_message_mut`] which can rewrite or clear values.
+///
+/// Order of visiting is the same as for [`MessageVisitor`].
+/// Removing a repeated field element shifts indices of the following elements.
+///
+/// All methods have default implementations which keep the message unchanged.
+pub trait MessageVisitorMut {
+    /// Called before fields of a message are visited.
+    ///
+    /// Return `false` to skip the message; [`leave_message`](Self::leave_message)
+    /// is not called for skipped messages.
+    fn enter_message(&mut self, message: &mut dyn MessageDyn) -> bool {
+        let _ = message;
+        true
+    }
+
+    /// Called after fields and unknown fields of a message are visited.
+    fn leave_message(&mut self, message: &mut dyn MessageDyn) {
+        let _ = message;
+    }
+
+    /// Called before values of a set field are visited.
+    ///
+    /// Return `false` to skip the field; [`leave_field`](Self::leave_field)
+    /// is not called for skipped fields.
+    fn enter_field(&mut self, field: &FieldDescriptor) -> bool {
+        let _ = field;
+        true
+    }
+
+    /// Called after values of a field are visited.
+    fn leave_field(&mut self, field: &FieldDescriptor) {
+        let _ = field;
+    }
+
+    /// Called for each value of a field, returns what to do with the value.
+    fn visit_value(
+        &mut self,
+        field: &FieldDescriptor,
+        position: ValuePosition,
+        value: ReflectValueRef,
+    ) -> VisitAction {
+        let _ = (field, position, value);
+        VisitAction::Keep
+    }
+
+    /// Called for unknown fields of a message if there are any.
+    fn visit_unknown_fields(&mut self, unknown_fields: &mut UnknownFields) {
+        let _ = unknown_fields;
+    }
+}
+
+/// Walk all set fields of a message tree with a visitor.
+pub fn walk_message<V: MessageVisitor + ?Sized>(message: &dyn MessageDyn, visitor: &mut V) {
+    if !visitor.enter_message(message) {
+        return;
+    }
+    for field in message.descriptor_dyn().fields() {
+        let value = field.get_reflect(message);
+        let set = match &value {
+            ReflectFieldRef::Optional(v) => v.is_some(),
+            ReflectFieldRef::Repeated(r) => !r.is_empty(),
+            ReflectFieldRef::Map(m) => !m.is_empty(),
+        };
+        if !set || !visitor.enter_field(&field) {
+            continue;
+        }
+        match value {
+            ReflectFieldRef::Optional(v) => {
+                walk_value(&field, ValuePosition::Singular, v.unwrap(), visitor);
+            }
+            ReflectFieldRef::Repeated(r) => {
+                for (i, v) in r.into_iter().enumerate() {
+                    walk_value(&field, ValuePosition::Index(i), v, visitor);
+                }
+            }
+            ReflectFieldRef::Map(m) => {
+                for (k, v) in &m {
+                    walk_value(&field, ValuePosition::Key(k), v, visitor);
+                }
+            }
+        }
+        visitor.leave_field(&field);
+    }
+    let unknown_fields = message.unknown_fields_dyn();
+    if unknown_fields.iter().next().is_some() {
+        visitor.visit_unknown_fields(unknown_fields);
+    }
+    visitor.leave_message(message);
+}
+
+fn walk_value<V: MessageVisitor + ?Sized>(
+    field: &FieldDescriptor,
+    position: ValuePosition,
+    value: ReflectValueRef,
+    visitor: &mut V,
+) {
+    visitor.visit_value(field, position, value.clone());
+    if let ReflectValueRef::Message(m) = value {
+        walk_message(&*m, visitor);
+    }
+}
+
+/// Walk all set fields of a message tree with a visitor which may modify it.
+pub fn walk_message_mut<V: MessageVisitorMut + ?Sized>(
+    message: &mut dyn MessageDyn,
+    visitor: &mut V,
+) {
+    if !visitor.enter_message(message) {
+        return;
+    }
+    for field in message.descriptor_dyn().fields() {
+        let set = match field.get_reflect(message) {
+            ReflectFieldRef::Optional(v) => v.is_some(),
+            ReflectFieldRef::Repeated(r) => !r.is_empty(),
+            ReflectFieldRef::Map(m) => !m.is_empty(),
+        };
+        if !set || !visitor.enter_field(&field) {
+            continue;
+        }
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => {
+                let value = field.get_singular(message).unwrap();
+                match visitor.visit_value(&field, ValuePosition::Singular, value) {
+                    VisitAction::Keep if is_message(&t) => {
+                        walk_message_mut(field.mut_message(message), visitor)
+                    }
+                    VisitAction::Keep => {}
+                    VisitAction::Replace(v) => field.set_singular_field(message, v),
+                    VisitAction::Clear => field.clear_field(message),
+                }
+            }
+            RuntimeFieldType::Repeated(t) => {
+                let mut i = 0;
+                while i < field.get_repeated(message).len() {
+                    let repeated = field.get_repeated(message);
+                    let value = repeated.get(i);
+                    match visitor.visit_value(&field, ValuePosition::Index(i), value) {
+                        VisitAction::Keep if is_message(&t) => walk_message_mut(
+                            field.mut_repeated(message).into_message_mut(i),
+                            visitor,
+                        ),
+                        VisitAction::Keep => {}
+                        VisitAction::Replace(v) => field.mut_repeated(message).set(i, v),
+                        VisitAction::Clear => {
+                            field.mut_repeated(message).remove(i);
+                            continue;
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            RuntimeFieldType::Map(_, t) => {
+                let keys: Vec<ReflectValueBox> = (&field.get_map(message))
+                    .into_iter()
+                    .map(|(k, _)| k.to_box())
+                    .collect();
+                for key in &keys {
+                    let key = key.as_value_ref();
+                    let map = field.get_map(message);
+                    let value = map.get(key.clone()).unwrap();
+                    match visitor.visit_value(&field, ValuePosition::Key(key.clone()), value) {
+                        VisitAction::Keep if is_message(&t) => walk_message_mut(
+                            field.mut_map(message).into_message_mut(key).unwrap(),
+                            visitor,
+                        ),
+                        VisitAction::Keep => {}
+                        VisitAction::Replace(v) => field.mut_map(message).insert(key.to_box(), v),
+                        VisitAction::Clear => field.mut_map(message).remove(key),
+                    }
+                }
+            }
+        }
+        visitor.leave_field(&field);
+    }
+    if message.unknown_fields_dyn().iter().next().is_some() {
+        visitor.visit_unknown_fields(message.mut_unknown_fields_dyn());
+    }
+    visitor.leave_message(message);
+}
+
+fn is_message(t: &RuntimeTypeBox) -> bool {
+    matches!(t, RuntimeTypeBox::Message(..))
+}
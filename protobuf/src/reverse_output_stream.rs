@@ -0,0 +1,430 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem;
+
+use crate::varint::encoded_varint64_len;
+use crate::wire_format;
+use crate::wire_format::WireType;
+use crate::zigzag::encode_zig_zag_32;
+use crate::zigzag::encode_zig_zag_64;
+use crate::CodedOutputStream;
+
+/// Output buffer filled from the end.
+///
+/// Messages are written backwards: the last field first, and the content
+/// of a nested message before its tag and length. So the length of a nested message
+/// is known when its length is written, and a message is serialized in one pass,
+/// without [`Message::compute_size`](crate::Message::compute_size) call
+/// and without cached sizes.
+///
+/// Bytes of each individual value are written in the usual order,
+/// so the resulting buffer is a regular protobuf encoding.
+///
+/// ```
+/// # use protobuf::ReverseOutputStream;
+/// let mut os = ReverseOutputStream::new();
+/// // Write `1: "ab"` and `2: 3`, the last field first.
+/// os.write_uint32(2, 3);
+/// os.write_string(1, "ab");
+/// assert_eq!(&[10, 2, b'a', b'b', 16, 3], os.as_bytes());
+/// ```
+#[derive(Debug, Default)]
+pub struct ReverseOutputStream {
+    /// Written bytes are `buf[pos..]`, `buf[..start]` is data
+    /// of the vec we append to.
+    buf: Vec<u8>,
+    start: usize,
+    pos: usize,
+    /// Write map entries and unknown fields sorted.
+    deterministic: bool,
+}
+
+impl ReverseOutputStream {
+    /// Create an empty stream.
+    pub fn new() -> ReverseOutputStream {
+        ReverseOutputStream::default()
+    }
+
+    /// Create an empty stream which can hold `capacity` bytes without reallocation.
+    pub fn with_capacity(capacity: usize) -> ReverseOutputStream {
+        ReverseOutputStream {
+            buf: vec![0; capacity],
+            start: 0,
+            pos: capacity,
+            deterministic: false,
+        }
+    }
+
+    /// Write to the end of `vec`, reusing its allocation.
+    ///
+    /// `vec` is left unchanged if `f` fails.
+    pub(crate) fn append_to_vec<F>(vec: &mut Vec<u8>, f: F) -> crate::Result<()>
+    where
+        F: FnOnce(&mut ReverseOutputStream) -> crate::Result<()>,
+    {
+        let start = vec.len();
+        let mut os = ReverseOutputStream {
+            buf: mem::take(vec),
+            start,
+            pos: start,
+            deterministic: false,
+        };
+        let r = f(&mut os);
+        if r.is_err() {
+            os.pos = os.buf.len();
+        }
+        *vec = os.into_vec();
+        r
+    }
+
+    /// Enable or disable deterministic serialization,
+    /// see [`CodedOutputStream::set_deterministic`].
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Is deterministic serialization enabled?
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Number of bytes written.
+    pub fn len(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Nothing is written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Take the written bytes.
+    pub fn into_vec(mut self) -> Vec<u8> {
+        let len = self.len();
+        self.buf.copy_within(self.pos.., self.start);
+        self.buf.truncate(self.start + len);
+        self.buf
+    }
+
+    /// Make room for `additional` bytes before written data.
+    fn reserve(&mut self, additional: usize) {
+        if self.pos - self.start >= additional {
+            return;
+        }
+        let len = self.len();
+        let old_end = self.buf.len();
+        // Leave room for tag and length after a large value.
+        let end = self.start + cmp::max(len, 64) + len + additional;
+        self.buf.resize(end, 0);
+        self.buf.copy_within(self.pos..old_end, end - len);
+        self.pos = end - len;
+    }
+
+    /// Allocate `len` bytes before written data.
+    fn prepend(&mut self, len: usize) -> &mut [u8] {
+        self.reserve(len);
+        self.pos -= len;
+        &mut self.buf[self.pos..self.pos + len]
+    }
+
+    /// Write `size` bytes in forward direction with `CodedOutputStream`.
+    ///
+    /// # Panics
+    ///
+    /// If `f` writes less than `size` bytes (writing more is an error).
+    pub fn write_forward<F>(&mut self, size: usize, f: F) -> crate::Result<()>
+    where
+        F: FnOnce(&mut CodedOutputStream) -> crate::Result<()>,
+    {
+        let deterministic = self.deterministic;
+        let bytes = self.prepend(size);
+        let mut os = CodedOutputStream::bytes(bytes);
+        os.set_deterministic(deterministic);
+        f(&mut os)?;
+        os.check_eof();
+        Ok(())
+    }
+
+    /// Write bytes.
+    pub fn write_raw_bytes(&mut self, bytes: &[u8]) {
+        self.prepend(bytes.len()).copy_from_slice(bytes);
+    }
+
+    /// Write a byte.
+    pub fn write_raw_byte(&mut self, byte: u8) {
+        self.prepend(1)[0] = byte;
+    }
+
+    /// Write varint.
+    pub fn write_raw_varint64(&mut self, mut value: u64) {
+        let len = encoded_varint64_len(value);
+        let bytes = self.prepend(len);
+        for b in &mut bytes[..len - 1] {
+            *b = (value & 0x7f) as u8 | 0x80;
+            value >>= 7;
+        }
+        bytes[len - 1] = value as u8;
+    }
+
+    /// Write varint.
+    pub fn write_raw_varint32(&mut self, value: u32) {
+        self.write_raw_varint64(value as u64);
+    }
+
+    /// Write 32-bit integer little endian.
+    pub fn write_raw_little_endian32(&mut self, value: u32) {
+        self.write_raw_bytes(&value.to_le_bytes());
+    }
+
+    /// Write 64-bit integer little endian.
+    pub fn write_raw_little_endian64(&mut self, value: u64) {
+        self.write_raw_bytes(&value.to_le_bytes());
+    }
+
+    /// Write a tag.
+    pub fn write_tag(&mut self, field_number: u32, wire_type: WireType) {
+        self.write_raw_varint32(wire_format::Tag::make(field_number, wire_type).value());
+    }
+
+    /// Write `float` value without tag.
+    pub fn write_float_no_tag(&mut self, value: f32) {
+        self.write_raw_little_endian32(value.to_bits());
+    }
+
+    /// Write `float` field.
+    pub fn write_float(&mut self, field_number: u32, value: f32) {
+        self.write_float_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed32);
+    }
+
+    /// Write `double` value without tag.
+    pub fn write_double_no_tag(&mut self, value: f64) {
+        self.write_raw_little_endian64(value.to_bits());
+    }
+
+    /// Write `double` field.
+    pub fn write_double(&mut self, field_number: u32, value: f64) {
+        self.write_double_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed64);
+    }
+
+    /// Write `int32` value without tag.
+    pub fn write_int32_no_tag(&mut self, value: i32) {
+        self.write_raw_varint64(value as i64 as u64);
+    }
+
+    /// Write `int32` field.
+    pub fn write_int32(&mut self, field_number: u32, value: i32) {
+        self.write_int32_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `int64` value without tag.
+    pub fn write_int64_no_tag(&mut self, value: i64) {
+        self.write_raw_varint64(value as u64);
+    }
+
+    /// Write `int64` field.
+    pub fn write_int64(&mut self, field_number: u32, value: i64) {
+        self.write_int64_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `uint32` value without tag.
+    pub fn write_uint32_no_tag(&mut self, value: u32) {
+        self.write_raw_varint32(value);
+    }
+
+    /// Write `uint32` field.
+    pub fn write_uint32(&mut self, field_number: u32, value: u32) {
+        self.write_uint32_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `uint64` value without tag.
+    pub fn write_uint64_no_tag(&mut self, value: u64) {
+        self.write_raw_varint64(value);
+    }
+
+    /// Write `uint64` field.
+    pub fn write_uint64(&mut self, field_number: u32, value: u64) {
+        self.write_uint64_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `sint32` value without tag.
+    pub fn write_sint32_no_tag(&mut self, value: i32) {
+        self.write_raw_varint32(encode_zig_zag_32(value));
+    }
+
+    /// Write `sint32` field.
+    pub fn write_sint32(&mut self, field_number: u32, value: i32) {
+        self.write_sint32_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `sint64` value without tag.
+    pub fn write_sint64_no_tag(&mut self, value: i64) {
+        self.write_raw_varint64(encode_zig_zag_64(value));
+    }
+
+    /// Write `sint64` field.
+    pub fn write_sint64(&mut self, field_number: u32, value: i64) {
+        self.write_sint64_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `fixed32` value without tag.
+    pub fn write_fixed32_no_tag(&mut self, value: u32) {
+        self.write_raw_little_endian32(value);
+    }
+
+    /// Write `fixed32` field.
+    pub fn write_fixed32(&mut self, field_number: u32, value: u32) {
+        self.write_fixed32_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed32);
+    }
+
+    /// Write `fixed64` value without tag.
+    pub fn write_fixed64_no_tag(&mut self, value: u64) {
+        self.write_raw_little_endian64(value);
+    }
+
+    /// Write `fixed64` field.
+    pub fn write_fixed64(&mut self, field_number: u32, value: u64) {
+        self.write_fixed64_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed64);
+    }
+
+    /// Write `sfixed32` value without tag.
+    pub fn write_sfixed32_no_tag(&mut self, value: i32) {
+        self.write_raw_little_endian32(value as u32);
+    }
+
+    /// Write `sfixed32` field.
+    pub fn write_sfixed32(&mut self, field_number: u32, value: i32) {
+        self.write_sfixed32_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed32);
+    }
+
+    /// Write `sfixed64` value without tag.
+    pub fn write_sfixed64_no_tag(&mut self, value: i64) {
+        self.write_raw_little_endian64(value as u64);
+    }
+
+    /// Write `sfixed64` field.
+    pub fn write_sfixed64(&mut self, field_number: u32, value: i64) {
+        self.write_sfixed64_no_tag(value);
+        self.write_tag(field_number, WireType::Fixed64);
+    }
+
+    /// Write `bool` value without tag.
+    pub fn write_bool_no_tag(&mut self, value: bool) {
+        self.write_raw_byte(value as u8);
+    }
+
+    /// Write `bool` field.
+    pub fn write_bool(&mut self, field_number: u32, value: bool) {
+        self.write_bool_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `enum` value without tag.
+    pub fn write_enum_no_tag(&mut self, value: i32) {
+        self.write_int32_no_tag(value);
+    }
+
+    /// Write `enum` field.
+    pub fn write_enum(&mut self, field_number: u32, value: i32) {
+        self.write_enum_no_tag(value);
+        self.write_tag(field_number, WireType::Varint);
+    }
+
+    /// Write `bytes` field.
+    pub fn write_bytes(&mut self, field_number: u32, bytes: &[u8]) {
+        self.write_raw_bytes(bytes);
+        self.write_raw_varint32(bytes.len() as u32);
+        self.write_tag(field_number, WireType::LengthDelimited);
+    }
+
+    /// Write `string` field.
+    pub fn write_string(&mut self, field_number: u32, s: &str) {
+        self.write_bytes(field_number, s.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReverseOutputStream;
+    use crate::CodedOutputStream;
+
+    fn forward(f: impl FnOnce(&mut CodedOutputStream) -> crate::Result<()>) -> Vec<u8> {
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::vec(&mut v);
+        f(&mut os).unwrap();
+        os.flush().unwrap();
+        drop(os);
+        v
+    }
+
+    #[test]
+    fn varint() {
+        for value in [0, 1, 127, 128, 300, 1 << 35, u64::MAX] {
+            let mut os = ReverseOutputStream::new();
+            os.write_raw_varint64(value);
+            assert_eq!(
+                forward(|os| os.write_raw_varint64(value)),
+                os.as_bytes(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn fields_written_backwards() {
+        let mut os = ReverseOutputStream::with_capacity(3);
+        os.write_string(3, "hello");
+        os.write_sint64(2, -2);
+        os.write_int32(1, -1);
+        let expected = forward(|os| {
+            os.write_int32(1, -1)?;
+            os.write_sint64(2, -2)?;
+            os.write_string(3, "hello")
+        });
+        assert_eq!(expected, os.into_vec());
+    }
+
+    #[test]
+    fn write_forward() {
+        let mut os = ReverseOutputStream::new();
+        os.write_bool(2, true);
+        os.write_forward(2, |cos| cos.write_uint32(1, 5)).unwrap();
+        assert_eq!(&[8, 5, 16, 1], os.as_bytes());
+    }
+
+    #[test]
+    fn append_to_vec() {
+        let mut v = vec![1, 2];
+        ReverseOutputStream::append_to_vec(&mut v, |os| {
+            os.write_bytes(1, &[7; 100]);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(&[1, 2, 10, 100, 7], &v[..5]);
+        assert_eq!(104, v.len());
+
+        let r = ReverseOutputStream::append_to_vec(&mut v, |os| {
+            os.write_uint32(1, 2);
+            Err(crate::Error::from(crate::error::WireError::IncorrectVarint))
+        });
+        assert!(r.is_err());
+        assert_eq!(104, v.len());
+    }
+}
@@ -0,0 +1,55 @@
+//! Paths of missing required fields.
+//!
+//! A path prefix is either empty or ends with `.`, so a path of a field is
+//! simply a concatenation of the prefix and the field name.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Record required field `name` is not set.
+pub fn push_missing_required_field(missing: &mut Vec<String>, prefix: &str, name: &str) {
+    missing.push(format!("{}{}", prefix, name));
+}
+
+/// Path prefix for fields of a message in singular field `name`.
+pub fn nested_field_path(prefix: &str, name: &str) -> String {
+    format!("{}{}.", prefix, name)
+}
+
+/// Path prefix for fields of a message at `index` in repeated field `name`.
+pub fn nested_repeated_field_path(prefix: &str, name: &str, index: usize) -> String {
+    format!("{}{}[{}].", prefix, name, index)
+}
+
+/// Path prefix for fields of a message at `key` in map field `name`.
+pub fn nested_map_field_path(prefix: &str, name: &str, key: &dyn fmt::Debug) -> String {
+    format!("{}{}[{:?}].", prefix, name, key)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn paths() {
+        let order = nested_field_path("", "order");
+        let item = nested_repeated_field_path(&order, "items", 3);
+        let attr = nested_map_field_path(&item, "attrs", &"color");
+        let mut missing = Vec::new();
+        push_missing_required_field(&mut missing, &item, "sku");
+        push_missing_required_field(&mut missing, &attr, "value");
+        push_missing_required_field(&mut missing, "", "id");
+        assert_eq!(
+            vec![
+                "order.items[3].sku",
+                "order.items[3].attrs[\"color\"].value",
+                "id"
+            ],
+            missing
+        );
+    }
+}
@@ -1,26 +1,104 @@
+use alloc::collections::btree_map;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::hash_map;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::hash::Hash;
 
 use crate::error::WireError;
 use crate::reflect::types::ProtobufType;
+use crate::reverse_output_stream::ReverseOutputStream;
 use crate::rt::compute_raw_varint64_size;
 use crate::rt::tag_size;
+use crate::wire_format::check_message_size;
 use crate::wire_format::WireType;
 use crate::CodedInputStream;
 use crate::CodedOutputStream;
 
+/// Storage of `map` field in generated code.
+///
+/// `HashMap` by default, or `BTreeMap` when the code is generated
+/// with `btree_map` option (`HashMap` is only available with `std` feature).
+pub trait MapStorage<K, V> {
+    /// Iterator over map entries.
+    type Iter<'a>: Iterator<Item = (&'a K, &'a V)>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    /// Iterate map entries.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Number of entries in the map.
+    fn len(&self) -> usize;
+
+    /// Map has no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert an entry replacing the previous value for the key.
+    fn insert(&mut self, key: K, value: V);
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V> MapStorage<K, V> for HashMap<K, V> {
+    type Iter<'a>
+        = hash_map::Iter<'a, K, V>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        HashMap::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+impl<K: Ord, V> MapStorage<K, V> for BTreeMap<K, V> {
+    type Iter<'a>
+        = btree_map::Iter<'a, K, V>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn iter(&self) -> btree_map::Iter<'_, K, V> {
+        BTreeMap::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+}
+
 /// Compute serialized size of `map` field and cache nested field sizes.
 pub fn compute_map_size<K, V>(
     field_number: u32,
-    map: &HashMap<K::ProtobufValue, V::ProtobufValue>,
+    map: &impl MapStorage<K::ProtobufValue, V::ProtobufValue>,
 ) -> u64
 where
     K: ProtobufType,
     V: ProtobufType,
-    K::ProtobufValue: Eq + Hash,
 {
     let mut sum = 0;
-    for (k, v) in map {
+    for (k, v) in map.iter() {
         let key_tag_size = 1;
         let value_tag_size = 1;
 
@@ -36,15 +114,32 @@ where
 /// Write map, message sizes must be already known.
 pub fn write_map_with_cached_sizes<K, V>(
     field_number: u32,
-    map: &HashMap<K::ProtobufValue, V::ProtobufValue>,
+    map: &impl MapStorage<K::ProtobufValue, V::ProtobufValue>,
+    os: &mut CodedOutputStream,
+) -> crate::Result<()>
+where
+    K: ProtobufType,
+    V: ProtobufType,
+    K::ProtobufValue: Ord,
+{
+    if os.is_deterministic() {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_by_key(|&(k, _)| k);
+        return write_map_entries_with_cached_sizes::<K, V>(field_number, entries, os);
+    }
+    write_map_entries_with_cached_sizes::<K, V>(field_number, map.iter(), os)
+}
+
+fn write_map_entries_with_cached_sizes<'a, K, V>(
+    field_number: u32,
+    entries: impl IntoIterator<Item = (&'a K::ProtobufValue, &'a V::ProtobufValue)>,
     os: &mut CodedOutputStream,
 ) -> crate::Result<()>
 where
     K: ProtobufType,
     V: ProtobufType,
-    K::ProtobufValue: Eq + Hash,
 {
-    for (k, v) in map {
+    for (k, v) in entries {
         let key_tag_size = 1;
         let value_tag_size = 1;
 
@@ -61,6 +156,45 @@ where
     Ok(())
 }
 
+/// Write map without cached sizes, entries are written in reverse order.
+pub fn write_map_reverse<K, V>(
+    field_number: u32,
+    map: &impl MapStorage<K::ProtobufValue, V::ProtobufValue>,
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()>
+where
+    K: ProtobufType,
+    V: ProtobufType,
+    K::ProtobufValue: Ord,
+{
+    if os.is_deterministic() {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_by_key(|&(k, _)| k);
+        return write_map_entries_reverse::<K, V>(field_number, entries.into_iter().rev(), os);
+    }
+    write_map_entries_reverse::<K, V>(field_number, map.iter(), os)
+}
+
+fn write_map_entries_reverse<'a, K, V>(
+    field_number: u32,
+    entries: impl IntoIterator<Item = (&'a K::ProtobufValue, &'a V::ProtobufValue)>,
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()>
+where
+    K: ProtobufType,
+    V: ProtobufType,
+{
+    for (k, v) in entries {
+        let end = os.len();
+        V::write_reverse(2, v, os)?;
+        K::write_reverse(1, k, os)?;
+        let entry_len = check_message_size((os.len() - end) as u64)?;
+        os.write_raw_varint32(entry_len);
+        os.write_tag(field_number, WireType::LengthDelimited);
+    }
+    Ok(())
+}
+
 pub(crate) fn read_map_template_new(
     is: &mut CodedInputStream,
     mut key: impl FnMut(WireType, &mut CodedInputStream) -> crate::Result<()>,
@@ -68,18 +202,22 @@ pub(crate) fn read_map_template_new(
 ) -> crate::Result<()> {
     let len = is.read_raw_varint32()?;
     let old_limit = is.push_limit(len as u64)?;
-    while !is.eof()? {
-        let (field_number, wire_type) = is.read_tag_unpack()?;
-        match field_number {
-            1 => key(wire_type, is)?,
-            2 => value(wire_type, is)?,
-            _ => is.skip_field(wire_type)?,
+    is.merge_nested(|is| {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => key(wire_type, is)?,
+                2 => value(wire_type, is)?,
+                _ => is.skip_field(wire_type)?,
+            }
         }
-    }
+        Ok(())
+    })?;
     is.pop_limit(old_limit);
     Ok(())
 }
 
+#[cfg(feature = "std")]
 pub(crate) fn read_map_template(
     wire_type: WireType,
     is: &mut CodedInputStream,
@@ -96,12 +234,11 @@ pub(crate) fn read_map_template(
 /// Read `map` field.
 pub fn read_map_into<K, V>(
     is: &mut CodedInputStream,
-    target: &mut HashMap<K::ProtobufValue, V::ProtobufValue>,
+    target: &mut impl MapStorage<K::ProtobufValue, V::ProtobufValue>,
 ) -> crate::Result<()>
 where
     K: ProtobufType,
     V: ProtobufType,
-    K::ProtobufValue: Eq + Hash,
 {
     let mut key = Default::default();
     let mut value = Default::default();
@@ -125,6 +262,7 @@ where
     )?;
 
     target.insert(key, value);
+    is.check_map_len(target.len())?;
 
     Ok(())
 }
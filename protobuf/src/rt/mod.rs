@@ -3,7 +3,11 @@
 //! These are not considered to be public API of rust-protobuf,
 //! so they can be changed any time (provided compatibility with
 //! previously generated code is preserved).
-use std::default::Default;
+pub use alloc::boxed::Box;
+pub use alloc::collections::BTreeMap;
+pub use alloc::string::String;
+pub use alloc::vec::Vec;
+use core::default::Default;
 
 pub use crate::cached_size::CachedSize;
 use crate::coded_input_stream::CodedInputStream;
@@ -18,15 +22,40 @@ use crate::EnumOrUnknown;
 use crate::Message;
 use crate::MessageField;
 
+pub(crate) mod initialized;
 pub(crate) mod map;
 pub(crate) mod repeated;
+pub(crate) mod reverse;
+#[cfg(feature = "std")]
+pub mod service;
+pub mod table;
 pub(crate) mod unsorted;
+#[cfg(feature = "std")]
+pub(crate) mod view;
+pub use initialized::nested_field_path;
+pub use initialized::nested_map_field_path;
+pub use initialized::nested_repeated_field_path;
+pub use initialized::push_missing_required_field;
 pub use map::compute_map_size;
 pub use map::read_map_into;
+pub use map::write_map_reverse;
 pub use map::write_map_with_cached_sizes;
+pub use map::MapStorage;
+pub use repeated::check_repeated_len;
 pub use repeated::read_repeated_packed_enum_or_unknown_into;
+pub use reverse::write_group_field_reverse;
+pub use reverse::write_message_field_reverse;
+pub use reverse::write_repeated_packed_reverse;
+pub use reverse::write_to_with_reverse;
+pub use reverse::write_unknown_fields_reverse;
 pub use unsorted::read_unknown_or_skip_group;
 pub use unsorted::unknown_fields_size;
+#[cfg(feature = "std")]
+pub use view::view_check_repeated;
+#[cfg(feature = "std")]
+pub use view::view_read_tag;
+#[cfg(feature = "std")]
+pub use view::view_skip_field;
 
 /// Given `u64` value compute varint encoded length.
 pub fn compute_raw_varint64_size(value: u64) -> u64 {
@@ -152,7 +181,7 @@ pub fn vec_packed_varint_zigzag_data_size<T: ProtobufVarintZigzag>(vec: &[T]) ->
 /// Size of serialized repeated packed enum field, excluding length and tag.
 pub fn vec_packed_enum_or_unknown_data_size<E: Enum>(vec: &[EnumOrUnknown<E>]) -> u64 {
     vec.iter()
-        .map(|e| compute_raw_varint32_size(e.value() as u32))
+        .map(|e| compute_raw_varint64_size(e.value() as i64 as u64))
         .fold(0, |a, i| a + i)
 }
 
@@ -262,6 +291,11 @@ pub fn string_size(field_number: u32, s: &str) -> u64 {
     tag_size(field_number) + string_size_no_tag(s)
 }
 
+/// Size of encoded group field, given size of group fields.
+pub fn group_size(field_number: u32, fields_size: u64) -> u64 {
+    tag_size(field_number) * 2 + fields_size
+}
+
 /// Read singular `message` field.
 pub fn read_singular_message_into_field<M>(
     is: &mut CodedInputStream,
@@ -276,6 +310,17 @@ where
     Ok(())
 }
 
+/// Read `message` field value.
+///
+/// Unlike [`CodedInputStream::read_message`], required fields are not checked:
+/// they are checked once for the whole message tree.
+#[doc(hidden)]
+pub fn read_message_no_check<M: Message>(is: &mut CodedInputStream) -> Result<M> {
+    let mut m = M::new();
+    is.merge_message(&mut m)?;
+    Ok(m)
+}
+
 /// Write message with field number and length to the stream.
 pub fn write_message_field_with_cached_size<M>(
     field_number: u32,
@@ -289,3 +334,42 @@ where
     os.write_raw_varint32(message.cached_size())?;
     message.write_to_with_cached_sizes(os)
 }
+
+/// Read singular `group` field.
+pub fn read_singular_group_into_field<M>(
+    field_number: u32,
+    is: &mut CodedInputStream,
+    target: &mut MessageField<M>,
+) -> Result<()>
+where
+    M: Message + Default,
+{
+    let mut m = M::new();
+    is.merge_group(field_number, &mut m)?;
+    *target = MessageField::some(m);
+    Ok(())
+}
+
+/// Read `group` field value.
+///
+/// Unlike [`CodedInputStream::read_group`], required fields are not checked.
+#[doc(hidden)]
+pub fn read_group_no_check<M: Message>(field_number: u32, is: &mut CodedInputStream) -> Result<M> {
+    let mut m = M::new();
+    is.merge_group(field_number, &mut m)?;
+    Ok(m)
+}
+
+/// Write group with start and end tags to the stream.
+pub fn write_group_field_with_cached_size<M>(
+    field_number: u32,
+    message: &M,
+    os: &mut CodedOutputStream,
+) -> Result<()>
+where
+    M: Message,
+{
+    os.write_tag(field_number, WireType::StartGroup)?;
+    message.write_to_with_cached_sizes(os)?;
+    os.write_tag(field_number, WireType::EndGroup)
+}
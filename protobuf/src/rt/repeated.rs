@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::CodedInputStream;
 use crate::Enum;
 use crate::EnumOrUnknown;
@@ -11,7 +13,16 @@ pub fn read_repeated_packed_enum_or_unknown_into<E: Enum>(
     let old_limit = is.push_limit(len)?;
     while !is.eof()? {
         target.push(is.read_enum_or_unknown()?);
+        is.check_repeated_len(target.len())?;
     }
     is.pop_limit(old_limit);
     Ok(())
 }
+
+/// Check the number of elements of repeated field against decode limits.
+///
+/// Called by generated code after element is read.
+#[inline]
+pub fn check_repeated_len(is: &CodedInputStream, len: usize) -> crate::Result<()> {
+    is.check_repeated_len(len)
+}
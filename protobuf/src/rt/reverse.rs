@@ -0,0 +1,79 @@
+//! Single-pass serialization helpers used in generated code.
+
+use crate::reverse_output_stream::ReverseOutputStream;
+use crate::rt::table::ProtobufTypePacked;
+use crate::rt::unknown_fields_size;
+use crate::wire_format::check_message_size;
+use crate::wire_format::WireType;
+use crate::CodedOutputStream;
+use crate::Message;
+use crate::UnknownFields;
+
+/// Write message to the stream in one pass, used by messages without cached size.
+///
+/// Writing such message with `write_to_with_cached_sizes` would recompute sizes
+/// of nested messages on each level.
+pub fn write_to_with_reverse<M: Message>(
+    message: &M,
+    os: &mut CodedOutputStream,
+) -> crate::Result<()> {
+    let mut reverse = ReverseOutputStream::new();
+    reverse.set_deterministic(os.is_deterministic());
+    message.write_reverse(&mut reverse)?;
+    os.write_raw_bytes(reverse.as_bytes())
+}
+
+/// Write message field without cached sizes.
+pub fn write_message_field_reverse<M: Message>(
+    field_number: u32,
+    message: &M,
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()> {
+    let end = os.len();
+    message.write_reverse(os)?;
+    let size = check_message_size((os.len() - end) as u64)?;
+    os.write_raw_varint32(size);
+    os.write_tag(field_number, WireType::LengthDelimited);
+    Ok(())
+}
+
+/// Write `group` field without cached sizes.
+pub fn write_group_field_reverse<M: Message>(
+    field_number: u32,
+    message: &M,
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()> {
+    os.write_tag(field_number, WireType::EndGroup);
+    message.write_reverse(os)?;
+    os.write_tag(field_number, WireType::StartGroup);
+    Ok(())
+}
+
+/// Write packed repeated field.
+pub fn write_repeated_packed_reverse<T: ProtobufTypePacked>(
+    field_number: u32,
+    values: &[T::ProtobufValue],
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let end = os.len();
+    T::write_packed_no_tag_reverse(values, os);
+    let data_size = check_message_size((os.len() - end) as u64)?;
+    os.write_raw_varint32(data_size);
+    os.write_tag(field_number, WireType::LengthDelimited);
+    Ok(())
+}
+
+/// Write unknown fields.
+pub fn write_unknown_fields_reverse(
+    unknown_fields: &UnknownFields,
+    os: &mut ReverseOutputStream,
+) -> crate::Result<()> {
+    let size = unknown_fields_size(unknown_fields);
+    if size == 0 {
+        return Ok(());
+    }
+    os.write_forward(size as usize, |os| os.write_unknown_fields(unknown_fields))
+}
@@ -0,0 +1,111 @@
+//! Functions used by generated service clients and servers.
+
+use crate::error::ProtobufError;
+use crate::service::Channel;
+use crate::service::MethodInfo;
+use crate::service::Requests;
+use crate::service::Streaming;
+use crate::Message;
+
+fn parse_responses<'a, M: Message, E: From<crate::Error> + 'a>(
+    responses: Streaming<'a, Vec<u8>, E>,
+) -> Streaming<'a, M, E> {
+    Box::new(responses.map(|r| Ok(M::parse_from_bytes(&r?)?)))
+}
+
+fn write_requests<'a, M: Message>(requests: impl Iterator<Item = M> + 'a) -> Requests<'a> {
+    Box::new(requests.map(|r| r.write_to_bytes()))
+}
+
+fn parse_requests<'a, M: Message, E: From<crate::Error>>(
+    requests: Requests<'a>,
+) -> Streaming<'a, M, E> {
+    Box::new(requests.map(|r| Ok(M::parse_from_bytes(&r?)?)))
+}
+
+fn write_responses<'a, M: Message, E: From<crate::Error> + 'a>(
+    responses: Streaming<'a, M, E>,
+) -> Streaming<'a, Vec<u8>, E> {
+    Box::new(responses.map(|r| Ok(r?.write_to_bytes()?)))
+}
+
+/// Client call of unary method.
+pub fn client_unary<C: Channel, Req: Message, Resp: Message>(
+    channel: &C,
+    method: &MethodInfo,
+    request: &Req,
+) -> Result<Resp, C::Error> {
+    let response = channel.unary(method, request.write_to_bytes()?)?;
+    Ok(Resp::parse_from_bytes(&response)?)
+}
+
+/// Client call of client streaming method.
+pub fn client_client_streaming<'a, C: Channel, Req: Message, Resp: Message>(
+    channel: &'a C,
+    method: &MethodInfo,
+    requests: impl Iterator<Item = Req> + 'a,
+) -> Result<Resp, C::Error> {
+    let response = channel.client_streaming(method, write_requests(requests))?;
+    Ok(Resp::parse_from_bytes(&response)?)
+}
+
+/// Client call of server streaming method.
+pub fn client_server_streaming<'a, C: Channel, Req: Message, Resp: Message>(
+    channel: &'a C,
+    method: &MethodInfo,
+    request: &Req,
+) -> Result<Streaming<'a, Resp, C::Error>, C::Error> {
+    let responses = channel.server_streaming(method, request.write_to_bytes()?)?;
+    Ok(parse_responses(responses))
+}
+
+/// Client call of bidirectional streaming method.
+pub fn client_bidi_streaming<'a, C: Channel, Req: Message, Resp: Message>(
+    channel: &'a C,
+    method: &MethodInfo,
+    requests: impl Iterator<Item = Req> + 'a,
+) -> Result<Streaming<'a, Resp, C::Error>, C::Error> {
+    let responses = channel.bidi_streaming(method, write_requests(requests))?;
+    Ok(parse_responses(responses))
+}
+
+/// Server dispatch of unary method.
+pub fn server_unary<Req: Message, Resp: Message, E: From<crate::Error>>(
+    request: Vec<u8>,
+    f: impl FnOnce(Req) -> Result<Resp, E>,
+) -> Result<Vec<u8>, E> {
+    let response = f(Req::parse_from_bytes(&request)?)?;
+    Ok(response.write_to_bytes()?)
+}
+
+/// Server dispatch of client streaming method.
+pub fn server_client_streaming<'a, Req: Message, Resp: Message, E: From<crate::Error>>(
+    requests: Requests<'a>,
+    f: impl FnOnce(Streaming<'a, Req, E>) -> Result<Resp, E>,
+) -> Result<Vec<u8>, E> {
+    let response = f(parse_requests(requests))?;
+    Ok(response.write_to_bytes()?)
+}
+
+/// Server dispatch of server streaming method.
+pub fn server_server_streaming<'a, Req: Message, Resp: Message, E: From<crate::Error> + 'a>(
+    request: Vec<u8>,
+    f: impl FnOnce(Req) -> Result<Streaming<'a, Resp, E>, E>,
+) -> Result<Streaming<'a, Vec<u8>, E>, E> {
+    let responses = f(Req::parse_from_bytes(&request)?)?;
+    Ok(write_responses(responses))
+}
+
+/// Server dispatch of bidirectional streaming method.
+pub fn server_bidi_streaming<'a, Req: Message, Resp: Message, E: From<crate::Error> + 'a>(
+    requests: Requests<'a>,
+    f: impl FnOnce(Streaming<'a, Req, E>) -> Result<Streaming<'a, Resp, E>, E>,
+) -> Result<Streaming<'a, Vec<u8>, E>, E> {
+    let responses = f(parse_requests(requests))?;
+    Ok(write_responses(responses))
+}
+
+/// Error returned by server when service has no method of requested kind.
+pub fn unknown_method(method: &MethodInfo) -> crate::Error {
+    ProtobufError::UnknownMethod(method.path()).into()
+}
@@ -0,0 +1,776 @@
+//! Table-driven parsing and serialization.
+//!
+//! When `table_driven` codegen option is enabled, generated messages
+//! do not contain per-field `merge_from`, `compute_size` and
+//! `write_to_with_cached_sizes` code. Instead, each message has a static
+//! [`MessageTable`] listing field numbers and field accessors, and
+//! these operations are delegated to [`merge_from`], [`compute_size`],
+//! [`write_to_with_cached_sizes`] and [`write_reverse`] functions.
+//!
+//! Accessors return field storage wrapped into one of the types of this module
+//! (e. g. [`Optional`] for `Option<T>` fields). These wrappers implement
+//! [`TableValue`], so encoding code is shared between all messages
+//! which have fields of the same type.
+//!
+//! Fields are serialized in field number order.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::coded_input_stream::CodedInputStream;
+use crate::coded_output_stream::CodedOutputStream;
+use crate::enums::Enum;
+use crate::error::Result;
+use crate::reflect::types::ProtobufType;
+use crate::reflect::types::ProtobufTypeBool;
+use crate::reflect::types::ProtobufTypeDouble;
+use crate::reflect::types::ProtobufTypeEnumOrUnknown;
+use crate::reflect::types::ProtobufTypeFixed32;
+use crate::reflect::types::ProtobufTypeFixed64;
+use crate::reflect::types::ProtobufTypeFloat;
+use crate::reflect::types::ProtobufTypeInt32;
+use crate::reflect::types::ProtobufTypeInt64;
+use crate::reflect::types::ProtobufTypeSfixed32;
+use crate::reflect::types::ProtobufTypeSfixed64;
+use crate::reflect::types::ProtobufTypeSint32;
+use crate::reflect::types::ProtobufTypeSint64;
+use crate::reflect::types::ProtobufTypeUint32;
+use crate::reflect::types::ProtobufTypeUint64;
+use crate::reverse_output_stream::ReverseOutputStream;
+use crate::rt::compute_map_size;
+use crate::rt::compute_raw_varint64_size;
+use crate::rt::read_map_into;
+use crate::rt::read_repeated_packed_enum_or_unknown_into;
+use crate::rt::read_singular_message_into_field;
+use crate::rt::read_unknown_or_skip_group;
+use crate::rt::tag_size;
+use crate::rt::unknown_fields_size;
+use crate::rt::vec_packed_enum_or_unknown_data_size;
+use crate::rt::vec_packed_fixed_data_size;
+use crate::rt::vec_packed_varint_data_size;
+use crate::rt::vec_packed_varint_zigzag_data_size;
+use crate::rt::write_map_reverse;
+use crate::rt::write_map_with_cached_sizes;
+use crate::rt::write_message_field_reverse;
+use crate::rt::write_message_field_with_cached_size;
+use crate::rt::write_repeated_packed_reverse;
+use crate::rt::write_unknown_fields_reverse;
+use crate::rt::MapStorage;
+use crate::wire_format::WireType;
+use crate::Message;
+use crate::MessageField;
+
+/// Field storage which can be parsed and serialized by table-driven code.
+pub trait TableValue {
+    /// Read a value of the field from the stream.
+    ///
+    /// Return `false` if the field does not accept given wire type,
+    /// in this case the value is stored in unknown fields.
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool>;
+
+    /// Compute serialized size of the field including tags,
+    /// and cache sizes of nested messages.
+    fn compute_size(&self, field_number: u32) -> u64;
+
+    /// Write the field with previously cached sizes.
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()>;
+
+    /// Write the field to the stream filled from the end.
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()>;
+}
+
+/// Types which can be encoded as packed repeated fields.
+pub trait ProtobufTypePacked: ProtobufType {
+    /// Read length-delimited packed values.
+    fn read_packed_into(
+        is: &mut CodedInputStream,
+        target: &mut Vec<Self::ProtobufValue>,
+    ) -> Result<()>;
+
+    /// Size of packed values, excluding tag and length.
+    fn packed_data_size(values: &[Self::ProtobufValue]) -> u64;
+
+    /// Write packed values, excluding tag and length.
+    fn write_packed_no_tag(
+        values: &[Self::ProtobufValue],
+        os: &mut CodedOutputStream,
+    ) -> Result<()>;
+
+    /// Write packed values to the stream filled from the end, excluding tag and length.
+    fn write_packed_no_tag_reverse(values: &[Self::ProtobufValue], os: &mut ReverseOutputStream);
+}
+
+macro_rules! impl_protobuf_type_packed {
+    ($t:ty, $read:ident, $data_size:ident, $write:ident, $write_reverse:ident) => {
+        impl ProtobufTypePacked for $t {
+            fn read_packed_into(
+                is: &mut CodedInputStream,
+                target: &mut Vec<Self::ProtobufValue>,
+            ) -> Result<()> {
+                is.$read(target)
+            }
+
+            fn packed_data_size(values: &[Self::ProtobufValue]) -> u64 {
+                $data_size(values)
+            }
+
+            fn write_packed_no_tag(
+                values: &[Self::ProtobufValue],
+                os: &mut CodedOutputStream,
+            ) -> Result<()> {
+                os.$write(values)
+            }
+
+            fn write_packed_no_tag_reverse(
+                values: &[Self::ProtobufValue],
+                os: &mut ReverseOutputStream,
+            ) {
+                for v in values.iter().rev() {
+                    os.$write_reverse(*v);
+                }
+            }
+        }
+    };
+}
+
+impl_protobuf_type_packed!(
+    ProtobufTypeFloat,
+    read_repeated_packed_float_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_float_no_tag,
+    write_float_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeDouble,
+    read_repeated_packed_double_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_double_no_tag,
+    write_double_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeInt32,
+    read_repeated_packed_int32_into,
+    vec_packed_varint_data_size,
+    write_repeated_packed_int32_no_tag,
+    write_int32_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeInt64,
+    read_repeated_packed_int64_into,
+    vec_packed_varint_data_size,
+    write_repeated_packed_int64_no_tag,
+    write_int64_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeUint32,
+    read_repeated_packed_uint32_into,
+    vec_packed_varint_data_size,
+    write_repeated_packed_uint32_no_tag,
+    write_uint32_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeUint64,
+    read_repeated_packed_uint64_into,
+    vec_packed_varint_data_size,
+    write_repeated_packed_uint64_no_tag,
+    write_uint64_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeSint32,
+    read_repeated_packed_sint32_into,
+    vec_packed_varint_zigzag_data_size,
+    write_repeated_packed_sint32_no_tag,
+    write_sint32_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeSint64,
+    read_repeated_packed_sint64_into,
+    vec_packed_varint_zigzag_data_size,
+    write_repeated_packed_sint64_no_tag,
+    write_sint64_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeFixed32,
+    read_repeated_packed_fixed32_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_fixed32_no_tag,
+    write_fixed32_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeFixed64,
+    read_repeated_packed_fixed64_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_fixed64_no_tag,
+    write_fixed64_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeSfixed32,
+    read_repeated_packed_sfixed32_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_sfixed32_no_tag,
+    write_sfixed32_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeSfixed64,
+    read_repeated_packed_sfixed64_into,
+    vec_packed_fixed_data_size,
+    write_repeated_packed_sfixed64_no_tag,
+    write_sfixed64_no_tag
+);
+impl_protobuf_type_packed!(
+    ProtobufTypeBool,
+    read_repeated_packed_bool_into,
+    vec_packed_varint_data_size,
+    write_repeated_packed_bool_no_tag,
+    write_bool_no_tag
+);
+
+impl<E: Enum> ProtobufTypePacked for ProtobufTypeEnumOrUnknown<E> {
+    fn read_packed_into(
+        is: &mut CodedInputStream,
+        target: &mut Vec<Self::ProtobufValue>,
+    ) -> Result<()> {
+        read_repeated_packed_enum_or_unknown_into(is, target)
+    }
+
+    fn packed_data_size(values: &[Self::ProtobufValue]) -> u64 {
+        vec_packed_enum_or_unknown_data_size(values)
+    }
+
+    fn write_packed_no_tag(
+        values: &[Self::ProtobufValue],
+        os: &mut CodedOutputStream,
+    ) -> Result<()> {
+        os.write_repeated_packed_enum_or_unknown_no_tag(values)
+    }
+
+    fn write_packed_no_tag_reverse(values: &[Self::ProtobufValue], os: &mut ReverseOutputStream) {
+        for v in values.iter().rev() {
+            os.write_enum_no_tag(v.value());
+        }
+    }
+}
+
+/// Size of a value with tag.
+fn value_size<T: ProtobufType>(field_number: u32, value: &T::ProtobufValue) -> u64 {
+    tag_size(field_number) + T::compute_size_with_length_delimiter(value)
+}
+
+/// Read an element of repeated field.
+fn merge_repeated<T: ProtobufType>(
+    target: &mut Vec<T::ProtobufValue>,
+    is: &mut CodedInputStream,
+) -> Result<()> {
+    target.push(T::read(is)?);
+    is.check_repeated_len(target.len())
+}
+
+/// Read an element of repeated field which can be packed.
+fn merge_repeated_packable<T: ProtobufTypePacked>(
+    target: &mut Vec<T::ProtobufValue>,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+) -> Result<bool> {
+    if wire_type == T::WIRE_TYPE {
+        merge_repeated::<T>(target, is)?;
+    } else if wire_type == WireType::LengthDelimited {
+        T::read_packed_into(is, target)?;
+    } else {
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+// Wrappers are `repr(transparent)`, so field storage reference
+// can be safely cast to wrapper reference.
+macro_rules! wrapper_ref_fns {
+    ($storage:ty) => {
+        /// Wrap field storage reference.
+        #[inline]
+        pub fn wrap(value: &$storage) -> &Self {
+            // SAFETY: `Self` is `repr(transparent)` over the storage.
+            unsafe { &*(value as *const $storage as *const Self) }
+        }
+
+        /// Wrap mutable field storage reference.
+        #[inline]
+        pub fn wrap_mut(value: &mut $storage) -> &mut Self {
+            // SAFETY: `Self` is `repr(transparent)` over the storage.
+            unsafe { &mut *(value as *mut $storage as *mut Self) }
+        }
+    };
+}
+
+/// Singular field without presence (proto3 field without `optional`),
+/// default value is not serialized.
+#[repr(transparent)]
+pub struct Implicit<T: ProtobufType>(T::ProtobufValue, PhantomData<T>);
+
+impl<T: ProtobufType> Implicit<T> {
+    wrapper_ref_fns!(T::ProtobufValue);
+}
+
+impl<T: ProtobufType> TableValue for Implicit<T>
+where
+    T::ProtobufValue: PartialEq,
+{
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != T::WIRE_TYPE {
+            return Ok(false);
+        }
+        self.0 = T::read(is)?;
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        if self.0 != T::ProtobufValue::default() {
+            value_size::<T>(field_number, &self.0)
+        } else {
+            0
+        }
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        if self.0 != T::ProtobufValue::default() {
+            T::write_with_cached_size(field_number, &self.0, os)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        if self.0 != T::ProtobufValue::default() {
+            T::write_reverse(field_number, &self.0, os)?;
+        }
+        Ok(())
+    }
+}
+
+/// Singular field stored as `Option<T>`.
+#[repr(transparent)]
+pub struct Optional<T: ProtobufType>(Option<T::ProtobufValue>, PhantomData<T>);
+
+impl<T: ProtobufType> Optional<T> {
+    wrapper_ref_fns!(Option<T::ProtobufValue>);
+}
+
+impl<T: ProtobufType> TableValue for Optional<T> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != T::WIRE_TYPE {
+            return Ok(false);
+        }
+        self.0 = Some(T::read(is)?);
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        match &self.0 {
+            Some(v) => value_size::<T>(field_number, v),
+            None => 0,
+        }
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        if let Some(v) = &self.0 {
+            T::write_with_cached_size(field_number, v, os)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        if let Some(v) = &self.0 {
+            T::write_reverse(field_number, v, os)?;
+        }
+        Ok(())
+    }
+}
+
+/// Singular message field.
+#[repr(transparent)]
+pub struct SingularMessage<M: Message + Default>(MessageField<M>);
+
+impl<M: Message + Default> SingularMessage<M> {
+    wrapper_ref_fns!(MessageField<M>);
+}
+
+impl<M: Message + Default> TableValue for SingularMessage<M> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != WireType::LengthDelimited {
+            return Ok(false);
+        }
+        read_singular_message_into_field(is, &mut self.0)?;
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        match self.0.as_ref() {
+            Some(m) => {
+                let len = m.compute_size();
+                tag_size(field_number) + compute_raw_varint64_size(len) + len
+            }
+            None => 0,
+        }
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        if let Some(m) = self.0.as_ref() {
+            write_message_field_with_cached_size(field_number, m, os)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        if let Some(m) = self.0.as_ref() {
+            write_message_field_reverse(field_number, m, os)?;
+        }
+        Ok(())
+    }
+}
+
+/// Value of `oneof` variant, always serialized.
+#[repr(transparent)]
+pub struct OneofValue<T: ProtobufType>(T::ProtobufValue, PhantomData<T>);
+
+impl<T: ProtobufType> OneofValue<T> {
+    wrapper_ref_fns!(T::ProtobufValue);
+}
+
+impl<T: ProtobufType> TableValue for OneofValue<T> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != T::WIRE_TYPE {
+            return Ok(false);
+        }
+        self.0 = T::read(is)?;
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        value_size::<T>(field_number, &self.0)
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        T::write_with_cached_size(field_number, &self.0, os)
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        T::write_reverse(field_number, &self.0, os)
+    }
+}
+
+/// Repeated field of messages, strings or bytes.
+#[repr(transparent)]
+pub struct Repeated<T: ProtobufType>(Vec<T::ProtobufValue>, PhantomData<T>);
+
+impl<T: ProtobufType> Repeated<T> {
+    wrapper_ref_fns!(Vec<T::ProtobufValue>);
+}
+
+impl<T: ProtobufType> TableValue for Repeated<T> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != T::WIRE_TYPE {
+            return Ok(false);
+        }
+        merge_repeated::<T>(&mut self.0, is)?;
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        self.0
+            .iter()
+            .map(|v| value_size::<T>(field_number, v))
+            .sum()
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        for v in &self.0 {
+            T::write_with_cached_size(field_number, v, os)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        for v in self.0.iter().rev() {
+            T::write_reverse(field_number, v, os)?;
+        }
+        Ok(())
+    }
+}
+
+/// Repeated scalar field serialized unpacked.
+///
+/// Both packed and unpacked values are accepted when parsing.
+#[repr(transparent)]
+pub struct RepeatedUnpacked<T: ProtobufTypePacked>(Vec<T::ProtobufValue>, PhantomData<T>);
+
+impl<T: ProtobufTypePacked> RepeatedUnpacked<T> {
+    wrapper_ref_fns!(Vec<T::ProtobufValue>);
+}
+
+impl<T: ProtobufTypePacked> TableValue for RepeatedUnpacked<T> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        merge_repeated_packable::<T>(&mut self.0, wire_type, is)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        self.0
+            .iter()
+            .map(|v| value_size::<T>(field_number, v))
+            .sum()
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        for v in &self.0 {
+            T::write_with_cached_size(field_number, v, os)?;
+        }
+        Ok(())
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        for v in self.0.iter().rev() {
+            T::write_reverse(field_number, v, os)?;
+        }
+        Ok(())
+    }
+}
+
+/// Repeated scalar field serialized packed.
+///
+/// Both packed and unpacked values are accepted when parsing.
+#[repr(transparent)]
+pub struct RepeatedPacked<T: ProtobufTypePacked>(Vec<T::ProtobufValue>, PhantomData<T>);
+
+impl<T: ProtobufTypePacked> RepeatedPacked<T> {
+    wrapper_ref_fns!(Vec<T::ProtobufValue>);
+}
+
+impl<T: ProtobufTypePacked> TableValue for RepeatedPacked<T> {
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        merge_repeated_packable::<T>(&mut self.0, wire_type, is)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        if self.0.is_empty() {
+            return 0;
+        }
+        let data_size = T::packed_data_size(&self.0);
+        tag_size(field_number) + compute_raw_varint64_size(data_size) + data_size
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        os.write_tag(field_number, WireType::LengthDelimited)?;
+        os.write_raw_varint32(T::packed_data_size(&self.0) as u32)?;
+        T::write_packed_no_tag(&self.0, os)
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        write_repeated_packed_reverse::<T>(field_number, &self.0, os)
+    }
+}
+
+/// `map` field stored in `M` (`HashMap` or `BTreeMap`).
+#[repr(transparent)]
+pub struct MapField<K: ProtobufType, V: ProtobufType, M>(M, PhantomData<(K, V)>);
+
+impl<K: ProtobufType, V: ProtobufType, M> MapField<K, V, M> {
+    wrapper_ref_fns!(M);
+}
+
+impl<K: ProtobufType, V: ProtobufType, M> TableValue for MapField<K, V, M>
+where
+    K::ProtobufValue: Ord,
+    M: MapStorage<K::ProtobufValue, V::ProtobufValue>,
+{
+    fn merge(&mut self, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        if wire_type != WireType::LengthDelimited {
+            return Ok(false);
+        }
+        read_map_into::<K, V>(is, &mut self.0)?;
+        Ok(true)
+    }
+
+    fn compute_size(&self, field_number: u32) -> u64 {
+        compute_map_size::<K, V>(field_number, &self.0)
+    }
+
+    fn write(&self, field_number: u32, os: &mut CodedOutputStream) -> Result<()> {
+        write_map_with_cached_sizes::<K, V>(field_number, &self.0, os)
+    }
+
+    fn write_reverse(&self, field_number: u32, os: &mut ReverseOutputStream) -> Result<()> {
+        write_map_reverse::<K, V>(field_number, &self.0, os)
+    }
+}
+
+enum FieldAccess<M: 'static> {
+    Field {
+        get: fn(&M) -> &dyn TableValue,
+        get_mut: fn(&mut M) -> &mut dyn TableValue,
+    },
+    Oneof {
+        wire_type: WireType,
+        get: fn(&M) -> Option<&dyn TableValue>,
+        merge: fn(&mut M, &mut CodedInputStream) -> Result<()>,
+    },
+}
+
+/// Entry of [`MessageTable`].
+pub struct TableField<M: 'static> {
+    number: u32,
+    access: FieldAccess<M>,
+}
+
+impl<M: 'static> TableField<M> {
+    /// Regular field.
+    pub const fn field(
+        number: u32,
+        get: fn(&M) -> &dyn TableValue,
+        get_mut: fn(&mut M) -> &mut dyn TableValue,
+    ) -> TableField<M> {
+        TableField {
+            number,
+            access: FieldAccess::Field { get, get_mut },
+        }
+    }
+
+    /// Variant of `oneof`.
+    ///
+    /// `get` returns `None` if other variant is set,
+    /// `merge` reads the value and sets the variant.
+    pub const fn oneof(
+        number: u32,
+        wire_type: WireType,
+        get: fn(&M) -> Option<&dyn TableValue>,
+        merge: fn(&mut M, &mut CodedInputStream) -> Result<()>,
+    ) -> TableField<M> {
+        TableField {
+            number,
+            access: FieldAccess::Oneof {
+                wire_type,
+                get,
+                merge,
+            },
+        }
+    }
+
+    fn merge(&self, m: &mut M, wire_type: WireType, is: &mut CodedInputStream) -> Result<bool> {
+        match &self.access {
+            FieldAccess::Field { get_mut, .. } => get_mut(m).merge(wire_type, is),
+            FieldAccess::Oneof {
+                wire_type: expected,
+                merge,
+                ..
+            } => {
+                if wire_type != *expected {
+                    return Ok(false);
+                }
+                merge(m, is)?;
+                Ok(true)
+            }
+        }
+    }
+
+    fn get<'a>(&self, m: &'a M) -> Option<&'a dyn TableValue> {
+        match &self.access {
+            FieldAccess::Field { get, .. } => Some(get(m)),
+            FieldAccess::Oneof { get, .. } => get(m),
+        }
+    }
+}
+
+/// Fields of a message, sorted by field number.
+pub struct MessageTable<M: 'static> {
+    fields: &'static [TableField<M>],
+}
+
+impl<M: 'static> MessageTable<M> {
+    /// Create a table.
+    ///
+    /// # Panics
+    ///
+    /// If fields are not sorted by number (at compile time when used in `static`).
+    pub const fn new(fields: &'static [TableField<M>]) -> MessageTable<M> {
+        let mut i = 1;
+        while i < fields.len() {
+            assert!(
+                fields[i - 1].number < fields[i].number,
+                "fields must be sorted by number"
+            );
+            i += 1;
+        }
+        MessageTable { fields }
+    }
+
+    fn find(&self, number: u32) -> Option<&TableField<M>> {
+        // Field numbers are often `1..=n`.
+        match self.fields.get((number as usize).wrapping_sub(1)) {
+            Some(f) if f.number == number => Some(f),
+            _ => self
+                .fields
+                .binary_search_by_key(&number, |f| f.number)
+                .ok()
+                .map(|i| &self.fields[i]),
+        }
+    }
+}
+
+/// Implementation of [`Message::merge_from`] for table-driven messages.
+pub fn merge_from<M: Message>(
+    m: &mut M,
+    is: &mut CodedInputStream,
+    table: &MessageTable<M>,
+) -> Result<()> {
+    while let Some(tag) = is.read_raw_tag_or_eof()? {
+        let merged = match (table.find(tag >> 3), WireType::new(tag & 7)) {
+            (Some(field), Some(wire_type)) => field.merge(m, wire_type, is)?,
+            _ => false,
+        };
+        if !merged {
+            read_unknown_or_skip_group(tag, is, m.mut_unknown_fields())?;
+        }
+    }
+    Ok(())
+}
+
+/// Implementation of [`Message::compute_size`] for table-driven messages.
+///
+/// Cached size of the message itself is not updated.
+pub fn compute_size<M: Message>(m: &M, table: &MessageTable<M>) -> u64 {
+    let mut my_size = 0;
+    for field in table.fields {
+        if let Some(v) = field.get(m) {
+            my_size += v.compute_size(field.number);
+        }
+    }
+    my_size + unknown_fields_size(m.unknown_fields())
+}
+
+/// Implementation of [`Message::write_to_with_cached_sizes`] for table-driven messages.
+pub fn write_to_with_cached_sizes<M: Message>(
+    m: &M,
+    os: &mut CodedOutputStream,
+    table: &MessageTable<M>,
+) -> Result<()> {
+    for field in table.fields {
+        if let Some(v) = field.get(m) {
+            v.write(field.number, os)?;
+        }
+    }
+    os.write_unknown_fields(m.unknown_fields())
+}
+
+/// Implementation of [`Message::write_reverse`] for table-driven messages.
+pub fn write_reverse<M: Message>(
+    m: &M,
+    os: &mut ReverseOutputStream,
+    table: &MessageTable<M>,
+) -> Result<()> {
+    write_unknown_fields_reverse(m.unknown_fields(), os)?;
+    for field in table.fields.iter().rev() {
+        if let Some(v) = field.get(m) {
+            v.write_reverse(field.number, os)?;
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::error::WireError;
 use crate::rt::bytes_size_no_tag;
 use crate::rt::tag_size;
 use crate::rt::ProtobufVarint;
@@ -6,13 +7,24 @@ use crate::wire_format::WireType;
 use crate::CodedInputStream;
 use crate::UnknownFields;
 
-fn skip_group(is: &mut CodedInputStream) -> crate::Result<()> {
+fn skip_group(field_number: u32, is: &mut CodedInputStream) -> crate::Result<()> {
+    is.incr_recursion()?;
+    let r = skip_group_fields(field_number, is);
+    is.decr_recursion();
+    r
+}
+
+fn skip_group_fields(field_number: u32, is: &mut CodedInputStream) -> crate::Result<()> {
     loop {
-        let (_, wire_type) = is.read_tag_unpack()?;
-        if wire_type == WireType::EndGroup {
-            return Ok(());
+        let (number, wire_type) = is.read_tag_unpack()?;
+        match wire_type {
+            WireType::EndGroup if number == field_number => return Ok(()),
+            WireType::EndGroup => {
+                return Err(WireError::UnexpectedWireType(WireType::EndGroup).into())
+            }
+            WireType::StartGroup => skip_group(number, is)?,
+            _ => is.skip_field(wire_type)?,
         }
-        is.skip_field(wire_type)?;
     }
 }
 
@@ -37,7 +49,8 @@ pub fn unknown_fields_size(unknown_fields: &UnknownFields) -> u64 {
 }
 
 /// Handle unknown field in generated code.
-/// Either store a value in unknown, or skip a group.
+/// Either store a value in unknown, or skip a group,
+/// or finish reading current group on `END_GROUP` tag.
 pub(crate) fn read_unknown_or_skip_group_with_tag_unpacked(
     field_number: u32,
     wire_type: WireType,
@@ -45,7 +58,8 @@ pub(crate) fn read_unknown_or_skip_group_with_tag_unpacked(
     unknown_fields: &mut UnknownFields,
 ) -> crate::Result<()> {
     match wire_type {
-        WireType::StartGroup => skip_group(is),
+        WireType::StartGroup => skip_group(field_number, is),
+        WireType::EndGroup => is.end_group(field_number),
         _ => {
             let unknown = is.read_unknown(wire_type)?;
             unknown_fields.add_value(field_number, unknown);
@@ -0,0 +1,105 @@
+use crate::coded_input_stream::DEFAULT_RECURSION_LIMIT;
+use crate::error::WireError;
+use crate::varint::decode_varint64;
+use crate::view::ViewType;
+use crate::wire_format::Tag;
+use crate::wire_format::WireType;
+
+fn read_raw<'a>(input: &mut &'a [u8], len: usize) -> crate::Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(WireError::UnexpectedEof.into());
+    }
+    let (r, rem) = input.split_at(len);
+    *input = rem;
+    Ok(r)
+}
+
+pub(crate) fn read_varint(input: &mut &[u8]) -> crate::Result<u64> {
+    match decode_varint64(input)? {
+        Some((value, len)) => {
+            *input = &input[len..];
+            Ok(value)
+        }
+        None => Err(WireError::UnexpectedEof.into()),
+    }
+}
+
+pub(crate) fn read_fixed32(input: &mut &[u8]) -> crate::Result<u32> {
+    let bytes = read_raw(input, 4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub(crate) fn read_fixed64(input: &mut &[u8]) -> crate::Result<u64> {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(read_raw(input, 8)?);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub(crate) fn read_len_delimited<'a>(input: &mut &'a [u8]) -> crate::Result<&'a [u8]> {
+    let len = read_varint(input)?;
+    if len > input.len() as u64 {
+        return Err(WireError::UnexpectedEof.into());
+    }
+    read_raw(input, len as usize)
+}
+
+fn skip_group(input: &mut &[u8], field_number: u32, depth: u32) -> crate::Result<()> {
+    if depth > DEFAULT_RECURSION_LIMIT {
+        return Err(WireError::OverRecursionLimit.into());
+    }
+    loop {
+        let tag = match view_read_tag(input)? {
+            Some(tag) => tag,
+            None => return Err(WireError::UnexpectedEof.into()),
+        };
+        match Tag::new(tag)?.unpack() {
+            (number, WireType::EndGroup) if number == field_number => return Ok(()),
+            (number, WireType::StartGroup) => skip_group(input, number, depth + 1)?,
+            _ => skip_field(input, tag, depth)?,
+        }
+    }
+}
+
+fn skip_field(input: &mut &[u8], tag: u32, depth: u32) -> crate::Result<()> {
+    match Tag::new(tag)?.unpack() {
+        (_, WireType::Varint) => read_varint(input).map(drop),
+        (_, WireType::Fixed64) => read_raw(input, 8).map(drop),
+        (_, WireType::Fixed32) => read_raw(input, 4).map(drop),
+        (_, WireType::LengthDelimited) => read_len_delimited(input).map(drop),
+        (number, WireType::StartGroup) => skip_group(input, number, depth + 1),
+        (_, WireType::EndGroup) => Err(WireError::UnexpectedWireType(WireType::EndGroup).into()),
+    }
+}
+
+/// Read next field tag of a message view, return `None` at the end of the message.
+pub fn view_read_tag(input: &mut &[u8]) -> crate::Result<Option<u32>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let tag = read_varint(input)? as u32;
+    Tag::new(tag)?;
+    Ok(Some(tag))
+}
+
+/// Skip field value of a message view, including groups.
+pub fn view_skip_field(input: &mut &[u8], tag: u32) -> crate::Result<()> {
+    skip_field(input, tag, 0)
+}
+
+/// Validate an element of repeated field of a message view,
+/// or all elements if they are packed.
+pub fn view_check_repeated<'a, T: ViewType<'a>>(
+    input: &mut &'a [u8],
+    tag: u32,
+) -> crate::Result<()> {
+    let (_, wire_type) = Tag::new(tag)?.unpack();
+    if wire_type == WireType::LengthDelimited && T::WIRE_TYPE != WireType::LengthDelimited {
+        let mut packed = read_len_delimited(input)?;
+        while !packed.is_empty() {
+            T::read(&mut packed)?;
+        }
+    } else {
+        T::read(input)?;
+    }
+    Ok(())
+}
@@ -24,51 +24,61 @@
 /// Extension fields
 pub mod exts {
 
-    pub const expose_oneof_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17001, phantom: ::std::marker::PhantomData };
+    pub const expose_oneof_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17001, phantom: ::core::marker::PhantomData };
 
-    pub const expose_fields_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
+    pub const expose_fields_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::core::marker::PhantomData };
 
-    pub const generate_accessors_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
+    pub const generate_accessors_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::core::marker::PhantomData };
 
-    pub const generate_getter_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::std::marker::PhantomData };
+    pub const generate_getter_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_bytes_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::std::marker::PhantomData };
+    pub const tokio_bytes_for_bytes_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_string_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
+    pub const tokio_bytes_for_string_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::core::marker::PhantomData };
 
-    pub const serde_derive_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17030, phantom: ::std::marker::PhantomData };
+    pub const serde_derive_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17030, phantom: ::core::marker::PhantomData };
 
-    pub const serde_derive_cfg_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17031, phantom: ::std::marker::PhantomData };
+    pub const serde_derive_cfg_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17031, phantom: ::core::marker::PhantomData };
 
-    pub const lite_runtime_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17035, phantom: ::std::marker::PhantomData };
+    pub const lite_runtime_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17035, phantom: ::core::marker::PhantomData };
 
-    pub const expose_oneof: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17001, phantom: ::std::marker::PhantomData };
+    pub const gen_services_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17036, phantom: ::core::marker::PhantomData };
 
-    pub const expose_fields: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
+    pub const gen_views_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17037, phantom: ::core::marker::PhantomData };
 
-    pub const generate_accessors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
+    pub const table_driven_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17038, phantom: ::core::marker::PhantomData };
 
-    pub const generate_getter: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::std::marker::PhantomData };
+    pub const gen_cached_size_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17039, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_bytes: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::std::marker::PhantomData };
+    pub const btree_map_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17040, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_string: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
+    pub const expose_oneof: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17001, phantom: ::core::marker::PhantomData };
 
-    pub const serde_derive: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17030, phantom: ::std::marker::PhantomData };
+    pub const expose_fields: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::core::marker::PhantomData };
 
-    pub const serde_derive_cfg: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17031, phantom: ::std::marker::PhantomData };
+    pub const generate_accessors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::core::marker::PhantomData };
 
-    pub const expose_fields_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
+    pub const generate_getter: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::core::marker::PhantomData };
 
-    pub const generate_accessors_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
+    pub const tokio_bytes_for_bytes: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::core::marker::PhantomData };
 
-    pub const generate_getter_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::std::marker::PhantomData };
+    pub const tokio_bytes_for_string: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_bytes_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::std::marker::PhantomData };
+    pub const serde_derive: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17030, phantom: ::core::marker::PhantomData };
 
-    pub const tokio_bytes_for_string_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
+    pub const serde_derive_cfg: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17031, phantom: ::core::marker::PhantomData };
 
-    pub const serde_rename_all: crate::ext::ExtFieldOptional<crate::descriptor::EnumOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17032, phantom: ::std::marker::PhantomData };
+    pub const expose_fields_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17003, phantom: ::core::marker::PhantomData };
+
+    pub const generate_accessors_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17004, phantom: ::core::marker::PhantomData };
+
+    pub const generate_getter_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17005, phantom: ::core::marker::PhantomData };
+
+    pub const tokio_bytes_for_bytes_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17011, phantom: ::core::marker::PhantomData };
+
+    pub const tokio_bytes_for_string_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, crate::reflect::types::ProtobufTypeBool> = crate::ext::ExtFieldOptional { field_number: 17012, phantom: ::core::marker::PhantomData };
+
+    pub const serde_rename_all: crate::ext::ExtFieldOptional<crate::descriptor::EnumOptions, crate::reflect::types::ProtobufTypeString> = crate::ext::ExtFieldOptional { field_number: 17032, phantom: ::core::marker::PhantomData };
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
@@ -87,135 +97,164 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     eOptionsR\x0eserdeDeriveAll:O\n\x14serde_derive_cfg_all\x18\x87\x85\x01\
     \x20\x01(\t\x12\x1c.google.protobuf.FileOptionsR\x11serdeDeriveCfgAll:H\
     \n\x10lite_runtime_all\x18\x8b\x85\x01\x20\x01(\x08\x12\x1c.google.proto\
-    buf.FileOptionsR\x0eliteRuntimeAll:D\n\x0cexpose_oneof\x18\xe9\x84\x01\
-    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0bexposeOneof:F\n\
-    \rexpose_fields\x18\xeb\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.Mes\
-    sageOptionsR\x0cexposeFields:P\n\x12generate_accessors\x18\xec\x84\x01\
-    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x11generateAccesso\
-    rs:J\n\x0fgenerate_getter\x18\xed\x84\x01\x20\x01(\x08\x12\x1f.google.pr\
-    otobuf.MessageOptionsR\x0egenerateGetter:T\n\x15tokio_bytes_for_bytes\
-    \x18\xf3\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
-    \x12tokioBytesForBytes:V\n\x16tokio_bytes_for_string\x18\xf4\x84\x01\x20\
-    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x13tokioBytesForString\
-    :D\n\x0cserde_derive\x18\x86\x85\x01\x20\x01(\x08\x12\x1f.google.protobu\
-    f.MessageOptionsR\x0bserdeDerive:K\n\x10serde_derive_cfg\x18\x87\x85\x01\
-    \x20\x01(\t\x12\x1f.google.protobuf.MessageOptionsR\x0eserdeDeriveCfg:O\
-    \n\x13expose_fields_field\x18\xeb\x84\x01\x20\x01(\x08\x12\x1d.google.pr\
-    otobuf.FieldOptionsR\x11exposeFieldsField:Y\n\x18generate_accessors_fiel\
-    d\x18\xec\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16\
-    generateAccessorsField:S\n\x15generate_getter_field\x18\xed\x84\x01\x20\
-    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x13generateGetterField:]\
-    \n\x1btokio_bytes_for_bytes_field\x18\xf3\x84\x01\x20\x01(\x08\x12\x1d.g\
-    oogle.protobuf.FieldOptionsR\x17tokioBytesForBytesField:_\n\x1ctokio_byt\
-    es_for_string_field\x18\xf4\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf\
-    .FieldOptionsR\x18tokioBytesForStringField:H\n\x10serde_rename_all\x18\
-    \x88\x85\x01\x20\x01(\t\x12\x1c.google.protobuf.EnumOptionsR\x0eserdeRen\
-    ameAllJ\x86\x19\n\x06\x12\x04\0\0F\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\
-    \t\n\x02\x03\0\x12\x03\x02\0*\n\xe5\x01\n\x01\x02\x12\x03\n\0\x122^\x20s\
-    ee\x20https://github.com/gogo/protobuf/blob/master/gogoproto/gogo.proto\
-    \n\x20for\x20the\x20original\x20idea\n2{\x20Generated\x20files\x20can\
-    \x20be\x20customized\x20using\x20this\x20proto\n\x20or\x20using\x20`Cust\
-    omize`\x20struct\x20when\x20codegen\x20is\x20invoked\x20programmatically\
-    .\n\n\t\n\x01\x07\x12\x04\x0c\0!\x01\n7\n\x02\x07\0\x12\x03\x0e\x04+\x1a\
-    ,\x20When\x20true,\x20oneof\x20field\x20is\x20generated\x20public\n\n\n\
-    \n\x03\x07\0\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\0\x04\x12\x03\x0e\x04\
-    \x0c\n\n\n\x03\x07\0\x05\x12\x03\x0e\r\x11\n\n\n\x03\x07\0\x01\x12\x03\
-    \x0e\x12\"\n\n\n\x03\x07\0\x03\x12\x03\x0e%*\nI\n\x02\x07\x01\x12\x03\
-    \x10\x04,\x1a>\x20When\x20true\x20all\x20fields\x20are\x20public,\x20and\
-    \x20not\x20accessors\x20generated\n\n\n\n\x03\x07\x01\x02\x12\x03\x0c\
-    \x07\"\n\n\n\x03\x07\x01\x04\x12\x03\x10\x04\x0c\n\n\n\x03\x07\x01\x05\
-    \x12\x03\x10\r\x11\n\n\n\x03\x07\x01\x01\x12\x03\x10\x12#\n\n\n\x03\x07\
-    \x01\x03\x12\x03\x10&+\nP\n\x02\x07\x02\x12\x03\x12\x041\x1aE\x20When\
-    \x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\
-    \x20not\x20generated\n\n\n\n\x03\x07\x02\x02\x12\x03\x0c\x07\"\n\n\n\x03\
-    \x07\x02\x04\x12\x03\x12\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\x12\r\x11\
-    \n\n\n\x03\x07\x02\x01\x12\x03\x12\x12(\n\n\n\x03\x07\x02\x03\x12\x03\
-    \x12+0\nL\n\x02\x07\x03\x12\x03\x14\x04.\x1aA\x20When\x20false,\x20`get_\
-    `\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\
-    \n\n\n\n\x03\x07\x03\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x03\x04\x12\x03\
-    \x14\x04\x0c\n\n\n\x03\x07\x03\x05\x12\x03\x14\r\x11\n\n\n\x03\x07\x03\
-    \x01\x12\x03\x14\x12%\n\n\n\x03\x07\x03\x03\x12\x03\x14(-\n2\n\x02\x07\
-    \x04\x12\x03\x16\x044\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\
-    \x20fields\n\n\n\n\x03\x07\x04\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x04\
-    \x04\x12\x03\x16\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\x16\r\x11\n\n\n\
-    \x03\x07\x04\x01\x12\x03\x16\x12+\n\n\n\x03\x07\x04\x03\x12\x03\x16.3\n3\
-    \n\x02\x07\x05\x12\x03\x18\x045\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20\
-    `string`\x20fields\n\n\n\n\x03\x07\x05\x02\x12\x03\x0c\x07\"\n\n\n\x03\
-    \x07\x05\x04\x12\x03\x18\x04\x0c\n\n\n\x03\x07\x05\x05\x12\x03\x18\r\x11\
-    \n\n\n\x03\x07\x05\x01\x12\x03\x18\x12,\n\n\n\x03\x07\x05\x03\x12\x03\
-    \x18/4\nJ\n\x02\x07\x06\x12\x03\x1b\x04+\x1a?\x20Use\x20`serde_derive`\
-    \x20to\x20implement\x20`Serialize`\x20and\x20`Deserialize`\n\n\n\n\x03\
-    \x07\x06\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x06\x04\x12\x03\x1b\x04\x0c\
-    \n\n\n\x03\x07\x06\x05\x12\x03\x1b\r\x11\n\n\n\x03\x07\x06\x01\x12\x03\
-    \x1b\x12\"\n\n\n\x03\x07\x06\x03\x12\x03\x1b%*\n3\n\x02\x07\x07\x12\x03\
-    \x1d\x041\x1a(\x20Guard\x20serde\x20annotations\x20with\x20cfg\x20attr.\
-    \n\n\n\n\x03\x07\x07\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x07\x04\x12\x03\
-    \x1d\x04\x0c\n\n\n\x03\x07\x07\x05\x12\x03\x1d\r\x13\n\n\n\x03\x07\x07\
-    \x01\x12\x03\x1d\x14(\n\n\n\x03\x07\x07\x03\x12\x03\x1d+0\nN\n\x02\x07\
-    \x08\x12\x03\x20\x04+\x1aC\x20When\x20true,\x20will\x20only\x20generate\
-    \x20codes\x20that\x20works\x20with\x20lite\x20runtime.\n\n\n\n\x03\x07\
-    \x08\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x08\x04\x12\x03\x20\x04\x0c\n\n\
-    \n\x03\x07\x08\x05\x12\x03\x20\r\x11\n\n\n\x03\x07\x08\x01\x12\x03\x20\
-    \x12\"\n\n\n\x03\x07\x08\x03\x12\x03\x20%*\n\t\n\x01\x07\x12\x04#\04\x01\
-    \n7\n\x02\x07\t\x12\x03%\x04'\x1a,\x20When\x20true,\x20oneof\x20field\
-    \x20is\x20generated\x20public\n\n\n\n\x03\x07\t\x02\x12\x03#\x07%\n\n\n\
-    \x03\x07\t\x04\x12\x03%\x04\x0c\n\n\n\x03\x07\t\x05\x12\x03%\r\x11\n\n\n\
-    \x03\x07\t\x01\x12\x03%\x12\x1e\n\n\n\x03\x07\t\x03\x12\x03%!&\nI\n\x02\
-    \x07\n\x12\x03'\x04(\x1a>\x20When\x20true\x20all\x20fields\x20are\x20pub\
-    lic,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\n\x02\x12\
-    \x03#\x07%\n\n\n\x03\x07\n\x04\x12\x03'\x04\x0c\n\n\n\x03\x07\n\x05\x12\
-    \x03'\r\x11\n\n\n\x03\x07\n\x01\x12\x03'\x12\x1f\n\n\n\x03\x07\n\x03\x12\
-    \x03'\"'\nP\n\x02\x07\x0b\x12\x03)\x04-\x1aE\x20When\x20false,\x20`get_`\
-    ,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20generated\n\
-    \n\n\n\x03\x07\x0b\x02\x12\x03#\x07%\n\n\n\x03\x07\x0b\x04\x12\x03)\x04\
-    \x0c\n\n\n\x03\x07\x0b\x05\x12\x03)\r\x11\n\n\n\x03\x07\x0b\x01\x12\x03)\
-    \x12$\n\n\n\x03\x07\x0b\x03\x12\x03)',\nL\n\x02\x07\x0c\x12\x03+\x04*\
-    \x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20generated\x20even\x20i\
-    f\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x0c\x02\x12\x03#\x07%\n\
-    \n\n\x03\x07\x0c\x04\x12\x03+\x04\x0c\n\n\n\x03\x07\x0c\x05\x12\x03+\r\
-    \x11\n\n\n\x03\x07\x0c\x01\x12\x03+\x12!\n\n\n\x03\x07\x0c\x03\x12\x03+$\
-    )\n2\n\x02\x07\r\x12\x03-\x040\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`\
-    bytes`\x20fields\n\n\n\n\x03\x07\r\x02\x12\x03#\x07%\n\n\n\x03\x07\r\x04\
-    \x12\x03-\x04\x0c\n\n\n\x03\x07\r\x05\x12\x03-\r\x11\n\n\n\x03\x07\r\x01\
-    \x12\x03-\x12'\n\n\n\x03\x07\r\x03\x12\x03-*/\n3\n\x02\x07\x0e\x12\x03/\
-    \x041\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\
-    \x03\x07\x0e\x02\x12\x03#\x07%\n\n\n\x03\x07\x0e\x04\x12\x03/\x04\x0c\n\
-    \n\n\x03\x07\x0e\x05\x12\x03/\r\x11\n\n\n\x03\x07\x0e\x01\x12\x03/\x12(\
-    \n\n\n\x03\x07\x0e\x03\x12\x03/+0\nJ\n\x02\x07\x0f\x12\x031\x04'\x1a?\
-    \x20Use\x20`serde_derive`\x20to\x20implement\x20`Serialize`\x20and\x20`D\
-    eserialize`\n\n\n\n\x03\x07\x0f\x02\x12\x03#\x07%\n\n\n\x03\x07\x0f\x04\
-    \x12\x031\x04\x0c\n\n\n\x03\x07\x0f\x05\x12\x031\r\x11\n\n\n\x03\x07\x0f\
-    \x01\x12\x031\x12\x1e\n\n\n\x03\x07\x0f\x03\x12\x031!&\n3\n\x02\x07\x10\
-    \x12\x033\x04-\x1a(\x20Guard\x20serde\x20annotations\x20with\x20cfg\x20a\
-    ttr.\n\n\n\n\x03\x07\x10\x02\x12\x03#\x07%\n\n\n\x03\x07\x10\x04\x12\x03\
-    3\x04\x0c\n\n\n\x03\x07\x10\x05\x12\x033\r\x13\n\n\n\x03\x07\x10\x01\x12\
-    \x033\x14$\n\n\n\x03\x07\x10\x03\x12\x033',\n\t\n\x01\x07\x12\x046\0A\
-    \x01\nI\n\x02\x07\x11\x12\x038\x04.\x1a>\x20When\x20true\x20all\x20field\
-    s\x20are\x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\
-    \x07\x11\x02\x12\x036\x07#\n\n\n\x03\x07\x11\x04\x12\x038\x04\x0c\n\n\n\
-    \x03\x07\x11\x05\x12\x038\r\x11\n\n\n\x03\x07\x11\x01\x12\x038\x12%\n\n\
-    \n\x03\x07\x11\x03\x12\x038(-\nP\n\x02\x07\x12\x12\x03:\x043\x1aE\x20Whe\
-    n\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\
-    \x20not\x20generated\n\n\n\n\x03\x07\x12\x02\x12\x036\x07#\n\n\n\x03\x07\
-    \x12\x04\x12\x03:\x04\x0c\n\n\n\x03\x07\x12\x05\x12\x03:\r\x11\n\n\n\x03\
-    \x07\x12\x01\x12\x03:\x12*\n\n\n\x03\x07\x12\x03\x12\x03:-2\nL\n\x02\x07\
-    \x13\x12\x03<\x040\x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20gene\
-    rated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x13\
-    \x02\x12\x036\x07#\n\n\n\x03\x07\x13\x04\x12\x03<\x04\x0c\n\n\n\x03\x07\
-    \x13\x05\x12\x03<\r\x11\n\n\n\x03\x07\x13\x01\x12\x03<\x12'\n\n\n\x03\
-    \x07\x13\x03\x12\x03<*/\n2\n\x02\x07\x14\x12\x03>\x046\x1a'\x20Use\x20`b\
-    ytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x14\x02\x12\x03\
-    6\x07#\n\n\n\x03\x07\x14\x04\x12\x03>\x04\x0c\n\n\n\x03\x07\x14\x05\x12\
-    \x03>\r\x11\n\n\n\x03\x07\x14\x01\x12\x03>\x12-\n\n\n\x03\x07\x14\x03\
-    \x12\x03>05\n3\n\x02\x07\x15\x12\x03@\x047\x1a(\x20Use\x20`bytes::Bytes`\
-    \x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x15\x02\x12\x036\x07#\n\n\
-    \n\x03\x07\x15\x04\x12\x03@\x04\x0c\n\n\n\x03\x07\x15\x05\x12\x03@\r\x11\
-    \n\n\n\x03\x07\x15\x01\x12\x03@\x12.\n\n\n\x03\x07\x15\x03\x12\x03@16\n\
-    \t\n\x01\x07\x12\x04C\0F\x01\n/\n\x02\x07\x16\x12\x03E\x04-\x1a$\x20use\
-    \x20rename_all\x20attribute\x20for\x20serde\n\n\n\n\x03\x07\x16\x02\x12\
-    \x03C\x07\"\n\n\n\x03\x07\x16\x04\x12\x03E\x04\x0c\n\n\n\x03\x07\x16\x05\
-    \x12\x03E\r\x13\n\n\n\x03\x07\x16\x01\x12\x03E\x14$\n\n\n\x03\x07\x16\
-    \x03\x12\x03E',\
+    buf.FileOptionsR\x0eliteRuntimeAll:H\n\x10gen_services_all\x18\x8c\x85\
+    \x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0egenServicesAll\
+    :B\n\rgen_views_all\x18\x8d\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf\
+    .FileOptionsR\x0bgenViewsAll:H\n\x10table_driven_all\x18\x8e\x85\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0etableDrivenAll:M\n\x13\
+    gen_cached_size_all\x18\x8f\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf\
+    .FileOptionsR\x10genCachedSizeAll:B\n\rbtree_map_all\x18\x90\x85\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0bbtreeMapAll:D\n\x0cexp\
+    ose_oneof\x18\xe9\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOp\
+    tionsR\x0bexposeOneof:F\n\rexpose_fields\x18\xeb\x84\x01\x20\x01(\x08\
+    \x12\x1f.google.protobuf.MessageOptionsR\x0cexposeFields:P\n\x12generate\
+    _accessors\x18\xec\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageO\
+    ptionsR\x11generateAccessors:J\n\x0fgenerate_getter\x18\xed\x84\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0egenerateGetter:T\n\
+    \x15tokio_bytes_for_bytes\x18\xf3\x84\x01\x20\x01(\x08\x12\x1f.google.pr\
+    otobuf.MessageOptionsR\x12tokioBytesForBytes:V\n\x16tokio_bytes_for_stri\
+    ng\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x13tokioBytesForString:D\n\x0cserde_derive\x18\x86\x85\x01\x20\x01(\x08\
+    \x12\x1f.google.protobuf.MessageOptionsR\x0bserdeDerive:K\n\x10serde_der\
+    ive_cfg\x18\x87\x85\x01\x20\x01(\t\x12\x1f.google.protobuf.MessageOption\
+    sR\x0eserdeDeriveCfg:O\n\x13expose_fields_field\x18\xeb\x84\x01\x20\x01(\
+    \x08\x12\x1d.google.protobuf.FieldOptionsR\x11exposeFieldsField:Y\n\x18g\
+    enerate_accessors_field\x18\xec\x84\x01\x20\x01(\x08\x12\x1d.google.prot\
+    obuf.FieldOptionsR\x16generateAccessorsField:S\n\x15generate_getter_fiel\
+    d\x18\xed\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x13\
+    generateGetterField:]\n\x1btokio_bytes_for_bytes_field\x18\xf3\x84\x01\
+    \x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x17tokioBytesForByte\
+    sField:_\n\x1ctokio_bytes_for_string_field\x18\xf4\x84\x01\x20\x01(\x08\
+    \x12\x1d.google.protobuf.FieldOptionsR\x18tokioBytesForStringField:H\n\
+    \x10serde_rename_all\x18\x88\x85\x01\x20\x01(\t\x12\x1c.google.protobuf.\
+    EnumOptionsR\x0eserdeRenameAllJ\x91\x1e\n\x06\x12\x04\0\0U\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\0*\n\xe5\x01\n\x01\
+    \x02\x12\x03\n\0\x122^\x20see\x20https://github.com/gogo/protobuf/blob/m\
+    aster/gogoproto/gogo.proto\n\x20for\x20the\x20original\x20idea\n2{\x20Ge\
+    nerated\x20files\x20can\x20be\x20customized\x20using\x20this\x20proto\n\
+    \x20or\x20using\x20`Customize`\x20struct\x20when\x20codegen\x20is\x20inv\
+    oked\x20programmatically.\n\n\t\n\x01\x07\x12\x04\x0c\00\x01\n7\n\x02\
+    \x07\0\x12\x03\x0e\x04+\x1a,\x20When\x20true,\x20oneof\x20field\x20is\
+    \x20generated\x20public\n\n\n\n\x03\x07\0\x02\x12\x03\x0c\x07\"\n\n\n\
+    \x03\x07\0\x04\x12\x03\x0e\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0e\r\x11\
+    \n\n\n\x03\x07\0\x01\x12\x03\x0e\x12\"\n\n\n\x03\x07\0\x03\x12\x03\x0e%*\
+    \nI\n\x02\x07\x01\x12\x03\x10\x04,\x1a>\x20When\x20true\x20all\x20fields\
+    \x20are\x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\
+    \x07\x01\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x01\x04\x12\x03\x10\x04\x0c\
+    \n\n\n\x03\x07\x01\x05\x12\x03\x10\r\x11\n\n\n\x03\x07\x01\x01\x12\x03\
+    \x10\x12#\n\n\n\x03\x07\x01\x03\x12\x03\x10&+\nP\n\x02\x07\x02\x12\x03\
+    \x12\x041\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\
+    \x20accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x02\x02\x12\x03\
+    \x0c\x07\"\n\n\n\x03\x07\x02\x04\x12\x03\x12\x04\x0c\n\n\n\x03\x07\x02\
+    \x05\x12\x03\x12\r\x11\n\n\n\x03\x07\x02\x01\x12\x03\x12\x12(\n\n\n\x03\
+    \x07\x02\x03\x12\x03\x12+0\nL\n\x02\x07\x03\x12\x03\x14\x04.\x1aA\x20Whe\
+    n\x20false,\x20`get_`\x20is\x20not\x20generated\x20even\x20if\x20`syntax\
+    \x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x03\x02\x12\x03\x0c\x07\"\n\n\n\x03\
+    \x07\x03\x04\x12\x03\x14\x04\x0c\n\n\n\x03\x07\x03\x05\x12\x03\x14\r\x11\
+    \n\n\n\x03\x07\x03\x01\x12\x03\x14\x12%\n\n\n\x03\x07\x03\x03\x12\x03\
+    \x14(-\n2\n\x02\x07\x04\x12\x03\x16\x044\x1a'\x20Use\x20`bytes::Bytes`\
+    \x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x04\x02\x12\x03\x0c\x07\"\n\
+    \n\n\x03\x07\x04\x04\x12\x03\x16\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\
+    \x16\r\x11\n\n\n\x03\x07\x04\x01\x12\x03\x16\x12+\n\n\n\x03\x07\x04\x03\
+    \x12\x03\x16.3\n3\n\x02\x07\x05\x12\x03\x18\x045\x1a(\x20Use\x20`bytes::\
+    Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x05\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\x05\x04\x12\x03\x18\x04\x0c\n\n\n\x03\x07\x05\x05\
+    \x12\x03\x18\r\x11\n\n\n\x03\x07\x05\x01\x12\x03\x18\x12,\n\n\n\x03\x07\
+    \x05\x03\x12\x03\x18/4\nJ\n\x02\x07\x06\x12\x03\x1b\x04+\x1a?\x20Use\x20\
+    `serde_derive`\x20to\x20implement\x20`Serialize`\x20and\x20`Deserialize`\
+    \n\n\n\n\x03\x07\x06\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x06\x04\x12\x03\
+    \x1b\x04\x0c\n\n\n\x03\x07\x06\x05\x12\x03\x1b\r\x11\n\n\n\x03\x07\x06\
+    \x01\x12\x03\x1b\x12\"\n\n\n\x03\x07\x06\x03\x12\x03\x1b%*\n3\n\x02\x07\
+    \x07\x12\x03\x1d\x041\x1a(\x20Guard\x20serde\x20annotations\x20with\x20c\
+    fg\x20attr.\n\n\n\n\x03\x07\x07\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x07\
+    \x04\x12\x03\x1d\x04\x0c\n\n\n\x03\x07\x07\x05\x12\x03\x1d\r\x13\n\n\n\
+    \x03\x07\x07\x01\x12\x03\x1d\x14(\n\n\n\x03\x07\x07\x03\x12\x03\x1d+0\nN\
+    \n\x02\x07\x08\x12\x03\x20\x04+\x1aC\x20When\x20true,\x20will\x20only\
+    \x20generate\x20codes\x20that\x20works\x20with\x20lite\x20runtime.\n\n\n\
+    \n\x03\x07\x08\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x08\x04\x12\x03\x20\
+    \x04\x0c\n\n\n\x03\x07\x08\x05\x12\x03\x20\r\x11\n\n\n\x03\x07\x08\x01\
+    \x12\x03\x20\x12\"\n\n\n\x03\x07\x08\x03\x12\x03\x20%*\nG\n\x02\x07\t\
+    \x12\x03#\x04+\x1a<\x20Generate\x20server\x20trait,\x20client\x20and\x20\
+    server\x20for\x20each\x20service.\n\n\n\n\x03\x07\t\x02\x12\x03\x0c\x07\
+    \"\n\n\n\x03\x07\t\x04\x12\x03#\x04\x0c\n\n\n\x03\x07\t\x05\x12\x03#\r\
+    \x11\n\n\n\x03\x07\t\x01\x12\x03#\x12\"\n\n\n\x03\x07\t\x03\x12\x03#%*\n\
+    D\n\x02\x07\n\x12\x03&\x04(\x1a9\x20Generate\x20borrowed\x20read-only\
+    \x20view\x20type\x20for\x20each\x20message.\n\n\n\n\x03\x07\n\x02\x12\
+    \x03\x0c\x07\"\n\n\n\x03\x07\n\x04\x12\x03&\x04\x0c\n\n\n\x03\x07\n\x05\
+    \x12\x03&\r\x11\n\n\n\x03\x07\n\x01\x12\x03&\x12\x1f\n\n\n\x03\x07\n\x03\
+    \x12\x03&\"'\nT\n\x02\x07\x0b\x12\x03)\x04+\x1aI\x20Generate\x20static\
+    \x20field\x20tables\x20instead\x20of\x20per-message\x20serialization\x20\
+    code.\n\n\n\n\x03\x07\x0b\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x0b\x04\
+    \x12\x03)\x04\x0c\n\n\n\x03\x07\x0b\x05\x12\x03)\r\x11\n\n\n\x03\x07\x0b\
+    \x01\x12\x03)\x12\"\n\n\n\x03\x07\x0b\x03\x12\x03)%*\n6\n\x02\x07\x0c\
+    \x12\x03,\x04.\x1a+\x20Generate\x20`cached_size`\x20field\x20in\x20messa\
+    ges.\n\n\n\n\x03\x07\x0c\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x0c\x04\x12\
+    \x03,\x04\x0c\n\n\n\x03\x07\x0c\x05\x12\x03,\r\x11\n\n\n\x03\x07\x0c\x01\
+    \x12\x03,\x12%\n\n\n\x03\x07\x0c\x03\x12\x03,(-\n@\n\x02\x07\r\x12\x03/\
+    \x04(\x1a5\x20Use\x20`BTreeMap`\x20instead\x20of\x20`HashMap`\x20for\x20\
+    map\x20fields.\n\n\n\n\x03\x07\r\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\r\
+    \x04\x12\x03/\x04\x0c\n\n\n\x03\x07\r\x05\x12\x03/\r\x11\n\n\n\x03\x07\r\
+    \x01\x12\x03/\x12\x1f\n\n\n\x03\x07\r\x03\x12\x03/\"'\n\t\n\x01\x07\x12\
+    \x042\0C\x01\n7\n\x02\x07\x0e\x12\x034\x04'\x1a,\x20When\x20true,\x20one\
+    of\x20field\x20is\x20generated\x20public\n\n\n\n\x03\x07\x0e\x02\x12\x03\
+    2\x07%\n\n\n\x03\x07\x0e\x04\x12\x034\x04\x0c\n\n\n\x03\x07\x0e\x05\x12\
+    \x034\r\x11\n\n\n\x03\x07\x0e\x01\x12\x034\x12\x1e\n\n\n\x03\x07\x0e\x03\
+    \x12\x034!&\nI\n\x02\x07\x0f\x12\x036\x04(\x1a>\x20When\x20true\x20all\
+    \x20fields\x20are\x20public,\x20and\x20not\x20accessors\x20generated\n\n\
+    \n\n\x03\x07\x0f\x02\x12\x032\x07%\n\n\n\x03\x07\x0f\x04\x12\x036\x04\
+    \x0c\n\n\n\x03\x07\x0f\x05\x12\x036\r\x11\n\n\n\x03\x07\x0f\x01\x12\x036\
+    \x12\x1f\n\n\n\x03\x07\x0f\x03\x12\x036\"'\nP\n\x02\x07\x10\x12\x038\x04\
+    -\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20acce\
+    ssors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x10\x02\x12\x032\x07%\n\
+    \n\n\x03\x07\x10\x04\x12\x038\x04\x0c\n\n\n\x03\x07\x10\x05\x12\x038\r\
+    \x11\n\n\n\x03\x07\x10\x01\x12\x038\x12$\n\n\n\x03\x07\x10\x03\x12\x038'\
+    ,\nL\n\x02\x07\x11\x12\x03:\x04*\x1aA\x20When\x20false,\x20`get_`\x20is\
+    \x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\
+    \n\x03\x07\x11\x02\x12\x032\x07%\n\n\n\x03\x07\x11\x04\x12\x03:\x04\x0c\
+    \n\n\n\x03\x07\x11\x05\x12\x03:\r\x11\n\n\n\x03\x07\x11\x01\x12\x03:\x12\
+    !\n\n\n\x03\x07\x11\x03\x12\x03:$)\n2\n\x02\x07\x12\x12\x03<\x040\x1a'\
+    \x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\
+    \x12\x02\x12\x032\x07%\n\n\n\x03\x07\x12\x04\x12\x03<\x04\x0c\n\n\n\x03\
+    \x07\x12\x05\x12\x03<\r\x11\n\n\n\x03\x07\x12\x01\x12\x03<\x12'\n\n\n\
+    \x03\x07\x12\x03\x12\x03<*/\n3\n\x02\x07\x13\x12\x03>\x041\x1a(\x20Use\
+    \x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x13\x02\
+    \x12\x032\x07%\n\n\n\x03\x07\x13\x04\x12\x03>\x04\x0c\n\n\n\x03\x07\x13\
+    \x05\x12\x03>\r\x11\n\n\n\x03\x07\x13\x01\x12\x03>\x12(\n\n\n\x03\x07\
+    \x13\x03\x12\x03>+0\nJ\n\x02\x07\x14\x12\x03@\x04'\x1a?\x20Use\x20`serde\
+    _derive`\x20to\x20implement\x20`Serialize`\x20and\x20`Deserialize`\n\n\n\
+    \n\x03\x07\x14\x02\x12\x032\x07%\n\n\n\x03\x07\x14\x04\x12\x03@\x04\x0c\
+    \n\n\n\x03\x07\x14\x05\x12\x03@\r\x11\n\n\n\x03\x07\x14\x01\x12\x03@\x12\
+    \x1e\n\n\n\x03\x07\x14\x03\x12\x03@!&\n3\n\x02\x07\x15\x12\x03B\x04-\x1a\
+    (\x20Guard\x20serde\x20annotations\x20with\x20cfg\x20attr.\n\n\n\n\x03\
+    \x07\x15\x02\x12\x032\x07%\n\n\n\x03\x07\x15\x04\x12\x03B\x04\x0c\n\n\n\
+    \x03\x07\x15\x05\x12\x03B\r\x13\n\n\n\x03\x07\x15\x01\x12\x03B\x14$\n\n\
+    \n\x03\x07\x15\x03\x12\x03B',\n\t\n\x01\x07\x12\x04E\0P\x01\nI\n\x02\x07\
+    \x16\x12\x03G\x04.\x1a>\x20When\x20true\x20all\x20fields\x20are\x20publi\
+    c,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\x16\x02\x12\
+    \x03E\x07#\n\n\n\x03\x07\x16\x04\x12\x03G\x04\x0c\n\n\n\x03\x07\x16\x05\
+    \x12\x03G\r\x11\n\n\n\x03\x07\x16\x01\x12\x03G\x12%\n\n\n\x03\x07\x16\
+    \x03\x12\x03G(-\nP\n\x02\x07\x17\x12\x03I\x043\x1aE\x20When\x20false,\
+    \x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20g\
+    enerated\n\n\n\n\x03\x07\x17\x02\x12\x03E\x07#\n\n\n\x03\x07\x17\x04\x12\
+    \x03I\x04\x0c\n\n\n\x03\x07\x17\x05\x12\x03I\r\x11\n\n\n\x03\x07\x17\x01\
+    \x12\x03I\x12*\n\n\n\x03\x07\x17\x03\x12\x03I-2\nL\n\x02\x07\x18\x12\x03\
+    K\x040\x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20generated\x20eve\
+    n\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x18\x02\x12\x03E\
+    \x07#\n\n\n\x03\x07\x18\x04\x12\x03K\x04\x0c\n\n\n\x03\x07\x18\x05\x12\
+    \x03K\r\x11\n\n\n\x03\x07\x18\x01\x12\x03K\x12'\n\n\n\x03\x07\x18\x03\
+    \x12\x03K*/\n2\n\x02\x07\x19\x12\x03M\x046\x1a'\x20Use\x20`bytes::Bytes`\
+    \x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x19\x02\x12\x03E\x07#\n\n\n\
+    \x03\x07\x19\x04\x12\x03M\x04\x0c\n\n\n\x03\x07\x19\x05\x12\x03M\r\x11\n\
+    \n\n\x03\x07\x19\x01\x12\x03M\x12-\n\n\n\x03\x07\x19\x03\x12\x03M05\n3\n\
+    \x02\x07\x1a\x12\x03O\x047\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`stri\
+    ng`\x20fields\n\n\n\n\x03\x07\x1a\x02\x12\x03E\x07#\n\n\n\x03\x07\x1a\
+    \x04\x12\x03O\x04\x0c\n\n\n\x03\x07\x1a\x05\x12\x03O\r\x11\n\n\n\x03\x07\
+    \x1a\x01\x12\x03O\x12.\n\n\n\x03\x07\x1a\x03\x12\x03O16\n\t\n\x01\x07\
+    \x12\x04R\0U\x01\n/\n\x02\x07\x1b\x12\x03T\x04-\x1a$\x20use\x20rename_al\
+    l\x20attribute\x20for\x20serde\n\n\n\n\x03\x07\x1b\x02\x12\x03R\x07\"\n\
+    \n\n\x03\x07\x1b\x04\x12\x03T\x04\x0c\n\n\n\x03\x07\x1b\x05\x12\x03T\r\
+    \x13\n\n\n\x03\x07\x1b\x01\x12\x03T\x14$\n\n\n\x03\x07\x1b\x03\x12\x03T'\
+    ,\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -230,10 +269,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
         deps.push(crate::descriptor::file_descriptor());
-        let mut messages = ::std::vec::Vec::new();
-        let mut enums = ::std::vec::Vec::new();
+        let mut messages = crate::rt::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
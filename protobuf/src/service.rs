@@ -0,0 +1,69 @@
+//! Support for generated services.
+//!
+//! Rust-protobuf is not an RPC library, so generated service code
+//! is transport agnostic. When `gen_services` codegen option is enabled,
+//! for each `service Foo` these are generated:
+//!
+//! * trait `Foo` to be implemented by the server
+//! * `FooClient<C>` which sends requests over a [`Channel`]
+//! * `FooServer<S>` which implements [`Channel`] by dispatching
+//!   serialized requests to an implementation of `Foo`
+//!
+//! Transport is provided by implementing [`Channel`] for the client side,
+//! and by calling `FooServer` [`Channel`] methods on the server side.
+
+/// Service method, passed to [`Channel`] by generated code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MethodInfo {
+    /// Fully qualified service name like `foo.bar.Baz`.
+    pub service: &'static str,
+    /// Method name as specified in `.proto` file.
+    pub method: &'static str,
+}
+
+impl MethodInfo {
+    /// Method path like `/foo.bar.Baz/Method` (same as in gRPC).
+    pub fn path(&self) -> String {
+        format!("/{}/{}", self.service, self.method)
+    }
+}
+
+/// Stream of messages of streaming methods.
+pub type Streaming<'a, T, E> = Box<dyn Iterator<Item = Result<T, E>> + 'a>;
+
+/// Serialized requests of client streaming methods.
+///
+/// Error is returned when request cannot be serialized.
+pub type Requests<'a> = Box<dyn Iterator<Item = crate::Result<Vec<u8>>> + 'a>;
+
+/// Transport used by generated clients.
+///
+/// Messages are passed serialized, so transport does not depend on message types.
+pub trait Channel {
+    /// Error returned by transport.
+    type Error: From<crate::Error>;
+
+    /// Call method with single request and single response.
+    fn unary(&self, method: &MethodInfo, request: Vec<u8>) -> Result<Vec<u8>, Self::Error>;
+
+    /// Call method with stream of requests and single response.
+    fn client_streaming<'a>(
+        &'a self,
+        method: &MethodInfo,
+        requests: Requests<'a>,
+    ) -> Result<Vec<u8>, Self::Error>;
+
+    /// Call method with single request and stream of responses.
+    fn server_streaming<'a>(
+        &'a self,
+        method: &MethodInfo,
+        request: Vec<u8>,
+    ) -> Result<Streaming<'a, Vec<u8>, Self::Error>, Self::Error>;
+
+    /// Call method with streams of requests and responses.
+    fn bidi_streaming<'a>(
+        &'a self,
+        method: &MethodInfo,
+        requests: Requests<'a>,
+    ) -> Result<Streaming<'a, Vec<u8>, Self::Error>, Self::Error>;
+}
@@ -0,0 +1,292 @@
+use bytes::BytesMut;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::Decoder;
+use tokio_util::codec::Encoder;
+
+use crate::reflect::MessageDescriptor;
+use crate::stream::codec::DynamicMessageCodec;
+use crate::stream::codec::MessageCodec;
+use crate::Message;
+use crate::MessageDyn;
+use crate::ParseOptions;
+
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Read next frame decoded by the codec.
+async fn read_frame<R, D>(
+    read: &mut R,
+    buf: &mut BytesMut,
+    codec: &mut D,
+) -> crate::Result<Option<D::Item>>
+where
+    R: AsyncRead + Unpin,
+    D: Decoder<Error = crate::Error>,
+{
+    loop {
+        if let Some(message) = codec.decode(buf)? {
+            return Ok(Some(message));
+        }
+        buf.reserve(READ_BUFFER_SIZE);
+        if read.read_buf(buf).await? == 0 {
+            return codec.decode_eof(buf);
+        }
+    }
+}
+
+/// Async reader of length-delimited messages of type `M`.
+///
+/// Same as [`MessageStreamReader`](crate::stream::MessageStreamReader), but over `AsyncRead`.
+pub struct AsyncMessageStreamReader<R: AsyncRead + Unpin, M: Message> {
+    read: R,
+    buf: BytesMut,
+    codec: MessageCodec<M>,
+}
+
+impl<R: AsyncRead + Unpin, M: Message> AsyncMessageStreamReader<R, M> {
+    /// Read messages from `AsyncRead`.
+    pub fn new(read: R) -> AsyncMessageStreamReader<R, M> {
+        AsyncMessageStreamReader {
+            read,
+            buf: BytesMut::new(),
+            codec: MessageCodec::new(),
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.codec.set_parse_options(options);
+    }
+
+    /// Read next message, return `None` on clean EOF.
+    ///
+    /// Input ending in the middle of a message is an error.
+    pub async fn read(&mut self) -> crate::Result<Option<M>> {
+        read_frame(&mut self.read, &mut self.buf, &mut self.codec).await
+    }
+
+    /// Get the underlying reader.
+    ///
+    /// Bytes read but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+/// Async reader of length-delimited messages of type specified by [`MessageDescriptor`].
+///
+/// Same as [`AsyncMessageStreamReader`], but for dynamic messages.
+pub struct AsyncDynamicMessageStreamReader<R: AsyncRead + Unpin> {
+    read: R,
+    buf: BytesMut,
+    codec: DynamicMessageCodec,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDynamicMessageStreamReader<R> {
+    /// Read messages from `AsyncRead`.
+    pub fn new(descriptor: MessageDescriptor, read: R) -> AsyncDynamicMessageStreamReader<R> {
+        AsyncDynamicMessageStreamReader {
+            read,
+            buf: BytesMut::new(),
+            codec: DynamicMessageCodec::new(descriptor),
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.codec.set_parse_options(options);
+    }
+
+    /// Read next message, return `None` on clean EOF.
+    ///
+    /// Input ending in the middle of a message is an error.
+    pub async fn read(&mut self) -> crate::Result<Option<Box<dyn MessageDyn>>> {
+        read_frame(&mut self.read, &mut self.buf, &mut self.codec).await
+    }
+
+    /// Get the underlying reader.
+    ///
+    /// Bytes read but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+/// Async writer of length-delimited messages of type `M`.
+///
+/// Same as [`MessageStreamWriter`](crate::stream::MessageStreamWriter), but over `AsyncWrite`.
+pub struct AsyncMessageStreamWriter<W: AsyncWrite + Unpin, M: Message> {
+    write: W,
+    buf: BytesMut,
+    codec: MessageCodec<M>,
+}
+
+impl<W: AsyncWrite + Unpin, M: Message> AsyncMessageStreamWriter<W, M> {
+    /// Write messages to `AsyncWrite`.
+    pub fn new(write: W) -> AsyncMessageStreamWriter<W, M> {
+        AsyncMessageStreamWriter {
+            write,
+            buf: BytesMut::new(),
+            codec: MessageCodec::new(),
+        }
+    }
+
+    /// Enable or disable deterministic serialization.
+    ///
+    /// See [`CodedOutputStream::set_deterministic`](crate::CodedOutputStream::set_deterministic).
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.codec.set_deterministic(deterministic);
+    }
+
+    /// Write a message.
+    ///
+    /// Results in error if message is not fully initialized.
+    pub async fn write(&mut self, message: &M) -> crate::Result<()> {
+        self.buf.clear();
+        self.codec.encode(message, &mut self.buf)?;
+        self.write.write_all(&self.buf).await?;
+        Ok(())
+    }
+
+    /// Flush the underlying `AsyncWrite`.
+    pub async fn flush(&mut self) -> crate::Result<()> {
+        self.write.flush().await?;
+        Ok(())
+    }
+
+    /// Get the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.write
+    }
+}
+
+/// Async writer of length-delimited messages of type specified by [`MessageDescriptor`].
+///
+/// Same as [`AsyncMessageStreamWriter`], but for dynamic messages.
+pub struct AsyncDynamicMessageStreamWriter<W: AsyncWrite + Unpin> {
+    write: W,
+    buf: BytesMut,
+    codec: DynamicMessageCodec,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncDynamicMessageStreamWriter<W> {
+    /// Write messages to `AsyncWrite`.
+    pub fn new(descriptor: MessageDescriptor, write: W) -> AsyncDynamicMessageStreamWriter<W> {
+        AsyncDynamicMessageStreamWriter {
+            write,
+            buf: BytesMut::new(),
+            codec: DynamicMessageCodec::new(descriptor),
+        }
+    }
+
+    /// Enable or disable deterministic serialization.
+    ///
+    /// See [`CodedOutputStream::set_deterministic`](crate::CodedOutputStream::set_deterministic).
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.codec.set_deterministic(deterministic);
+    }
+
+    /// Write a message.
+    ///
+    /// Results in error if message is not fully initialized.
+    ///
+    /// # Panics
+    ///
+    /// If message type is different from the stream message type.
+    pub async fn write(&mut self, message: &dyn MessageDyn) -> crate::Result<()> {
+        self.buf.clear();
+        self.codec.encode(message, &mut self.buf)?;
+        self.write.write_all(&self.buf).await?;
+        Ok(())
+    }
+
+    /// Flush the underlying `AsyncWrite`.
+    pub async fn flush(&mut self) -> crate::Result<()> {
+        self.write.flush().await?;
+        Ok(())
+    }
+
+    /// Get the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.write
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AsyncDynamicMessageStreamReader;
+    use super::AsyncDynamicMessageStreamWriter;
+    use super::AsyncMessageStreamReader;
+    use super::AsyncMessageStreamWriter;
+    use crate::well_known_types::BytesValue;
+    use crate::Message;
+    use crate::MessageFull;
+
+    fn messages() -> Vec<BytesValue> {
+        (0..100)
+            .map(|i| {
+                let mut m = BytesValue::new();
+                m.value = vec![i as u8; i];
+                m
+            })
+            .collect()
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    #[cfg_attr(miri, ignore)]
+    async fn read_write() {
+        let messages = messages();
+
+        let mut writer = AsyncMessageStreamWriter::new(Vec::new());
+        for m in &messages {
+            writer.write(m).await.unwrap();
+        }
+        writer.flush().await.unwrap();
+        let bytes = writer.into_inner();
+
+        let mut expected = Vec::new();
+        for m in &messages {
+            m.write_length_delimited_to_vec(&mut expected).unwrap();
+        }
+        assert_eq!(expected, bytes);
+
+        let mut reader = AsyncMessageStreamReader::<_, BytesValue>::new(&bytes[..]);
+        for m in &messages {
+            assert_eq!(m, &reader.read().await.unwrap().unwrap());
+        }
+        assert!(reader.read().await.unwrap().is_none());
+
+        // Truncated last message.
+        let mut reader = AsyncMessageStreamReader::<_, BytesValue>::new(&bytes[..bytes.len() - 1]);
+        for m in &messages[..messages.len() - 1] {
+            assert_eq!(m, &reader.read().await.unwrap().unwrap());
+        }
+        assert!(reader.read().await.is_err());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    #[cfg_attr(miri, ignore)]
+    async fn read_write_dynamic() {
+        let messages = messages();
+        let descriptor = BytesValue::descriptor_static();
+
+        let mut writer = AsyncDynamicMessageStreamWriter::new(descriptor.clone(), Vec::new());
+        for m in &messages {
+            writer.write(m).await.unwrap();
+        }
+        let bytes = writer.into_inner();
+
+        let mut reader = AsyncDynamicMessageStreamReader::new(descriptor.clone(), &bytes[..]);
+        for m in &messages {
+            let read = reader.read().await.unwrap().unwrap();
+            assert!(descriptor.eq(m, &*read));
+        }
+        assert!(reader.read().await.unwrap().is_none());
+    }
+}
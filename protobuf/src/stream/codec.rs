@@ -0,0 +1,336 @@
+use std::marker::PhantomData;
+
+use bytes::Buf;
+use bytes::Bytes;
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+use tokio_util::codec::Encoder;
+
+use crate::error::WireError;
+use crate::reflect::MessageDescriptor;
+use crate::rt::compute_raw_varint32_size;
+use crate::varint::decode_varint64;
+use crate::wire_format::check_message_size;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
+use crate::Message;
+use crate::MessageDyn;
+use crate::ParseOptions;
+
+/// Do not reserve more than this for an incomplete frame: frame length
+/// comes from the peer and the frame may never arrive.
+const MAX_RESERVE: usize = 64 * 1024;
+
+/// Find next frame in the buffer.
+///
+/// Return message bytes without length prefix, or `None` if more data is needed.
+fn decode_frame(src: &mut BytesMut, options: &ParseOptions) -> crate::Result<Option<Bytes>> {
+    let (len, len_len) = match decode_varint64(src)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+    if let Some(max_total_bytes) = options.max_total_bytes {
+        if len > max_total_bytes {
+            return Err(WireError::DecodeLimitExceeded("max_total_bytes", max_total_bytes).into());
+        }
+    }
+    let len = check_message_size(len)? as usize;
+    let frame_len = len_len + len;
+    if src.len() < frame_len {
+        src.reserve((frame_len - src.len()).min(MAX_RESERVE));
+        return Ok(None);
+    }
+    let mut frame = src.split_to(frame_len).freeze();
+    frame.advance(len_len);
+    Ok(Some(frame))
+}
+
+/// Error when stream ends with incomplete frame.
+fn check_decode_eof(src: &BytesMut) -> crate::Result<()> {
+    if src.is_empty() {
+        Ok(())
+    } else {
+        Err(WireError::TruncatedMessage.into())
+    }
+}
+
+/// Parse message from frame, `Bytes` and `Chars` fields share the frame buffer.
+fn parse_frame<M>(
+    frame: &Bytes,
+    options: &ParseOptions,
+    parse: impl FnOnce(&mut CodedInputStream) -> crate::Result<M>,
+) -> crate::Result<M> {
+    let mut is = CodedInputStream::from_tokio_bytes(frame);
+    is.set_parse_options(options);
+    let message = parse(&mut is)?;
+    is.check_eof()?;
+    Ok(message)
+}
+
+/// Write length-delimited message to the buffer.
+fn encode_frame(
+    dst: &mut BytesMut,
+    deterministic: bool,
+    compute_size: impl FnOnce() -> u64,
+    write: impl FnOnce(&mut CodedOutputStream) -> crate::Result<()>,
+) -> crate::Result<()> {
+    let size = check_message_size(compute_size())?;
+    let start = dst.len();
+    let frame_len = compute_raw_varint32_size(size) as usize + size as usize;
+    dst.resize(start + frame_len, 0);
+    let mut os = CodedOutputStream::bytes(&mut dst[start..]);
+    os.set_deterministic(deterministic);
+    os.write_raw_varint32(size)?;
+    write(&mut os)?;
+    os.check_eof();
+    Ok(())
+}
+
+/// [`Decoder`] and [`Encoder`] of length-delimited messages of type `M`.
+///
+/// Frame format is the same as in [`MessageStreamReader`](crate::stream::MessageStreamReader).
+/// Decoded `bytes` and `string` fields stored as `Bytes` and `Chars`
+/// share the read buffer.
+pub struct MessageCodec<M: Message> {
+    parse_options: ParseOptions,
+    deterministic: bool,
+    phantom: PhantomData<fn(M) -> M>,
+}
+
+impl<M: Message> MessageCodec<M> {
+    /// Create a codec.
+    pub fn new() -> MessageCodec<M> {
+        MessageCodec {
+            parse_options: ParseOptions::default(),
+            deterministic: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Apply decode limits to decoded messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.parse_options = options.clone();
+    }
+
+    /// Enable or disable deterministic serialization of encoded messages.
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+}
+
+impl<M: Message> Default for MessageCodec<M> {
+    fn default() -> Self {
+        MessageCodec::new()
+    }
+}
+
+impl<M: Message> Decoder for MessageCodec<M> {
+    type Item = M;
+    type Error = crate::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> crate::Result<Option<M>> {
+        let frame = match decode_frame(src, &self.parse_options)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        parse_frame(&frame, &self.parse_options, |is| M::parse_from(is)).map(Some)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> crate::Result<Option<M>> {
+        match self.decode(src)? {
+            Some(message) => Ok(Some(message)),
+            None => check_decode_eof(src).map(|()| None),
+        }
+    }
+}
+
+impl<'a, M: Message> Encoder<&'a M> for MessageCodec<M> {
+    type Error = crate::Error;
+
+    fn encode(&mut self, message: &'a M, dst: &mut BytesMut) -> crate::Result<()> {
+        message.check_initialized()?;
+        encode_frame(
+            dst,
+            self.deterministic,
+            || message.compute_size(),
+            |os| message.write_to_with_cached_sizes(os),
+        )
+    }
+}
+
+impl<M: Message> Encoder<M> for MessageCodec<M> {
+    type Error = crate::Error;
+
+    fn encode(&mut self, message: M, dst: &mut BytesMut) -> crate::Result<()> {
+        self.encode(&message, dst)
+    }
+}
+
+/// [`Decoder`] and [`Encoder`] of length-delimited messages
+/// of type specified by [`MessageDescriptor`].
+///
+/// Same as [`MessageCodec`], but for dynamic messages.
+pub struct DynamicMessageCodec {
+    descriptor: MessageDescriptor,
+    parse_options: ParseOptions,
+    deterministic: bool,
+}
+
+impl DynamicMessageCodec {
+    /// Create a codec for given message type.
+    pub fn new(descriptor: MessageDescriptor) -> DynamicMessageCodec {
+        DynamicMessageCodec {
+            descriptor,
+            parse_options: ParseOptions::default(),
+            deterministic: false,
+        }
+    }
+
+    /// Apply decode limits to decoded messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.parse_options = options.clone();
+    }
+
+    /// Enable or disable deterministic serialization of encoded messages.
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+}
+
+impl Decoder for DynamicMessageCodec {
+    type Item = Box<dyn MessageDyn>;
+    type Error = crate::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> crate::Result<Option<Box<dyn MessageDyn>>> {
+        let frame = match decode_frame(src, &self.parse_options)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        let descriptor = &self.descriptor;
+        parse_frame(&frame, &self.parse_options, |is| descriptor.parse_from(is)).map(Some)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> crate::Result<Option<Box<dyn MessageDyn>>> {
+        match self.decode(src)? {
+            Some(message) => Ok(Some(message)),
+            None => check_decode_eof(src).map(|()| None),
+        }
+    }
+}
+
+impl<'a> Encoder<&'a dyn MessageDyn> for DynamicMessageCodec {
+    type Error = crate::Error;
+
+    /// # Panics
+    ///
+    /// If message type is different from the codec message type.
+    fn encode(&mut self, message: &'a dyn MessageDyn, dst: &mut BytesMut) -> crate::Result<()> {
+        assert_eq!(
+            self.descriptor,
+            message.descriptor_dyn(),
+            "message type does not match codec message type"
+        );
+        message.check_initialized_dyn()?;
+        encode_frame(
+            dst,
+            self.deterministic,
+            || message.compute_size_dyn(),
+            |os| message.write_to_with_cached_sizes_dyn(os),
+        )
+    }
+}
+
+impl Encoder<Box<dyn MessageDyn>> for DynamicMessageCodec {
+    type Error = crate::Error;
+
+    fn encode(&mut self, message: Box<dyn MessageDyn>, dst: &mut BytesMut) -> crate::Result<()> {
+        self.encode(&*message, dst)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder;
+    use tokio_util::codec::Encoder;
+
+    use super::MessageCodec;
+    use crate::well_known_types::BytesValue;
+    use crate::ParseOptions;
+
+    fn messages() -> Vec<BytesValue> {
+        (0..100)
+            .map(|i| {
+                let mut m = BytesValue::new();
+                m.value = vec![i as u8; i];
+                m
+            })
+            .collect()
+    }
+
+    #[test]
+    fn codec() {
+        let messages = messages();
+        let mut codec = MessageCodec::<BytesValue>::new();
+        let mut buf = BytesMut::new();
+        for m in &messages {
+            codec.encode(m, &mut buf).unwrap();
+        }
+
+        // Feed bytes one by one.
+        let mut src = BytesMut::new();
+        let mut decoded = Vec::new();
+        for b in buf.iter() {
+            src.extend_from_slice(&[*b]);
+            while let Some(m) = codec.decode(&mut src).unwrap() {
+                decoded.push(m);
+            }
+        }
+        assert_eq!(messages, decoded);
+        assert!(codec.decode_eof(&mut src).unwrap().is_none());
+
+        src.extend_from_slice(&[3, 10]);
+        assert!(codec.decode(&mut src).unwrap().is_none());
+        assert!(codec.decode_eof(&mut src).is_err());
+    }
+
+    #[test]
+    fn codec_limits() {
+        let mut m = BytesValue::new();
+        m.value = vec![1; 100];
+        let mut buf = BytesMut::new();
+        MessageCodec::new().encode(&m, &mut buf).unwrap();
+
+        let mut codec = MessageCodec::<BytesValue>::new();
+        codec.set_parse_options(&ParseOptions {
+            max_total_bytes: Some(50),
+            ..Default::default()
+        });
+        // Frame length is checked before the frame is buffered.
+        let err = codec.decode(&mut buf.clone().split_to(2)).unwrap_err();
+        assert!(err.to_string().contains("max_total_bytes"), "{}", err);
+
+        codec.set_parse_options(&ParseOptions {
+            max_bytes_len: Some(50),
+            ..Default::default()
+        });
+        assert!(codec.decode(&mut buf).is_err());
+    }
+
+    #[test]
+    fn codec_large_prefix_does_not_allocate_frame() {
+        let mut codec = MessageCodec::<BytesValue>::new();
+        // Length prefix of 1 GiB without the message.
+        let mut src = BytesMut::from(&[0x80, 0x80, 0x80, 0x80, 0x04][..]);
+        assert!(codec.decode(&mut src).unwrap().is_none());
+        assert!(
+            src.capacity() <= super::MAX_RESERVE + 5,
+            "{}",
+            src.capacity()
+        );
+    }
+}
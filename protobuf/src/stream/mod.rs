@@ -0,0 +1,333 @@
+//! Streams of length-delimited messages.
+//!
+//! Each message is prefixed with its length encoded as varint,
+//! which is the format of [`Message::write_length_delimited_to`]
+//! and `writeDelimitedTo`/`parseDelimitedFrom` of protobuf-java.
+//!
+//! ```
+//! # use protobuf::stream::MessageStreamReader;
+//! # use protobuf::stream::MessageStreamWriter;
+//! # use protobuf::well_known_types::Duration;
+//! let mut bytes = Vec::new();
+//! let mut writer = MessageStreamWriter::new(&mut bytes);
+//! for seconds in 0..3 {
+//!     let mut d = Duration::new();
+//!     d.seconds = seconds;
+//!     writer.write(&d).unwrap();
+//! }
+//! writer.flush().unwrap();
+//! drop(writer);
+//!
+//! let mut read = &bytes[..];
+//! let reader = MessageStreamReader::<Duration>::new(&mut read);
+//! let seconds: Vec<i64> = reader.map(|d| d.unwrap().seconds).collect();
+//! assert_eq!(vec![0, 1, 2], seconds);
+//! ```
+//!
+//! [`MessageDecoder`] decodes messages from input chunks pushed by the caller.
+//!
+//! With `with-tokio` feature, async readers and writers over
+//! `tokio::io::AsyncRead`/`AsyncWrite` and `tokio_util::codec` codecs are available.
+
+use std::io::BufRead;
+use std::io::Read;
+use std::io::Write;
+use std::marker::PhantomData;
+
+use crate::error::WireError;
+use crate::reflect::MessageDescriptor;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
+use crate::Message;
+use crate::MessageDyn;
+use crate::ParseOptions;
+
+#[cfg(feature = "with-tokio")]
+mod async_io;
+#[cfg(feature = "with-tokio")]
+mod codec;
+mod push;
+
+#[cfg(feature = "with-tokio")]
+pub use self::async_io::AsyncDynamicMessageStreamReader;
+#[cfg(feature = "with-tokio")]
+pub use self::async_io::AsyncDynamicMessageStreamWriter;
+#[cfg(feature = "with-tokio")]
+pub use self::async_io::AsyncMessageStreamReader;
+#[cfg(feature = "with-tokio")]
+pub use self::async_io::AsyncMessageStreamWriter;
+#[cfg(feature = "with-tokio")]
+pub use self::codec::DynamicMessageCodec;
+#[cfg(feature = "with-tokio")]
+pub use self::codec::MessageCodec;
+pub use self::push::DecodeStatus;
+pub use self::push::DynamicMessageDecoder;
+pub use self::push::MessageDecoder;
+
+/// Read next length-delimited message.
+///
+/// Return `None` on EOF before the message.
+fn read_next<M>(
+    is: &mut CodedInputStream,
+    new: impl FnOnce() -> M,
+    merge: impl FnOnce(&mut M, &mut CodedInputStream) -> crate::Result<()>,
+) -> crate::Result<Option<M>> {
+    if is.eof()? {
+        return Ok(None);
+    }
+    let len = is.read_raw_varint64()?;
+    let old_limit = is.push_limit(len)?;
+    let mut message = new();
+    merge(&mut message, is)?;
+    // Message parsing stops at limit or at the end of input.
+    if is.bytes_until_limit() != 0 {
+        return Err(WireError::TruncatedMessage.into());
+    }
+    is.pop_limit(old_limit);
+    Ok(Some(message))
+}
+
+/// Iterator over length-delimited messages of type `M`.
+///
+/// Iteration stops when input ends between messages,
+/// or after the first error, e. g. when the last message is truncated.
+pub struct MessageStreamReader<'a, M: Message> {
+    is: CodedInputStream<'a>,
+    done: bool,
+    phantom: PhantomData<fn() -> M>,
+}
+
+impl<'a, M: Message> MessageStreamReader<'a, M> {
+    /// Read messages from `Read`.
+    ///
+    /// Note input is buffered, so more bytes than messages occupy may be consumed.
+    pub fn new(read: &'a mut dyn Read) -> MessageStreamReader<'a, M> {
+        MessageStreamReader::from_coded_input_stream(CodedInputStream::new(read))
+    }
+
+    /// Read messages from `BufRead`, utilizing its buffer.
+    pub fn from_buffered_reader(buf_read: &'a mut dyn BufRead) -> MessageStreamReader<'a, M> {
+        MessageStreamReader::from_coded_input_stream(CodedInputStream::from_buffered_reader(
+            buf_read,
+        ))
+    }
+
+    /// Read messages from `CodedInputStream`.
+    pub fn from_coded_input_stream(is: CodedInputStream<'a>) -> MessageStreamReader<'a, M> {
+        MessageStreamReader {
+            is,
+            done: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to the whole stream.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.is.set_parse_options(options);
+    }
+
+    /// Read next message, return `None` on clean EOF.
+    pub fn read(&mut self) -> crate::Result<Option<M>> {
+        let message = read_next(&mut self.is, M::new, |m, is| m.merge_from(is))?;
+        if let Some(message) = &message {
+            message.check_initialized()?;
+        }
+        Ok(message)
+    }
+}
+
+impl<'a, M: Message> Iterator for MessageStreamReader<'a, M> {
+    type Item = crate::Result<M>;
+
+    fn next(&mut self) -> Option<crate::Result<M>> {
+        if self.done {
+            return None;
+        }
+        let r = self.read().transpose();
+        if !matches!(r, Some(Ok(_))) {
+            self.done = true;
+        }
+        r
+    }
+}
+
+/// Iterator over length-delimited messages of type specified by [`MessageDescriptor`].
+///
+/// Same as [`MessageStreamReader`], but for dynamic messages.
+pub struct DynamicMessageStreamReader<'a> {
+    descriptor: MessageDescriptor,
+    is: CodedInputStream<'a>,
+    done: bool,
+}
+
+impl<'a> DynamicMessageStreamReader<'a> {
+    /// Read messages from `Read`.
+    pub fn new(
+        descriptor: MessageDescriptor,
+        read: &'a mut dyn Read,
+    ) -> DynamicMessageStreamReader<'a> {
+        DynamicMessageStreamReader::from_coded_input_stream(descriptor, CodedInputStream::new(read))
+    }
+
+    /// Read messages from `BufRead`, utilizing its buffer.
+    pub fn from_buffered_reader(
+        descriptor: MessageDescriptor,
+        buf_read: &'a mut dyn BufRead,
+    ) -> DynamicMessageStreamReader<'a> {
+        DynamicMessageStreamReader::from_coded_input_stream(
+            descriptor,
+            CodedInputStream::from_buffered_reader(buf_read),
+        )
+    }
+
+    /// Read messages from `CodedInputStream`.
+    pub fn from_coded_input_stream(
+        descriptor: MessageDescriptor,
+        is: CodedInputStream<'a>,
+    ) -> DynamicMessageStreamReader<'a> {
+        DynamicMessageStreamReader {
+            descriptor,
+            is,
+            done: false,
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to the whole stream.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.is.set_parse_options(options);
+    }
+
+    /// Read next message, return `None` on clean EOF.
+    pub fn read(&mut self) -> crate::Result<Option<Box<dyn MessageDyn>>> {
+        let descriptor = &self.descriptor;
+        let message = read_next(
+            &mut self.is,
+            || descriptor.new_instance(),
+            |m, is| m.merge_from_dyn(is),
+        )?;
+        if let Some(message) = &message {
+            message.check_initialized_dyn()?;
+        }
+        Ok(message)
+    }
+}
+
+impl<'a> Iterator for DynamicMessageStreamReader<'a> {
+    type Item = crate::Result<Box<dyn MessageDyn>>;
+
+    fn next(&mut self) -> Option<crate::Result<Box<dyn MessageDyn>>> {
+        if self.done {
+            return None;
+        }
+        let r = self.read().transpose();
+        if !matches!(r, Some(Ok(_))) {
+            self.done = true;
+        }
+        r
+    }
+}
+
+/// Write messages of type `M` prefixed with their length.
+///
+/// Output is buffered, call [`flush`](Self::flush) after the last message.
+pub struct MessageStreamWriter<'a, M: Message> {
+    os: CodedOutputStream<'a>,
+    phantom: PhantomData<fn(&M)>,
+}
+
+impl<'a, M: Message> MessageStreamWriter<'a, M> {
+    /// Write messages to `Write`.
+    pub fn new(write: &'a mut dyn Write) -> MessageStreamWriter<'a, M> {
+        MessageStreamWriter::from_coded_output_stream(CodedOutputStream::new(write))
+    }
+
+    /// Write messages to `CodedOutputStream`.
+    pub fn from_coded_output_stream(os: CodedOutputStream<'a>) -> MessageStreamWriter<'a, M> {
+        MessageStreamWriter {
+            os,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Enable or disable deterministic serialization.
+    ///
+    /// See [`CodedOutputStream::set_deterministic`].
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.os.set_deterministic(deterministic);
+    }
+
+    /// Write a message.
+    ///
+    /// Results in error if message is not fully initialized.
+    pub fn write(&mut self, message: &M) -> crate::Result<()> {
+        message.check_initialized()?;
+        message.write_length_delimited_to(&mut self.os)
+    }
+
+    /// Flush the buffer to the underlying `Write`.
+    pub fn flush(&mut self) -> crate::Result<()> {
+        self.os.flush()
+    }
+}
+
+/// Write messages of type specified by [`MessageDescriptor`] prefixed with their length.
+///
+/// Same as [`MessageStreamWriter`], but for dynamic messages.
+pub struct DynamicMessageStreamWriter<'a> {
+    descriptor: MessageDescriptor,
+    os: CodedOutputStream<'a>,
+}
+
+impl<'a> DynamicMessageStreamWriter<'a> {
+    /// Write messages to `Write`.
+    pub fn new(
+        descriptor: MessageDescriptor,
+        write: &'a mut dyn Write,
+    ) -> DynamicMessageStreamWriter<'a> {
+        DynamicMessageStreamWriter::from_coded_output_stream(
+            descriptor,
+            CodedOutputStream::new(write),
+        )
+    }
+
+    /// Write messages to `CodedOutputStream`.
+    pub fn from_coded_output_stream(
+        descriptor: MessageDescriptor,
+        os: CodedOutputStream<'a>,
+    ) -> DynamicMessageStreamWriter<'a> {
+        DynamicMessageStreamWriter { descriptor, os }
+    }
+
+    /// Enable or disable deterministic serialization.
+    ///
+    /// See [`CodedOutputStream::set_deterministic`].
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.os.set_deterministic(deterministic);
+    }
+
+    /// Write a message.
+    ///
+    /// Results in error if message is not fully initialized.
+    ///
+    /// # Panics
+    ///
+    /// If message type is different from the stream message type.
+    pub fn write(&mut self, message: &dyn MessageDyn) -> crate::Result<()> {
+        assert_eq!(
+            self.descriptor,
+            message.descriptor_dyn(),
+            "message type does not match stream message type"
+        );
+        message.check_initialized_dyn()?;
+        message.write_length_delimited_to_dyn(&mut self.os)
+    }
+
+    /// Flush the buffer to the underlying `Write`.
+    pub fn flush(&mut self) -> crate::Result<()> {
+        self.os.flush()
+    }
+}
@@ -0,0 +1,437 @@
+use std::cmp;
+use std::marker::PhantomData;
+
+use crate::error::WireError;
+use crate::reflect::MessageDescriptor;
+use crate::wire_format::check_message_size;
+use crate::wire_format::Tag;
+use crate::wire_format::WireType;
+use crate::CodedInputStream;
+use crate::Message;
+use crate::MessageDyn;
+use crate::ParseOptions;
+
+/// Result of feeding input to [`MessageDecoder`] or [`DynamicMessageDecoder`].
+#[derive(Debug)]
+pub enum DecodeStatus<M> {
+    /// Input is consumed, more input is needed to complete the message.
+    NeedMore,
+    /// Message is complete.
+    Message(M),
+}
+
+/// Varint decoded byte by byte.
+#[derive(Default)]
+struct PartialVarint {
+    value: u64,
+    len: u32,
+}
+
+impl PartialVarint {
+    /// Consume a byte, return the value if varint is complete.
+    fn push(&mut self, b: u8) -> crate::Result<Option<u64>> {
+        if self.len == 10 || self.len == 9 && (b & 0x7f) > 1 {
+            return Err(WireError::IncorrectVarint.into());
+        }
+        self.value |= ((b & 0x7f) as u64) << (self.len * 7);
+        self.len += 1;
+        if b < 0x80 {
+            let value = self.value;
+            *self = PartialVarint::default();
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+enum ScanState {
+    /// Reading field tag.
+    Tag,
+    /// Reading varint field value.
+    Varint,
+    /// Reading length of length-delimited field.
+    Len,
+    /// Skipping fixed-size field value or length-delimited field content.
+    Skip(u64),
+}
+
+/// Validates wire structure of message fields as input arrives.
+///
+/// Content of length-delimited fields is not inspected.
+struct WireScanner {
+    state: ScanState,
+    varint: PartialVarint,
+    /// Field numbers of open groups.
+    groups: Vec<u32>,
+    recursion_limit: u32,
+}
+
+impl WireScanner {
+    fn new(recursion_limit: u32) -> WireScanner {
+        WireScanner {
+            state: ScanState::Tag,
+            varint: PartialVarint::default(),
+            groups: Vec::new(),
+            recursion_limit,
+        }
+    }
+
+    fn scan(&mut self, mut input: &[u8]) -> crate::Result<()> {
+        while let Some((&b, rem)) = input.split_first() {
+            if let ScanState::Skip(n) = self.state {
+                let skip = cmp::min(n, input.len() as u64);
+                input = &input[skip as usize..];
+                self.state = match n - skip {
+                    0 => ScanState::Tag,
+                    n => ScanState::Skip(n),
+                };
+                continue;
+            }
+            input = rem;
+            if let Some(value) = self.varint.push(b)? {
+                self.state = self.next_state(value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn next_state(&mut self, value: u64) -> crate::Result<ScanState> {
+        Ok(match self.state {
+            ScanState::Tag => {
+                let (field_number, wire_type) = Tag::new(value as u32)?.unpack();
+                match wire_type {
+                    WireType::Varint => ScanState::Varint,
+                    WireType::Fixed64 => ScanState::Skip(8),
+                    WireType::Fixed32 => ScanState::Skip(4),
+                    WireType::LengthDelimited => ScanState::Len,
+                    WireType::StartGroup => {
+                        if self.groups.len() as u32 >= self.recursion_limit {
+                            return Err(WireError::OverRecursionLimit.into());
+                        }
+                        self.groups.push(field_number);
+                        ScanState::Tag
+                    }
+                    WireType::EndGroup => {
+                        if self.groups.pop() != Some(field_number) {
+                            return Err(WireError::UnexpectedWireType(WireType::EndGroup).into());
+                        }
+                        ScanState::Tag
+                    }
+                }
+            }
+            ScanState::Varint => ScanState::Tag,
+            ScanState::Len => match check_message_size(value)? {
+                0 => ScanState::Tag,
+                len => ScanState::Skip(len as u64),
+            },
+            ScanState::Skip(..) => unreachable!(),
+        })
+    }
+
+    /// Check input can end here.
+    fn check_end(&self) -> crate::Result<()> {
+        match self.state {
+            ScanState::Tag if self.varint.is_empty() && self.groups.is_empty() => Ok(()),
+            _ => Err(WireError::TruncatedMessage.into()),
+        }
+    }
+}
+
+enum Frame {
+    /// Message ends with the input.
+    Unbounded,
+    /// Reading length prefix of the next message.
+    Prefix(PartialVarint),
+    /// Reading message content, number of remaining bytes.
+    Body(u64),
+}
+
+/// Part of decoder independent of message type.
+struct PushDecoder {
+    length_delimited: bool,
+    frame: Frame,
+    scanner: WireScanner,
+    buf: Vec<u8>,
+    parse_options: ParseOptions,
+}
+
+impl PushDecoder {
+    fn new(length_delimited: bool) -> PushDecoder {
+        let parse_options = ParseOptions::default();
+        PushDecoder {
+            length_delimited,
+            frame: PushDecoder::initial_frame(length_delimited),
+            scanner: WireScanner::new(parse_options.recursion_limit),
+            buf: Vec::new(),
+            parse_options,
+        }
+    }
+
+    fn initial_frame(length_delimited: bool) -> Frame {
+        if length_delimited {
+            Frame::Prefix(PartialVarint::default())
+        } else {
+            Frame::Unbounded
+        }
+    }
+
+    fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.parse_options = options.clone();
+        self.scanner.recursion_limit = options.recursion_limit;
+    }
+
+    fn reset(&mut self) {
+        self.frame = PushDecoder::initial_frame(self.length_delimited);
+        self.scanner = WireScanner::new(self.parse_options.recursion_limit);
+        self.buf.clear();
+    }
+
+    /// Consume input, return `true` when message bytes are complete.
+    fn feed(&mut self, input: &mut &[u8]) -> crate::Result<bool> {
+        let r = self.feed_impl(input);
+        if r.is_err() {
+            self.reset();
+        }
+        r
+    }
+
+    fn feed_impl(&mut self, input: &mut &[u8]) -> crate::Result<bool> {
+        if let Frame::Prefix(varint) = &mut self.frame {
+            let len = loop {
+                let (&b, rem) = match input.split_first() {
+                    Some(r) => r,
+                    None => return Ok(false),
+                };
+                *input = rem;
+                if let Some(len) = varint.push(b)? {
+                    break len;
+                }
+            };
+            self.check_total_bytes(len)?;
+            self.frame = Frame::Body(check_message_size(len)? as u64);
+        }
+
+        let len = match self.frame {
+            Frame::Unbounded => input.len(),
+            Frame::Body(remaining) => cmp::min(remaining, input.len() as u64) as usize,
+            Frame::Prefix(..) => unreachable!(),
+        };
+        let (chunk, rem) = input.split_at(len);
+        *input = rem;
+        self.check_total_bytes(self.buf.len() as u64 + len as u64)?;
+        self.scanner.scan(chunk)?;
+        self.buf.extend_from_slice(chunk);
+
+        match &mut self.frame {
+            Frame::Body(remaining) => {
+                *remaining -= len as u64;
+                if *remaining != 0 {
+                    return Ok(false);
+                }
+                self.scanner.check_end()?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn check_total_bytes(&self, len: u64) -> crate::Result<()> {
+        match self.parse_options.max_total_bytes {
+            Some(max_total_bytes) if len > max_total_bytes => {
+                Err(WireError::DecodeLimitExceeded("max_total_bytes", max_total_bytes).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// End of input, return `true` if there is a complete message.
+    fn finish(&mut self) -> crate::Result<bool> {
+        let r = match &self.frame {
+            Frame::Unbounded => self.scanner.check_end().map(|()| true),
+            Frame::Prefix(varint) if varint.is_empty() => Ok(false),
+            _ => Err(WireError::TruncatedMessage.into()),
+        };
+        if r.is_err() {
+            self.reset();
+        }
+        r
+    }
+
+    /// Parse complete message and reset for the next message.
+    fn parse<M>(
+        &mut self,
+        parse: impl FnOnce(&mut CodedInputStream) -> crate::Result<M>,
+    ) -> crate::Result<M> {
+        let r = {
+            let mut is = CodedInputStream::from_bytes(&self.buf);
+            is.set_parse_options(&self.parse_options);
+            parse(&mut is).and_then(|m| is.check_eof().map(|()| m))
+        };
+        self.reset();
+        r
+    }
+}
+
+/// Push-style decoder of messages of type `M`.
+///
+/// Input is fed in chunks of any size as it arrives, for example
+/// from event-driven network code, and decoder reports when a message is complete.
+/// Wire structure (tags, varints, lengths and groups) is validated while
+/// input arrives, so malformed input is reported early; field values are
+/// decoded once the message is complete.
+///
+/// Bytes of the current message are kept by the decoder,
+/// so callers do not need to buffer input.
+///
+/// Decoder is reset after a complete message or an error.
+/// After an error position in a length-delimited stream is lost.
+///
+/// ```
+/// # use protobuf::stream::DecodeStatus;
+/// # use protobuf::stream::MessageDecoder;
+/// # use protobuf::well_known_types::Duration;
+/// # use protobuf::Message;
+/// let mut d = Duration::new();
+/// d.seconds = 1000;
+/// let bytes = d.write_length_delimited_to_bytes().unwrap();
+///
+/// let mut decoder = MessageDecoder::<Duration>::length_delimited();
+/// let mut decoded = Vec::new();
+/// for chunk in bytes.chunks(2) {
+///     let mut chunk = chunk;
+///     while !chunk.is_empty() {
+///         if let DecodeStatus::Message(m) = decoder.feed(&mut chunk).unwrap() {
+///             decoded.push(m);
+///         }
+///     }
+/// }
+/// assert!(decoder.finish().unwrap().is_none());
+/// assert_eq!(vec![d], decoded);
+/// ```
+pub struct MessageDecoder<M: Message> {
+    decoder: PushDecoder,
+    phantom: PhantomData<fn() -> M>,
+}
+
+impl<M: Message> MessageDecoder<M> {
+    /// Decoder of a single message which ends with the input.
+    ///
+    /// Message is returned by [`finish`](Self::finish).
+    pub fn new() -> MessageDecoder<M> {
+        MessageDecoder {
+            decoder: PushDecoder::new(false),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Decoder of a sequence of length-delimited messages.
+    ///
+    /// Format is the same as in [`MessageStreamReader`](crate::stream::MessageStreamReader).
+    pub fn length_delimited() -> MessageDecoder<M> {
+        MessageDecoder {
+            decoder: PushDecoder::new(true),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.decoder.set_parse_options(options);
+    }
+
+    /// Feed a chunk of input.
+    ///
+    /// Consumed bytes are removed from the front of `input`.
+    /// Input is consumed entirely unless a message is complete,
+    /// in which case the remaining input belongs to the next message.
+    pub fn feed(&mut self, input: &mut &[u8]) -> crate::Result<DecodeStatus<M>> {
+        if !self.decoder.feed(input)? {
+            return Ok(DecodeStatus::NeedMore);
+        }
+        self.decoder
+            .parse(|is| M::parse_from(is))
+            .map(DecodeStatus::Message)
+    }
+
+    /// Signal the end of input.
+    ///
+    /// Return the message for decoder created with [`new`](Self::new).
+    /// Length-delimited decoder returns `None` if input ended between messages,
+    /// and an error if input ended in the middle of a message.
+    pub fn finish(&mut self) -> crate::Result<Option<M>> {
+        if !self.decoder.finish()? {
+            return Ok(None);
+        }
+        self.decoder.parse(|is| M::parse_from(is)).map(Some)
+    }
+}
+
+impl<M: Message> Default for MessageDecoder<M> {
+    fn default() -> Self {
+        MessageDecoder::new()
+    }
+}
+
+/// Push-style decoder of messages of type specified by [`MessageDescriptor`].
+///
+/// Same as [`MessageDecoder`], but for dynamic messages.
+pub struct DynamicMessageDecoder {
+    descriptor: MessageDescriptor,
+    decoder: PushDecoder,
+}
+
+impl DynamicMessageDecoder {
+    /// Decoder of a single message which ends with the input.
+    pub fn new(descriptor: MessageDescriptor) -> DynamicMessageDecoder {
+        DynamicMessageDecoder {
+            descriptor,
+            decoder: PushDecoder::new(false),
+        }
+    }
+
+    /// Decoder of a sequence of length-delimited messages.
+    pub fn length_delimited(descriptor: MessageDescriptor) -> DynamicMessageDecoder {
+        DynamicMessageDecoder {
+            descriptor,
+            decoder: PushDecoder::new(true),
+        }
+    }
+
+    /// Apply decode limits to messages.
+    ///
+    /// Total bytes limit applies to each message.
+    pub fn set_parse_options(&mut self, options: &ParseOptions) {
+        self.decoder.set_parse_options(options);
+    }
+
+    /// Feed a chunk of input.
+    ///
+    /// See [`MessageDecoder::feed`].
+    pub fn feed(&mut self, input: &mut &[u8]) -> crate::Result<DecodeStatus<Box<dyn MessageDyn>>> {
+        if !self.decoder.feed(input)? {
+            return Ok(DecodeStatus::NeedMore);
+        }
+        let descriptor = &self.descriptor;
+        self.decoder
+            .parse(|is| descriptor.parse_from(is))
+            .map(DecodeStatus::Message)
+    }
+
+    /// Signal the end of input.
+    ///
+    /// See [`MessageDecoder::finish`].
+    pub fn finish(&mut self) -> crate::Result<Option<Box<dyn MessageDyn>>> {
+        if !self.decoder.finish()? {
+            return Ok(None);
+        }
+        let descriptor = &self.descriptor;
+        self.decoder.parse(|is| descriptor.parse_from(is)).map(Some)
+    }
+}
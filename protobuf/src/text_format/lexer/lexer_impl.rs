@@ -89,6 +89,11 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Current byte offset in the input
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
     /// No more chars
     pub fn eof(&self) -> bool {
         self.pos == self.input.len()
@@ -518,14 +523,20 @@ impl<'a> Lexer<'a> {
         let mut first = true;
         loop {
             if !first {
-                self.skip_ws()?;
+                // Do not consume whitespace after the last literal,
+                // so the token ends where the literal ends
+                let mut clone = *self;
+                clone.skip_ws()?;
+                if !clone.lookahead_char_is_in("'\"") {
+                    break;
+                }
+                *self = clone;
             }
 
             let start = self.pos;
 
             let q = match self.next_char_if_in("'\"") {
                 Some(q) => q,
-                None if !first => break,
                 None => return Err(LexerError::IncorrectInput),
             };
             first = false;
@@ -650,15 +661,22 @@ impl<'a> Lexer<'a> {
     pub fn next_token(&mut self) -> LexerResult<Option<TokenWithLocation>> {
         self.skip_ws()?;
         let loc = self.loc;
+        let pos = self.pos;
 
         Ok(if self.eof() {
             None
         } else {
             let token = self.next_token_inner()?;
+            let end_pos = self.pos;
             // Skip whitespace here to update location
             // to the beginning of the next token
             self.skip_ws()?;
-            Some(TokenWithLocation { token, loc })
+            Some(TokenWithLocation {
+                token,
+                loc,
+                pos,
+                end_pos,
+            })
         })
     }
 }
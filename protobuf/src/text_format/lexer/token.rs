@@ -44,4 +44,8 @@ impl Token {
 pub struct TokenWithLocation {
     pub token: Token,
     pub loc: Loc,
+    /// Byte offset of the token start in the input
+    pub pos: usize,
+    /// Byte offset of the token end in the input
+    pub end_pos: usize,
 }
@@ -43,6 +43,7 @@ pub struct Tokenizer<'a> {
     lexer: Lexer<'a>,
     next_token: Option<TokenWithLocation>,
     last_token_loc: Option<Loc>,
+    last_token_end_pos: usize,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -51,6 +52,7 @@ impl<'a> Tokenizer<'a> {
             lexer: Lexer::new(input, comment_style),
             next_token: None,
             last_token_loc: None,
+            last_token_end_pos: 0,
         }
     }
 
@@ -66,11 +68,32 @@ impl<'a> Tokenizer<'a> {
     }
 
     pub fn lookahead_loc(&mut self) -> Loc {
-        drop(self.lookahead());
+        let _ = self.lookahead();
         // TODO: does not handle EOF properly
         self.loc()
     }
 
+    /// Byte offset of the next token start, or input length on EOF.
+    pub fn lookahead_pos(&mut self) -> usize {
+        let _ = self.lookahead();
+        match &self.next_token {
+            Some(token) => token.pos,
+            None => self.lexer.pos(),
+        }
+    }
+
+    /// Byte offset of the end of the last consumed token.
+    pub fn last_token_end_pos(&self) -> usize {
+        self.last_token_end_pos
+    }
+
+    /// Consume the token fetched by lookahead.
+    fn take_next_token(&mut self) -> Option<Token> {
+        let token = self.next_token.take()?;
+        self.last_token_end_pos = token.end_pos;
+        Some(token.token)
+    }
+
     fn lookahead(&mut self) -> TokenizerResult<Option<&Token>> {
         Ok(match self.next_token {
             Some(ref token) => Some(&token.token),
@@ -94,10 +117,7 @@ impl<'a> Tokenizer<'a> {
 
     fn next(&mut self) -> TokenizerResult<Option<Token>> {
         self.lookahead()?;
-        Ok(self
-            .next_token
-            .take()
-            .map(|TokenWithLocation { token, .. }| token))
+        Ok(self.take_next_token())
     }
 
     pub fn next_some(&mut self) -> TokenizerResult<Token> {
@@ -109,10 +129,7 @@ impl<'a> Tokenizer<'a> {
 
     /// Can be called only after lookahead, otherwise it's error
     pub fn advance(&mut self) -> TokenizerResult<Token> {
-        self.next_token
-            .take()
-            .map(|TokenWithLocation { token, .. }| token)
-            .ok_or(TokenizerError::InternalError)
+        self.take_next_token().ok_or(TokenizerError::InternalError)
     }
 
     /// No more tokens
@@ -132,7 +149,7 @@ impl<'a> Tokenizer<'a> {
             },
             _ => return Ok(None),
         };
-        self.next_token = None;
+        self.take_next_token();
         Ok(Some(v))
     }
 
@@ -146,7 +163,7 @@ impl<'a> Tokenizer<'a> {
             Some(ref token) => p(&token.token)?,
             None => return Err(TokenizerError::UnexpectedEof.into()),
         };
-        self.next_token = None;
+        self.take_next_token();
         Ok(r)
     }
 
@@ -18,6 +18,10 @@
 //! This format is not specified, but it is implemented by all official
 //! protobuf implementations, including `protoc` command which can decode
 //! and encode messages using text format.
+//!
+//! Extensions (`[pkg.ext]: value`) and expanded `google.protobuf.Any`
+//! (`[type.googleapis.com/pkg.Msg] { ... }`) are supported when registries
+//! are provided in [`PrintOptions`] and [`ParseOptions`].
 
 mod parse;
 mod print;
@@ -29,13 +33,18 @@ mod print;
 pub mod lexer;
 
 pub use self::parse::merge_from_str;
+pub use self::parse::merge_from_str_with_options;
 pub use self::parse::parse_from_str;
+pub use self::parse::parse_from_str_with_options;
 pub use self::parse::ParseError;
+pub use self::parse::ParseOptions;
 pub use self::print::fmt;
 pub use self::print::print_to;
 pub use self::print::print_to_string;
 pub use self::print::print_to_string_pretty;
+pub use self::print::print_to_string_with_options;
 #[doc(hidden)]
 pub use self::print::quote_bytes_to;
 #[doc(hidden)]
 pub use self::print::quote_escape_bytes;
+pub use self::print::PrintOptions;
@@ -4,16 +4,21 @@ use crate::message_dyn::MessageDyn;
 use crate::message_full::MessageFull;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::EnumValueDescriptor;
+use crate::reflect::ExtensionRegistry;
+use crate::reflect::FieldDescriptor;
 use crate::reflect::MessageDescriptor;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::RuntimeFieldType;
 use crate::reflect::RuntimeTypeBox;
+use crate::reflect::TypeRegistry;
 use crate::text_format::lexer::int;
 use crate::text_format::lexer::Loc;
 use crate::text_format::lexer::ParserLanguage;
 use crate::text_format::lexer::StrLitDecodeError;
+use crate::text_format::lexer::Token;
 use crate::text_format::lexer::Tokenizer;
 use crate::text_format::lexer::TokenizerError;
+use crate::text_format::print::text_format_field_name;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ParseErrorWithoutLoc {
@@ -23,6 +28,10 @@ pub enum ParseErrorWithoutLoc {
     StrLitDecodeError(#[from] StrLitDecodeError),
     #[error("Unknown field: `{}`", .0)]
     UnknownField(String),
+    #[error("Extension not found: `{}`", .0)]
+    ExtensionNotFound(String),
+    #[error("Any type not found: `{}`", .0)]
+    AnyTypeNotFound(String),
     #[error("Unknown enum value: `{}`", .0)]
     UnknownEnumValue(String),
     #[error("Map field specified more than once: `{}`", .0)]
@@ -33,6 +42,8 @@ pub enum ParseErrorWithoutLoc {
     ExpectingBool,
     #[error("Message not initialized")]
     MessageNotInitialized,
+    #[error("Cannot set field `{}`: {}", .0, .1)]
+    CannotSetField(String, crate::Error),
 }
 
 impl From<int::Overflow> for ParseErrorWithoutLoc {
@@ -55,6 +66,7 @@ pub type ParseWithLocResult<A> = Result<A, ParseError>;
 #[derive(Clone)]
 struct Parser<'a> {
     tokenizer: Tokenizer<'a>,
+    parse_options: &'a ParseOptions,
 }
 
 impl<'a> Parser<'a> {
@@ -64,6 +76,20 @@ impl<'a> Parser<'a> {
         Ok(self.tokenizer.next_ident()?)
     }
 
+    /// Read extension name or `Any` type URL after `[`.
+    fn next_bracketed_name(&mut self) -> ParseResult<String> {
+        let mut name = String::new();
+        loop {
+            match self.tokenizer.next_some()? {
+                Token::Symbol(']') => break,
+                Token::Ident(ident) => name.push_str(&ident),
+                Token::Symbol(c) if c == '.' || c == '/' => name.push(c),
+                _ => return Err(ParseErrorWithoutLoc::UnknownField(name)),
+            }
+        }
+        Ok(name)
+    }
+
     fn read_colon(&mut self, desc: &'static str) -> ParseResult<()> {
         Ok(self.tokenizer.next_symbol_expect_eq(':', desc)?)
     }
@@ -246,9 +272,23 @@ impl<'a> Parser<'a> {
         message: &mut dyn MessageDyn,
         descriptor: &MessageDescriptor,
     ) -> ParseResult<()> {
+        if self.tokenizer.next_symbol_if_eq('[')? {
+            let name = self.next_bracketed_name()?;
+            return if name.contains('/') {
+                self.merge_any_expansion(message, descriptor, name)
+            } else {
+                self.merge_extension(message, descriptor, name)
+            };
+        }
+
         let field_name = self.next_field_name()?;
 
-        let field = match descriptor.field_by_name(&field_name) {
+        let field = match descriptor.field_by_name(&field_name).or_else(|| {
+            // Groups are referenced by group type name
+            descriptor
+                .fields()
+                .find(|f| text_format_field_name(f) == field_name)
+        }) {
             Some(field) => field,
             None => {
                 // TODO: shouldn't unknown fields be quietly skipped?
@@ -256,14 +296,26 @@ impl<'a> Parser<'a> {
             }
         };
 
+        self.merge_field_value(message, &field)
+    }
+
+    fn merge_field_value(
+        &mut self,
+        message: &mut dyn MessageDyn,
+        field: &FieldDescriptor,
+    ) -> ParseResult<()> {
         match field.runtime_field_type() {
             RuntimeFieldType::Singular(t) => {
                 let value = self.read_value_of_type(&t)?;
-                field.set_singular_field(message, value);
+                field
+                    .try_set_singular_field(message, value)
+                    .map_err(|e| ParseErrorWithoutLoc::CannotSetField(field.to_string(), e))?;
             }
             RuntimeFieldType::Repeated(t) => {
                 let value = self.read_value_of_type(&t)?;
-                field.mut_repeated(message).push(value);
+                field
+                    .push_repeated(message, value)
+                    .map_err(|e| ParseErrorWithoutLoc::CannotSetField(field.to_string(), e))?;
             }
             RuntimeFieldType::Map(k, v) => {
                 let (k, v) = self.read_map_entry(&k, &v)?;
@@ -274,6 +326,58 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// `[pkg.ext]: value`
+    fn merge_extension(
+        &mut self,
+        message: &mut dyn MessageDyn,
+        descriptor: &MessageDescriptor,
+        name: String,
+    ) -> ParseResult<()> {
+        let field = match self
+            .parse_options
+            .extension_registry
+            .find_by_name(descriptor.full_name(), &name)
+        {
+            Some(field) => field,
+            None => return Err(ParseErrorWithoutLoc::ExtensionNotFound(name)),
+        };
+        self.merge_field_value(message, &field)
+    }
+
+    /// `[type.googleapis.com/pkg.Msg] { ... }` in `google.protobuf.Any`
+    fn merge_any_expansion(
+        &mut self,
+        message: &mut dyn MessageDyn,
+        descriptor: &MessageDescriptor,
+        type_url: String,
+    ) -> ParseResult<()> {
+        if descriptor.full_name() != "google.protobuf.Any" {
+            return Err(ParseErrorWithoutLoc::UnknownField(format!(
+                "[{}]",
+                type_url
+            )));
+        }
+        let value_descriptor = match self
+            .parse_options
+            .type_registry
+            .find_message_by_type_url(&type_url)
+        {
+            Some(d) => d,
+            None => return Err(ParseErrorWithoutLoc::AnyTypeNotFound(type_url)),
+        };
+
+        self.tokenizer.next_symbol_if_eq(':')?;
+        let value = self.read_message(&value_descriptor)?;
+        let value = value
+            .write_to_bytes_dyn()
+            .map_err(|_| ParseErrorWithoutLoc::MessageNotInitialized)?;
+
+        let any_field = |name| descriptor.field_by_name(name).unwrap();
+        any_field("type_url").set_singular_field(message, ReflectValueBox::String(type_url));
+        any_field("value").set_singular_field(message, ReflectValueBox::Bytes(value));
+        Ok(())
+    }
+
     fn merge_inner(&mut self, message: &mut dyn MessageDyn) -> ParseResult<()> {
         loop {
             if self.tokenizer.syntax_eof()? {
@@ -296,20 +400,69 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Text format parse options.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::reflect::ExtensionRegistry;
+/// use protobuf::text_format;
+///
+/// let mut extension_registry = ExtensionRegistry::new();
+/// extension_registry.add_file(&protobuf::rustproto::file_descriptor());
+/// let parse_options = text_format::ParseOptions {
+///     extension_registry,
+///     ..Default::default()
+/// };
+/// let options: protobuf::descriptor::FileOptions =
+///     text_format::parse_from_str_with_options("[rustproto.lite_runtime_all]: true", &parse_options)
+///         .unwrap();
+/// assert_eq!(Some(true), protobuf::rustproto::exts::lite_runtime_all.get(&options));
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct ParseOptions {
+    /// Extensions which can be parsed with `[pkg.ext]: value` syntax.
+    ///
+    /// Parsing an extension not found in the registry is an error.
+    pub extension_registry: ExtensionRegistry,
+    /// Types used to parse `google.protobuf.Any` written as
+    /// `[type.googleapis.com/pkg.Msg] { ... }`.
+    ///
+    /// Parsing `Any` with a type not found in the registry is an error.
+    pub type_registry: TypeRegistry,
+    /// Prevent initializing `ParseOptions` enumerating all field.
+    pub _future_options: (),
+}
+
 /// Parse text format message.
 ///
 /// This function does not check if message required fields are set.
-pub fn merge_from_str(message: &mut dyn MessageDyn, input: &str) -> ParseWithLocResult<()> {
+pub fn merge_from_str_with_options(
+    message: &mut dyn MessageDyn,
+    input: &str,
+    parse_options: &ParseOptions,
+) -> ParseWithLocResult<()> {
     let mut parser = Parser {
         tokenizer: Tokenizer::new(input, ParserLanguage::TextFormat),
+        parse_options,
     };
     parser.merge(message)
 }
 
 /// Parse text format message.
-pub fn parse_from_str<M: MessageFull>(input: &str) -> ParseWithLocResult<M> {
+///
+/// This function does not check if message required fields are set.
+pub fn merge_from_str(message: &mut dyn MessageDyn, input: &str) -> ParseWithLocResult<()> {
+    merge_from_str_with_options(message, input, &ParseOptions::default())
+}
+
+/// Parse text format message.
+pub fn parse_from_str_with_options<M: MessageFull>(
+    input: &str,
+    parse_options: &ParseOptions,
+) -> ParseWithLocResult<M> {
     let mut m = M::new();
-    merge_from_str(&mut m, input)?;
+    merge_from_str_with_options(&mut m, input, parse_options)?;
     if let Err(_) = m.check_initialized() {
         return Err(ParseError {
             error: ParseErrorWithoutLoc::MessageNotInitialized,
@@ -318,3 +471,8 @@ pub fn parse_from_str<M: MessageFull>(input: &str) -> ParseWithLocResult<M> {
     }
     Ok(m)
 }
+
+/// Parse text format message.
+pub fn parse_from_str<M: MessageFull>(input: &str) -> ParseWithLocResult<M> {
+    parse_from_str_with_options(input, &ParseOptions::default())
+}
@@ -1,10 +1,14 @@
 use std::fmt;
 use std::fmt::Write;
 
+use crate::descriptor::field_descriptor_proto::Type;
 use crate::message_dyn::MessageDyn;
+use crate::reflect::ExtensionRegistry;
+use crate::reflect::FieldDescriptor;
 use crate::reflect::MessageRef;
 use crate::reflect::ReflectFieldRef;
 use crate::reflect::ReflectValueRef;
+use crate::reflect::TypeRegistry;
 
 #[doc(hidden)]
 pub fn quote_bytes_to(bytes: &[u8], buf: &mut String) {
@@ -56,6 +60,7 @@ fn do_indent(buf: &mut String, pretty: bool, indent: usize) {
 trait FieldName: fmt::Display {}
 impl<'a> FieldName for &'a str {}
 impl FieldName for u32 {}
+impl<'a> FieldName for fmt::Arguments<'a> {}
 
 fn print_start_field<F: FieldName>(
     buf: &mut String,
@@ -78,6 +83,22 @@ fn print_end_field(buf: &mut String, pretty: bool) {
     }
 }
 
+fn print_message_value(
+    m: &MessageRef,
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    options: &PrintOptions,
+) {
+    buf.push_str(" {");
+    if pretty {
+        buf.push_str("\n");
+    }
+    print_to_internal(m, buf, pretty, indent + 1, options);
+    do_indent(buf, pretty, indent);
+    buf.push_str("}");
+}
+
 fn print_field<F: FieldName>(
     buf: &mut String,
     pretty: bool,
@@ -85,18 +106,13 @@ fn print_field<F: FieldName>(
     first: &mut bool,
     field_name: F,
     value: ReflectValueRef,
+    options: &PrintOptions,
 ) {
     print_start_field(buf, pretty, indent, first, field_name);
 
     match value {
         ReflectValueRef::Message(m) => {
-            buf.push_str(" {");
-            if pretty {
-                buf.push_str("\n");
-            }
-            print_to_internal(&m, buf, pretty, indent + 1);
-            do_indent(buf, pretty, indent);
-            buf.push_str("}");
+            print_message_value(&m, buf, pretty, indent, options);
         }
         ReflectValueRef::Enum(d, v) => {
             buf.push_str(": ");
@@ -139,63 +155,227 @@ fn print_field<F: FieldName>(
     print_end_field(buf, pretty);
 }
 
-fn print_to_internal(m: &MessageRef, buf: &mut String, pretty: bool, indent: usize) {
+/// Group fields are printed with the group type name rather than the field name.
+pub(crate) fn text_format_field_name(f: &FieldDescriptor) -> &str {
+    let proto = f.proto();
+    match proto.field_type() {
+        Type::TYPE_GROUP => proto.type_name().rsplit('.').next().unwrap(),
+        _ => proto.name(),
+    }
+}
+
+/// Print `google.protobuf.Any` as `[type_url] { ... }` if the type is known.
+///
+/// Return `false` if the message is not an `Any` or cannot be expanded.
+fn print_any_expanded(
+    m: &MessageRef,
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    options: &PrintOptions,
+) -> bool {
     let d = m.descriptor_dyn();
+    if d.full_name() != "google.protobuf.Any" {
+        return false;
+    }
+    let type_url = d.field_by_name("type_url").unwrap();
+    let type_url = match type_url.get_singular_field_or_default(&**m) {
+        ReflectValueRef::String(type_url) => type_url,
+        _ => unreachable!(),
+    };
+    let value = d.field_by_name("value").unwrap();
+    let value = match value.get_singular_field_or_default(&**m) {
+        ReflectValueRef::Bytes(value) => value,
+        _ => unreachable!(),
+    };
+    let message = match options.type_registry.find_message_by_type_url(type_url) {
+        Some(descriptor) => match descriptor.parse_from_bytes(value) {
+            Ok(message) => message,
+            Err(_) => return false,
+        },
+        None => return false,
+    };
+
     let mut first = true;
-    for f in d.fields() {
-        match f.get_reflect(&**m) {
-            ReflectFieldRef::Map(map) => {
-                for (k, v) in &map {
-                    print_start_field(buf, pretty, indent, &mut first, f.name());
-                    buf.push_str(" {");
-                    if pretty {
-                        buf.push_str("\n");
-                    }
-
-                    let mut entry_first = true;
-
-                    print_field(buf, pretty, indent + 1, &mut entry_first, "key", k);
-                    print_field(buf, pretty, indent + 1, &mut entry_first, "value", v);
-                    do_indent(buf, pretty, indent);
-                    buf.push_str("}");
-                    print_end_field(buf, pretty);
+    print_start_field(
+        buf,
+        pretty,
+        indent,
+        &mut first,
+        format_args!("[{}]", type_url),
+    );
+    print_message_value(&MessageRef::from(&*message), buf, pretty, indent, options);
+    print_end_field(buf, pretty);
+    true
+}
+
+fn print_field_values<F: FieldName + Copy>(
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    first: &mut bool,
+    field_name: F,
+    field: ReflectFieldRef,
+    options: &PrintOptions,
+) {
+    match field {
+        ReflectFieldRef::Map(map) => {
+            for (k, v) in &map {
+                print_start_field(buf, pretty, indent, first, field_name);
+                buf.push_str(" {");
+                if pretty {
+                    buf.push_str("\n");
                 }
+
+                let mut entry_first = true;
+
+                print_field(buf, pretty, indent + 1, &mut entry_first, "key", k, options);
+                print_field(
+                    buf,
+                    pretty,
+                    indent + 1,
+                    &mut entry_first,
+                    "value",
+                    v,
+                    options,
+                );
+                do_indent(buf, pretty, indent);
+                buf.push_str("}");
+                print_end_field(buf, pretty);
             }
-            ReflectFieldRef::Repeated(repeated) => {
-                // TODO: do not print zeros for v3
-                for v in repeated {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v);
-                }
+        }
+        ReflectFieldRef::Repeated(repeated) => {
+            // TODO: do not print zeros for v3
+            for v in repeated {
+                print_field(buf, pretty, indent, first, field_name, v, options);
             }
-            ReflectFieldRef::Optional(optional) => {
-                if let Some(v) = optional {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v);
-                }
+        }
+        ReflectFieldRef::Optional(optional) => {
+            if let Some(v) = optional {
+                print_field(buf, pretty, indent, first, field_name, v, options);
             }
         }
     }
+}
+
+fn print_to_internal(
+    m: &MessageRef,
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    options: &PrintOptions,
+) {
+    if print_any_expanded(m, buf, pretty, indent, options) {
+        return;
+    }
+
+    let d = m.descriptor_dyn();
+    let mut first = true;
+    for f in d.fields() {
+        let field_name = text_format_field_name(&f);
+        let field = f.get_reflect(&**m);
+        print_field_values(buf, pretty, indent, &mut first, field_name, field, options);
+    }
+
+    let extensions = options.extension_registry.extensions_of(d.full_name());
+    for f in &extensions {
+        let field_name = format!("[{}]", f.full_name());
+        let field = f.get_reflect(&**m);
+        print_field_values(
+            buf,
+            pretty,
+            indent,
+            &mut first,
+            field_name.as_str(),
+            field,
+            options,
+        );
+    }
 
     let unknown_fields = m.unknown_fields_dyn();
-    let mut numbers: Vec<u32> = m.unknown_fields_dyn().iter().map(|(n, _)| n).collect();
+    let mut numbers: Vec<u32> = m
+        .unknown_fields_dyn()
+        .iter()
+        .map(|(n, _)| n)
+        .filter(|&n| {
+            // Printed above
+            !extensions.iter().any(|f| f.proto().number() as u32 == n)
+        })
+        .collect();
     // Sort for stable output
     numbers.sort();
     for &n in &numbers {
         for v in unknown_fields.get(n).unwrap() {
             // TODO: try decode nested message for length-delimited
-            print_field(buf, pretty, indent, &mut first, n, v.to_reflect_value_ref());
+            print_field(
+                buf,
+                pretty,
+                indent,
+                &mut first,
+                n,
+                v.to_reflect_value_ref(),
+                options,
+            );
         }
     }
 }
 
+/// Text format print options.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::reflect::ExtensionRegistry;
+/// use protobuf::text_format;
+///
+/// let mut extension_registry = ExtensionRegistry::new();
+/// extension_registry.add_file(&protobuf::rustproto::file_descriptor());
+/// let print_options = text_format::PrintOptions {
+///     extension_registry,
+///     ..Default::default()
+/// };
+/// let mut options = protobuf::descriptor::FileOptions::new();
+/// protobuf::rustproto::exts::lite_runtime_all.set(&mut options, true);
+/// assert_eq!(
+///     "[rustproto.lite_runtime_all]: true",
+///     text_format::print_to_string_with_options(&options, &print_options)
+/// );
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct PrintOptions {
+    /// Multiline output with indentation.
+    pub pretty: bool,
+    /// Extensions printed as `[pkg.ext]: value`.
+    ///
+    /// Extensions not found in the registry are printed as unknown fields.
+    pub extension_registry: ExtensionRegistry,
+    /// Types used to print `google.protobuf.Any` as `[type.googleapis.com/pkg.Msg] { ... }`.
+    ///
+    /// `Any` with a type not found in the registry is printed as a regular message.
+    pub type_registry: TypeRegistry,
+    /// Prevent initializing `PrintOptions` enumerating all field.
+    pub _future_options: (),
+}
+
 /// Text-format
 pub fn print_to(m: &dyn MessageDyn, buf: &mut String) {
-    print_to_internal(&MessageRef::from(m), buf, false, 0)
+    print_to_internal(
+        &MessageRef::from(m),
+        buf,
+        false,
+        0,
+        &PrintOptions::default(),
+    )
 }
 
 fn print_to_string_internal(m: &dyn MessageDyn, pretty: bool) -> String {
-    let mut r = String::new();
-    print_to_internal(&MessageRef::from(m), &mut r, pretty, 0);
-    r.to_string()
+    print_to_string_with_options(
+        m,
+        &PrintOptions {
+            pretty,
+            ..Default::default()
+        },
+    )
 }
 
 /// Text-format
@@ -208,6 +388,19 @@ pub fn print_to_string_pretty(m: &dyn MessageDyn) -> String {
     print_to_string_internal(m, true)
 }
 
+/// Text-format with options.
+pub fn print_to_string_with_options(m: &dyn MessageDyn, print_options: &PrintOptions) -> String {
+    let mut r = String::new();
+    print_to_internal(
+        &MessageRef::from(m),
+        &mut r,
+        print_options.pretty,
+        0,
+        print_options,
+    );
+    r
+}
+
 /// Text-format to `fmt::Formatter`.
 pub fn fmt(m: &dyn MessageDyn, f: &mut fmt::Formatter) -> fmt::Result {
     let pretty = f.alternate();
@@ -1,12 +1,23 @@
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::collections::btree_map;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::default::Default;
+#[cfg(feature = "std")]
+use core::hash::BuildHasherDefault;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::slice;
+#[cfg(feature = "std")]
 use std::collections::hash_map;
+#[cfg(feature = "std")]
 use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::default::Default;
-use std::hash::BuildHasherDefault;
-use std::hash::Hash;
-use std::hash::Hasher;
-use std::slice;
 
+#[cfg(feature = "std")]
 use crate::reflect::ReflectValueRef;
 use crate::rt;
 use crate::wire_format::WireType;
@@ -111,6 +122,7 @@ impl<'o> UnknownValueRef<'o> {
         }
     }
 
+    #[cfg(feature = "std")]
     pub(crate) fn to_reflect_value_ref(&'o self) -> ReflectValueRef<'o> {
         match self {
             UnknownValueRef::Fixed32(v) => ReflectValueRef::U32(*v),
@@ -201,6 +213,16 @@ impl<'o> Iterator for UnknownValuesIter<'o> {
     }
 }
 
+#[cfg(feature = "std")]
+type UnknownFieldsMap = HashMap<u32, UnknownValues, BuildHasherDefault<DefaultHasher>>;
+#[cfg(feature = "std")]
+type UnknownFieldsMapIter<'s> = hash_map::Iter<'s, u32, UnknownValues>;
+// There's no default hasher without `std`.
+#[cfg(not(feature = "std"))]
+type UnknownFieldsMap = BTreeMap<u32, UnknownValues>;
+#[cfg(not(feature = "std"))]
+type UnknownFieldsMapIter<'s> = btree_map::Iter<'s, u32, UnknownValues>;
+
 /// Hold "unknown" fields in parsed message.
 ///
 /// Field may be unknown if it they are added in newer version of `.proto`.
@@ -223,13 +245,14 @@ pub struct UnknownFields {
     // Note, Google Protobuf C++ simply uses linear map (which can exploitable the same way),
     // and Google Protobuf Java uses tree map to store unknown fields
     // (which is more expensive than hashmap).
-    fields: Option<Box<HashMap<u32, UnknownValues, BuildHasherDefault<DefaultHasher>>>>,
+    fields: Option<Box<UnknownFieldsMap>>,
 }
 
 /// Very simple hash implementation of `Hash` for `UnknownFields`.
 /// Since map is unordered, we cannot put entry hashes into hasher,
 /// instead we summing hashes of entries.
 impl Hash for UnknownFields {
+    #[cfg(feature = "std")]
     fn hash<H: Hasher>(&self, state: &mut H) {
         if let Some(ref map) = self.fields {
             if !map.is_empty() {
@@ -244,6 +267,16 @@ impl Hash for UnknownFields {
             }
         }
     }
+
+    /// Without `std` the map is ordered, so entries are hashed in order.
+    #[cfg(not(feature = "std"))]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Some(ref map) = self.fields {
+            if !map.is_empty() {
+                Hash::hash(&**map, state);
+            }
+        }
+    }
 }
 
 impl UnknownFields {
@@ -268,10 +301,7 @@ impl UnknownFields {
     fn find_field<'a>(&'a mut self, number: &'a u32) -> &'a mut UnknownValues {
         self.init_map();
 
-        match self.fields.as_mut().unwrap().entry(*number) {
-            hash_map::Entry::Occupied(e) => e.into_mut(),
-            hash_map::Entry::Vacant(e) => e.insert(Default::default()),
-        }
+        self.fields.as_mut().unwrap().entry(*number).or_default()
     }
 
     /// Add unknown fixed 32-bit
@@ -301,6 +331,21 @@ impl UnknownFields {
         self.find_field(&number).add_value(value);
     }
 
+    /// Append all unknown values of `other`.
+    pub fn extend_from(&mut self, other: &UnknownFields) {
+        if let Some(other) = &other.fields {
+            for (number, values) in other.iter() {
+                let field = self.find_field(number);
+                field.fixed32.extend_from_slice(&values.fixed32);
+                field.fixed64.extend_from_slice(&values.fixed64);
+                field.varint.extend_from_slice(&values.varint);
+                field
+                    .length_delimited
+                    .extend_from_slice(&values.length_delimited);
+            }
+        }
+    }
+
     /// Remove unknown field by number
     pub fn remove(&mut self, field_number: u32) {
         if let Some(fields) = &mut self.fields {
@@ -346,7 +391,7 @@ impl<'a> IntoIterator for &'a UnknownFields {
 
 /// Iterator over [`UnknownFields`](crate::UnknownFields)
 pub struct UnknownFieldsIter<'s> {
-    entries: Option<hash_map::Iter<'s, u32, UnknownValues>>,
+    entries: Option<UnknownFieldsMapIter<'s>>,
 }
 
 impl<'s> Iterator for UnknownFieldsIter<'s> {
@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
 
 /// Encode u64 as varint.
 /// Panics if buffer length is less than 10.
@@ -97,9 +97,30 @@ pub(crate) fn encoded_varint64_len(value: u64) -> usize {
     }
 }
 
+/// Decode varint from the beginning of the buffer.
+///
+/// Return value and encoded length, or `None` if buffer ends before the varint.
+#[cfg(feature = "std")]
+pub(crate) fn decode_varint64(buf: &[u8]) -> crate::Result<Option<(u64, usize)>> {
+    let mut r: u64 = 0;
+    for (i, &b) in buf.iter().enumerate() {
+        if i == 10 || i == 9 && (b & 0x7f) > 1 {
+            return Err(crate::error::WireError::IncorrectVarint.into());
+        }
+        r |= ((b & 0x7f) as u64) << (i * 7);
+        if b < 0x80 {
+            return Ok(Some((r, i + 1)));
+        }
+    }
+    if buf.len() >= 10 {
+        return Err(crate::error::WireError::IncorrectVarint.into());
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod test {
-    use std::mem::MaybeUninit;
+    use core::mem::MaybeUninit;
 
     use crate::varint::encode_varint64;
     use crate::varint::encoded_varint64_len;
@@ -0,0 +1,251 @@
+//! Borrowed read-only views of messages.
+//!
+//! When `gen_views` codegen option is enabled, for each message `Foo`
+//! a view type `FooView<'a>` is generated. View borrows
+//! `string`, `bytes` and packed repeated fields from the input slice,
+//! so parsing a view does not allocate.
+//!
+//! [`MessageView::parse`] validates wire structure and values of all the
+//! fields of the message, but nested messages are parsed lazily
+//! when accessed, so accessors of message fields return `Result`.
+//!
+//! Views differ from owned messages in a few ways:
+//! * if a singular message field occurs several times in the input,
+//!   only the last occurrence is used, while owned parsing merges occurrences
+//! * required fields are not checked
+//! * map fields, group fields and extensions are not accessible
+//! * repeated fields are iterated by scanning the message bytes on each access
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str;
+
+use crate::enums::Enum;
+use crate::error::WireError;
+use crate::reflect::types::ProtobufTypeBool;
+use crate::reflect::types::ProtobufTypeBytes;
+use crate::reflect::types::ProtobufTypeDouble;
+use crate::reflect::types::ProtobufTypeEnumOrUnknown;
+use crate::reflect::types::ProtobufTypeFixed32;
+use crate::reflect::types::ProtobufTypeFixed64;
+use crate::reflect::types::ProtobufTypeFloat;
+use crate::reflect::types::ProtobufTypeInt32;
+use crate::reflect::types::ProtobufTypeInt64;
+use crate::reflect::types::ProtobufTypeSfixed32;
+use crate::reflect::types::ProtobufTypeSfixed64;
+use crate::reflect::types::ProtobufTypeSint32;
+use crate::reflect::types::ProtobufTypeSint64;
+use crate::reflect::types::ProtobufTypeString;
+use crate::reflect::types::ProtobufTypeUint32;
+use crate::reflect::types::ProtobufTypeUint64;
+use crate::rt::view::read_fixed32;
+use crate::rt::view::read_fixed64;
+use crate::rt::view::read_len_delimited;
+use crate::rt::view::read_varint;
+use crate::rt::view_read_tag;
+use crate::rt::view_skip_field;
+use crate::wire_format::Tag;
+use crate::wire_format::WireType;
+use crate::zigzag::decode_zig_zag_32;
+use crate::zigzag::decode_zig_zag_64;
+use crate::EnumOrUnknown;
+use crate::Message;
+
+/// Read-only view of a message borrowing the input.
+///
+/// Implemented by generated `FooView<'a>` types.
+pub trait MessageView<'a>: Copy + Default + fmt::Debug {
+    /// Owned message type.
+    type Message: Message;
+
+    /// Parse view from message bytes.
+    ///
+    /// Nested messages are validated when accessed.
+    fn parse(bytes: &'a [u8]) -> crate::Result<Self>;
+
+    /// Bytes this view was parsed from.
+    fn bytes(&self) -> &'a [u8];
+
+    /// Parse owned message from the same bytes.
+    fn to_message(&self) -> crate::Result<Self::Message> {
+        Self::Message::parse_from_bytes(self.bytes())
+    }
+}
+
+/// Protobuf type which can be decoded borrowing the input.
+///
+/// Implemented for protobuf type markers like
+/// [`ProtobufTypeInt32`](crate::reflect::types::ProtobufTypeInt32).
+pub trait ViewType<'a> {
+    /// Decoded value.
+    type Value;
+
+    /// Wire type of single value, packed values are stored as length-delimited.
+    const WIRE_TYPE: WireType;
+
+    /// Decode a value advancing the input.
+    fn read(input: &mut &'a [u8]) -> crate::Result<Self::Value>;
+}
+
+macro_rules! impl_view_type {
+    ($t:ty, $value:ty, $wire_type:ident, |$input:ident| $read:expr) => {
+        impl<'a> ViewType<'a> for $t {
+            type Value = $value;
+
+            const WIRE_TYPE: WireType = WireType::$wire_type;
+
+            fn read($input: &mut &'a [u8]) -> crate::Result<$value> {
+                Ok($read)
+            }
+        }
+    };
+}
+
+impl_view_type!(ProtobufTypeFloat, f32, Fixed32, |input| f32::from_bits(
+    read_fixed32(input)?
+));
+impl_view_type!(ProtobufTypeDouble, f64, Fixed64, |input| f64::from_bits(
+    read_fixed64(input)?
+));
+impl_view_type!(ProtobufTypeInt32, i32, Varint, |input| read_varint(input)?
+    as i32);
+impl_view_type!(ProtobufTypeInt64, i64, Varint, |input| read_varint(input)?
+    as i64);
+impl_view_type!(ProtobufTypeUint32, u32, Varint, |input| read_varint(input)?
+    as u32);
+impl_view_type!(ProtobufTypeUint64, u64, Varint, |input| read_varint(input)?);
+impl_view_type!(ProtobufTypeSint32, i32, Varint, |input| decode_zig_zag_32(
+    read_varint(input)? as u32
+));
+impl_view_type!(ProtobufTypeSint64, i64, Varint, |input| decode_zig_zag_64(
+    read_varint(input)?
+));
+impl_view_type!(ProtobufTypeFixed32, u32, Fixed32, |input| read_fixed32(
+    input
+)?);
+impl_view_type!(ProtobufTypeFixed64, u64, Fixed64, |input| read_fixed64(
+    input
+)?);
+impl_view_type!(
+    ProtobufTypeSfixed32,
+    i32,
+    Fixed32,
+    |input| read_fixed32(input)? as i32
+);
+impl_view_type!(
+    ProtobufTypeSfixed64,
+    i64,
+    Fixed64,
+    |input| read_fixed64(input)? as i64
+);
+impl_view_type!(ProtobufTypeBool, bool, Varint, |input| read_varint(input)?
+    != 0);
+impl_view_type!(ProtobufTypeBytes, &'a [u8], LengthDelimited, |input| {
+    read_len_delimited(input)?
+});
+impl_view_type!(ProtobufTypeString, &'a str, LengthDelimited, |input| {
+    str::from_utf8(read_len_delimited(input)?).map_err(|_| WireError::Utf8Error)?
+});
+
+impl<'a, E: Enum> ViewType<'a> for ProtobufTypeEnumOrUnknown<E> {
+    type Value = EnumOrUnknown<E>;
+
+    const WIRE_TYPE: WireType = WireType::Varint;
+
+    fn read(input: &mut &'a [u8]) -> crate::Result<EnumOrUnknown<E>> {
+        Ok(EnumOrUnknown::from_i32(read_varint(input)? as i32))
+    }
+}
+
+/// [`ViewType`] for message fields, decodes message view `V`.
+///
+/// Value is a `Result`, because nested messages are parsed lazily.
+pub struct ViewTypeMessage<V>(PhantomData<V>);
+
+impl<'a, V: MessageView<'a>> ViewType<'a> for ViewTypeMessage<V> {
+    type Value = crate::Result<V>;
+
+    const WIRE_TYPE: WireType = WireType::LengthDelimited;
+
+    fn read(input: &mut &'a [u8]) -> crate::Result<crate::Result<V>> {
+        Ok(V::parse(read_len_delimited(input)?))
+    }
+}
+
+/// Iterator over values of a repeated field of a message view.
+///
+/// Both packed and unpacked values are returned, in the input order.
+pub struct RepeatedView<'a, T: ViewType<'a>> {
+    input: &'a [u8],
+    packed: &'a [u8],
+    field_number: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T: ViewType<'a>> RepeatedView<'a, T> {
+    #[doc(hidden)]
+    pub fn new(message_bytes: &'a [u8], field_number: u32) -> RepeatedView<'a, T> {
+        RepeatedView {
+            input: message_bytes,
+            packed: &[],
+            field_number,
+            phantom: PhantomData,
+        }
+    }
+
+    fn next_impl(&mut self) -> crate::Result<Option<T::Value>> {
+        loop {
+            if !self.packed.is_empty() {
+                return T::read(&mut self.packed).map(Some);
+            }
+            let tag = match view_read_tag(&mut self.input)? {
+                Some(tag) => tag,
+                None => return Ok(None),
+            };
+            let (field_number, wire_type) = Tag::new(tag)?.unpack();
+            if field_number == self.field_number {
+                if wire_type == T::WIRE_TYPE {
+                    return T::read(&mut self.input).map(Some);
+                }
+                if wire_type == WireType::LengthDelimited {
+                    self.packed = read_len_delimited(&mut self.input)?;
+                    continue;
+                }
+            }
+            view_skip_field(&mut self.input, tag)?;
+        }
+    }
+}
+
+impl<'a, T: ViewType<'a>> Clone for RepeatedView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ViewType<'a>> Copy for RepeatedView<'a, T> {}
+
+impl<'a, T: ViewType<'a>> fmt::Debug for RepeatedView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RepeatedView")
+            .field("field_number", &self.field_number)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: ViewType<'a>> Iterator for RepeatedView<'a, T> {
+    type Item = T::Value;
+
+    fn next(&mut self) -> Option<T::Value> {
+        // Values are validated when the view is parsed,
+        // so decoding errors are not expected here.
+        match self.next_impl() {
+            Ok(value) => value,
+            Err(_) => {
+                self.input = &[];
+                self.packed = &[];
+                None
+            }
+        }
+    }
+}
@@ -21,6 +21,103 @@
 
 //! Generated file from `google/protobuf/any.proto`
 
+///  `Any` contains an arbitrary serialized protocol buffer message along with a
+///  URL that describes the type of the serialized message.
+///
+///  Protobuf library provides support to pack/unpack Any values in the form
+///  of utility functions or additional generated methods of the Any type.
+///
+///  Example 1: Pack and unpack a message in C++.
+///
+///  ```text
+///      Foo foo = ...;
+///      Any any;
+///      any.PackFrom(foo);
+///      ...
+///      if (any.UnpackTo(&foo)) {
+///        ...
+///      }
+///  ```
+///
+///  Example 2: Pack and unpack a message in Java.
+///
+///  ```text
+///      Foo foo = ...;
+///      Any any = Any.pack(foo);
+///      ...
+///      if (any.is(Foo.class)) {
+///        foo = any.unpack(Foo.class);
+///      }
+///  ```
+///
+///   Example 3: Pack and unpack a message in Python.
+///
+///  ```text
+///      foo = Foo(...)
+///      any = Any()
+///      any.Pack(foo)
+///      ...
+///      if any.Is(Foo.DESCRIPTOR):
+///        any.Unpack(foo)
+///        ...
+///  ```
+///
+///   Example 4: Pack and unpack a message in Go
+///
+///  ```text
+///       foo := &pb.Foo{...}
+///       any, err := anypb.New(foo)
+///       if err != nil {
+///         ...
+///       }
+///       ...
+///       foo := &pb.Foo{}
+///       if err := any.UnmarshalTo(foo); err != nil {
+///         ...
+///       }
+///  ```
+///
+///  The pack methods provided by protobuf library will by default use
+///  'type.googleapis.com/full.type.name' as the type URL and the unpack
+///  methods only use the fully qualified type name after the last '/'
+///  in the type URL, for example "foo.bar.com/x/y.z" will yield type
+///  name "y.z".
+///
+///
+///  JSON
+///  ====
+///  The JSON representation of an `Any` value uses the regular
+///  representation of the deserialized, embedded message, with an
+///  additional field `@type` which contains the type URL. Example:
+///
+///  ```text
+///      package google.profile;
+///      message Person {
+///        string first_name = 1;
+///        string last_name = 2;
+///      }
+///  ```
+///
+///  ```text
+///      {
+///        "@type": "type.googleapis.com/google.profile.Person",
+///        "firstName": <string>,
+///        "lastName": <string>
+///      }
+///  ```
+///
+///  If the embedded message type is well-known and has a custom JSON
+///  representation, that representation will be embedded adding a field
+///  `value` which holds the custom JSON in addition to the `@type`
+///  field. Example (for message \[google.protobuf.Duration\]\[\]):
+///
+///  ```text
+///      {
+///        "@type": "type.googleapis.com/google.protobuf.Duration",
+///        "value": "1.212s"
+///      }
+///  ```
+///
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Any)
 pub struct Any {
@@ -38,7 +135,7 @@ pub struct Any {
     ///  server that maps type URLs to message definitions as follows:
     ///
     ///  * If no scheme is provided, `https` is assumed.
-    ///  * An HTTP GET on the URL must yield a [google.protobuf.Type][]
+    ///  * An HTTP GET on the URL must yield a \[google.protobuf.Type\]\[\]
     ///    value in binary format, or produce an error.
     ///  * Applications are allowed to cache lookup results based on the
     ///    URL, or have them precompiled into a binary to avoid any
@@ -54,10 +151,10 @@ pub struct Any {
     ///  used with implementation specific semantics.
     ///
     // @@protoc_insertion_point(field:google.protobuf.Any.type_url)
-    pub type_url: ::std::string::String,
+    pub type_url: crate::rt::String,
     ///  Must be a valid serialized protocol buffer of the above specified type.
     // @@protoc_insertion_point(field:google.protobuf.Any.value)
-    pub value: ::std::vec::Vec<u8>,
+    pub value: crate::rt::Vec<u8>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.Any.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -65,7 +162,7 @@ pub struct Any {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Any {
+impl<'a> ::core::default::Default for &'a Any {
     fn default() -> &'a Any {
         <Any as crate::Message>::default_instance()
     }
@@ -73,11 +170,11 @@ impl<'a> ::std::default::Default for &'a Any {
 
 impl Any {
     pub fn new() -> Any {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "type_url",
             |m: &Any| { &m.type_url },
@@ -104,8 +201,7 @@ impl crate::Message for Any {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.type_url = is.read_string()?;
@@ -118,7 +214,17 @@ impl crate::Message for Any {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.type_url.is_empty() {
+            self.type_url.clone_from(&other.type_url);
+        }
+        if !other.value.is_empty() {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -144,7 +250,18 @@ impl crate::Message for Any {
             os.write_bytes(2, &self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value);
+        }
+        if !self.type_url.is_empty() {
+            os.write_string(1, &self.type_url);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -171,8 +288,8 @@ impl crate::Message for Any {
 
     fn default_instance() -> &'static Any {
         static instance: Any = Any {
-            type_url: ::std::string::String::new(),
-            value: ::std::vec::Vec::new(),
+            type_url: crate::rt::String::new(),
+            value: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -186,8 +303,8 @@ impl crate::MessageFull for Any {
     }
 }
 
-impl ::std::fmt::Display for Any {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Any {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -346,10 +463,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Any::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -28,7 +28,7 @@
 ///  from API Services, which represent a concrete implementation of an interface
 ///  as opposed to simply a description of methods and bindings. They are also
 ///  sometimes simply referred to as "APIs" in other contexts, such as the name of
-///  this message itself. See https://cloud.google.com/apis/design/glossary for
+///  this message itself. See <https://cloud.google.com/apis/design/glossary> for
 ///  detailed terminology.
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Api)
@@ -37,13 +37,13 @@ pub struct Api {
     ///  The fully qualified name of this interface, including package name
     ///  followed by the interface's simple name.
     // @@protoc_insertion_point(field:google.protobuf.Api.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  The methods of this interface, in unspecified order.
     // @@protoc_insertion_point(field:google.protobuf.Api.methods)
-    pub methods: ::std::vec::Vec<Method>,
+    pub methods: crate::rt::Vec<Method>,
     ///  Any metadata attached to the interface.
     // @@protoc_insertion_point(field:google.protobuf.Api.options)
-    pub options: ::std::vec::Vec<crate::well_known_types::Option>,
+    pub options: crate::rt::Vec<crate::well_known_types::Option>,
     ///  A version string for this interface. If specified, must have the form
     ///  `major-version.minor-version`, as in `1.10`. If the minor version is
     ///  omitted, it defaults to zero. If the entire version field is empty, the
@@ -51,8 +51,8 @@ pub struct Api {
     ///  field is not empty, the version in the package name will be verified to be
     ///  consistent with what is provided here.
     ///
-    ///  The versioning schema uses [semantic
-    ///  versioning](http://semver.org) where the major version number
+    ///  The versioning schema uses \[semantic
+    ///  versioning\](<http://semver.org)> where the major version number
     ///  indicates a breaking change and the minor version an additive,
     ///  non-breaking change. Both version numbers are signals to users
     ///  what to expect from different versions, and should be carefully
@@ -66,14 +66,14 @@ pub struct Api {
     ///
     ///
     // @@protoc_insertion_point(field:google.protobuf.Api.version)
-    pub version: ::std::string::String,
+    pub version: crate::rt::String,
     ///  Source context for the protocol buffer service represented by this
     ///  message.
     // @@protoc_insertion_point(field:google.protobuf.Api.source_context)
     pub source_context: crate::MessageField<crate::well_known_types::SourceContext>,
-    ///  Included interfaces. See [Mixin][].
+    ///  Included interfaces. See \[Mixin\]\[\].
     // @@protoc_insertion_point(field:google.protobuf.Api.mixins)
-    pub mixins: ::std::vec::Vec<Mixin>,
+    pub mixins: crate::rt::Vec<Mixin>,
     ///  The source syntax of the service.
     // @@protoc_insertion_point(field:google.protobuf.Api.syntax)
     pub syntax: crate::EnumOrUnknown<crate::well_known_types::Syntax>,
@@ -84,7 +84,7 @@ pub struct Api {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Api {
+impl<'a> ::core::default::Default for &'a Api {
     fn default() -> &'a Api {
         <Api as crate::Message>::default_instance()
     }
@@ -92,11 +92,11 @@ impl<'a> ::std::default::Default for &'a Api {
 
 impl Api {
     pub fn new() -> Api {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = crate::rt::Vec::with_capacity(7);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Api| { &m.name },
@@ -167,18 +167,34 @@ impl crate::Message for Api {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.methods.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "methods", i), missing);
+        };
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+        for v in &self.source_context {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "source_context"), missing);
+        };
+        for (i, v) in self.mixins.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "mixins", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
                 },
                 18 => {
-                    self.methods.push(is.read_message()?);
+                    self.methods.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.methods.len())?;
                 },
                 26 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 34 => {
                     self.version = is.read_string()?;
@@ -187,7 +203,8 @@ impl crate::Message for Api {
                     crate::rt::read_singular_message_into_field(is, &mut self.source_context)?;
                 },
                 50 => {
-                    self.mixins.push(is.read_message()?);
+                    self.mixins.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.mixins.len())?;
                 },
                 56 => {
                     self.syntax = is.read_enum_or_unknown()?;
@@ -197,7 +214,26 @@ impl crate::Message for Api {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        self.methods.extend(other.methods.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if !other.version.is_empty() {
+            self.version.clone_from(&other.version);
+        }
+        if let Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        self.mixins.extend(other.mixins.iter().cloned());
+        if other.syntax != crate::EnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            self.syntax.clone_from(&other.syntax);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -257,7 +293,33 @@ impl crate::Message for Api {
             os.write_enum(7, crate::EnumOrUnknown::value(&self.syntax))?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.syntax != crate::EnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            os.write_enum(7, crate::EnumOrUnknown::value(&self.syntax));
+        }
+        for v in self.mixins.iter().rev() {
+            crate::rt::write_message_field_reverse(6, v, os)?;
+        };
+        if let Some(v) = self.source_context.as_ref() {
+            crate::rt::write_message_field_reverse(5, v, os)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(4, &self.version);
+        }
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        };
+        for v in self.methods.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -289,12 +351,12 @@ impl crate::Message for Api {
 
     fn default_instance() -> &'static Api {
         static instance: Api = Api {
-            name: ::std::string::String::new(),
-            methods: ::std::vec::Vec::new(),
-            options: ::std::vec::Vec::new(),
-            version: ::std::string::String::new(),
+            name: crate::rt::String::new(),
+            methods: crate::rt::Vec::new(),
+            options: crate::rt::Vec::new(),
+            version: crate::rt::String::new(),
             source_context: crate::MessageField::none(),
-            mixins: ::std::vec::Vec::new(),
+            mixins: crate::rt::Vec::new(),
             syntax: crate::EnumOrUnknown::from_i32(0),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -309,8 +371,8 @@ impl crate::MessageFull for Api {
     }
 }
 
-impl ::std::fmt::Display for Api {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Api {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -326,22 +388,22 @@ pub struct Method {
     // message fields
     ///  The simple name of this method.
     // @@protoc_insertion_point(field:google.protobuf.Method.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  A URL of the input message type.
     // @@protoc_insertion_point(field:google.protobuf.Method.request_type_url)
-    pub request_type_url: ::std::string::String,
+    pub request_type_url: crate::rt::String,
     ///  If true, the request is streamed.
     // @@protoc_insertion_point(field:google.protobuf.Method.request_streaming)
     pub request_streaming: bool,
     ///  The URL of the output message type.
     // @@protoc_insertion_point(field:google.protobuf.Method.response_type_url)
-    pub response_type_url: ::std::string::String,
+    pub response_type_url: crate::rt::String,
     ///  If true, the response is streamed.
     // @@protoc_insertion_point(field:google.protobuf.Method.response_streaming)
     pub response_streaming: bool,
     ///  Any metadata attached to the method.
     // @@protoc_insertion_point(field:google.protobuf.Method.options)
-    pub options: ::std::vec::Vec<crate::well_known_types::Option>,
+    pub options: crate::rt::Vec<crate::well_known_types::Option>,
     ///  The source syntax of this method.
     // @@protoc_insertion_point(field:google.protobuf.Method.syntax)
     pub syntax: crate::EnumOrUnknown<crate::well_known_types::Syntax>,
@@ -352,7 +414,7 @@ pub struct Method {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Method {
+impl<'a> ::core::default::Default for &'a Method {
     fn default() -> &'a Method {
         <Method as crate::Message>::default_instance()
     }
@@ -360,11 +422,11 @@ impl<'a> ::std::default::Default for &'a Method {
 
 impl Method {
     pub fn new() -> Method {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = crate::rt::Vec::with_capacity(7);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Method| { &m.name },
@@ -420,9 +482,14 @@ impl crate::Message for Method {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
@@ -440,7 +507,8 @@ impl crate::Message for Method {
                     self.response_streaming = is.read_bool()?;
                 },
                 50 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 56 => {
                     self.syntax = is.read_enum_or_unknown()?;
@@ -450,7 +518,30 @@ impl crate::Message for Method {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if !other.request_type_url.is_empty() {
+            self.request_type_url.clone_from(&other.request_type_url);
+        }
+        if other.request_streaming != false {
+            self.request_streaming.clone_from(&other.request_streaming);
+        }
+        if !other.response_type_url.is_empty() {
+            self.response_type_url.clone_from(&other.response_type_url);
+        }
+        if other.response_streaming != false {
+            self.response_streaming.clone_from(&other.response_streaming);
+        }
+        self.options.extend(other.options.iter().cloned());
+        if other.syntax != crate::EnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            self.syntax.clone_from(&other.syntax);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -507,7 +598,33 @@ impl crate::Message for Method {
             os.write_enum(7, crate::EnumOrUnknown::value(&self.syntax))?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.syntax != crate::EnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            os.write_enum(7, crate::EnumOrUnknown::value(&self.syntax));
+        }
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(6, v, os)?;
+        };
+        if self.response_streaming != false {
+            os.write_bool(5, self.response_streaming);
+        }
+        if !self.response_type_url.is_empty() {
+            os.write_string(4, &self.response_type_url);
+        }
+        if self.request_streaming != false {
+            os.write_bool(3, self.request_streaming);
+        }
+        if !self.request_type_url.is_empty() {
+            os.write_string(2, &self.request_type_url);
+        }
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -539,12 +656,12 @@ impl crate::Message for Method {
 
     fn default_instance() -> &'static Method {
         static instance: Method = Method {
-            name: ::std::string::String::new(),
-            request_type_url: ::std::string::String::new(),
+            name: crate::rt::String::new(),
+            request_type_url: crate::rt::String::new(),
             request_streaming: false,
-            response_type_url: ::std::string::String::new(),
+            response_type_url: crate::rt::String::new(),
             response_streaming: false,
-            options: ::std::vec::Vec::new(),
+            options: crate::rt::Vec::new(),
             syntax: crate::EnumOrUnknown::from_i32(0),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -559,8 +676,8 @@ impl crate::MessageFull for Method {
     }
 }
 
-impl ::std::fmt::Display for Method {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Method {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -569,17 +686,109 @@ impl crate::reflect::ProtobufValue for Method {
     type RuntimeType = crate::reflect::runtime_types::RuntimeTypeMessage<Self>;
 }
 
+///  Declares an API Interface to be included in this interface. The including
+///  interface must redeclare all the methods from the included interface, but
+///  documentation and options are inherited as follows:
+///
+///  - If after comment and whitespace stripping, the documentation
+///    string of the redeclared method is empty, it will be inherited
+///    from the original method.
+///
+///  - Each annotation belonging to the service config (http,
+///    visibility) which is not set in the redeclared method will be
+///    inherited.
+///
+///  - If an http annotation is inherited, the path pattern will be
+///    modified as follows. Any version prefix will be replaced by the
+///    version of the including interface plus the \[root\]\[\] path if
+///    specified.
+///
+///  Example of a simple mixin:
+///
+///  ```text
+///      package google.acl.v1;
+///      service AccessControl {
+///        // Get the underlying ACL object.
+///        rpc GetAcl(GetAclRequest) returns (Acl) {
+///          option (google.api.http).get = "/v1/{resource=**}:getAcl";
+///        }
+///      }
+///  ```
+///
+///  ```text
+///      package google.storage.v2;
+///      service Storage {
+///        rpc GetAcl(GetAclRequest) returns (Acl);
+///  ```
+///
+///  ```text
+///        // Get a data record.
+///        rpc GetData(GetDataRequest) returns (Data) {
+///          option (google.api.http).get = "/v2/{resource=**}";
+///        }
+///      }
+///  ```
+///
+///  Example of a mixin configuration:
+///
+///  ```text
+///      apis:
+///      - name: google.storage.v2.Storage
+///        mixins:
+///        - name: google.acl.v1.AccessControl
+///  ```
+///
+///  The mixin construct implies that all methods in `AccessControl` are
+///  also declared with same name and request/response types in
+///  `Storage`. A documentation generator or annotation processor will
+///  see the effective `Storage.GetAcl` method after inheriting
+///  documentation and annotations as follows:
+///
+///  ```text
+///      service Storage {
+///        // Get the underlying ACL object.
+///        rpc GetAcl(GetAclRequest) returns (Acl) {
+///          option (google.api.http).get = "/v2/{resource=**}:getAcl";
+///        }
+///        ...
+///      }
+///  ```
+///
+///  Note how the version in the path pattern changed from `v1` to `v2`.
+///
+///  If the `root` field in the mixin is specified, it should be a
+///  relative path under which inherited HTTP paths are placed. Example:
+///
+///  ```text
+///      apis:
+///      - name: google.storage.v2.Storage
+///        mixins:
+///        - name: google.acl.v1.AccessControl
+///          root: acls
+///  ```
+///
+///  This implies the following inherited HTTP annotation:
+///
+///  ```text
+///      service Storage {
+///        // Get the underlying ACL object.
+///        rpc GetAcl(GetAclRequest) returns (Acl) {
+///          option (google.api.http).get = "/v2/acls/{resource=**}:getAcl";
+///        }
+///        ...
+///      }
+///  ```
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Mixin)
 pub struct Mixin {
     // message fields
     ///  The fully qualified name of the interface which is included.
     // @@protoc_insertion_point(field:google.protobuf.Mixin.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  If non-empty specifies a path under which inherited HTTP paths
     ///  are rooted.
     // @@protoc_insertion_point(field:google.protobuf.Mixin.root)
-    pub root: ::std::string::String,
+    pub root: crate::rt::String,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.Mixin.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -587,7 +796,7 @@ pub struct Mixin {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Mixin {
+impl<'a> ::core::default::Default for &'a Mixin {
     fn default() -> &'a Mixin {
         <Mixin as crate::Message>::default_instance()
     }
@@ -595,11 +804,11 @@ impl<'a> ::std::default::Default for &'a Mixin {
 
 impl Mixin {
     pub fn new() -> Mixin {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Mixin| { &m.name },
@@ -626,8 +835,7 @@ impl crate::Message for Mixin {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
@@ -640,7 +848,17 @@ impl crate::Message for Mixin {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if !other.root.is_empty() {
+            self.root.clone_from(&other.root);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -666,7 +884,18 @@ impl crate::Message for Mixin {
             os.write_string(2, &self.root)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.root.is_empty() {
+            os.write_string(2, &self.root);
+        }
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -693,8 +922,8 @@ impl crate::Message for Mixin {
 
     fn default_instance() -> &'static Mixin {
         static instance: Mixin = Mixin {
-            name: ::std::string::String::new(),
-            root: ::std::string::String::new(),
+            name: crate::rt::String::new(),
+            root: crate::rt::String::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -708,8 +937,8 @@ impl crate::MessageFull for Mixin {
     }
 }
 
-impl ::std::fmt::Display for Mixin {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Mixin {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -951,14 +1180,14 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
         deps.push(crate::well_known_types::file_descriptors::source_context());
         deps.push(crate::well_known_types::file_descriptors::type_pb());
-        let mut messages = ::std::vec::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Api::generated_message_descriptor_data());
         messages.push(Method::generated_message_descriptor_data());
         messages.push(Mixin::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -21,6 +21,80 @@
 
 //! Generated file from `google/protobuf/duration.proto`
 
+///  A Duration represents a signed, fixed-length span of time represented
+///  as a count of seconds and fractions of seconds at nanosecond
+///  resolution. It is independent of any calendar and concepts like "day"
+///  or "month". It is related to Timestamp in that the difference between
+///  two Timestamp values is a Duration and it can be added or subtracted
+///  from a Timestamp. Range is approximately +-10,000 years.
+///
+///  # Examples
+///
+///  Example 1: Compute Duration from two Timestamps in pseudo code.
+///
+///  ```text
+///      Timestamp start = ...;
+///      Timestamp end = ...;
+///      Duration duration = ...;
+///  ```
+///
+///  ```text
+///      duration.seconds = end.seconds - start.seconds;
+///      duration.nanos = end.nanos - start.nanos;
+///  ```
+///
+///  ```text
+///      if (duration.seconds < 0 && duration.nanos > 0) {
+///        duration.seconds += 1;
+///        duration.nanos -= 1000000000;
+///      } else if (duration.seconds > 0 && duration.nanos < 0) {
+///        duration.seconds -= 1;
+///        duration.nanos += 1000000000;
+///      }
+///  ```
+///
+///  Example 2: Compute Timestamp from Timestamp + Duration in pseudo code.
+///
+///  ```text
+///      Timestamp start = ...;
+///      Duration duration = ...;
+///      Timestamp end = ...;
+///  ```
+///
+///  ```text
+///      end.seconds = start.seconds + duration.seconds;
+///      end.nanos = start.nanos + duration.nanos;
+///  ```
+///
+///  ```text
+///      if (end.nanos < 0) {
+///        end.seconds -= 1;
+///        end.nanos += 1000000000;
+///      } else if (end.nanos >= 1000000000) {
+///        end.seconds += 1;
+///        end.nanos -= 1000000000;
+///      }
+///  ```
+///
+///  Example 3: Compute Duration from datetime.timedelta in Python.
+///
+///  ```text
+///      td = datetime.timedelta(days=3, minutes=10)
+///      duration = Duration()
+///      duration.FromTimedelta(td)
+///  ```
+///
+///  # JSON Mapping
+///
+///  In JSON format, the Duration type is encoded as a string rather than an
+///  object, where the string ends in the suffix "s" (indicating seconds) and
+///  is preceded by the number of seconds, with nanoseconds expressed as
+///  fractional seconds. For example, 3 seconds with 0 nanoseconds should be
+///  encoded in JSON format as "3s", while 3 seconds and 1 nanosecond should
+///  be expressed in JSON format as "3.000000001s", and 3 seconds and 1
+///  microsecond should be expressed in JSON format as "3.000001s".
+///
+///
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Duration)
 pub struct Duration {
@@ -45,7 +119,7 @@ pub struct Duration {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Duration {
+impl<'a> ::core::default::Default for &'a Duration {
     fn default() -> &'a Duration {
         <Duration as crate::Message>::default_instance()
     }
@@ -53,11 +127,11 @@ impl<'a> ::std::default::Default for &'a Duration {
 
 impl Duration {
     pub fn new() -> Duration {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "seconds",
             |m: &Duration| { &m.seconds },
@@ -84,8 +158,7 @@ impl crate::Message for Duration {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.seconds = is.read_int64()?;
@@ -98,7 +171,17 @@ impl crate::Message for Duration {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.seconds != 0 {
+            self.seconds.clone_from(&other.seconds);
+        }
+        if other.nanos != 0 {
+            self.nanos.clone_from(&other.nanos);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -124,7 +207,18 @@ impl crate::Message for Duration {
             os.write_int32(2, self.nanos)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.nanos != 0 {
+            os.write_int32(2, self.nanos);
+        }
+        if self.seconds != 0 {
+            os.write_int64(1, self.seconds);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -166,8 +260,8 @@ impl crate::MessageFull for Duration {
     }
 }
 
-impl ::std::fmt::Display for Duration {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Duration {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -304,10 +398,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Duration::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
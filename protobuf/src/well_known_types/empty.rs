@@ -21,6 +21,17 @@
 
 //! Generated file from `google/protobuf/empty.proto`
 
+///  A generic empty message that you can re-use to avoid defining duplicated
+///  empty messages in your APIs. A typical example is to use it as the request
+///  or the response type of an API method. For instance:
+///
+///  ```text
+///      service Foo {
+///        rpc Bar(google.protobuf.Empty) returns (google.protobuf.Empty);
+///      }
+///  ```
+///
+///  The JSON representation for `Empty` is empty JSON object `{}`.
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Empty)
 pub struct Empty {
@@ -31,7 +42,7 @@ pub struct Empty {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Empty {
+impl<'a> ::core::default::Default for &'a Empty {
     fn default() -> &'a Empty {
         <Empty as crate::Message>::default_instance()
     }
@@ -39,11 +50,11 @@ impl<'a> ::std::default::Default for &'a Empty {
 
 impl Empty {
     pub fn new() -> Empty {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = crate::rt::Vec::with_capacity(0);
         crate::reflect::GeneratedMessageDescriptorData::new_2::<Empty>(
             "Empty",
             0,
@@ -60,15 +71,18 @@ impl crate::Message for Empty {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -82,7 +96,12 @@ impl crate::Message for Empty {
 
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -120,8 +139,8 @@ impl crate::MessageFull for Empty {
     }
 }
 
-impl ::std::fmt::Display for Empty {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Empty {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -199,10 +218,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Empty::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
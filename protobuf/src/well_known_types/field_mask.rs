@@ -21,13 +21,236 @@
 
 //! Generated file from `google/protobuf/field_mask.proto`
 
+///  `FieldMask` represents a set of symbolic field paths, for example:
+///
+///  ```text
+///      paths: "f.a"
+///      paths: "f.b.d"
+///  ```
+///
+///  Here `f` represents a field in some root message, `a` and `b`
+///  fields in the message found in `f`, and `d` a field found in the
+///  message in `f.b`.
+///
+///  Field masks are used to specify a subset of fields that should be
+///  returned by a get operation or modified by an update operation.
+///  Field masks also have a custom JSON encoding (see below).
+///
+///  # Field Masks in Projections
+///
+///  When used in the context of a projection, a response message or
+///  sub-message is filtered by the API to only contain those fields as
+///  specified in the mask. For example, if the mask in the previous
+///  example is applied to a response message as follows:
+///
+///  ```text
+///      f {
+///        a : 22
+///        b {
+///          d : 1
+///          x : 2
+///        }
+///        y : 13
+///      }
+///      z: 8
+///  ```
+///
+///  The result will not contain specific values for fields x,y and z
+///  (their value will be set to the default, and omitted in proto text
+///  output):
+///
+///
+///  ```text
+///      f {
+///        a : 22
+///        b {
+///          d : 1
+///        }
+///      }
+///  ```
+///
+///  A repeated field is not allowed except at the last position of a
+///  paths string.
+///
+///  If a FieldMask object is not present in a get operation, the
+///  operation applies to all fields (as if a FieldMask of all fields
+///  had been specified).
+///
+///  Note that a field mask does not necessarily apply to the
+///  top-level response message. In case of a REST get operation, the
+///  field mask applies directly to the response, but in case of a REST
+///  list operation, the mask instead applies to each individual message
+///  in the returned resource list. In case of a REST custom method,
+///  other definitions may be used. Where the mask applies will be
+///  clearly documented together with its declaration in the API.  In
+///  any case, the effect on the returned resource/resources is required
+///  behavior for APIs.
+///
+///  # Field Masks in Update Operations
+///
+///  A field mask in update operations specifies which fields of the
+///  targeted resource are going to be updated. The API is required
+///  to only change the values of the fields as specified in the mask
+///  and leave the others untouched. If a resource is passed in to
+///  describe the updated values, the API ignores the values of all
+///  fields not covered by the mask.
+///
+///  If a repeated field is specified for an update operation, new values will
+///  be appended to the existing repeated field in the target resource. Note that
+///  a repeated field is only allowed in the last position of a `paths` string.
+///
+///  If a sub-message is specified in the last position of the field mask for an
+///  update operation, then new value will be merged into the existing sub-message
+///  in the target resource.
+///
+///  For example, given the target message:
+///
+///  ```text
+///      f {
+///        b {
+///          d: 1
+///          x: 2
+///        }
+///        c: [1]
+///      }
+///  ```
+///
+///  And an update message:
+///
+///  ```text
+///      f {
+///        b {
+///          d: 10
+///        }
+///        c: [2]
+///      }
+///  ```
+///
+///  then if the field mask is:
+///
+///   paths: \["f.b", "f.c"\]
+///
+///  then the result will be:
+///
+///  ```text
+///      f {
+///        b {
+///          d: 10
+///          x: 2
+///        }
+///        c: [1, 2]
+///      }
+///  ```
+///
+///  An implementation may provide options to override this default behavior for
+///  repeated and message fields.
+///
+///  In order to reset a field's value to the default, the field must
+///  be in the mask and set to the default value in the provided resource.
+///  Hence, in order to reset all fields of a resource, provide a default
+///  instance of the resource and set all fields in the mask, or do
+///  not provide a mask as described below.
+///
+///  If a field mask is not present on update, the operation applies to
+///  all fields (as if a field mask of all fields has been specified).
+///  Note that in the presence of schema evolution, this may mean that
+///  fields the client does not know and has therefore not filled into
+///  the request will be reset to their default. If this is unwanted
+///  behavior, a specific service may require a client to always specify
+///  a field mask, producing an error if not.
+///
+///  As with get operations, the location of the resource which
+///  describes the updated values in the request message depends on the
+///  operation kind. In any case, the effect of the field mask is
+///  required to be honored by the API.
+///
+///  ## Considerations for HTTP REST
+///
+///  The HTTP kind of an update operation which uses a field mask must
+///  be set to PATCH instead of PUT in order to satisfy HTTP semantics
+///  (PUT must only be used for full updates).
+///
+///  # JSON Encoding of Field Masks
+///
+///  In JSON, a field mask is encoded as a single string where paths are
+///  separated by a comma. Fields name in each path are converted
+///  to/from lower-camel naming conventions.
+///
+///  As an example, consider the following message declarations:
+///
+///  ```text
+///      message Profile {
+///        User user = 1;
+///        Photo photo = 2;
+///      }
+///      message User {
+///        string display_name = 1;
+///        string address = 2;
+///      }
+///  ```
+///
+///  In proto a field mask for `Profile` may look as such:
+///
+///  ```text
+///      mask {
+///        paths: "user.display_name"
+///        paths: "photo"
+///      }
+///  ```
+///
+///  In JSON, the same mask is represented as below:
+///
+///  ```text
+///      {
+///        mask: "user.displayName,photo"
+///      }
+///  ```
+///
+///  # Field Masks and Oneof Fields
+///
+///  Field masks treat fields in oneofs just as regular fields. Consider the
+///  following message:
+///
+///  ```text
+///      message SampleMessage {
+///        oneof test_oneof {
+///          string name = 4;
+///          SubMessage sub_message = 9;
+///        }
+///      }
+///  ```
+///
+///  The field mask can be:
+///
+///  ```text
+///      mask {
+///        paths: "name"
+///      }
+///  ```
+///
+///  Or:
+///
+///  ```text
+///      mask {
+///        paths: "sub_message"
+///      }
+///  ```
+///
+///  Note that oneof type names ("test_oneof" in this case) cannot be used in
+///  paths.
+///
+///  ## Field Mask Verification
+///
+///  The implementation of any API method which has a FieldMask type field in the
+///  request should verify the included field paths, and return an
+///  `INVALID_ARGUMENT` error if any path is unmappable.
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.FieldMask)
 pub struct FieldMask {
     // message fields
     ///  The set of field mask paths.
     // @@protoc_insertion_point(field:google.protobuf.FieldMask.paths)
-    pub paths: ::std::vec::Vec<::std::string::String>,
+    pub paths: crate::rt::Vec<crate::rt::String>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.FieldMask.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -35,7 +258,7 @@ pub struct FieldMask {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FieldMask {
+impl<'a> ::core::default::Default for &'a FieldMask {
     fn default() -> &'a FieldMask {
         <FieldMask as crate::Message>::default_instance()
     }
@@ -43,11 +266,11 @@ impl<'a> ::std::default::Default for &'a FieldMask {
 
 impl FieldMask {
     pub fn new() -> FieldMask {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "paths",
             |m: &FieldMask| { &m.paths },
@@ -69,18 +292,23 @@ impl crate::Message for FieldMask {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.paths.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.paths.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.paths.extend(other.paths.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -100,7 +328,15 @@ impl crate::Message for FieldMask {
             os.write_string(1, &v)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.paths.iter().rev() {
+            os.write_string(1, &v);
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -126,7 +362,7 @@ impl crate::Message for FieldMask {
 
     fn default_instance() -> &'static FieldMask {
         static instance: FieldMask = FieldMask {
-            paths: ::std::vec::Vec::new(),
+            paths: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -140,8 +376,8 @@ impl crate::MessageFull for FieldMask {
     }
 }
 
-impl ::std::fmt::Display for FieldMask {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FieldMask {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -354,10 +590,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(FieldMask::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -30,7 +30,7 @@ pub struct SourceContext {
     ///  The path-qualified name of the .proto file that contained the associated
     ///  protobuf element.  For example: `"google/protobuf/source_context.proto"`.
     // @@protoc_insertion_point(field:google.protobuf.SourceContext.file_name)
-    pub file_name: ::std::string::String,
+    pub file_name: crate::rt::String,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.SourceContext.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -38,7 +38,7 @@ pub struct SourceContext {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SourceContext {
+impl<'a> ::core::default::Default for &'a SourceContext {
     fn default() -> &'a SourceContext {
         <SourceContext as crate::Message>::default_instance()
     }
@@ -46,11 +46,11 @@ impl<'a> ::std::default::Default for &'a SourceContext {
 
 impl SourceContext {
     pub fn new() -> SourceContext {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "file_name",
             |m: &SourceContext| { &m.file_name },
@@ -72,8 +72,7 @@ impl crate::Message for SourceContext {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.file_name = is.read_string()?;
@@ -83,7 +82,14 @@ impl crate::Message for SourceContext {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.file_name.is_empty() {
+            self.file_name.clone_from(&other.file_name);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -103,7 +109,15 @@ impl crate::Message for SourceContext {
             os.write_string(1, &self.file_name)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.file_name.is_empty() {
+            os.write_string(1, &self.file_name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -129,7 +143,7 @@ impl crate::Message for SourceContext {
 
     fn default_instance() -> &'static SourceContext {
         static instance: SourceContext = SourceContext {
-            file_name: ::std::string::String::new(),
+            file_name: crate::rt::String::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -143,8 +157,8 @@ impl crate::MessageFull for SourceContext {
     }
 }
 
-impl ::std::fmt::Display for SourceContext {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for SourceContext {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -222,10 +236,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(SourceContext::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -35,7 +35,7 @@ pub struct Struct {
     // message fields
     ///  Unordered map of dynamically typed values.
     // @@protoc_insertion_point(field:google.protobuf.Struct.fields)
-    pub fields: ::std::collections::HashMap<::std::string::String, Value>,
+    pub fields: ::std::collections::HashMap<crate::rt::String, Value>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.Struct.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -43,7 +43,7 @@ pub struct Struct {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Struct {
+impl<'a> ::core::default::Default for &'a Struct {
     fn default() -> &'a Struct {
         <Struct as crate::Message>::default_instance()
     }
@@ -51,11 +51,11 @@ impl<'a> ::std::default::Default for &'a Struct {
 
 impl Struct {
     pub fn new() -> Struct {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
             "fields",
             |m: &Struct| { &m.fields },
@@ -73,12 +73,22 @@ impl crate::Message for Struct {
     const NAME: &'static str = "Struct";
 
     fn is_initialized(&self) -> bool {
+        for v in self.fields.values() {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (k, v) in &self.fields {
+            v.collect_missing_required_fields(&crate::rt::nested_map_field_path(prefix, "fields", k), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     crate::rt::read_map_into::<crate::reflect::types::ProtobufTypeString, crate::reflect::types::ProtobufTypeMessage<Value>>(is, &mut self.fields)?;
@@ -88,7 +98,12 @@ impl crate::Message for Struct {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.fields.extend(other.fields.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -104,7 +119,13 @@ impl crate::Message for Struct {
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         crate::rt::write_map_with_cached_sizes::<crate::reflect::types::ProtobufTypeString, crate::reflect::types::ProtobufTypeMessage<Value>>(1, &self.fields, os)?;
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        crate::rt::write_map_reverse::<crate::reflect::types::ProtobufTypeString, crate::reflect::types::ProtobufTypeMessage<Value>>(1, &self.fields, os)?;
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -140,8 +161,8 @@ impl crate::MessageFull for Struct {
     }
 }
 
-impl ::std::fmt::Display for Struct {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Struct {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -160,7 +181,8 @@ impl crate::reflect::ProtobufValue for Struct {
 // @@protoc_insertion_point(message:google.protobuf.Value)
 pub struct Value {
     // message oneof groups
-    pub kind: ::std::option::Option<value::Kind>,
+    ///  The kind of value.
+    pub kind: ::core::option::Option<value::Kind>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.Value.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -168,7 +190,7 @@ pub struct Value {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Value {
+impl<'a> ::core::default::Default for &'a Value {
     fn default() -> &'a Value {
         <Value as crate::Message>::default_instance()
     }
@@ -176,105 +198,105 @@ impl<'a> ::std::default::Default for &'a Value {
 
 impl Value {
     pub fn new() -> Value {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     // .google.protobuf.NullValue null_value = 1;
 
     pub fn null_value(&self) -> NullValue {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::null_value(v)) => crate::EnumOrUnknown::enum_value_or_default(&v),
+            ::core::option::Option::Some(value::Kind::null_value(v)) => crate::EnumOrUnknown::enum_value_or_default(&v),
             _ => NullValue::NULL_VALUE,
         }
     }
 
     pub fn clear_null_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_null_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::null_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::null_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_null_value(&mut self, v: NullValue) {
-        self.kind = ::std::option::Option::Some(value::Kind::null_value(crate::EnumOrUnknown::new(v)))
+        self.kind = ::core::option::Option::Some(value::Kind::null_value(crate::EnumOrUnknown::new(v)))
     }
 
     // double number_value = 2;
 
     pub fn number_value(&self) -> f64 {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::number_value(v)) => v,
+            ::core::option::Option::Some(value::Kind::number_value(v)) => v,
             _ => 0.,
         }
     }
 
     pub fn clear_number_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_number_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::number_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::number_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_number_value(&mut self, v: f64) {
-        self.kind = ::std::option::Option::Some(value::Kind::number_value(v))
+        self.kind = ::core::option::Option::Some(value::Kind::number_value(v))
     }
 
     // string string_value = 3;
 
     pub fn string_value(&self) -> &str {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::string_value(ref v)) => v,
+            ::core::option::Option::Some(value::Kind::string_value(ref v)) => v,
             _ => "",
         }
     }
 
     pub fn clear_string_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_string_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::string_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::string_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_string_value(&mut self, v: ::std::string::String) {
-        self.kind = ::std::option::Option::Some(value::Kind::string_value(v))
+    pub fn set_string_value(&mut self, v: crate::rt::String) {
+        self.kind = ::core::option::Option::Some(value::Kind::string_value(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_string_value(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(value::Kind::string_value(_)) = self.kind {
+    pub fn mut_string_value(&mut self) -> &mut crate::rt::String {
+        if let ::core::option::Option::Some(value::Kind::string_value(_)) = self.kind {
         } else {
-            self.kind = ::std::option::Option::Some(value::Kind::string_value(::std::string::String::new()));
+            self.kind = ::core::option::Option::Some(value::Kind::string_value(crate::rt::String::new()));
         }
         match self.kind {
-            ::std::option::Option::Some(value::Kind::string_value(ref mut v)) => v,
+            ::core::option::Option::Some(value::Kind::string_value(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_string_value(&mut self) -> ::std::string::String {
+    pub fn take_string_value(&mut self) -> crate::rt::String {
         if self.has_string_value() {
             match self.kind.take() {
-                ::std::option::Option::Some(value::Kind::string_value(v)) => v,
+                ::core::option::Option::Some(value::Kind::string_value(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ::std::string::String::new()
+            crate::rt::String::new()
         }
     }
 
@@ -282,60 +304,60 @@ impl Value {
 
     pub fn bool_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::bool_value(v)) => v,
+            ::core::option::Option::Some(value::Kind::bool_value(v)) => v,
             _ => false,
         }
     }
 
     pub fn clear_bool_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_bool_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::bool_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::bool_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_bool_value(&mut self, v: bool) {
-        self.kind = ::std::option::Option::Some(value::Kind::bool_value(v))
+        self.kind = ::core::option::Option::Some(value::Kind::bool_value(v))
     }
 
     // .google.protobuf.Struct struct_value = 5;
 
     pub fn struct_value(&self) -> &Struct {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::struct_value(ref v)) => v,
+            ::core::option::Option::Some(value::Kind::struct_value(ref v)) => v,
             _ => <Struct as crate::Message>::default_instance(),
         }
     }
 
     pub fn clear_struct_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_struct_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::struct_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::struct_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_struct_value(&mut self, v: Struct) {
-        self.kind = ::std::option::Option::Some(value::Kind::struct_value(v))
+        self.kind = ::core::option::Option::Some(value::Kind::struct_value(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_struct_value(&mut self) -> &mut Struct {
-        if let ::std::option::Option::Some(value::Kind::struct_value(_)) = self.kind {
+        if let ::core::option::Option::Some(value::Kind::struct_value(_)) = self.kind {
         } else {
-            self.kind = ::std::option::Option::Some(value::Kind::struct_value(Struct::new()));
+            self.kind = ::core::option::Option::Some(value::Kind::struct_value(Struct::new()));
         }
         match self.kind {
-            ::std::option::Option::Some(value::Kind::struct_value(ref mut v)) => v,
+            ::core::option::Option::Some(value::Kind::struct_value(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -344,7 +366,7 @@ impl Value {
     pub fn take_struct_value(&mut self) -> Struct {
         if self.has_struct_value() {
             match self.kind.take() {
-                ::std::option::Option::Some(value::Kind::struct_value(v)) => v,
+                ::core::option::Option::Some(value::Kind::struct_value(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -356,35 +378,35 @@ impl Value {
 
     pub fn list_value(&self) -> &ListValue {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::list_value(ref v)) => v,
+            ::core::option::Option::Some(value::Kind::list_value(ref v)) => v,
             _ => <ListValue as crate::Message>::default_instance(),
         }
     }
 
     pub fn clear_list_value(&mut self) {
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
     }
 
     pub fn has_list_value(&self) -> bool {
         match self.kind {
-            ::std::option::Option::Some(value::Kind::list_value(..)) => true,
+            ::core::option::Option::Some(value::Kind::list_value(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
     pub fn set_list_value(&mut self, v: ListValue) {
-        self.kind = ::std::option::Option::Some(value::Kind::list_value(v))
+        self.kind = ::core::option::Option::Some(value::Kind::list_value(v))
     }
 
     // Mutable pointer to the field.
     pub fn mut_list_value(&mut self) -> &mut ListValue {
-        if let ::std::option::Option::Some(value::Kind::list_value(_)) = self.kind {
+        if let ::core::option::Option::Some(value::Kind::list_value(_)) = self.kind {
         } else {
-            self.kind = ::std::option::Option::Some(value::Kind::list_value(ListValue::new()));
+            self.kind = ::core::option::Option::Some(value::Kind::list_value(ListValue::new()));
         }
         match self.kind {
-            ::std::option::Option::Some(value::Kind::list_value(ref mut v)) => v,
+            ::core::option::Option::Some(value::Kind::list_value(ref mut v)) => v,
             _ => panic!(),
         }
     }
@@ -393,7 +415,7 @@ impl Value {
     pub fn take_list_value(&mut self) -> ListValue {
         if self.has_list_value() {
             match self.kind.take() {
-                ::std::option::Option::Some(value::Kind::list_value(v)) => v,
+                ::core::option::Option::Some(value::Kind::list_value(v)) => v,
                 _ => panic!(),
             }
         } else {
@@ -402,16 +424,17 @@ impl Value {
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = crate::rt::Vec::with_capacity(6);
         fields.push(crate::reflect::rt::v2::make_oneof_enum_accessors::<_, _>(
             "null_value",
             |message: &Value| match &message.kind {
-                ::std::option::Option::Some(value::Kind::null_value(e)) => ::std::option::Option::Some(*e),
-                _ => ::std::option::Option::None,
+                ::core::option::Option::Some(value::Kind::null_value(e)) => ::core::option::Option::Some(*e),
+                _ => ::core::option::Option::None,
             },
             |message: &mut Value, e: crate::EnumOrUnknown<NullValue>| {
-                message.kind = ::std::option::Option::Some(value::Kind::null_value(e));
+                message.kind = ::core::option::Option::Some(value::Kind::null_value(e));
             },
+            Value::clear_null_value,
             NullValue::NULL_VALUE,
         ));
         fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
@@ -419,18 +442,21 @@ impl Value {
             Value::has_number_value,
             Value::number_value,
             Value::set_number_value,
+            Value::clear_number_value,
         ));
         fields.push(crate::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
             "string_value",
             Value::has_string_value,
             Value::string_value,
             Value::set_string_value,
+            Value::clear_string_value,
         ));
         fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
             "bool_value",
             Value::has_bool_value,
             Value::bool_value,
             Value::set_bool_value,
+            Value::clear_bool_value,
         ));
         fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Struct>(
             "struct_value",
@@ -438,6 +464,7 @@ impl Value {
             Value::struct_value,
             Value::mut_struct_value,
             Value::set_struct_value,
+            Value::clear_struct_value,
         ));
         fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListValue>(
             "list_value",
@@ -445,6 +472,7 @@ impl Value {
             Value::list_value,
             Value::mut_list_value,
             Value::set_list_value,
+            Value::clear_list_value,
         ));
         crate::reflect::GeneratedMessageDescriptorData::new_2::<Value>(
             "Value",
@@ -471,41 +499,74 @@ impl crate::Message for Value {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        if let Some(value::Kind::struct_value(ref v)) = self.kind {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "struct_value"), missing);
+        }
+        if let Some(value::Kind::list_value(ref v)) = self.kind {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "list_value"), missing);
+        }
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::null_value(is.read_enum_or_unknown()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::null_value(is.read_enum_or_unknown()?));
                 },
                 17 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::number_value(is.read_double()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::number_value(is.read_double()?));
                 },
                 26 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::string_value(is.read_string()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::string_value(is.read_string()?));
                 },
                 32 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::bool_value(is.read_bool()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::bool_value(is.read_bool()?));
                 },
                 42 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::struct_value(is.read_message()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::struct_value(crate::rt::read_message_no_check(is)?));
                 },
                 50 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::list_value(is.read_message()?));
+                    self.kind = ::core::option::Option::Some(value::Kind::list_value(crate::rt::read_message_no_check(is)?));
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if let ::core::option::Option::Some(ref v) = other.kind {
+            match v {
+                &value::Kind::struct_value(ref v) => {
+                    if let ::core::option::Option::Some(value::Kind::struct_value(ref mut m)) = self.kind {
+                        m.merge_from_message(v);
+                    } else {
+                        self.kind = ::core::option::Option::Some(value::Kind::struct_value(v.clone()));
+                    }
+                },
+                &value::Kind::list_value(ref v) => {
+                    if let ::core::option::Option::Some(value::Kind::list_value(ref mut m)) = self.kind {
+                        m.merge_from_message(v);
+                    } else {
+                        self.kind = ::core::option::Option::Some(value::Kind::list_value(v.clone()));
+                    }
+                },
+                _ => {
+                    self.kind = ::core::option::Option::Some(v.clone());
+                },
+            };
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.kind {
+        if let ::core::option::Option::Some(ref v) = self.kind {
             match v {
                 &value::Kind::null_value(v) => {
                     my_size += crate::rt::enum_or_unknown_size(1, v);
@@ -535,7 +596,7 @@ impl crate::Message for Value {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
-        if let ::std::option::Option::Some(ref v) = self.kind {
+        if let ::core::option::Option::Some(ref v) = self.kind {
             match v {
                 &value::Kind::null_value(v) => {
                     os.write_enum(1, crate::EnumOrUnknown::value(&v))?;
@@ -558,7 +619,34 @@ impl crate::Message for Value {
             };
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let ::core::option::Option::Some(ref v) = self.kind {
+            match v {
+                &value::Kind::null_value(v) => {
+                    os.write_enum(1, crate::EnumOrUnknown::value(&v));
+                },
+                &value::Kind::number_value(v) => {
+                    os.write_double(2, v);
+                },
+                &value::Kind::string_value(ref v) => {
+                    os.write_string(3, v);
+                },
+                &value::Kind::bool_value(v) => {
+                    os.write_bool(4, v);
+                },
+                &value::Kind::struct_value(ref v) => {
+                    crate::rt::write_message_field_reverse(5, v, os)?;
+                },
+                &value::Kind::list_value(ref v) => {
+                    crate::rt::write_message_field_reverse(6, v, os)?;
+                },
+            };
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -578,18 +666,18 @@ impl crate::Message for Value {
     }
 
     fn clear(&mut self) {
-        self.kind = ::std::option::Option::None;
-        self.kind = ::std::option::Option::None;
-        self.kind = ::std::option::Option::None;
-        self.kind = ::std::option::Option::None;
-        self.kind = ::std::option::Option::None;
-        self.kind = ::std::option::Option::None;
+        self.kind = ::core::option::Option::None;
+        self.kind = ::core::option::Option::None;
+        self.kind = ::core::option::Option::None;
+        self.kind = ::core::option::Option::None;
+        self.kind = ::core::option::Option::None;
+        self.kind = ::core::option::Option::None;
         self.unknown_fields.clear();
     }
 
     fn default_instance() -> &'static Value {
         static instance: Value = Value {
-            kind: ::std::option::Option::None,
+            kind: ::core::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -603,8 +691,8 @@ impl crate::MessageFull for Value {
     }
 }
 
-impl ::std::fmt::Display for Value {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -616,21 +704,28 @@ impl crate::reflect::ProtobufValue for Value {
 /// Nested message and enums of message `Value`
 pub mod value {
 
+    ///  The kind of value.
     #[derive(Clone,PartialEq,Debug)]
     #[non_exhaustive]
     // @@protoc_insertion_point(oneof:google.protobuf.Value.kind)
     pub enum Kind {
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.null_value)
+        ///  Represents a null value.
         null_value(crate::EnumOrUnknown<super::NullValue>),
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.number_value)
+        ///  Represents a double value.
         number_value(f64),
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.string_value)
-        string_value(::std::string::String),
+        ///  Represents a string value.
+        string_value(crate::rt::String),
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.bool_value)
+        ///  Represents a boolean value.
         bool_value(bool),
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.struct_value)
+        ///  Represents a structured value.
         struct_value(super::Struct),
         // @@protoc_insertion_point(oneof_field:google.protobuf.Value.list_value)
+        ///  Represents a repeated `Value`.
         list_value(super::ListValue),
     }
 
@@ -647,7 +742,7 @@ pub struct ListValue {
     // message fields
     ///  Repeated field of dynamically typed values.
     // @@protoc_insertion_point(field:google.protobuf.ListValue.values)
-    pub values: ::std::vec::Vec<Value>,
+    pub values: crate::rt::Vec<Value>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.ListValue.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -655,7 +750,7 @@ pub struct ListValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ListValue {
+impl<'a> ::core::default::Default for &'a ListValue {
     fn default() -> &'a ListValue {
         <ListValue as crate::Message>::default_instance()
     }
@@ -663,11 +758,11 @@ impl<'a> ::std::default::Default for &'a ListValue {
 
 impl ListValue {
     pub fn new() -> ListValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "values",
             |m: &ListValue| { &m.values },
@@ -693,19 +788,30 @@ impl crate::Message for ListValue {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.values.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "values", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.values.push(is.read_message()?);
+                    self.values.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.values.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.values.extend(other.values.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -726,7 +832,15 @@ impl crate::Message for ListValue {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.values.iter().rev() {
+            crate::rt::write_message_field_reverse(1, v, os)?;
+        };
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -752,7 +866,7 @@ impl crate::Message for ListValue {
 
     fn default_instance() -> &'static ListValue {
         static instance: ListValue = ListValue {
-            values: ::std::vec::Vec::new(),
+            values: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -766,8 +880,8 @@ impl crate::MessageFull for ListValue {
     }
 }
 
-impl ::std::fmt::Display for ListValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for ListValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -783,6 +897,7 @@ impl crate::reflect::ProtobufValue for ListValue {
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:google.protobuf.NullValue)
 pub enum NullValue {
+    ///  Null value.
     // @@protoc_insertion_point(enum_value:google.protobuf.NullValue.NULL_VALUE)
     NULL_VALUE = 0,
 }
@@ -792,10 +907,10 @@ impl crate::Enum for NullValue {
         *self as i32
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<NullValue> {
+    fn from_i32(value: i32) -> ::core::option::Option<NullValue> {
         match value {
-            0 => ::std::option::Option::Some(NullValue::NULL_VALUE),
-            _ => ::std::option::Option::None
+            0 => ::core::option::Option::Some(NullValue::NULL_VALUE),
+            _ => ::core::option::Option::None
         }
     }
 
@@ -813,7 +928,7 @@ impl crate::EnumFull for NullValue {
     }
 }
 
-impl ::std::default::Default for NullValue {
+impl ::core::default::Default for NullValue {
     fn default() -> Self {
         NullValue::NULL_VALUE
     }
@@ -955,12 +1070,12 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Struct::generated_message_descriptor_data());
         messages.push(Value::generated_message_descriptor_data());
         messages.push(ListValue::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         enums.push(NullValue::generated_enum_descriptor_data());
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
@@ -21,6 +21,119 @@
 
 //! Generated file from `google/protobuf/timestamp.proto`
 
+///  A Timestamp represents a point in time independent of any time zone or local
+///  calendar, encoded as a count of seconds and fractions of seconds at
+///  nanosecond resolution. The count is relative to an epoch at UTC midnight on
+///  January 1, 1970, in the proleptic Gregorian calendar which extends the
+///  Gregorian calendar backwards to year one.
+///
+///  All minutes are 60 seconds long. Leap seconds are "smeared" so that no leap
+///  second table is needed for interpretation, using a \[24-hour linear
+///  smear\](<https://developers.google.com/time/smear)>.
+///
+///  The range is from 0001-01-01T00:00:00Z to 9999-12-31T23:59:59.999999999Z. By
+///  restricting to that range, we ensure that we can convert to and from \[RFC
+///  3339\](<https://www.ietf.org/rfc/rfc3339.txt)> date strings.
+///
+///  # Examples
+///
+///  Example 1: Compute Timestamp from POSIX `time()`.
+///
+///  ```text
+///      Timestamp timestamp;
+///      timestamp.set_seconds(time(NULL));
+///      timestamp.set_nanos(0);
+///  ```
+///
+///  Example 2: Compute Timestamp from POSIX `gettimeofday()`.
+///
+///  ```text
+///      struct timeval tv;
+///      gettimeofday(&tv, NULL);
+///  ```
+///
+///  ```text
+///      Timestamp timestamp;
+///      timestamp.set_seconds(tv.tv_sec);
+///      timestamp.set_nanos(tv.tv_usec * 1000);
+///  ```
+///
+///  Example 3: Compute Timestamp from Win32 `GetSystemTimeAsFileTime()`.
+///
+///  ```text
+///      FILETIME ft;
+///      GetSystemTimeAsFileTime(&ft);
+///      UINT64 ticks = (((UINT64)ft.dwHighDateTime) << 32) | ft.dwLowDateTime;
+///  ```
+///
+///  ```text
+///      // A Windows tick is 100 nanoseconds. Windows epoch 1601-01-01T00:00:00Z
+///      // is 11644473600 seconds before Unix epoch 1970-01-01T00:00:00Z.
+///      Timestamp timestamp;
+///      timestamp.set_seconds((INT64) ((ticks / 10000000) - 11644473600LL));
+///      timestamp.set_nanos((INT32) ((ticks % 10000000) * 100));
+///  ```
+///
+///  Example 4: Compute Timestamp from Java `System.currentTimeMillis()`.
+///
+///  ```text
+///      long millis = System.currentTimeMillis();
+///  ```
+///
+///  ```text
+///      Timestamp timestamp = Timestamp.newBuilder().setSeconds(millis / 1000)
+///          .setNanos((int) ((millis % 1000) * 1000000)).build();
+///  ```
+///
+///
+///  Example 5: Compute Timestamp from Java `Instant.now()`.
+///
+///  ```text
+///      Instant now = Instant.now();
+///  ```
+///
+///  ```text
+///      Timestamp timestamp =
+///          Timestamp.newBuilder().setSeconds(now.getEpochSecond())
+///              .setNanos(now.getNano()).build();
+///  ```
+///
+///
+///  Example 6: Compute Timestamp from current time in Python.
+///
+///  ```text
+///      timestamp = Timestamp()
+///      timestamp.GetCurrentTime()
+///  ```
+///
+///  # JSON Mapping
+///
+///  In JSON format, the Timestamp type is encoded as a string in the
+///  \[RFC 3339\](<https://www.ietf.org/rfc/rfc3339.txt)> format. That is, the
+///  format is "{year}-{month}-{day}T{hour}:{min}:{sec}\[.{frac_sec}\]Z"
+///  where {year} is always expressed using four digits while {month}, {day},
+///  {hour}, {min}, and {sec} are zero-padded to two digits each. The fractional
+///  seconds, which can go up to 9 digits (i.e. up to 1 nanosecond resolution),
+///  are optional. The "Z" suffix indicates the timezone ("UTC"); the timezone
+///  is required. A proto3 JSON serializer should always use UTC (as indicated by
+///  "Z") when printing the Timestamp type and a proto3 JSON parser should be
+///  able to accept both UTC and other timezones (as indicated by an offset).
+///
+///  For example, "2017-01-15T01:30:15.01Z" encodes 15.01 seconds past
+///  01:30 UTC on January 15, 2017.
+///
+///  In JavaScript, one can convert a Date object to this format using the
+///  standard
+///  \[toISOString()\](<https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString)>
+///  method. In Python, a standard `datetime.datetime` object can be converted
+///  to this format using
+///  \[`strftime`\](<https://docs.python.org/2/library/time.html#time.strftime)> with
+///  the time format spec '%Y-%m-%dT%H:%M:%S.%fZ'. Likewise, in Java, one can use
+///  the Joda Time's \[`ISODateTimeFormat.dateTime()`\](
+///  <http://www.joda.org/joda-time/apidocs/org/joda/time/format/ISODateTimeFormat.html#dateTime%2D%2D>
+///  ) to obtain a formatter capable of generating timestamps in this format.
+///
+///
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:google.protobuf.Timestamp)
 pub struct Timestamp {
@@ -43,7 +156,7 @@ pub struct Timestamp {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Timestamp {
+impl<'a> ::core::default::Default for &'a Timestamp {
     fn default() -> &'a Timestamp {
         <Timestamp as crate::Message>::default_instance()
     }
@@ -51,11 +164,11 @@ impl<'a> ::std::default::Default for &'a Timestamp {
 
 impl Timestamp {
     pub fn new() -> Timestamp {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "seconds",
             |m: &Timestamp| { &m.seconds },
@@ -82,8 +195,7 @@ impl crate::Message for Timestamp {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.seconds = is.read_int64()?;
@@ -96,7 +208,17 @@ impl crate::Message for Timestamp {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.seconds != 0 {
+            self.seconds.clone_from(&other.seconds);
+        }
+        if other.nanos != 0 {
+            self.nanos.clone_from(&other.nanos);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -122,7 +244,18 @@ impl crate::Message for Timestamp {
             os.write_int32(2, self.nanos)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.nanos != 0 {
+            os.write_int32(2, self.nanos);
+        }
+        if self.seconds != 0 {
+            os.write_int64(1, self.seconds);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -164,8 +297,8 @@ impl crate::MessageFull for Timestamp {
     }
 }
 
-impl ::std::fmt::Display for Timestamp {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -327,10 +460,10 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Timestamp::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
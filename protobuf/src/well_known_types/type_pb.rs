@@ -28,16 +28,16 @@ pub struct Type {
     // message fields
     ///  The fully qualified message name.
     // @@protoc_insertion_point(field:google.protobuf.Type.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  The list of fields.
     // @@protoc_insertion_point(field:google.protobuf.Type.fields)
-    pub fields: ::std::vec::Vec<Field>,
+    pub fields: crate::rt::Vec<Field>,
     ///  The list of types appearing in `oneof` definitions in this type.
     // @@protoc_insertion_point(field:google.protobuf.Type.oneofs)
-    pub oneofs: ::std::vec::Vec<::std::string::String>,
+    pub oneofs: crate::rt::Vec<crate::rt::String>,
     ///  The protocol buffer options.
     // @@protoc_insertion_point(field:google.protobuf.Type.options)
-    pub options: ::std::vec::Vec<Option>,
+    pub options: crate::rt::Vec<Option>,
     ///  The source context.
     // @@protoc_insertion_point(field:google.protobuf.Type.source_context)
     pub source_context: crate::MessageField<crate::well_known_types::SourceContext>,
@@ -51,7 +51,7 @@ pub struct Type {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Type {
+impl<'a> ::core::default::Default for &'a Type {
     fn default() -> &'a Type {
         <Type as crate::Message>::default_instance()
     }
@@ -59,11 +59,11 @@ impl<'a> ::std::default::Default for &'a Type {
 
 impl Type {
     pub fn new() -> Type {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = crate::rt::Vec::with_capacity(6);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Type| { &m.name },
@@ -124,21 +124,35 @@ impl crate::Message for Type {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.fields.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "fields", i), missing);
+        };
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+        for v in &self.source_context {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "source_context"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
                 },
                 18 => {
-                    self.fields.push(is.read_message()?);
+                    self.fields.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.fields.len())?;
                 },
                 26 => {
                     self.oneofs.push(is.read_string()?);
+                    crate::rt::check_repeated_len(is, self.oneofs.len())?;
                 },
                 34 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 42 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.source_context)?;
@@ -151,7 +165,23 @@ impl crate::Message for Type {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        self.fields.extend(other.fields.iter().cloned());
+        self.oneofs.extend(other.oneofs.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if let Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            self.syntax.clone_from(&other.syntax);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -204,7 +234,30 @@ impl crate::Message for Type {
             os.write_enum(6, crate::EnumOrUnknown::value(&self.syntax))?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            os.write_enum(6, crate::EnumOrUnknown::value(&self.syntax));
+        }
+        if let Some(v) = self.source_context.as_ref() {
+            crate::rt::write_message_field_reverse(5, v, os)?;
+        }
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        };
+        for v in self.oneofs.iter().rev() {
+            os.write_string(3, &v);
+        };
+        for v in self.fields.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -235,10 +288,10 @@ impl crate::Message for Type {
 
     fn default_instance() -> &'static Type {
         static instance: Type = Type {
-            name: ::std::string::String::new(),
-            fields: ::std::vec::Vec::new(),
-            oneofs: ::std::vec::Vec::new(),
-            options: ::std::vec::Vec::new(),
+            name: crate::rt::String::new(),
+            fields: crate::rt::Vec::new(),
+            oneofs: crate::rt::Vec::new(),
+            options: crate::rt::Vec::new(),
             source_context: crate::MessageField::none(),
             syntax: crate::EnumOrUnknown::from_i32(0),
             unknown_fields: crate::UnknownFields::new(),
@@ -254,8 +307,8 @@ impl crate::MessageFull for Type {
     }
 }
 
-impl ::std::fmt::Display for Type {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Type {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -280,11 +333,11 @@ pub struct Field {
     pub number: i32,
     ///  The field name.
     // @@protoc_insertion_point(field:google.protobuf.Field.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  The field type URL, without the scheme, for message or enumeration
     ///  types. Example: `"type.googleapis.com/google.protobuf.Timestamp"`.
     // @@protoc_insertion_point(field:google.protobuf.Field.type_url)
-    pub type_url: ::std::string::String,
+    pub type_url: crate::rt::String,
     ///  The index of the field type in `Type.oneofs`, for message or enumeration
     ///  types. The first type has index 1; zero means the type is not in the list.
     // @@protoc_insertion_point(field:google.protobuf.Field.oneof_index)
@@ -294,13 +347,13 @@ pub struct Field {
     pub packed: bool,
     ///  The protocol buffer options.
     // @@protoc_insertion_point(field:google.protobuf.Field.options)
-    pub options: ::std::vec::Vec<Option>,
+    pub options: crate::rt::Vec<Option>,
     ///  The field JSON name.
     // @@protoc_insertion_point(field:google.protobuf.Field.json_name)
-    pub json_name: ::std::string::String,
+    pub json_name: crate::rt::String,
     ///  The string value of the default value of this field. Proto2 syntax only.
     // @@protoc_insertion_point(field:google.protobuf.Field.default_value)
-    pub default_value: ::std::string::String,
+    pub default_value: crate::rt::String,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.Field.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -308,7 +361,7 @@ pub struct Field {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Field {
+impl<'a> ::core::default::Default for &'a Field {
     fn default() -> &'a Field {
         <Field as crate::Message>::default_instance()
     }
@@ -316,11 +369,11 @@ impl<'a> ::std::default::Default for &'a Field {
 
 impl Field {
     pub fn new() -> Field {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(10);
+        let mut fields = crate::rt::Vec::with_capacity(10);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "kind",
             |m: &Field| { &m.kind },
@@ -391,9 +444,14 @@ impl crate::Message for Field {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.kind = is.read_enum_or_unknown()?;
@@ -417,7 +475,8 @@ impl crate::Message for Field {
                     self.packed = is.read_bool()?;
                 },
                 74 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 82 => {
                     self.json_name = is.read_string()?;
@@ -430,7 +489,39 @@ impl crate::Message for Field {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.kind != crate::EnumOrUnknown::new(field::Kind::TYPE_UNKNOWN) {
+            self.kind.clone_from(&other.kind);
+        }
+        if other.cardinality != crate::EnumOrUnknown::new(field::Cardinality::CARDINALITY_UNKNOWN) {
+            self.cardinality.clone_from(&other.cardinality);
+        }
+        if other.number != 0 {
+            self.number.clone_from(&other.number);
+        }
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if !other.type_url.is_empty() {
+            self.type_url.clone_from(&other.type_url);
+        }
+        if other.oneof_index != 0 {
+            self.oneof_index.clone_from(&other.oneof_index);
+        }
+        if other.packed != false {
+            self.packed.clone_from(&other.packed);
+        }
+        self.options.extend(other.options.iter().cloned());
+        if !other.json_name.is_empty() {
+            self.json_name.clone_from(&other.json_name);
+        }
+        if !other.default_value.is_empty() {
+            self.default_value.clone_from(&other.default_value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -505,7 +596,42 @@ impl crate::Message for Field {
             os.write_string(11, &self.default_value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.default_value.is_empty() {
+            os.write_string(11, &self.default_value);
+        }
+        if !self.json_name.is_empty() {
+            os.write_string(10, &self.json_name);
+        }
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(9, v, os)?;
+        };
+        if self.packed != false {
+            os.write_bool(8, self.packed);
+        }
+        if self.oneof_index != 0 {
+            os.write_int32(7, self.oneof_index);
+        }
+        if !self.type_url.is_empty() {
+            os.write_string(6, &self.type_url);
+        }
+        if !self.name.is_empty() {
+            os.write_string(4, &self.name);
+        }
+        if self.number != 0 {
+            os.write_int32(3, self.number);
+        }
+        if self.cardinality != crate::EnumOrUnknown::new(field::Cardinality::CARDINALITY_UNKNOWN) {
+            os.write_enum(2, crate::EnumOrUnknown::value(&self.cardinality));
+        }
+        if self.kind != crate::EnumOrUnknown::new(field::Kind::TYPE_UNKNOWN) {
+            os.write_enum(1, crate::EnumOrUnknown::value(&self.kind));
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -543,13 +669,13 @@ impl crate::Message for Field {
             kind: crate::EnumOrUnknown::from_i32(0),
             cardinality: crate::EnumOrUnknown::from_i32(0),
             number: 0,
-            name: ::std::string::String::new(),
-            type_url: ::std::string::String::new(),
+            name: crate::rt::String::new(),
+            type_url: crate::rt::String::new(),
             oneof_index: 0,
             packed: false,
-            options: ::std::vec::Vec::new(),
-            json_name: ::std::string::String::new(),
-            default_value: ::std::string::String::new(),
+            options: crate::rt::Vec::new(),
+            json_name: crate::rt::String::new(),
+            default_value: crate::rt::String::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -563,8 +689,8 @@ impl crate::MessageFull for Field {
     }
 }
 
-impl ::std::fmt::Display for Field {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Field {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -579,42 +705,61 @@ pub mod field {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.Field.Kind)
     pub enum Kind {
+        ///  Field type unknown.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_UNKNOWN)
         TYPE_UNKNOWN = 0,
+        ///  Field type double.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_DOUBLE)
         TYPE_DOUBLE = 1,
+        ///  Field type float.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_FLOAT)
         TYPE_FLOAT = 2,
+        ///  Field type int64.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_INT64)
         TYPE_INT64 = 3,
+        ///  Field type uint64.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_UINT64)
         TYPE_UINT64 = 4,
+        ///  Field type int32.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_INT32)
         TYPE_INT32 = 5,
+        ///  Field type fixed64.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_FIXED64)
         TYPE_FIXED64 = 6,
+        ///  Field type fixed32.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_FIXED32)
         TYPE_FIXED32 = 7,
+        ///  Field type bool.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_BOOL)
         TYPE_BOOL = 8,
+        ///  Field type string.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_STRING)
         TYPE_STRING = 9,
+        ///  Field type group. Proto2 syntax only, and deprecated.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_GROUP)
         TYPE_GROUP = 10,
+        ///  Field type message.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_MESSAGE)
         TYPE_MESSAGE = 11,
+        ///  Field type bytes.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_BYTES)
         TYPE_BYTES = 12,
+        ///  Field type uint32.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_UINT32)
         TYPE_UINT32 = 13,
+        ///  Field type enum.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_ENUM)
         TYPE_ENUM = 14,
+        ///  Field type sfixed32.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_SFIXED32)
         TYPE_SFIXED32 = 15,
+        ///  Field type sfixed64.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_SFIXED64)
         TYPE_SFIXED64 = 16,
+        ///  Field type sint32.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_SINT32)
         TYPE_SINT32 = 17,
+        ///  Field type sint64.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Kind.TYPE_SINT64)
         TYPE_SINT64 = 18,
     }
@@ -624,28 +769,28 @@ pub mod field {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+        fn from_i32(value: i32) -> ::core::option::Option<Kind> {
             match value {
-                0 => ::std::option::Option::Some(Kind::TYPE_UNKNOWN),
-                1 => ::std::option::Option::Some(Kind::TYPE_DOUBLE),
-                2 => ::std::option::Option::Some(Kind::TYPE_FLOAT),
-                3 => ::std::option::Option::Some(Kind::TYPE_INT64),
-                4 => ::std::option::Option::Some(Kind::TYPE_UINT64),
-                5 => ::std::option::Option::Some(Kind::TYPE_INT32),
-                6 => ::std::option::Option::Some(Kind::TYPE_FIXED64),
-                7 => ::std::option::Option::Some(Kind::TYPE_FIXED32),
-                8 => ::std::option::Option::Some(Kind::TYPE_BOOL),
-                9 => ::std::option::Option::Some(Kind::TYPE_STRING),
-                10 => ::std::option::Option::Some(Kind::TYPE_GROUP),
-                11 => ::std::option::Option::Some(Kind::TYPE_MESSAGE),
-                12 => ::std::option::Option::Some(Kind::TYPE_BYTES),
-                13 => ::std::option::Option::Some(Kind::TYPE_UINT32),
-                14 => ::std::option::Option::Some(Kind::TYPE_ENUM),
-                15 => ::std::option::Option::Some(Kind::TYPE_SFIXED32),
-                16 => ::std::option::Option::Some(Kind::TYPE_SFIXED64),
-                17 => ::std::option::Option::Some(Kind::TYPE_SINT32),
-                18 => ::std::option::Option::Some(Kind::TYPE_SINT64),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(Kind::TYPE_UNKNOWN),
+                1 => ::core::option::Option::Some(Kind::TYPE_DOUBLE),
+                2 => ::core::option::Option::Some(Kind::TYPE_FLOAT),
+                3 => ::core::option::Option::Some(Kind::TYPE_INT64),
+                4 => ::core::option::Option::Some(Kind::TYPE_UINT64),
+                5 => ::core::option::Option::Some(Kind::TYPE_INT32),
+                6 => ::core::option::Option::Some(Kind::TYPE_FIXED64),
+                7 => ::core::option::Option::Some(Kind::TYPE_FIXED32),
+                8 => ::core::option::Option::Some(Kind::TYPE_BOOL),
+                9 => ::core::option::Option::Some(Kind::TYPE_STRING),
+                10 => ::core::option::Option::Some(Kind::TYPE_GROUP),
+                11 => ::core::option::Option::Some(Kind::TYPE_MESSAGE),
+                12 => ::core::option::Option::Some(Kind::TYPE_BYTES),
+                13 => ::core::option::Option::Some(Kind::TYPE_UINT32),
+                14 => ::core::option::Option::Some(Kind::TYPE_ENUM),
+                15 => ::core::option::Option::Some(Kind::TYPE_SFIXED32),
+                16 => ::core::option::Option::Some(Kind::TYPE_SFIXED64),
+                17 => ::core::option::Option::Some(Kind::TYPE_SINT32),
+                18 => ::core::option::Option::Some(Kind::TYPE_SINT64),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -681,7 +826,7 @@ pub mod field {
         }
     }
 
-    impl ::std::default::Default for Kind {
+    impl ::core::default::Default for Kind {
         fn default() -> Self {
             Kind::TYPE_UNKNOWN
         }
@@ -697,12 +842,16 @@ pub mod field {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     // @@protoc_insertion_point(enum:google.protobuf.Field.Cardinality)
     pub enum Cardinality {
+        ///  For fields with unknown cardinality.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Cardinality.CARDINALITY_UNKNOWN)
         CARDINALITY_UNKNOWN = 0,
+        ///  For optional fields.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Cardinality.CARDINALITY_OPTIONAL)
         CARDINALITY_OPTIONAL = 1,
+        ///  For required fields. Proto2 syntax only.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Cardinality.CARDINALITY_REQUIRED)
         CARDINALITY_REQUIRED = 2,
+        ///  For repeated fields.
         // @@protoc_insertion_point(enum_value:google.protobuf.Field.Cardinality.CARDINALITY_REPEATED)
         CARDINALITY_REPEATED = 3,
     }
@@ -712,13 +861,13 @@ pub mod field {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Cardinality> {
+        fn from_i32(value: i32) -> ::core::option::Option<Cardinality> {
             match value {
-                0 => ::std::option::Option::Some(Cardinality::CARDINALITY_UNKNOWN),
-                1 => ::std::option::Option::Some(Cardinality::CARDINALITY_OPTIONAL),
-                2 => ::std::option::Option::Some(Cardinality::CARDINALITY_REQUIRED),
-                3 => ::std::option::Option::Some(Cardinality::CARDINALITY_REPEATED),
-                _ => ::std::option::Option::None
+                0 => ::core::option::Option::Some(Cardinality::CARDINALITY_UNKNOWN),
+                1 => ::core::option::Option::Some(Cardinality::CARDINALITY_OPTIONAL),
+                2 => ::core::option::Option::Some(Cardinality::CARDINALITY_REQUIRED),
+                3 => ::core::option::Option::Some(Cardinality::CARDINALITY_REPEATED),
+                _ => ::core::option::Option::None
             }
         }
 
@@ -739,7 +888,7 @@ pub mod field {
         }
     }
 
-    impl ::std::default::Default for Cardinality {
+    impl ::core::default::Default for Cardinality {
         fn default() -> Self {
             Cardinality::CARDINALITY_UNKNOWN
         }
@@ -759,13 +908,13 @@ pub struct Enum {
     // message fields
     ///  Enum type name.
     // @@protoc_insertion_point(field:google.protobuf.Enum.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  Enum value definitions.
     // @@protoc_insertion_point(field:google.protobuf.Enum.enumvalue)
-    pub enumvalue: ::std::vec::Vec<EnumValue>,
+    pub enumvalue: crate::rt::Vec<EnumValue>,
     ///  Protocol buffer options.
     // @@protoc_insertion_point(field:google.protobuf.Enum.options)
-    pub options: ::std::vec::Vec<Option>,
+    pub options: crate::rt::Vec<Option>,
     ///  The source context.
     // @@protoc_insertion_point(field:google.protobuf.Enum.source_context)
     pub source_context: crate::MessageField<crate::well_known_types::SourceContext>,
@@ -779,7 +928,7 @@ pub struct Enum {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Enum {
+impl<'a> ::core::default::Default for &'a Enum {
     fn default() -> &'a Enum {
         <Enum as crate::Message>::default_instance()
     }
@@ -787,11 +936,11 @@ impl<'a> ::std::default::Default for &'a Enum {
 
 impl Enum {
     pub fn new() -> Enum {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = crate::rt::Vec::with_capacity(5);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Enum| { &m.name },
@@ -847,18 +996,31 @@ impl crate::Message for Enum {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.enumvalue.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "enumvalue", i), missing);
+        };
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+        for v in &self.source_context {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "source_context"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
                 },
                 18 => {
-                    self.enumvalue.push(is.read_message()?);
+                    self.enumvalue.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.enumvalue.len())?;
                 },
                 26 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 34 => {
                     crate::rt::read_singular_message_into_field(is, &mut self.source_context)?;
@@ -871,7 +1033,22 @@ impl crate::Message for Enum {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        self.enumvalue.extend(other.enumvalue.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if let Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            self.syntax.clone_from(&other.syntax);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -918,7 +1095,27 @@ impl crate::Message for Enum {
             os.write_enum(5, crate::EnumOrUnknown::value(&self.syntax))?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            os.write_enum(5, crate::EnumOrUnknown::value(&self.syntax));
+        }
+        if let Some(v) = self.source_context.as_ref() {
+            crate::rt::write_message_field_reverse(4, v, os)?;
+        }
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        };
+        for v in self.enumvalue.iter().rev() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        };
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -948,9 +1145,9 @@ impl crate::Message for Enum {
 
     fn default_instance() -> &'static Enum {
         static instance: Enum = Enum {
-            name: ::std::string::String::new(),
-            enumvalue: ::std::vec::Vec::new(),
-            options: ::std::vec::Vec::new(),
+            name: crate::rt::String::new(),
+            enumvalue: crate::rt::Vec::new(),
+            options: crate::rt::Vec::new(),
             source_context: crate::MessageField::none(),
             syntax: crate::EnumOrUnknown::from_i32(0),
             unknown_fields: crate::UnknownFields::new(),
@@ -966,8 +1163,8 @@ impl crate::MessageFull for Enum {
     }
 }
 
-impl ::std::fmt::Display for Enum {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Enum {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -983,13 +1180,13 @@ pub struct EnumValue {
     // message fields
     ///  Enum value name.
     // @@protoc_insertion_point(field:google.protobuf.EnumValue.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  Enum value number.
     // @@protoc_insertion_point(field:google.protobuf.EnumValue.number)
     pub number: i32,
     ///  Protocol buffer options.
     // @@protoc_insertion_point(field:google.protobuf.EnumValue.options)
-    pub options: ::std::vec::Vec<Option>,
+    pub options: crate::rt::Vec<Option>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.EnumValue.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -997,7 +1194,7 @@ pub struct EnumValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a EnumValue {
+impl<'a> ::core::default::Default for &'a EnumValue {
     fn default() -> &'a EnumValue {
         <EnumValue as crate::Message>::default_instance()
     }
@@ -1005,11 +1202,11 @@ impl<'a> ::std::default::Default for &'a EnumValue {
 
 impl EnumValue {
     pub fn new() -> EnumValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = crate::rt::Vec::with_capacity(3);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &EnumValue| { &m.name },
@@ -1045,9 +1242,14 @@ impl crate::Message for EnumValue {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for (i, v) in self.options.iter().enumerate() {
+            v.collect_missing_required_fields(&crate::rt::nested_repeated_field_path(prefix, "options", i), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
@@ -1056,14 +1258,26 @@ impl crate::Message for EnumValue {
                     self.number = is.read_int32()?;
                 },
                 26 => {
-                    self.options.push(is.read_message()?);
+                    self.options.push(crate::rt::read_message_no_check(is)?);
+                    crate::rt::check_repeated_len(is, self.options.len())?;
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if other.number != 0 {
+            self.number.clone_from(&other.number);
+        }
+        self.options.extend(other.options.iter().cloned());
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -1096,7 +1310,21 @@ impl crate::Message for EnumValue {
             crate::rt::write_message_field_with_cached_size(3, v, os)?;
         };
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        for v in self.options.iter().rev() {
+            crate::rt::write_message_field_reverse(3, v, os)?;
+        };
+        if self.number != 0 {
+            os.write_int32(2, self.number);
+        }
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -1124,9 +1352,9 @@ impl crate::Message for EnumValue {
 
     fn default_instance() -> &'static EnumValue {
         static instance: EnumValue = EnumValue {
-            name: ::std::string::String::new(),
+            name: crate::rt::String::new(),
             number: 0,
-            options: ::std::vec::Vec::new(),
+            options: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -1140,8 +1368,8 @@ impl crate::MessageFull for EnumValue {
     }
 }
 
-impl ::std::fmt::Display for EnumValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EnumValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1161,7 +1389,7 @@ pub struct Option {
     ///  For custom options, it should be the fully-qualified name. For example,
     ///  `"google.api.http"`.
     // @@protoc_insertion_point(field:google.protobuf.Option.name)
-    pub name: ::std::string::String,
+    pub name: crate::rt::String,
     ///  The option's value packed in an Any message. If the value is a primitive,
     ///  the corresponding wrapper type defined in google/protobuf/wrappers.proto
     ///  should be used. If the value is an enum, it should be stored as an int32
@@ -1175,7 +1403,7 @@ pub struct Option {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Option {
+impl<'a> ::core::default::Default for &'a Option {
     fn default() -> &'a Option {
         <Option as crate::Message>::default_instance()
     }
@@ -1183,11 +1411,11 @@ impl<'a> ::std::default::Default for &'a Option {
 
 impl Option {
     pub fn new() -> Option {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = crate::rt::Vec::with_capacity(2);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "name",
             |m: &Option| { &m.name },
@@ -1218,9 +1446,14 @@ impl crate::Message for Option {
         true
     }
 
+    fn collect_missing_required_fields(&self, prefix: &str, missing: &mut crate::rt::Vec<crate::rt::String>) {
+        for v in &self.value {
+            v.collect_missing_required_fields(&crate::rt::nested_field_path(prefix, "value"), missing);
+        };
+    }
+
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.name = is.read_string()?;
@@ -1233,7 +1466,17 @@ impl crate::Message for Option {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name.clone_from(&other.name);
+        }
+        if let Some(v) = other.value.as_ref() {
+            self.value.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -1260,7 +1503,18 @@ impl crate::Message for Option {
             crate::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if let Some(v) = self.value.as_ref() {
+            crate::rt::write_message_field_reverse(2, v, os)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -1287,7 +1541,7 @@ impl crate::Message for Option {
 
     fn default_instance() -> &'static Option {
         static instance: Option = Option {
-            name: ::std::string::String::new(),
+            name: crate::rt::String::new(),
             value: crate::MessageField::none(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -1302,8 +1556,8 @@ impl crate::MessageFull for Option {
     }
 }
 
-impl ::std::fmt::Display for Option {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Option {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1316,8 +1570,10 @@ impl crate::reflect::ProtobufValue for Option {
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:google.protobuf.Syntax)
 pub enum Syntax {
+    ///  Syntax `proto2`.
     // @@protoc_insertion_point(enum_value:google.protobuf.Syntax.SYNTAX_PROTO2)
     SYNTAX_PROTO2 = 0,
+    ///  Syntax `proto3`.
     // @@protoc_insertion_point(enum_value:google.protobuf.Syntax.SYNTAX_PROTO3)
     SYNTAX_PROTO3 = 1,
 }
@@ -1327,11 +1583,11 @@ impl crate::Enum for Syntax {
         *self as i32
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<Syntax> {
+    fn from_i32(value: i32) -> ::core::option::Option<Syntax> {
         match value {
-            0 => ::std::option::Option::Some(Syntax::SYNTAX_PROTO2),
-            1 => ::std::option::Option::Some(Syntax::SYNTAX_PROTO3),
-            _ => ::std::option::Option::None
+            0 => ::core::option::Option::Some(Syntax::SYNTAX_PROTO2),
+            1 => ::core::option::Option::Some(Syntax::SYNTAX_PROTO3),
+            _ => ::core::option::Option::None
         }
     }
 
@@ -1350,7 +1606,7 @@ impl crate::EnumFull for Syntax {
     }
 }
 
-impl ::std::default::Default for Syntax {
+impl ::core::default::Default for Syntax {
     fn default() -> Self {
         Syntax::SYNTAX_PROTO2
     }
@@ -1659,16 +1915,16 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
         deps.push(crate::well_known_types::file_descriptors::any());
         deps.push(crate::well_known_types::file_descriptors::source_context());
-        let mut messages = ::std::vec::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(Type::generated_message_descriptor_data());
         messages.push(Field::generated_message_descriptor_data());
         messages.push(Enum::generated_message_descriptor_data());
         messages.push(EnumValue::generated_message_descriptor_data());
         messages.push(Option::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         enums.push(Syntax::generated_enum_descriptor_data());
         enums.push(field::Kind::generated_enum_descriptor_data());
         enums.push(field::Cardinality::generated_enum_descriptor_data());
@@ -38,7 +38,7 @@ pub struct DoubleValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DoubleValue {
+impl<'a> ::core::default::Default for &'a DoubleValue {
     fn default() -> &'a DoubleValue {
         <DoubleValue as crate::Message>::default_instance()
     }
@@ -46,11 +46,11 @@ impl<'a> ::std::default::Default for &'a DoubleValue {
 
 impl DoubleValue {
     pub fn new() -> DoubleValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &DoubleValue| { &m.value },
@@ -72,8 +72,7 @@ impl crate::Message for DoubleValue {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 9 => {
                     self.value = is.read_double()?;
@@ -83,7 +82,14 @@ impl crate::Message for DoubleValue {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0. {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -103,7 +109,15 @@ impl crate::Message for DoubleValue {
             os.write_double(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0. {
+            os.write_double(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -143,8 +157,8 @@ impl crate::MessageFull for DoubleValue {
     }
 }
 
-impl ::std::fmt::Display for DoubleValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for DoubleValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -170,7 +184,7 @@ pub struct FloatValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FloatValue {
+impl<'a> ::core::default::Default for &'a FloatValue {
     fn default() -> &'a FloatValue {
         <FloatValue as crate::Message>::default_instance()
     }
@@ -178,11 +192,11 @@ impl<'a> ::std::default::Default for &'a FloatValue {
 
 impl FloatValue {
     pub fn new() -> FloatValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &FloatValue| { &m.value },
@@ -204,8 +218,7 @@ impl crate::Message for FloatValue {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 13 => {
                     self.value = is.read_float()?;
@@ -215,7 +228,14 @@ impl crate::Message for FloatValue {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0. {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -235,7 +255,15 @@ impl crate::Message for FloatValue {
             os.write_float(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0. {
+            os.write_float(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -275,8 +303,8 @@ impl crate::MessageFull for FloatValue {
     }
 }
 
-impl ::std::fmt::Display for FloatValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FloatValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -302,7 +330,7 @@ pub struct Int64Value {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Int64Value {
+impl<'a> ::core::default::Default for &'a Int64Value {
     fn default() -> &'a Int64Value {
         <Int64Value as crate::Message>::default_instance()
     }
@@ -310,11 +338,11 @@ impl<'a> ::std::default::Default for &'a Int64Value {
 
 impl Int64Value {
     pub fn new() -> Int64Value {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &Int64Value| { &m.value },
@@ -336,8 +364,7 @@ impl crate::Message for Int64Value {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.value = is.read_int64()?;
@@ -347,7 +374,14 @@ impl crate::Message for Int64Value {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -367,7 +401,15 @@ impl crate::Message for Int64Value {
             os.write_int64(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0 {
+            os.write_int64(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -407,8 +449,8 @@ impl crate::MessageFull for Int64Value {
     }
 }
 
-impl ::std::fmt::Display for Int64Value {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Int64Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -434,7 +476,7 @@ pub struct UInt64Value {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UInt64Value {
+impl<'a> ::core::default::Default for &'a UInt64Value {
     fn default() -> &'a UInt64Value {
         <UInt64Value as crate::Message>::default_instance()
     }
@@ -442,11 +484,11 @@ impl<'a> ::std::default::Default for &'a UInt64Value {
 
 impl UInt64Value {
     pub fn new() -> UInt64Value {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &UInt64Value| { &m.value },
@@ -468,8 +510,7 @@ impl crate::Message for UInt64Value {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.value = is.read_uint64()?;
@@ -479,7 +520,14 @@ impl crate::Message for UInt64Value {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -499,7 +547,15 @@ impl crate::Message for UInt64Value {
             os.write_uint64(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0 {
+            os.write_uint64(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -539,8 +595,8 @@ impl crate::MessageFull for UInt64Value {
     }
 }
 
-impl ::std::fmt::Display for UInt64Value {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for UInt64Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -566,7 +622,7 @@ pub struct Int32Value {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Int32Value {
+impl<'a> ::core::default::Default for &'a Int32Value {
     fn default() -> &'a Int32Value {
         <Int32Value as crate::Message>::default_instance()
     }
@@ -574,11 +630,11 @@ impl<'a> ::std::default::Default for &'a Int32Value {
 
 impl Int32Value {
     pub fn new() -> Int32Value {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &Int32Value| { &m.value },
@@ -600,8 +656,7 @@ impl crate::Message for Int32Value {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.value = is.read_int32()?;
@@ -611,7 +666,14 @@ impl crate::Message for Int32Value {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -631,7 +693,15 @@ impl crate::Message for Int32Value {
             os.write_int32(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0 {
+            os.write_int32(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -671,8 +741,8 @@ impl crate::MessageFull for Int32Value {
     }
 }
 
-impl ::std::fmt::Display for Int32Value {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Int32Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -698,7 +768,7 @@ pub struct UInt32Value {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UInt32Value {
+impl<'a> ::core::default::Default for &'a UInt32Value {
     fn default() -> &'a UInt32Value {
         <UInt32Value as crate::Message>::default_instance()
     }
@@ -706,11 +776,11 @@ impl<'a> ::std::default::Default for &'a UInt32Value {
 
 impl UInt32Value {
     pub fn new() -> UInt32Value {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &UInt32Value| { &m.value },
@@ -732,8 +802,7 @@ impl crate::Message for UInt32Value {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.value = is.read_uint32()?;
@@ -743,7 +812,14 @@ impl crate::Message for UInt32Value {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -763,7 +839,15 @@ impl crate::Message for UInt32Value {
             os.write_uint32(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != 0 {
+            os.write_uint32(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -803,8 +887,8 @@ impl crate::MessageFull for UInt32Value {
     }
 }
 
-impl ::std::fmt::Display for UInt32Value {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for UInt32Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -830,7 +914,7 @@ pub struct BoolValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a BoolValue {
+impl<'a> ::core::default::Default for &'a BoolValue {
     fn default() -> &'a BoolValue {
         <BoolValue as crate::Message>::default_instance()
     }
@@ -838,11 +922,11 @@ impl<'a> ::std::default::Default for &'a BoolValue {
 
 impl BoolValue {
     pub fn new() -> BoolValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &BoolValue| { &m.value },
@@ -864,8 +948,7 @@ impl crate::Message for BoolValue {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
                     self.value = is.read_bool()?;
@@ -875,7 +958,14 @@ impl crate::Message for BoolValue {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != false {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -895,7 +985,15 @@ impl crate::Message for BoolValue {
             os.write_bool(1, self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if self.value != false {
+            os.write_bool(1, self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -935,8 +1033,8 @@ impl crate::MessageFull for BoolValue {
     }
 }
 
-impl ::std::fmt::Display for BoolValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for BoolValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -954,7 +1052,7 @@ pub struct StringValue {
     // message fields
     ///  The string value.
     // @@protoc_insertion_point(field:google.protobuf.StringValue.value)
-    pub value: ::std::string::String,
+    pub value: crate::rt::String,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.StringValue.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -962,7 +1060,7 @@ pub struct StringValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StringValue {
+impl<'a> ::core::default::Default for &'a StringValue {
     fn default() -> &'a StringValue {
         <StringValue as crate::Message>::default_instance()
     }
@@ -970,11 +1068,11 @@ impl<'a> ::std::default::Default for &'a StringValue {
 
 impl StringValue {
     pub fn new() -> StringValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &StringValue| { &m.value },
@@ -996,8 +1094,7 @@ impl crate::Message for StringValue {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.value = is.read_string()?;
@@ -1007,7 +1104,14 @@ impl crate::Message for StringValue {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.value.is_empty() {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -1027,7 +1131,15 @@ impl crate::Message for StringValue {
             os.write_string(1, &self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -1053,7 +1165,7 @@ impl crate::Message for StringValue {
 
     fn default_instance() -> &'static StringValue {
         static instance: StringValue = StringValue {
-            value: ::std::string::String::new(),
+            value: crate::rt::String::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -1067,8 +1179,8 @@ impl crate::MessageFull for StringValue {
     }
 }
 
-impl ::std::fmt::Display for StringValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for StringValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1086,7 +1198,7 @@ pub struct BytesValue {
     // message fields
     ///  The bytes value.
     // @@protoc_insertion_point(field:google.protobuf.BytesValue.value)
-    pub value: ::std::vec::Vec<u8>,
+    pub value: crate::rt::Vec<u8>,
     // special fields
     // @@protoc_insertion_point(special_field:google.protobuf.BytesValue.unknown_fields)
     pub unknown_fields: crate::UnknownFields,
@@ -1094,7 +1206,7 @@ pub struct BytesValue {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a BytesValue {
+impl<'a> ::core::default::Default for &'a BytesValue {
     fn default() -> &'a BytesValue {
         <BytesValue as crate::Message>::default_instance()
     }
@@ -1102,11 +1214,11 @@ impl<'a> ::std::default::Default for &'a BytesValue {
 
 impl BytesValue {
     pub fn new() -> BytesValue {
-        ::std::default::Default::default()
+        ::core::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = crate::rt::Vec::with_capacity(1);
         fields.push(crate::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "value",
             |m: &BytesValue| { &m.value },
@@ -1128,8 +1240,7 @@ impl crate::Message for BytesValue {
     }
 
     fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::Result<()> {
-        while !is.eof()? {
-            let tag = is.read_raw_varint32()?;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
                     self.value = is.read_bytes()?;
@@ -1139,7 +1250,14 @@ impl crate::Message for BytesValue {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.value.is_empty() {
+            self.value.clone_from(&other.value);
+        }
+        self.unknown_fields.extend_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -1159,7 +1277,15 @@ impl crate::Message for BytesValue {
             os.write_bytes(1, &self.value)?;
         }
         os.write_unknown_fields(self.unknown_fields())?;
-        ::std::result::Result::Ok(())
+        ::core::result::Result::Ok(())
+    }
+
+    fn write_reverse(&self, os: &mut crate::ReverseOutputStream) -> crate::Result<()> {
+        crate::rt::write_unknown_fields_reverse(self.unknown_fields(), os)?;
+        if !self.value.is_empty() {
+            os.write_bytes(1, &self.value);
+        }
+        ::core::result::Result::Ok(())
     }
 
     fn cached_size(&self) -> u32 {
@@ -1185,7 +1311,7 @@ impl crate::Message for BytesValue {
 
     fn default_instance() -> &'static BytesValue {
         static instance: BytesValue = BytesValue {
-            value: ::std::vec::Vec::new(),
+            value: crate::rt::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -1199,8 +1325,8 @@ impl crate::MessageFull for BytesValue {
     }
 }
 
-impl ::std::fmt::Display for BytesValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for BytesValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         crate::text_format::fmt(self, f)
     }
 }
@@ -1342,8 +1468,8 @@ pub fn file_descriptor_proto() -> &'static crate::descriptor::FileDescriptorProt
 pub fn file_descriptor() -> crate::reflect::FileDescriptor {
     static file_descriptor_lazy: crate::rt::Lazy<crate::reflect::GeneratedFileDescriptor> = crate::rt::Lazy::new();
     let file_descriptor = file_descriptor_lazy.get(|| {
-        let mut deps = ::std::vec::Vec::new();
-        let mut messages = ::std::vec::Vec::new();
+        let mut deps = crate::rt::Vec::new();
+        let mut messages = crate::rt::Vec::new();
         messages.push(DoubleValue::generated_message_descriptor_data());
         messages.push(FloatValue::generated_message_descriptor_data());
         messages.push(Int64Value::generated_message_descriptor_data());
@@ -1353,7 +1479,7 @@ pub fn file_descriptor() -> crate::reflect::FileDescriptor {
         messages.push(BoolValue::generated_message_descriptor_data());
         messages.push(StringValue::generated_message_descriptor_data());
         messages.push(BytesValue::generated_message_descriptor_data());
-        let mut enums = ::std::vec::Vec::new();
+        let mut enums = crate::rt::Vec::new();
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+
+use crate::json::json_name;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::error::ReflectError;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::well_known_types::FieldMask;
+
+/// Options for [`FieldMask::merge`].
+#[derive(Default, Debug, Clone)]
+pub struct FieldMaskMergeOptions {
+    /// Clear message fields in destination before merging them from source,
+    /// instead of merging recursively.
+    pub replace_message_fields: bool,
+    /// Clear repeated and map fields in destination before appending values from source.
+    pub replace_repeated_fields: bool,
+    /// Prevent users from creating this struct by name.
+    pub _future_options: (),
+}
+
+/// Paths of a field mask as a tree of field names.
+///
+/// A node without children except the root node means the whole field is selected.
+#[derive(Default)]
+struct FieldMaskTree {
+    children: BTreeMap<String, FieldMaskTree>,
+}
+
+impl FieldMaskTree {
+    fn from_paths<'a>(paths: impl IntoIterator<Item = &'a String>) -> FieldMaskTree {
+        let mut tree = FieldMaskTree::default();
+        for path in paths {
+            tree.add_path(path);
+        }
+        tree
+    }
+
+    fn add_path(&mut self, path: &str) {
+        if path.is_empty() {
+            return;
+        }
+        let mut node = self;
+        let mut covered = false;
+        for name in path.split('.') {
+            if covered {
+                // A shorter path already selects the whole field.
+                return;
+            }
+            let created = !node.children.contains_key(name);
+            node = node.children.entry(name.to_owned()).or_default();
+            covered = !created && node.children.is_empty();
+        }
+        node.children.clear();
+    }
+
+    /// Add to `out` the part of `path` selected by this tree.
+    fn intersect_path(&self, path: &str, out: &mut FieldMaskTree) {
+        if self.children.is_empty() {
+            return;
+        }
+        let mut node = self;
+        for name in path.split('.') {
+            if node.children.is_empty() {
+                // The whole field is selected by a shorter path of this tree.
+                out.add_path(path);
+                return;
+            }
+            node = match node.children.get(name) {
+                Some(node) => node,
+                None => return,
+            };
+        }
+        node.collect_paths(path, &mut |p| out.add_path(&p));
+    }
+
+    fn collect_paths(&self, prefix: &str, f: &mut impl FnMut(String)) {
+        if self.children.is_empty() {
+            if !prefix.is_empty() {
+                f(prefix.to_owned());
+            }
+            return;
+        }
+        for (name, child) in &self.children {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            child.collect_paths(&path, f);
+        }
+    }
+
+    fn to_field_mask(&self) -> FieldMask {
+        let mut mask = FieldMask::new();
+        self.collect_paths("", &mut |p| mask.paths.push(p));
+        mask
+    }
+
+    fn trim(&self, message: &mut dyn MessageDyn) {
+        for field in message.descriptor_dyn().fields() {
+            match self.children.get(field.name()) {
+                None => field.clear_field(message),
+                Some(child) => {
+                    if !child.children.is_empty()
+                        && field.has_field(message)
+                        && singular_message_type(&field).is_some()
+                    {
+                        child.trim(field.mut_message(message));
+                    }
+                }
+            }
+        }
+    }
+
+    fn merge(
+        &self,
+        source: &dyn MessageDyn,
+        destination: &mut dyn MessageDyn,
+        options: &FieldMaskMergeOptions,
+    ) {
+        let descriptor = source.descriptor_dyn();
+        for (name, child) in &self.children {
+            let field = match descriptor.field_by_name(name) {
+                Some(field) => field,
+                None => continue,
+            };
+            if !child.children.is_empty() {
+                // Do not create empty message in destination.
+                if !field.has_field(source) && !field.has_field(destination) {
+                    continue;
+                }
+                if singular_message_type(&field).is_some() {
+                    child.merge(
+                        &*field.get_message(source),
+                        field.mut_message(destination),
+                        options,
+                    );
+                }
+                continue;
+            }
+            match field.get_reflect(source) {
+                ReflectFieldRef::Optional(None) => field.clear_field(destination),
+                ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => {
+                    if options.replace_message_fields {
+                        field.clear_field(destination);
+                    }
+                    field.mut_message(destination).merge_from_message_dyn(&*m);
+                }
+                ReflectFieldRef::Optional(Some(v)) => {
+                    field.set_singular_field(destination, v.to_box())
+                }
+                ReflectFieldRef::Repeated(r) => {
+                    if options.replace_repeated_fields {
+                        field.clear_field(destination);
+                    }
+                    let mut destination = field.mut_repeated(destination);
+                    for v in r {
+                        destination.push(v.to_box());
+                    }
+                }
+                ReflectFieldRef::Map(m) => {
+                    if options.replace_repeated_fields {
+                        field.clear_field(destination);
+                    }
+                    let mut destination = field.mut_map(destination);
+                    for (k, v) in &m {
+                        destination.insert(k.to_box(), v.to_box());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Message type of a field if the field is a singular message field.
+fn singular_message_type(field: &FieldDescriptor) -> Option<MessageDescriptor> {
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(RuntimeTypeBox::Message(m)) => Some(m),
+        _ => None,
+    }
+}
+
+/// Convert `foo_bar.baz_qux` to `fooBar.bazQux`.
+fn snake_case_to_camel_case(path: &str) -> String {
+    json_name(path)
+}
+
+/// Convert `fooBar.bazQux` to `foo_bar.baz_qux`.
+fn camel_case_to_snake_case(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    for c in path.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('_');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+impl FieldMask {
+    /// Create a field mask from given paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use protobuf::well_known_types::FieldMask;
+    /// let mask = FieldMask::from_paths(["name", "address.city"]);
+    /// assert_eq!(vec!["name", "address.city"], mask.paths);
+    /// ```
+    pub fn from_paths<S: Into<String>>(paths: impl IntoIterator<Item = S>) -> FieldMask {
+        let mut mask = FieldMask::new();
+        mask.paths = paths.into_iter().map(Into::into).collect();
+        mask
+    }
+
+    /// Check every path of this mask refers to a field of message `descriptor`.
+    ///
+    /// All fields in a path except the last one must be singular message fields.
+    pub fn check_valid_for(&self, descriptor: &MessageDescriptor) -> crate::Result<()> {
+        for path in &self.paths {
+            if !FieldMask::is_path_valid(descriptor, path) {
+                return Err(ReflectError::InvalidFieldMaskPath(
+                    path.clone(),
+                    descriptor.full_name().to_owned(),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn is_path_valid(descriptor: &MessageDescriptor, path: &str) -> bool {
+        let mut message = Some(descriptor.clone());
+        for name in path.split('.') {
+            let field = match message.and_then(|m| m.field_by_name(name)) {
+                Some(field) => field,
+                None => return false,
+            };
+            message = singular_message_type(&field);
+        }
+        true
+    }
+
+    /// Canonical form of this mask: paths are sorted, duplicates and
+    /// paths covered by other paths (`a.b` is covered by `a`) are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use protobuf::well_known_types::FieldMask;
+    /// let mask = FieldMask::from_paths(["b", "a.c", "a", "b"]);
+    /// assert_eq!(vec!["a", "b"], mask.canonical().paths);
+    /// ```
+    pub fn canonical(&self) -> FieldMask {
+        FieldMaskTree::from_paths(&self.paths).to_field_mask()
+    }
+
+    /// Canonical mask which selects fields selected by either mask.
+    pub fn union(&self, other: &FieldMask) -> FieldMask {
+        FieldMaskTree::from_paths(self.paths.iter().chain(&other.paths)).to_field_mask()
+    }
+
+    /// Canonical mask which selects fields selected by both masks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use protobuf::well_known_types::FieldMask;
+    /// let a = FieldMask::from_paths(["a", "b.c"]);
+    /// let b = FieldMask::from_paths(["a.d", "b"]);
+    /// assert_eq!(vec!["a.d", "b.c"], a.intersection(&b).paths);
+    /// ```
+    pub fn intersection(&self, other: &FieldMask) -> FieldMask {
+        let tree = FieldMaskTree::from_paths(&self.paths);
+        let mut result = FieldMaskTree::default();
+        for path in &other.paths {
+            tree.intersect_path(path, &mut result);
+        }
+        result.to_field_mask()
+    }
+
+    /// Check if a field at `path` is selected by this mask,
+    /// either by the path itself or by a path of enclosing message.
+    pub fn contains(&self, path: &str) -> bool {
+        let tree = FieldMaskTree::from_paths(&self.paths);
+        let mut node = &tree;
+        for name in path.split('.') {
+            node = match node.children.get(name) {
+                Some(node) => node,
+                None => return false,
+            };
+            if node.children.is_empty() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Paths with lowerCamelCase field names joined with comma,
+    /// as used in JSON representation of `FieldMask`.
+    ///
+    /// Field names which contain uppercase letters or underscores not followed by
+    /// a lowercase letter cannot be converted back by
+    /// [`from_json_string`](FieldMask::from_json_string).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use protobuf::well_known_types::FieldMask;
+    /// let mask = FieldMask::from_paths(["user.display_name", "photo"]);
+    /// assert_eq!("user.displayName,photo", mask.to_json_string());
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        self.paths
+            .iter()
+            .map(|p| snake_case_to_camel_case(p))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Parse comma-separated paths with lowerCamelCase field names,
+    /// converting field names to snake_case.
+    pub fn from_json_string(json: &str) -> FieldMask {
+        FieldMask::from_paths(
+            json.split(',')
+                .filter(|p| !p.is_empty())
+                .map(camel_case_to_snake_case),
+        )
+    }
+
+    /// Clear all fields of the message not selected by this mask.
+    ///
+    /// Fields of a message field selected with subpaths (like `a.b`) are trimmed recursively.
+    /// Empty mask leaves the message unchanged. Unknown fields are not touched.
+    pub fn trim(&self, message: &mut dyn MessageDyn) {
+        let tree = FieldMaskTree::from_paths(&self.paths);
+        if tree.children.is_empty() {
+            return;
+        }
+        tree.trim(message);
+    }
+
+    /// Merge fields selected by this mask from `source` into `destination`.
+    ///
+    /// Selected singular fields which are not set in `source` are cleared in `destination`.
+    /// Repeated fields are appended and message fields are merged recursively unless
+    /// `options` say to replace them. Paths which are not valid for the message type
+    /// are ignored, use [`check_valid_for`](FieldMask::check_valid_for) to validate them.
+    ///
+    /// # Panics
+    ///
+    /// If `source` and `destination` are of different types.
+    pub fn merge(
+        &self,
+        source: &dyn MessageDyn,
+        destination: &mut dyn MessageDyn,
+        options: &FieldMaskMergeOptions,
+    ) {
+        assert_eq!(source.descriptor_dyn(), destination.descriptor_dyn());
+        FieldMaskTree::from_paths(&self.paths).merge(source, destination, options);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::well_known_types::FieldMask;
+
+    #[test]
+    fn canonical() {
+        let mask = FieldMask::from_paths(["foo.bar", "baz", "foo", "baz", "a.b.c", "a.b.d", ""]);
+        assert_eq!(vec!["a.b.c", "a.b.d", "baz", "foo"], mask.canonical().paths);
+    }
+
+    #[test]
+    fn union() {
+        let a = FieldMask::from_paths(["foo", "bar.baz", "bar.quz"]);
+        let b = FieldMask::from_paths(["foo.bar", "bar"]);
+        assert_eq!(vec!["bar", "foo"], a.union(&b).paths);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = FieldMask::from_paths(["foo", "bar.baz", "bar.quz"]);
+        let b = FieldMask::from_paths(["foo.bar", "bar", "quz"]);
+        assert_eq!(
+            vec!["bar.baz", "bar.quz", "foo.bar"],
+            a.intersection(&b).paths
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        assert_eq!(
+            Vec::<String>::new(),
+            a.intersection(&FieldMask::new()).paths
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let mask = FieldMask::from_paths(["foo", "bar.baz"]);
+        assert!(mask.contains("foo"));
+        assert!(mask.contains("foo.bar"));
+        assert!(mask.contains("bar.baz"));
+        assert!(!mask.contains("bar"));
+        assert!(!mask.contains("bar.quz"));
+        assert!(!mask.contains("baz"));
+    }
+
+    #[test]
+    fn json() {
+        let mask = FieldMask::from_paths(["foo_bar", "baz_quz.a_b_c", "x"]);
+        assert_eq!("fooBar,bazQuz.aBC,x", mask.to_json_string());
+        assert_eq!(mask, FieldMask::from_json_string("fooBar,bazQuz.aBC,x"));
+        assert_eq!(FieldMask::new(), FieldMask::from_json_string(""));
+    }
+}
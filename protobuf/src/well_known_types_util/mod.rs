@@ -1,3 +1,7 @@
 mod any;
 mod duration;
+mod field_mask;
 mod timestamp;
+mod wrappers;
+
+pub use self::field_mask::FieldMaskMergeOptions;
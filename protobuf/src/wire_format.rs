@@ -1,5 +1,6 @@
 //! Constants used in serializations.
 
+#[cfg(feature = "std")]
 use crate::descriptor::field_descriptor_proto;
 use crate::error::WireError;
 
@@ -35,9 +36,9 @@ pub enum WireType {
     Fixed64 = 1,
     /// Length-delimited field
     LengthDelimited = 2,
-    /// Groups are not supported in rust-protobuf
+    /// Start of a group (deprecated proto2 feature)
     StartGroup = 3,
-    /// Groups are not supported in rust-protobuf
+    /// End of a group (deprecated proto2 feature)
     EndGroup = 4,
     /// 32-bit field (e. g. `fixed32` or `float`)
     Fixed32 = 5,
@@ -58,6 +59,7 @@ impl WireType {
     }
 
     #[doc(hidden)]
+    #[cfg(feature = "std")]
     pub fn for_type(field_type: field_descriptor_proto::Type) -> WireType {
         use field_descriptor_proto::Type;
         match field_type {
@@ -78,7 +80,7 @@ impl WireType {
             Type::TYPE_STRING => WireType::LengthDelimited,
             Type::TYPE_BYTES => WireType::LengthDelimited,
             Type::TYPE_MESSAGE => WireType::LengthDelimited,
-            Type::TYPE_GROUP => WireType::LengthDelimited, // not true
+            Type::TYPE_GROUP => WireType::StartGroup,
         }
     }
 }
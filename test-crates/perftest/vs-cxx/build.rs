@@ -1,3 +1,5 @@
+use std::fs;
+
 use protobuf_codegen::Codegen;
 use protobuf_codegen::Customize;
 
@@ -9,4 +11,14 @@ fn main() {
         .input("perftest_data.proto")
         .customize(Customize::default().gen_mod_rs(false))
         .run_from_script();
+
+    // Same messages with table-driven serialization to compare with.
+    fs::create_dir_all("table_driven").unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir("table_driven")
+        .include(".")
+        .input("perftest_data.proto")
+        .customize(Customize::default().gen_mod_rs(false).table_driven(true))
+        .run_from_script();
 }
@@ -1,8 +1,6 @@
-use std::fs::File;
-use std::path::Path;
+use std::fs;
 use std::time::Instant;
 
-use perftest_data::PerftestData;
 use protobuf::Message;
 use protobuf::MessageFull;
 use rand::Rng;
@@ -10,6 +8,8 @@ use rand::SeedableRng;
 use rand::StdRng;
 
 mod perftest_data;
+#[path = "table_driven/perftest_data.rs"]
+mod perftest_data_table_driven;
 
 fn measure_ns<R, F: FnMut() -> R>(mut f: F) -> (u64, R) {
     let start = Instant::now();
@@ -123,19 +123,42 @@ fn main() {
         data_size: data_size,
     };
 
-    let mut is = File::open(&Path::new("perftest_data.pbbin")).unwrap();
-    let test_data = PerftestData::parse_from_reader(&mut is).unwrap();
-
-    runner.test("test1", &test_data.test1);
-    runner.test("test_repeated_bool", &test_data.test_repeated_bool);
-    runner.test(
-        "test_repeated_packed_int32",
-        &test_data.test_repeated_packed_int32,
-    );
-    runner.test("test_repeated_messages", &test_data.test_repeated_messages);
-    runner.test("test_optional_messages", &test_data.test_optional_messages);
-    runner.test("test_strings", &test_data.test_strings);
-    runner.test("test_small_bytearrays", &test_data.test_small_bytearrays);
-    runner.test("test_large_bytearrays", &test_data.test_large_bytearrays);
+    let bytes = fs::read("perftest_data.pbbin").unwrap();
+
+    // Table-driven variants are named with `/table` suffix.
+    macro_rules! run_all {
+        ($data:ty, $suffix:expr) => {
+            let test_data = <$data>::parse_from_bytes(&bytes).unwrap();
+            runner.test(&format!("test1{}", $suffix), &test_data.test1);
+            runner.test(
+                &format!("test_repeated_bool{}", $suffix),
+                &test_data.test_repeated_bool,
+            );
+            runner.test(
+                &format!("test_repeated_packed_int32{}", $suffix),
+                &test_data.test_repeated_packed_int32,
+            );
+            runner.test(
+                &format!("test_repeated_messages{}", $suffix),
+                &test_data.test_repeated_messages,
+            );
+            runner.test(
+                &format!("test_optional_messages{}", $suffix),
+                &test_data.test_optional_messages,
+            );
+            runner.test(&format!("test_strings{}", $suffix), &test_data.test_strings);
+            runner.test(
+                &format!("test_small_bytearrays{}", $suffix),
+                &test_data.test_small_bytearrays,
+            );
+            runner.test(
+                &format!("test_large_bytearrays{}", $suffix),
+                &test_data.test_large_bytearrays,
+            );
+        };
+    }
+
+    run_all!(perftest_data::PerftestData, "");
+    run_all!(perftest_data_table_driven::PerftestData, "/table");
     runner.check();
 }
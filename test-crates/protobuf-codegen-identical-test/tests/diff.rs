@@ -282,12 +282,14 @@ where
 
     let mut protoc_descriptors = Parser::new()
         .protoc()
+        .include_source_info(true)
         .includes(&includes)
         .inputs(&inputs)
         .file_descriptor_set()
         .unwrap();
     let mut pure_descriptors = Parser::new()
         .pure()
+        .include_source_info(true)
         .includes(&includes)
         .inputs(&inputs)
         .file_descriptor_set()
@@ -7,7 +7,8 @@ use protobuf::MessageFull;
 pub fn dynamic_descriptor_for_descriptor<M: MessageFull>() -> MessageDescriptor {
     let deps = M::descriptor_static().file_descriptor().deps().to_vec();
     let dynamic_file_descriptor =
-        FileDescriptor::new_dynamic(M::descriptor_static().file_descriptor_proto().clone(), deps);
+        FileDescriptor::new_dynamic(M::descriptor_static().file_descriptor_proto().clone(), deps)
+            .unwrap();
 
     // Find the dynamic version of the generated message.
     let dynamic_descriptor = dynamic_file_descriptor
@@ -0,0 +1,14 @@
+use protobuf_codegen::Codegen;
+use protobuf_codegen::Customize;
+
+fn main() {
+    protobuf_test_common::build::clean_old_files();
+
+    Codegen::new()
+        .pure()
+        .out_dir("src")
+        .include("src")
+        .input("src/no_std_pb.proto")
+        .customize(Customize::default().gen_mod_rs(false).btree_map(true))
+        .run_from_script();
+}
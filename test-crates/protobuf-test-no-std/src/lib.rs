@@ -0,0 +1,72 @@
+//! Generated lite runtime code must compile with `protobuf` without `std` feature.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod no_std_pb;
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use protobuf::CodedOutputStream;
+    use protobuf::Message;
+
+    use crate::no_std_pb::AllTypes;
+    use crate::no_std_pb::Color;
+    use crate::no_std_pb::Inner;
+
+    fn inner(id: u32) -> Inner {
+        let mut inner = Inner::new();
+        inner.set_id(id);
+        inner
+    }
+
+    #[test]
+    fn defaults() {
+        let m = AllTypes::new();
+        assert!(m.double_field().is_nan());
+        assert_eq!(f32::NEG_INFINITY, m.float_field());
+        assert_eq!("default", m.string_field());
+        assert_eq!(Color::GREEN, m.color());
+    }
+
+    #[test]
+    fn write_read() {
+        let mut m = AllTypes::new();
+        m.set_int32_field(-17);
+        m.set_sint64_field(-23);
+        m.set_string_field("abc".to_string());
+        m.set_bytes_field(vec![1, 2, 3]);
+        m.set_color(Color::BLUE);
+        m.inner = Some(inner(1)).into();
+        m.packed_field = vec![1, 2, 3];
+        m.repeated_inner.push(inner(2));
+        m.map_field.insert("x".to_string(), inner(3));
+        m.set_one_string("one".to_string());
+
+        let bytes = m.write_to_bytes().unwrap();
+        assert_eq!(m, AllTypes::parse_from_bytes(&bytes).unwrap());
+
+        let mut buf = vec![0; bytes.len()];
+        let mut os = CodedOutputStream::bytes(&mut buf);
+        m.write_to(&mut os).unwrap();
+        os.check_eof();
+        drop(os);
+        assert_eq!(bytes, buf);
+
+        // Slice is too small.
+        let mut buf = vec![0; bytes.len() - 1];
+        assert!(m.write_to(&mut CodedOutputStream::bytes(&mut buf)).is_err());
+    }
+
+    #[test]
+    fn required() {
+        let mut m = AllTypes::new();
+        m.repeated_inner.push(Inner::new());
+        assert!(!m.is_initialized());
+        assert!(m.write_to_bytes().is_err());
+    }
+}
@@ -10,6 +10,7 @@ fn dynamic_file_descriptor() -> FileDescriptor {
             .clone(),
         Vec::new(),
     )
+    .unwrap()
 }
 
 fn do_test_repeated(file_descriptor: &FileDescriptor) {
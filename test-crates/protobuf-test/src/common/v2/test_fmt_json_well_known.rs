@@ -1,3 +1,5 @@
+use protobuf::json;
+use protobuf::reflect::TypeRegistry;
 use protobuf::well_known_types::*;
 use protobuf_test_common::*;
 
@@ -126,11 +128,71 @@ fn test_wrappers() {
     test_json_print_parse_message("{\"bytesValue\": \"YWI=\"}", &m);
 }
 
+fn any_type_registry() -> TypeRegistry {
+    let mut type_registry = TypeRegistry::new();
+    type_registry.add_message_type::<Duration>();
+    type_registry.add_message_type::<TestFmtJsonWellKnownTypes>();
+    type_registry
+}
+
+fn test_any_print_parse(s: &str, m: &TestFmtJsonWellKnownTypes) {
+    let print_options = json::PrintOptions {
+        type_registry: any_type_registry(),
+        ..Default::default()
+    };
+    assert_eq!(
+        s,
+        json::print_to_string_with_options(m, &print_options).unwrap()
+    );
+    test_any_parse(s, m);
+}
+
+fn test_any_parse(s: &str, m: &TestFmtJsonWellKnownTypes) {
+    let parse_options = json::ParseOptions {
+        type_registry: any_type_registry(),
+        ..Default::default()
+    };
+    let parsed: TestFmtJsonWellKnownTypes =
+        json::parse_from_str_with_options(s, &parse_options).unwrap();
+    assert_eq!(m, &parsed);
+}
+
 #[test]
 fn test_any() {
     let mut m = TestFmtJsonWellKnownTypes::new();
     m.any_value.mut_or_default();
-    // TODO
+    test_any_print_parse("{\"anyValue\": {}}", &m);
+
+    let mut d = Duration::new();
+    d.seconds = 1;
+    m.set_any_value(Any::pack(&d).unwrap());
+    test_any_print_parse(
+        "{\"anyValue\": {\"@type\": \"type.googleapis.com/google.protobuf.Duration\", \"value\": \"1.000000000s\"}}",
+        &m,
+    );
+
+    let mut inner = TestFmtJsonWellKnownTypes::new();
+    inner.mut_int32_value().value = 17;
+    m.set_any_value(Any::pack(&inner).unwrap());
+    test_any_print_parse(
+        "{\"anyValue\": {\"@type\": \"type.googleapis.com/TestFmtJsonWellKnownTypes\", \"int32Value\": 17}}",
+        &m,
+    );
+    test_any_parse(
+        "{\"anyValue\": {\"int32Value\": 17, \"@type\": \"type.googleapis.com/TestFmtJsonWellKnownTypes\"}}",
+        &m,
+    );
+}
+
+#[test]
+fn test_any_type_not_found() {
+    let mut m = TestFmtJsonWellKnownTypes::new();
+    m.set_any_value(Any::pack(&Timestamp::new()).unwrap());
+    assert!(json::print_to_string(&m).is_err());
+    assert!(json::parse_from_str::<TestFmtJsonWellKnownTypes>(
+        "{\"anyValue\": {\"@type\": \"type.googleapis.com/google.protobuf.Timestamp\", \"value\": \"1970-01-01T00:00:00Z\"}}"
+    )
+    .is_err());
 }
 
 #[test]
@@ -153,4 +215,7 @@ fn test_field_mask() {
         v
     });
     test_json_print_parse_message("{\"fieldMask\": \"ab,c.d.e\"}", &m);
+
+    m.set_field_mask(FieldMask::from_paths(["foo_bar.baz_qux"]));
+    test_json_print_parse_message("{\"fieldMask\": \"fooBar.bazQux\"}", &m);
 }
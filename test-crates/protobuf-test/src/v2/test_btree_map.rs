@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::ReflectValueRef;
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf_test_common::hex::encode_hex;
+use protobuf_test_common::*;
+
+use super::test_btree_map_pb::*;
+
+fn inner(name: &str) -> Inner {
+    let mut inner = Inner::new();
+    inner.set_name(name.to_owned());
+    inner
+}
+
+#[test]
+fn test_field_type() {
+    let m = WithBTreeMap::new();
+    let _: &BTreeMap<i32, String> = &m.int32_to_string;
+    let _: &BTreeMap<String, Inner> = &m.string_to_inner;
+}
+
+#[test]
+fn test_serialize_deserialize() {
+    let mut m = WithBTreeMap::new();
+    m.string_to_inner.insert("x".to_owned(), inner("y"));
+    test_serialize_deserialize_with_dynamic("12 08 0a 01 78 12 03 0a 01 79", &m);
+}
+
+#[test]
+fn test_write_in_key_order() {
+    let mut m = WithBTreeMap::new();
+    m.int32_to_string.insert(2, "b".to_owned());
+    m.int32_to_string.insert(1, "a".to_owned());
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(
+        "0a 05 08 01 12 01 61 0a 05 08 02 12 01 62",
+        encode_hex(&bytes)
+    );
+    assert_eq!(m, WithBTreeMap::parse_from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = WithBTreeMap::new();
+    m.string_to_inner.insert("x".to_owned(), inner("y"));
+
+    let field = WithBTreeMap::descriptor_static()
+        .field_by_name("string_to_inner")
+        .unwrap();
+    assert_eq!(1, field.get_map(&m).len());
+    assert_eq!(
+        ReflectValueBox::Message(Box::new(inner("y"))),
+        field
+            .get_map(&m)
+            .get(ReflectValueRef::String("x"))
+            .unwrap()
+            .to_box()
+    );
+
+    field.mut_map(&mut m).insert(
+        ReflectValueBox::String("z".to_owned()),
+        ReflectValueBox::Message(Box::new(inner("w"))),
+    );
+    assert_eq!(Some(&inner("w")), m.string_to_inner.get("z"));
+
+    field.mut_map(&mut m).remove(ReflectValueRef::String("x"));
+    assert_eq!(
+        vec!["z"],
+        m.string_to_inner
+            .keys()
+            .map(|k| k.as_str())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_text_format() {
+    let mut m = WithBTreeMap::new();
+    m.int32_to_string.insert(1, "a".to_owned());
+    m.string_to_inner.insert("x".to_owned(), inner("y"));
+    test_text_format_message(&m);
+}
+
+#[test]
+fn test_json() {
+    let mut m = WithBTreeMap::new();
+    m.int32_to_string.insert(1, "a".to_owned());
+    m.string_to_inner.insert("x".to_owned(), inner("y"));
+    test_json_message(&m);
+}
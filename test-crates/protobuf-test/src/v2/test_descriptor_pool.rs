@@ -0,0 +1,62 @@
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::reflect::DescriptorPool;
+
+use super::test_extension_registry_pb;
+use super::test_gen_services_pb;
+
+#[test]
+fn lookup_across_files() {
+    let mut fds = FileDescriptorSet::new();
+    fds.file
+        .push(test_gen_services_pb::file_descriptor_proto().clone());
+    fds.file
+        .push(test_extension_registry_pb::file_descriptor_proto().clone());
+
+    let mut pool = DescriptorPool::new();
+    // Imports are not in the set.
+    assert!(pool.add_file_descriptor_set(fds.clone()).is_err());
+    assert!(pool.files().is_empty());
+
+    // Imports are resolved from previously added files.
+    pool.add_file(&protobuf::rustproto::file_descriptor())
+        .unwrap();
+    pool.add_file_descriptor_set(fds).unwrap();
+
+    let service = pool
+        .service_by_full_name("test_gen_services.Calculator")
+        .unwrap();
+    assert_eq!("Calculator", service.proto().name());
+    let method = pool
+        .method_by_full_name(".test_gen_services.Calculator.Increment")
+        .unwrap();
+    assert_eq!("Number", method.input_type().name());
+
+    let extension = pool
+        .extension_by_full_name("test_extension_registry.ext_packed")
+        .unwrap();
+    assert!(extension.is_extension());
+    assert_eq!(
+        pool.message_by_full_name("test_extension_registry.Extendable")
+            .unwrap(),
+        extension.containing_message()
+    );
+
+    assert!(pool
+        .message_by_full_name("test_extension_registry.ext_packed")
+        .is_none());
+    assert!(pool
+        .service_by_full_name("test_gen_services.Number")
+        .is_none());
+}
+
+#[test]
+fn generated_and_dynamic_files_conflict() {
+    let mut pool = DescriptorPool::new();
+    pool.add_file(&test_gen_services_pb::file_descriptor())
+        .unwrap();
+
+    let mut proto = test_gen_services_pb::file_descriptor_proto().clone();
+    proto.set_name("copy.proto".to_owned());
+    let e = pool.add_file_descriptor_proto(proto).unwrap_err();
+    assert!(e.to_string().contains("is defined in both"), "{}", e);
+}
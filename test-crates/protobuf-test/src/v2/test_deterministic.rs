@@ -0,0 +1,90 @@
+use protobuf::CodedOutputStream;
+use protobuf::Message;
+use protobuf::MessageDyn;
+use protobuf::MessageFull;
+use protobuf::UnknownValue;
+
+use super::test_deterministic_pb::TestDeterministic;
+
+fn write_deterministic(m: &dyn MessageDyn) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.set_deterministic(true);
+        m.write_to_dyn(&mut os).unwrap();
+        os.flush().unwrap();
+    }
+    bytes
+}
+
+fn make_message(reverse: bool) -> TestDeterministic {
+    let mut keys: Vec<i32> = (0..50).collect();
+    if reverse {
+        keys.reverse();
+    }
+
+    let mut m = TestDeterministic::new();
+    for &k in &keys {
+        m.by_name.insert(format!("k{}", k), k);
+        let mut nested = TestDeterministic::new();
+        nested.by_name.insert(format!("n{}", k), k);
+        nested.by_name.insert(format!("m{}", k), k);
+        m.by_id.insert(k, nested);
+        m.mut_unknown_fields()
+            .add_value(1000 + k as u32, UnknownValue::Varint(k as u64));
+    }
+    m
+}
+
+#[test]
+fn generated() {
+    let expected = write_deterministic(&make_message(false));
+    for i in 0..10 {
+        // Each `HashMap` has its own hash seed, so iteration order differs.
+        assert_eq!(expected, write_deterministic(&make_message(i % 2 == 0)));
+    }
+
+    // Deterministic output is valid serialization of the same message.
+    let parsed = TestDeterministic::parse_from_bytes(&expected).unwrap();
+    assert_eq!(make_message(false), parsed);
+    // And it is independent of how the message was obtained.
+    assert_eq!(expected, write_deterministic(&parsed));
+}
+
+#[test]
+fn dynamic() {
+    let expected = write_deterministic(&make_message(false));
+    let descriptor = TestDeterministic::descriptor_static();
+    let dynamic = descriptor.parse_from_bytes(&expected).unwrap();
+    assert_eq!(expected, write_deterministic(&*dynamic));
+}
+
+#[test]
+fn non_deterministic_by_default() {
+    let m = make_message(false);
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(m, TestDeterministic::parse_from_bytes(&bytes).unwrap());
+    assert_eq!(bytes.len(), write_deterministic(&m).len());
+}
+
+#[test]
+fn write_to_bytes_deterministic() {
+    let expected = write_deterministic(&make_message(false));
+    for i in 0..10 {
+        let m = make_message(i % 2 == 0);
+        assert_eq!(expected, m.write_to_bytes_deterministic().unwrap());
+        assert_eq!(
+            expected,
+            (&m as &dyn MessageDyn)
+                .write_to_bytes_deterministic_dyn()
+                .unwrap()
+        );
+    }
+
+    let descriptor = TestDeterministic::descriptor_static();
+    let dynamic = descriptor.parse_from_bytes(&expected).unwrap();
+    assert_eq!(
+        expected,
+        dynamic.write_to_bytes_deterministic_dyn().unwrap()
+    );
+}
@@ -0,0 +1,207 @@
+use protobuf::reflect::DifferenceKind;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDifferencer;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::text_format;
+use protobuf::MessageFull;
+
+use super::test_differencer_pb;
+use super::test_differencer_pb::Item;
+use super::test_differencer_pb::Order;
+
+fn order(text: &str) -> Order {
+    text_format::parse_from_str(text).unwrap()
+}
+
+fn diff_strings(differencer: &MessageDifferencer, a: &Order, b: &Order) -> Vec<String> {
+    let diff = differencer.diff(a, b);
+    assert_eq!(diff.is_empty(), differencer.equals(a, b));
+    diff.iter().map(|d| d.to_string()).collect()
+}
+
+#[test]
+fn equal() {
+    let a = order("id: 1 items { sku: \"a\" price: 1.5 } tags: \"x\"");
+    let differencer = MessageDifferencer::default();
+    assert!(differencer.equals(&a, &a.clone()));
+    assert_eq!(Vec::<String>::new(), diff_strings(&differencer, &a, &a));
+}
+
+#[test]
+fn singular() {
+    let a = order("id: 1 comment: \"c\"");
+    let b = order("id: 2 items { }");
+    let diff = MessageDifferencer::default().diff(&a, &b);
+    assert_eq!(3, diff.len());
+    assert_eq!("id", diff[0].path);
+    assert_eq!(
+        DifferenceKind::Modified(ReflectValueBox::U64(1), ReflectValueBox::U64(2)),
+        diff[0].kind
+    );
+    assert_eq!("items[0]", diff[1].path);
+    assert!(matches!(diff[1].kind, DifferenceKind::Added(..)));
+    assert_eq!("comment", diff[2].path);
+    assert_eq!(
+        DifferenceKind::Removed(ReflectValueBox::String("c".to_owned())),
+        diff[2].kind
+    );
+}
+
+#[test]
+fn nested_path() {
+    let a = order("items { sku: \"a\" } items { sku: \"b\" } items { sku: \"c\" price: 1 }");
+    let b = order("items { sku: \"a\" } items { sku: \"b\" } items { sku: \"c\" price: 2 }");
+    assert_eq!(
+        vec!["items[2].price: modified F64(1.0) -> F64(2.0)"],
+        diff_strings(&MessageDifferencer::default(), &a, &b)
+    );
+}
+
+#[test]
+fn repeated_as_list() {
+    let a = order("tags: \"x\" tags: \"y\" tags: \"z\"");
+    let b = order("tags: \"y\" tags: \"x\"");
+    assert_eq!(
+        vec![
+            "tags[0]: modified String(\"x\") -> String(\"y\")",
+            "tags[1]: modified String(\"y\") -> String(\"x\")",
+            "tags[2]: removed String(\"z\")",
+        ],
+        diff_strings(&MessageDifferencer::default(), &a, &b)
+    );
+}
+
+#[test]
+fn repeated_as_set() {
+    let a = order("tags: \"x\" tags: \"y\" tags: \"y\" tags: \"z\"");
+    let b = order("tags: \"y\" tags: \"w\" tags: \"x\" tags: \"y\"");
+    let differencer = MessageDifferencer {
+        repeated_as_set: vec![Order::descriptor_static().field_by_name("tags").unwrap()],
+        ..Default::default()
+    };
+    assert_eq!(
+        vec![
+            "tags[3]: removed String(\"z\")",
+            "tags[1]: added String(\"w\")"
+        ],
+        diff_strings(&differencer, &a, &b)
+    );
+
+    let b = order("tags: \"z\" tags: \"y\" tags: \"x\" tags: \"y\"");
+    assert!(differencer.equals(&a, &b));
+}
+
+#[test]
+fn repeated_as_map() {
+    let a =
+        order("items { sku: \"a\" price: 1 } items { sku: \"b\" price: 2 } items { sku: \"c\" }");
+    let b =
+        order("items { sku: \"d\" } items { sku: \"b\" price: 3 } items { sku: \"a\" price: 1 }");
+    let differencer = MessageDifferencer {
+        repeated_as_map: vec![(
+            Order::descriptor_static().field_by_name("items").unwrap(),
+            Item::descriptor_static().field_by_name("sku").unwrap(),
+        )],
+        ..Default::default()
+    };
+    let diff = diff_strings(&differencer, &a, &b);
+    assert_eq!(3, diff.len());
+    assert_eq!("items[\"b\"].price: modified F64(2.0) -> F64(3.0)", diff[0]);
+    assert!(diff[1].starts_with("items[\"c\"]: removed "), "{}", diff[1]);
+    assert!(diff[2].starts_with("items[\"d\"]: added "), "{}", diff[2]);
+}
+
+#[test]
+fn map() {
+    let a = order(
+        "items_by_name { key: \"a\" value { price: 1 } } \
+         items_by_name { key: \"b\" value { } }",
+    );
+    let b = order(
+        "items_by_name { key: \"a\" value { price: 2 } } \
+         items_by_name { key: \"c\" value { } }",
+    );
+    let diff = diff_strings(&MessageDifferencer::default(), &a, &b);
+    assert_eq!(3, diff.len());
+    assert_eq!(
+        "items_by_name[\"a\"].price: modified F64(1.0) -> F64(2.0)",
+        diff[0]
+    );
+    assert!(
+        diff[1].starts_with("items_by_name[\"b\"]: removed "),
+        "{}",
+        diff[1]
+    );
+    assert!(
+        diff[2].starts_with("items_by_name[\"c\"]: added "),
+        "{}",
+        diff[2]
+    );
+}
+
+#[test]
+fn ignored_fields() {
+    let a = order("id: 1 items { sku: \"a\" price: 1 }");
+    let b = order("id: 2 items { sku: \"b\" price: 1 }");
+    let differencer = MessageDifferencer {
+        ignored_fields: vec![
+            Order::descriptor_static().field_by_name("id").unwrap(),
+            Item::descriptor_static().field_by_name("sku").unwrap(),
+        ],
+        ..Default::default()
+    };
+    assert!(differencer.equals(&a, &b));
+    assert!(differencer.diff(&a, &b).is_empty());
+}
+
+#[test]
+fn float_tolerance() {
+    let a = order("items { price: 100 weight: 1 }");
+    let b = order("items { price: 100.5 weight: 1.0001 }");
+    let mut differencer = MessageDifferencer::default();
+    assert_eq!(2, differencer.diff(&a, &b).len());
+
+    differencer.float_margin = 0.001;
+    assert_eq!(
+        vec!["items[0].price: modified F64(100.0) -> F64(100.5)"],
+        diff_strings(&differencer, &a, &b)
+    );
+
+    differencer.float_fraction = 0.01;
+    assert!(differencer.equals(&a, &b));
+}
+
+#[test]
+fn nan() {
+    let a = order("items { price: nan }");
+    let mut differencer = MessageDifferencer::default();
+    assert!(!differencer.equals(&a, &a));
+    differencer.nan_equal = true;
+    assert!(differencer.equals(&a, &a));
+}
+
+#[test]
+fn dynamic() {
+    let file_descriptor = FileDescriptor::new_dynamic(
+        test_differencer_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let descriptor = file_descriptor
+        .message_by_package_relative_name("Order")
+        .unwrap();
+    let mut a = descriptor.new_instance();
+    let mut b = descriptor.new_instance();
+    text_format::merge_from_str(&mut *a, "items { sku: \"a\" price: 1 } tags: \"x\"").unwrap();
+    text_format::merge_from_str(&mut *b, "items { sku: \"a\" price: 2 } tags: \"x\"").unwrap();
+
+    let differencer = MessageDifferencer::default();
+    let diff: Vec<String> = differencer
+        .diff(&*a, &*b)
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    assert_eq!(vec!["items[0].price: modified F64(1.0) -> F64(2.0)"], diff);
+    assert!(!differencer.equals(&*a, &*b));
+    assert!(differencer.equals(&*a, &*a.clone_box()));
+}
@@ -0,0 +1,39 @@
+// Generated code cannot be inspected at runtime, so check the source
+const GENERATED: &str = include_str!("test_doc_comments_pb.rs");
+
+fn assert_doc(doc: &str) {
+    assert!(
+        GENERATED.lines().any(|line| line.trim() == doc),
+        "doc line not found: {}",
+        doc
+    );
+}
+
+#[test]
+fn test_message() {
+    assert_doc("///  Message with comments.");
+    assert_doc(r"///  Text in \[brackets\] and map\<string, string\> is escaped.");
+    assert_doc("///  ```text");
+    assert_doc("///      indented code is not a doctest;");
+    assert_doc("///  fenced code is not a doctest;");
+    assert_doc("///  Nested message comment");
+}
+
+#[test]
+fn test_field() {
+    assert_doc("///  Field comment");
+    assert_doc("///  Trailing comment");
+}
+
+#[test]
+fn test_oneof() {
+    assert_doc("///  Oneof comment");
+    assert_doc("///  Oneof field comment");
+}
+
+#[test]
+fn test_enum() {
+    assert_doc("///  Enum comment");
+    assert_doc("///  Value comment");
+    assert_doc("///  Trailing value comment");
+}
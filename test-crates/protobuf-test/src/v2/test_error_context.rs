@@ -0,0 +1,113 @@
+use protobuf::ErrorKind;
+use protobuf::Message;
+use protobuf::MessageFull;
+
+use super::test_error_context_pb::Root;
+
+#[test]
+fn top_level_field() {
+    // Field 1, truncated varint.
+    let err = Root::parse_from_bytes(&[1 << 3, 0x80]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert_eq!(&[1], err.field_path());
+    assert_eq!(Some(2), err.position());
+    assert_eq!(vec!["id"], err.field_path_names(&Root::descriptor_static()));
+}
+
+#[test]
+fn nested_field() {
+    let input = [
+        // Root.middle
+        7 << 3 | 2,
+        5,
+        // Middle.leaves
+        4 << 3 | 2,
+        3,
+        // Leaf.name, invalid UTF-8
+        1 << 3 | 2,
+        1,
+        0xff,
+    ];
+    let err = Root::parse_from_bytes(&input).unwrap_err();
+    assert_eq!(ErrorKind::Utf8, err.kind());
+    assert_eq!(&[7, 4, 1], err.field_path());
+    assert_eq!(Some(7), err.position());
+    assert_eq!(
+        vec!["middle", "leaves", "name"],
+        err.field_path_names(&Root::descriptor_static())
+    );
+    let message = err.to_string();
+    assert!(message.contains("field 7.4.1"), "{}", message);
+    assert!(message.contains("offset 7"), "{}", message);
+}
+
+#[test]
+fn map_value() {
+    let input = [
+        // Root.middle
+        7 << 3 | 2,
+        10,
+        // Middle.leaf_map entry
+        5 << 3 | 2,
+        8,
+        // key
+        1 << 3 | 2,
+        1,
+        b'k',
+        // value
+        2 << 3 | 2,
+        3,
+        // Leaf.name, invalid UTF-8
+        1 << 3 | 2,
+        1,
+        0xff,
+    ];
+    let err = Root::parse_from_bytes(&input).unwrap_err();
+    assert_eq!(ErrorKind::Utf8, err.kind());
+    assert_eq!(&[7, 5, 2, 1], err.field_path());
+    assert_eq!(
+        vec!["middle", "leaf_map", "value", "name"],
+        err.field_path_names(&Root::descriptor_static())
+    );
+}
+
+#[test]
+fn group() {
+    let input = [
+        // Root.g start
+        8 << 3 | 3,
+        // G.leaf
+        9 << 3 | 2,
+        2,
+        // Leaf.value, varint with incorrect wire type
+        2 << 3 | 7,
+        0,
+    ];
+    let err = Root::parse_from_bytes(&input).unwrap_err();
+    assert_eq!(&[8, 9, 2], err.field_path());
+    assert_eq!(
+        vec!["g", "leaf", "value"],
+        err.field_path_names(&Root::descriptor_static())
+    );
+}
+
+#[test]
+fn error_after_field() {
+    // Field 1 is complete, the next tag is truncated.
+    let err = Root::parse_from_bytes(&[1 << 3, 1, 0x80]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(err.field_path().is_empty());
+    assert_eq!(Some(3), err.position());
+}
+
+#[test]
+fn not_decode_error() {
+    let mut root = Root::new();
+    root.set_id(1000);
+    let mut buf = [0; 1];
+    let mut os = protobuf::CodedOutputStream::bytes(&mut buf);
+    let err = root.write_to(&mut os).unwrap_err();
+    assert_eq!(ErrorKind::BufferTooSmall, err.kind());
+    assert_eq!(None, err.position());
+    assert!(err.field_path().is_empty());
+}
@@ -0,0 +1,222 @@
+use protobuf::reflect::ExtensionRegistry;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::ReflectValueRef;
+use protobuf::reflect::RuntimeTypeBox;
+use protobuf::ErrorKind;
+use protobuf::Message;
+use protobuf::MessageFull;
+
+use super::test_extension_registry_pb::*;
+
+fn registry() -> ExtensionRegistry {
+    let mut registry = ExtensionRegistry::new();
+    registry.add_file(&file_descriptor());
+    registry
+}
+
+fn sub(n: i32) -> Sub {
+    let mut sub = Sub::new();
+    sub.set_n(n);
+    sub
+}
+
+#[test]
+fn optional_set_get_clear() {
+    let mut m = Extendable::new();
+    assert!(!exts::ext_int32.has(&m));
+    assert_eq!(None, exts::ext_int32.get(&m));
+
+    exts::ext_int32.set(&mut m, 10);
+    exts::ext_string.set(&mut m, "abc".to_owned());
+    exts::ext_message.set(&mut m, sub(3));
+    exts::ext_int32.set(&mut m, 20);
+
+    assert!(exts::ext_int32.has(&m));
+    assert_eq!(Some(20), exts::ext_int32.get(&m));
+    assert_eq!(Some("abc".to_owned()), exts::ext_string.get(&m));
+    assert_eq!(Some(sub(3)), exts::ext_message.get(&m));
+
+    exts::ext_int32.clear(&mut m);
+    assert!(!exts::ext_int32.has(&m));
+    assert_eq!(Some("abc".to_owned()), exts::ext_string.get(&m));
+
+    let parsed = Extendable::parse_from_bytes(&m.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(Some("abc".to_owned()), exts::ext_string.get(&parsed));
+    assert_eq!(Some(sub(3)), exts::ext_message.get(&parsed));
+}
+
+#[test]
+fn optional_mut_or_default() {
+    let mut m = Extendable::new();
+    exts::ext_message.mut_or_default(&mut m).set_n(5);
+    *exts::ext_int32.mut_or_default(&mut m) += 2;
+    *exts::ext_int32.mut_or_default(&mut m) += 2;
+    assert_eq!(Some(sub(5)), exts::ext_message.get(&m));
+    assert_eq!(Some(4), exts::ext_int32.get(&m));
+}
+
+#[test]
+fn repeated() {
+    let mut m = Extendable::new();
+    assert_eq!(Vec::<i32>::new(), exts::ext_packed.get(&m));
+
+    exts::ext_packed.set(&mut m, vec![1, 2]);
+    exts::ext_packed.push(&mut m, 3);
+    exts::ext_packed.mut_vec(&mut m).push(4);
+    exts::ext_repeated_string.push(&mut m, "a".to_owned());
+    exts::ext_repeated_string.push(&mut m, "b".to_owned());
+    assert_eq!(vec![1, 2, 3, 4], exts::ext_packed.get(&m));
+
+    let parsed = Extendable::parse_from_bytes(&m.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(vec![1, 2, 3, 4], exts::ext_packed.get(&parsed));
+    assert_eq!(
+        vec!["a".to_owned(), "b".to_owned()],
+        exts::ext_repeated_string.get(&parsed)
+    );
+
+    exts::ext_packed.clear(&mut m);
+    assert_eq!(Vec::<i32>::new(), exts::ext_packed.get(&m));
+}
+
+#[test]
+fn registry_find() {
+    let registry = registry();
+    let extendee = Extendable::descriptor_static().full_name().to_owned();
+
+    let field = registry.find_by_number(&extendee, 101).unwrap();
+    assert_eq!("ext_string", field.name());
+    assert!(field.is_extension());
+    assert_eq!(Extendable::descriptor_static(), field.containing_message());
+
+    let field = registry
+        .find_by_name(&extendee, "test_extension_registry.ext_packed")
+        .unwrap();
+    assert_eq!(103, field.proto().number());
+
+    assert_eq!(None, registry.find_by_number(&extendee, 1));
+    assert_eq!(
+        None,
+        registry.find_by_number("test_extension_registry.Sub", 100)
+    );
+    assert_eq!(7, registry.extensions_of(&extendee).len());
+}
+
+#[test]
+fn reflect_generated() {
+    let registry = registry();
+    let extendee = Extendable::descriptor_static().full_name().to_owned();
+    let ext_int32 = registry.find_by_number(&extendee, 100).unwrap();
+    let ext_string = registry.find_by_number(&extendee, 101).unwrap();
+    let ext_message = registry.find_by_number(&extendee, 102).unwrap();
+    let ext_packed = registry.find_by_number(&extendee, 103).unwrap();
+    let ext_sint64 = registry.find_by_number(&extendee, 105).unwrap();
+
+    let mut m = Extendable::new();
+    assert!(!ext_int32.has_field(&m));
+    assert_eq!(
+        ReflectValueRef::I64(-7),
+        ext_sint64.get_singular_field_or_default(&m)
+    );
+
+    exts::ext_packed.set(&mut m, vec![1, 2]);
+    exts::ext_message.set(&mut m, sub(8));
+    ext_int32.set_singular_field(&mut m, ReflectValueBox::I32(17));
+    ext_string.set_singular_field(&mut m, ReflectValueBox::String("xx".to_owned()));
+
+    assert_eq!(Some(17), exts::ext_int32.get(&m));
+    assert_eq!(Some("xx".to_owned()), exts::ext_string.get(&m));
+    assert!(ext_int32.has_field(&m));
+    assert_eq!(
+        Some(ReflectValueRef::String("xx")),
+        ext_string.get_singular(&m)
+    );
+    let message = ext_message.get_message(&m);
+    assert_eq!(Some(&sub(8)), message.downcast_ref::<Sub>());
+
+    let packed = ext_packed.get_repeated(&m);
+    assert_eq!(2, packed.len());
+    assert_eq!(ReflectValueRef::I32(2), packed.get(1));
+}
+
+#[test]
+fn reflect_dynamic() {
+    let registry = registry();
+    let extendee = Extendable::descriptor_static();
+    let ext_int32 = registry.find_by_number(extendee.full_name(), 100).unwrap();
+    let ext_message = registry.find_by_number(extendee.full_name(), 102).unwrap();
+    let ext_packed = registry.find_by_number(extendee.full_name(), 103).unwrap();
+
+    let mut original = Extendable::new();
+    original.set_regular(1);
+    exts::ext_int32.set(&mut original, 2);
+    exts::ext_packed.set(&mut original, vec![3, 4]);
+    let bytes = original.write_to_bytes().unwrap();
+
+    let dynamic_descriptor = protobuf::reflect::FileDescriptor::new_dynamic(
+        file_descriptor().proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let dynamic_extendee = dynamic_descriptor
+        .message_by_package_relative_name("Extendable")
+        .unwrap();
+    let dynamic_ext_int32 = dynamic_descriptor
+        .extensions()
+        .into_iter()
+        .find(|e| e.name() == ext_int32.name())
+        .unwrap();
+    let dynamic_ext_packed = dynamic_descriptor
+        .extensions()
+        .into_iter()
+        .find(|e| e.name() == ext_packed.name())
+        .unwrap();
+    let dynamic_ext_message = dynamic_descriptor
+        .extensions()
+        .into_iter()
+        .find(|e| e.name() == ext_message.name())
+        .unwrap();
+
+    let mut m = dynamic_extendee.parse_from_bytes(&bytes).unwrap();
+    assert_eq!(
+        Some(ReflectValueRef::I32(2)),
+        dynamic_ext_int32.get_singular(&*m)
+    );
+    assert_eq!(2, dynamic_ext_packed.get_repeated(&*m).len());
+
+    dynamic_ext_packed
+        .mut_repeated(&mut *m)
+        .push(ReflectValueBox::I32(5));
+    dynamic_ext_int32.set_singular_field(&mut *m, ReflectValueBox::I32(6));
+    let sub_message = dynamic_ext_message.mut_message(&mut *m);
+    sub_message
+        .descriptor_dyn()
+        .field_by_name("n")
+        .unwrap()
+        .set_singular_field(sub_message, ReflectValueBox::I32(7));
+
+    let parsed = Extendable::parse_from_bytes(&m.write_to_bytes_dyn().unwrap()).unwrap();
+    assert_eq!(1, parsed.regular());
+    assert_eq!(Some(6), exts::ext_int32.get(&parsed));
+    assert_eq!(vec![3, 4, 5], exts::ext_packed.get(&parsed));
+    assert_eq!(Some(sub(7)), exts::ext_message.get(&parsed));
+}
+
+#[test]
+fn reflect_group_of_generated_message() {
+    let registry = registry();
+    let extendee = Extendable::descriptor_static();
+    let ext_group = registry.find_by_number(extendee.full_name(), 106).unwrap();
+    let group = match ext_group.singular_runtime_type() {
+        RuntimeTypeBox::Message(m) => m.new_instance(),
+        t => panic!("{}", t),
+    };
+
+    let mut m = Extendable::new();
+    exts::ext_int32.set(&mut m, 1);
+    let e = ext_group
+        .try_set_singular_field(&mut m, ReflectValueBox::Message(group))
+        .unwrap_err();
+    assert_eq!(ErrorKind::Reflect, e.kind());
+    assert!(!ext_group.has_field(&m));
+    assert_eq!(Some(1), exts::ext_int32.get(&m));
+}
@@ -0,0 +1,180 @@
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDifferencer;
+use protobuf::text_format;
+use protobuf::well_known_types::FieldMask;
+use protobuf::ErrorKind;
+use protobuf::FieldMaskMergeOptions;
+use protobuf::MessageFull;
+
+use super::test_field_mask_pb;
+use super::test_field_mask_pb::User;
+
+const USER: &str = r#"
+    name: "alice"
+    age: 30
+    address { city: "Paris" street: "Rue" }
+    tags: "a"
+    labels { key: "k" value: "v" }
+    previous_addresses { city: "Oslo" }
+    email: "alice@example.com"
+"#;
+
+fn user(text: &str) -> User {
+    text_format::parse_from_str(text).unwrap()
+}
+
+#[test]
+fn check_valid_for() {
+    let descriptor = User::descriptor_static();
+    FieldMask::from_paths(["name", "address.city", "tags", "labels", "mail.street"])
+        .check_valid_for(&descriptor)
+        .unwrap();
+
+    for path in [
+        "nam",
+        "address.town",
+        "name.x",
+        "previous_addresses.city",
+        "labels.k",
+        "",
+    ] {
+        let err = FieldMask::from_paths([path])
+            .check_valid_for(&descriptor)
+            .unwrap_err();
+        assert_eq!(ErrorKind::Reflect, err.kind(), "{}", path);
+    }
+}
+
+#[test]
+fn trim() {
+    let mut m = user(USER);
+    FieldMask::from_paths(["age", "address.city", "labels", "mail"]).trim(&mut m);
+    assert_eq!(
+        user("age: 30 address { city: \"Paris\" } labels { key: \"k\" value: \"v\" }"),
+        m
+    );
+}
+
+#[test]
+fn trim_empty_mask() {
+    let mut m = user(USER);
+    FieldMask::new().trim(&mut m);
+    assert_eq!(user(USER), m);
+}
+
+#[test]
+fn merge() {
+    let source = user(USER);
+    let mut destination = user(
+        r#"
+        name: "bob"
+        age: 40
+        address { street: "Main" }
+        tags: "b"
+        labels { key: "l" value: "w" }
+        mail { city: "Rome" }
+    "#,
+    );
+    FieldMask::from_paths(["age", "address.city", "tags", "labels", "email"]).merge(
+        &source,
+        &mut destination,
+        &FieldMaskMergeOptions::default(),
+    );
+    assert_eq!(
+        user(
+            r#"
+            name: "bob"
+            age: 30
+            address { city: "Paris" street: "Main" }
+            tags: "b"
+            tags: "a"
+            labels { key: "k" value: "v" }
+            labels { key: "l" value: "w" }
+            email: "alice@example.com"
+        "#
+        ),
+        destination
+    );
+}
+
+#[test]
+fn merge_clears_unset_fields() {
+    let mut destination = user(USER);
+    FieldMask::from_paths(["name", "address.street", "email", "tags"]).merge(
+        &User::new(),
+        &mut destination,
+        &FieldMaskMergeOptions::default(),
+    );
+    let mut expected = user(USER);
+    expected.clear_name();
+    expected.address.mut_or_default().clear_street();
+    expected.clear_email();
+    assert_eq!(expected, destination);
+}
+
+#[test]
+fn merge_does_not_create_unset_messages() {
+    let mut destination = user("name: \"n\"");
+    FieldMask::from_paths(["address.city", "mail.street"]).merge(
+        &User::new(),
+        &mut destination,
+        &FieldMaskMergeOptions::default(),
+    );
+    assert!(destination.address.is_none());
+    assert!(!destination.has_mail());
+    assert_eq!(user("name: \"n\""), destination);
+}
+
+#[test]
+fn merge_replace() {
+    let source = user("address { city: \"Paris\" } tags: \"a\" labels { key: \"k\" value: \"v\" }");
+    let mut destination =
+        user("address { street: \"Main\" } tags: \"b\" labels { key: \"l\" value: \"w\" }");
+    FieldMask::from_paths(["address", "tags", "labels"]).merge(
+        &source,
+        &mut destination,
+        &FieldMaskMergeOptions {
+            replace_message_fields: true,
+            replace_repeated_fields: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(source, destination);
+}
+
+#[test]
+fn dynamic() {
+    let file_descriptor = FileDescriptor::new_dynamic(
+        test_field_mask_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let descriptor = file_descriptor
+        .message_by_package_relative_name("User")
+        .unwrap();
+    let parse = |text: &str| {
+        let mut m = descriptor.new_instance();
+        text_format::merge_from_str(&mut *m, text).unwrap();
+        m
+    };
+
+    let differencer = MessageDifferencer::default();
+
+    let mask = FieldMask::from_paths(["age", "address.city", "mail"]);
+    mask.check_valid_for(&descriptor).unwrap();
+
+    let mut trimmed = parse(USER);
+    mask.trim(&mut *trimmed);
+    assert!(differencer.equals(&*parse("age: 30 address { city: \"Paris\" }"), &*trimmed));
+
+    let mut merged = parse("name: \"bob\" address { street: \"Main\" } email: \"bob@example.com\"");
+    mask.merge(
+        &*parse(USER),
+        &mut *merged,
+        &FieldMaskMergeOptions::default(),
+    );
+    assert!(differencer.equals(
+        &*parse("name: \"bob\" age: 30 address { city: \"Paris\" street: \"Main\" } email: \"bob@example.com\""),
+        &*merged
+    ));
+}
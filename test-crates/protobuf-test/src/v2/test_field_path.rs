@@ -0,0 +1,224 @@
+use protobuf::reflect::FieldPath;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::ReflectValueRef;
+use protobuf::text_format;
+use protobuf::ErrorKind;
+use protobuf::MessageDyn;
+use protobuf::MessageFull;
+
+use super::test_field_path_pb;
+use super::test_field_path_pb::Root;
+
+const ROOT: &str = r#"
+    node {
+      leaf { name: "a" values: 1 values: 2 }
+      leaves { name: "b" }
+      leaves { name: "c" }
+      leaf_by_name { key: "d" value { name: "d" } }
+      child { leaf { name: "e" } }
+    }
+    names { key: 7 value: "seven" }
+    nodes { key: true value { leaf { name: "f" } } }
+    labels { key: "quo\"te" value: "q" }
+    id: 10
+"#;
+
+fn path(descriptor: &MessageDescriptor, path: &str) -> FieldPath {
+    FieldPath::parse(descriptor, path).unwrap()
+}
+
+fn dynamic_root(text: &str) -> Box<dyn MessageDyn> {
+    let file = FileDescriptor::new_dynamic(
+        test_field_path_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let mut m = file
+        .message_by_package_relative_name("Root")
+        .unwrap()
+        .new_instance();
+    text_format::merge_from_str(&mut *m, text).unwrap();
+    m
+}
+
+fn test_get(descriptor: &MessageDescriptor, m: &dyn MessageDyn) {
+    let get = |p: &str| path(descriptor, p).get(m);
+    let string = |s| Some(ReflectValueRef::String(s));
+    assert_eq!(string("a"), get("node.leaf.name"));
+    assert_eq!(Some(ReflectValueRef::I32(2)), get("node.leaf.values[1]"));
+    assert_eq!(string("c"), get("node.leaves[1].name"));
+    assert_eq!(string("d"), get("node.leaf_by_name[\"d\"].name"));
+    assert_eq!(string("e"), get("node.child.leaf.name"));
+    assert_eq!(string("seven"), get("names[7]"));
+    assert_eq!(string("f"), get("nodes[true].leaf.name"));
+    assert_eq!(string("q"), get("labels[\"quo\\\"te\"]"));
+    assert_eq!(Some(ReflectValueRef::I64(10)), get("id"));
+
+    assert_eq!(None, get("node.leaf.values[2]"));
+    assert_eq!(None, get("node.leaves[2].name"));
+    assert_eq!(None, get("node.leaf_by_name[\"x\"].name"));
+    assert_eq!(None, get("node.child.child.leaf.name"));
+    assert_eq!(None, get("names[8]"));
+    assert_eq!(None, get("nodes[false]"));
+}
+
+#[test]
+fn get_generated() {
+    let root: Root = text_format::parse_from_str(ROOT).unwrap();
+    test_get(&Root::descriptor_static(), &root);
+}
+
+#[test]
+fn get_dynamic() {
+    let root = dynamic_root(ROOT);
+    test_get(&root.descriptor_dyn(), &*root);
+}
+
+fn test_set_clear(descriptor: &MessageDescriptor, m: &mut dyn MessageDyn) {
+    let path = |p: &str| path(descriptor, p);
+    let string = |s: &str| ReflectValueBox::String(s.to_owned());
+
+    for (p, value) in [
+        ("node.child.child.leaf.name", string("x")),
+        ("node.leaf_by_name[\"y\"].name", string("y")),
+        ("nodes[false].leaf.name", string("z")),
+        ("node.leaves[1].name", string("w")),
+        ("node.leaf.values[0]", ReflectValueBox::I32(5)),
+        ("names[1]", string("one")),
+        ("id", ReflectValueBox::I64(3)),
+    ] {
+        let path = path(p);
+        path.set(m, value.clone());
+        assert_eq!(Some(value.as_value_ref()), path.get(m), "{}", p);
+    }
+    assert_eq!(
+        Some(ReflectValueRef::String("b")),
+        path("node.leaves[0].name").get(m)
+    );
+
+    for p in [
+        "node.child.child.leaf.name",
+        "node.leaf_by_name[\"y\"]",
+        "node.leaves[0]",
+        "node.leaf.values[0]",
+        "names[1]",
+        "id",
+    ] {
+        path(p).clear(m);
+    }
+    assert_eq!(None, path("node.child.child.leaf.name").get(m));
+    assert!(path("node.child.child.leaf").get(m).is_some());
+    assert_eq!(None, path("node.leaf_by_name[\"y\"]").get(m));
+    assert_eq!(
+        Some(ReflectValueRef::String("w")),
+        path("node.leaves[0].name").get(m)
+    );
+    assert_eq!(None, path("node.leaves[1]").get(m));
+    assert_eq!(
+        Some(ReflectValueRef::I32(2)),
+        path("node.leaf.values[0]").get(m)
+    );
+    assert_eq!(None, path("names[1]").get(m));
+    assert_eq!(None, path("id").get(m));
+
+    // Clearing values under absent messages does not create them.
+    path("node.child.child.child.leaf.name").clear(m);
+    path("nodes[true].child.leaf").clear(m);
+    assert_eq!(None, path("node.child.child.child").get(m));
+    assert_eq!(None, path("nodes[true].child").get(m));
+}
+
+#[test]
+fn set_clear_generated() {
+    let mut root: Root = text_format::parse_from_str(ROOT).unwrap();
+    test_set_clear(&Root::descriptor_static(), &mut root);
+}
+
+#[test]
+fn set_clear_dynamic() {
+    let mut root = dynamic_root(ROOT);
+    test_set_clear(&root.descriptor_dyn(), &mut *root);
+}
+
+fn test_get_mut(descriptor: &MessageDescriptor, m: &mut dyn MessageDyn) {
+    let leaf = path(descriptor, "node.leaves[1]");
+    let name = leaf
+        .get_mut(m)
+        .unwrap()
+        .descriptor_dyn()
+        .field_by_name("name")
+        .unwrap();
+    name.set_singular_field(
+        leaf.get_mut(m).unwrap(),
+        ReflectValueBox::String("g".to_owned()),
+    );
+    assert_eq!(
+        Some(ReflectValueRef::String("g")),
+        path(descriptor, "node.leaves[1].name").get(m)
+    );
+
+    assert!(path(descriptor, "nodes[true].leaf").get_mut(m).is_some());
+    assert!(path(descriptor, "node.leaf_by_name[\"d\"]")
+        .get_mut(m)
+        .is_some());
+    assert!(path(descriptor, "node.leaves[2]").get_mut(m).is_none());
+    assert!(path(descriptor, "nodes[false]").get_mut(m).is_none());
+    assert!(path(descriptor, "node.child.child").get_mut(m).is_none());
+    // `get_mut` does not create messages.
+    assert_eq!(None, path(descriptor, "node.child.child").get(m));
+}
+
+#[test]
+fn get_mut_generated() {
+    let mut root: Root = text_format::parse_from_str(ROOT).unwrap();
+    test_get_mut(&Root::descriptor_static(), &mut root);
+}
+
+#[test]
+fn get_mut_dynamic() {
+    let mut root = dynamic_root(ROOT);
+    test_get_mut(&root.descriptor_dyn(), &mut *root);
+}
+
+#[test]
+fn display() {
+    let descriptor = Root::descriptor_static();
+    for p in [
+        "node.leaves[1].name",
+        "node.leaf_by_name[\"a\\\"b\\\\c\"].values[0]",
+        "names[-3]",
+        "nodes[true]",
+        "id",
+    ] {
+        assert_eq!(p, path(&descriptor, p).to_string());
+    }
+}
+
+#[test]
+fn parse_errors() {
+    let descriptor = Root::descriptor_static();
+    for p in [
+        "",
+        "nod",
+        "node.",
+        "node..leaf",
+        "node.leaves",
+        "node.leaves.name",
+        "node.leaves[x]",
+        "node.leaves[-1]",
+        "node.leaves[0",
+        "node.leaf[0]",
+        "node.leaf_by_name[d]",
+        "node.leaf_by_name[\"d]",
+        "node.leaf_by_name[\"d\\n\"]",
+        "names[\"7\"]",
+        "nodes[1]",
+        "id.x",
+        "node.leaves[0]x",
+    ] {
+        let e = FieldPath::parse(&descriptor, p).unwrap_err();
+        assert_eq!(ErrorKind::Reflect, e.kind(), "{}", p);
+    }
+}
@@ -0,0 +1,119 @@
+use protobuf::reflect::ExtensionRegistry;
+use protobuf::reflect::TypeRegistry;
+use protobuf::text_format;
+use protobuf::text_format::ParseOptions;
+use protobuf::text_format::PrintOptions;
+use protobuf::well_known_types::Any;
+
+use super::test_fmt_text_format_ext_pb::*;
+
+fn print_options() -> PrintOptions {
+    let mut extension_registry = ExtensionRegistry::new();
+    extension_registry.add_file(&file_descriptor());
+    let mut type_registry = TypeRegistry::new();
+    type_registry.add_file(&file_descriptor());
+    PrintOptions {
+        extension_registry,
+        type_registry,
+        ..Default::default()
+    }
+}
+
+fn parse_options() -> ParseOptions {
+    let print_options = print_options();
+    ParseOptions {
+        extension_registry: print_options.extension_registry,
+        type_registry: print_options.type_registry,
+        ..Default::default()
+    }
+}
+
+fn payload(name: &str, values: Vec<i32>) -> Payload {
+    let mut p = Payload::new();
+    p.set_name(name.to_owned());
+    p.values = values;
+    p
+}
+
+#[test]
+fn extensions() {
+    let mut m = Extendable::new();
+    m.set_regular(1);
+    exts::ext_int32.set(&mut m, 2);
+    exts::ext_payload.set(&mut m, payload("p", vec![3]));
+    exts::ext_strings.set(&mut m, vec!["a".to_owned(), "b".to_owned()]);
+
+    let text = text_format::print_to_string_with_options(&m, &print_options());
+    assert_eq!(
+        "regular: 1 \
+         [test_fmt_text_format_ext.ext_int32]: 2 \
+         [test_fmt_text_format_ext.ext_payload] {name: \"p\" values: 3} \
+         [test_fmt_text_format_ext.ext_strings]: \"a\" \
+         [test_fmt_text_format_ext.ext_strings]: \"b\"",
+        text
+    );
+
+    let parsed: Extendable =
+        text_format::parse_from_str_with_options(&text, &parse_options()).unwrap();
+    assert_eq!(1, parsed.regular());
+    assert_eq!(Some(2), exts::ext_int32.get(&parsed));
+    assert_eq!(Some(payload("p", vec![3])), exts::ext_payload.get(&parsed));
+    assert_eq!(
+        vec!["a".to_owned(), "b".to_owned()],
+        exts::ext_strings.get(&parsed)
+    );
+}
+
+#[test]
+fn extensions_without_registry() {
+    let mut m = Extendable::new();
+    exts::ext_int32.set(&mut m, 2);
+    assert_eq!("100: 2", text_format::print_to_string(&m));
+
+    let e = text_format::parse_from_str::<Extendable>("[test_fmt_text_format_ext.ext_int32]: 2");
+    assert!(e.is_err());
+}
+
+#[test]
+fn group_extensions_of_generated_message() {
+    // Group extensions cannot be stored in unknown fields of a generated message.
+    for text in [
+        "[test_fmt_text_format_ext.extgroup] { g: 1 }",
+        "[test_fmt_text_format_ext.extgroups] { g: 1 }",
+    ] {
+        let e = text_format::parse_from_str_with_options::<Extendable>(text, &parse_options())
+            .unwrap_err();
+        assert!(
+            e.to_string().contains("Cannot set field"),
+            "{}: {}",
+            text,
+            e
+        );
+    }
+}
+
+#[test]
+fn any_expansion() {
+    let mut m = Extendable::new();
+    m.any = protobuf::MessageField::some(Any::pack(&payload("x", vec![1, 2])).unwrap());
+
+    let text = text_format::print_to_string_with_options(&m, &print_options());
+    assert_eq!(
+        "any {[type.googleapis.com/test_fmt_text_format_ext.Payload] {name: \"x\" values: 1 values: 2}}",
+        text
+    );
+
+    let parsed: Extendable =
+        text_format::parse_from_str_with_options(&text, &parse_options()).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!(
+        Some(payload("x", vec![1, 2])),
+        parsed.any.unpack::<Payload>().unwrap()
+    );
+
+    // Type not in registry
+    let e = text_format::parse_from_str::<Extendable>(&text);
+    assert!(e.is_err());
+    // Any is printed as a regular message
+    assert!(text_format::print_to_string(&m).starts_with("any {type_url: "));
+}
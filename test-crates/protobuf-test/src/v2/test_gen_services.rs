@@ -0,0 +1,129 @@
+use protobuf::service::Channel;
+use protobuf::service::MethodInfo;
+use protobuf::service::Streaming;
+
+use super::test_gen_services_pb::*;
+
+fn number(value: i32) -> Number {
+    let mut n = Number::new();
+    n.set_value(value);
+    n
+}
+
+struct CalculatorImpl;
+
+impl Calculator for CalculatorImpl {
+    type Error = protobuf::Error;
+
+    fn increment(&self, request: Number) -> protobuf::Result<Number> {
+        Ok(number(request.value() + 1))
+    }
+
+    fn sum<'a>(
+        &'a self,
+        requests: Streaming<'a, Number, protobuf::Error>,
+    ) -> protobuf::Result<Number> {
+        let mut sum = 0;
+        for r in requests {
+            sum += r?.value();
+        }
+        Ok(number(sum))
+    }
+
+    fn count_to<'a>(
+        &'a self,
+        request: Number,
+    ) -> protobuf::Result<Streaming<'a, Number, protobuf::Error>> {
+        Ok(Box::new((1..=request.value()).map(|i| Ok(number(i)))))
+    }
+
+    fn double<'a>(
+        &'a self,
+        requests: Streaming<'a, Number, protobuf::Error>,
+    ) -> protobuf::Result<Streaming<'a, Number, protobuf::Error>> {
+        Ok(Box::new(requests.map(|r| Ok(number(r?.value() * 2)))))
+    }
+}
+
+fn client() -> CalculatorClient<CalculatorServer<CalculatorImpl>> {
+    CalculatorClient::new(CalculatorServer::new(CalculatorImpl))
+}
+
+fn values(
+    responses: protobuf::Result<Streaming<Number, protobuf::Error>>,
+) -> protobuf::Result<Vec<i32>> {
+    responses?.map(|r| r.map(|n| n.value())).collect()
+}
+
+#[test]
+fn unary() {
+    assert_eq!(11, client().increment(&number(10)).unwrap().value());
+}
+
+#[test]
+fn client_streaming() {
+    let sum = client().sum(vec![number(1), number(2), number(3)]).unwrap();
+    assert_eq!(6, sum.value());
+}
+
+#[test]
+fn server_streaming() {
+    let client = client();
+    assert_eq!(vec![1, 2, 3], values(client.count_to(&number(3))).unwrap());
+}
+
+#[test]
+fn bidi_streaming() {
+    let client = client();
+    let doubled = values(client.double(vec![number(1), number(5)])).unwrap();
+    assert_eq!(vec![2, 10], doubled);
+}
+
+#[test]
+fn unknown_method() {
+    let server = CalculatorServer::new(CalculatorImpl);
+    let method = MethodInfo {
+        service: "test_gen_services.Calculator",
+        method: "Sum",
+    };
+    let err = server.unary(&method, Vec::new()).unwrap_err();
+    assert_eq!(
+        "Unknown method `/test_gen_services.Calculator/Sum`",
+        err.to_string()
+    );
+}
+
+struct CounterImpl;
+
+impl Counter for CounterImpl {
+    type Error = protobuf::Error;
+
+    fn increment(&self, request: Number) -> protobuf::Result<Number> {
+        Ok(number(request.value() + 10))
+    }
+}
+
+#[test]
+fn method_of_other_service() {
+    let counter = CounterClient::new(CounterServer::new(CounterImpl));
+    assert_eq!(11, counter.increment(&number(1)).unwrap().value());
+
+    let counter = CounterClient::new(CalculatorServer::new(CalculatorImpl));
+    let err = counter.increment(&number(1)).unwrap_err();
+    assert_eq!(
+        "Unknown method `/test_gen_services.Counter/Increment`",
+        err.to_string()
+    );
+
+    let calculator = CalculatorClient::new(CounterServer::new(CounterImpl));
+    assert!(calculator.increment(&number(1)).is_err());
+}
+
+#[test]
+fn method_info() {
+    let method = MethodInfo {
+        service: "test_gen_services.Calculator",
+        method: "Increment",
+    };
+    assert_eq!("/test_gen_services.Calculator/Increment", method.path());
+}
@@ -0,0 +1,74 @@
+use protobuf::Message;
+use protobuf_test_common::hex::decode_hex;
+use protobuf_test_common::*;
+
+use super::test_group_pb::message_with_group::Identifier;
+use super::test_group_pb::*;
+
+fn message_with_group() -> MessageWithGroup {
+    let mut identifier = Identifier::new();
+    identifier.set_iii(1);
+    identifier.set_sss("s".to_owned());
+
+    let mut m = MessageWithGroup::new();
+    m.set_aaa("a".to_owned());
+    m.identifier.push(identifier);
+    m
+}
+
+#[test]
+fn test_serialize_deserialize() {
+    test_serialize_deserialize_with_dynamic(
+        "0a 01 61 93 01 98 01 01 a2 01 01 73 94 01",
+        &message_with_group(),
+    );
+}
+
+#[test]
+fn test_serialize_deserialize_repeated() {
+    let mut m = message_with_group();
+    m.identifier.push(Identifier::new());
+    test_serialize_deserialize_no_hex_with_dynamic(&m);
+}
+
+#[test]
+fn test_singular_group() {
+    let mut m = MessageWithSingularGroup::new();
+    m.g.mut_or_default().set_s("s".to_owned());
+    test_serialize_deserialize_with_dynamic("0b 12 01 73 0c", &m);
+}
+
+#[test]
+fn test_singular_group_field_with_group_number() {
+    let mut m = MessageWithSingularGroup::new();
+    m.g.mut_or_default().set_a(5);
+    test_serialize_deserialize_with_dynamic("0b 08 05 0c", &m);
+}
+
+#[test]
+fn test_skip_unknown_nested_group() {
+    // field 5 is a group containing group 6 containing varint field 1
+    let m = MessageWithGroup::parse_from_bytes(&decode_hex("2b 33 08 01 34 2c 0a 01 61")).unwrap();
+    assert_eq!("a", m.aaa());
+}
+
+#[test]
+fn test_mismatched_end_group() {
+    assert!(MessageWithGroup::parse_from_bytes(&decode_hex("2b 34")).is_err());
+    assert!(MessageWithGroup::parse_from_bytes(&decode_hex("93 01 9c 01")).is_err());
+}
+
+#[test]
+fn test_unterminated_group() {
+    assert!(MessageWithGroup::parse_from_bytes(&decode_hex("93 01 98 01 01")).is_err());
+}
+
+#[test]
+fn test_text_format() {
+    test_text_format_message(&message_with_group());
+}
+
+#[test]
+fn test_json() {
+    test_json_message(&message_with_group());
+}
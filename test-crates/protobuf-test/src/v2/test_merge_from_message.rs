@@ -0,0 +1,186 @@
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDifferencer;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::text_format;
+use protobuf::Message;
+use protobuf::MessageDyn;
+
+use super::test_merge_from_message_pb;
+use super::test_merge_from_message_pb::Outer;
+
+const TARGET: &str = r#"
+    i: 1
+    s: "s"
+    inner { a: 1 b: "b" c: 1 }
+    inners { a: 1 }
+    strings: "x"
+    inner_map { key: "k" value { a: 1 b: "b" } }
+    inner_map { key: "l" value { a: 1 } }
+    int_map { key: 1 value: 1 }
+    one_inner { a: 1 b: "b" }
+    G { x: 1 y: 1 }
+"#;
+
+const SOURCE: &str = r#"
+    i: 2
+    bs: "bs"
+    inner { a: 2 c: 2 }
+    inners { a: 2 }
+    strings: "y"
+    inner_map { key: "k" value { b: "c" } }
+    int_map { key: 2 value: 2 }
+    one_inner { a: 2 }
+    G { x: 2 }
+"#;
+
+const MERGED: &str = r#"
+    i: 2
+    s: "s"
+    bs: "bs"
+    inner { a: 2 b: "b" c: 1 c: 2 }
+    inners { a: 1 }
+    inners { a: 2 }
+    strings: "x"
+    strings: "y"
+    inner_map { key: "k" value { b: "c" } }
+    inner_map { key: "l" value { a: 1 } }
+    int_map { key: 1 value: 1 }
+    int_map { key: 2 value: 2 }
+    one_inner { a: 2 b: "b" }
+    G { x: 2 y: 1 }
+"#;
+
+#[test]
+fn generated() {
+    let target: Outer = text_format::parse_from_str(TARGET).unwrap();
+    let source: Outer = text_format::parse_from_str(SOURCE).unwrap();
+    let expected: Outer = text_format::parse_from_str(MERGED).unwrap();
+
+    let mut merged = target;
+    merged.merge_from_message(&source);
+    assert_eq!(expected, merged);
+}
+
+#[test]
+fn oneof_replaces() {
+    let mut target: Outer = text_format::parse_from_str(TARGET).unwrap();
+    let source: Outer = text_format::parse_from_str("one_string: \"o\"").unwrap();
+    target.merge_from_message(&source);
+    assert!(!target.has_one_inner());
+    assert_eq!("o", target.one_string());
+
+    let source: Outer = text_format::parse_from_str("one_inner { a: 3 }").unwrap();
+    target.merge_from_message(&source);
+    assert_eq!(3, target.one_inner().a());
+    assert!(!target.has_one_string());
+}
+
+#[test]
+fn unknown_fields() {
+    let mut target = Outer::new();
+    target.mut_unknown_fields().add_varint(100, 1);
+    let mut source = Outer::new();
+    source.mut_unknown_fields().add_varint(100, 2);
+    source.mut_unknown_fields().add_fixed32(101, 3);
+    target.merge_from_message(&source);
+    assert_eq!(
+        &[1, 2],
+        &target.unknown_fields().get(100).unwrap().varint[..]
+    );
+    assert_eq!(&[3], &target.unknown_fields().get(101).unwrap().fixed32[..]);
+}
+
+#[test]
+fn dyn_generated() {
+    let mut merged: Outer = text_format::parse_from_str(TARGET).unwrap();
+    let source: Outer = text_format::parse_from_str(SOURCE).unwrap();
+    let expected: Outer = text_format::parse_from_str(MERGED).unwrap();
+    (&mut merged as &mut dyn MessageDyn).merge_from_message_dyn(&source);
+    assert_eq!(expected, merged);
+}
+
+#[test]
+fn dynamic() {
+    let file_descriptor = FileDescriptor::new_dynamic(
+        test_merge_from_message_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let descriptor = file_descriptor
+        .message_by_package_relative_name("Outer")
+        .unwrap();
+    let parse = |text: &str| {
+        let mut m = descriptor.new_instance();
+        text_format::merge_from_str(&mut *m, text).unwrap();
+        m
+    };
+
+    let mut merged = parse(TARGET);
+    let source = parse(SOURCE);
+    merged.merge_from_message_dyn(&*source);
+    let differencer = MessageDifferencer::default();
+    assert_eq!(
+        Vec::<protobuf::reflect::Difference>::new(),
+        differencer.diff(&*parse(MERGED), &*merged)
+    );
+
+    let mut merged_oneof = parse(TARGET);
+    merged_oneof.merge_from_message_dyn(&*parse("one_string: \"o\""));
+    assert!(differencer.equals(
+        &*parse(&TARGET.replace("one_inner { a: 1 b: \"b\" }", "one_string: \"o\"")),
+        &*merged_oneof
+    ));
+}
+
+#[test]
+fn dynamic_extensions() {
+    let file_descriptor = FileDescriptor::new_dynamic(
+        test_merge_from_message_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let descriptor = file_descriptor
+        .message_by_package_relative_name("Outer")
+        .unwrap();
+    let ext_inner = file_descriptor.extensions()[0].clone();
+    let ext_ints = file_descriptor.extensions()[1].clone();
+    assert_eq!("ext_inner", ext_inner.name());
+    let inner = file_descriptor
+        .message_by_package_relative_name("Inner")
+        .unwrap();
+    let a = inner.field_by_name("a").unwrap();
+    let b = inner.field_by_name("b").unwrap();
+
+    let mut target = descriptor.new_instance();
+    a.set_singular_field(ext_inner.mut_message(&mut *target), ReflectValueBox::I32(1));
+    b.set_singular_field(
+        ext_inner.mut_message(&mut *target),
+        ReflectValueBox::String("b".to_owned()),
+    );
+    ext_ints
+        .mut_repeated(&mut *target)
+        .push(ReflectValueBox::I32(1));
+
+    let mut source = descriptor.new_instance();
+    a.set_singular_field(ext_inner.mut_message(&mut *source), ReflectValueBox::I32(2));
+    ext_ints
+        .mut_repeated(&mut *source)
+        .push(ReflectValueBox::I32(2));
+
+    target.merge_from_message_dyn(&*source);
+    let merged_inner = ext_inner.mut_message(&mut *target);
+    assert_eq!(
+        Some(ReflectValueBox::I32(2)),
+        a.get_singular(merged_inner).map(|v| v.to_box())
+    );
+    assert_eq!(
+        Some(ReflectValueBox::String("b".to_owned())),
+        b.get_singular(merged_inner).map(|v| v.to_box())
+    );
+    let ints: Vec<_> = ext_ints
+        .get_repeated(&*target)
+        .into_iter()
+        .map(|v| v.to_box())
+        .collect();
+    assert_eq!(vec![ReflectValueBox::I32(1), ReflectValueBox::I32(2)], ints);
+}
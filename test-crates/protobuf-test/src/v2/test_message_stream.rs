@@ -0,0 +1,119 @@
+use std::io::BufReader;
+
+use protobuf::stream::DynamicMessageStreamReader;
+use protobuf::stream::DynamicMessageStreamWriter;
+use protobuf::stream::MessageStreamReader;
+use protobuf::stream::MessageStreamWriter;
+use protobuf::Message;
+use protobuf::MessageFull;
+
+use super::test_message_stream_pb::TestMessageStream;
+
+fn messages() -> Vec<TestMessageStream> {
+    let mut r = Vec::new();
+    // Empty message is serialized as single zero length byte.
+    r.push(TestMessageStream::new());
+    for i in 0..100 {
+        let mut m = TestMessageStream::new();
+        m.set_name(format!("m{}", i));
+        m.values = (0..i).collect();
+        r.push(m);
+    }
+    r
+}
+
+fn write_messages(messages: &[TestMessageStream]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut writer = MessageStreamWriter::new(&mut bytes);
+    for m in messages {
+        writer.write(m).unwrap();
+    }
+    writer.flush().unwrap();
+    drop(writer);
+    bytes
+}
+
+#[test]
+fn read_write() {
+    let messages = messages();
+    let bytes = write_messages(&messages);
+
+    let mut expected = Vec::new();
+    for m in &messages {
+        m.write_length_delimited_to_vec(&mut expected).unwrap();
+    }
+    assert_eq!(expected, bytes);
+
+    let mut read = &bytes[..];
+    let read: Vec<TestMessageStream> = MessageStreamReader::new(&mut read)
+        .collect::<protobuf::Result<_>>()
+        .unwrap();
+    assert_eq!(messages, read);
+
+    // Small buffer so messages cross buffer boundary.
+    let mut buf_read = BufReader::with_capacity(7, &bytes[..]);
+    let read: Vec<TestMessageStream> = MessageStreamReader::from_buffered_reader(&mut buf_read)
+        .collect::<protobuf::Result<_>>()
+        .unwrap();
+    assert_eq!(messages, read);
+}
+
+#[test]
+fn empty_input() {
+    let mut read: &[u8] = &[];
+    let mut reader = MessageStreamReader::<TestMessageStream>::new(&mut read);
+    assert!(reader.read().unwrap().is_none());
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn truncated_last_message() {
+    let messages = messages();
+    let bytes = write_messages(&messages[..3]);
+
+    // Truncated in the middle of message body.
+    let mut read = &bytes[..bytes.len() - 1];
+    let mut reader = MessageStreamReader::<TestMessageStream>::new(&mut read);
+    assert_eq!(messages[0], reader.next().unwrap().unwrap());
+    assert_eq!(messages[1], reader.next().unwrap().unwrap());
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+
+    // Truncated in the middle of length varint.
+    let mut bytes = write_messages(&messages[..1]);
+    bytes.push(0x80);
+    let mut read = &bytes[..];
+    let mut reader = MessageStreamReader::<TestMessageStream>::new(&mut read);
+    assert_eq!(messages[0], reader.next().unwrap().unwrap());
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn dynamic() {
+    let messages = messages();
+    let descriptor = TestMessageStream::descriptor_static();
+
+    let mut bytes = Vec::new();
+    let mut writer = DynamicMessageStreamWriter::new(descriptor.clone(), &mut bytes);
+    for m in &messages {
+        writer.write(m).unwrap();
+    }
+    writer.flush().unwrap();
+    drop(writer);
+    assert_eq!(write_messages(&messages), bytes);
+
+    let mut read = &bytes[..];
+    let reader = DynamicMessageStreamReader::new(descriptor.clone(), &mut read);
+    let read: Vec<_> = reader.map(|m| m.unwrap()).collect();
+    assert_eq!(messages.len(), read.len());
+    for (m, r) in messages.iter().zip(&read) {
+        assert!(descriptor.eq(m, &**r));
+    }
+
+    let mut read = &bytes[..bytes.len() - 1];
+    let reader = DynamicMessageStreamReader::new(descriptor.clone(), &mut read);
+    let results: Vec<_> = reader.collect();
+    assert_eq!(messages.len(), results.len());
+    assert!(results.last().unwrap().is_err());
+}
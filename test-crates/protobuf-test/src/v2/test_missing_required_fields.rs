@@ -0,0 +1,122 @@
+use protobuf::reflect::FileDescriptor;
+use protobuf::text_format;
+use protobuf::CodedInputStream;
+use protobuf::CodedOutputStream;
+use protobuf::ErrorKind;
+use protobuf::Message;
+use protobuf::MessageDyn;
+use protobuf::MessageFull;
+
+use super::test_missing_required_fields_pb;
+use super::test_missing_required_fields_pb::Customer;
+
+const CUSTOMER: &str = r#"
+    order {
+        items { sku: "a" }
+        items { quantity: 2 }
+        items {
+            sku: "c"
+            parts { sku: "d" }
+            parts { }
+        }
+        items_by_name { key: "x" value { quantity: 1 } }
+        notes { key: 1 value: "n" }
+        gift_item { }
+    }
+    favorite { sku: "e" }
+"#;
+
+fn expected_missing() -> Vec<&'static str> {
+    vec![
+        "name",
+        "order.id",
+        "order.items[1].sku",
+        "order.items[2].parts[1].sku",
+        "order.items_by_name[\"x\"].sku",
+        "order.gift_item.sku",
+    ]
+}
+
+fn test_missing_required_fields(message: &mut dyn MessageDyn) {
+    assert_eq!(vec!["name"], message.missing_required_fields_dyn());
+    text_format::merge_from_str(message, CUSTOMER).unwrap();
+    assert!(!message.is_initialized_dyn());
+    assert_eq!(expected_missing(), message.missing_required_fields_dyn());
+
+    let err = message.check_initialized_dyn().unwrap_err();
+    assert_eq!(ErrorKind::MessageNotInitialized, err.kind());
+    assert_eq!(expected_missing(), err.missing_required_fields());
+}
+
+#[test]
+fn missing_required_fields_generated() {
+    let mut message = Customer::new();
+    test_missing_required_fields(&mut message);
+
+    assert_eq!(expected_missing(), message.missing_required_fields());
+    let err = message.check_initialized().unwrap_err();
+    assert_eq!(expected_missing(), err.missing_required_fields());
+    assert_eq!(
+        "Message `Customer` is missing required fields: name, order.id, \
+         order.items[1].sku, order.items[2].parts[1].sku, \
+         order.items_by_name[\"x\"].sku, order.gift_item.sku",
+        err.to_string()
+    );
+}
+
+#[test]
+fn missing_required_fields_dynamic() {
+    let file_descriptor = FileDescriptor::new_dynamic(
+        test_missing_required_fields_pb::file_descriptor_proto().clone(),
+        Vec::new(),
+    )
+    .unwrap();
+    let mut message = file_descriptor
+        .message_by_package_relative_name("Customer")
+        .unwrap()
+        .new_instance();
+    test_missing_required_fields(&mut *message);
+}
+
+#[test]
+fn missing_required_fields_parse() {
+    let mut order = test_missing_required_fields_pb::Order::new();
+    order.items.push(Default::default());
+    let mut bytes = Vec::new();
+    order.compute_size();
+    let mut os = CodedOutputStream::vec(&mut bytes);
+    order.write_to_with_cached_sizes(&mut os).unwrap();
+    os.flush().unwrap();
+    drop(os);
+
+    let err = test_missing_required_fields_pb::Order::parse_from_bytes(&bytes).unwrap_err();
+    assert_eq!(&["id", "items[0].sku"], err.missing_required_fields());
+}
+
+#[test]
+fn read_message_checks_required_fields() {
+    let mut order = test_missing_required_fields_pb::Order::new();
+    order.items.push(Default::default());
+    let bytes = order.write_length_delimited_to_bytes().unwrap();
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    let err = is
+        .read_message::<test_missing_required_fields_pb::Order>()
+        .unwrap_err();
+    assert_eq!(&["id", "items[0].sku"], err.missing_required_fields());
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    let err = is
+        .read_message_dyn(&test_missing_required_fields_pb::Order::descriptor_static())
+        .unwrap_err();
+    assert_eq!(&["id", "items[0].sku"], err.missing_required_fields());
+}
+
+#[test]
+fn missing_required_fields_empty_when_initialized() {
+    let mut message = Customer::new();
+    message.set_name("n".to_owned());
+    assert!(message.is_initialized());
+    assert!(message.missing_required_fields().is_empty());
+    assert!(message.check_initialized().is_ok());
+}
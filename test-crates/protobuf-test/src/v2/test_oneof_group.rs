@@ -1,6 +1,18 @@
+use protobuf_test_common::*;
+
+use super::test_oneof_group_pb::a;
 use super::test_oneof_group_pb::A;
 
 #[test]
 fn test() {
     A::new();
 }
+
+#[test]
+fn test_serialize_deserialize() {
+    let mut c = a::C::new();
+    c.set_d(10);
+    let mut m = A::new();
+    m.set_c(c);
+    test_serialize_deserialize_with_dynamic("0b 10 0a 0c", &m);
+}
@@ -0,0 +1,163 @@
+use protobuf::CodedInputStream;
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::ParseOptions;
+
+use super::test_parse_options_pb::TestParseOptions;
+
+fn parse_both(bytes: &[u8], options: &ParseOptions) -> Result<(), String> {
+    let generated = TestParseOptions::parse_from_bytes_with_options(bytes, options)
+        .map(|_| ())
+        .map_err(|e| e.to_string());
+    let dynamic = TestParseOptions::descriptor_static()
+        .parse_from_bytes_with_options(bytes, options)
+        .map(|_| ())
+        .map_err(|e| e.to_string());
+    assert_eq!(generated, dynamic);
+    generated
+}
+
+fn assert_limit_exceeded(bytes: &[u8], options: &ParseOptions, limit: &str) {
+    let err = parse_both(bytes, options).unwrap_err();
+    assert!(err.contains(limit), "{}", err);
+    // Default options accept the message.
+    parse_both(bytes, &ParseOptions::default()).unwrap();
+}
+
+#[test]
+fn max_total_bytes() {
+    let mut m = TestParseOptions::new();
+    m.set_s("abcdef".to_owned());
+    let bytes = m.write_to_bytes().unwrap();
+
+    let options = |max_total_bytes| ParseOptions {
+        max_total_bytes: Some(max_total_bytes),
+        ..Default::default()
+    };
+    parse_both(&bytes, &options(bytes.len() as u64)).unwrap();
+    assert_limit_exceeded(&bytes, &options(bytes.len() as u64 - 1), "max_total_bytes");
+    // Limit at a field boundary.
+    assert_limit_exceeded(&bytes, &options(0), "max_total_bytes");
+
+    let mut reader = &bytes[..];
+    TestParseOptions::parse_from_reader_with_options(&mut reader, &options(bytes.len() as u64))
+        .unwrap();
+    let mut reader = &bytes[..];
+    let err = TestParseOptions::parse_from_reader_with_options(&mut reader, &options(2))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("max_total_bytes"), "{}", err);
+}
+
+#[test]
+fn max_total_bytes_reported_after_position() {
+    let mut m = TestParseOptions::new();
+    m.set_s("abcdef".to_owned());
+    let mut bytes = m.write_length_delimited_to_bytes().unwrap();
+    bytes.extend(m.write_length_delimited_to_bytes().unwrap());
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.read_message::<TestParseOptions>().unwrap();
+    is.set_parse_options(&ParseOptions {
+        max_total_bytes: Some(3),
+        ..Default::default()
+    });
+    let err = is.read_message::<TestParseOptions>().unwrap_err();
+    assert_eq!(
+        "Decode limit `max_total_bytes` of 3 exceeded",
+        err.to_string()
+    );
+}
+
+#[test]
+fn max_string_and_bytes_len() {
+    let mut m = TestParseOptions::new();
+    m.set_s("abcdef".to_owned());
+    m.set_b(vec![1, 2, 3]);
+    let bytes = m.write_to_bytes().unwrap();
+
+    let options = ParseOptions {
+        max_string_len: Some(6),
+        max_bytes_len: Some(3),
+        ..Default::default()
+    };
+    parse_both(&bytes, &options).unwrap();
+
+    let options = ParseOptions {
+        max_string_len: Some(5),
+        ..Default::default()
+    };
+    assert_limit_exceeded(&bytes, &options, "max_string_len");
+
+    let options = ParseOptions {
+        max_bytes_len: Some(2),
+        ..Default::default()
+    };
+    assert_limit_exceeded(&bytes, &options, "max_bytes_len");
+}
+
+#[test]
+fn max_repeated_len() {
+    let options = ParseOptions {
+        max_repeated_len: Some(3),
+        ..Default::default()
+    };
+
+    let mut m = TestParseOptions::new();
+    m.unpacked = vec![1, 2, 3];
+    m.packed = vec![1, 2, 3];
+    m.nested = vec![TestParseOptions::new(); 3];
+    parse_both(&m.write_to_bytes().unwrap(), &options).unwrap();
+
+    let mut m = TestParseOptions::new();
+    m.unpacked = vec![1, 2, 3, 4];
+    assert_limit_exceeded(&m.write_to_bytes().unwrap(), &options, "max_repeated_len");
+
+    let mut m = TestParseOptions::new();
+    m.packed = vec![1, 2, 3, 4];
+    assert_limit_exceeded(&m.write_to_bytes().unwrap(), &options, "max_repeated_len");
+
+    let mut m = TestParseOptions::new();
+    m.nested = vec![TestParseOptions::new(); 4];
+    assert_limit_exceeded(&m.write_to_bytes().unwrap(), &options, "max_repeated_len");
+}
+
+#[test]
+fn max_map_entries() {
+    let mut m = TestParseOptions::new();
+    m.entries.insert("a".to_owned(), 1);
+    m.entries.insert("b".to_owned(), 2);
+    let bytes = m.write_to_bytes().unwrap();
+
+    let options = ParseOptions {
+        max_map_entries: Some(2),
+        ..Default::default()
+    };
+    parse_both(&bytes, &options).unwrap();
+
+    let options = ParseOptions {
+        max_map_entries: Some(1),
+        ..Default::default()
+    };
+    assert_limit_exceeded(&bytes, &options, "max_map_entries");
+}
+
+#[test]
+fn recursion_limit() {
+    let mut m = TestParseOptions::new();
+    m.nested.push(TestParseOptions::new());
+    m.nested[0].nested.push(TestParseOptions::new());
+    let bytes = m.write_to_bytes().unwrap();
+
+    let options = ParseOptions {
+        recursion_limit: 2,
+        ..Default::default()
+    };
+    TestParseOptions::parse_from_bytes_with_options(&bytes, &options).unwrap();
+
+    let options = ParseOptions {
+        recursion_limit: 1,
+        ..Default::default()
+    };
+    assert!(TestParseOptions::parse_from_bytes_with_options(&bytes, &options).is_err());
+}
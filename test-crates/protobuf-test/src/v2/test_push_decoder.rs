@@ -0,0 +1,177 @@
+use protobuf::stream::DecodeStatus;
+use protobuf::stream::DynamicMessageDecoder;
+use protobuf::stream::MessageDecoder;
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::ParseOptions;
+
+use super::test_push_decoder_pb::test_push_decoder::Item;
+use super::test_push_decoder_pb::TestPushDecoder;
+
+fn messages() -> Vec<TestPushDecoder> {
+    (0..20)
+        .map(|i| {
+            let mut m = TestPushDecoder::new();
+            m.set_req(i);
+            if i % 2 == 0 {
+                m.set_name(format!("m{}", i));
+            }
+            m.values = (0..i as i64).map(|v| v << 40).collect();
+            m.set_f32(i as u32);
+            m.set_f64(i as u64);
+            m.nested.mut_or_default().set_value(i);
+            for j in 0..i % 3 {
+                let mut item = Item::new();
+                item.set_key(format!("k{}", j));
+                m.item.push(item);
+            }
+            m
+        })
+        .collect()
+}
+
+fn length_delimited(messages: &[TestPushDecoder]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for m in messages {
+        m.write_length_delimited_to_vec(&mut bytes).unwrap();
+    }
+    bytes
+}
+
+fn decode_chunks(
+    decoder: &mut MessageDecoder<TestPushDecoder>,
+    bytes: &[u8],
+    chunk_size: usize,
+) -> protobuf::Result<Vec<TestPushDecoder>> {
+    let mut r = Vec::new();
+    for chunk in bytes.chunks(chunk_size) {
+        let mut chunk = chunk;
+        while !chunk.is_empty() {
+            if let DecodeStatus::Message(m) = decoder.feed(&mut chunk)? {
+                r.push(m);
+            }
+        }
+    }
+    if let Some(m) = decoder.finish()? {
+        r.push(m);
+    }
+    Ok(r)
+}
+
+#[test]
+fn length_delimited_chunks() {
+    let messages = messages();
+    let bytes = length_delimited(&messages);
+    for chunk_size in [1, 2, 3, 7, 100, bytes.len()] {
+        let mut decoder = MessageDecoder::length_delimited();
+        let decoded = decode_chunks(&mut decoder, &bytes, chunk_size).unwrap();
+        assert_eq!(messages, decoded, "chunk_size={}", chunk_size);
+    }
+}
+
+#[test]
+fn single_message() {
+    let m = messages().pop().unwrap();
+    let bytes = m.write_to_bytes().unwrap();
+    let mut decoder = MessageDecoder::new();
+    for chunk_size in [1, 5, bytes.len()] {
+        let decoded = decode_chunks(&mut decoder, &bytes, chunk_size).unwrap();
+        assert_eq!(vec![m.clone()], decoded);
+    }
+}
+
+#[test]
+fn dynamic() {
+    let messages = messages();
+    let bytes = length_delimited(&messages);
+    let descriptor = TestPushDecoder::descriptor_static();
+    let mut decoder = DynamicMessageDecoder::length_delimited(descriptor.clone());
+    let mut decoded = Vec::new();
+    for b in &bytes {
+        if let DecodeStatus::Message(m) = decoder.feed(&mut &[*b][..]).unwrap() {
+            decoded.push(m);
+        }
+    }
+    assert!(decoder.finish().unwrap().is_none());
+    assert_eq!(messages.len(), decoded.len());
+    for (m, d) in messages.iter().zip(&decoded) {
+        assert!(descriptor.eq(m, &**d));
+    }
+}
+
+#[test]
+fn truncated() {
+    let messages = messages();
+    let bytes = length_delimited(&messages);
+    let mut decoder = MessageDecoder::length_delimited();
+    assert!(decode_chunks(&mut decoder, &bytes[..bytes.len() - 1], 10).is_err());
+
+    // Input ends inside a group.
+    let bytes = messages[2].write_to_bytes().unwrap();
+    let group_end = bytes.iter().rposition(|&b| b == (6 << 3 | 4)).unwrap();
+    let mut decoder = MessageDecoder::<TestPushDecoder>::new();
+    decoder.feed(&mut &bytes[..group_end]).unwrap();
+    assert!(decoder.finish().is_err());
+}
+
+#[test]
+fn malformed_input_reported_early() {
+    // Field number zero.
+    let mut decoder = MessageDecoder::<TestPushDecoder>::new();
+    assert!(decoder.feed(&mut &[0][..]).is_err());
+
+    // Unknown wire type.
+    assert!(decoder.feed(&mut &[1 << 3 | 7][..]).is_err());
+
+    // End group without start group.
+    assert!(decoder.feed(&mut &[6 << 3 | 4][..]).is_err());
+
+    // Varint longer than 10 bytes.
+    assert!(decoder
+        .feed(&mut &[0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff][..])
+        .is_err());
+
+    // Decoder is reset after error.
+    let m = &messages()[3];
+    let decoded = decode_chunks(&mut decoder, &m.write_to_bytes().unwrap(), 4).unwrap();
+    assert_eq!(vec![m.clone()], decoded);
+}
+
+#[test]
+fn missing_required_field() {
+    // Only `name` field.
+    let bytes = [1 << 3 | 2, 1, b'n'];
+    let mut decoder = MessageDecoder::<TestPushDecoder>::new();
+    decoder.feed(&mut &bytes[..]).unwrap();
+    assert!(decoder.finish().is_err());
+}
+
+#[test]
+fn parse_options() {
+    let messages = messages();
+    let bytes = length_delimited(&messages);
+
+    let mut decoder = MessageDecoder::length_delimited();
+    decoder.set_parse_options(&ParseOptions {
+        max_total_bytes: Some(20),
+        ..Default::default()
+    });
+    let err = decode_chunks(&mut decoder, &bytes, 1).unwrap_err();
+    assert!(err.to_string().contains("max_total_bytes"), "{}", err);
+
+    let mut decoder = MessageDecoder::length_delimited();
+    decoder.set_parse_options(&ParseOptions {
+        max_repeated_len: Some(5),
+        ..Default::default()
+    });
+    let err = decode_chunks(&mut decoder, &bytes, 3).unwrap_err();
+    assert!(err.to_string().contains("max_repeated_len"), "{}", err);
+
+    let mut decoder = MessageDecoder::<TestPushDecoder>::new();
+    decoder.set_parse_options(&ParseOptions {
+        max_total_bytes: Some(10),
+        ..Default::default()
+    });
+    let err = decoder.feed(&mut &[0; 11][..]).unwrap_err();
+    assert!(err.to_string().contains("max_total_bytes"), "{}", err);
+}
@@ -0,0 +1,131 @@
+use protobuf::CodedOutputStream;
+use protobuf::EnumOrUnknown;
+use protobuf::Message;
+use protobuf::ReverseOutputStream;
+
+use super::test_single_pass_pb::outer::Group;
+use super::test_single_pass_pb::Color;
+use super::test_single_pass_pb::Inner;
+use super::test_single_pass_pb::Outer;
+
+fn inner(name: &str, values: &[i32], depth: u32) -> Inner {
+    let mut inner = Inner::new();
+    inner.set_name(name.to_owned());
+    inner.values = values.to_vec();
+    if depth > 0 {
+        inner.child = Some(self::inner("child", &[-1], depth - 1)).into();
+    }
+    inner
+}
+
+fn outer() -> Outer {
+    let mut m = Outer::new();
+    m.set_i32(-3);
+    m.set_f64(1 << 60);
+    m.set_by(vec![0; 300]);
+    m.set_color(Color::GREEN);
+    m.inner = Some(inner("in", &[1, -200, 70000], 3)).into();
+    m.inners = vec![inner("a", &[], 0), Inner::new(), inner("b", &[5], 1)];
+    m.unpacked = vec![-1, 0, 1 << 40];
+    m.colors = vec![EnumOrUnknown::new(Color::RED), EnumOrUnknown::from_i32(-7)];
+    m.doubles = vec![0.5, -2.0];
+    for i in 0..10 {
+        m.map.insert(i * 100, inner(&i.to_string(), &[i], 1));
+    }
+    let mut group = Group::new();
+    group.set_x(9);
+    group.inner = Some(inner("g", &[], 2)).into();
+    m.group = Some(group).into();
+    m.set_one_inner(inner("one", &[4], 0));
+    m.set_two_bool(true);
+    m.mut_unknown_fields().add_varint(1000, 17);
+    m.mut_unknown_fields()
+        .add_length_delimited(1001, b"unknown".to_vec());
+    m
+}
+
+/// Serialize with `compute_size` and `write_to_with_cached_sizes`.
+fn write_two_pass(m: &Outer, deterministic: bool) -> Vec<u8> {
+    let mut v = Vec::new();
+    let mut os = CodedOutputStream::vec(&mut v);
+    os.set_deterministic(deterministic);
+    m.write_to(&mut os).unwrap();
+    os.flush().unwrap();
+    drop(os);
+    v
+}
+
+#[test]
+fn same_as_two_pass() {
+    let m = outer();
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(m.compute_size(), bytes.len() as u64);
+    assert_eq!(write_two_pass(&m, false).len(), bytes.len());
+    assert_eq!(m, Outer::parse_from_bytes(&bytes).unwrap());
+
+    let mut vec = vec![1, 2];
+    m.write_to_vec(&mut vec).unwrap();
+    assert_eq!(&bytes[..], &vec[2..]);
+}
+
+#[test]
+fn deterministic() {
+    let m = outer();
+    let mut os = ReverseOutputStream::new();
+    os.set_deterministic(true);
+    m.write_reverse(&mut os).unwrap();
+    assert_eq!(write_two_pass(&m, true), os.into_vec());
+}
+
+#[test]
+fn unknown_fields_written_last() {
+    let mut m = Outer::new();
+    m.set_i32(1);
+    m.mut_unknown_fields().add_varint(1000, 2);
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(&[5 << 3, 1, 0xc0, 0x3e, 2], &bytes[..]);
+
+    let parsed = Outer::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(&[2], &parsed.unknown_fields().get(1000).unwrap().varint[..]);
+}
+
+#[test]
+fn length_delimited() {
+    let m = outer();
+    let bytes = m.write_length_delimited_to_bytes().unwrap();
+
+    let mut vec = Vec::new();
+    m.write_length_delimited_to_vec(&mut vec).unwrap();
+    assert_eq!(bytes, vec);
+
+    let mut v = Vec::new();
+    let mut os = CodedOutputStream::vec(&mut v);
+    m.write_length_delimited_to(&mut os).unwrap();
+    os.flush().unwrap();
+    drop(os);
+    assert_eq!(v.len(), bytes.len());
+
+    let mut is = protobuf::CodedInputStream::from_bytes(&bytes);
+    assert_eq!(m, is.read_message::<Outer>().unwrap());
+    assert!(is.eof().unwrap());
+}
+
+#[test]
+fn not_initialized() {
+    use super::test_required_pb::TestRequired;
+
+    let m = TestRequired::new();
+    assert!(m.write_to_bytes().is_err());
+    assert!(m.write_to_vec(&mut Vec::new()).is_err());
+}
+
+#[test]
+fn packed_negative_enum() {
+    let mut m = Outer::new();
+    m.colors = vec![EnumOrUnknown::from_i32(-7)];
+    let bytes = m.write_to_bytes().unwrap();
+    // Negative values are encoded in 10 bytes.
+    assert_eq!(&[8 << 3 | 2, 10], &bytes[..2]);
+    assert_eq!(bytes.len() as u64, m.compute_size());
+    assert_eq!(m, Outer::parse_from_bytes(&bytes).unwrap());
+}
@@ -0,0 +1,134 @@
+use protobuf::EnumOrUnknown;
+use protobuf::Message;
+
+use super::test_table_driven_classic_pb as classic;
+use super::test_table_driven_pb::Color;
+use super::test_table_driven_pb::Inner;
+use super::test_table_driven_pb::Outer;
+
+fn inner(name: &str, values: &[i32]) -> classic::Inner {
+    let mut inner = classic::Inner::new();
+    inner.set_name(name.to_owned());
+    inner.values = values.to_vec();
+    inner
+}
+
+fn classic_outer() -> classic::Outer {
+    let mut m = classic::Outer::new();
+    m.set_i32(-17);
+    m.set_s64(-1 << 40);
+    m.set_f32(0xabcd);
+    m.set_d(1.5);
+    m.set_b(false);
+    m.set_s("world".to_owned());
+    m.set_by(vec![1, 2, 3]);
+    m.set_color(classic::Color::BLUE);
+    m.inner = Some(inner("in", &[1, 2])).into();
+    m.inners = vec![inner("a", &[]), inner("b", &[3])];
+    m.unpacked = vec![1, -1, 300];
+    m.packed = vec![0, 1 << 50, 7];
+    m.colors = vec![
+        EnumOrUnknown::new(classic::Color::RED),
+        EnumOrUnknown::from_i32(10),
+    ];
+    m.strings = vec!["x".to_owned(), "".to_owned()];
+    m.map.insert("k".to_owned(), inner("v", &[5]));
+    m.set_req(2.5);
+    m.set_one_inner(inner("one", &[-1]));
+    m.set_far(1 << 40);
+    m
+}
+
+#[test]
+fn same_encoding_as_classic() {
+    let classic = classic_outer();
+    let bytes = classic.write_to_bytes().unwrap();
+
+    let table = Outer::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(-17, table.i32());
+    assert_eq!(-1 << 40, table.s64());
+    assert_eq!(Color::BLUE, table.color());
+    assert_eq!(&[1, -1, 300], &table.unpacked[..]);
+    assert_eq!(10, table.colors[1].value());
+    assert_eq!("v", table.map["k"].name());
+    assert_eq!("one", table.one_inner().name());
+    assert_eq!(1 << 40, table.far());
+    assert_eq!(0, table.unknown_fields().iter().count());
+
+    assert_eq!(bytes.len() as u64, table.compute_size());
+    assert_eq!(bytes, table.write_to_bytes().unwrap());
+
+    let mut forward = Vec::new();
+    let mut os = protobuf::CodedOutputStream::vec(&mut forward);
+    table.write_to(&mut os).unwrap();
+    os.flush().unwrap();
+    drop(os);
+    assert_eq!(bytes, forward);
+}
+
+#[test]
+fn default_values() {
+    assert!(Outer::parse_from_bytes(&[]).is_err());
+
+    let m = Outer::new();
+    assert_eq!(-5, m.s64());
+    assert_eq!(0, m.compute_size());
+}
+
+#[test]
+fn packed_and_unpacked_accepted() {
+    let mut classic = classic::Outer::new();
+    classic.set_req(1.0);
+    classic.unpacked = vec![1, 2];
+    classic.packed = vec![3, 4];
+    let bytes = classic.write_to_bytes().unwrap();
+
+    // Switch wire type of both fields.
+    let mut input = Vec::new();
+    input.extend_from_slice(&[11 << 3 | 2, 2, 1, 2]);
+    input.extend_from_slice(&[12 << 3, 3, 12 << 3, 4]);
+    input.extend_from_slice(&[0x85, 0x01, 0, 0, 0x80, 0x3f]);
+
+    let m = Outer::parse_from_bytes(&input).unwrap();
+    assert_eq!(&[1, 2], &m.unpacked[..]);
+    assert_eq!(&[3, 4], &m.packed[..]);
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn unknown_fields_preserved() {
+    let mut input = Vec::new();
+    // Unknown field 20.
+    input.extend_from_slice(&[0xa0, 0x01, 7]);
+    // Known field with wrong wire type.
+    input.extend_from_slice(&[1 << 3 | 5, 1, 2, 3, 4]);
+
+    let mut m = Inner::new();
+    m.merge_from_bytes(&input).unwrap();
+    assert!(!m.has_name());
+    assert_eq!(&[7], &m.unknown_fields().get(20).unwrap().varint[..]);
+    assert_eq!(
+        &[0x04030201],
+        &m.unknown_fields().get(1).unwrap().fixed32[..]
+    );
+
+    m.set_name("n".to_owned());
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed = classic::Inner::parse_from_bytes(&bytes).unwrap();
+    assert_eq!("n", parsed.name());
+    assert_eq!(2, parsed.unknown_fields().iter().count());
+}
+
+#[test]
+fn oneof_last_variant_wins() {
+    let mut classic = classic::Outer::new();
+    classic.set_req(0.0);
+    classic.set_one_string("s".to_owned());
+    let mut bytes = classic.write_to_bytes().unwrap();
+    classic.set_one_u32(3);
+    bytes.extend(classic.write_to_bytes().unwrap());
+
+    let m = Outer::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(3, m.one_u32());
+    assert!(!m.has_one_string());
+}
@@ -0,0 +1,155 @@
+use protobuf::view::MessageView;
+use protobuf::well_known_types::Duration;
+use protobuf::Message;
+
+use super::test_views_imported_pb::Imported;
+use super::test_views_pb::Color;
+use super::test_views_pb::Inner;
+use super::test_views_pb::InnerView;
+use super::test_views_pb::Outer;
+use super::test_views_pb::OuterView;
+
+fn inner(name: &str, values: &[i32]) -> Inner {
+    let mut inner = Inner::new();
+    inner.set_name(name.to_owned());
+    inner.values = values.to_vec();
+    inner
+}
+
+fn outer() -> Outer {
+    let mut m = Outer::new();
+    m.set_i32(-17);
+    m.set_s64(-1 << 40);
+    m.set_f32(0xabcd);
+    m.set_d(1.5);
+    m.set_b(true);
+    m.set_s("world".to_owned());
+    m.set_by(vec![1, 2, 3]);
+    m.set_color(Color::BLUE);
+    m.inner = Some(inner("in", &[1, 2])).into();
+    m.inners = vec![inner("a", &[]), inner("b", &[3])];
+    m.unpacked = vec![1, -1, 300];
+    m.packed = vec![0, 1 << 50, 7];
+    m.strings = vec!["x".to_owned(), "".to_owned(), "zz".to_owned()];
+    m.set_one_string("one".to_owned());
+    m.duration.mut_or_default().seconds = 10;
+    m.map.insert("k".to_owned(), 1);
+    m
+}
+
+#[test]
+fn view_matches_message() {
+    let m = outer();
+    let bytes = m.write_to_bytes().unwrap();
+    let view = OuterView::parse(&bytes).unwrap();
+
+    assert_eq!(m.i32(), view.i32());
+    assert_eq!(m.s64(), view.s64());
+    assert_eq!(m.f32(), view.f32());
+    assert_eq!(m.d(), view.d());
+    assert_eq!(m.b(), view.b());
+    assert_eq!(m.s(), view.s());
+    assert_eq!(m.by(), view.by());
+    assert_eq!(Color::BLUE, view.color());
+
+    let inner = view.inner().unwrap().unwrap();
+    assert_eq!("in", inner.name());
+    assert_eq!(vec![1, 2], inner.values().collect::<Vec<_>>());
+
+    let inners: Vec<InnerView> = view.inners().map(|v| v.unwrap()).collect();
+    assert_eq!(2, inners.len());
+    assert_eq!("a", inners[0].name());
+    assert_eq!("b", inners[1].name());
+    assert_eq!(m.inners[1], inners[1].to_message().unwrap());
+
+    assert_eq!(m.unpacked, view.unpacked().collect::<Vec<_>>());
+    assert_eq!(m.packed, view.packed().collect::<Vec<_>>());
+    assert_eq!(m.strings, view.strings().collect::<Vec<_>>());
+
+    assert!(!view.has_one_u32());
+    assert_eq!("one", view.one_string());
+
+    let duration = Duration::parse_from_bytes(view.duration().unwrap()).unwrap();
+    assert_eq!(10, duration.seconds);
+
+    assert_eq!(&bytes[..], view.bytes());
+    assert_eq!(m, view.to_message().unwrap());
+}
+
+#[test]
+fn view_of_message_from_file_without_views() {
+    let mut m = Outer::new();
+    m.imported.mut_or_default().set_i(5);
+    let bytes = m.write_to_bytes().unwrap();
+    let view = OuterView::parse(&bytes).unwrap();
+    let imported = Imported::parse_from_bytes(view.imported().unwrap()).unwrap();
+    assert_eq!(5, imported.i());
+}
+
+#[test]
+fn view_defaults() {
+    let view = OuterView::parse(&[]).unwrap();
+    assert!(!view.has_i32());
+    assert_eq!(0, view.i32());
+    assert_eq!(-5, view.s64());
+    assert_eq!("hello", view.s());
+    assert_eq!(b"", view.by());
+    assert_eq!(Color::GREEN, view.color());
+    assert!(view.inner().unwrap().is_none());
+    assert_eq!(0, view.inners().count());
+    assert_eq!(0, view.packed().count());
+    assert!(view.duration().is_none());
+}
+
+#[test]
+fn view_oneof_last_wins() {
+    let mut m = Outer::new();
+    m.set_one_string("s".to_owned());
+    let mut bytes = m.write_to_bytes().unwrap();
+    m.set_one_u32(3);
+    bytes.extend(m.write_to_bytes().unwrap());
+
+    let view = OuterView::parse(&bytes).unwrap();
+    assert!(!view.has_one_string());
+    assert_eq!(3, view.one_u32());
+}
+
+#[test]
+fn view_packed_and_unpacked_mixed() {
+    // Field 11 unpacked: 1, then packed: [2, 3], then unpacked: 4.
+    let bytes = [11 << 3, 1, 11 << 3 | 2, 2, 2, 3, 11 << 3, 4];
+    let view = OuterView::parse(&bytes).unwrap();
+    assert_eq!(vec![1, 2, 3, 4], view.unpacked().collect::<Vec<_>>());
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        Outer::parse_from_bytes(&bytes).unwrap().unpacked
+    );
+}
+
+#[test]
+fn view_invalid_utf8() {
+    let bytes = [6 << 3 | 2, 1, 0xff];
+    assert!(OuterView::parse(&bytes).is_err());
+
+    let bytes = [13 << 3 | 2, 1, 0xff];
+    assert!(OuterView::parse(&bytes).is_err());
+}
+
+#[test]
+fn view_nested_errors_are_lazy() {
+    // Field 9 (inner) containing field 1 (name) with invalid UTF-8.
+    let bytes = [9 << 3 | 2, 3, 1 << 3 | 2, 1, 0xff];
+    let view = OuterView::parse(&bytes).unwrap();
+    assert!(view.inner().is_err());
+}
+
+#[test]
+fn view_truncated() {
+    let m = outer();
+    let bytes = m.write_to_bytes().unwrap();
+    for len in 0..bytes.len() {
+        // Must not panic; truncated prefix may be accidentally valid.
+        let _ = OuterView::parse(&bytes[..len]);
+    }
+    assert!(OuterView::parse(&bytes[..bytes.len() - 1]).is_err());
+}
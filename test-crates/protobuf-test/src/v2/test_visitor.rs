@@ -0,0 +1,286 @@
+use protobuf::reflect::walk_message;
+use protobuf::reflect::walk_message_mut;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDifferencer;
+use protobuf::reflect::MessageVisitor;
+use protobuf::reflect::MessageVisitorMut;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::ReflectValueRef;
+use protobuf::reflect::ValuePosition;
+use protobuf::reflect::VisitAction;
+use protobuf::text_format;
+use protobuf::MessageDyn;
+use protobuf::UnknownFields;
+
+use super::test_visitor_pb;
+use super::test_visitor_pb::Record;
+
+const RECORD: &str = r#"
+    secret: "s"
+    item { name: "a" secret: "sa" }
+    items { name: "b" }
+    items { name: "c" secret: "sc" }
+    item_by_key { key: "d" value { secret: "sd" } }
+    labels { key: "e" value: "f" }
+    numbers: 1
+    numbers: 2
+    numbers: 3
+    kind_item { name: "g" }
+"#;
+
+fn generated(text: &str) -> Box<dyn MessageDyn> {
+    let record: Record = text_format::parse_from_str(text).unwrap();
+    with_unknown_field(Box::new(record))
+}
+
+fn dynamic(text: &str) -> Box<dyn MessageDyn> {
+    let file =
+        FileDescriptor::new_dynamic(test_visitor_pb::file_descriptor_proto().clone(), Vec::new())
+            .unwrap();
+    let mut record = file
+        .message_by_package_relative_name("Record")
+        .unwrap()
+        .new_instance();
+    text_format::merge_from_str(&mut *record, text).unwrap();
+    with_unknown_field(record)
+}
+
+fn with_unknown_field(mut m: Box<dyn MessageDyn>) -> Box<dyn MessageDyn> {
+    m.mut_unknown_fields_dyn().add_varint(100, 7);
+    m
+}
+
+#[derive(Default)]
+struct Log(Vec<String>);
+
+impl MessageVisitor for Log {
+    fn enter_message(&mut self, message: &dyn MessageDyn) -> bool {
+        self.0
+            .push(format!("enter {}", message.descriptor_dyn().name()));
+        true
+    }
+
+    fn leave_message(&mut self, message: &dyn MessageDyn) {
+        self.0
+            .push(format!("leave {}", message.descriptor_dyn().name()));
+    }
+
+    fn enter_field(&mut self, field: &FieldDescriptor) -> bool {
+        self.0.push(format!("field {}", field.name()));
+        // Skip the field, but not the rest of the message.
+        field.name() != "labels"
+    }
+
+    fn leave_field(&mut self, field: &FieldDescriptor) {
+        self.0.push(format!("end {}", field.name()));
+    }
+
+    fn visit_value(
+        &mut self,
+        _field: &FieldDescriptor,
+        position: ValuePosition,
+        value: ReflectValueRef,
+    ) {
+        let value = match value {
+            ReflectValueRef::Message(..) => "message".to_owned(),
+            v => format!("{:?}", v),
+        };
+        self.0.push(format!("{:?} {}", position, value));
+    }
+
+    fn visit_unknown_fields(&mut self, unknown_fields: &UnknownFields) {
+        self.0
+            .push(format!("unknown {}", unknown_fields.iter().count()));
+    }
+}
+
+fn test_walk(record: &dyn MessageDyn) {
+    let mut log = Log::default();
+    walk_message(record, &mut log);
+    let expected = [
+        "enter Record",
+        "field secret",
+        "Singular String(\"s\")",
+        "end secret",
+        "field item",
+        "Singular message",
+        "enter Item",
+        "field name",
+        "Singular String(\"a\")",
+        "end name",
+        "field secret",
+        "Singular String(\"sa\")",
+        "end secret",
+        "leave Item",
+        "end item",
+        "field items",
+        "Index(0) message",
+        "enter Item",
+        "field name",
+        "Singular String(\"b\")",
+        "end name",
+        "leave Item",
+        "Index(1) message",
+        "enter Item",
+        "field name",
+        "Singular String(\"c\")",
+        "end name",
+        "field secret",
+        "Singular String(\"sc\")",
+        "end secret",
+        "leave Item",
+        "end items",
+        "field item_by_key",
+        "Key(String(\"d\")) message",
+        "enter Item",
+        "field secret",
+        "Singular String(\"sd\")",
+        "end secret",
+        "leave Item",
+        "end item_by_key",
+        "field labels",
+        "field numbers",
+        "Index(0) I32(1)",
+        "Index(1) I32(2)",
+        "Index(2) I32(3)",
+        "end numbers",
+        "field kind_item",
+        "Singular message",
+        "enter Item",
+        "field name",
+        "Singular String(\"g\")",
+        "end name",
+        "leave Item",
+        "end kind_item",
+        "unknown 1",
+        "leave Record",
+    ];
+    assert_eq!(&expected[..], &log.0[..]);
+}
+
+#[test]
+fn walk_generated() {
+    test_walk(&*generated(RECORD));
+}
+
+#[test]
+fn walk_dynamic() {
+    test_walk(&*dynamic(RECORD));
+}
+
+#[test]
+fn skip_message() {
+    struct SkipItems(u32);
+
+    impl MessageVisitor for SkipItems {
+        fn enter_message(&mut self, message: &dyn MessageDyn) -> bool {
+            self.0 += 1;
+            message.descriptor_dyn().name() != "Item"
+        }
+
+        fn leave_message(&mut self, message: &dyn MessageDyn) {
+            assert_eq!("Record", message.descriptor_dyn().name());
+        }
+    }
+
+    let mut visitor = SkipItems(0);
+    walk_message(&*generated(RECORD), &mut visitor);
+    assert_eq!(6, visitor.0);
+}
+
+/// Scrub secrets, drop odd numbers and unknown fields,
+/// remove items without name and the first label.
+struct Scrub;
+
+impl MessageVisitorMut for Scrub {
+    fn visit_value(
+        &mut self,
+        field: &FieldDescriptor,
+        position: ValuePosition,
+        value: ReflectValueRef,
+    ) -> VisitAction {
+        match (field.name(), position, value) {
+            ("secret", ValuePosition::Singular, _) => {
+                VisitAction::Replace(ReflectValueBox::String("***".to_owned()))
+            }
+            ("numbers", _, ReflectValueRef::I32(n)) if n % 2 == 1 => VisitAction::Clear,
+            ("numbers", _, ReflectValueRef::I32(n)) => {
+                VisitAction::Replace(ReflectValueBox::I32(n * 10))
+            }
+            ("items" | "item_by_key", _, ReflectValueRef::Message(m))
+                if !m
+                    .descriptor_dyn()
+                    .field_by_name("name")
+                    .unwrap()
+                    .has_field(&*m) =>
+            {
+                VisitAction::Clear
+            }
+            ("labels", _, _) => VisitAction::Clear,
+            _ => VisitAction::Keep,
+        }
+    }
+
+    fn visit_unknown_fields(&mut self, unknown_fields: &mut UnknownFields) {
+        unknown_fields.clear();
+    }
+}
+
+fn test_walk_mut(mut record: Box<dyn MessageDyn>) {
+    walk_message_mut(&mut *record, &mut Scrub);
+    let mut expected = record.descriptor_dyn().new_instance();
+    text_format::merge_from_str(&mut *expected, SCRUBBED).unwrap();
+    assert!(
+        MessageDifferencer::default().equals(&*expected, &*record),
+        "{:?}",
+        MessageDifferencer::default().diff(&*expected, &*record)
+    );
+    assert_eq!(0, record.unknown_fields_dyn().iter().count());
+}
+
+const SCRUBBED: &str = r#"
+    secret: "***"
+    item { name: "a" secret: "***" }
+    items { name: "b" }
+    items { name: "c" secret: "***" }
+    numbers: 20
+    kind_item { name: "g" }
+"#;
+
+#[test]
+fn walk_mut_generated() {
+    test_walk_mut(generated(RECORD));
+}
+
+#[test]
+fn walk_mut_dynamic() {
+    test_walk_mut(dynamic(RECORD));
+}
+
+#[test]
+fn clear_oneof() {
+    struct ClearKind;
+
+    impl MessageVisitorMut for ClearKind {
+        fn visit_value(
+            &mut self,
+            field: &FieldDescriptor,
+            _: ValuePosition,
+            _: ReflectValueRef,
+        ) -> VisitAction {
+            match field.containing_oneof() {
+                Some(..) => VisitAction::Clear,
+                None => VisitAction::Keep,
+            }
+        }
+    }
+
+    for mut record in [generated(RECORD), dynamic(RECORD)] {
+        walk_message_mut(&mut *record, &mut ClearKind);
+        let kind_item = record.descriptor_dyn().field_by_name("kind_item").unwrap();
+        assert!(!kind_item.has_field(&*record));
+        let item = record.descriptor_dyn().field_by_name("item").unwrap();
+        assert!(item.has_field(&*record));
+    }
+}
@@ -0,0 +1,27 @@
+use protobuf::text_format;
+use protobuf::Message;
+
+use super::test_merge_from_message_pb::Proto3Merge;
+
+#[test]
+fn default_values_do_not_overwrite() {
+    let mut target: Proto3Merge =
+        text_format::parse_from_str("i: 1 s: \"s\" bs: \"b\" d: 1 e: ONE oi: 1 inner { a: 1 }")
+            .unwrap();
+    let expected = target.clone();
+    target.merge_from_message(&Proto3Merge::new());
+    assert_eq!(expected, target);
+}
+
+#[test]
+fn set_values_overwrite() {
+    let mut target: Proto3Merge =
+        text_format::parse_from_str("i: 1 s: \"s\" d: 1 inner { a: 1 }").unwrap();
+    let source: Proto3Merge =
+        text_format::parse_from_str("s: \"t\" bs: \"b\" e: ONE oi: 0 inner { }").unwrap();
+    target.merge_from_message(&source);
+    let expected: Proto3Merge =
+        text_format::parse_from_str("i: 1 s: \"t\" bs: \"b\" d: 1 e: ONE oi: 0 inner { a: 1 }")
+            .unwrap();
+    assert_eq!(expected, target);
+}
@@ -0,0 +1,103 @@
+use protobuf::reflect::ReflectValueRef;
+use protobuf::MessageDyn;
+use protobuf::MessageFull;
+use protobuf_test_common::*;
+
+use super::test_proto3_optional_pb::*;
+
+#[test]
+fn test_has_clear() {
+    let mut m = TestProto3Optional::new();
+    assert!(!m.has_iii());
+    assert_eq!(0, m.iii());
+    m.set_iii(0);
+    assert!(m.has_iii());
+    m.clear_iii();
+    assert!(!m.has_iii());
+
+    assert!(!m.has_sss());
+    assert_eq!("", m.sss());
+    m.set_sss(String::new());
+    assert!(m.has_sss());
+
+    assert!(!m.has_eee());
+    m.set_eee(TestProto3OptionalEnum::UNKNOWN);
+    assert!(m.has_eee());
+}
+
+#[test]
+fn test_default_values_are_written() {
+    let mut m = TestProto3Optional::new();
+    test_serialize_deserialize_with_dynamic("", &m);
+
+    m.set_iii(0);
+    test_serialize_deserialize_with_dynamic("08 00", &m);
+
+    m.set_sss(String::new());
+    m.set_bbb(Vec::new());
+    m.set_eee(TestProto3OptionalEnum::UNKNOWN);
+    test_serialize_deserialize_with_dynamic("08 00 12 00 1a 00 20 00", &m);
+
+    let mut m = TestProto3Optional::new();
+    m.plain = 0;
+    m.set_conflict(0);
+    test_serialize_deserialize_with_dynamic("48 00", &m);
+}
+
+#[test]
+fn test_reflect_has_field() {
+    let mut m = TestProto3Optional::new();
+    let iii = TestProto3Optional::descriptor_static()
+        .field_by_name("iii")
+        .unwrap();
+    assert!(!iii.has_field(&m));
+    assert_eq!(None, iii.get_singular(&m));
+    m.set_iii(0);
+    assert!(iii.has_field(&m));
+    assert_eq!(Some(ReflectValueRef::I32(0)), iii.get_singular(&m));
+}
+
+#[test]
+fn test_synthetic_oneofs() {
+    let d = TestProto3Optional::descriptor_static();
+
+    let iii = d.field_by_name("iii").unwrap();
+    assert!(iii.is_proto3_optional());
+    assert!(iii.containing_oneof().is_none());
+    let oneof = iii.containing_oneof_including_synthetic().unwrap();
+    assert!(oneof.is_synthetic());
+    assert_eq!("_iii", oneof.name());
+    assert!(oneof.fields().eq(vec![iii.clone()]));
+
+    let plain = d.field_by_name("plain").unwrap();
+    assert!(!plain.is_proto3_optional());
+    assert!(plain.containing_oneof_including_synthetic().is_none());
+
+    let regular = d
+        .field_by_name("regular_uint32")
+        .unwrap()
+        .containing_oneof()
+        .unwrap();
+    assert!(!regular.is_synthetic());
+    assert_eq!("regular", regular.name());
+
+    let conflict = d.field_by_name("conflict").unwrap();
+    assert_eq!(
+        "X_conflict",
+        conflict
+            .containing_oneof_including_synthetic()
+            .unwrap()
+            .name()
+    );
+}
+
+#[test]
+fn test_dynamic() {
+    let d = TestProto3Optional::descriptor_static();
+    let mut m = d.new_instance();
+    let iii = d.field_by_name("iii").unwrap();
+    assert!(!iii.has_field(&*m));
+    iii.set_singular_field(&mut *m, 0.into());
+    assert!(iii.has_field(&*m));
+    assert_eq!(vec![0x08, 0x00], m.write_to_bytes_dyn().unwrap());
+}
@@ -0,0 +1,31 @@
+use protobuf::Message;
+
+use super::test_table_driven_proto3_pb::Implicit;
+use super::test_table_driven_proto3_pb::Kind;
+
+#[test]
+fn zeros_are_not_written() {
+    let mut m = Implicit::new();
+    m.set_explicit(0);
+    m.child = Some(Implicit::new()).into();
+    // Only `explicit` and `child` are written.
+    assert_eq!(vec![6 << 3, 0, 8 << 3 | 2, 0], m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn round_trip() {
+    let mut m = Implicit::new();
+    m.i32 = -3;
+    m.d = 0.5;
+    m.s = "s".to_owned();
+    m.by = vec![0];
+    m.kind = Kind::FIRST.into();
+    m.packed = vec![-1, 0, 1];
+    m.child.mut_or_default().s = "child".to_owned();
+    m.mut_one_child().set_one_bool(false);
+
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(bytes.len() as u64, m.compute_size());
+    assert_eq!(bytes.len() as u32, m.cached_size());
+    assert_eq!(m, Implicit::parse_from_bytes(&bytes).unwrap());
+}